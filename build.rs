@@ -0,0 +1,52 @@
+//! Embeds build metadata for `bootroot version --verbose`.
+//!
+//! Sets `BOOTROOT_GIT_SHA`, `BOOTROOT_BUILD_EPOCH`, and
+//! `BOOTROOT_RUSTC_VERSION`. Values that cannot be determined (for
+//! example a source tarball without `.git`) fall back to `unknown` so the
+//! build never fails on missing metadata.
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const UNKNOWN: &str = "unknown";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    // Only watch git metadata that exists: a missing path would make
+    // cargo rerun the script on every build.
+    for path in [".git/HEAD", ".git/refs/heads"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+
+    let git_sha = command_stdout("git", &["rev-parse", "--short=12", "HEAD"])
+        .unwrap_or_else(|| UNKNOWN.to_string());
+    println!("cargo:rustc-env=BOOTROOT_GIT_SHA={git_sha}");
+
+    // SOURCE_DATE_EPOCH keeps reproducible builds reproducible.
+    let build_epoch = std::env::var("SOURCE_DATE_EPOCH").unwrap_or_else(|_| {
+        SystemTime::now().duration_since(UNIX_EPOCH).map_or_else(
+            |_| UNKNOWN.to_string(),
+            |elapsed| elapsed.as_secs().to_string(),
+        )
+    });
+    println!("cargo:rustc-env=BOOTROOT_BUILD_EPOCH={build_epoch}");
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version =
+        command_stdout(&rustc, &["--version"]).unwrap_or_else(|| UNKNOWN.to_string());
+    println!("cargo:rustc-env=BOOTROOT_RUSTC_VERSION={rustc_version}");
+}
+
+fn command_stdout(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let trimmed = stdout.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
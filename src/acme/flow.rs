@@ -469,6 +469,9 @@ mod tests {
             },
             profiles: Vec::new(),
             openbao: None,
+            env_interpolation: false,
+            strict_env_interpolation: false,
+            renew_jitter_percent: 0,
         }
     }
 
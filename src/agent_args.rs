@@ -1,7 +1,10 @@
+use std::net::SocketAddr;
 use std::path::PathBuf;
 
 use clap::{ArgAction, Parser};
 
+use crate::config::MAX_RENEW_JITTER_PERCENT;
+
 #[derive(Parser, Debug)]
 #[command(
     author,
@@ -49,6 +52,20 @@ pub struct Args {
     /// Disable TLS certificate verification for this run only (INSECURE break-glass override)
     #[arg(long, action = ArgAction::SetTrue)]
     pub insecure: bool,
+
+    /// Address for the `/healthz` and `/metrics` endpoints (daemon mode only)
+    #[arg(long, env = "BOOTROOT_METRICS_ADDR")]
+    pub metrics_addr: Option<SocketAddr>,
+
+    /// Renew earlier by up to this percentage of `renew_before` (0-50,
+    /// overrides `renew_jitter_percent` in the config)
+    #[arg(
+        long,
+        env = "BOOTROOT_RENEW_JITTER",
+        value_name = "PERCENT",
+        value_parser = clap::value_parser!(u8).range(0..=i64::from(MAX_RENEW_JITTER_PERCENT))
+    )]
+    pub renew_jitter: Option<u8>,
 }
 
 #[cfg(test)]
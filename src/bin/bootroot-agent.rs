@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use bootroot::config::CliOverrides;
+use bootroot::metrics::{self, AgentMetrics};
 use bootroot::{Args, config, eab, profile, run_daemon, run_oneshot};
 use clap::Parser;
 #[cfg(unix)]
@@ -46,6 +47,11 @@ async fn main() -> anyhow::Result<()> {
     } else {
         args.eab_file.clone()
     };
+    let agent_metrics = Arc::new(AgentMetrics::default());
+    let _metrics_server = match args.metrics_addr {
+        Some(addr) => Some(metrics::spawn_server(addr, Arc::clone(&agent_metrics)).await?),
+        None => None,
+    };
     let mut pending = None;
     #[cfg(unix)]
     let mut hup = signal(SignalKind::hangup())?;
@@ -63,6 +69,7 @@ async fn main() -> anyhow::Result<()> {
             args.config.clone(),
             args.insecure,
             cli_overrides.clone(),
+            Arc::clone(&agent_metrics),
         ));
         #[cfg(unix)]
         loop {
@@ -72,6 +79,13 @@ async fn main() -> anyhow::Result<()> {
                     match load_settings(&args).await {
                         Ok((settings, final_eab)) => {
                             info!("Reload signal received. Restarting daemon with new config.");
+                            agent_metrics.retain_profiles(
+                                &settings
+                                    .profiles
+                                    .iter()
+                                    .map(|profile| config::profile_domain(&settings, profile))
+                                    .collect(),
+                            );
                             pending = Some((settings, final_eab));
                             task.abort();
                             let _ = task.await;
@@ -190,6 +204,9 @@ mod tests {
             },
             profiles,
             openbao: None,
+            env_interpolation: false,
+            strict_env_interpolation: false,
+            renew_jitter_percent: 0,
         }
     }
 
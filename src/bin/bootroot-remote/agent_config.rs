@@ -383,6 +383,7 @@ fn build_openbao_updates(
     let mut pairs = vec![
         ("url", args.openbao_url.clone()),
         ("kv_mount", args.kv_mount.clone()),
+        ("service_kv_base", args.service_kv_base.clone()),
         ("role_id_path", args.role_id_path.display().to_string()),
         ("secret_id_path", args.secret_id_path.display().to_string()),
         ("ca_bundle_path", args.ca_bundle_path.display().to_string()),
@@ -647,6 +648,7 @@ mod tests {
         ResolvedBootstrapArgs {
             openbao_url: "https://localhost:8200".to_string(),
             kv_mount: "secret".to_string(),
+            service_kv_base: "bootroot/services".to_string(),
             service_name: "edge-proxy".to_string(),
             role_id_path: PathBuf::from("/tmp/role_id"),
             secret_id_path: PathBuf::from("/tmp/secrets/services/edge-proxy/secret_id"),
@@ -684,6 +686,7 @@ mod tests {
             vec![
                 "url",
                 "kv_mount",
+                "service_kv_base",
                 "role_id_path",
                 "secret_id_path",
                 "ca_bundle_path",
@@ -6,7 +6,7 @@ use super::io::{read_required_string, read_secret_file, write_secret_file};
 use super::openbao_client::build_openbao_client;
 use super::summary::{ApplyStatus, status_to_str};
 use super::validation::validate_service_name;
-use super::{ApplySecretIdArgs, Locale, OutputFormat, SECRET_ID_KEY, SERVICE_KV_BASE, localized};
+use super::{ApplySecretIdArgs, Locale, OutputFormat, SECRET_ID_KEY, localized};
 
 /// Resolves the CA-bundle PEM for the `AppRole` login transport.
 ///
@@ -97,7 +97,11 @@ pub(super) async fn run_apply_secret_id(args: ApplySecretIdArgs, lang: Locale) -
         })?;
     client.set_token(token);
 
-    let kv_path = format!("{SERVICE_KV_BASE}/{}/secret_id", args.service_name);
+    let kv_path = format!(
+        "{}/{}/secret_id",
+        args.service_kv_base.trim_matches('/'),
+        args.service_name
+    );
     let data = client
         .read_kv(&args.kv_mount, &kv_path)
         .await
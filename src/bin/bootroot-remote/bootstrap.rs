@@ -7,12 +7,14 @@ use super::io::{
     pull_secrets, read_secret_file, remove_eab_file, write_eab_file, write_secret_file,
 };
 use super::openbao_client::build_openbao_client;
-use super::summary::{ApplyItemSummary, ApplySummary, merge_apply_status, print_summary};
+use super::summary::{
+    ApplyItemSummary, ApplySummary, merge_apply_status, print_next_steps, print_summary,
+};
 use super::validation::{
     validate_agent_domain, validate_profile_hostname, validate_profile_instance_id,
     validate_service_name,
 };
-use super::{HookFailurePolicy, Locale, ResolvedBootstrapArgs, localized};
+use super::{HookFailurePolicy, Locale, OutputFormat, ResolvedBootstrapArgs, localized};
 
 /// Errors specific to wrap-token unwrapping.
 #[derive(Debug)]
@@ -127,7 +129,14 @@ pub(super) async fn run_bootstrap(args: ResolvedBootstrapArgs, lang: Locale) ->
         })?;
     client.set_token(token);
 
-    let pulled = pull_secrets(&client, &args.kv_mount, &args.service_name, lang).await?;
+    let pulled = pull_secrets(
+        &client,
+        &args.kv_mount,
+        &args.service_kv_base,
+        &args.service_name,
+        lang,
+    )
+    .await?;
     let secret_id_status = match write_secret_file(&args.secret_id_path, &pulled.secret_id).await {
         Ok(status) => ApplyItemSummary::applied(status),
         Err(err) => ApplyItemSummary::failed(localized(
@@ -200,6 +209,13 @@ pub(super) async fn run_bootstrap(args: ResolvedBootstrapArgs, lang: Locale) ->
     if summary.has_failures() {
         return Ok(1);
     }
+    if matches!(args.output, OutputFormat::Text) {
+        let eab_file_path = args
+            .eab_file_path
+            .exists()
+            .then_some(args.eab_file_path.as_path());
+        print_next_steps(&args.agent_config_path, eab_file_path, lang);
+    }
     Ok(0)
 }
 
@@ -530,6 +546,7 @@ mod tests {
         ResolvedBootstrapArgs {
             openbao_url: String::new(),
             kv_mount: String::new(),
+            service_kv_base: String::new(),
             service_name: String::new(),
             role_id_path: PathBuf::new(),
             secret_id_path: PathBuf::new(),
@@ -146,10 +146,11 @@ pub(super) struct PulledSecrets {
 pub(super) async fn pull_secrets(
     client: &bootroot::openbao::OpenBaoClient,
     mount: &str,
+    service_kv_base: &str,
     service: &str,
     lang: Locale,
 ) -> Result<PulledSecrets> {
-    let base = format!("{}/{service}", super::SERVICE_KV_BASE);
+    let base = format!("{service_kv_base}/{service}");
     let secret_id_data = client
         .read_kv(mount, &format!("{base}/secret_id"))
         .await
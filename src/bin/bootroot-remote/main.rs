@@ -9,6 +9,7 @@ mod validation;
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
+use bootroot::env_compat::OPENBAO_URL_ENV;
 use bootroot::locale::Locale;
 use bootroot::trust_bootstrap::{
     EAB_HMAC_KEY, EAB_KID_KEY, HMAC_KEY, SECRET_ID_KEY, SERVICE_KV_BASE,
@@ -31,7 +32,8 @@ const DEFAULT_AGENT_RESPONDER_URL: &str = "http://127.0.0.1:8080";
     about = "One-shot bootstrap and secret_id handoff for remote services"
 )]
 struct Args {
-    /// Language for CLI output (en or ko)
+    /// Language for CLI output (en, ko, fr, or auto to follow the
+    /// system locale)
     #[arg(long, env = "BOOTROOT_LANG", default_value = "en", global = true)]
     lang: String,
 
@@ -39,23 +41,25 @@ struct Args {
     command: Command,
 }
 
+// bootroot-remote carries English and Korean messages only; French
+// falls back to English here.
 fn localized(lang: Locale, en: &str, ko: &str) -> String {
     match lang {
-        Locale::En => en.to_string(),
+        Locale::En | Locale::Fr => en.to_string(),
         Locale::Ko => ko.to_string(),
     }
 }
 
 fn summary_header(lang: Locale) -> &'static str {
     match lang {
-        Locale::En => "bootroot-remote bootstrap summary",
+        Locale::En | Locale::Fr => "bootroot-remote bootstrap summary",
         Locale::Ko => "bootroot-remote 부트스트랩 요약",
     }
 }
 
 fn redacted_error_label(lang: Locale) -> &'static str {
     match lang {
-        Locale::En => "error",
+        Locale::En | Locale::Fr => "error",
         Locale::Ko => "오류",
     }
 }
@@ -80,13 +84,18 @@ struct BootstrapArgs {
     artifact: Option<PathBuf>,
 
     /// `OpenBao` base URL
-    #[arg(long, env = "OPENBAO_URL", required_unless_present = "artifact")]
+    #[arg(long, env = OPENBAO_URL_ENV, required_unless_present = "artifact")]
     openbao_url: Option<String>,
 
     /// `OpenBao` KV mount (v2)
     #[arg(long, default_value = "secret", env = "OPENBAO_KV_MOUNT")]
     kv_mount: String,
 
+    /// KV base the service's payloads live under (`<kv_prefix>/services`
+    /// on the control plane)
+    #[arg(long, default_value = SERVICE_KV_BASE, env = "OPENBAO_SERVICE_KV_BASE")]
+    service_kv_base: String,
+
     /// Service name
     #[arg(long, required_unless_present = "artifact")]
     service_name: Option<String>,
@@ -179,6 +188,7 @@ struct BootstrapArgs {
 struct ResolvedBootstrapArgs {
     openbao_url: String,
     kv_mount: String,
+    service_kv_base: String,
     service_name: String,
     role_id_path: PathBuf,
     secret_id_path: PathBuf,
@@ -241,13 +251,18 @@ struct ResolvedPostRenewHook {
 #[derive(clap::Args, Debug)]
 struct ApplySecretIdArgs {
     /// `OpenBao` base URL
-    #[arg(long, env = "OPENBAO_URL")]
+    #[arg(long, env = OPENBAO_URL_ENV)]
     openbao_url: String,
 
     /// `OpenBao` KV mount (v2)
     #[arg(long, default_value = "secret", env = "OPENBAO_KV_MOUNT")]
     kv_mount: String,
 
+    /// KV base the service's payloads live under (`<kv_prefix>/services`
+    /// on the control plane)
+    #[arg(long, default_value = SERVICE_KV_BASE, env = "OPENBAO_SERVICE_KV_BASE")]
+    service_kv_base: String,
+
     /// Service name
     #[arg(long)]
     service_name: String,
@@ -278,10 +293,16 @@ enum OutputFormat {
     Json,
 }
 
+fn main() {
+    // SAFETY: runs before the tokio runtime (and any other thread) starts.
+    unsafe { bootroot::env_compat::apply_vault_env_aliases() };
+    async_main();
+}
+
 #[tokio::main]
-async fn main() {
+async fn async_main() {
     let args = Args::parse();
-    let lang = match Locale::parse(&args.lang) {
+    let lang = match Locale::resolve(&args.lang) {
         Ok(lang) => lang,
         Err(err) => {
             eprintln!("{err}");
@@ -337,6 +358,10 @@ struct BootstrapArtifact {
     schema_version: u32,
     openbao_url: String,
     kv_mount: String,
+    /// Absent from artifacts written before the KV prefix was
+    /// configurable; those services live under the default base.
+    #[serde(default)]
+    service_kv_base: Option<String>,
     service_name: String,
     role_id_path: String,
     secret_id_path: String,
@@ -478,6 +503,12 @@ async fn resolve_bootstrap_args(args: BootstrapArgs) -> Result<ResolvedBootstrap
         .as_ref()
         .map(|a| a.kv_mount.clone())
         .unwrap_or(args.kv_mount);
+    let service_kv_base = artifact
+        .as_ref()
+        .and_then(|a| a.service_kv_base.clone())
+        .unwrap_or(args.service_kv_base)
+        .trim_matches('/')
+        .to_string();
     let agent_email_override = artifact.as_ref().and_then(|a| a.agent_email.clone());
     let agent_server_override = artifact.as_ref().and_then(|a| a.agent_server.clone());
     let agent_responder_url_override = artifact
@@ -563,6 +594,7 @@ async fn resolve_bootstrap_args(args: BootstrapArgs) -> Result<ResolvedBootstrap
     Ok(ResolvedBootstrapArgs {
         openbao_url,
         kv_mount,
+        service_kv_base,
         service_name,
         role_id_path,
         secret_id_path,
@@ -622,6 +654,10 @@ mod tests {
             summary_header(Locale::Ko),
             "bootroot-remote 부트스트랩 요약"
         );
+        assert_eq!(
+            summary_header(Locale::Fr),
+            "bootroot-remote bootstrap summary"
+        );
     }
 
     /// Issue #587 §4: `--post-renew-arg` must accept hyphen-prefixed
@@ -718,6 +754,7 @@ mod tests {
             artifact: Some(artifact_path),
             openbao_url: Some("https://cli-url:8200".to_string()),
             kv_mount: "cli-kv".to_string(),
+            service_kv_base: SERVICE_KV_BASE.to_string(),
             service_name: Some("cli-svc".to_string()),
             role_id_path: Some(PathBuf::from("/cli/role_id")),
             secret_id_path: Some(PathBuf::from("/cli/secret_id")),
@@ -787,6 +824,7 @@ mod tests {
             artifact: Some(artifact_path),
             openbao_url: None,
             kv_mount: "secret".to_string(),
+            service_kv_base: SERVICE_KV_BASE.to_string(),
             service_name: None,
             role_id_path: None,
             secret_id_path: None,
@@ -894,6 +932,7 @@ mod tests {
             artifact: Some(artifact_path),
             openbao_url: None,
             kv_mount: "secret".to_string(),
+            service_kv_base: SERVICE_KV_BASE.to_string(),
             service_name: None,
             role_id_path: None,
             secret_id_path: None,
@@ -929,6 +968,7 @@ mod tests {
             artifact: Some(artifact_path),
             openbao_url: None,
             kv_mount: "secret".to_string(),
+            service_kv_base: SERVICE_KV_BASE.to_string(),
             service_name: None,
             role_id_path: None,
             secret_id_path: None,
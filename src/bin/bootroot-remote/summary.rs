@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use anyhow::Result;
 use serde::Serialize;
 
@@ -131,6 +133,41 @@ pub(super) fn print_summary(
     }
 }
 
+/// Prints the commands that bring the freshly bootstrapped service
+/// online, so the operator does not have to look up the run command.
+pub(super) fn print_next_steps(
+    agent_config_path: &Path,
+    eab_file_path: Option<&Path>,
+    lang: Locale,
+) {
+    let run_command = match eab_file_path {
+        Some(eab_file_path) => format!(
+            "bootroot-agent --config {} --eab-file {}",
+            agent_config_path.display(),
+            eab_file_path.display()
+        ),
+        None => format!("bootroot-agent --config {}", agent_config_path.display()),
+    };
+    match lang {
+        Locale::En | Locale::Fr => {
+            println!("next steps:");
+            println!("- start bootroot-agent as a host daemon: {run_command}");
+            println!(
+                "- keep it running (e.g. a systemd unit with Restart=always); its fast-poll loop \
+                 pulls later secret_id, EAB, responder HMAC, and trust rotations"
+            );
+        }
+        Locale::Ko => {
+            println!("다음 단계:");
+            println!("- bootroot-agent를 호스트 데몬으로 시작하세요: {run_command}");
+            println!(
+                "- 계속 실행되도록 유지하세요(예: Restart=always systemd 유닛). fast-poll 루프가 \
+                 이후 secret_id, EAB, responder HMAC, trust 회전을 가져옵니다"
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
 pub(crate) mod args;
+pub(crate) mod config_file;
 pub(crate) mod output;
 pub(crate) mod prompt;
@@ -1,11 +1,18 @@
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::time::Duration;
 
+use bootroot::env_compat::OPENBAO_URL_ENV;
 use clap::{ArgGroup, ValueEnum};
 use clap::{Args, Parser, Subcommand};
 
+use crate::commands::compose_file::default_compose_file;
+use crate::commands::infra::{DEFAULT_PULL_RETRIES, DEFAULT_READINESS_TIMEOUT_SECS};
 use crate::commands::init::{
-    DEFAULT_CERT_DURATION, DEFAULT_COMPOSE_FILE, DEFAULT_KV_MOUNT, DEFAULT_OPENBAO_URL,
-    DEFAULT_SECRETS_DIR, DEFAULT_STEPCA_PROVISIONER, SECRET_ID_TTL,
+    DEFAULT_AGENT_RENDER_TIMEOUT_SECS, DEFAULT_CERT_DURATION, DEFAULT_KV_MOUNT,
+    DEFAULT_KV_PATH_PREFIX, DEFAULT_OPENBAO_AGENT_IMAGE, DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
+    DEFAULT_OPENBAO_URL, DEFAULT_SECRETS_DIR, DEFAULT_STEPCA_IMAGE, DEFAULT_STEPCA_PROVISIONER,
+    SECRET_ID_TTL,
 };
 use crate::state::{DeliveryMode, HookFailurePolicyEntry};
 
@@ -40,14 +47,69 @@ intact.";
     long_about = None,
 )]
 pub(crate) struct Cli {
-    /// Language for CLI output (en or ko)
+    /// Language for CLI output (en, ko, fr, or auto to follow the
+    /// system locale)
     #[arg(long, env = "BOOTROOT_LANG", default_value = "en", global = true)]
     pub(crate) lang: String,
 
+    /// Log `OpenBao` API calls to stderr (`-v`: method, path, status,
+    /// latency; `-vv`: also headers and bodies).  Tokens, secret IDs,
+    /// unseal keys, and KV secret values are redacted
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    pub(crate) verbose: u8,
+
+    /// Container runtime whose CLI runs compose, inspect, and container
+    /// commands
+    #[arg(
+        long,
+        env = "BOOTROOT_CONTAINER_RUNTIME",
+        value_enum,
+        default_value_t = ContainerRuntime::Docker,
+        global = true
+    )]
+    pub(crate) container_runtime: ContainerRuntime,
+
+    /// Abort the whole command once it has run this many seconds (exit
+    /// code 124).  The step in flight gets a short grace period to finish
+    /// and persist its state before the command stops
+    #[arg(
+        long,
+        env = "BOOTROOT_OPERATION_TIMEOUT",
+        value_name = "SECS",
+        value_parser = clap::value_parser!(u64).range(1..),
+        global = true
+    )]
+    pub(crate) operation_timeout: Option<u64>,
+
+    /// TOML file whose values become the defaults of matching flags
+    /// (`openbao_url`, `kv_mount`, `secrets_dir`, `compose_file`)
+    /// [default: `bootroot.toml` in the current directory, if present]
+    #[arg(long, env = "BOOTROOT_CONFIG", value_name = "PATH", global = true)]
+    pub(crate) config: Option<PathBuf>,
+
     #[command(subcommand)]
     pub(crate) command: CliCommand,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ContainerRuntime {
+    /// Docker Engine (`docker`, `docker compose`)
+    #[default]
+    Docker,
+    /// Podman (`podman`, `podman compose`)
+    Podman,
+}
+
+impl ContainerRuntime {
+    /// Returns the executable invoked for this runtime.
+    pub(crate) fn binary(self) -> &'static str {
+        match self {
+            Self::Docker => "docker",
+            Self::Podman => "podman",
+        }
+    }
+}
+
 #[derive(Subcommand, Debug)]
 pub(crate) enum CliCommand {
     /// Manages the infrastructure compose stack (`OpenBao`, `PostgreSQL`,
@@ -131,6 +193,54 @@ pub(crate) enum CliCommand {
     /// so a configuration change takes effect.
     #[command(subcommand)]
     Ca(CaCommand),
+    /// Exports bootroot's CA trust material for systems outside its
+    /// management.
+    ///
+    /// Covers `export`, which writes the root and intermediate CA bundle
+    /// as PEM, DER, or PKCS#7 for load balancers, browser trust stores,
+    /// and similar consumers, and `show`, a read-only trust-health view.
+    #[command(subcommand)]
+    Trust(TrustCommand),
+    /// Moves bootroot's `OpenBao` KV secrets to another mount or path
+    /// prefix.
+    ///
+    /// Copies every secret under the source prefix to the destination,
+    /// reads each copy back to verify it, and records the new mount in
+    /// `state.json`. With `--delete-source`, the verified source secrets
+    /// are deleted after confirmation (or `--yes`).
+    Migrate(MigrateArgs),
+    /// Warns when secret files written by bootroot are tracked by git.
+    ///
+    /// Lists the git index (`git ls-files`) and flags paths such as
+    /// `password.txt`, `state.json`, `role_id`/`secret_id`, and private
+    /// keys. File contents are never read. Exits non-zero when any secret
+    /// file is tracked, so it can run as a pre-push check.
+    AuditSecrets(AuditSecretsArgs),
+    /// Collects diagnostics into a directory to attach to a bug report.
+    ///
+    /// Writes bootroot and docker compose versions, `docker compose ps`
+    /// output, the tail of the compose logs, and `state.json` with
+    /// `AppRole` IDs, tokens, and hook arguments masked. Docker failures
+    /// are recorded in the bundle instead of aborting it.
+    SupportBundle(SupportBundleArgs),
+    /// Runs init, service add, rotate, and verify against a throwaway
+    /// compose stack, then tears it down.
+    ///
+    /// Meant for CI of a checkout or a fresh host before touching
+    /// production: it refuses to run where `state.json`, `secrets/`,
+    /// `.env`, or the `bootroot-openbao` container already exist, and
+    /// runs `bootroot clean --yes` afterwards even when a step fails.
+    /// Needs a local docker daemon; built only with the `self-test`
+    /// feature.
+    #[cfg(feature = "self-test")]
+    SelfTest(SelfTestArgs),
+    /// Prints the bootroot version.
+    ///
+    /// With the global `--verbose` flag, also prints the git commit,
+    /// build date, and rustc version embedded at build time, the
+    /// installed docker/podman versions, and the images referenced by
+    /// the compose file, for inclusion in support requests.
+    Version(VersionArgs),
 }
 
 #[derive(Subcommand, Debug)]
@@ -174,6 +284,63 @@ pub(crate) struct CaRestartArgs {
     pub(crate) compose_file: ComposeFileArgs,
 }
 
+#[derive(Subcommand, Debug)]
+pub(crate) enum TrustCommand {
+    /// Writes the current CA bundle (root and intermediate) and prints
+    /// its SHA-256 fingerprints.
+    ///
+    /// Reads `ca_bundle_pem` from the `bootroot/ca` `OpenBao` KV path
+    /// when a root token is available and falls back to the local
+    /// `certs/` directory otherwise. Read-only: nothing in `OpenBao` or
+    /// `state.json` changes.
+    Export(TrustExportArgs),
+    /// Prints the root and intermediate CA subjects, SHA-256
+    /// fingerprints, and not-after dates.
+    ///
+    /// Compares the local `certs/` files with the `bootroot/ca` `OpenBao`
+    /// KV bundle when a root token is available and flags drift between
+    /// them. Read-only.
+    Show(TrustShowArgs),
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct TrustExportArgs {
+    /// Output encoding of the bundle
+    #[arg(long, value_enum, default_value_t = TrustExportFormat::Pem)]
+    pub(crate) format: TrustExportFormat,
+
+    /// File to write the bundle to (defaults to stdout)
+    #[arg(long)]
+    pub(crate) out: Option<PathBuf>,
+
+    /// `OpenBao` API URL override (defaults to the value in `state.json`)
+    #[arg(long)]
+    pub(crate) openbao_url: Option<String>,
+
+    #[command(flatten)]
+    pub(crate) root_token: RootTokenArgs,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct TrustShowArgs {
+    /// `OpenBao` API URL override (defaults to the value in `state.json`)
+    #[arg(long)]
+    pub(crate) openbao_url: Option<String>,
+
+    #[command(flatten)]
+    pub(crate) root_token: RootTokenArgs,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TrustExportFormat {
+    /// Concatenated PEM certificates
+    Pem,
+    /// A single DER certificate (bundles with one certificate only)
+    Der,
+    /// Certificates-only PKCS#7 (`.p7b`) container
+    P7b,
+}
+
 #[derive(Subcommand, Debug)]
 pub(crate) enum InfraCommand {
     /// Starts the bootroot compose stack from the existing `secrets/`
@@ -194,6 +361,18 @@ pub(crate) enum InfraCommand {
     /// `--*-bind-wildcard` and `--*-advertise-addr` flags. These are
     /// deliberate guardrails — see issue #588 for the rationale.
     Install(InfraInstallArgs),
+    /// Stops and removes the bootroot compose stack's containers.
+    ///
+    /// Volumes (`OpenBao`, `PostgreSQL`, and step-ca data) are kept unless
+    /// `--volumes` is given; that removal is permanent and asks for
+    /// confirmation unless `--yes` is set.
+    Down(InfraDownArgs),
+    /// Restarts bootroot services through compose and waits for them to
+    /// report ready again.
+    Restart(InfraRestartArgs),
+    /// Shows (and optionally follows) the logs of bootroot services
+    /// through `docker compose logs`.
+    Logs(InfraLogsArgs),
 }
 
 #[derive(Subcommand, Debug)]
@@ -263,6 +442,11 @@ pub(crate) enum ServiceCommand {
     /// service's `OpenBao` KV path, and the next-step daemon snippet.
     /// The `AppRole` `secret_id` itself is never printed.
     Info(ServiceInfoArgs),
+    /// Lists the registered services with their delivery mode, hostname,
+    /// and domain.
+    ///
+    /// Read-only; reads `state.json` only.
+    List(ServiceListArgs),
     /// Edits a registered service's `secret_id` policy, post-renew hook,
     /// or cert ownership in place.
     ///
@@ -289,7 +473,7 @@ pub(crate) enum ServiceCommand {
 #[derive(Args, Debug, Clone)]
 pub(crate) struct OpenBaoArgs {
     /// `OpenBao` API URL
-    #[arg(long, default_value = DEFAULT_OPENBAO_URL)]
+    #[arg(long, env = OPENBAO_URL_ENV, default_value = DEFAULT_OPENBAO_URL)]
     pub(crate) openbao_url: String,
 
     /// `OpenBao` KV mount path (KV v2)
@@ -310,8 +494,10 @@ pub(crate) struct OpenBaoOverrideArgs {
 
 #[derive(Args, Debug, Clone)]
 pub(crate) struct ComposeFileArgs {
-    /// Path to docker-compose.yml
-    #[arg(long, default_value = DEFAULT_COMPOSE_FILE)]
+    /// Path to the compose file [default: first of compose.yaml,
+    /// compose.yml, docker-compose.yml, docker-compose.yaml in the current
+    /// directory]
+    #[arg(long, default_value_os_t = default_compose_file(), hide_default_value = true)]
     pub(crate) compose_file: PathBuf,
 }
 
@@ -331,7 +517,8 @@ pub(crate) struct SecretsDirOverrideArgs {
 
 #[derive(Args, Debug, Clone)]
 pub(crate) struct RootTokenArgs {
-    /// `OpenBao` root token
+    /// `OpenBao` root token (`BAO_TOKEN`/`VAULT_TOKEN` are read when
+    /// `OPENBAO_ROOT_TOKEN` is unset)
     #[arg(long, env = "OPENBAO_ROOT_TOKEN")]
     pub(crate) root_token: Option<String>,
 }
@@ -380,7 +567,7 @@ pub(crate) struct RuntimeAuthArgs {
     ///
     /// Resolution order: `--root-token-file` (if set, must not be combined
     /// with `--root-token`) > `--root-token` (CLI) > `OPENBAO_ROOT_TOKEN`
-    /// env > interactive prompt.
+    /// env (or `BAO_TOKEN`/`VAULT_TOKEN` when unset) > interactive prompt.
     #[arg(long)]
     pub(crate) root_token: Option<String>,
 
@@ -423,6 +610,35 @@ pub(crate) struct DbTimeoutArgs {
     pub(crate) timeout_secs: u64,
 }
 
+/// Per-probe overrides for the DB connectivity check. Each falls back to
+/// `--db-timeout-secs` when omitted.
+#[derive(Args, Debug, Clone, Default)]
+pub(crate) struct DbProbeTimeoutArgs {
+    /// TCP reachability probe timeout in seconds (default: `--db-timeout-secs`)
+    #[arg(long = "db-tcp-timeout", value_name = "SECS")]
+    pub(crate) tcp_timeout_secs: Option<u64>,
+
+    /// Authentication probe timeout in seconds (default: `--db-timeout-secs`)
+    #[arg(long = "db-auth-timeout", value_name = "SECS")]
+    pub(crate) auth_timeout_secs: Option<u64>,
+}
+
+impl DbProbeTimeoutArgs {
+    /// Returns the TCP probe timeout, falling back to `default_secs`.
+    pub(crate) fn tcp_timeout(&self, default_secs: u64) -> Duration {
+        Duration::from_secs(self.tcp_timeout_secs.unwrap_or(default_secs))
+    }
+
+    /// Returns the authentication probe timeout, falling back to
+    /// `default_secs`.
+    pub(crate) fn auth_timeout(&self, default_secs: u64) -> Duration {
+        Duration::from_secs(self.auth_timeout_secs.unwrap_or(default_secs))
+    }
+}
+
+// Each boolean is an independent opt-in switch (`--yes`, `--show-secrets`,
+// `--dry-run`, `--confirm-restart`); none of them are mutually exclusive.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
 pub(crate) struct RotateArgs {
     #[command(subcommand)]
@@ -441,6 +657,11 @@ pub(crate) struct RotateArgs {
     #[command(flatten)]
     pub(crate) secrets_dir: SecretsDirOverrideArgs,
 
+    /// step-ca `ca.json` override (default: the value in `state.json`,
+    /// else `<secrets-dir>/config/ca.json`)
+    #[arg(long, value_name = "PATH")]
+    pub(crate) ca_json: Option<PathBuf>,
+
     #[command(flatten)]
     pub(crate) runtime_auth: RuntimeAuthArgs,
 
@@ -451,6 +672,25 @@ pub(crate) struct RotateArgs {
     /// Show secrets in plaintext instead of masking them
     #[arg(long)]
     pub(crate) show_secrets: bool,
+
+    /// Print the KV paths, files, `AppRoles`, and services the command
+    /// would change, then exit without contacting `OpenBao`
+    #[arg(long, visible_alias = "plan", global = true)]
+    pub(crate) dry_run: bool,
+
+    /// Wait for restarted services to report ready and fail otherwise
+    #[arg(long, global = true)]
+    pub(crate) confirm_restart: bool,
+
+    /// Seconds `--confirm-restart` waits for a restarted service
+    #[arg(
+        long,
+        global = true,
+        value_name = "SECS",
+        default_value_t = DEFAULT_READINESS_TIMEOUT_SECS,
+        requires = "confirm_restart"
+    )]
+    pub(crate) restart_timeout: u64,
 }
 
 #[derive(Subcommand, Debug)]
@@ -598,6 +838,12 @@ pub(crate) struct RotateDbArgs {
 
     #[command(flatten)]
     pub(crate) timeout: DbTimeoutArgs,
+
+    /// Accept a `PostgreSQL` host port bind inside this private CIDR
+    /// (e.g. `10.0.0.0/24`) for trusted internal networks.  Wildcard and
+    /// public binds are still rejected
+    #[arg(long, value_name = "CIDR")]
+    pub(crate) allow_nonloopback_postgres: Option<String>,
 }
 
 #[derive(Args, Debug)]
@@ -675,6 +921,21 @@ pub(crate) struct RotateAppRoleSecretIdArgs {
     #[arg(long)]
     pub(crate) all_services: bool,
 
+    /// Maximum number of services rotated in parallel with
+    /// `--all-services`.
+    #[arg(long, default_value = "1")]
+    pub(crate) concurrency: NonZeroUsize,
+
+    /// Maximum number of locally delivered services rotated in parallel
+    /// with `--all-services`.
+    ///
+    /// Local targets rewrite credential files that agents on this host
+    /// pick up, so they stay serial by default to keep host-side work
+    /// from contending; remote targets only talk to `OpenBao`. Counted
+    /// within `--concurrency`.
+    #[arg(long, default_value = "1")]
+    pub(crate) local_concurrency: NonZeroUsize,
+
     /// Infra role to rotate the `AppRole` `secret_id` for.
     ///
     /// Targets the `AppRole`s consumed by the long-running `OpenBao`
@@ -728,7 +989,16 @@ pub(crate) enum InfraRoleTarget {
 }
 
 #[derive(Args, Debug)]
-pub(crate) struct RotateTrustSyncArgs {}
+pub(crate) struct RotateTrustSyncArgs {
+    /// Maximum number of per-service trust payloads written in parallel
+    #[arg(long, default_value = "1")]
+    pub(crate) concurrency: NonZeroUsize,
+
+    /// Rewrite every service's trust payload, including services already
+    /// at the current trust generation
+    #[arg(long)]
+    pub(crate) force: bool,
+}
 
 #[derive(Args, Debug)]
 pub(crate) struct RotateForceReissueArgs {
@@ -782,11 +1052,81 @@ pub(crate) struct RotateCaKeyArgs {
     pub(crate) cleanup: bool,
 }
 
+#[derive(Args, Debug)]
+pub(crate) struct InfraDownArgs {
+    #[command(flatten)]
+    pub(crate) compose_file: ComposeFileArgs,
+
+    /// Also remove the stack's named volumes (destroys all bootroot data)
+    #[arg(long)]
+    pub(crate) volumes: bool,
+
+    /// Skip the confirmation prompt for `--volumes`
+    #[arg(long, short = 'y')]
+    pub(crate) yes: bool,
+
+    /// Docker Compose profile to activate (repeatable). When omitted, the
+    /// profiles recorded by the last `infra install`/`infra up` apply
+    #[arg(long = "compose-profile")]
+    pub(crate) compose_profile: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct InfraRestartArgs {
+    #[command(flatten)]
+    pub(crate) compose_file: ComposeFileArgs,
+
+    /// Services to restart (default: openbao, postgres, step-ca,
+    /// bootroot-http01)
+    #[arg(value_delimiter = ',')]
+    pub(crate) services: Vec<String>,
+
+    /// Seconds to wait for each restarted service to report running (and
+    /// healthy, when it has a healthcheck) before failing
+    #[arg(long, default_value_t = DEFAULT_READINESS_TIMEOUT_SECS)]
+    pub(crate) readiness_timeout: u64,
+
+    /// Docker Compose profile to activate (repeatable). When omitted, the
+    /// profiles recorded by the last `infra install`/`infra up` apply
+    #[arg(long = "compose-profile")]
+    pub(crate) compose_profile: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct InfraLogsArgs {
+    #[command(flatten)]
+    pub(crate) compose_file: ComposeFileArgs,
+
+    /// Services to show logs for (default: openbao, postgres, step-ca,
+    /// bootroot-http01)
+    #[arg(value_delimiter = ',')]
+    pub(crate) services: Vec<String>,
+
+    /// Keep streaming new log output
+    #[arg(long, short = 'f')]
+    pub(crate) follow: bool,
+
+    /// Number of lines to show from the end of each service's log
+    #[arg(long, value_name = "N")]
+    pub(crate) tail: Option<u64>,
+
+    /// Docker Compose profile to activate (repeatable). When omitted, the
+    /// profiles recorded by the last `infra install`/`infra up` apply
+    #[arg(long = "compose-profile")]
+    pub(crate) compose_profile: Vec<String>,
+}
+
 #[derive(Args, Debug)]
 pub(crate) struct InfraUpArgs {
     #[command(flatten)]
     pub(crate) compose_file: ComposeFileArgs,
 
+    /// Accept a `PostgreSQL` host port bind inside this private CIDR
+    /// (e.g. `10.0.0.0/24`) for trusted internal networks.  Wildcard and
+    /// public binds are still rejected
+    #[arg(long, value_name = "CIDR")]
+    pub(crate) allow_nonloopback_postgres: Option<String>,
+
     /// Comma-separated list of services to start
     // Keep "bootroot-http01" in sync with RESPONDER_SERVICE_NAME.
     #[arg(
@@ -804,13 +1144,47 @@ pub(crate) struct InfraUpArgs {
     #[arg(long, default_value = "always")]
     pub(crate) restart_policy: String,
 
+    /// Extra `docker compose pull` attempts after a failed pull, waiting
+    /// 2s, 4s, 8s, ... in between (0 disables retries)
+    #[arg(long, default_value_t = DEFAULT_PULL_RETRIES)]
+    pub(crate) pull_retries: u32,
+
+    /// Seconds to wait for each service to report running (and healthy,
+    /// when it has a healthcheck) before failing
+    #[arg(
+        long,
+        visible_alias = "wait-timeout",
+        default_value_t = DEFAULT_READINESS_TIMEOUT_SECS
+    )]
+    pub(crate) readiness_timeout: u64,
+
+    /// Per-service readiness timeout as `<service>=<seconds>` (repeatable).
+    /// Overrides `--readiness-timeout` for that service only
+    #[arg(long = "service-timeout", value_name = "SERVICE=SECS")]
+    pub(crate) service_timeout: Vec<String>,
+
+    /// Delay between readiness checks while waiting (e.g. "500ms", "2s")
+    #[arg(
+        long,
+        value_name = "DURATION",
+        default_value = "500ms",
+        value_parser = parse_poll_interval
+    )]
+    pub(crate) poll_interval: Duration,
+
     /// `OpenBao` API URL for auto-unseal (dev/test only)
-    #[arg(long, default_value = DEFAULT_OPENBAO_URL)]
+    #[arg(long, env = OPENBAO_URL_ENV, default_value = DEFAULT_OPENBAO_URL)]
     pub(crate) openbao_url: String,
 
     /// Auto-unseal `OpenBao` from file (dev/test only)
     #[arg(long, env = "OPENBAO_UNSEAL_FILE")]
     pub(crate) openbao_unseal_from_file: Option<PathBuf>,
+
+    /// Docker Compose profile to activate (repeatable). Passed as
+    /// `--profile <name>` to `pull`, `up`, and `ps`. When omitted, the
+    /// profiles recorded by the last `infra install`/`infra up` apply
+    #[arg(long = "compose-profile")]
+    pub(crate) compose_profile: Vec<String>,
 }
 
 // Each boolean flag is a deliberate, independent opt-in confirmation
@@ -821,6 +1195,12 @@ pub(crate) struct InfraInstallArgs {
     #[command(flatten)]
     pub(crate) compose_file: ComposeFileArgs,
 
+    /// Accept a `PostgreSQL` host port bind inside this private CIDR
+    /// (e.g. `10.0.0.0/24`) for trusted internal networks.  Wildcard and
+    /// public binds are still rejected
+    #[arg(long, value_name = "CIDR")]
+    pub(crate) allow_nonloopback_postgres: Option<String>,
+
     /// Comma-separated list of services to start
     #[arg(
         long,
@@ -837,8 +1217,18 @@ pub(crate) struct InfraInstallArgs {
     #[arg(long, default_value = "always")]
     pub(crate) restart_policy: String,
 
+    /// Seconds to wait for each service to report running (and healthy,
+    /// when it has a healthcheck) before failing
+    #[arg(long, default_value_t = DEFAULT_READINESS_TIMEOUT_SECS)]
+    pub(crate) readiness_timeout: u64,
+
+    /// Per-service readiness timeout as `<service>=<seconds>` (repeatable).
+    /// Overrides `--readiness-timeout` for that service only
+    #[arg(long = "service-timeout", value_name = "SERVICE=SECS")]
+    pub(crate) service_timeout: Vec<String>,
+
     /// `OpenBao` API URL
-    #[arg(long, default_value = DEFAULT_OPENBAO_URL)]
+    #[arg(long, env = OPENBAO_URL_ENV, default_value = DEFAULT_OPENBAO_URL)]
     pub(crate) openbao_url: String,
 
     /// Bind `OpenBao` to a non-loopback address (requires TLS).
@@ -921,6 +1311,12 @@ pub(crate) struct InfraInstallArgs {
     /// source tree or network.
     #[arg(long = "no-build")]
     pub(crate) no_build: bool,
+
+    /// Docker Compose profile to activate (repeatable). Passed as
+    /// `--profile <name>` to `pull`, `up`, and `ps`, and recorded in
+    /// `state.json` so `status` and `rotate` target the same set
+    #[arg(long = "compose-profile")]
+    pub(crate) compose_profile: Vec<String>,
 }
 
 #[derive(Args, Debug)]
@@ -941,6 +1337,13 @@ pub(crate) struct CleanArgs {
     pub(crate) openbao_only: bool,
 }
 
+#[cfg(feature = "self-test")]
+#[derive(Args, Debug)]
+pub(crate) struct SelfTestArgs {
+    #[command(flatten)]
+    pub(crate) compose_file: ComposeFileArgs,
+}
+
 #[derive(Args, Debug)]
 pub(crate) struct OpenbaoSaveUnsealKeysArgs {
     /// Secrets directory
@@ -1021,11 +1424,11 @@ pub(crate) enum InitSkipPhase {
 // Each boolean flag corresponds to an explicit, per-prompt
 // non-interactive opt-out on the `init` surface (`--no-eab`,
 // `--save-unseal-keys`, `--no-save-unseal-keys`, plus the internal
-// `reinit_mode`).  Per the project pattern (#588 §3b), `init` uses
-// per-prompt explicit flags rather than a global `--yes`, so refactoring
-// them into a single state enum would obscure the clap-level mutual
-// exclusivity (`conflicts_with`) and `requires = "summary_json"`
-// constraints that this surface relies on.
+// `reinit_mode`).  Per the project pattern (#588 §3b), prompts that
+// choose a value keep their own explicit flags; `--yes` only confirms
+// destructive actions.  Refactoring them into a single state enum would
+// obscure the clap-level mutual exclusivity (`conflicts_with`) and
+// `requires = "summary_json"` constraints that this surface relies on.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
 pub(crate) struct InitArgs {
@@ -1038,6 +1441,12 @@ pub(crate) struct InitArgs {
     #[command(flatten)]
     pub(crate) compose: ComposeFileArgs,
 
+    /// Accept a `PostgreSQL` host port bind inside this private CIDR
+    /// (e.g. `10.0.0.0/24`) for trusted internal networks.  Wildcard and
+    /// public binds are still rejected
+    #[arg(long, value_name = "CIDR")]
+    pub(crate) allow_nonloopback_postgres: Option<String>,
+
     /// Enable optional features
     #[arg(long, value_enum, value_delimiter = ',')]
     pub(crate) enable: Vec<InitFeature>,
@@ -1046,10 +1455,86 @@ pub(crate) struct InitArgs {
     #[arg(long, value_enum, value_delimiter = ',')]
     pub(crate) skip: Vec<InitSkipPhase>,
 
+    /// Skip step-ca bootstrap for deployments that use an external
+    /// ACME CA.  No `password.txt`, `ca.json`, step-ca templates, step-ca
+    /// `AppRole`/policy, or step-ca KV secrets are created; only the
+    /// responder and agent pieces are configured.
+    #[arg(long = "skip-step-ca")]
+    pub(crate) skip_step_ca: bool,
+
+    /// step-ca `ca.json` whose DB DSN and certificate duration init
+    /// manages (default: `<secrets-dir>/config/ca.json`).  Must be under
+    /// `--secrets-dir`; recorded in `state.json` for `rotate`
+    #[arg(long, value_name = "PATH")]
+    pub(crate) ca_json: Option<PathBuf>,
+
+    /// Skip HTTP-01 responder setup for DNS-01-only deployments.  No
+    /// responder config, compose override, responder `AppRole`/policy, or
+    /// `responder/hmac` KV secret is created, and the responder
+    /// reachability check is skipped.
+    #[arg(long = "skip-responder")]
+    pub(crate) skip_responder: bool,
+
+    /// Continue an interrupted init.  Policies, `AppRoles`, and KV secrets
+    /// that already exist are kept instead of being rewritten, the
+    /// existing `password.txt` and responder HMAC are reused, and no
+    /// overwrite prompts are shown; only missing pieces are created.
+    #[arg(long)]
+    pub(crate) resume: bool,
+
+    /// Run only the `OpenBao` phase: unseal, KV mount, policies,
+    /// `AppRoles`, and KV secrets, then write `state.json`.  step-ca init,
+    /// template and responder files, `OpenBao` Agents, and the CA trust
+    /// payload are left for a follow-up `init --resume`
+    #[arg(long = "openbao-only")]
+    pub(crate) openbao_only: bool,
+
+    /// Confirm init's destructive actions without prompting: overwriting
+    /// an existing `password.txt`, `ca.json`, or `state.json`, database
+    /// provisioning (`--enable db-provision`), and unsealing from
+    /// `--openbao-unseal-from-file`.  Values are never assumed: a missing
+    /// root token, secret, EAB answer, or unseal-key choice is still read
+    /// from stdin, so supply those as flags for unattended runs
+    #[arg(short = 'y', long)]
+    pub(crate) yes: bool,
+
+    /// Print the `OpenBao` Agent `agent.hcl` and template snippets for
+    /// step-ca, the responder, and bootroot-agent for the current KV
+    /// mount and `OpenBao` address, then exit without initializing
+    #[arg(long)]
+    pub(crate) print_agent_config_template: bool,
+
+    /// Print the init plan (overwrites, DB provisioning, new KV paths,
+    /// step-ca initialization) as JSON, then exit without changing anything
+    #[arg(
+        long = "dump-plan-json",
+        conflicts_with = "print_agent_config_template"
+    )]
+    pub(crate) dump_plan_json: bool,
+
+    /// Print the init plan plus every `OpenBao` write, file write, and
+    /// container command init would perform, then exit without running
+    /// any of them
+    #[arg(
+        long = "dry-run",
+        conflicts_with_all = ["print_agent_config_template", "dump_plan_json"]
+    )]
+    pub(crate) dry_run: bool,
+
     /// Path to init summary JSON file
     #[arg(long = "summary-json")]
     pub(crate) summary_json: Option<PathBuf>,
 
+    /// Write the unmasked secrets from the init summary to this file
+    /// (mode `0600`) instead of stdout, which stays masked
+    #[arg(long, value_name = "PATH")]
+    pub(crate) reveal_to_file: Option<PathBuf>,
+
+    /// Append a JSON line (timestamp, step, status) for each significant
+    /// init step to this file; secret values are never logged
+    #[arg(long, value_name = "PATH")]
+    pub(crate) audit_log: Option<PathBuf>,
+
     #[command(flatten)]
     pub(crate) root_token: RootTokenArgs,
 
@@ -1061,6 +1546,11 @@ pub(crate) struct InitArgs {
     #[arg(long, env = "OPENBAO_UNSEAL_FILE")]
     pub(crate) openbao_unseal_from_file: Option<PathBuf>,
 
+    /// `OpenBao` uses an auto-unseal seal (transit/KMS): initialize it
+    /// with recovery keys and skip the unseal step
+    #[arg(long, conflicts_with_all = ["unseal_key", "openbao_unseal_from_file"])]
+    pub(crate) auto_unseal: bool,
+
     /// Role-level `secret_id` TTL for `AppRole` roles created during init.
     /// Set this to at least 2× your planned rotation interval so that a
     /// missed or delayed run does not expire credentials
@@ -1080,6 +1570,17 @@ pub(crate) struct InitArgs {
     #[arg(long = "rotate-bound-cidrs")]
     pub(crate) rotate_bound_cidrs: Vec<String>,
 
+    /// CIDR ranges the service `AppRole` created by init
+    /// (`bootroot-agent-role`) accepts logins and token use from
+    /// (repeatable, e.g. `--approle-bind-cidr 10.0.0.0/24`).
+    ///
+    /// Sent as the role-level `secret_id_bound_cidrs` and
+    /// `token_bound_cidrs`, so a leaked `role_id`/`secret_id` pair is
+    /// rejected from any other network. The in-compose `OpenBao` Agent
+    /// and rotate roles are never bound. Omitted = no binding.
+    #[arg(long = "approle-bind-cidr")]
+    pub(crate) approle_bind_cidr: Vec<String>,
+
     /// step-ca password (password.txt)
     #[arg(long, env = "STEPCA_PASSWORD")]
     pub(crate) stepca_password: Option<String>,
@@ -1106,6 +1607,9 @@ pub(crate) struct InitArgs {
     #[command(flatten)]
     pub(crate) db_timeout: DbTimeoutArgs,
 
+    #[command(flatten)]
+    pub(crate) db_probe_timeout: DbProbeTimeoutArgs,
+
     /// HTTP-01 responder HMAC secret
     #[arg(long, env = "HTTP01_HMAC")]
     pub(crate) http_hmac: Option<String>,
@@ -1118,6 +1622,15 @@ pub(crate) struct InitArgs {
     #[arg(long, default_value_t = 5)]
     pub(crate) responder_timeout_secs: u64,
 
+    /// Time to wait for the step-ca/responder `OpenBao` Agents to render
+    /// their files after they start (seconds)
+    #[arg(
+        long,
+        env = "BOOTROOT_AGENT_RENDER_TIMEOUT_SECS",
+        default_value_t = DEFAULT_AGENT_RENDER_TIMEOUT_SECS
+    )]
+    pub(crate) agent_render_timeout_secs: u64,
+
     /// step-ca ACME provisioner name
     #[arg(long, default_value = DEFAULT_STEPCA_PROVISIONER)]
     pub(crate) stepca_provisioner: String,
@@ -1130,6 +1643,29 @@ pub(crate) struct InitArgs {
     #[arg(long, default_value = DEFAULT_CERT_DURATION)]
     pub(crate) cert_duration: String,
 
+    /// Image `step ca init` runs in. Pin a private-registry copy for
+    /// air-gapped hosts
+    #[arg(long, default_value = DEFAULT_STEPCA_IMAGE)]
+    pub(crate) stepca_image: String,
+
+    /// Image of the step-ca/responder `OpenBao` Agent containers written
+    /// to the agent compose override
+    #[arg(long, default_value = DEFAULT_OPENBAO_AGENT_IMAGE)]
+    pub(crate) openbao_agent_image: String,
+
+    /// Path the secrets directory is mounted at inside the step-ca/
+    /// responder `OpenBao` Agent containers; the generated `agent.hcl`
+    /// token sink, `AppRole` credential, and template paths live under it
+    #[arg(long, default_value = DEFAULT_OPENBAO_AGENT_MOUNT_ROOT)]
+    pub(crate) openbao_agent_mount_root: String,
+
+    /// Run a consumer under the step-ca or responder `OpenBao` Agent as
+    /// `AGENT=CMD` (`stepca` or `responder`; repeatable).  The agent's
+    /// `exec` block restarts it whenever a rendered secret changes.
+    /// Omitted = template-only agents
+    #[arg(long, value_name = "AGENT=CMD")]
+    pub(crate) openbao_agent_exec: Vec<String>,
+
     /// ACME EAB key ID (optional)
     #[arg(long, env = "EAB_KID")]
     pub(crate) eab_kid: Option<String>,
@@ -1205,6 +1741,12 @@ pub(crate) struct ReinitArgs {
     #[command(flatten)]
     pub(crate) compose: ComposeFileArgs,
 
+    /// Accept a `PostgreSQL` host port bind inside this private CIDR
+    /// (e.g. `10.0.0.0/24`) for trusted internal networks.  Wildcard and
+    /// public binds are still rejected
+    #[arg(long, value_name = "CIDR")]
+    pub(crate) allow_nonloopback_postgres: Option<String>,
+
     /// Skip confirmation prompts.  With `--yes` the entire flow is
     /// non-interactive: destructive actions proceed without prompting
     /// and newly-generated `OpenBao` unseal keys are written
@@ -1249,18 +1791,113 @@ pub(crate) struct ReinitArgs {
     pub(crate) no_eab: bool,
 }
 
+// Each boolean is an independent CLI switch; the mode flags
+// (`--delete-source`, `--dual-write`, `--finalize`) are kept apart by
+// clap `conflicts_with` rules rather than an enum.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Args, Debug)]
+pub(crate) struct MigrateArgs {
+    /// `OpenBao` API URL override (defaults to the value in `state.json`)
+    #[arg(long)]
+    pub(crate) openbao_url: Option<String>,
+
+    /// Source KV v2 mount (defaults to the mount in `state.json`)
+    #[arg(long)]
+    pub(crate) from_mount: Option<String>,
+
+    /// Destination KV v2 mount, enabled if it does not exist yet
+    #[arg(long, required_unless_present = "finalize")]
+    pub(crate) to_mount: Option<String>,
+
+    /// Source path prefix
+    #[arg(long, default_value = DEFAULT_KV_PATH_PREFIX)]
+    pub(crate) from_prefix: String,
+
+    /// Destination path prefix
+    #[arg(long, default_value = DEFAULT_KV_PATH_PREFIX)]
+    pub(crate) to_prefix: String,
+
+    /// Delete the source secrets once every copy is verified
+    #[arg(long, conflicts_with = "dual_write")]
+    pub(crate) delete_source: bool,
+
+    /// Keep the source and have `init`/`rotate` write every secret to
+    /// both layouts until `--finalize`
+    #[arg(long)]
+    pub(crate) dual_write: bool,
+
+    /// End a dual-write transition by deleting the old layout's secrets
+    #[arg(
+        long,
+        conflicts_with_all = ["from_mount", "to_mount", "delete_source", "dual_write"]
+    )]
+    pub(crate) finalize: bool,
+
+    /// Skip the confirmation prompt before deleting secrets
+    #[arg(long, short = 'y')]
+    pub(crate) yes: bool,
+
+    #[command(flatten)]
+    pub(crate) root_token: RootTokenArgs,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct AuditSecretsArgs {
+    /// Directory inside the git work tree to audit
+    #[arg(long, default_value = ".")]
+    pub(crate) repo_dir: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct SupportBundleArgs {
+    /// ZIP file to write the bundle to (parent directory created if missing)
+    #[arg(long)]
+    pub(crate) out: PathBuf,
+
+    #[command(flatten)]
+    pub(crate) compose_file: ComposeFileArgs,
+
+    /// Number of log lines to keep per compose service
+    #[arg(long, default_value_t = 200)]
+    pub(crate) log_tail: u32,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct VersionArgs {
+    #[command(flatten)]
+    pub(crate) compose_file: ComposeFileArgs,
+}
+
 #[derive(Args, Debug)]
 pub(crate) struct StatusArgs {
     #[command(flatten)]
     pub(crate) compose: ComposeFileArgs,
 
+    /// Defaults to the values in `state.json`; without a state file the
+    /// URL is read from `OPENBAO_URL` or one of its aliases.
     #[command(flatten)]
-    pub(crate) openbao: OpenBaoArgs,
+    pub(crate) openbao: OpenBaoOverrideArgs,
 
     #[command(flatten)]
     pub(crate) root_token: RootTokenArgs,
+
+    /// Output format (`json` emits a versioned report for scripts)
+    #[arg(long, value_enum, default_value_t = StatusOutputFormat::Text)]
+    pub(crate) output: StatusOutputFormat,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StatusOutputFormat {
+    /// Human-readable summary
+    Text,
+    /// Machine-readable `StatusReport` JSON
+    Json,
 }
 
+// Each boolean is an independent opt-in switch (`--dry-run`,
+// `--print-only`, `--no-wrap`, `--quiet`, `--show-secrets`); clap keeps
+// the conflicting ones apart with `conflicts_with`.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
 pub(crate) struct ServiceAddArgs {
     /// Service name identifier
@@ -1322,10 +1959,11 @@ pub(crate) struct ServiceAddArgs {
     pub(crate) agent_email: Option<String>,
 
     /// ACME directory URL persisted into the rendered `agent.toml`
-    /// baseline (`server` field).  Defaults to the compose-topology
-    /// step-ca URL when omitted; override on non-default deployments
-    /// so KV-driven re-renders do not revert operator edits.
-    #[arg(long)]
+    /// baseline (`server` field).  Local-file services default to the
+    /// URL `bootroot init` recorded in `state.json`, remote services to
+    /// the compose-topology step-ca URL; override on non-default
+    /// deployments so KV-driven re-renders do not revert operator edits.
+    #[arg(long, visible_alias = "ca-url")]
     pub(crate) agent_server: Option<String>,
 
     /// HTTP-01 responder admin URL persisted into the rendered
@@ -1387,6 +2025,11 @@ pub(crate) struct ServiceAddArgs {
     #[arg(long)]
     pub(crate) rn_cidrs: Vec<String>,
 
+    /// CIDR ranges the service `AppRole` accepts logins and token use from
+    /// (repeatable, e.g. `--approle-bind-cidr 10.0.0.0/24`)
+    #[arg(long = "approle-bind-cidr")]
+    pub(crate) approle_bind_cidr: Vec<String>,
+
     /// Numeric gid or group name that should own the issued cert/key
     /// files and their parent directories.
     ///
@@ -1401,6 +2044,37 @@ pub(crate) struct ServiceAddArgs {
     /// issue #593 for the cross-host NSS rationale.
     #[arg(long, value_name = "GID-OR-NAME")]
     pub(crate) cert_group: Option<String>,
+
+    /// Output format (`json` emits a `ServiceAddResult` for scripts)
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = ServiceAddOutputFormat::Text,
+        conflicts_with_all = ["dry_run", "print_only"]
+    )]
+    pub(crate) output: ServiceAddOutputFormat,
+
+    /// Omit the next-steps snippets and consumer-reload hint from the
+    /// text summary
+    #[arg(long, short = 'q')]
+    pub(crate) quiet: bool,
+
+    /// Show the `role_id` unmasked in `--output json`
+    #[arg(long)]
+    pub(crate) show_secrets: bool,
+
+    /// Write the unmasked JSON result to this file (mode `0600`);
+    /// stdout stays masked
+    #[arg(long, value_name = "PATH")]
+    pub(crate) reveal_to_file: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ServiceAddOutputFormat {
+    /// Human-readable summary and next steps
+    Text,
+    /// Machine-readable `ServiceAddResult` JSON
+    Json,
 }
 
 #[derive(Args, Debug)]
@@ -1408,6 +2082,34 @@ pub(crate) struct ServiceInfoArgs {
     /// Service name identifier
     #[arg(long, required = true)]
     pub(crate) service_name: String,
+
+    /// Output format (`json` emits the `state.json` entry for scripts)
+    #[arg(long, value_enum, default_value_t = ServiceInfoOutputFormat::Text)]
+    pub(crate) output: ServiceInfoOutputFormat,
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct ServiceListArgs {
+    /// Output format (`json` emits an array of service summaries for
+    /// scripts)
+    #[arg(long, value_enum, default_value_t = ServiceListOutputFormat::Text)]
+    pub(crate) output: ServiceListOutputFormat,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ServiceListOutputFormat {
+    /// Human-readable list
+    Text,
+    /// Machine-readable array of service summaries
+    Json,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ServiceInfoOutputFormat {
+    /// Human-readable summary
+    Text,
+    /// Machine-readable service entry JSON with the `role_id` masked
+    Json,
 }
 
 #[derive(Args, Debug)]
@@ -1559,12 +2261,25 @@ pub(crate) struct VerifyArgs {
     #[command(flatten)]
     pub(crate) db_timeout: DbTimeoutArgs,
 
+    #[command(flatten)]
+    pub(crate) db_probe_timeout: DbProbeTimeoutArgs,
+
     /// Compose file whose sibling `.env` provides `POSTGRES_HOST_PORT` for
     /// host-side DSN translation when `--db-check` is set.
     #[command(flatten)]
     pub(crate) compose_file: ComposeFileArgs,
 }
 
+/// Parses `--poll-interval`, rejecting a zero delay that would spin on
+/// `docker inspect`.
+fn parse_poll_interval(value: &str) -> Result<Duration, String> {
+    let interval = humantime::parse_duration(value.trim()).map_err(|err| err.to_string())?;
+    if interval.is_zero() {
+        return Err("poll interval must be greater than zero".to_string());
+    }
+    Ok(interval)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1580,6 +2295,111 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_infra_up_wait_options() {
+        let cli = Cli::parse_from(["bootroot", "infra", "up"]);
+        let CliCommand::Infra(InfraCommand::Up(args)) = cli.command else {
+            panic!("expected infra up");
+        };
+        assert_eq!(args.readiness_timeout, DEFAULT_READINESS_TIMEOUT_SECS);
+        assert_eq!(args.poll_interval, Duration::from_millis(500));
+
+        let cli = Cli::parse_from([
+            "bootroot",
+            "infra",
+            "up",
+            "--wait-timeout",
+            "120",
+            "--poll-interval",
+            "2s",
+        ]);
+        let CliCommand::Infra(InfraCommand::Up(args)) = cli.command else {
+            panic!("expected infra up");
+        };
+        assert_eq!(args.readiness_timeout, 120);
+        assert_eq!(args.poll_interval, Duration::from_secs(2));
+
+        for invalid in ["0s", "soon"] {
+            assert!(
+                Cli::try_parse_from(["bootroot", "infra", "up", "--poll-interval", invalid])
+                    .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_status_output_format() {
+        let cli = Cli::parse_from(["bootroot", "status"]);
+        match cli.command {
+            CliCommand::Status(args) => assert_eq!(args.output, StatusOutputFormat::Text),
+            _ => panic!("expected status"),
+        }
+
+        let cli = Cli::parse_from(["bootroot", "status", "--output", "json"]);
+        match cli.command {
+            CliCommand::Status(args) => assert_eq!(args.output, StatusOutputFormat::Json),
+            _ => panic!("expected status"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_service_info_output_format() {
+        let cli = Cli::parse_from([
+            "bootroot",
+            "service",
+            "info",
+            "--service-name",
+            "edge-proxy",
+            "--output",
+            "json",
+        ]);
+        match cli.command {
+            CliCommand::Service(ServiceCommand::Info(args)) => {
+                assert_eq!(args.output, ServiceInfoOutputFormat::Json);
+            }
+            _ => panic!("expected service info"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_db_probe_timeouts() {
+        let cli = Cli::parse_from(["bootroot", "init", "--db-timeout-secs", "7"]);
+        let CliCommand::Init(args) = cli.command else {
+            panic!("expected init");
+        };
+        let secs = args.db_timeout.timeout_secs;
+        assert_eq!(
+            args.db_probe_timeout.tcp_timeout(secs),
+            Duration::from_secs(7)
+        );
+        assert_eq!(
+            args.db_probe_timeout.auth_timeout(secs),
+            Duration::from_secs(7)
+        );
+
+        let cli = Cli::parse_from([
+            "bootroot",
+            "verify",
+            "--db-check",
+            "--db-tcp-timeout",
+            "1",
+            "--db-auth-timeout",
+            "30",
+        ]);
+        let CliCommand::Verify(args) = cli.command else {
+            panic!("expected verify");
+        };
+        let secs = args.db_timeout.timeout_secs;
+        assert_eq!(
+            args.db_probe_timeout.tcp_timeout(secs),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            args.db_probe_timeout.auth_timeout(secs),
+            Duration::from_secs(30)
+        );
+    }
+
     #[test]
     fn test_cli_parses_rotate_stepca() {
         let cli = Cli::parse_from(["bootroot", "rotate", "stepca-password"]);
@@ -1654,6 +2474,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_rotate_dry_run_and_plan_alias() {
+        for flag in ["--dry-run", "--plan"] {
+            let cli = Cli::parse_from(["bootroot", "rotate", "eab-clear", flag]);
+            match cli.command {
+                CliCommand::Rotate(args) => assert!(args.dry_run),
+                _ => panic!("expected Rotate command"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_rotate_fleet_concurrency() {
+        let cli = Cli::parse_from([
+            "bootroot",
+            "rotate",
+            "approle-secret-id",
+            "--all-services",
+            "--concurrency",
+            "4",
+        ]);
+        match cli.command {
+            CliCommand::Rotate(args) => match args.command {
+                RotateCommand::AppRoleSecretId(approle) => {
+                    assert_eq!(approle.concurrency.get(), 4);
+                    assert_eq!(approle.local_concurrency.get(), 1);
+                }
+                _ => panic!("expected AppRoleSecretId subcommand"),
+            },
+            _ => panic!("expected Rotate command"),
+        }
+        let cli = Cli::parse_from(["bootroot", "rotate", "trust-sync", "--concurrency", "8"]);
+        match cli.command {
+            CliCommand::Rotate(args) => match args.command {
+                RotateCommand::TrustSync(trust) => assert_eq!(trust.concurrency.get(), 8),
+                _ => panic!("expected TrustSync subcommand"),
+            },
+            _ => panic!("expected Rotate command"),
+        }
+        assert!(
+            Cli::try_parse_from(["bootroot", "rotate", "trust-sync", "--concurrency", "0"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_cli_parses_rotate_approle_secret_id_all_services() {
         let cli = Cli::parse_from(["bootroot", "rotate", "approle-secret-id", "--all-services"]);
@@ -1846,6 +2711,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_init_approle_bind_cidr() {
+        let cli = Cli::parse_from([
+            "bootroot",
+            "init",
+            "--approle-bind-cidr",
+            "10.0.0.0/24",
+            "--approle-bind-cidr",
+            "fd00::/64",
+        ]);
+        match cli.command {
+            CliCommand::Init(args) => {
+                assert_eq!(args.approle_bind_cidr, vec!["10.0.0.0/24", "fd00::/64"]);
+            }
+            _ => panic!("expected Init command"),
+        }
+    }
+
     #[test]
     fn test_cli_init_rotate_bound_cidrs_empty_by_default() {
         let cli = Cli::parse_from(["bootroot", "init"]);
@@ -1958,6 +2841,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_service_add_output_json() {
+        let cli = Cli::parse_from(["bootroot", "service", "add", "--service-name", "edge-proxy"]);
+        match cli.command {
+            CliCommand::Service(ServiceCommand::Add(args)) => {
+                assert_eq!(args.output, ServiceAddOutputFormat::Text);
+                assert!(!args.quiet);
+                assert!(!args.show_secrets);
+                assert!(args.reveal_to_file.is_none());
+            }
+            _ => panic!("expected service add"),
+        }
+
+        let cli = Cli::parse_from([
+            "bootroot",
+            "service",
+            "add",
+            "--service-name",
+            "edge-proxy",
+            "--output",
+            "json",
+            "-q",
+            "--show-secrets",
+            "--reveal-to-file",
+            "result.json",
+        ]);
+        match cli.command {
+            CliCommand::Service(ServiceCommand::Add(args)) => {
+                assert_eq!(args.output, ServiceAddOutputFormat::Json);
+                assert!(args.quiet);
+                assert!(args.show_secrets);
+                assert_eq!(args.reveal_to_file, Some(PathBuf::from("result.json")));
+            }
+            _ => panic!("expected service add"),
+        }
+    }
+
+    #[test]
+    fn test_cli_rejects_service_add_output_json_with_dry_run() {
+        let result = Cli::try_parse_from([
+            "bootroot",
+            "service",
+            "add",
+            "--output",
+            "json",
+            "--dry-run",
+        ]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_cli_parses_init_cert_duration_default() {
         let cli = Cli::parse_from(["bootroot", "init"]);
@@ -1980,6 +2913,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_init_image_overrides() {
+        let cli = Cli::parse_from([
+            "bootroot",
+            "init",
+            "--stepca-image",
+            "registry.internal/smallstep/step-ca:0.30.2",
+            "--openbao-agent-image",
+            "registry.internal/openbao/openbao:2.5.5",
+        ]);
+        match cli.command {
+            CliCommand::Init(args) => {
+                assert_eq!(
+                    args.stepca_image,
+                    "registry.internal/smallstep/step-ca:0.30.2"
+                );
+                assert_eq!(
+                    args.openbao_agent_image,
+                    "registry.internal/openbao/openbao:2.5.5"
+                );
+            }
+            _ => panic!("expected init"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_init_image_defaults() {
+        let cli = Cli::parse_from(["bootroot", "init"]);
+        match cli.command {
+            CliCommand::Init(args) => {
+                assert_eq!(args.stepca_image, DEFAULT_STEPCA_IMAGE);
+                assert_eq!(args.openbao_agent_image, DEFAULT_OPENBAO_AGENT_IMAGE);
+                assert_eq!(
+                    args.openbao_agent_mount_root,
+                    DEFAULT_OPENBAO_AGENT_MOUNT_ROOT
+                );
+                assert!(args.openbao_agent_exec.is_empty());
+            }
+            _ => panic!("expected init"),
+        }
+    }
+
     #[test]
     fn test_cli_parses_ca_update() {
         let cli = Cli::parse_from(["bootroot", "ca", "update", "--cert-duration", "48h"]);
@@ -1992,6 +2967,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_trust_export() {
+        let cli = Cli::parse_from(["bootroot", "trust", "export"]);
+        match cli.command {
+            CliCommand::Trust(TrustCommand::Export(args)) => {
+                assert_eq!(args.format, TrustExportFormat::Pem);
+                assert!(args.out.is_none());
+            }
+            _ => panic!("expected trust export"),
+        }
+
+        let cli = Cli::parse_from([
+            "bootroot",
+            "trust",
+            "export",
+            "--format",
+            "p7b",
+            "--out",
+            "ca-bundle.p7b",
+        ]);
+        match cli.command {
+            CliCommand::Trust(TrustCommand::Export(args)) => {
+                assert_eq!(args.format, TrustExportFormat::P7b);
+                assert_eq!(args.out, Some(PathBuf::from("ca-bundle.p7b")));
+            }
+            _ => panic!("expected trust export"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_trust_show() {
+        let cli = Cli::parse_from(["bootroot", "trust", "show", "--root-token", "root"]);
+        match cli.command {
+            CliCommand::Trust(TrustCommand::Show(args)) => {
+                assert_eq!(args.root_token.root_token.as_deref(), Some("root"));
+                assert!(args.openbao_url.is_none());
+            }
+            _ => panic!("expected trust show"),
+        }
+    }
+
     #[test]
     fn test_cli_parses_ca_restart() {
         let cli = Cli::parse_from(["bootroot", "ca", "restart"]);
@@ -2023,6 +3039,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_global_container_runtime() {
+        let cli = Cli::parse_from(["bootroot", "status", "--container-runtime", "podman"]);
+        assert_eq!(cli.container_runtime, ContainerRuntime::Podman);
+        let cli = Cli::parse_from(["bootroot", "status"]);
+        assert_eq!(cli.container_runtime, ContainerRuntime::Docker);
+    }
+
+    #[test]
+    fn test_cli_parses_init_dump_plan_json() {
+        let cli = Cli::parse_from(["bootroot", "init", "--dump-plan-json"]);
+        match cli.command {
+            CliCommand::Init(args) => assert!(args.dump_plan_json),
+            _ => panic!("expected init"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_init_dry_run() {
+        let cli = Cli::parse_from(["bootroot", "init", "--dry-run"]);
+        match cli.command {
+            CliCommand::Init(args) => assert!(args.dry_run),
+            _ => panic!("expected init"),
+        }
+    }
+
+    #[test]
+    fn test_cli_rejects_init_dry_run_with_dump_plan_json() {
+        let result = Cli::try_parse_from(["bootroot", "init", "--dry-run", "--dump-plan-json"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_defaulted_openbao_url_flags_read_openbao_url_env() {
+        let command = <Cli as clap::CommandFactory>::command();
+        for path in [
+            ["init"].as_slice(),
+            &["reinit"],
+            &["infra", "up"],
+            &["infra", "install"],
+        ] {
+            let subcommand = path.iter().fold(&command, |command, name| {
+                command.find_subcommand(name).expect("subcommand")
+            });
+            let arg = subcommand
+                .get_arguments()
+                .find(|arg| arg.get_id() == "openbao_url")
+                .expect("--openbao-url");
+            assert_eq!(
+                arg.get_env(),
+                Some(std::ffi::OsStr::new(OPENBAO_URL_ENV)),
+                "{path:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_init_print_agent_config_template() {
+        let cli = Cli::parse_from(["bootroot", "init", "--print-agent-config-template"]);
+        match cli.command {
+            CliCommand::Init(args) => assert!(args.print_agent_config_template),
+            _ => panic!("expected init"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_init_yes() {
+        for flag in ["--yes", "-y"] {
+            let cli = Cli::parse_from(["bootroot", "init", flag]);
+            match cli.command {
+                CliCommand::Init(args) => assert!(args.yes),
+                _ => panic!("expected init"),
+            }
+        }
+        let cli = Cli::parse_from(["bootroot", "init"]);
+        match cli.command {
+            CliCommand::Init(args) => assert!(!args.yes),
+            _ => panic!("expected init"),
+        }
+    }
+
     /// `--save-unseal-keys` parses to the matching flag and leaves
     /// `--no-save-unseal-keys` unset.
     #[test]
@@ -2102,6 +3199,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_infra_down_and_restart() {
+        let cli = Cli::parse_from(["bootroot", "infra", "down", "--volumes", "-y"]);
+        let CliCommand::Infra(InfraCommand::Down(args)) = cli.command else {
+            panic!("expected infra down");
+        };
+        assert!(args.volumes);
+        assert!(args.yes);
+
+        let cli = Cli::parse_from(["bootroot", "infra", "restart", "step-ca,bootroot-http01"]);
+        let CliCommand::Infra(InfraCommand::Restart(args)) = cli.command else {
+            panic!("expected infra restart");
+        };
+        assert_eq!(args.services, ["step-ca", "bootroot-http01"]);
+        assert_eq!(args.readiness_timeout, DEFAULT_READINESS_TIMEOUT_SECS);
+    }
+
+    #[test]
+    fn test_cli_parses_infra_logs() {
+        let cli = Cli::parse_from(["bootroot", "infra", "logs", "step-ca", "-f", "--tail", "50"]);
+        let CliCommand::Infra(InfraCommand::Logs(args)) = cli.command else {
+            panic!("expected infra logs");
+        };
+        assert_eq!(args.services, ["step-ca"]);
+        assert!(args.follow);
+        assert_eq!(args.tail, Some(50));
+    }
+
     #[test]
     fn test_cli_parses_infra_install() {
         let cli = Cli::parse_from([
@@ -2283,6 +3408,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_migrate_with_default_prefixes() {
+        let cli = Cli::parse_from([
+            "bootroot",
+            "migrate",
+            "--to-mount",
+            "bootroot",
+            "--delete-source",
+        ]);
+        match cli.command {
+            CliCommand::Migrate(args) => {
+                assert_eq!(args.from_mount, None);
+                assert_eq!(args.to_mount.as_deref(), Some("bootroot"));
+                assert_eq!(args.from_prefix, "bootroot");
+                assert_eq!(args.to_prefix, "bootroot");
+                assert!(args.delete_source);
+                assert!(!args.yes);
+            }
+            _ => panic!("expected migrate"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_migrate_dual_write_and_finalize() {
+        let cli = Cli::parse_from(["bootroot", "migrate", "--to-mount", "kv2", "--dual-write"]);
+        match cli.command {
+            CliCommand::Migrate(args) => {
+                assert!(args.dual_write);
+                assert!(!args.finalize);
+            }
+            _ => panic!("expected migrate"),
+        }
+        let cli = Cli::parse_from(["bootroot", "migrate", "--finalize", "-y"]);
+        match cli.command {
+            CliCommand::Migrate(args) => {
+                assert!(args.finalize);
+                assert_eq!(args.to_mount, None);
+            }
+            _ => panic!("expected migrate"),
+        }
+        assert!(
+            Cli::try_parse_from([
+                "bootroot",
+                "migrate",
+                "--to-mount",
+                "kv2",
+                "--dual-write",
+                "--delete-source",
+            ])
+            .is_err()
+        );
+    }
+
     #[test]
     fn test_cli_parses_clean_short_yes() {
         let cli = Cli::parse_from(["bootroot", "clean", "-y"]);
@@ -2561,6 +3739,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_service_add_ca_url_alias() {
+        let cli = Cli::parse_from([
+            "bootroot",
+            "service",
+            "add",
+            "--ca-url",
+            "https://ca.internal/acme/acme/directory",
+        ]);
+        match cli.command {
+            CliCommand::Service(ServiceCommand::Add(args)) => {
+                assert_eq!(
+                    args.agent_server.as_deref(),
+                    Some("https://ca.internal/acme/acme/directory")
+                );
+            }
+            _ => panic!("expected service add"),
+        }
+    }
+
     #[test]
     fn test_cli_rejects_no_wrap_with_secret_id_wrap_ttl() {
         let result = Cli::try_parse_from([
@@ -2704,6 +3902,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_service_add_approle_bind_cidr() {
+        let cli = Cli::parse_from([
+            "bootroot",
+            "service",
+            "add",
+            "--approle-bind-cidr",
+            "10.0.0.0/24",
+        ]);
+        match cli.command {
+            CliCommand::Service(ServiceCommand::Add(args)) => {
+                assert_eq!(args.approle_bind_cidr, vec!["10.0.0.0/24"]);
+            }
+            _ => panic!("expected service add"),
+        }
+    }
+
     #[test]
     fn test_cli_parses_service_add_rn_cidrs_empty_by_default() {
         let cli = Cli::parse_from(["bootroot", "service", "add"]);
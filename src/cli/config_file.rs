@@ -0,0 +1,217 @@
+//! `bootroot.toml`: per-directory defaults for frequently repeated flags.
+//!
+//! A value from the file replaces the built-in default of every flag with
+//! the same name, so the precedence is CLI flag > environment variable >
+//! `bootroot.toml` > built-in default. Flags without a built-in default
+//! (for example the `--openbao-url` overrides that fall back to
+//! `state.json`) are left alone.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use clap::{ArgMatches, Command, CommandFactory, FromArgMatches};
+use config::{Config, File, FileFormat};
+use serde::Deserialize;
+
+use super::args::Cli;
+use crate::i18n::Messages;
+
+/// File looked up in the current directory when `--config` is not given.
+pub(crate) const CONFIG_FILE_NAME: &str = "bootroot.toml";
+
+/// Flag defaults read from `bootroot.toml`; each field is named after the
+/// flag it fills.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ConfigFileDefaults {
+    pub(crate) openbao_url: Option<String>,
+    pub(crate) kv_mount: Option<String>,
+    pub(crate) secrets_dir: Option<String>,
+    pub(crate) compose_file: Option<String>,
+}
+
+impl ConfigFileDefaults {
+    /// Reads `path`, which must be a TOML file using only the known keys.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or does not match the
+    /// schema.
+    pub(crate) fn load(path: &Path, messages: &Messages) -> Result<Self> {
+        Config::builder()
+            .add_source(File::from(path).format(FileFormat::Toml))
+            .build()
+            .and_then(Config::try_deserialize)
+            .map_err(|err| {
+                anyhow::anyhow!(
+                    messages
+                        .error_config_file_invalid(&path.display().to_string(), &err.to_string())
+                )
+            })
+    }
+
+    /// Returns `command` with the file's values as the defaults of the
+    /// matching flags, in every subcommand.
+    pub(crate) fn apply(&self, command: Command) -> Command {
+        [
+            ("openbao_url", &self.openbao_url),
+            ("kv_mount", &self.kv_mount),
+            ("secrets_dir", &self.secrets_dir),
+            ("compose_file", &self.compose_file),
+        ]
+        .into_iter()
+        .filter_map(|(id, value)| value.as_deref().map(|value| (id, value)))
+        .fold(command, |command, (id, value)| {
+            with_default(command, id, value)
+        })
+    }
+}
+
+/// Replaces the built-in default of flag `id` in `command` and all of its
+/// subcommands.
+fn with_default(mut command: Command, id: &str, value: &str) -> Command {
+    let has_default = command
+        .get_arguments()
+        .any(|arg| arg.get_id() == id && !arg.get_default_values().is_empty());
+    if has_default {
+        command = command.mut_arg(id, |arg| arg.default_value(value.to_string()));
+    }
+    let subcommands: Vec<String> = command
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    for name in subcommands {
+        command = command.mut_subcommand(name, |sub| with_default(sub, id, value));
+    }
+    command
+}
+
+/// Returns the config file to load: `--config` when given, else
+/// `bootroot.toml` in the current directory when it exists.
+fn config_path(cli: &Cli) -> Option<PathBuf> {
+    cli.config.clone().or_else(|| {
+        let default = PathBuf::from(CONFIG_FILE_NAME);
+        default.is_file().then_some(default)
+    })
+}
+
+/// Parses `args` again with defaults from the config file `cli` points
+/// at, or returns `cli` unchanged when there is none.
+///
+/// Exits like [`clap::Parser::parse`] when the arguments do not parse.
+///
+/// # Errors
+/// Returns an error if the config file cannot be loaded.
+pub(crate) fn reparse_with_config_file(
+    cli: Cli,
+    args: impl IntoIterator<Item = OsString>,
+    messages: &Messages,
+) -> Result<Cli> {
+    let Some(path) = config_path(&cli) else {
+        return Ok(cli);
+    };
+    let defaults = ConfigFileDefaults::load(&path, messages)?;
+    let matches = defaults
+        .apply(Cli::command())
+        .try_get_matches_from(args)
+        .unwrap_or_else(|err| err.exit());
+    Ok(cli_from_matches(&matches))
+}
+
+fn cli_from_matches(matches: &ArgMatches) -> Cli {
+    Cli::from_arg_matches(matches).unwrap_or_else(|err| err.exit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::args::{CliCommand, InfraCommand, InitArgs};
+    use crate::i18n::test_messages;
+
+    fn parse_with(defaults: &ConfigFileDefaults, args: &[&str]) -> Cli {
+        let matches = defaults
+            .apply(Cli::command())
+            .try_get_matches_from(args)
+            .unwrap();
+        cli_from_matches(&matches)
+    }
+
+    fn init_args(cli: Cli) -> Box<InitArgs> {
+        let CliCommand::Init(args) = cli.command else {
+            panic!("expected init");
+        };
+        args
+    }
+
+    #[test]
+    fn load_reads_known_keys_and_rejects_unknown_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        std::fs::write(
+            &path,
+            "openbao_url = \"https://bao.internal:8200\"\nkv_mount = \"bootroot\"\n",
+        )
+        .unwrap();
+        let messages = test_messages();
+
+        let defaults = ConfigFileDefaults::load(&path, &messages).unwrap();
+        assert_eq!(
+            defaults,
+            ConfigFileDefaults {
+                openbao_url: Some("https://bao.internal:8200".to_string()),
+                kv_mount: Some("bootroot".to_string()),
+                ..ConfigFileDefaults::default()
+            }
+        );
+
+        std::fs::write(&path, "openbao_addr = \"https://bao.internal:8200\"\n").unwrap();
+        let err = ConfigFileDefaults::load(&path, &messages).unwrap_err();
+        assert!(err.to_string().contains("openbao_addr"), "{err}");
+    }
+
+    #[test]
+    fn config_value_fills_unset_flag_and_flag_wins() {
+        let defaults = ConfigFileDefaults {
+            openbao_url: Some("https://bao.internal:8200".to_string()),
+            secrets_dir: Some("/srv/bootroot/secrets".to_string()),
+            compose_file: Some("/srv/bootroot/compose.yml".to_string()),
+            ..ConfigFileDefaults::default()
+        };
+
+        let args = init_args(parse_with(&defaults, &["bootroot", "init"]));
+        assert_eq!(args.openbao.openbao_url, "https://bao.internal:8200");
+        assert_eq!(
+            args.secrets_dir.secrets_dir,
+            PathBuf::from("/srv/bootroot/secrets")
+        );
+        assert_eq!(args.openbao.kv_mount, "secret");
+
+        let args = init_args(parse_with(
+            &defaults,
+            &["bootroot", "init", "--openbao-url", "http://localhost:8200"],
+        ));
+        assert_eq!(args.openbao.openbao_url, "http://localhost:8200");
+
+        let cli = parse_with(&defaults, &["bootroot", "infra", "up"]);
+        let CliCommand::Infra(InfraCommand::Up(args)) = cli.command else {
+            panic!("expected infra up");
+        };
+        assert_eq!(
+            args.compose_file.compose_file,
+            PathBuf::from("/srv/bootroot/compose.yml")
+        );
+    }
+
+    #[test]
+    fn config_value_leaves_flags_without_builtin_default_unset() {
+        let defaults = ConfigFileDefaults {
+            openbao_url: Some("https://bao.internal:8200".to_string()),
+            ..ConfigFileDefaults::default()
+        };
+        let cli = parse_with(&defaults, &["bootroot", "rotate", "infra-cert"]);
+        let CliCommand::Rotate(args) = cli.command else {
+            panic!("expected rotate");
+        };
+        assert!(args.openbao.openbao_url.is_none());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::commands::init::{InitPlan, InitSummary};
+use crate::commands::init::{InitPlan, InitPlannedActions, InitSummary};
+use crate::commands::service::cert_files::check_cert_files;
 use crate::commands::service::{display_policy_value, display_wrap_ttl, service_eab_file_path};
 use crate::i18n::{Messages, ServiceNextStepsDaemon};
 use crate::state::{DeliveryMode, PostRenewHookEntry, ServiceEntry};
@@ -36,13 +37,23 @@ pub(crate) struct ServiceAddSummaryOptions<'a> {
 }
 
 pub(crate) fn print_init_summary(summary: &InitSummary, messages: &Messages) {
-    print_init_header(summary, messages);
-    print_init_secrets(summary, messages);
-    print_responder_check(summary, messages);
-    print_db_check(summary, messages);
-    print_kv_paths(messages);
-    print_approles(summary, messages);
-    print_next_steps(summary, messages);
+    // codeql[rust/cleartext-logging]: secrets can be shown intentionally via --enable show-secrets.
+    for line in init_summary_lines(summary, messages) {
+        println!("{line}");
+    }
+}
+
+/// Renders the init summary, masking secrets unless `show_secrets`.
+pub(crate) fn init_summary_lines(summary: &InitSummary, messages: &Messages) -> Vec<String> {
+    let mut lines = init_header_lines(summary, messages);
+    lines.extend(init_secret_lines(summary, summary.show_secrets, messages));
+    lines.push(responder_check_line(summary, messages));
+    lines.extend(db_check_lines(summary, messages));
+    lines.extend(kv_path_lines(summary, messages));
+    lines.push(messages.summary_approles().to_string());
+    lines.extend(approle_lines(summary, summary.show_secrets, messages));
+    lines.extend(next_step_lines(summary, messages));
+    lines
 }
 
 pub(crate) fn print_init_plan(plan: &InitPlan, messages: &Messages) {
@@ -67,6 +78,49 @@ pub(crate) fn print_init_plan(plan: &InitPlan, messages: &Messages) {
     }
 }
 
+/// Prints the side effects `init --dry-run` skipped, grouped by target.
+pub(crate) fn print_init_planned_actions(actions: &InitPlannedActions, messages: &Messages) {
+    for line in init_planned_action_lines(actions, messages) {
+        // codeql[rust/cleartext-logging]: output lists API paths, file paths, and commands, not secret values.
+        println!("{line}");
+    }
+}
+
+/// Renders the `init --dry-run` action list.
+pub(crate) fn init_planned_action_lines(
+    actions: &InitPlannedActions,
+    messages: &Messages,
+) -> Vec<String> {
+    let mut lines = vec![
+        messages.init_dry_run_openbao_writes().to_string(),
+        messages.init_dry_run_openbao_bootstrap().to_string(),
+    ];
+    lines.extend(
+        actions
+            .openbao_writes
+            .iter()
+            .map(|path| messages.init_dry_run_item(path)),
+    );
+    lines.push(messages.init_dry_run_file_writes().to_string());
+    lines.extend(
+        actions
+            .file_writes
+            .iter()
+            .map(|path| messages.init_dry_run_item(&path.display().to_string())),
+    );
+    if !actions.container_commands.is_empty() {
+        lines.push(messages.init_dry_run_container_commands().to_string());
+        lines.extend(
+            actions
+                .container_commands
+                .iter()
+                .map(|command| messages.init_dry_run_item(command)),
+        );
+    }
+    lines.push(messages.init_dry_run_done().to_string());
+    lines
+}
+
 pub(crate) fn print_service_add_summary(
     entry: &ServiceEntry,
     secret_id_path: &std::path::Path,
@@ -250,6 +304,15 @@ pub(crate) fn print_service_info_summary(entry: &ServiceEntry, messages: &Messag
             messages.service_info_token_bound_cidrs(&cidrs.join(", "))
         );
     }
+    if matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap) {
+        match entry.sync_generation {
+            Some(generation) => println!(
+                "{}",
+                messages.service_info_sync_generation(&generation.to_string())
+            ),
+            None => println!("{}", messages.service_info_sync_generation_none()),
+        }
+    }
     println!(
         "{}",
         messages.service_summary_openbao_path(&entry.service_name)
@@ -266,6 +329,19 @@ pub(crate) fn print_service_info_summary(entry: &ServiceEntry, messages: &Messag
         "{}",
         messages.service_summary_key_path(&entry.key_path.display().to_string())
     );
+    match check_cert_files(entry) {
+        Some(check) => {
+            println!(
+                "{}",
+                messages.service_info_cert_file(&check.cert.describe(messages))
+            );
+            println!(
+                "{}",
+                messages.service_info_key_file(&check.key.describe(messages))
+            );
+        }
+        None => println!("{}", messages.service_info_cert_files_remote()),
+    }
     println!(
         "{}",
         messages.service_summary_secret_path(&entry.approle.secret_id_path.display().to_string())
@@ -397,183 +473,171 @@ pub(crate) fn print_verify_plan(
     );
 }
 
-fn print_init_header(summary: &InitSummary, messages: &Messages) {
-    println!("{}", messages.summary_title());
-    println!("{}", messages.summary_openbao_url(&summary.openbao_url));
-    println!("{}", messages.summary_kv_mount(&summary.kv_mount));
-    // codeql[rust/cleartext-logging]: output is a filesystem path, not a secret value.
-    println!(
-        "{}",
-        messages.summary_secrets_dir(&summary.secrets_dir.display().to_string())
-    );
-    match summary.step_ca_result {
-        crate::commands::init::StepCaInitResult::Initialized => {
-            println!("{}", messages.summary_stepca_completed());
-        }
-        crate::commands::init::StepCaInitResult::Skipped => {
-            println!("{}", messages.summary_stepca_skipped());
+fn init_header_lines(summary: &InitSummary, messages: &Messages) -> Vec<String> {
+    let mut lines = vec![
+        messages.summary_title().to_string(),
+        messages.summary_openbao_url(&summary.openbao_url),
+        messages.summary_kv_mount(&summary.kv_mount),
+        // codeql[rust/cleartext-logging]: output is a filesystem path, not a secret value.
+        messages.summary_secrets_dir(&summary.secrets_dir.display().to_string()),
+    ];
+    lines.push(
+        match summary.step_ca_result {
+            crate::commands::init::StepCaInitResult::Initialized => {
+                messages.summary_stepca_completed()
+            }
+            crate::commands::init::StepCaInitResult::Skipped => messages.summary_stepca_skipped(),
+            crate::commands::init::StepCaInitResult::External => messages.summary_stepca_external(),
+            crate::commands::init::StepCaInitResult::Deferred => messages.summary_stepca_deferred(),
         }
-    }
-
-    if summary.init_response {
-        println!(
-            "{}",
-            messages.summary_openbao_init_completed(
-                crate::commands::init::INIT_SECRET_SHARES,
-                crate::commands::init::INIT_SECRET_THRESHOLD
-            )
-        );
+        .to_string(),
+    );
+    lines.push(if summary.init_response {
+        messages.summary_openbao_init_completed(
+            crate::commands::init::INIT_SECRET_SHARES,
+            crate::commands::init::INIT_SECRET_THRESHOLD,
+        )
     } else {
-        println!("{}", messages.summary_openbao_init_skipped());
-    }
+        messages.summary_openbao_init_skipped().to_string()
+    });
+    lines
 }
 
-fn print_init_secrets(summary: &InitSummary, messages: &Messages) {
-    println!(
-        "{}",
-        messages.summary_root_token(&display_secret(&summary.root_token, summary.show_secrets))
-    );
-
-    if !summary.unseal_keys.is_empty() {
-        for (idx, key) in summary.unseal_keys.iter().enumerate() {
-            println!(
-                "{}",
-                messages.summary_unseal_key(idx + 1, &display_secret(key, summary.show_secrets))
-            );
-        }
+/// Renders the secret-bearing init summary lines, masked unless `show`.
+pub(crate) fn init_secret_lines(
+    summary: &InitSummary,
+    show: bool,
+    messages: &Messages,
+) -> Vec<String> {
+    let mut lines = vec![messages.summary_root_token(&display_secret(&summary.root_token, show))];
+    for (idx, key) in summary.unseal_keys.iter().enumerate() {
+        lines.push(messages.summary_unseal_key(idx + 1, &display_secret(key, show)));
     }
-
-    // codeql[rust/cleartext-logging]: secrets can be shown intentionally via --enable show-secrets.
-    println!(
-        "{}",
-        messages.summary_stepca_password(&display_secret(
-            &summary.stepca_password,
-            summary.show_secrets
-        ))
-    );
-    println!(
-        "{}",
-        messages.summary_db_dsn(&display_secret(&summary.db_dsn, summary.show_secrets))
-    );
-    println!(
-        "{}",
-        messages.summary_responder_hmac(&display_secret(&summary.http_hmac, summary.show_secrets))
-    );
-    if let Some(eab) = summary.eab.as_ref() {
-        println!(
-            "{}",
-            messages.summary_eab_kid(&display_secret(&eab.kid, summary.show_secrets))
-        );
-        println!(
-            "{}",
-            messages.summary_eab_hmac(&display_secret(&eab.hmac, summary.show_secrets))
+    for (idx, key) in summary.recovery_keys.iter().enumerate() {
+        lines.push(messages.summary_recovery_key(idx + 1, &display_secret(key, show)));
+    }
+    if !is_external_ca(summary) {
+        lines.push(
+            messages.summary_stepca_password(&display_secret(&summary.stepca_password, show)),
         );
+        lines.push(messages.summary_db_dsn(&display_secret(&summary.db_dsn, show)));
+    }
+    if has_responder(summary) {
+        lines.push(messages.summary_responder_hmac(&display_secret(&summary.http_hmac, show)));
+    }
+    if let Some(eab) = summary.eab.as_ref() {
+        lines.push(messages.summary_eab_kid(&display_secret(&eab.kid, show)));
+        lines.push(messages.summary_eab_hmac(&display_secret(&eab.hmac, show)));
     } else {
-        println!("{}", messages.summary_eab_missing());
+        lines.push(messages.summary_eab_missing().to_string());
     }
+    lines
 }
 
-fn print_responder_check(summary: &InitSummary, messages: &Messages) {
+/// Renders every secret from the init summary unmasked, for
+/// `init --reveal-to-file`.
+pub(crate) fn render_init_secrets(summary: &InitSummary, messages: &Messages) -> String {
+    let mut lines = init_secret_lines(summary, true, messages);
+    lines.push(messages.summary_approles().to_string());
+    lines.extend(approle_lines(summary, true, messages));
+    let mut rendered = lines.join("\n");
+    rendered.push('\n');
+    rendered
+}
+
+fn responder_check_line(summary: &InitSummary, messages: &Messages) -> String {
     match summary.responder_check {
-        crate::commands::init::ResponderCheck::Ok => {
-            println!("{}", messages.summary_responder_check_ok());
-        }
+        crate::commands::init::ResponderCheck::Ok => messages.summary_responder_check_ok(),
         crate::commands::init::ResponderCheck::Skipped => {
-            println!("{}", messages.summary_responder_check_skipped());
+            messages.summary_responder_check_skipped()
         }
     }
+    .to_string()
 }
 
-fn print_db_check(summary: &InitSummary, messages: &Messages) {
-    match summary.db_check {
-        crate::commands::init::DbCheckStatus::Ok => {
-            println!("{}", messages.summary_db_check_ok());
-        }
-        crate::commands::init::DbCheckStatus::Skipped => {
-            println!("{}", messages.summary_db_check_skipped());
-        }
+fn db_check_lines(summary: &InitSummary, messages: &Messages) -> Vec<String> {
+    if is_external_ca(summary) {
+        return Vec::new();
     }
-    println!(
-        "{}",
+    let status = match summary.db_check {
+        crate::commands::init::DbCheckStatus::Ok => messages.summary_db_check_ok(),
+        crate::commands::init::DbCheckStatus::Skipped => messages.summary_db_check_skipped(),
+    };
+    vec![
+        status.to_string(),
         messages.summary_db_host_resolution(
             &summary.db_dsn_host_original,
-            &summary.db_dsn_host_effective
-        )
-    );
+            &summary.db_dsn_host_effective,
+        ),
+    ]
 }
 
-fn print_kv_paths(messages: &Messages) {
-    println!("{}", messages.summary_kv_paths());
-    println!("  - {}", crate::commands::init::PATH_STEPCA_PASSWORD);
-    println!("  - {}", crate::commands::init::PATH_STEPCA_DB);
-    println!("  - {}", crate::commands::init::PATH_RESPONDER_HMAC);
-    println!("  - {}", crate::commands::init::PATH_CA_TRUST);
-    println!("  - {}", crate::commands::init::PATH_AGENT_EAB);
+/// Reports whether init ran with `--skip-step-ca` against an external CA.
+fn is_external_ca(summary: &InitSummary) -> bool {
+    summary.step_ca_result == crate::commands::init::StepCaInitResult::External
 }
 
-fn print_approles(summary: &InitSummary, messages: &Messages) {
-    println!("{}", messages.summary_approles());
-    for role in &summary.approles {
-        println!("  - {} ({})", role.label, role.role_name);
-        // codeql[rust/cleartext-logging]: secrets can be shown intentionally via --enable show-secrets.
-        println!(
-            "{}",
-            messages.summary_role_id(&display_secret(&role.role_id, summary.show_secrets))
-        );
-        println!(
-            "{}",
-            messages.summary_secret_id(&display_secret(&role.secret_id, summary.show_secrets))
-        );
-    }
+fn has_responder(summary: &InitSummary) -> bool {
+    summary.responder_config_path.is_some()
 }
 
-fn print_next_steps(summary: &InitSummary, messages: &Messages) {
-    println!("{}", messages.summary_next_steps());
-    println!("{}", messages.next_steps_configure_templates());
-    println!(
-        "{}",
-        messages
-            .next_steps_responder_template(&summary.responder_template_path.display().to_string())
-    );
-    println!(
-        "{}",
-        messages.next_steps_responder_config(&summary.responder_config_path.display().to_string())
-    );
-    println!(
-        "{}",
-        messages.next_steps_openbao_agent_stepca_config(
-            &summary
-                .openbao_agent_stepca_config_path
-                .display()
-                .to_string()
-        )
-    );
-    println!(
-        "{}",
-        messages.next_steps_openbao_agent_responder_config(
-            &summary
-                .openbao_agent_responder_config_path
-                .display()
-                .to_string()
-        )
-    );
+/// Renders the KV path list from the paths the run actually wrote.
+pub(crate) fn kv_path_lines(summary: &InitSummary, messages: &Messages) -> Vec<String> {
+    std::iter::once(messages.summary_kv_paths().to_string())
+        .chain(summary.kv_paths.iter().map(|path| format!("  - {path}")))
+        .collect()
+}
+
+fn approle_lines(summary: &InitSummary, show: bool, messages: &Messages) -> Vec<String> {
+    summary
+        .approles
+        .iter()
+        .flat_map(|role| {
+            [
+                format!("  - {} ({})", role.label, role.role_name),
+                messages.summary_role_id(&display_secret(&role.role_id, show)),
+                messages.summary_secret_id(&display_secret(&role.secret_id, show)),
+            ]
+        })
+        .collect()
+}
+
+fn next_step_lines(summary: &InitSummary, messages: &Messages) -> Vec<String> {
+    if summary.step_ca_result == crate::commands::init::StepCaInitResult::Deferred {
+        return vec![
+            messages.summary_next_steps().to_string(),
+            messages.next_steps_finish_openbao_only().to_string(),
+        ];
+    }
+    let mut lines = vec![
+        messages.summary_next_steps().to_string(),
+        messages.next_steps_configure_templates().to_string(),
+    ];
+    let display = |path: &std::path::PathBuf| path.display().to_string();
+    if let Some(path) = summary.responder_template_path.as_ref() {
+        lines.push(messages.next_steps_responder_template(&display(path)));
+    }
+    if let Some(path) = summary.responder_config_path.as_ref() {
+        lines.push(messages.next_steps_responder_config(&display(path)));
+    }
+    if let Some(path) = summary.openbao_agent_stepca_config_path.as_ref() {
+        lines.push(messages.next_steps_openbao_agent_stepca_config(&display(path)));
+    }
+    if let Some(path) = summary.openbao_agent_responder_config_path.as_ref() {
+        lines.push(messages.next_steps_openbao_agent_responder_config(&display(path)));
+    }
     if let Some(path) = summary.openbao_agent_override_path.as_ref() {
-        println!(
-            "{}",
-            messages.next_steps_openbao_agent_override(&path.display().to_string())
-        );
+        lines.push(messages.next_steps_openbao_agent_override(&display(path)));
     }
     if let Some(url) = summary.responder_url.as_deref() {
-        println!("{}", messages.next_steps_responder_url(url));
+        lines.push(messages.next_steps_responder_url(url));
     }
-    println!("{}", messages.next_steps_reload_services());
-    println!("{}", messages.next_steps_run_status());
+    lines.push(messages.next_steps_reload_services().to_string());
+    lines.push(messages.next_steps_run_status().to_string());
     if summary.eab.is_none() {
-        println!(
-            "{}",
-            messages.next_steps_eab_hint(crate::commands::init::PATH_AGENT_EAB)
-        );
+        lines.push(messages.next_steps_eab_hint(crate::commands::init::PATH_AGENT_EAB));
     }
+    lines
 }
 
 pub(crate) fn format_hook(hook: &PostRenewHookEntry) -> String {
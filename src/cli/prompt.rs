@@ -1,4 +1,5 @@
-use std::io::{BufRead, Write};
+use std::io::{BufRead, IsTerminal, Write};
+use std::os::fd::{AsRawFd, RawFd};
 
 use anyhow::{Context, Result};
 
@@ -8,6 +9,9 @@ pub(crate) struct Prompt<'a> {
     input: &'a mut dyn BufRead,
     output: &'a mut dyn Write,
     messages: &'a Messages,
+    /// Whether `input` is the process's terminal stdin, so
+    /// [`Prompt::prompt_secret`] can turn off its echo.
+    terminal_stdin: bool,
 }
 
 impl<'a> Prompt<'a> {
@@ -20,6 +24,21 @@ impl<'a> Prompt<'a> {
             input,
             output,
             messages,
+            terminal_stdin: false,
+        }
+    }
+
+    /// Creates a prompt over the process's stdin, hiding secret input
+    /// when stdin is a terminal.
+    pub(crate) fn for_stdin(
+        input: &'a mut std::io::StdinLock<'static>,
+        output: &'a mut dyn Write,
+        messages: &'a Messages,
+    ) -> Self {
+        let terminal_stdin = input.is_terminal();
+        Self {
+            terminal_stdin,
+            ..Self::new(input, output, messages)
         }
     }
 
@@ -47,17 +66,85 @@ impl<'a> Prompt<'a> {
         Ok(trimmed.to_string())
     }
 
+    /// Reads a secret such as a token, password, or unseal key without
+    /// echoing it when the input is a terminal.
+    pub(crate) fn prompt_secret(&mut self, label: &str) -> Result<String> {
+        write!(self.output, "{}", format_prompt(label, None))
+            .with_context(|| self.messages.error_prompt_write_failed())?;
+        self.output
+            .flush()
+            .with_context(|| self.messages.error_prompt_flush_failed())?;
+        let mut line = String::new();
+        let read = {
+            let _echo = self
+                .terminal_stdin
+                .then(|| EchoGuard::disable(std::io::stdin().as_raw_fd()))
+                .flatten();
+            self.input.read_line(&mut line)
+        }
+        .with_context(|| self.messages.error_prompt_read_failed())?;
+        if read == 0 {
+            anyhow::bail!(self.messages.error_prompt_eof());
+        }
+        Ok(line.trim().to_string())
+    }
+
+    /// Asks a yes/no question; only `y`/`yes` (any case) confirms.
+    ///
+    /// Unlike [`Prompt::prompt_text`], EOF counts as "no", so a
+    /// non-interactive run declines instead of failing on the read.
+    pub(crate) fn confirm(&mut self, label: &str) -> Result<bool> {
+        write!(self.output, "{}", format_prompt(label, None))
+            .with_context(|| self.messages.error_prompt_write_failed())?;
+        self.output
+            .flush()
+            .with_context(|| self.messages.error_prompt_flush_failed())?;
+        let mut line = String::new();
+        self.input
+            .read_line(&mut line)
+            .with_context(|| self.messages.error_prompt_read_failed())?;
+        let answer = line.trim().to_ascii_lowercase();
+        Ok(answer == "y" || answer == "yes")
+    }
+
+    /// Writes an informational line, such as instructions or a
+    /// validation error, between prompts.
+    pub(crate) fn write_line(&mut self, line: &str) -> Result<()> {
+        writeln!(self.output, "{line}").with_context(|| self.messages.error_prompt_write_failed())
+    }
+
     pub(crate) fn prompt_with_validation<T, F>(
         &mut self,
         label: &str,
         default: Option<&str>,
-        mut validate: F,
+        validate: F,
+    ) -> Result<T>
+    where
+        F: FnMut(&str) -> Result<T>,
+    {
+        self.read_until_valid(|prompt| prompt.prompt_text(label, default), validate)
+    }
+
+    /// Like [`Prompt::prompt_with_validation`], but reads through
+    /// [`Prompt::prompt_secret`].
+    pub(crate) fn prompt_secret_with_validation<T, F>(
+        &mut self,
+        label: &str,
+        validate: F,
     ) -> Result<T>
     where
         F: FnMut(&str) -> Result<T>,
+    {
+        self.read_until_valid(|prompt| prompt.prompt_secret(label), validate)
+    }
+
+    fn read_until_valid<T, R, F>(&mut self, mut read: R, mut validate: F) -> Result<T>
+    where
+        R: FnMut(&mut Self) -> Result<String>,
+        F: FnMut(&str) -> Result<T>,
     {
         loop {
-            let value = self.prompt_text(label, default)?;
+            let value = read(self)?;
             match validate(&value) {
                 Ok(parsed) => return Ok(parsed),
                 Err(err) => {
@@ -69,6 +156,45 @@ impl<'a> Prompt<'a> {
     }
 }
 
+/// Turns terminal echo off for `fd` and restores the previous settings
+/// on drop.
+struct EchoGuard {
+    fd: RawFd,
+    original: libc::termios,
+}
+
+impl EchoGuard {
+    /// Returns `None` when `fd` is not a terminal or its settings cannot
+    /// be changed; the prompt then reads with echo rather than failing.
+    fn disable(fd: RawFd) -> Option<Self> {
+        // SAFETY: an all-zero `termios` is a valid out-parameter that
+        // `tcgetattr` fully overwrites on success.
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        // SAFETY: `original` is a live, exclusively borrowed termios.
+        if unsafe { libc::tcgetattr(fd, &raw mut original) } != 0 {
+            return None;
+        }
+        let mut hidden = original;
+        // Keep echoing the newline so the next output starts on a new line.
+        hidden.c_lflag &= !libc::ECHO;
+        hidden.c_lflag |= libc::ECHONL;
+        // SAFETY: `hidden` is a valid termios copied from `tcgetattr`.
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw const hidden) } != 0 {
+            return None;
+        }
+        Some(Self { fd, original })
+    }
+}
+
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        // SAFETY: restores the settings captured in `disable` on the same fd.
+        unsafe {
+            libc::tcsetattr(self.fd, libc::TCSANOW, &raw const self.original);
+        }
+    }
+}
+
 fn format_prompt(label: &str, default: Option<&str>) -> String {
     match default {
         Some(value) => format!("{label} [{value}]: "),
@@ -114,6 +240,18 @@ mod tests {
         assert_eq!(err.to_string(), messages.error_prompt_eof());
     }
 
+    #[test]
+    fn confirm_accepts_yes_and_declines_on_eof() {
+        let messages = Messages::new("en").unwrap();
+        for (input, expected) in [("YES\n", true), ("y\n", true), ("n\n", false), ("", false)] {
+            let mut input = Cursor::new(input);
+            let mut output = Vec::new();
+            let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+            assert_eq!(prompt.confirm("Proceed? [y/N]").unwrap(), expected);
+            assert_eq!(String::from_utf8(output).unwrap(), "Proceed? [y/N]: ");
+        }
+    }
+
     #[test]
     fn prompt_with_validation_errors_on_eof_without_looping() {
         let mut input = Cursor::new("");
@@ -129,4 +267,42 @@ mod tests {
         let err = result.expect_err("EOF must error instead of looping");
         assert_eq!(err.to_string(), messages.error_prompt_eof());
     }
+
+    #[test]
+    fn prompt_secret_reads_value_without_echoing_it() {
+        let mut input = Cursor::new("s.secret-token\n");
+        let mut output = Vec::new();
+        let messages = Messages::new("en").unwrap();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        let value = prompt.prompt_secret("Token").unwrap();
+        assert_eq!(value, "s.secret-token");
+        assert_eq!(String::from_utf8(output).unwrap(), "Token: ");
+    }
+
+    #[test]
+    fn prompt_secret_with_validation_retries_blank_input() {
+        let mut input = Cursor::new("\nkey-1\n");
+        let mut output = Vec::new();
+        let messages = Messages::new("en").unwrap();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        let value: String = prompt
+            .prompt_secret_with_validation("Key", |value| {
+                if value.is_empty() {
+                    anyhow::bail!(messages.error_value_required());
+                }
+                Ok(value.to_string())
+            })
+            .unwrap();
+        assert_eq!(value, "key-1");
+    }
+
+    #[test]
+    fn prompt_secret_errors_on_eof() {
+        let mut input = Cursor::new("");
+        let mut output = Vec::new();
+        let messages = Messages::new("en").unwrap();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        let err = prompt.prompt_secret("Token").expect_err("EOF must error");
+        assert_eq!(err.to_string(), messages.error_prompt_eof());
+    }
 }
@@ -1,18 +1,27 @@
+pub(crate) mod audit_secrets;
 pub(crate) mod ca;
+pub(crate) mod cancellation;
+pub(crate) mod capabilities;
 pub(crate) mod clean;
 pub(crate) mod compose_file;
 pub(crate) mod constants;
+pub(crate) mod container_runtime;
 pub(crate) mod dns_alias;
 pub(crate) mod dotenv;
 pub(crate) mod guardrails;
 pub(crate) mod infra;
 pub(crate) mod init;
+pub(crate) mod migrate;
 pub(crate) mod monitoring;
 pub(crate) mod openbao_auth;
 pub(crate) mod openbao_unseal;
 pub(crate) mod reinit;
 pub(crate) mod rotate;
+#[cfg(feature = "self-test")]
+pub(crate) mod self_test;
 pub(crate) mod service;
 pub(crate) mod status;
+pub(crate) mod support_bundle;
 pub(crate) mod trust;
 pub(crate) mod verify;
+pub(crate) mod version;
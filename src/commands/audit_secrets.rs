@@ -0,0 +1,134 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+use crate::cli::args::AuditSecretsArgs;
+use crate::i18n::Messages;
+
+/// File names `init` and `service add` write secret material into.
+const SECRET_FILE_NAMES: &[&str] = &[
+    "password.txt",
+    "state.json",
+    "state.json.bak",
+    "secret_id",
+    "role_id",
+    "unseal-keys.txt",
+    "root_ca_key",
+    "intermediate_ca_key",
+];
+/// File extensions that always carry private key material.
+const SECRET_FILE_EXTENSIONS: &[&str] = &["key"];
+
+pub(crate) fn run_audit_secrets(args: &AuditSecretsArgs, messages: &Messages) -> Result<()> {
+    let Some(tracked) = find_tracked_secrets(&args.repo_dir, messages)? else {
+        println!("{}", messages.audit_secrets_not_git_repo());
+        return Ok(());
+    };
+    if tracked.is_empty() {
+        println!("{}", messages.audit_secrets_clean());
+        return Ok(());
+    }
+    for path in &tracked {
+        eprintln!("{}", messages.audit_secrets_tracked(path));
+    }
+    eprintln!("{}", messages.audit_secrets_hint());
+    anyhow::bail!(messages.error_audit_secrets_tracked(&tracked.len().to_string()));
+}
+
+/// Lists git-tracked paths under `repo_dir` whose names match secret
+/// material, or `None` when `repo_dir` is not inside a git work tree.
+///
+/// Only the index is inspected (`git ls-files`); file contents are
+/// never read.
+fn find_tracked_secrets(repo_dir: &Path, messages: &Messages) -> Result<Option<Vec<String>>> {
+    let output = Command::new("git")
+        .args(["ls-files", "-z"])
+        .current_dir(repo_dir)
+        .output()
+        .with_context(|| messages.error_command_run_failed("git ls-files"))?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let listing = String::from_utf8_lossy(&output.stdout);
+    Ok(Some(
+        listing
+            .split('\0')
+            .filter(|path| is_secret_path(Path::new(path)))
+            .map(str::to_string)
+            .collect(),
+    ))
+}
+
+fn is_secret_path(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    SECRET_FILE_NAMES.contains(&name)
+        || path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| SECRET_FILE_EXTENSIONS.contains(&ext))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    #[test]
+    fn test_is_secret_path_matches_sensitive_names() {
+        assert!(is_secret_path(Path::new("secrets/password.txt")));
+        assert!(is_secret_path(Path::new("state.json")));
+        assert!(is_secret_path(Path::new("secrets/services/edge/secret_id")));
+        assert!(is_secret_path(Path::new("certs/edge.key")));
+        assert!(is_secret_path(Path::new("secrets/secrets/root_ca_key")));
+        assert!(!is_secret_path(Path::new("README.md")));
+        assert!(!is_secret_path(Path::new("certs/edge.crt")));
+    }
+
+    #[test]
+    fn test_find_tracked_secrets_reports_tracked_password() {
+        let dir = tempdir().unwrap();
+        git(dir.path(), &["init", "--quiet"]);
+        fs::write(dir.path().join("password.txt"), "hunter2").unwrap();
+        fs::write(dir.path().join("README.md"), "docs").unwrap();
+        fs::write(dir.path().join("state.json"), "{}").unwrap();
+        git(dir.path(), &["add", "password.txt", "README.md"]);
+
+        let messages = crate::i18n::test_messages();
+        let tracked = find_tracked_secrets(dir.path(), &messages)
+            .unwrap()
+            .expect("git work tree");
+        assert_eq!(tracked, vec!["password.txt".to_string()]);
+
+        let args = AuditSecretsArgs {
+            repo_dir: dir.path().to_path_buf(),
+        };
+        let err = run_audit_secrets(&args, &messages).unwrap_err();
+        assert_eq!(err.to_string(), "1 secret file(s) tracked by git");
+    }
+
+    #[test]
+    fn test_find_tracked_secrets_returns_none_outside_git() {
+        let dir = tempdir().unwrap();
+        let messages = crate::i18n::test_messages();
+        assert!(
+            find_tracked_secrets(dir.path(), &messages)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
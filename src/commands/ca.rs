@@ -8,7 +8,8 @@ use anyhow::{Context, Result};
 use crate::cli::args::{CaRestartArgs, CaUpdateArgs};
 use crate::commands::infra::{collect_container_failures, collect_readiness, run_docker};
 use crate::commands::init::{
-    RESPONDER_TEMPLATE_DIR, STEPCA_CA_JSON_TEMPLATE_NAME, set_acme_cert_duration,
+    RESPONDER_TEMPLATE_DIR, STEPCA_CA_JSON_TEMPLATE_NAME, missing_acme_provisioner_error,
+    set_acme_cert_duration,
 };
 use crate::i18n::Messages;
 
@@ -68,7 +69,7 @@ fn wait_for_stepca_ready(compose_file: &Path, messages: &Messages) -> Result<()>
     let services = [STEP_CA_SERVICE.to_string()];
     let deadline = Instant::now() + READINESS_TIMEOUT;
     loop {
-        let failures = match collect_readiness(compose_file, None, &services, messages) {
+        let failures = match collect_readiness(compose_file, &[], &services, messages) {
             Ok(readiness) => collect_container_failures(&readiness),
             Err(err) => vec![err.to_string()],
         };
@@ -97,10 +98,11 @@ fn patch_ca_json(
     let mut value: serde_json::Value =
         serde_json::from_str(&contents).context(messages.error_parse_ca_json_failed())?;
     if !set_acme_cert_duration(&mut value, cert_duration, Some(provisioner)) {
-        anyhow::bail!(
-            "ca.json does not contain an ACME provisioner named {provisioner:?} — \
-             cannot set defaultTLSCertDuration"
-        );
+        return Err(missing_acme_provisioner_error(
+            "ca.json",
+            &value,
+            provisioner,
+        ));
     }
     let updated =
         serde_json::to_string_pretty(&value).context(messages.error_serialize_ca_json_failed())?;
@@ -121,10 +123,11 @@ fn patch_ca_json_ctmpl(
     let mut value: serde_json::Value =
         serde_json::from_str(&masked).context(messages.error_parse_ca_json_failed())?;
     if !set_acme_cert_duration(&mut value, cert_duration, Some(provisioner)) {
-        anyhow::bail!(
-            "ca.json.ctmpl does not contain an ACME provisioner named {provisioner:?} — \
-             cannot set defaultTLSCertDuration"
-        );
+        return Err(missing_acme_provisioner_error(
+            "ca.json.ctmpl",
+            &value,
+            provisioner,
+        ));
     }
     let serialized =
         serde_json::to_string_pretty(&value).context(messages.error_serialize_ca_json_failed())?;
@@ -301,6 +304,7 @@ mod tests {
 
         let err = patch_ca_json(&path, "48h", "acme", &test_messages()).unwrap_err();
         assert!(err.to_string().contains("ACME provisioner"));
+        assert!(err.to_string().contains("it has no ACME provisioners"));
     }
 
     #[test]
@@ -309,12 +313,20 @@ mod tests {
         let path = dir.path().join("ca.json");
         std::fs::write(
             &path,
-            r#"{"authority":{"provisioners":[{"type":"ACME","name":"staging"}]}}"#,
+            r#"{"authority":{"provisioners":[
+                {"type":"ACME","name":"staging"},
+                {"type":"JWK","name":"admin"},
+                {"type":"ACME","name":"prod"}
+            ]}}"#,
         )
         .unwrap();
 
         let err = patch_ca_json(&path, "48h", "acme", &test_messages()).unwrap_err();
         let msg = err.to_string();
         assert!(msg.contains("ACME provisioner"), "unexpected error: {msg}");
+        assert!(
+            msg.contains("available ACME provisioners: staging, prod"),
+            "unexpected error: {msg}"
+        );
     }
 }
@@ -0,0 +1,384 @@
+//! Cooperative Ctrl-C handling for long-running commands.
+//!
+//! `infra up` and `rotate` install a SIGINT handler that only records the
+//! request. Multi-step flows call [`Cancellation::check`] between steps,
+//! so the step in flight finishes and any state it persists is written
+//! before the command exits with [`EXIT_CODE_CANCELLED`]. Polling loops
+//! wait through [`Cancellation::sleep`], which wakes as soon as the
+//! request arrives. A second Ctrl-C exits immediately. Container commands
+//! run in their own process group (see `container_runtime::command`), so
+//! the terminal's SIGINT does not reach the compose step in flight.
+//!
+//! `--operation-timeout` reuses the same path: [`Cancellation::with_deadline`]
+//! requests cancellation once the deadline passes, gives the step in
+//! flight [`DEADLINE_GRACE`] to finish, and then fails the command with
+//! [`DeadlineExceeded`] and [`EXIT_CODE_DEADLINE_EXCEEDED`].
+//!
+//! A step that must not be cut short, such as `OpenBao` initialization
+//! and unseal or a password change that is only consistent once it has
+//! reached both the database and KV, runs inside a
+//! [`Cancellation::critical_section`]. While the section is held, checks
+//! pass, sleeps run their full length, and the deadline waits for the
+//! section to end instead of dropping the command; the first check after
+//! it stops the command as usual.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use anyhow::Result;
+use tokio::sync::Notify;
+
+use crate::i18n::Messages;
+
+/// Exit code for a run stopped by Ctrl-C (128 + SIGINT, as shells report).
+pub(crate) const EXIT_CODE_CANCELLED: u8 = 130;
+
+/// Exit code for a run stopped by `--operation-timeout`, following the
+/// GNU `timeout(1)` convention.
+pub(crate) const EXIT_CODE_DEADLINE_EXCEEDED: u8 = 124;
+
+/// How long the step in flight may keep running after the deadline
+/// passes, so state it is persisting is written before the command stops.
+const DEADLINE_GRACE: Duration = Duration::from_secs(10);
+
+static GLOBAL: Cancellation = Cancellation::new();
+
+/// Error returned when a step observes a pending cancellation request.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub(crate) struct Cancelled(&'static str);
+
+/// Error returned when a command outlives `--operation-timeout`.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub(crate) struct DeadlineExceeded(String);
+
+pub(crate) struct Cancellation {
+    requested: AtomicBool,
+    critical_depth: AtomicUsize,
+    notify: Notify,
+}
+
+impl Cancellation {
+    pub(crate) const fn new() -> Self {
+        Self {
+            requested: AtomicBool::new(false),
+            critical_depth: AtomicUsize::new(0),
+            notify: Notify::const_new(),
+        }
+    }
+
+    pub(crate) fn cancel(&self) {
+        self.requested.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+
+    fn in_critical_section(&self) -> bool {
+        self.critical_depth.load(Ordering::SeqCst) > 0
+    }
+
+    /// Returns [`Cancelled`] when cancellation has been requested, unless
+    /// a [`Self::critical_section`] is held.
+    pub(crate) fn check(&self, messages: &Messages) -> Result<()> {
+        if self.is_cancelled() && !self.in_critical_section() {
+            return Err(Cancelled(messages.error_cancelled()).into());
+        }
+        Ok(())
+    }
+
+    /// Checks for a pending request, then shields the caller from
+    /// cancellation until the returned guard is dropped.
+    pub(crate) fn critical_section(&self, messages: &Messages) -> Result<CriticalSection<'_>> {
+        self.check(messages)?;
+        self.critical_depth.fetch_add(1, Ordering::SeqCst);
+        Ok(CriticalSection(self))
+    }
+
+    /// Sleeps for `duration`, returning [`Cancelled`] as soon as
+    /// cancellation is requested instead of waiting out the delay.
+    pub(crate) async fn sleep(&self, duration: Duration, messages: &Messages) -> Result<()> {
+        if self.in_critical_section() {
+            tokio::time::sleep(duration).await;
+            return Ok(());
+        }
+        // Register for the wakeup before checking the flag so a request
+        // that lands in between is not missed.
+        let notified = self.notify.notified();
+        tokio::pin!(notified);
+        notified.as_mut().enable();
+        self.check(messages)?;
+        tokio::select! {
+            () = tokio::time::sleep(duration) => Ok(()),
+            () = notified => self.check(messages),
+        }
+    }
+
+    /// Runs `operation` to completion, or fails it with
+    /// [`DeadlineExceeded`] once it has run longer than `deadline`.
+    ///
+    /// Past the deadline, cancellation is requested so the operation
+    /// stops at its next [`Self::check`], and it gets `grace` to do so
+    /// before being dropped; either way the result is the deadline error.
+    /// An operation still inside a [`Self::critical_section`] when the
+    /// grace runs out is never dropped: it keeps running until it stops
+    /// at a check past the section, or finishes.
+    pub(crate) async fn with_deadline<T>(
+        &self,
+        deadline: Option<Duration>,
+        grace: Duration,
+        messages: &Messages,
+        operation: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let Some(deadline) = deadline else {
+            return operation.await;
+        };
+        tokio::pin!(operation);
+        if let Ok(result) = tokio::time::timeout(deadline, operation.as_mut()).await {
+            return result;
+        }
+        self.cancel();
+        if tokio::time::timeout(grace, operation.as_mut())
+            .await
+            .is_err()
+            && self.in_critical_section()
+        {
+            let _ = operation.await;
+        }
+        Err(DeadlineExceeded(
+            messages.error_operation_deadline_exceeded(
+                &humantime::format_duration(deadline).to_string(),
+            ),
+        )
+        .into())
+    }
+}
+
+/// Guard returned by [`Cancellation::critical_section`].
+pub(crate) struct CriticalSection<'a>(&'a Cancellation);
+
+impl Drop for CriticalSection<'_> {
+    fn drop(&mut self) {
+        self.0.critical_depth.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Returns the process-wide cancellation state the SIGINT handler sets.
+pub(crate) fn global() -> &'static Cancellation {
+    &GLOBAL
+}
+
+/// Spawns a task on `runtime` that turns the first Ctrl-C into a
+/// cancellation request and the second into an immediate exit.
+pub(crate) fn install_sigint_handler(runtime: &tokio::runtime::Runtime, messages: &Messages) {
+    let notice = messages.cancellation_requested();
+    runtime.spawn(async move {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        eprintln!("{notice}");
+        GLOBAL.cancel();
+        if tokio::signal::ctrl_c().await.is_ok() {
+            std::process::exit(i32::from(EXIT_CODE_CANCELLED));
+        }
+    });
+}
+
+/// Runs `operation` under the process-wide `--operation-timeout`
+/// deadline; see [`Cancellation::with_deadline`].
+pub(crate) async fn with_deadline<T>(
+    deadline: Option<Duration>,
+    messages: &Messages,
+    operation: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    GLOBAL
+        .with_deadline(deadline, DEADLINE_GRACE, messages, operation)
+        .await
+}
+
+/// Returns whether `err` was caused by a cancellation request.
+pub(crate) fn is_cancelled_error(err: &anyhow::Error) -> bool {
+    err.chain().any(<dyn std::error::Error>::is::<Cancelled>)
+}
+
+/// Returns whether `err` was caused by `--operation-timeout` expiring.
+pub(crate) fn is_deadline_error(err: &anyhow::Error) -> bool {
+    err.chain()
+        .any(<dyn std::error::Error>::is::<DeadlineExceeded>)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn check_passes_until_cancelled() {
+        let messages = crate::i18n::test_messages();
+        let cancellation = Cancellation::new();
+        assert!(cancellation.check(&messages).is_ok());
+        cancellation.cancel();
+        let err = cancellation.check(&messages).unwrap_err();
+        assert!(is_cancelled_error(&err));
+    }
+
+    #[tokio::test]
+    async fn sleep_wakes_promptly_on_cancel() {
+        let messages = crate::i18n::test_messages();
+        let cancellation = Arc::new(Cancellation::new());
+        let trigger = Arc::clone(&cancellation);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            trigger.cancel();
+        });
+        let started = std::time::Instant::now();
+        let err = cancellation
+            .sleep(Duration::from_mins(1), &messages)
+            .await
+            .unwrap_err();
+        assert!(is_cancelled_error(&err));
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn cancel_mid_loop_stops_after_current_step() {
+        let messages = crate::i18n::test_messages();
+        let cancellation = Cancellation::new();
+        let mut completed = Vec::new();
+        let result: Result<()> = async {
+            for step in 0..5 {
+                cancellation.check(&messages)?;
+                completed.push(step);
+                if step == 1 {
+                    cancellation.cancel();
+                }
+                cancellation
+                    .sleep(Duration::from_millis(1), &messages)
+                    .await?;
+            }
+            Ok(())
+        }
+        .await;
+        let err = result.unwrap_err();
+        assert!(is_cancelled_error(&err));
+        assert_eq!(completed, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn with_deadline_fails_operation_that_overruns() {
+        let messages = crate::i18n::test_messages();
+        let cancellation = Cancellation::new();
+        let started = std::time::Instant::now();
+        let err = cancellation
+            .with_deadline(
+                Some(Duration::from_millis(20)),
+                Duration::from_millis(20),
+                &messages,
+                async {
+                    tokio::time::sleep(Duration::from_mins(1)).await;
+                    Ok(())
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(is_deadline_error(&err));
+        assert!(!is_cancelled_error(&err));
+        assert!(cancellation.is_cancelled());
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn with_deadline_lets_current_step_finish_within_grace() {
+        let messages = crate::i18n::test_messages();
+        let cancellation = Cancellation::new();
+        let mut completed = Vec::new();
+        let err = cancellation
+            .with_deadline(
+                Some(Duration::from_millis(30)),
+                Duration::from_secs(30),
+                &messages,
+                async {
+                    for step in 0..100 {
+                        cancellation.check(&messages)?;
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        completed.push(step);
+                    }
+                    Ok(())
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(is_deadline_error(&err));
+        assert!(!completed.is_empty() && completed.len() < 100);
+    }
+
+    #[tokio::test]
+    async fn with_deadline_waits_for_critical_section_then_stops() {
+        let messages = crate::i18n::test_messages();
+        let cancellation = Cancellation::new();
+        let mut completed = Vec::new();
+        let err = cancellation
+            .with_deadline(
+                Some(Duration::from_millis(20)),
+                Duration::from_millis(20),
+                &messages,
+                async {
+                    {
+                        let _section = cancellation.critical_section(&messages)?;
+                        for step in 0..5 {
+                            cancellation.check(&messages)?;
+                            cancellation
+                                .sleep(Duration::from_millis(20), &messages)
+                                .await?;
+                            completed.push(step);
+                        }
+                    }
+                    cancellation.check(&messages)?;
+                    completed.push(5);
+                    Ok(())
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(is_deadline_error(&err));
+        assert_eq!(completed, vec![0, 1, 2, 3, 4]);
+        assert!(!cancellation.in_critical_section());
+    }
+
+    #[test]
+    fn critical_section_fails_once_cancelled() {
+        let messages = crate::i18n::test_messages();
+        let cancellation = Cancellation::new();
+        cancellation.cancel();
+        let err = cancellation.critical_section(&messages).err().unwrap();
+        assert!(is_cancelled_error(&err));
+        assert!(!cancellation.in_critical_section());
+    }
+
+    #[tokio::test]
+    async fn with_deadline_passes_through_results_in_time() {
+        let messages = crate::i18n::test_messages();
+        let cancellation = Cancellation::new();
+        let value = cancellation
+            .with_deadline(
+                Some(Duration::from_secs(30)),
+                Duration::from_secs(1),
+                &messages,
+                async { Ok(7) },
+            )
+            .await
+            .unwrap();
+        assert_eq!(value, 7);
+        let value = cancellation
+            .with_deadline(None, Duration::from_secs(1), &messages, async { Ok(8) })
+            .await
+            .unwrap();
+        assert_eq!(value, 8);
+        assert!(!cancellation.is_cancelled());
+    }
+}
@@ -0,0 +1,183 @@
+//! Up-front token capability checks.
+//!
+//! A token missing one capability otherwise fails part-way through a batch
+//! of writes with a bare 403. [`preflight_capabilities`] asks `OpenBao`
+//! (`sys/capabilities-self`) before the batch starts and names the exact
+//! path, capability, and policy to fix.
+
+use anyhow::Result;
+use bootroot::openbao::OpenBaoClient;
+
+use crate::i18n::Messages;
+
+/// Capability that `OpenBao` reports for a root token on every path.
+const ROOT_CAPABILITY: &str = "root";
+
+/// One capability a step needs on one `OpenBao` API path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RequiredCapability {
+    pub(crate) path: String,
+    pub(crate) capability: &'static str,
+}
+
+impl RequiredCapability {
+    pub(crate) fn new(path: impl Into<String>, capability: &'static str) -> Self {
+        Self {
+            path: path.into(),
+            capability,
+        }
+    }
+
+    /// Requires overwriting the KV v2 secret at `path` under `mount`.
+    pub(crate) fn kv_update(mount: &str, path: &str) -> Self {
+        Self::new(format!("{mount}/data/{path}"), "update")
+    }
+}
+
+/// Where a missing capability has to be granted.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum CapabilitySource {
+    /// The bootroot policy attached to the `AppRole` the step normally
+    /// runs with.
+    Policy(&'static str),
+    /// The step is meant to run with the root token.
+    RootToken,
+}
+
+/// Fails with a precise error when the client token lacks any of
+/// `required`.
+///
+/// The check is best-effort: when `sys/capabilities-self` itself cannot be
+/// queried (for example a token created without the `default` policy),
+/// the step runs unchecked and any denial surfaces as before.
+pub(crate) async fn preflight_capabilities(
+    client: &OpenBaoClient,
+    required: &[RequiredCapability],
+    source: CapabilitySource,
+    messages: &Messages,
+) -> Result<()> {
+    if required.is_empty() {
+        return Ok(());
+    }
+    let paths: Vec<&str> = required.iter().map(|item| item.path.as_str()).collect();
+    let granted = match client.capabilities_self(&paths).await {
+        Ok(granted) => granted,
+        Err(err) => {
+            tracing::debug!("skipping OpenBao capability preflight: {err:#}");
+            return Ok(());
+        }
+    };
+    for item in required {
+        let allowed = granted.get(&item.path).is_some_and(|capabilities| {
+            capabilities
+                .iter()
+                .any(|capability| capability == ROOT_CAPABILITY || capability == item.capability)
+        });
+        if !allowed {
+            anyhow::bail!(match source {
+                CapabilitySource::Policy(policy) => {
+                    messages.error_openbao_capability_missing(item.capability, &item.path, policy)
+                }
+                CapabilitySource::RootToken => {
+                    messages.error_openbao_capability_missing_root(item.capability, &item.path)
+                }
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn client_for(server: &MockServer) -> OpenBaoClient {
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("runtime-token".to_string());
+        client
+    }
+
+    #[tokio::test]
+    async fn preflight_reports_missing_write_with_policy() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/sys/capabilities-self"))
+            .and(body_json(serde_json::json!({
+                "paths": ["secret/data/bootroot/stepca/password", "secret/data/bootroot/ca"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "secret/data/bootroot/stepca/password": ["create", "read", "update"],
+                "secret/data/bootroot/ca": ["read"]
+            })))
+            .mount(&server)
+            .await;
+
+        let messages = crate::i18n::test_messages();
+        let required = [
+            RequiredCapability::kv_update("secret", "bootroot/stepca/password"),
+            RequiredCapability::kv_update("secret", "bootroot/ca"),
+        ];
+        let err = preflight_capabilities(
+            &client_for(&server),
+            &required,
+            CapabilitySource::Policy("bootroot-runtime-rotate"),
+            &messages,
+        )
+        .await
+        .expect_err("missing update must fail the preflight");
+        assert_eq!(
+            err.to_string(),
+            messages.error_openbao_capability_missing(
+                "update",
+                "secret/data/bootroot/ca",
+                "bootroot-runtime-rotate"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn preflight_accepts_root_capability() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/sys/capabilities-self"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "capabilities": ["root"],
+                "data": { "capabilities": ["root"], "secret/data/bootroot/ca": ["root"] }
+            })))
+            .mount(&server)
+            .await;
+
+        let messages = crate::i18n::test_messages();
+        preflight_capabilities(
+            &client_for(&server),
+            &[RequiredCapability::kv_update("secret", "bootroot/ca")],
+            CapabilitySource::RootToken,
+            &messages,
+        )
+        .await
+        .expect("root token passes");
+    }
+
+    #[tokio::test]
+    async fn preflight_is_skipped_when_endpoint_is_unavailable() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/sys/capabilities-self"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("permission denied"))
+            .mount(&server)
+            .await;
+
+        let messages = crate::i18n::test_messages();
+        preflight_capabilities(
+            &client_for(&server),
+            &[RequiredCapability::kv_update("secret", "bootroot/ca")],
+            CapabilitySource::RootToken,
+            &messages,
+        )
+        .await
+        .expect("unavailable preflight does not block the step");
+    }
+}
@@ -1,10 +1,10 @@
 use std::path::Path;
-use std::process::Command as ProcessCommand;
 
 use anyhow::{Context, Result};
 
 use crate::cli::args::CleanArgs;
 use crate::commands::compose_file::compose_file_dir;
+use crate::commands::container_runtime;
 use crate::commands::infra::run_docker;
 use crate::commands::init::{OPENBAO_CONTAINER_NAME, prompt_yes_no};
 use crate::i18n::Messages;
@@ -179,27 +179,37 @@ fn normalise_compose_project_name(input: &str) -> String {
         .collect()
 }
 
-/// Reports whether a container exists on the local docker daemon,
+/// Reports whether a container exists on the local container runtime,
 /// independent of any label.  Used by `reinit`'s scope check to
 /// distinguish "container missing" (the stuck-after-`clean --openbao-only`
 /// recovery path) from "container exists but its compose labels are
 /// missing", which `inspect_label_via_docker` cannot tell apart.
 pub(crate) fn container_exists_via_docker(container: &str) -> Result<bool> {
-    let output = ProcessCommand::new("docker")
+    let output = container_runtime::command()
         .args(["container", "inspect", "--format", "{{.Id}}", container])
         .output()
-        .with_context(|| "failed to run `docker container inspect`")?;
+        .with_context(|| {
+            format!(
+                "failed to run `{} container inspect`",
+                container_runtime::selected().binary()
+            )
+        })?;
     Ok(output.status.success())
 }
 
-/// Reads a single label from a docker container. Returns `Ok(None)`
+/// Reads a single label from a container on the selected runtime. Returns `Ok(None)`
 /// when the container is missing OR when the label is unset.
 pub(crate) fn inspect_label_via_docker(container: &str, label: &str) -> Result<Option<String>> {
     let format_arg = format!("{{{{index .Config.Labels \"{label}\"}}}}");
-    let output = ProcessCommand::new("docker")
+    let output = container_runtime::command()
         .args(["inspect", "--format", &format_arg, container])
         .output()
-        .with_context(|| "failed to run `docker inspect`")?;
+        .with_context(|| {
+            format!(
+                "failed to run `{} inspect`",
+                container_runtime::selected().binary()
+            )
+        })?;
     if !output.status.success() {
         // Treat any inspect failure (most commonly "no such object") as
         // "container missing"; we will fall back to the basename.
@@ -227,6 +237,7 @@ fn remove_clean_artifacts(
 ) -> Result<()> {
     remove_path_if_exists(&compose_dir.join("secrets"), messages)?;
     remove_file_if_exists(state_path, messages)?;
+    remove_file_if_exists(&StateFile::backup_path(state_path), messages)?;
     remove_file_if_exists(&compose_dir.join(".env"), messages)?;
     Ok(())
 }
@@ -292,6 +303,8 @@ mod tests {
         // NOT in the compose subdirectory.
         let state_path = root.path().join("state.json");
         std::fs::write(&state_path, "{}").unwrap();
+        let backup_path = StateFile::backup_path(&state_path);
+        std::fs::write(&backup_path, "{}").unwrap();
 
         // .env and secrets/ live in the compose directory.
         std::fs::write(compose_dir.join(".env"), "K=V").unwrap();
@@ -301,6 +314,7 @@ mod tests {
         remove_clean_artifacts(&compose_dir, &state_path, &messages).unwrap();
 
         assert!(!state_path.exists(), "state.json should be deleted");
+        assert!(!backup_path.exists(), "state.json.bak should be deleted");
         assert!(
             !compose_dir.join(".env").exists(),
             ".env should be deleted from compose dir"
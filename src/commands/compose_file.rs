@@ -1,5 +1,46 @@
 use std::path::{Path, PathBuf};
 
+use anyhow::Result;
+
+use crate::commands::init::DEFAULT_COMPOSE_FILE;
+use crate::i18n::Messages;
+
+/// Compose file names searched when `--compose-file` is not given, in
+/// the order the first match wins.
+pub(crate) const COMPOSE_FILE_CANDIDATES: [&str; 4] = [
+    "compose.yaml",
+    "compose.yml",
+    "docker-compose.yml",
+    "docker-compose.yaml",
+];
+
+/// Returns the first [`COMPOSE_FILE_CANDIDATES`] entry that exists as a
+/// file in `dir`.
+pub(crate) fn find_compose_file(dir: &Path) -> Option<&'static str> {
+    COMPOSE_FILE_CANDIDATES
+        .into_iter()
+        .find(|name| dir.join(name).is_file())
+}
+
+/// Returns the `--compose-file` default: the first candidate found in the
+/// current directory, or `docker-compose.yml` when none exists so that
+/// [`ensure_compose_file_exists`] can report the miss.
+pub(crate) fn default_compose_file() -> PathBuf {
+    PathBuf::from(find_compose_file(Path::new(".")).unwrap_or(DEFAULT_COMPOSE_FILE))
+}
+
+/// Fails with an error naming the searched candidates when
+/// `compose_file` does not exist.
+pub(crate) fn ensure_compose_file_exists(compose_file: &Path, messages: &Messages) -> Result<()> {
+    if compose_file.is_file() {
+        return Ok(());
+    }
+    anyhow::bail!(messages.error_compose_file_not_found(
+        &compose_file.display().to_string(),
+        &COMPOSE_FILE_CANDIDATES.join(", ")
+    ))
+}
+
 /// Returns the directory containing `compose_file`, normalising the
 /// "no directory component" case to `"."`.
 ///
@@ -20,6 +61,24 @@ pub(crate) fn compose_file_dir(compose_file: &Path) -> PathBuf {
 mod tests {
     use super::*;
 
+    #[test]
+    fn compose_yaml_is_picked_without_docker_compose_yml() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("compose.yaml"), "services: {}\n").unwrap();
+        std::fs::write(dir.path().join("docker-compose.yaml"), "services: {}\n").unwrap();
+        assert_eq!(find_compose_file(dir.path()), Some("compose.yaml"));
+    }
+
+    #[test]
+    fn missing_compose_file_names_candidates() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(find_compose_file(dir.path()), None);
+        let messages = crate::i18n::test_messages();
+        let missing = dir.path().join(DEFAULT_COMPOSE_FILE);
+        let err = ensure_compose_file_exists(&missing, &messages).unwrap_err();
+        assert!(err.to_string().contains("compose.yaml, compose.yml"));
+    }
+
     #[test]
     fn empty_parent_becomes_dot() {
         assert_eq!(
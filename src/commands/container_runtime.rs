@@ -0,0 +1,121 @@
+//! Container runtime selection for `--container-runtime`.
+//!
+//! Every compose, inspect, and container command goes through
+//! [`command`], so choosing Podman only swaps the executable; its
+//! `compose` subcommand and `inspect --format` templates match Docker's
+//! for the calls bootroot makes.
+
+use std::borrow::Cow;
+use std::os::unix::process::CommandExt;
+use std::process::Command as ProcessCommand;
+use std::sync::OnceLock;
+
+use crate::cli::args::ContainerRuntime;
+
+static SELECTED: OnceLock<ContainerRuntime> = OnceLock::new();
+
+#[cfg(test)]
+thread_local! {
+    static TEST_OVERRIDE: std::cell::Cell<Option<ContainerRuntime>> =
+        const { std::cell::Cell::new(None) };
+}
+
+/// Records the runtime chosen on the command line. Only the first call
+/// takes effect; `main` makes it before any command runs.
+pub(crate) fn select(runtime: ContainerRuntime) {
+    let _ = SELECTED.set(runtime);
+}
+
+/// Returns the selected runtime, defaulting to Docker.
+pub(crate) fn selected() -> ContainerRuntime {
+    #[cfg(test)]
+    if let Some(runtime) = TEST_OVERRIDE.with(std::cell::Cell::get) {
+        return runtime;
+    }
+    SELECTED.get().copied().unwrap_or_default()
+}
+
+/// Starts a command for the selected runtime's executable.
+pub(crate) fn command() -> ProcessCommand {
+    detached_command(selected().binary())
+}
+
+/// Names a command in messages by the selected runtime's executable,
+/// rewriting a leading `docker` in `label` (for example `docker compose
+/// pull`).
+pub(crate) fn describe(label: &str) -> Cow<'_, str> {
+    match label.strip_prefix("docker") {
+        Some(rest) if rest.is_empty() || rest.starts_with(' ') => {
+            Cow::Owned(format!("{}{rest}", selected().binary()))
+        }
+        _ => Cow::Borrowed(label),
+    }
+}
+
+/// Starts `program` in its own process group.
+///
+/// A Ctrl-C at the terminal signals the whole foreground process group.
+/// Keeping container commands out of it lets the step in flight finish
+/// while the SIGINT handler only records the cancellation request.
+fn detached_command(program: &str) -> ProcessCommand {
+    let mut command = ProcessCommand::new(program);
+    command.process_group(0);
+    command
+}
+
+/// Overrides the runtime for the current test thread until dropped.
+#[cfg(test)]
+pub(crate) struct ScopedRuntime {
+    previous: Option<ContainerRuntime>,
+}
+
+#[cfg(test)]
+impl ScopedRuntime {
+    pub(crate) fn set(runtime: ContainerRuntime) -> Self {
+        let previous = TEST_OVERRIDE.with(|cell| cell.replace(Some(runtime)));
+        Self { previous }
+    }
+}
+
+#[cfg(test)]
+impl Drop for ScopedRuntime {
+    fn drop(&mut self) {
+        TEST_OVERRIDE.with(|cell| cell.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_uses_docker_by_default() {
+        assert_eq!(command().get_program(), "docker");
+    }
+
+    #[test]
+    fn detached_command_runs_in_its_own_process_group() {
+        let output = detached_command("sh")
+            .args(["-c", "echo $$ $(cut -d' ' -f5 /proc/$$/stat)"])
+            .output()
+            .expect("run sh");
+        let stdout = String::from_utf8(output.stdout).expect("utf-8 output");
+        let (pid, group) = stdout.trim().split_once(' ').expect("pid and pgid");
+        assert_eq!(pid, group, "child must lead its own process group");
+    }
+
+    #[test]
+    fn command_uses_selected_runtime_binary() {
+        let _runtime = ScopedRuntime::set(ContainerRuntime::Podman);
+        assert_eq!(command().get_program(), "podman");
+    }
+
+    #[test]
+    fn describe_names_the_selected_runtime() {
+        assert_eq!(describe("docker compose pull"), "docker compose pull");
+        let _runtime = ScopedRuntime::set(ContainerRuntime::Podman);
+        assert_eq!(describe("docker compose pull"), "podman compose pull");
+        assert_eq!(describe("docker"), "podman");
+        assert_eq!(describe("dockerd"), "dockerd");
+    }
+}
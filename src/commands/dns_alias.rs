@@ -146,8 +146,10 @@ fn apply_dns_aliases(aliases: &[String], messages: &Messages) -> Result<()> {
         return Ok(());
     }
 
+    // Progress goes to stderr so `service add --output json` keeps
+    // stdout parseable.
     for alias in aliases {
-        println!("{}", messages.dns_alias_registered(alias));
+        eprintln!("{}", messages.dns_alias_registered(alias));
     }
     Ok(())
 }
@@ -212,11 +214,14 @@ mod tests {
                 secret_id_ttl: None,
                 secret_id_wrap_ttl: None,
                 token_bound_cidrs: None,
+                approle_bound_cidrs: None,
             },
             agent_email: None,
             agent_server: None,
             agent_responder_url: None,
             cert_group_gid: None,
+            sync_generation: None,
+            trust_generation: None,
         }
     }
 
@@ -35,6 +35,10 @@ const HTTP01_TLS_CONTAINER_PREFIX: &str = "/app/bootroot-http01/";
 /// Default `OpenBao` API port used to identify the API listener block.
 const OPENBAO_API_PORT: &str = ":8200";
 
+/// step-ca address agents use when no `--stepca-bind` is recorded: the
+/// compose stack publishes step-ca on host loopback only.
+const DEFAULT_STEPCA_CLIENT_ADDR: &str = "localhost:9000";
+
 /// Returns whether a DB host is allowed under the single-host guardrail.
 #[must_use]
 pub(crate) fn is_single_host_db_host(host: &str) -> bool {
@@ -56,19 +60,120 @@ pub(crate) fn ensure_single_host_db_host(host: &str, messages: &Messages) -> Res
     }
 }
 
+/// Private CIDR accepted by `--allow-nonloopback-postgres`.
+///
+/// Only ranges entirely inside RFC 1918 (`10.0.0.0/8`, `172.16.0.0/12`,
+/// `192.168.0.0/16`) or IPv6 unique-local (`fc00::/7`) space parse, so
+/// a wildcard or public host bind can never fall inside the allowance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PrivateCidr {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl PrivateCidr {
+    /// Parses `value` as a CIDR confined to private address space.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `value` is not a valid CIDR or covers any
+    /// non-private address.
+    pub(crate) fn parse(value: &str, messages: &Messages) -> Result<Self> {
+        let invalid = || anyhow::anyhow!(messages.error_postgres_allowed_cidr_invalid(value));
+        bootroot::input_validation::validate_cidr(value).map_err(|_| invalid())?;
+        let (addr, prefix) = value.split_once('/').ok_or_else(invalid)?;
+        let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+        let prefix: u8 = prefix.parse().map_err(|_| invalid())?;
+        let cidr = Self {
+            network: mask_ip(addr, prefix),
+            prefix,
+        };
+        // A CIDR is an aligned block, so when both ends are private the
+        // whole block sits inside a single private range.
+        let last = last_ip(cidr.network, prefix);
+        if !is_private_ip(cidr.network) || !is_private_ip(last) {
+            return Err(invalid());
+        }
+        Ok(cidr)
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip) {
+            (IpAddr::V4(_), IpAddr::V4(_)) | (IpAddr::V6(_), IpAddr::V6(_)) => {
+                mask_ip(ip, self.prefix) == self.network
+            }
+            _ => false,
+        }
+    }
+}
+
+fn mask_ip(ip: IpAddr, prefix: u8) -> IpAddr {
+    match ip {
+        IpAddr::V4(v4) => {
+            let mask = u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+            IpAddr::V4((u32::from(v4) & mask).into())
+        }
+        IpAddr::V6(v6) => {
+            let mask = u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+            IpAddr::V6((u128::from(v6) & mask).into())
+        }
+    }
+}
+
+fn last_ip(network: IpAddr, prefix: u8) -> IpAddr {
+    match network {
+        IpAddr::V4(v4) => {
+            let host = u32::MAX.checked_shr(u32::from(prefix)).unwrap_or(0);
+            IpAddr::V4((u32::from(v4) | host).into())
+        }
+        IpAddr::V6(v6) => {
+            let host = u128::MAX.checked_shr(u32::from(prefix)).unwrap_or(0);
+            IpAddr::V6((u128::from(v6) | host).into())
+        }
+    }
+}
+
+fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_private(),
+        IpAddr::V6(v6) => v6.is_unique_local(),
+    }
+}
+
+/// Parses the optional `--allow-nonloopback-postgres` value.
+///
+/// # Errors
+///
+/// Returns an error when the value is not a private CIDR.
+pub(crate) fn parse_postgres_allowed_cidr(
+    value: Option<&str>,
+    messages: &Messages,
+) -> Result<Option<PrivateCidr>> {
+    value
+        .map(|value| PrivateCidr::parse(value, messages))
+        .transpose()
+}
+
 /// Ensures `PostgreSQL` is not published to non-localhost host interfaces.
 ///
+/// A host bind inside `allowed_cidr` is also accepted, so operators on
+/// a trusted internal network can publish `PostgreSQL` on a private
+/// interface without lifting the guardrail for wildcard or public binds.
+///
 /// # Errors
 ///
 /// Returns an error when the compose file publishes postgres with an unsafe
-/// host bind, such as `0.0.0.0:5432:5432` or `5432:5432`.
+/// host bind, such as `0.0.0.0:5432:5432` or `5432:5432`, or when
+/// `allowed_cidr` is not a private CIDR.
 pub(crate) fn ensure_postgres_localhost_binding(
     compose_file: &Path,
+    allowed_cidr: Option<&str>,
     messages: &Messages,
 ) -> Result<()> {
+    let allowed = parse_postgres_allowed_cidr(allowed_cidr, messages)?;
     let compose_contents = fs::read_to_string(compose_file)
         .with_context(|| messages.error_read_file_failed(&compose_file.display().to_string()))?;
-    if has_unsafe_port_binding_for_service(&compose_contents, "postgres:") {
+    if has_unsafe_port_binding_within(&compose_contents, "postgres:", allowed.as_ref()) {
         anyhow::bail!(messages.error_postgres_port_binding_unsafe());
     }
     Ok(())
@@ -76,18 +181,25 @@ pub(crate) fn ensure_postgres_localhost_binding(
 
 /// Ensures all guarded compose services publish ports only to localhost.
 ///
+/// `postgres_allowed_cidr` relaxes the check for the `postgres` service
+/// only; see [`ensure_postgres_localhost_binding`].
+///
 /// # Errors
 ///
 /// Returns an error naming the first service that publishes a port to a
-/// non-localhost host interface.
+/// non-localhost host interface, or when `postgres_allowed_cidr` is not
+/// a private CIDR.
 pub(crate) fn ensure_all_services_localhost_binding(
     compose_file: &Path,
+    postgres_allowed_cidr: Option<&str>,
     messages: &Messages,
 ) -> Result<()> {
+    let postgres_allowed = parse_postgres_allowed_cidr(postgres_allowed_cidr, messages)?;
     let compose_contents = fs::read_to_string(compose_file)
         .with_context(|| messages.error_read_file_failed(&compose_file.display().to_string()))?;
     for service in GUARDED_SERVICES {
-        if has_unsafe_port_binding_for_service(&compose_contents, service) {
+        let allowed = postgres_allowed.as_ref().filter(|_| service == "postgres:");
+        if has_unsafe_port_binding_within(&compose_contents, service, allowed) {
             let name = service.trim_end_matches(':');
             anyhow::bail!(messages.error_service_port_binding_unsafe(name));
         }
@@ -237,6 +349,25 @@ pub(crate) fn client_url_from_bind_addr(bind_addr: &str) -> String {
     format!("https://{client_ip}:{port}")
 }
 
+/// Derives the ACME directory URL agents on this host use to reach the
+/// step-ca `provisioner`.
+///
+/// Without a bind address, or with a wildcard one, agents reach step-ca
+/// through `localhost` (the name its certificate carries); a specific
+/// bind IP is used as-is.
+#[must_use]
+pub(crate) fn stepca_acme_directory_url(bind_addr: Option<&str>, provisioner: &str) -> String {
+    let port = bind_addr
+        .and_then(|addr| addr.rsplit_once(':'))
+        .map(|(_, port)| port);
+    let client_addr = match (bind_addr, port) {
+        (Some(addr), Some(_)) if !is_wildcard_bind(addr) => addr.to_string(),
+        (Some(_), Some(port)) => format!("localhost:{port}"),
+        _ => DEFAULT_STEPCA_CLIENT_ADDR.to_string(),
+    };
+    format!("https://{client_addr}/acme/{provisioner}/directory")
+}
+
 /// Validates the `--http01-admin-bind` CLI flag value.
 ///
 /// Reuses the same IP:port format and wildcard rules as `OpenBao` binding.
@@ -1205,6 +1336,34 @@ pub(crate) fn validate_openbao_override_binding(
     Ok(())
 }
 
+/// Returns the host IP and host port a compose service publishes for
+/// `container_port`.
+///
+/// Accepts `container`, `host:container`, and `ip:host:container` forms
+/// with an optional `/protocol` suffix; port ranges are ignored.  The IP
+/// is `None` for the forms without one and keeps the brackets of an IPv6
+/// literal.
+pub(crate) fn published_host_binding(
+    compose: &str,
+    service_key: &str,
+    container_port: u16,
+) -> Option<(Option<String>, u16)> {
+    collect_port_mappings_for_service(compose, service_key)
+        .iter()
+        .find_map(|mapping| {
+            let mapping = mapping.split('/').next().unwrap_or_default();
+            let (host, container) = mapping.rsplit_once(':')?;
+            if container.parse::<u16>().ok()? != container_port {
+                return None;
+            }
+            let (host_ip, host_port) = match host.rsplit_once(':') {
+                Some((ip, port)) => (Some(ip.to_string()), port),
+                None => (None, host),
+            };
+            Some((host_ip, host_port.parse().ok()?))
+        })
+}
+
 /// Collects raw port mapping values for a compose service.
 ///
 /// Returns each `- "host:container"` value with surrounding quotes
@@ -1268,6 +1427,16 @@ fn collect_port_mappings_for_service(compose: &str, service_key: &str) -> Vec<St
 }
 
 fn has_unsafe_port_binding_for_service(compose: &str, service_key: &str) -> bool {
+    has_unsafe_port_binding_within(compose, service_key, None)
+}
+
+/// Like [`has_unsafe_port_binding_for_service`], but treats a host bind
+/// to an IP inside `allowed` as safe.
+fn has_unsafe_port_binding_within(
+    compose: &str,
+    service_key: &str,
+    allowed: Option<&PrivateCidr>,
+) -> bool {
     let mut in_service = false;
     let mut service_indent = 0usize;
     let mut in_ports = false;
@@ -1301,7 +1470,7 @@ fn has_unsafe_port_binding_for_service(compose: &str, service_key: &str) -> bool
             let rest = trimmed.strip_prefix("ports:").unwrap_or("").trim();
             if let Some(values) = parse_inline_yaml_port_values(rest) {
                 for v in &values {
-                    if is_unsafe_port_mapping(v) {
+                    if is_unsafe_port_mapping(v) && !is_allowed_host_bind(v, allowed) {
                         return true;
                     }
                 }
@@ -1319,7 +1488,7 @@ fn has_unsafe_port_binding_for_service(compose: &str, service_key: &str) -> bool
 
         if in_ports && trimmed.starts_with('-') {
             let value = trimmed.trim_start_matches('-').trim();
-            if is_unsafe_port_mapping(value) {
+            if is_unsafe_port_mapping(value) && !is_allowed_host_bind(value, allowed) {
                 return true;
             }
         }
@@ -1374,6 +1543,24 @@ fn is_unsafe_port_mapping(raw: &str) -> bool {
     true
 }
 
+/// Returns whether a port mapping binds an explicit host IP inside
+/// `allowed` (e.g. `10.0.0.5:5432:5432` or `[fd00::5]:5432:5432`).
+fn is_allowed_host_bind(raw: &str, allowed: Option<&PrivateCidr>) -> bool {
+    let Some(allowed) = allowed else {
+        return false;
+    };
+    let mapping = raw.trim_matches('"').trim_matches('\'');
+    let host = if let Some(rest) = mapping.strip_prefix('[') {
+        rest.split_once(']').map(|(host, _)| host)
+    } else {
+        let parts: Vec<&str> = mapping.split(':').collect();
+        // Only `host_ip:host_port:container_port` names a host IP.
+        (parts.len() == 3).then(|| parts.first().copied()).flatten()
+    };
+    host.and_then(|host| host.parse::<IpAddr>().ok())
+        .is_some_and(|ip| allowed.contains(ip))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1404,6 +1591,92 @@ services:
         assert!(!has_unsafe_port_binding_for_service(compose, "postgres:"));
     }
 
+    fn postgres_compose(mapping: &str) -> String {
+        format!(
+            "services:\n  postgres:\n    image: postgres:18\n    ports:\n      - \"{mapping}\"\n"
+        )
+    }
+
+    #[test]
+    fn allowed_cidr_accepts_postgres_bind_inside_range() {
+        let messages = crate::i18n::test_messages();
+        let allowed = PrivateCidr::parse("10.20.0.0/16", &messages).unwrap();
+        let compose = postgres_compose("10.20.3.4:5432:5432");
+        assert!(has_unsafe_port_binding_for_service(&compose, "postgres:"));
+        assert!(!has_unsafe_port_binding_within(
+            &compose,
+            "postgres:",
+            Some(&allowed)
+        ));
+
+        let allowed_v6 = PrivateCidr::parse("fd00::/64", &messages).unwrap();
+        let compose_v6 = postgres_compose("[fd00::5]:5432:5432");
+        assert!(!has_unsafe_port_binding_within(
+            &compose_v6,
+            "postgres:",
+            Some(&allowed_v6)
+        ));
+    }
+
+    #[test]
+    fn allowed_cidr_still_rejects_public_and_wildcard_binds() {
+        let messages = crate::i18n::test_messages();
+        let allowed = PrivateCidr::parse("10.20.0.0/16", &messages).unwrap();
+        for mapping in [
+            "203.0.113.7:5432:5432",
+            "0.0.0.0:5432:5432",
+            "10.21.0.1:5432:5432",
+            "5432:5432",
+        ] {
+            assert!(
+                has_unsafe_port_binding_within(
+                    &postgres_compose(mapping),
+                    "postgres:",
+                    Some(&allowed)
+                ),
+                "{mapping} must stay rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn private_cidr_rejects_non_private_ranges() {
+        let messages = crate::i18n::test_messages();
+        for value in ["0.0.0.0/0", "203.0.113.0/24", "10.0.0.0/7", "::/0", "nope"] {
+            let err = PrivateCidr::parse(value, &messages).unwrap_err();
+            assert!(
+                err.to_string().contains("--allow-nonloopback-postgres"),
+                "{value}: {err}"
+            );
+        }
+        assert!(PrivateCidr::parse("172.16.0.0/12", &messages).is_ok());
+    }
+
+    #[test]
+    fn ensure_all_services_applies_allowance_to_postgres_only() {
+        let messages = crate::i18n::test_messages();
+        let dir = tempfile::tempdir().unwrap();
+        let compose_path = dir.path().join("docker-compose.yml");
+        std::fs::write(&compose_path, postgres_compose("192.168.10.5:5432:5432")).unwrap();
+        ensure_all_services_localhost_binding(&compose_path, Some("192.168.10.0/24"), &messages)
+            .unwrap();
+        assert!(ensure_all_services_localhost_binding(&compose_path, None, &messages).is_err());
+
+        std::fs::write(
+            &compose_path,
+            "services:\n  openbao:\n    ports:\n      - \"192.168.10.5:8200:8200\"\n",
+        )
+        .unwrap();
+        assert!(
+            ensure_all_services_localhost_binding(
+                &compose_path,
+                Some("192.168.10.0/24"),
+                &messages
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn has_unsafe_postgres_port_binding_rejects_all_interfaces() {
         let compose = r#"
@@ -1486,6 +1759,33 @@ services:
         assert_eq!(mappings, vec!["192.168.1.10:8200:8200"]);
     }
 
+    #[test]
+    fn published_host_binding_resolves_remapped_container_port() {
+        let compose = r#"
+services:
+  bootroot-http01:
+    ports:
+      - "127.0.0.1:80:80"
+      - "[::1]:9080:8080/tcp"
+"#;
+        assert_eq!(
+            published_host_binding(compose, "bootroot-http01:", 8080),
+            Some((Some("[::1]".to_string()), 9080))
+        );
+        assert_eq!(
+            published_host_binding(compose, "bootroot-http01:", 80),
+            Some((Some("127.0.0.1".to_string()), 80))
+        );
+        assert_eq!(
+            published_host_binding(compose, "bootroot-http01:", 443),
+            None
+        );
+        assert_eq!(
+            published_host_binding("services:\n  x:\n    ports: [\"9000:8080\"]\n", "x:", 8080),
+            Some((None, 9000))
+        );
+    }
+
     #[test]
     fn detects_unsafe_openbao_port_binding() {
         let compose = r#"
@@ -1665,6 +1965,22 @@ services:
         );
     }
 
+    #[test]
+    fn stepca_acme_directory_url_follows_bind_and_provisioner() {
+        assert_eq!(
+            stepca_acme_directory_url(None, "acme"),
+            "https://localhost:9000/acme/acme/directory"
+        );
+        assert_eq!(
+            stepca_acme_directory_url(Some("0.0.0.0:9443"), "edge"),
+            "https://localhost:9443/acme/edge/directory"
+        );
+        assert_eq!(
+            stepca_acme_directory_url(Some("192.168.1.10:9000"), "acme"),
+            "https://192.168.1.10:9000/acme/acme/directory"
+        );
+    }
+
     /// Generates a CA-signed server cert and root CA cert PEM pair.
     fn gen_ca_signed_cert_pair() -> (String, String, String) {
         use rcgen::{BasicConstraints, CertificateParams, DnType, IsCa, Issuer, KeyPair};
@@ -1,14 +1,19 @@
+use std::collections::BTreeMap;
 use std::io::IsTerminal;
 use std::os::unix::fs::MetadataExt;
 use std::path::{Path, PathBuf};
 use std::process::Command as ProcessCommand;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use bootroot::openbao::OpenBaoClient;
 
-use crate::cli::args::{InfraInstallArgs, InfraUpArgs};
+use crate::cli::args::{
+    InfraDownArgs, InfraInstallArgs, InfraLogsArgs, InfraRestartArgs, InfraUpArgs,
+};
+use crate::commands::cancellation;
 use crate::commands::constants::RESPONDER_SERVICE_NAME;
+use crate::commands::container_runtime;
 use crate::commands::dns_alias::replay_dns_aliases;
 use crate::commands::dotenv::write_dotenv;
 use crate::commands::guardrails::{
@@ -27,7 +32,7 @@ use crate::commands::init::{
     DEFAULT_KV_MOUNT, HTTP01_ADMIN_INFRA_CERT_KEY, HTTP01_EXPOSED_COMPOSE_OVERRIDE_NAME,
     OPENBAO_EXPOSED_COMPOSE_OVERRIDE_NAME, OPENBAO_INFRA_CERT_KEY, RESPONDER_COMPOSE_OVERRIDE_NAME,
     RESPONDER_CONFIG_DIR, STEPCA_EXPOSED_COMPOSE_OVERRIDE_NAME, compose_has_responder,
-    compose_has_stepca,
+    compose_has_stepca, ensure_compose_defines_services, prompt_yes_no,
 };
 use crate::commands::openbao_unseal::{prompt_unseal_keys_interactive, read_unseal_keys_from_file};
 use crate::i18n::Messages;
@@ -56,6 +61,17 @@ const STEPCA_USER_ENV_KEY: &str = "BOOTROOT_STEPCA_USER";
 const OPENBAO_API_WAIT_ATTEMPTS: u32 = 60;
 const OPENBAO_API_WAIT_DELAY: Duration = Duration::from_millis(500);
 
+/// Default `--readiness-timeout`: how long each service may take to report
+/// running/healthy after `docker compose up -d`.
+pub(crate) const DEFAULT_READINESS_TIMEOUT_SECS: u64 = 120;
+/// Default `--pull-retries`: extra `docker compose pull` attempts after a
+/// failed one.
+pub(crate) const DEFAULT_PULL_RETRIES: u32 = 3;
+/// Delay before the first pull retry; it doubles on each further retry.
+const PULL_RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+/// Default `infra up --poll-interval` and the fixed delay used elsewhere.
+pub(crate) const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Assembles the `docker compose up` argv for `infra install`.
 ///
 /// When `no_build` is `false` (the default), the command builds the
@@ -71,12 +87,18 @@ const OPENBAO_API_WAIT_DELAY: Duration = Duration::from_millis(500);
 /// reaches the network under an air-gapped install and would silently
 /// substitute a registry image for the preloaded/release payload. Plain
 /// `up` (no `--no-build`) would instead silently build a missing image.
+///
+/// Selected compose profiles are emitted as `--profile <name>` ahead of
+/// the `up` subcommand, where Compose expects its global flags.
 fn build_compose_up_args<'a>(
     compose_str: &'a str,
+    profiles: &'a [String],
     no_build: bool,
     svc_refs: &[&'a str],
 ) -> Vec<&'a str> {
-    let mut up_args: Vec<&str> = vec!["compose", "-f", compose_str, "up"];
+    let mut up_args: Vec<&str> = vec!["compose", "-f", compose_str];
+    up_args.extend(compose_profile_args(profiles));
+    up_args.push("up");
     if no_build {
         up_args.extend(["--no-build", "--pull", "never"]);
     } else {
@@ -87,6 +109,43 @@ fn build_compose_up_args<'a>(
     up_args
 }
 
+/// Assembles the `docker compose down` argv for `infra down`. Named
+/// volumes are removed only when `volumes` is set.
+fn build_compose_down_args<'a>(
+    compose_str: &'a str,
+    profiles: &'a [String],
+    volumes: bool,
+) -> Vec<&'a str> {
+    let mut down_args: Vec<&str> = vec!["compose", "-f", compose_str];
+    down_args.extend(compose_profile_args(profiles));
+    down_args.push("down");
+    if volumes {
+        down_args.push("--volumes");
+    }
+    down_args
+}
+
+/// Assembles the `docker compose logs` argv for `infra logs`.
+fn build_compose_logs_args<'a>(
+    compose_str: &'a str,
+    profiles: &'a [String],
+    follow: bool,
+    tail: Option<&'a str>,
+    services: &'a [String],
+) -> Vec<&'a str> {
+    let mut logs_args: Vec<&str> = vec!["compose", "-f", compose_str];
+    logs_args.extend(compose_profile_args(profiles));
+    logs_args.push("logs");
+    if follow {
+        logs_args.push("--follow");
+    }
+    if let Some(tail) = tail {
+        logs_args.extend(["--tail", tail]);
+    }
+    logs_args.extend(services.iter().map(String::as_str));
+    logs_args
+}
+
 /// Determines whether `infra install` runs the preliminary `docker compose
 /// pull --ignore-pull-failures` before `up`.
 ///
@@ -100,9 +159,64 @@ fn should_pull_before_up(loaded_archives: usize, no_build: bool) -> bool {
     loaded_archives == 0 && !no_build
 }
 
+/// Restart policies `docker update --restart` accepts without a retry
+/// count.
+const RESTART_POLICIES: &[&str] = &["no", "always", "unless-stopped", "on-failure"];
+
+/// Rejects a `--restart-policy` value docker would refuse, so a typo fails
+/// before any container is started instead of at the first `docker update`.
+///
+/// `on-failure:N` is accepted for any non-negative retry count `N`.
+fn validate_restart_policy(policy: &str, messages: &Messages) -> Result<()> {
+    let valid = RESTART_POLICIES.contains(&policy)
+        || policy
+            .strip_prefix("on-failure:")
+            .is_some_and(|count| count.parse::<u32>().is_ok());
+    if !valid {
+        anyhow::bail!(messages.error_restart_policy_invalid(policy));
+    }
+    Ok(())
+}
+
+/// Builds the `docker update` arguments that apply `restart_policy`
+/// (passed verbatim, including an `on-failure:N` retry count) to
+/// `container_id`.
+fn docker_update_args<'a>(restart_policy: &'a str, container_id: &'a str) -> [&'a str; 4] {
+    ["update", "--restart", restart_policy, container_id]
+}
+
+/// Expands compose profiles into the `--profile <name>` global flags
+/// that must precede the compose subcommand (`pull`, `up`, `ps`, ...).
+pub(crate) fn compose_profile_args(profiles: &[String]) -> Vec<&str> {
+    profiles
+        .iter()
+        .flat_map(|profile| ["--profile", profile.as_str()])
+        .collect()
+}
+
+/// Resolves the compose profiles an `infra up` run activates: the
+/// `--compose-profile` flags when given, otherwise the set recorded in
+/// `state.json` by the previous `infra install` / `infra up`.
+fn resolve_compose_profiles(requested: &[String], state_path: &Path) -> Vec<String> {
+    if !requested.is_empty() {
+        return requested.to_vec();
+    }
+    StateFile::load(state_path)
+        .map(|state| state.compose_profiles)
+        .unwrap_or_default()
+}
+
 #[allow(clippy::too_many_lines)]
 pub(crate) async fn run_infra_up(args: &InfraUpArgs, messages: &Messages) -> Result<()> {
-    ensure_all_services_localhost_binding(&args.compose_file.compose_file, messages)?;
+    validate_restart_policy(&args.restart_policy, messages)?;
+    let timeouts =
+        ReadinessTimeouts::parse(args.readiness_timeout, &args.service_timeout, messages)?;
+    ensure_compose_defines_services(&args.compose_file.compose_file, &args.services, messages)?;
+    ensure_all_services_localhost_binding(
+        &args.compose_file.compose_file,
+        args.allow_nonloopback_postgres.as_deref(),
+        messages,
+    )?;
 
     // Check for stored OpenBao non-loopback bind intent.
     let compose_dir = args
@@ -163,19 +277,23 @@ pub(crate) async fn run_infra_up(args: &InfraUpArgs, messages: &Messages) -> Res
         upsert_stepca_user_env(&compose_dir.join(".env"), &up_secrets_dir, messages)?;
     }
 
+    let compose_profiles = resolve_compose_profiles(&args.compose_profile, &state_path);
+    if !args.compose_profile.is_empty() {
+        save_compose_profiles_to(&state_path, &compose_profiles, &args.openbao_url, messages)?;
+    }
+
     let compose_str = args.compose_file.compose_file.to_string_lossy();
     let svc_refs: Vec<&str> = args.services.iter().map(String::as_str).collect();
 
     if loaded_archives == 0 {
-        let mut pull_args: Vec<&str> = vec![
-            "compose",
-            "-f",
-            &compose_str,
-            "pull",
-            "--ignore-pull-failures",
-        ];
+        let mut pull_args: Vec<&str> = vec!["compose", "-f", &compose_str];
+        pull_args.extend(compose_profile_args(&compose_profiles));
+        pull_args.extend(["pull", "--ignore-pull-failures"]);
         pull_args.extend(&svc_refs);
-        run_docker(&pull_args, "docker compose pull", messages)?;
+        retry_compose_pull(args.pull_retries, PULL_RETRY_BASE_DELAY, messages, || {
+            run_docker(&pull_args, "docker compose pull", messages)
+        })
+        .await?;
     }
 
     let openbao_override_str = openbao_override
@@ -205,9 +323,11 @@ pub(crate) async fn run_infra_up(args: &InfraUpArgs, messages: &Messages) -> Res
     if let Some(ref s) = stepca_override_str {
         up_args.extend(["-f", s.as_str()]);
     }
+    up_args.extend(compose_profile_args(&compose_profiles));
     up_args.extend(["up", "-d"]);
     up_args.extend(&svc_refs);
     run_docker(&up_args, "docker compose up", messages)?;
+    cancellation::global().check(messages)?;
 
     // Converge secrets ownership so an operator who upgrades and then only
     // ever runs `infra up` also repairs any root-owned CA material left by
@@ -247,7 +367,13 @@ pub(crate) async fn run_infra_up(args: &InfraUpArgs, messages: &Messages) -> Res
     // cert with `UnknownIssuer` on the reinit-recovery path where
     // OpenBao listens on `https://<non-loopback>:8200`.
     let effective_secrets_dir = resolve_effective_secrets_dir(&state_path, compose_dir);
-    if let Some(path) = unseal_file.as_deref() {
+    // `init --auto-unseal` recorded a transit/KMS seal, which unseals
+    // OpenBao on its own; its recovery keys cannot be submitted here.
+    let seal_unseals_itself = state_path.exists()
+        && StateFile::load(&state_path).is_ok_and(|state| state.openbao_auto_unseal);
+    if seal_unseals_itself {
+        println!("{}", messages.infra_openbao_auto_unseal_skipped());
+    } else if let Some(path) = unseal_file.as_deref() {
         auto_unseal_openbao(
             path,
             &effective_openbao_url,
@@ -265,21 +391,16 @@ pub(crate) async fn run_infra_up(args: &InfraUpArgs, messages: &Messages) -> Res
         )
         .await?;
     }
+    cancellation::global().check(messages)?;
 
-    let readiness = collect_readiness(
-        &args.compose_file.compose_file,
-        None,
-        &args.services,
-        messages,
-    )?;
+    let compose_file = &args.compose_file.compose_file;
+    let readiness = wait_for_readiness(&timeouts, args.poll_interval, messages, || {
+        probe_readiness(compose_file, &compose_profiles, &args.services, messages)
+    })
+    .await?;
 
     for entry in &readiness {
-        let update_args = [
-            "update",
-            "--restart",
-            &*args.restart_policy,
-            &*entry.container_id,
-        ];
+        let update_args = docker_update_args(&args.restart_policy, &entry.container_id);
         run_docker(&update_args, "docker update", messages)?;
     }
 
@@ -297,9 +418,83 @@ pub(crate) async fn run_infra_up(args: &InfraUpArgs, messages: &Messages) -> Res
     Ok(())
 }
 
+pub(crate) fn run_infra_down(args: &InfraDownArgs, messages: &Messages) -> Result<()> {
+    if args.volumes && !args.yes && !prompt_yes_no(messages.prompt_infra_down_volumes(), messages)?
+    {
+        anyhow::bail!(messages.error_operation_cancelled());
+    }
+    let compose_profiles =
+        resolve_compose_profiles(&args.compose_profile, &StateFile::default_path());
+    let compose_str = args.compose_file.compose_file.to_string_lossy();
+    let down_args = build_compose_down_args(&compose_str, &compose_profiles, args.volumes);
+    run_docker(&down_args, "docker compose down", messages)?;
+    println!("{}", messages.infra_down_completed());
+    Ok(())
+}
+
+pub(crate) async fn run_infra_restart(args: &InfraRestartArgs, messages: &Messages) -> Result<()> {
+    let services = if args.services.is_empty() {
+        default_infra_services()
+    } else {
+        args.services.clone()
+    };
+    let compose_file = &args.compose_file.compose_file;
+    ensure_compose_defines_services(compose_file, &services, messages)?;
+    let compose_profiles =
+        resolve_compose_profiles(&args.compose_profile, &StateFile::default_path());
+    let compose_str = compose_file.to_string_lossy();
+    let mut restart_args: Vec<&str> = vec!["compose", "-f", &compose_str];
+    restart_args.extend(compose_profile_args(&compose_profiles));
+    restart_args.push("restart");
+    restart_args.extend(services.iter().map(String::as_str));
+    run_docker(&restart_args, "docker compose restart", messages)?;
+
+    let timeouts = ReadinessTimeouts::parse(args.readiness_timeout, &[], messages)?;
+    let readiness = wait_for_readiness(&timeouts, READINESS_POLL_INTERVAL, messages, || {
+        probe_readiness(compose_file, &compose_profiles, &services, messages)
+    })
+    .await?;
+    print_readiness_summary(&readiness, messages);
+    ensure_all_healthy(&readiness, messages)?;
+    println!("{}", messages.infra_restart_completed());
+    Ok(())
+}
+
+/// Streams `docker compose logs` for the named services (all bootroot
+/// services by default) straight to the terminal.
+pub(crate) fn run_infra_logs(args: &InfraLogsArgs, messages: &Messages) -> Result<()> {
+    let services = if args.services.is_empty() {
+        default_infra_services()
+    } else {
+        args.services.clone()
+    };
+    let compose_file = &args.compose_file.compose_file;
+    ensure_compose_defines_services(compose_file, &services, messages)?;
+    let compose_profiles =
+        resolve_compose_profiles(&args.compose_profile, &StateFile::default_path());
+    let compose_str = compose_file.to_string_lossy();
+    let tail = args.tail.map(|lines| lines.to_string());
+    let logs_args = build_compose_logs_args(
+        &compose_str,
+        &compose_profiles,
+        args.follow,
+        tail.as_deref(),
+        &services,
+    );
+    run_docker(&logs_args, "docker compose logs", messages)
+}
+
 #[allow(clippy::too_many_lines)]
-pub(crate) fn run_infra_install(args: &InfraInstallArgs, messages: &Messages) -> Result<()> {
-    ensure_all_services_localhost_binding(&args.compose_file.compose_file, messages)?;
+pub(crate) async fn run_infra_install(args: &InfraInstallArgs, messages: &Messages) -> Result<()> {
+    validate_restart_policy(&args.restart_policy, messages)?;
+    let timeouts =
+        ReadinessTimeouts::parse(args.readiness_timeout, &args.service_timeout, messages)?;
+    ensure_compose_defines_services(&args.compose_file.compose_file, &args.services, messages)?;
+    ensure_all_services_localhost_binding(
+        &args.compose_file.compose_file,
+        args.allow_nonloopback_postgres.as_deref(),
+        messages,
+    )?;
 
     // Validate and resolve OpenBao non-loopback bind intent.
     let openbao_bind = if let Some(ref bind_addr) = args.openbao_bind {
@@ -484,6 +679,15 @@ pub(crate) fn run_infra_install(args: &InfraInstallArgs, messages: &Messages) ->
         clear_stepca_bind_intent(compose_dir, messages)?;
     }
 
+    // Record the selected compose profiles (or clear a stale set) so the
+    // latest install always reflects the operator's current intent.
+    save_compose_profiles_to(
+        &StateFile::default_path(),
+        &args.compose_profile,
+        &args.openbao_url,
+        messages,
+    )?;
+
     // Docker Compose reads .env from the compose file's directory.
     let env_path = compose_dir.join(".env");
     // step-ca runs as the `secrets/` owner; pin that uid/gid so a mount
@@ -568,13 +772,9 @@ pub(crate) fn run_infra_install(args: &InfraInstallArgs, messages: &Messages) ->
         .collect();
 
     if should_pull_before_up(loaded_archives, args.no_build) {
-        let mut pull_args: Vec<&str> = vec![
-            "compose",
-            "-f",
-            &compose_str,
-            "pull",
-            "--ignore-pull-failures",
-        ];
+        let mut pull_args: Vec<&str> = vec!["compose", "-f", &compose_str];
+        pull_args.extend(compose_profile_args(&args.compose_profile));
+        pull_args.extend(["pull", "--ignore-pull-failures"]);
         pull_args.extend(&svc_refs);
         run_docker_with_env(
             &pull_args,
@@ -588,7 +788,12 @@ pub(crate) fn run_infra_install(args: &InfraInstallArgs, messages: &Messages) ->
     // official prebuilt image and is pulled, not built. `--no-build` with
     // `--pull never` uses the pre-loaded images exactly as-is for an
     // air-gapped install, never reaching a registry.
-    let up_args = build_compose_up_args(&compose_str, args.no_build, &svc_refs);
+    let up_args = build_compose_up_args(
+        &compose_str,
+        &args.compose_profile,
+        args.no_build,
+        &svc_refs,
+    );
     let up_label = if args.no_build {
         "docker compose up --no-build --pull never"
     } else {
@@ -613,20 +818,19 @@ pub(crate) fn run_infra_install(args: &InfraInstallArgs, messages: &Messages) ->
         .filter(|s| s.as_str() != "step-ca")
         .cloned()
         .collect();
-    let readiness = collect_readiness(
-        &args.compose_file.compose_file,
-        None,
-        &prereq_services,
-        messages,
-    )?;
+    let compose_file = &args.compose_file.compose_file;
+    let readiness = wait_for_readiness(&timeouts, READINESS_POLL_INTERVAL, messages, || {
+        probe_readiness(
+            compose_file,
+            &args.compose_profile,
+            &prereq_services,
+            messages,
+        )
+    })
+    .await?;
 
     for entry in &readiness {
-        let update_args = [
-            "update",
-            "--restart",
-            &*args.restart_policy,
-            &*entry.container_id,
-        ];
+        let update_args = docker_update_args(&args.restart_policy, &entry.container_id);
         run_docker(&update_args, "docker update", messages)?;
     }
 
@@ -635,17 +839,12 @@ pub(crate) fn run_infra_install(args: &InfraInstallArgs, messages: &Messages) ->
         let stepca_services = vec!["step-ca".to_string()];
         if let Ok(stepca_readiness) = collect_readiness(
             &args.compose_file.compose_file,
-            None,
+            &args.compose_profile,
             &stepca_services,
             messages,
         ) {
             for entry in &stepca_readiness {
-                let update_args = [
-                    "update",
-                    "--restart",
-                    &*args.restart_policy,
-                    &*entry.container_id,
-                ];
+                let update_args = docker_update_args(&args.restart_policy, &entry.container_id);
                 let _ = run_docker(&update_args, "docker update", messages);
             }
         }
@@ -706,8 +905,8 @@ fn build_ownership_sweep_args<'a>(
         "--user",
         "root",
         // Run `chown` directly instead of through the image's default
-        // entrypoint. The `rotate` flows reuse the `smallstep/step-ca:0.30.2`
-        // helper image here, whose entrypoint would otherwise print a
+        // entrypoint. The `rotate` flows reuse the step-ca helper image
+        // recorded at init here, whose entrypoint would otherwise print a
         // spurious "there is no ca.json config file" warning — the sweep
         // deliberately mounts only the secrets subtree, not `/home/step`.
         // Overriding the entrypoint keeps the sweep visibly just a scoped
@@ -736,7 +935,7 @@ fn build_ownership_sweep_args<'a>(
 /// already exists.
 fn resolve_stepca_image(compose_file: &Path, messages: &Messages) -> Result<String> {
     let container_id =
-        docker_compose_output(compose_file, None, &["ps", "-a", "-q", "step-ca"], messages)?;
+        docker_compose_output(compose_file, &[], &["ps", "-a", "-q", "step-ca"], messages)?;
     let container_id = container_id.trim();
     if container_id.is_empty() {
         anyhow::bail!(messages.error_service_no_container("step-ca"));
@@ -763,8 +962,8 @@ fn resolve_stepca_image(compose_file: &Path, messages: &Messages) -> Result<Stri
 /// image the flow already has on hand so the sweep introduces no new
 /// dependency: the `infra` flows resolve the compose step-ca server image
 /// (see [`resolve_stepca_image`]) after `up` has made it available, while
-/// the `rotate` flows pass the same `smallstep/step-ca:0.30.2` image their
-/// `step` helpers already run.
+/// the `rotate` flows pass the step-ca image recorded at init, which
+/// their `step` helpers already run.
 pub(crate) fn sweep_secrets_ownership(
     secrets_dir: &Path,
     image: &str,
@@ -925,7 +1124,7 @@ fn best_effort_listening_pid(port: u16) -> Option<String> {
 /// it may not be bootstrapped yet during the `init` flow.
 pub(crate) fn ensure_init_prereqs_ready(compose_file: &Path, messages: &Messages) -> Result<()> {
     let services = vec!["openbao".to_string(), "postgres".to_string()];
-    let readiness = collect_readiness(compose_file, None, &services, messages)?;
+    let readiness = collect_readiness(compose_file, &[], &services, messages)?;
     ensure_all_healthy(&readiness, messages)?;
     Ok(())
 }
@@ -992,7 +1191,9 @@ async fn wait_for_openbao_api_reachable(client: &OpenBaoClient, messages: &Messa
                 }
             }
         }
-        tokio::time::sleep(OPENBAO_API_WAIT_DELAY).await;
+        cancellation::global()
+            .sleep(OPENBAO_API_WAIT_DELAY, messages)
+            .await?;
     }
     unreachable!("loop above returns on every iteration")
 }
@@ -1656,6 +1857,36 @@ fn clear_stepca_bind_intent_to(
     Ok(())
 }
 
+/// Persists the selected compose profiles to `StateFile` so later
+/// `infra up`, `status`, and `rotate` runs replay the same `--profile`
+/// set. An empty selection clears previously stored profiles; no state
+/// file is created merely to record an empty set.
+fn save_compose_profiles_to(
+    state_path: &Path,
+    profiles: &[String],
+    openbao_url: &str,
+    messages: &Messages,
+) -> Result<()> {
+    let mut state = if state_path.exists() {
+        StateFile::load(state_path)?
+    } else if profiles.is_empty() {
+        return Ok(());
+    } else {
+        StateFile {
+            openbao_url: openbao_url.to_string(),
+            kv_mount: DEFAULT_KV_MOUNT.to_string(),
+            ..Default::default()
+        }
+    };
+    if state.compose_profiles == profiles {
+        return Ok(());
+    }
+    state.compose_profiles = profiles.to_vec();
+    state
+        .save(state_path)
+        .with_context(|| messages.error_serialize_state_failed())
+}
+
 pub(crate) fn default_infra_services() -> Vec<String> {
     vec![
         "openbao".to_string(),
@@ -1673,38 +1904,225 @@ pub(crate) struct ContainerReadiness {
     pub(crate) health: Option<String>,
 }
 
+impl ContainerReadiness {
+    /// Returns whether the container is running and, when it has a
+    /// healthcheck, reports healthy.
+    fn is_ready(&self) -> bool {
+        self.status == "running"
+            && self
+                .health
+                .as_deref()
+                .is_none_or(|health| health == "healthy")
+    }
+}
+
+/// How long each service may take to become ready: the global
+/// `--readiness-timeout` unless a `--service-timeout` entry names it.
+#[derive(Debug)]
+struct ReadinessTimeouts {
+    default: Duration,
+    per_service: BTreeMap<String, Duration>,
+}
+
+impl ReadinessTimeouts {
+    /// Parses the repeatable `--service-timeout <service>=<seconds>`
+    /// values on top of the global default.
+    fn parse(default_secs: u64, overrides: &[String], messages: &Messages) -> Result<Self> {
+        let mut per_service = BTreeMap::new();
+        for value in overrides {
+            let Some((service, secs)) = value.split_once('=') else {
+                anyhow::bail!(messages.error_service_timeout_invalid(value));
+            };
+            let service = service.trim();
+            let Ok(secs) = secs.trim().parse::<u64>() else {
+                anyhow::bail!(messages.error_service_timeout_invalid(value));
+            };
+            if service.is_empty() {
+                anyhow::bail!(messages.error_service_timeout_invalid(value));
+            }
+            per_service.insert(service.to_string(), Duration::from_secs(secs));
+        }
+        Ok(Self {
+            default: Duration::from_secs(default_secs),
+            per_service,
+        })
+    }
+
+    fn for_service(&self, service: &str) -> Duration {
+        self.per_service
+            .get(service)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// Polls `probe` until every service is ready or one that is not has
+/// used up its own timeout, then returns the last readiness snapshot for
+/// [`ensure_all_healthy`] to judge.
+///
+/// A slow service with a longer `--service-timeout` keeps the loop
+/// going, while a fast service that misses its shorter deadline ends it
+/// without waiting for the slow one.
+async fn wait_for_readiness<F>(
+    timeouts: &ReadinessTimeouts,
+    poll_interval: Duration,
+    messages: &Messages,
+    mut probe: impl FnMut() -> F,
+) -> Result<Vec<ContainerReadiness>>
+where
+    F: Future<Output = Result<Vec<ContainerReadiness>>>,
+{
+    let started = Instant::now();
+    loop {
+        let readiness = probe().await?;
+        let elapsed = started.elapsed();
+        let mut pending = readiness
+            .iter()
+            .filter(|entry| !entry.is_ready())
+            .peekable();
+        if pending.peek().is_none()
+            || pending.any(|entry| elapsed >= timeouts.for_service(&entry.service))
+        {
+            return Ok(readiness);
+        }
+        cancellation::global()
+            .sleep(poll_interval, messages)
+            .await?;
+    }
+}
+
+/// Runs the `docker compose pull` in `attempt`, retrying up to `retries`
+/// more times after a failure with a delay that starts at `base_delay` and
+/// doubles each time, so a registry hiccup does not abort `infra up`.
+///
+/// The last failure is returned as-is.
+async fn retry_compose_pull(
+    retries: u32,
+    base_delay: Duration,
+    messages: &Messages,
+    mut attempt: impl FnMut() -> Result<()>,
+) -> Result<()> {
+    let mut delay = base_delay;
+    for retry in 1..=retries {
+        let Err(err) = attempt() else {
+            return Ok(());
+        };
+        eprintln!(
+            "{}",
+            messages.warning_infra_pull_retry(
+                container_runtime::selected().binary(),
+                &retry.to_string(),
+                &retries.to_string(),
+                &humantime::format_duration(delay).to_string(),
+                &format!("{err:#}")
+            )
+        );
+        cancellation::global().sleep(delay, messages).await?;
+        delay = delay.saturating_mul(2);
+    }
+    attempt()
+}
+
+/// Polls a single compose `service` until it is ready or `timeout`
+/// elapses, failing with its last observed state when it never recovers.
+pub(crate) async fn wait_for_service_ready(
+    compose_file: &Path,
+    profiles: &[String],
+    service: &str,
+    timeout: Duration,
+    messages: &Messages,
+) -> Result<()> {
+    let timeouts = ReadinessTimeouts {
+        default: timeout,
+        per_service: BTreeMap::new(),
+    };
+    let services = [service.to_string()];
+    let readiness = wait_for_readiness(&timeouts, READINESS_POLL_INTERVAL, messages, || {
+        probe_readiness(compose_file, profiles, &services, messages)
+    })
+    .await?;
+    ensure_all_healthy(&readiness, messages)
+}
+
+/// Runs [`collect_readiness`] on the blocking pool, so a readiness wait
+/// keeps its task free to observe `--operation-timeout` and Ctrl-C while
+/// the container CLI runs.
+async fn probe_readiness(
+    compose_file: &Path,
+    profiles: &[String],
+    services: &[String],
+    messages: &Messages,
+) -> Result<Vec<ContainerReadiness>> {
+    let compose_file = compose_file.to_path_buf();
+    let profiles = profiles.to_vec();
+    let services = services.to_vec();
+    let messages = messages.clone();
+    tokio::task::spawn_blocking(move || {
+        collect_readiness(&compose_file, &profiles, &services, &messages)
+    })
+    .await
+    .map_err(|e| anyhow::anyhow!("spawn_blocking for readiness probe failed: {e}"))?
+}
+
+/// Inspects each compose service's container, one thread per service,
+/// returning entries in `services` order whatever order they finish in.
+///
+/// When several services fail, the error for the earliest one in
+/// `services` is returned.
 pub(crate) fn collect_readiness(
     compose_file: &Path,
-    profile: Option<&str>,
+    profiles: &[String],
     services: &[String],
     messages: &Messages,
 ) -> Result<Vec<ContainerReadiness>> {
-    let mut readiness = Vec::with_capacity(services.len());
-    for service in services {
-        let container_id =
-            docker_compose_output(compose_file, profile, &["ps", "-q", service], messages)?;
-        let container_id = container_id.trim().to_string();
-        if container_id.is_empty() {
-            anyhow::bail!(messages.error_service_no_container(service));
-        }
-        let inspect_output = docker_output(
-            &[
-                "inspect",
-                "--format",
-                "{{.State.Status}}|{{if .State.Health}}{{.State.Health.Status}}{{end}}",
-                &container_id,
-            ],
-            messages,
-        )?;
-        let (status, health) = parse_container_state(&inspect_output);
-        readiness.push(ContainerReadiness {
-            service: service.clone(),
-            container_id,
-            status,
-            health,
-        });
-    }
-    Ok(readiness)
+    std::thread::scope(|scope| {
+        let probes: Vec<_> = services
+            .iter()
+            .map(|service| {
+                scope.spawn(move || {
+                    inspect_service_readiness(compose_file, profiles, service, messages)
+                })
+            })
+            .collect();
+        probes
+            .into_iter()
+            .map(|probe| {
+                probe
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+fn inspect_service_readiness(
+    compose_file: &Path,
+    profiles: &[String],
+    service: &str,
+    messages: &Messages,
+) -> Result<ContainerReadiness> {
+    let container_id =
+        docker_compose_output(compose_file, profiles, &["ps", "-q", service], messages)?;
+    let container_id = container_id.trim().to_string();
+    if container_id.is_empty() {
+        anyhow::bail!(messages.error_service_no_container(service));
+    }
+    let inspect_output = docker_output(
+        &[
+            "inspect",
+            "--format",
+            "{{.State.Status}}|{{if .State.Health}}{{.State.Health.Status}}{{end}}",
+            &container_id,
+        ],
+        messages,
+    )?;
+    let (status, health) = parse_container_state(&inspect_output);
+    Ok(ContainerReadiness {
+        service: service.to_string(),
+        container_id,
+        status,
+        health,
+    })
 }
 
 pub(crate) fn parse_container_state(raw: &str) -> (String, Option<String>) {
@@ -1808,36 +2226,32 @@ pub(crate) fn run_docker_with_env(
     context: &str,
     messages: &Messages,
 ) -> Result<()> {
-    let mut cmd = ProcessCommand::new("docker");
+    let mut cmd = container_runtime::command();
     cmd.args(args);
     for (key, value) in env {
         cmd.env(key, value);
     }
+    let context = container_runtime::describe(context);
     let status = cmd
         .status()
-        .with_context(|| messages.error_command_run_failed(context))?;
+        .with_context(|| messages.error_command_run_failed(&context))?;
     if !status.success() {
-        anyhow::bail!(messages.error_command_failed_status(context, &status.to_string()));
+        anyhow::bail!(messages.error_command_failed_status(&context, &status.to_string()));
     }
     Ok(())
 }
 
 pub(crate) fn docker_compose_output(
     compose_file: &Path,
-    profile: Option<&str>,
+    profiles: &[String],
     args: &[&str],
     messages: &Messages,
 ) -> Result<String> {
-    let compose_str = compose_file.to_string_lossy();
-    let mut cmd = ProcessCommand::new("docker");
-    cmd.args(["compose", "-f", compose_str.as_ref()]);
-    if let Some(profile) = profile {
-        cmd.args(["--profile", profile]);
-    }
+    let mut cmd = compose_command(compose_file, profiles);
     cmd.args(args);
-    let output = cmd
-        .output()
-        .with_context(|| messages.error_command_run_failed("docker compose"))?;
+    let output = cmd.output().with_context(|| {
+        messages.error_command_run_failed(&container_runtime::describe("docker compose"))
+    })?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         anyhow::bail!(messages.error_docker_compose_failed(&stderr));
@@ -1845,11 +2259,21 @@ pub(crate) fn docker_compose_output(
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
+/// Starts a `<runtime> compose -f <file> [--profile ...]` command.
+fn compose_command(compose_file: &Path, profiles: &[String]) -> ProcessCommand {
+    let mut cmd = container_runtime::command();
+    cmd.arg("compose").arg("-f").arg(compose_file);
+    cmd.args(compose_profile_args(profiles));
+    cmd
+}
+
 pub(crate) fn docker_output(args: &[&str], messages: &Messages) -> Result<String> {
-    let output = ProcessCommand::new("docker")
+    let output = container_runtime::command()
         .args(args)
         .output()
-        .with_context(|| messages.error_command_run_failed("docker"))?;
+        .with_context(|| {
+            messages.error_command_run_failed(container_runtime::selected().binary())
+        })?;
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         anyhow::bail!(messages.error_docker_command_failed(&stderr));
@@ -1862,6 +2286,7 @@ mod tests {
     use tempfile::tempdir;
 
     use super::*;
+    use crate::cli::args::ContainerRuntime;
     use crate::i18n::test_messages;
 
     const COMPOSE_WITH_STEPCA: &str = "services:\n  openbao:\n    image: openbao/openbao\n  \
@@ -1980,7 +2405,7 @@ mod tests {
     #[test]
     fn build_compose_up_args_defaults_to_build() {
         let svc_refs = ["openbao", "postgres"];
-        let args = build_compose_up_args("docker-compose.yml", false, &svc_refs);
+        let args = build_compose_up_args("docker-compose.yml", &[], false, &svc_refs);
         assert_eq!(
             args,
             vec![
@@ -1999,7 +2424,7 @@ mod tests {
     #[test]
     fn build_compose_up_args_no_build_selects_no_build_flag() {
         let svc_refs = ["openbao", "postgres"];
-        let args = build_compose_up_args("docker-compose.deploy.yml", true, &svc_refs);
+        let args = build_compose_up_args("docker-compose.deploy.yml", &[], true, &svc_refs);
         assert_eq!(
             args,
             vec![
@@ -2018,6 +2443,56 @@ mod tests {
         assert!(!args.contains(&"--build"));
     }
 
+    #[test]
+    fn build_compose_up_args_places_profiles_before_up() {
+        let svc_refs = ["openbao", "prometheus"];
+        let profiles = vec!["monitoring".to_string(), "lan".to_string()];
+        let args = build_compose_up_args("docker-compose.yml", &profiles, false, &svc_refs);
+        assert_eq!(
+            args,
+            vec![
+                "compose",
+                "-f",
+                "docker-compose.yml",
+                "--profile",
+                "monitoring",
+                "--profile",
+                "lan",
+                "up",
+                "--build",
+                "-d",
+                "openbao",
+                "prometheus",
+            ]
+        );
+    }
+
+    #[test]
+    fn save_compose_profiles_round_trips_and_clears() {
+        let messages = test_messages();
+        let dir = tempdir().expect("tempdir");
+        let state_path = dir.path().join("state.json");
+
+        // Nothing recorded yet and nothing selected: no state file created.
+        save_compose_profiles_to(&state_path, &[], "http://localhost:8200", &messages)
+            .expect("no-op save");
+        assert!(!state_path.exists());
+        assert!(resolve_compose_profiles(&[], &state_path).is_empty());
+
+        let profiles = vec!["monitoring".to_string()];
+        save_compose_profiles_to(&state_path, &profiles, "http://localhost:8200", &messages)
+            .expect("save profiles");
+        assert_eq!(resolve_compose_profiles(&[], &state_path), profiles);
+
+        // Explicit flags win over the recorded set.
+        let explicit = vec!["debug".to_string()];
+        assert_eq!(resolve_compose_profiles(&explicit, &state_path), explicit);
+
+        save_compose_profiles_to(&state_path, &[], "http://localhost:8200", &messages)
+            .expect("clear profiles");
+        assert!(resolve_compose_profiles(&[], &state_path).is_empty());
+    }
+
     #[test]
     fn should_pull_before_up_pulls_only_without_archives_or_no_build() {
         // Default build path with no local archives: refresh floating tags.
@@ -2031,6 +2506,203 @@ mod tests {
         assert!(!should_pull_before_up(2, true));
     }
 
+    #[test]
+    fn validate_restart_policy_accepts_docker_policies() {
+        let messages = test_messages();
+        for policy in [
+            "no",
+            "always",
+            "unless-stopped",
+            "on-failure",
+            "on-failure:5",
+        ] {
+            validate_restart_policy(policy, &messages)
+                .unwrap_or_else(|err| panic!("{policy} rejected: {err}"));
+        }
+    }
+
+    #[test]
+    fn validate_restart_policy_rejects_unknown_values() {
+        let messages = test_messages();
+        for policy in ["sometimes", "on-failure:", "on-failure:-1", "Always"] {
+            let err = validate_restart_policy(policy, &messages).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                messages.error_restart_policy_invalid(policy)
+            );
+        }
+    }
+
+    fn readiness(service: &str, health: &str) -> ContainerReadiness {
+        ContainerReadiness {
+            service: service.to_string(),
+            container_id: format!("{service}-id"),
+            status: "running".to_string(),
+            health: Some(health.to_string()),
+        }
+    }
+
+    #[test]
+    fn readiness_timeouts_parse_service_overrides() {
+        let messages = test_messages();
+        let timeouts = ReadinessTimeouts::parse(
+            30,
+            &[
+                "step-ca=180".to_string(),
+                " bootroot-http01 = 5 ".to_string(),
+            ],
+            &messages,
+        )
+        .unwrap();
+        assert_eq!(timeouts.for_service("step-ca"), Duration::from_mins(3));
+        assert_eq!(
+            timeouts.for_service("bootroot-http01"),
+            Duration::from_secs(5)
+        );
+        assert_eq!(timeouts.for_service("openbao"), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn readiness_timeouts_reject_malformed_pairs() {
+        let messages = test_messages();
+        for value in ["step-ca", "=30", "step-ca=", "step-ca=-1", "step-ca=1m"] {
+            let err = ReadinessTimeouts::parse(30, &[value.to_string()], &messages).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                messages.error_service_timeout_invalid(value)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_compose_pull_retries_until_success_or_budget_spent() {
+        let messages = test_messages();
+        let mut attempts = 0;
+        retry_compose_pull(3, Duration::ZERO, &messages, || {
+            attempts += 1;
+            if attempts < 3 {
+                anyhow::bail!("registry unavailable");
+            }
+            Ok(())
+        })
+        .await
+        .unwrap();
+        assert_eq!(attempts, 3);
+
+        let mut attempts = 0;
+        let err = retry_compose_pull(1, Duration::ZERO, &messages, || {
+            attempts += 1;
+            anyhow::bail!("registry unavailable")
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(attempts, 2);
+        assert!(err.to_string().contains("registry unavailable"));
+    }
+
+    #[tokio::test]
+    async fn wait_for_readiness_honors_per_service_override() {
+        let messages = test_messages();
+        // The global timeout is exhausted immediately; only step-ca's
+        // override keeps the loop polling until it turns healthy.
+        let timeouts = ReadinessTimeouts::parse(0, &["step-ca=60".to_string()], &messages).unwrap();
+        let mut probes = 0;
+        let result = wait_for_readiness(&timeouts, Duration::from_millis(1), &messages, || {
+            probes += 1;
+            let stepca = if probes < 3 { "starting" } else { "healthy" };
+            std::future::ready(Ok(vec![
+                readiness("bootroot-http01", "healthy"),
+                readiness("step-ca", stepca),
+            ]))
+        })
+        .await
+        .unwrap();
+        assert_eq!(probes, 3);
+        assert!(collect_container_failures(&result).is_empty());
+    }
+
+    #[tokio::test]
+    async fn wait_for_readiness_fails_fast_for_service_without_override() {
+        let messages = test_messages();
+        let timeouts = ReadinessTimeouts::parse(0, &["step-ca=60".to_string()], &messages).unwrap();
+        let mut probes = 0;
+        let result = wait_for_readiness(&timeouts, Duration::from_millis(1), &messages, || {
+            probes += 1;
+            std::future::ready(Ok(vec![
+                readiness("bootroot-http01", "unhealthy"),
+                readiness("step-ca", "starting"),
+            ]))
+        })
+        .await
+        .unwrap();
+        assert_eq!(probes, 1);
+        assert_eq!(
+            collect_container_failures(&result),
+            vec![
+                "bootroot-http01 health=unhealthy".to_string(),
+                "step-ca health=starting".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn compose_down_args_keep_volumes_by_default() {
+        let profiles = vec!["monitoring".to_string()];
+        assert_eq!(
+            build_compose_down_args("compose.yml", &profiles, false),
+            [
+                "compose",
+                "-f",
+                "compose.yml",
+                "--profile",
+                "monitoring",
+                "down"
+            ]
+        );
+    }
+
+    #[test]
+    fn compose_down_args_remove_volumes_when_requested() {
+        assert_eq!(
+            build_compose_down_args("compose.yml", &[], true),
+            ["compose", "-f", "compose.yml", "down", "--volumes"]
+        );
+    }
+
+    #[test]
+    fn compose_logs_args_include_follow_and_tail() {
+        let profiles = vec!["monitoring".to_string()];
+        let services = vec!["step-ca".to_string(), "openbao".to_string()];
+        assert_eq!(
+            build_compose_logs_args("compose.yml", &profiles, true, Some("100"), &services),
+            [
+                "compose",
+                "-f",
+                "compose.yml",
+                "--profile",
+                "monitoring",
+                "logs",
+                "--follow",
+                "--tail",
+                "100",
+                "step-ca",
+                "openbao",
+            ]
+        );
+        assert_eq!(
+            build_compose_logs_args("compose.yml", &[], false, None, &services),
+            ["compose", "-f", "compose.yml", "logs", "step-ca", "openbao"]
+        );
+    }
+
+    #[test]
+    fn docker_update_args_pass_on_failure_count_verbatim() {
+        assert_eq!(
+            docker_update_args("on-failure:5", "abc123"),
+            ["update", "--restart", "on-failure:5", "abc123"]
+        );
+    }
+
     #[test]
     fn resolve_effective_secrets_dir_returns_none_without_state() {
         let dir = tempfile::tempdir().unwrap();
@@ -2126,6 +2798,32 @@ mod tests {
         assert!(health.is_none());
     }
 
+    #[test]
+    fn test_parse_container_state_podman_starting_health() {
+        let (status, health) = parse_container_state("running|starting\n");
+        assert_eq!(status, "running");
+        assert_eq!(health.as_deref(), Some("starting"));
+    }
+
+    #[test]
+    fn compose_command_uses_selected_container_runtime() {
+        let _runtime = container_runtime::ScopedRuntime::set(ContainerRuntime::Podman);
+        let profiles = vec!["edge".to_string()];
+        let cmd = compose_command(Path::new("docker-compose.yml"), &profiles);
+        assert_eq!(cmd.get_program(), "podman");
+        let args: Vec<_> = cmd.get_args().collect();
+        assert_eq!(
+            args,
+            ["compose", "-f", "docker-compose.yml", "--profile", "edge"]
+        );
+    }
+
+    #[test]
+    fn compose_command_defaults_to_docker() {
+        let cmd = compose_command(Path::new("docker-compose.yml"), &[]);
+        assert_eq!(cmd.get_program(), "docker");
+    }
+
     /// Closes #588 §4a: a port collision on the host-side `PostgreSQL`
     /// publish must abort with an actionable message before
     /// `docker compose up` half-creates containers.
@@ -4281,8 +4979,8 @@ tls_key_path = \"/app/bootroot-http01/tls/server.key\"
     /// Regression: `infra install --http01-admin-bind` must reject
     /// early when the compose file lacks `bootroot-http01`, and must
     /// not leave behind any `OpenBao` state from other flags.
-    #[test]
-    fn infra_install_rejects_http01_admin_bind_without_responder() {
+    #[tokio::test]
+    async fn infra_install_rejects_http01_admin_bind_without_responder() {
         use crate::cli::args::ComposeFileArgs;
 
         let messages = crate::i18n::test_messages();
@@ -4295,9 +4993,12 @@ tls_key_path = \"/app/bootroot-http01/tls/server.key\"
             compose_file: ComposeFileArgs {
                 compose_file: compose_path,
             },
+            allow_nonloopback_postgres: None,
             services: vec!["openbao".to_string()],
             image_archive_dir: None,
             restart_policy: "always".to_string(),
+            readiness_timeout: DEFAULT_READINESS_TIMEOUT_SECS,
+            service_timeout: Vec::new(),
             openbao_url: "http://localhost:8200".to_string(),
             openbao_bind: None,
             openbao_tls_required: false,
@@ -4312,8 +5013,9 @@ tls_key_path = \"/app/bootroot-http01/tls/server.key\"
             stepca_advertise_addr: None,
             postgres_host_port: None,
             no_build: false,
+            compose_profile: Vec::new(),
         };
-        let err = run_infra_install(&args, &messages).unwrap_err();
+        let err = run_infra_install(&args, &messages).await.unwrap_err();
         let msg = err.to_string();
         assert!(
             msg.contains("bootroot-http01"),
@@ -1,8 +1,21 @@
 pub(crate) const DEFAULT_OPENBAO_URL: &str = "http://localhost:8200";
 pub(crate) const DEFAULT_KV_MOUNT: &str = "secret";
+/// Path prefix every bootroot KV secret lives under within the mount.
+pub(crate) const DEFAULT_KV_PATH_PREFIX: &str = "bootroot";
 pub(crate) const DEFAULT_SECRETS_DIR: &str = "secrets";
 pub(crate) const DEFAULT_COMPOSE_FILE: &str = "docker-compose.yml";
 pub(crate) const DEFAULT_STEPCA_PROVISIONER: &str = "acme";
+/// Default image `step ca init` runs in. Matches the step-ca service
+/// image in the compose files.
+pub(crate) const DEFAULT_STEPCA_IMAGE: &str = "smallstep/step-ca:0.30.2";
+// Keep in sync with the `openbao` service image in docker-compose.yml and
+// docker-compose.deploy.yml so the Agents run the same OpenBao build as the
+// server they authenticate against.
+pub(crate) const DEFAULT_OPENBAO_AGENT_IMAGE: &str = "openbao/openbao:2.5.5";
+/// Where the `OpenBao` Agent containers mount the secrets directory.
+pub(crate) const DEFAULT_OPENBAO_AGENT_MOUNT_ROOT: &str = "/openbao/secrets";
+/// How long init waits for the infra `OpenBao` Agents' first render.
+pub(crate) const DEFAULT_AGENT_RENDER_TIMEOUT_SECS: u64 = 30;
 
 /// Default `defaultTLSCertDuration` embedded in the ACME provisioner
 /// of `ca.json` / `ca.json.ctmpl`. Matches step-ca's own default.
@@ -24,6 +37,8 @@ pub(crate) const SECRET_BYTES: usize = 32;
 pub(crate) const DEFAULT_RESPONDER_TOKEN_TTL_SECS: u64 = 60;
 // Keep "bootroot-http01" in sync with RESPONDER_SERVICE_NAME.
 pub(crate) const DEFAULT_RESPONDER_ADMIN_URL: &str = "http://bootroot-http01:8080";
+/// Container-side port of the responder admin API in compose.
+pub(crate) const RESPONDER_ADMIN_PORT: u16 = 8080;
 pub(crate) const RESPONDER_TEMPLATE_DIR: &str = "templates";
 pub(crate) const RESPONDER_TEMPLATE_NAME: &str = "responder.toml.ctmpl";
 pub(crate) const RESPONDER_CONFIG_DIR: &str = "responder";
@@ -134,15 +149,15 @@ pub(crate) mod openbao_constants {
     pub(crate) const APPROLE_BOOTROOT_RUNTIME_ROTATE: &str = "bootroot-runtime-rotate-role";
     pub(crate) const APPROLE_BOOTROOT_INFRA_ROTATE: &str = "bootroot-infra-rotate-role";
 
-    pub(crate) const PATH_STEPCA_PASSWORD: &str = "bootroot/stepca/password";
-    pub(crate) const PATH_STEPCA_DB: &str = "bootroot/stepca/db";
+    pub(crate) const PATH_STEPCA_PASSWORD: &str = bootroot::openbao::KV_PATH_STEPCA_PASSWORD;
+    pub(crate) const PATH_STEPCA_DB: &str = bootroot::openbao::KV_PATH_STEPCA_DB;
     /// Stores the admin DSN bootroot used to provision the runtime
     /// role/database. Carries strictly higher privilege than
     /// `PATH_STEPCA_DB` and must NOT be readable by the same `OpenBao`
     /// policy as the runtime DSN — only operator/root tokens
     /// (and any future rotate-only `AppRole`) may read this path.
     pub(crate) const PATH_STEPCA_DB_ADMIN: &str = "bootroot/stepca/db_admin";
-    pub(crate) const PATH_RESPONDER_HMAC: &str = "bootroot/responder/hmac";
+    pub(crate) const PATH_RESPONDER_HMAC: &str = bootroot::openbao::KV_PATH_RESPONDER_HMAC;
     pub(crate) const PATH_AGENT_EAB: &str = "bootroot/agent/eab";
-    pub(crate) const PATH_CA_TRUST: &str = "bootroot/ca";
+    pub(crate) const PATH_CA_TRUST: &str = bootroot::openbao::KV_PATH_CA_TRUST;
 }
@@ -3,12 +3,13 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 
 use super::constants::{
-    DEFAULT_RESPONDER_ADMIN_URL, OPENBAO_CONTAINER_NAME, RESPONDER_CONFIG_DIR,
-    RESPONDER_CONFIG_NAME,
+    DEFAULT_RESPONDER_ADMIN_URL, OPENBAO_AGENT_RESPONDER_SERVICE, OPENBAO_AGENT_STEPCA_SERVICE,
+    OPENBAO_CONTAINER_NAME, RESPONDER_ADMIN_PORT, RESPONDER_CONFIG_DIR, RESPONDER_CONFIG_NAME,
 };
 use crate::cli::args::InitArgs;
+use crate::commands::compose_file::ensure_compose_file_exists;
 use crate::commands::constants::{RESPONDER_SERVICE_NAME, STEPCA_SERVICE_NAME};
-use crate::commands::guardrails::parse_hcl_string_value;
+use crate::commands::guardrails::{parse_hcl_string_value, published_host_binding};
 use crate::i18n::Messages;
 
 pub(crate) struct ResponderPaths {
@@ -19,14 +20,62 @@ pub(crate) struct ResponderPaths {
 pub(crate) struct StepCaTemplatePaths {
     pub(crate) password_template_path: PathBuf,
     pub(crate) ca_json_template_path: PathBuf,
+    /// Host path the `OpenBao` Agent renders `ca.json` to.
+    pub(crate) ca_json_output: PathBuf,
 }
 
 pub(crate) struct OpenBaoAgentPaths {
-    pub(crate) stepca_agent_config: PathBuf,
-    pub(crate) responder_agent_config: PathBuf,
+    pub(crate) stepca_agent_config: Option<PathBuf>,
+    pub(crate) responder_agent_config: Option<PathBuf>,
     pub(crate) compose_override_path: Option<PathBuf>,
 }
 
+impl OpenBaoAgentPaths {
+    /// Returns the compose service names of the infra agents that were
+    /// generated.
+    pub(crate) fn services(&self) -> Vec<&'static str> {
+        let mut services = Vec::new();
+        if self.stepca_agent_config.is_some() {
+            services.push(OPENBAO_AGENT_STEPCA_SERVICE);
+        }
+        if self.responder_agent_config.is_some() {
+            services.push(OPENBAO_AGENT_RESPONDER_SERVICE);
+        }
+        services
+    }
+}
+
+/// Resolves step-ca's `ca.json`: `ca_json` (`--ca-json`) when given,
+/// otherwise `<secrets_dir>/config/ca.json`.
+///
+/// The `OpenBao` Agent renders `ca.json` through the secrets mount, so
+/// an override outside `secrets_dir` is rejected. The result is rooted
+/// at `secrets_dir` so [`to_container_path`] accepts relative and
+/// absolute spellings alike.
+pub(crate) fn resolve_ca_json_path(
+    secrets_dir: &Path,
+    ca_json: Option<&Path>,
+    messages: &Messages,
+) -> Result<PathBuf> {
+    let Some(ca_json) = ca_json else {
+        return Ok(secrets_dir.join("config").join("ca.json"));
+    };
+    let absolute = |path: &Path| {
+        std::path::absolute(path)
+            .with_context(|| messages.error_resolve_ca_json_failed(&path.display().to_string()))
+    };
+    let relative = absolute(ca_json)?
+        .strip_prefix(absolute(secrets_dir)?)
+        .map(Path::to_path_buf)
+        .map_err(|_| {
+            anyhow::anyhow!(messages.error_ca_json_outside_secrets_dir(
+                &ca_json.display().to_string(),
+                &secrets_dir.display().to_string(),
+            ))
+        })?;
+    Ok(secrets_dir.join(relative))
+}
+
 /// Resolves a host path to its container-internal equivalent.
 ///
 /// Strips the `secrets_dir` prefix and prepends `container_mount`.
@@ -73,6 +122,43 @@ pub(crate) fn compose_has_stepca(compose_file: &Path, messages: &Messages) -> Re
     ))
 }
 
+/// Fails before any docker action when the compose file does not declare
+/// each of `services` as a top-level service.
+///
+/// Pointing `--compose-file` at an unrelated compose otherwise surfaces
+/// only later, as a "service has no running container" readiness error.
+pub(crate) fn ensure_compose_defines_services(
+    compose_file: &Path,
+    services: &[String],
+    messages: &Messages,
+) -> Result<()> {
+    if let Some(missing) = missing_compose_service(compose_file, services, messages)? {
+        anyhow::bail!(
+            messages.error_compose_missing_service(&compose_file.display().to_string(), missing)
+        );
+    }
+    Ok(())
+}
+
+/// Returns the first of `services` the compose file does not declare as a
+/// top-level service.
+///
+/// # Errors
+/// Returns an error if the compose file is missing or unreadable.
+pub(crate) fn missing_compose_service<'a>(
+    compose_file: &Path,
+    services: &'a [String],
+    messages: &Messages,
+) -> Result<Option<&'a str>> {
+    ensure_compose_file_exists(compose_file, messages)?;
+    let compose_contents = std::fs::read_to_string(compose_file)
+        .with_context(|| messages.error_read_file_failed(&compose_file.display().to_string()))?;
+    Ok(services
+        .iter()
+        .find(|service| !compose_has_top_level_service(&compose_contents, service))
+        .map(String::as_str))
+}
+
 /// Returns true when the compose document declares a top-level service
 /// whose mapping key is exactly `service_name`.
 ///
@@ -125,6 +211,7 @@ fn compose_has_top_level_service(yaml: &str, service_name: &str) -> bool {
 pub(crate) fn resolve_responder_url(
     args: &InitArgs,
     compose_has_responder: bool,
+    messages: &Messages,
 ) -> Result<Option<String>> {
     if let Some(responder_url) = args.responder_url.as_ref() {
         return Ok(Some(responder_url.clone()));
@@ -132,14 +219,33 @@ pub(crate) fn resolve_responder_url(
     if !compose_has_responder {
         return Ok(None);
     }
+    let compose_file = &args.compose.compose_file;
+    let compose_contents = std::fs::read_to_string(compose_file)
+        .with_context(|| messages.error_read_file_failed(&compose_file.display().to_string()))?;
+    // The stock publish keeps the admin port, so the container-network
+    // default applies.  A remapped port is only reachable on the host, at
+    // the mapping's host IP (which the admin TLS SANs carry) or at
+    // `localhost` for a wildcard or IP-less mapping.
+    let admin_url = match published_host_binding(
+        &compose_contents,
+        &format!("{RESPONDER_SERVICE_NAME}:"),
+        RESPONDER_ADMIN_PORT,
+    ) {
+        Some((host_ip, port)) if port != RESPONDER_ADMIN_PORT => {
+            let host = match host_ip.as_deref() {
+                None | Some("" | "0.0.0.0" | "[::]") => "localhost",
+                Some(ip) => ip,
+            };
+            format!("http://{host}:{port}")
+        }
+        _ => DEFAULT_RESPONDER_ADMIN_URL.to_string(),
+    };
     // When the responder has TLS paths configured, the admin API must
     // be reached via HTTPS.
     if responder_tls_configured(&args.secrets_dir.secrets_dir)? {
-        Ok(Some(
-            DEFAULT_RESPONDER_ADMIN_URL.replace("http://", "https://"),
-        ))
+        Ok(Some(admin_url.replace("http://", "https://")))
     } else {
-        Ok(Some(DEFAULT_RESPONDER_ADMIN_URL.to_string()))
+        Ok(Some(admin_url))
     }
 }
 
@@ -194,6 +300,44 @@ pub(crate) fn resolve_openbao_agent_addr(openbao_url: &str, compose_has_openbao:
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::i18n::test_messages;
+
+    #[test]
+    fn resolve_ca_json_path_defaults_under_secrets_dir() {
+        let secrets_dir = Path::new("secrets");
+        let path = resolve_ca_json_path(secrets_dir, None, &test_messages()).unwrap();
+        assert_eq!(path, secrets_dir.join("config").join("ca.json"));
+    }
+
+    #[test]
+    fn resolve_ca_json_path_roots_override_at_secrets_dir() {
+        let messages = test_messages();
+        let secrets_dir = Path::new("secrets");
+        let absolute = std::path::absolute("secrets/stepca/ca.json").unwrap();
+        for ca_json in [Path::new("./secrets/stepca/ca.json"), absolute.as_path()] {
+            let path = resolve_ca_json_path(secrets_dir, Some(ca_json), &messages).unwrap();
+            assert_eq!(path, secrets_dir.join("stepca").join("ca.json"));
+            assert_eq!(
+                to_container_path(secrets_dir, &path, "/openbao/secrets").unwrap(),
+                "/openbao/secrets/stepca/ca.json"
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_ca_json_path_rejects_override_outside_secrets_dir() {
+        let messages = test_messages();
+        let err = resolve_ca_json_path(
+            Path::new("secrets"),
+            Some(Path::new("/etc/step-ca/ca.json")),
+            &messages,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            messages.error_ca_json_outside_secrets_dir("/etc/step-ca/ca.json", "secrets")
+        );
+    }
 
     #[test]
     fn responder_tls_configured_returns_true_when_config_has_tls_paths() {
@@ -281,6 +425,35 @@ volumes:
         assert!(!compose_has_top_level_service(yaml, "openbao"));
     }
 
+    #[test]
+    fn ensure_compose_defines_services_names_missing_stepca() {
+        let dir = tempfile::tempdir().unwrap();
+        let compose_path = dir.path().join("docker-compose.yml");
+        std::fs::write(
+            &compose_path,
+            "services:\n  openbao:\n    image: openbao\n  postgres:\n    image: postgres\n  \
+             bootroot-http01:\n    image: responder\n",
+        )
+        .unwrap();
+        let messages = crate::i18n::test_messages();
+        let services = crate::commands::infra::default_infra_services();
+
+        let err = ensure_compose_defines_services(&compose_path, &services, &messages).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Compose file {} does not define required service step-ca; check \
+                 --compose-file, or drop the service from --services",
+                compose_path.display()
+            )
+        );
+        let without_stepca: Vec<String> = services
+            .into_iter()
+            .filter(|service| service != "step-ca")
+            .collect();
+        ensure_compose_defines_services(&compose_path, &without_stepca, &messages).unwrap();
+    }
+
     #[test]
     fn compose_has_stepca_detects_service_key() {
         let dir = tempfile::tempdir().unwrap();
@@ -1,5 +1,8 @@
+mod agent_templates;
+mod audit_log;
 mod ca_certs;
 mod database;
+mod gitignore;
 pub(crate) mod http01_admin_tls;
 mod openbao_setup;
 pub(crate) mod openbao_tls;
@@ -14,10 +17,11 @@ use std::path::PathBuf;
 use anyhow::{Context, Result};
 use bootroot::openbao::{InitResponse, OpenBaoClient};
 pub(crate) use ca_certs::{
-    compute_ca_bundle_pem, compute_ca_fingerprints, read_ca_cert_fingerprint,
+    compute_ca_bundle_pem, compute_ca_fingerprints, read_ca_cert_fingerprint, trust_payload_changed,
 };
 pub(crate) use openbao_setup::{
-    infra_rotate_policy, parse_ttl_to_secs, validate_rotate_bound_cidrs, validate_secret_id_ttl,
+    infra_rotate_policy, parse_ttl_to_secs, validate_approle_bind_cidrs,
+    validate_rotate_bound_cidrs, validate_secret_id_ttl,
 };
 pub(crate) use orchestrator::run_init;
 pub(crate) use prompts::prompt_yes_no;
@@ -29,6 +33,13 @@ pub(super) struct InitBootstrap {
     pub(super) init_response: Option<InitResponse>,
     pub(super) root_token: String,
     pub(super) unseal_keys: Vec<String>,
+    pub(super) recovery_keys: Vec<String>,
+}
+
+/// Root token and unseal (or recovery) keys `OpenBao` issued to this run.
+pub(super) struct IssuedOpenBaoCredentials {
+    pub(super) root_token: String,
+    pub(super) keys: Vec<String>,
 }
 
 pub(super) struct InitSecrets {
@@ -50,6 +61,22 @@ pub(super) struct RollbackFile {
     pub(super) original: Option<String>,
 }
 
+impl RollbackFile {
+    /// Captures the current contents of `path` (or its absence) so a
+    /// later rollback can restore or remove whatever init writes there.
+    pub(super) fn snapshot(path: PathBuf, messages: &Messages) -> Result<Self> {
+        let original = match std::fs::read_to_string(&path) {
+            Ok(contents) => Some(contents),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| messages.error_read_file_failed(&path.display().to_string()));
+            }
+        };
+        Ok(Self { path, original })
+    }
+}
+
 #[derive(Default)]
 pub(super) struct InitRollback {
     pub(super) created_policies: Vec<String>,
@@ -65,20 +92,55 @@ pub(super) struct InitRollback {
     /// Responder config compose override for restarting without the
     /// exposed port binding during rollback.
     pub(super) responder_compose_override: Option<PathBuf>,
-    /// Infra `OpenBao` agent compose override applied in the TLS case
-    /// (Phase 2, post-TLS-transition).  On rollback the two agent
-    /// containers are stopped and removed so they are not left running
-    /// with a TLS `VAULT_ADDR`/`ca_cert` against a rolled-back plaintext
-    /// `OpenBao`.
+    /// Responder config override applied on the plaintext path.  On
+    /// rollback the responder is stopped so it does not keep serving the
+    /// config rendered by the failed init.  The TLS path is undone via
+    /// `responder_config_backup` instead.
+    pub(super) applied_responder_compose_override: Option<PathBuf>,
+    /// Infra `OpenBao` agent compose override, registered once the agents
+    /// are started (single-phase on plaintext, Phase 2 after the TLS
+    /// transition otherwise).  On rollback the agent containers are
+    /// stopped and removed so they are not left running against
+    /// incomplete config or, in the TLS case, with a TLS
+    /// `VAULT_ADDR`/`ca_cert` against a rolled-back plaintext `OpenBao`.
     pub(super) openbao_agent_compose_override: Option<PathBuf>,
+    /// Agent services started from `openbao_agent_compose_override`.
+    pub(super) openbao_agent_services: Vec<&'static str>,
     /// State file snapshot taken before the `OpenBao` TLS transition
     /// persists the HTTPS URL and infra cert entries.  On rollback it
     /// restores the pre-TLS `state.json` so it does not keep pointing at
     /// an HTTPS URL / TLS certs after `OpenBao` is recreated on plaintext.
     pub(super) state_backup: Option<RollbackFile>,
+    /// Generated responder, step-ca template, `OpenBao` Agent, and
+    /// compose override files, snapshotted before init writes them so a
+    /// failed init does not leave orphaned config behind.
+    pub(super) generated_files: Vec<RollbackFile>,
+    /// Set once this run initialized `OpenBao`. Rollback cannot undo the
+    /// initialization, so a cancelled run prints these for `init
+    /// --resume` instead of leaving the server unreachable.
+    pub(super) issued_openbao_credentials: Option<IssuedOpenBaoCredentials>,
 }
 
 impl InitRollback {
+    /// Snapshots each of `paths` that is not already tracked in
+    /// `generated_files`.
+    pub(super) fn track_generated_files(
+        &mut self,
+        paths: Vec<PathBuf>,
+        messages: &Messages,
+    ) -> Result<()> {
+        for path in paths {
+            if self.generated_files.iter().any(|file| file.path == path) {
+                continue;
+            }
+            self.generated_files
+                .push(RollbackFile::snapshot(path, messages)?);
+        }
+        Ok(())
+    }
+
+    // Undo steps run in one place so their reverse ordering stays visible.
+    #[allow(clippy::too_many_lines)]
     pub(super) async fn rollback(
         &self,
         client: &OpenBaoClient,
@@ -130,7 +192,11 @@ impl InitRollback {
         if let Some(override_path) = &self.openbao_agent_compose_override
             && let Some(compose_file) = &self.compose_file
         {
-            let args = rollback_openbao_agent_docker_args(compose_file, override_path);
+            let args = rollback_openbao_agent_docker_args(
+                compose_file,
+                override_path,
+                &self.openbao_agent_services,
+            );
             let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
             if let Err(err) = crate::commands::infra::run_docker(
                 &arg_refs,
@@ -141,6 +207,22 @@ impl InitRollback {
             }
         }
 
+        // Stop the responder started with the plaintext config override;
+        // its rendered config is about to be restored or removed below.
+        if let Some(override_path) = &self.applied_responder_compose_override
+            && let Some(compose_file) = &self.compose_file
+        {
+            let args = rollback_stop_responder_docker_args(compose_file, override_path);
+            let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+            if let Err(err) = crate::commands::infra::run_docker(
+                &arg_refs,
+                "docker compose stop responder (rollback)",
+                messages,
+            ) {
+                eprintln!("Rollback: failed to stop responder: {err}");
+            }
+        }
+
         // Restore the pre-TLS `state.json` so it does not keep pointing
         // at the HTTPS URL / TLS infra certs after OpenBao is back on
         // plaintext.  Done after the container work so a failed restore
@@ -213,6 +295,11 @@ impl InitRollback {
         {
             eprintln!("Rollback: failed to restore {}: {err}", file.path.display());
         }
+        for file in &self.generated_files {
+            if let Err(err) = rollback_file(file, messages) {
+                eprintln!("Rollback: failed to restore {}: {err}", file.path.display());
+            }
+        }
     }
 }
 
@@ -259,18 +346,39 @@ fn rollback_responder_docker_args(
     args
 }
 
+/// Builds the Docker Compose arguments for stopping the responder that
+/// init started with its config override.
+///
+/// Returns `["compose", "-f", <compose>, "-f", <override>, "stop",
+/// <responder>]`.
+fn rollback_stop_responder_docker_args(
+    compose_file: &std::path::Path,
+    override_path: &std::path::Path,
+) -> Vec<String> {
+    vec![
+        "compose".to_string(),
+        "-f".to_string(),
+        compose_file.to_string_lossy().into_owned(),
+        "-f".to_string(),
+        override_path.to_string_lossy().into_owned(),
+        "stop".to_string(),
+        crate::commands::constants::RESPONDER_SERVICE_NAME.to_string(),
+    ]
+}
+
 /// Builds the Docker Compose arguments for tearing down the infra
 /// `OpenBao` agents during rollback.
 ///
 /// Returns `["compose", "-f", <compose>, "-f", <override>, "rm", "-s",
-/// "-f", <stepca>, <responder>]` so the two agent containers are
-/// stopped and removed.  They were started with a TLS `VAULT_ADDR`/
+/// "-f", <services>...]` so the agent containers are stopped and
+/// removed.  They were started with a TLS `VAULT_ADDR`/
 /// `ca_cert` that a rolled-back plaintext `OpenBao` cannot serve.
 fn rollback_openbao_agent_docker_args(
     compose_file: &std::path::Path,
     override_path: &std::path::Path,
+    services: &[&str],
 ) -> Vec<String> {
-    vec![
+    let mut args = vec![
         "compose".to_string(),
         "-f".to_string(),
         compose_file.to_string_lossy().into_owned(),
@@ -279,9 +387,9 @@ fn rollback_openbao_agent_docker_args(
         "rm".to_string(),
         "-s".to_string(),
         "-f".to_string(),
-        super::constants::OPENBAO_AGENT_STEPCA_SERVICE.to_string(),
-        super::constants::OPENBAO_AGENT_RESPONDER_SERVICE.to_string(),
-    ]
+    ];
+    args.extend(services.iter().map(ToString::to_string));
+    args
 }
 
 fn rollback_file(file: &RollbackFile, messages: &Messages) -> Result<()> {
@@ -524,7 +632,14 @@ mod rollback_tests {
 
         let compose = PathBuf::from("docker-compose.yml");
         let override_path = PathBuf::from("secrets/openbao/agent.override.yml");
-        let args = super::rollback_openbao_agent_docker_args(&compose, &override_path);
+        let args = super::rollback_openbao_agent_docker_args(
+            &compose,
+            &override_path,
+            &[
+                crate::commands::init::constants::OPENBAO_AGENT_STEPCA_SERVICE,
+                crate::commands::init::constants::OPENBAO_AGENT_RESPONDER_SERVICE,
+            ],
+        );
 
         assert_eq!(args.first().map(String::as_str), Some("compose"));
         assert!(
@@ -581,6 +696,48 @@ mod rollback_tests {
             "state.json must be restored to its pre-TLS content after rollback"
         );
     }
+
+    /// Regression: a failure after the responder files were written
+    /// must remove them during rollback (fresh install) and restore a
+    /// pre-existing override verbatim, so a failed init leaves no
+    /// orphaned config behind.
+    #[tokio::test]
+    async fn rollback_removes_generated_responder_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let messages = crate::i18n::test_messages();
+        let secrets_dir = dir.path().join("secrets");
+        let paths = super::responder_setup::responder_generated_paths(&secrets_dir);
+        let override_path = paths.last().unwrap().clone();
+        std::fs::create_dir_all(override_path.parent().unwrap()).unwrap();
+        std::fs::write(&override_path, "previous override\n").unwrap();
+
+        let mut rollback = InitRollback::default();
+        rollback
+            .track_generated_files(paths.clone(), &messages)
+            .unwrap();
+        let written = super::responder_setup::write_responder_files(
+            &secrets_dir,
+            "secret",
+            "hmac",
+            false,
+            &messages,
+        )
+        .await
+        .unwrap();
+        std::fs::write(&override_path, "new override\n").unwrap();
+        assert!(written.template_path.exists());
+        assert!(written.config_path.exists());
+
+        let client = OpenBaoClient::new("http://127.0.0.1:1").unwrap();
+        rollback.rollback(&client, "secret", &messages).await;
+
+        assert!(!written.template_path.exists());
+        assert!(!written.config_path.exists());
+        assert_eq!(
+            std::fs::read_to_string(&override_path).unwrap(),
+            "previous override\n"
+        );
+    }
 }
 
 #[cfg(test)]
@@ -589,7 +746,10 @@ pub(super) mod test_support {
     use std::sync::{Mutex, MutexGuard, OnceLock};
 
     use super::super::constants::openbao_constants::SECRET_ID_TTL;
-    use super::super::constants::{DEFAULT_CERT_DURATION, DEFAULT_STEPCA_PROVISIONER};
+    use super::super::constants::{
+        DEFAULT_CERT_DURATION, DEFAULT_OPENBAO_AGENT_IMAGE, DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
+        DEFAULT_STEPCA_IMAGE, DEFAULT_STEPCA_PROVISIONER,
+    };
     use crate::cli::args::InitArgs;
     pub(in crate::commands::init::steps) use crate::i18n::test_messages;
 
@@ -614,14 +774,27 @@ pub(super) mod test_support {
             compose: crate::cli::args::ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             enable: Vec::new(),
             skip: Vec::new(),
+            skip_step_ca: false,
+            ca_json: None,
+            skip_responder: false,
+            resume: false,
+            openbao_only: false,
+            yes: false,
+            print_agent_config_template: false,
+            dump_plan_json: false,
+            dry_run: false,
             summary_json: None,
+            reveal_to_file: None,
+            audit_log: None,
             root_token: crate::cli::args::RootTokenArgs { root_token: None },
             unseal_key: Vec::new(),
             openbao_unseal_from_file: None,
             secret_id_ttl: SECRET_ID_TTL.to_string(),
             rotate_bound_cidrs: Vec::new(),
+            approle_bind_cidr: Vec::new(),
             stepca_password: None,
             db_dsn: None,
             db_admin: crate::cli::args::DbAdminDsnArgs { admin_dsn: None },
@@ -629,11 +802,18 @@ pub(super) mod test_support {
             db_password: None,
             db_name: None,
             db_timeout: crate::cli::args::DbTimeoutArgs { timeout_secs: 2 },
+            db_probe_timeout: crate::cli::args::DbProbeTimeoutArgs::default(),
             http_hmac: None,
             responder_url: None,
             responder_timeout_secs: 5,
+            agent_render_timeout_secs: crate::commands::init::DEFAULT_AGENT_RENDER_TIMEOUT_SECS,
             stepca_provisioner: DEFAULT_STEPCA_PROVISIONER.to_string(),
             cert_duration: DEFAULT_CERT_DURATION.to_string(),
+            auto_unseal: false,
+            stepca_image: DEFAULT_STEPCA_IMAGE.to_string(),
+            openbao_agent_image: DEFAULT_OPENBAO_AGENT_IMAGE.to_string(),
+            openbao_agent_mount_root: DEFAULT_OPENBAO_AGENT_MOUNT_ROOT.to_string(),
+            openbao_agent_exec: Vec::new(),
             eab_kid: None,
             eab_hmac: None,
             no_eab: false,
@@ -644,6 +824,45 @@ pub(super) mod test_support {
         }
     }
 
+    /// Environment variable that rewrites golden files instead of
+    /// comparing against them.
+    const UPDATE_GOLDEN_ENV: &str = "BOOTROOT_UPDATE_GOLDEN";
+
+    /// Compares `actual` with `tests/golden/<name>`, or rewrites the file
+    /// when `BOOTROOT_UPDATE_GOLDEN=1` so the change shows up in
+    /// `git diff` for review.
+    pub(in crate::commands::init::steps) fn assert_golden(name: &str, actual: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("golden")
+            .join(name);
+        if std::env::var(UPDATE_GOLDEN_ENV).is_ok_and(|value| value == "1") {
+            std::fs::write(&path, actual).expect("write golden file");
+            return;
+        }
+        let expected = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+            panic!(
+                "read golden file {}: {err}; rerun with {UPDATE_GOLDEN_ENV}=1 to create it",
+                path.display()
+            )
+        });
+        if expected != actual {
+            let mismatch = expected
+                .lines()
+                .zip(actual.lines())
+                .position(|(left, right)| left != right)
+                .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+            panic!(
+                "output differs from golden file {} at line {}\n\
+                 --- expected\n{expected}\n+++ actual\n{actual}\n\
+                 If the change is intended, rerun with {UPDATE_GOLDEN_ENV}=1 \
+                 and review the golden file with `git diff`.",
+                path.display(),
+                mismatch + 1,
+            );
+        }
+    }
+
     pub(in crate::commands::init::steps) fn test_cert_pem(common_name: &str) -> String {
         let mut params =
             rcgen::CertificateParams::new(vec![common_name.to_string()]).expect("params");
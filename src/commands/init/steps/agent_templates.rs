@@ -0,0 +1,271 @@
+//! Copy-paste `OpenBao` Agent snippets for `init --print-agent-config-template`.
+//!
+//! Operators who run the agents themselves get the same `agent.hcl` and
+//! template bodies init would write, rendered for the current KV mount
+//! and `OpenBao` address, instead of reverse-engineering them.
+
+use anyhow::{Context, Result};
+
+use super::super::constants::openbao_constants::PATH_AGENT_EAB;
+use super::super::constants::{
+    OPENBAO_AGENT_CONFIG_NAME, OPENBAO_AGENT_DIR, OPENBAO_AGENT_RESPONDER_DIR,
+    OPENBAO_AGENT_ROLE_ID_NAME, OPENBAO_AGENT_SECRET_ID_NAME, OPENBAO_AGENT_STEPCA_DIR,
+    RESPONDER_CONFIG_DIR, RESPONDER_CONFIG_NAME, RESPONDER_TEMPLATE_DIR, RESPONDER_TEMPLATE_NAME,
+    STEPCA_CA_JSON_TEMPLATE_NAME, STEPCA_PASSWORD_TEMPLATE_NAME,
+};
+use super::super::paths::{
+    compose_has_openbao, resolve_ca_json_path, resolve_openbao_agent_addr, to_container_path,
+};
+use super::openbao_setup::{AgentExecCommands, agent_credential_path, build_openbao_agent_config};
+use super::responder_setup::build_responder_template;
+use super::stepca_setup::{build_ca_json_template, build_password_template};
+use crate::cli::args::InitArgs;
+use crate::i18n::Messages;
+
+/// Name of the bootroot-agent EAB snippet, rendered into `agent.toml`.
+const AGENT_EAB_TEMPLATE_NAME: &str = "agent-eab.toml.ctmpl";
+
+/// One file an operator has to create, keyed by its path under the
+/// secrets directory.
+struct AgentTemplateSnippet {
+    path: String,
+    contents: String,
+}
+
+/// The step-ca `ca.json` template and the container path the agent
+/// renders it to.
+struct CaJsonTemplate {
+    output: String,
+    contents: String,
+}
+
+/// Builds the step-ca, responder, and bootroot-agent snippets for
+/// `args`' KV mount and `OpenBao` address.
+fn agent_template_snippets(
+    args: &InitArgs,
+    messages: &Messages,
+) -> Result<Vec<AgentTemplateSnippet>> {
+    let compose_has_openbao = compose_has_openbao(&args.compose.compose_file, messages)?;
+    let openbao_addr = resolve_openbao_agent_addr(&args.openbao.openbao_url, compose_has_openbao);
+    let exec = AgentExecCommands::parse(&args.openbao_agent_exec, messages)?;
+    let ca_json = read_ca_json_template(args, messages)?;
+    Ok(build_snippets(
+        &args.openbao.kv_mount,
+        &openbao_addr,
+        &args.openbao_agent_mount_root,
+        &exec,
+        ca_json.as_ref(),
+    ))
+}
+
+/// Renders the `ca.json` template from the existing `ca.json`, or
+/// returns `None` when step-ca has not generated one yet.
+fn read_ca_json_template(args: &InitArgs, messages: &Messages) -> Result<Option<CaJsonTemplate>> {
+    let secrets_dir = &args.secrets_dir.secrets_dir;
+    let ca_json_path = resolve_ca_json_path(secrets_dir, args.ca_json.as_deref(), messages)?;
+    if !ca_json_path.exists() {
+        return Ok(None);
+    }
+    let ca_json_contents = std::fs::read_to_string(&ca_json_path)
+        .with_context(|| messages.error_read_file_failed(&ca_json_path.display().to_string()))?;
+    let contents = build_ca_json_template(
+        &ca_json_contents,
+        &args.openbao.kv_mount,
+        &args.cert_duration,
+        &args.stepca_provisioner,
+        messages,
+    )?;
+    let output = to_container_path(secrets_dir, &ca_json_path, &args.openbao_agent_mount_root)?;
+    Ok(Some(CaJsonTemplate { output, contents }))
+}
+
+/// Prints each snippet under a header naming its path.
+pub(crate) fn print_agent_template_snippets(args: &InitArgs, messages: &Messages) -> Result<()> {
+    for snippet in agent_template_snippets(args, messages)? {
+        println!("{}", messages.init_agent_template_header(&snippet.path));
+        println!("{}", snippet.contents.trim_end());
+        println!();
+    }
+    Ok(())
+}
+
+/// Builds every snippet. Without `ca_json`, step-ca's `agent.hcl` only
+/// renders `password.txt`, so it never references a template that was
+/// not printed.
+fn build_snippets(
+    kv_mount: &str,
+    openbao_addr: &str,
+    mount: &str,
+    exec: &AgentExecCommands,
+    ca_json: Option<&CaJsonTemplate>,
+) -> Vec<AgentTemplateSnippet> {
+    let templates = format!("{mount}/{RESPONDER_TEMPLATE_DIR}");
+
+    let mut stepca_templates = vec![(
+        format!("{templates}/{STEPCA_PASSWORD_TEMPLATE_NAME}"),
+        format!("{mount}/password.txt"),
+    )];
+    if let Some(ca_json) = ca_json {
+        stepca_templates.push((
+            format!("{templates}/{STEPCA_CA_JSON_TEMPLATE_NAME}"),
+            ca_json.output.clone(),
+        ));
+    }
+    let stepca_config = build_openbao_agent_config(
+        openbao_addr,
+        mount,
+        &agent_credential_path(mount, OPENBAO_AGENT_STEPCA_DIR, OPENBAO_AGENT_ROLE_ID_NAME),
+        &agent_credential_path(
+            mount,
+            OPENBAO_AGENT_STEPCA_DIR,
+            OPENBAO_AGENT_SECRET_ID_NAME,
+        ),
+        &stepca_templates,
+        None,
+        exec.stepca.as_deref(),
+    );
+
+    let responder_config = build_openbao_agent_config(
+        openbao_addr,
+        mount,
+        &agent_credential_path(
+            mount,
+            OPENBAO_AGENT_RESPONDER_DIR,
+            OPENBAO_AGENT_ROLE_ID_NAME,
+        ),
+        &agent_credential_path(
+            mount,
+            OPENBAO_AGENT_RESPONDER_DIR,
+            OPENBAO_AGENT_SECRET_ID_NAME,
+        ),
+        &[(
+            format!("{templates}/{RESPONDER_TEMPLATE_NAME}"),
+            format!("{mount}/{RESPONDER_CONFIG_DIR}/{RESPONDER_CONFIG_NAME}"),
+        )],
+        None,
+        exec.responder.as_deref(),
+    );
+
+    let mut snippets = vec![
+        AgentTemplateSnippet {
+            path: format!(
+                "{OPENBAO_AGENT_DIR}/{OPENBAO_AGENT_STEPCA_DIR}/{OPENBAO_AGENT_CONFIG_NAME}"
+            ),
+            contents: stepca_config,
+        },
+        AgentTemplateSnippet {
+            path: format!("{RESPONDER_TEMPLATE_DIR}/{STEPCA_PASSWORD_TEMPLATE_NAME}"),
+            contents: build_password_template(kv_mount),
+        },
+    ];
+    if let Some(ca_json) = ca_json {
+        snippets.push(AgentTemplateSnippet {
+            path: format!("{RESPONDER_TEMPLATE_DIR}/{STEPCA_CA_JSON_TEMPLATE_NAME}"),
+            contents: ca_json.contents.clone(),
+        });
+    }
+    snippets.extend([
+        AgentTemplateSnippet {
+            path: format!(
+                "{OPENBAO_AGENT_DIR}/{OPENBAO_AGENT_RESPONDER_DIR}/{OPENBAO_AGENT_CONFIG_NAME}"
+            ),
+            contents: responder_config,
+        },
+        AgentTemplateSnippet {
+            path: format!("{RESPONDER_TEMPLATE_DIR}/{RESPONDER_TEMPLATE_NAME}"),
+            contents: build_responder_template(kv_mount, false),
+        },
+        AgentTemplateSnippet {
+            path: format!("{RESPONDER_TEMPLATE_DIR}/{AGENT_EAB_TEMPLATE_NAME}"),
+            contents: build_agent_eab_template(kv_mount),
+        },
+    ]);
+    snippets
+}
+
+/// Renders the `[eab]` table of bootroot-agent's `agent.toml` from the
+/// EAB secret init registers.
+fn build_agent_eab_template(kv_mount: &str) -> String {
+    format!(
+        "[eab]\n{{{{ with secret \"{kv_mount}/data/{PATH_AGENT_EAB}\" }}}}\
+         kid = \"{{{{ .Data.data.kid }}}}\"\n\
+         hmac = \"{{{{ .Data.data.hmac }}}}\"{{{{ end }}}}\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init::PATH_STEPCA_PASSWORD;
+
+    fn snippet<'a>(snippets: &'a [AgentTemplateSnippet], path: &str) -> &'a str {
+        snippets
+            .iter()
+            .find(|snippet| snippet.path == path)
+            .map(|snippet| snippet.contents.as_str())
+            .expect("snippet present")
+    }
+
+    #[test]
+    fn stepca_template_reads_kv_data_path_under_mount() {
+        let snippets = build_snippets(
+            "kv-custom",
+            "http://openbao:8200",
+            "/openbao/secrets",
+            &AgentExecCommands::default(),
+            None,
+        );
+        let password = snippet(&snippets, "templates/password.txt.ctmpl");
+        assert!(password.contains(&format!("kv-custom/data/{PATH_STEPCA_PASSWORD}")));
+        let config = snippet(&snippets, "openbao/stepca/agent.hcl");
+        assert!(config.contains("http://openbao:8200"));
+        assert!(config.contains("/openbao/secrets/templates/password.txt.ctmpl"));
+    }
+
+    #[test]
+    fn agent_eab_template_reads_eab_path() {
+        let snippets = build_snippets(
+            "secret",
+            "http://openbao:8200",
+            "/openbao/secrets",
+            &AgentExecCommands::default(),
+            None,
+        );
+        let eab = snippet(&snippets, "templates/agent-eab.toml.ctmpl");
+        assert!(eab.contains("secret/data/bootroot/agent/eab"));
+        assert!(eab.contains(".Data.data.kid"));
+    }
+
+    #[test]
+    fn stepca_config_renders_ca_json_only_when_its_template_is_printed() {
+        let without = build_snippets(
+            "secret",
+            "http://openbao:8200",
+            "/openbao/secrets",
+            &AgentExecCommands::default(),
+            None,
+        );
+        assert!(!snippet(&without, "openbao/stepca/agent.hcl").contains("ca.json.ctmpl"));
+        assert!(
+            !without
+                .iter()
+                .any(|snippet| snippet.path == "templates/ca.json.ctmpl")
+        );
+
+        let ca_json = CaJsonTemplate {
+            output: "/openbao/secrets/config/ca.json".to_string(),
+            contents: "{\"db\": {}}".to_string(),
+        };
+        let with = build_snippets(
+            "secret",
+            "http://openbao:8200",
+            "/openbao/secrets",
+            &AgentExecCommands::default(),
+            Some(&ca_json),
+        );
+        let config = snippet(&with, "openbao/stepca/agent.hcl");
+        assert!(config.contains("/openbao/secrets/templates/ca.json.ctmpl"));
+        assert!(config.contains("/openbao/secrets/config/ca.json"));
+        assert_eq!(snippet(&with, "templates/ca.json.ctmpl"), ca_json.contents);
+    }
+}
@@ -0,0 +1,176 @@
+//! JSON-lines audit trail for `init --audit-log`.
+//!
+//! Each significant init step appends one line carrying a timestamp, the
+//! step name, and whether it succeeded. Lines never include secret
+//! values, and each one is written out on its own so a crash mid-init
+//! still leaves a partial trail.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::i18n::Messages;
+
+/// Init steps recorded in the audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum InitAuditStep {
+    OpenBaoInitialized,
+    OpenBaoUnsealed,
+    PoliciesWritten,
+    AppRolesCreated,
+    KvPathsWritten,
+    StepCaInitialized,
+    TrustFingerprintsStored,
+}
+
+impl InitAuditStep {
+    fn name(self) -> &'static str {
+        match self {
+            Self::OpenBaoInitialized => "openbao_initialized",
+            Self::OpenBaoUnsealed => "openbao_unsealed",
+            Self::PoliciesWritten => "policies_written",
+            Self::AppRolesCreated => "approles_created",
+            Self::KvPathsWritten => "kv_paths_written",
+            Self::StepCaInitialized => "stepca_initialized",
+            Self::TrustFingerprintsStored => "trust_fingerprints_stored",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct InitAuditRecord {
+    timestamp: String,
+    step: &'static str,
+    status: &'static str,
+}
+
+/// Appends init steps to the `--audit-log` file; a no-op without one.
+#[derive(Default)]
+pub(super) struct InitAuditLog {
+    sink: Option<(PathBuf, File)>,
+}
+
+impl InitAuditLog {
+    /// Opens `path` for appending, creating it with mode `0600`.
+    ///
+    /// Called before any `OpenBao` work so an unwritable destination
+    /// fails init up front rather than leaving an unrecorded run.
+    ///
+    /// # Errors
+    /// Returns an error if the file or its parent directory cannot be
+    /// created or opened.
+    pub(super) fn open(path: Option<&Path>, messages: &Messages) -> Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::default());
+        };
+        let open = || -> std::io::Result<File> {
+            use std::os::unix::fs::OpenOptionsExt;
+            if let Some(parent) = path.parent()
+                && !parent.as_os_str().is_empty()
+            {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .mode(0o600)
+                .open(path)
+        };
+        let file =
+            open().map_err(|err| {
+                anyhow::anyhow!(messages.error_init_audit_log_open_failed(
+                    &path.display().to_string(),
+                    &err.to_string()
+                ))
+            })?;
+        Ok(Self {
+            sink: Some((path.to_path_buf(), file)),
+        })
+    }
+
+    /// Records whether `step` succeeded, judged by `result`.
+    ///
+    /// A failed append only warns: by the time a step runs `OpenBao` may
+    /// already hold a fresh root token that init must still hand back.
+    pub(super) fn record<T>(
+        &mut self,
+        step: InitAuditStep,
+        result: &Result<T>,
+        messages: &Messages,
+    ) {
+        let Some((path, file)) = self.sink.as_mut() else {
+            return;
+        };
+        let record = InitAuditRecord {
+            timestamp: humantime::format_rfc3339_seconds(SystemTime::now()).to_string(),
+            step: step.name(),
+            status: if result.is_ok() { "ok" } else { "failed" },
+        };
+        let written = serde_json::to_string(&record)
+            .map_err(std::io::Error::other)
+            .and_then(|mut line| {
+                line.push('\n');
+                file.write_all(line.as_bytes())?;
+                file.flush()
+            });
+        if let Err(err) = written {
+            eprintln!(
+                "{}",
+                messages.warning_init_audit_log_write_failed(
+                    step.name(),
+                    &path.display().to_string(),
+                    &err.to_string()
+                )
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::*;
+    use crate::i18n::test_messages;
+
+    #[test]
+    fn record_appends_one_json_line_per_step() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("init.jsonl");
+        let messages = test_messages();
+        let mut audit = InitAuditLog::open(Some(&path), &messages).unwrap();
+
+        audit.record(InitAuditStep::PoliciesWritten, &Ok(()), &messages);
+        audit.record::<()>(
+            InitAuditStep::AppRolesCreated,
+            &Err(anyhow::anyhow!("secret-value")),
+            &messages,
+        );
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let records: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["step"], "policies_written");
+        assert_eq!(records[0]["status"], "ok");
+        assert_eq!(records[1]["step"], "approles_created");
+        assert_eq!(records[1]["status"], "failed");
+        assert!(!contents.contains("secret-value"));
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn open_without_path_records_nothing() {
+        let messages = test_messages();
+        let mut audit = InitAuditLog::open(None, &messages).unwrap();
+        audit.record(InitAuditStep::KvPathsWritten, &Ok(()), &messages);
+        assert!(audit.sink.is_none());
+    }
+}
@@ -38,7 +38,7 @@ pub(crate) async fn compute_ca_bundle_pem(
     Ok(format!("{root}{intermediate}"))
 }
 
-pub(super) fn trust_payload_changed(
+pub(crate) fn trust_payload_changed(
     current: Option<&serde_json::Value>,
     fingerprints: &[String],
     ca_bundle_pem: &str,
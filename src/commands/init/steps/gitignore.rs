@@ -0,0 +1,171 @@
+use std::fmt::Write as _;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use super::super::constants::{
+    CA_CERTS_DIR, OPENBAO_AGENT_ROLE_ID_NAME, OPENBAO_AGENT_SECRET_ID_NAME,
+};
+use crate::i18n::Messages;
+
+const GITIGNORE_FILENAME: &str = ".gitignore";
+const GITIGNORE_HEADER: &str = "# bootroot secrets";
+const STATE_FILE_ENTRY: &str = "state.json";
+
+/// Returns the `.gitignore` path [`update_gitignore`] writes.
+pub(super) fn gitignore_path(project_dir: &Path) -> PathBuf {
+    project_dir.join(GITIGNORE_FILENAME)
+}
+
+/// Merges the bootroot secret paths into `<project_dir>/.gitignore`.
+///
+/// Entries already present (ignoring surrounding whitespace) are left
+/// alone, so repeated `init` runs never duplicate lines and unrelated
+/// operator entries are preserved verbatim.
+pub(super) fn update_gitignore(
+    project_dir: &Path,
+    secrets_dir: &Path,
+    messages: &Messages,
+) -> Result<()> {
+    let path = gitignore_path(project_dir);
+    let existing = if path.exists() {
+        std::fs::read_to_string(&path)
+            .with_context(|| messages.error_read_file_failed(&path.display().to_string()))?
+    } else {
+        String::new()
+    };
+    let Some(updated) = merge_gitignore(&existing, &gitignore_entries(project_dir, secrets_dir))
+    else {
+        return Ok(());
+    };
+    std::fs::write(&path, updated)
+        .with_context(|| messages.error_write_file_failed(&path.display().to_string()))
+}
+
+/// Returns the ignore patterns for the secret files `init` writes.
+///
+/// Paths under the secrets directory are anchored relative to the
+/// project root; a secrets directory outside the project contributes
+/// only the unanchored `role_id`/`secret_id` patterns.
+fn gitignore_entries(project_dir: &Path, secrets_dir: &Path) -> Vec<String> {
+    let mut entries = Vec::new();
+    if let Some(prefix) = relative_secrets_dir(project_dir, secrets_dir) {
+        let prefix = if prefix.is_empty() {
+            String::from("/")
+        } else {
+            format!("/{prefix}/")
+        };
+        entries.push(format!("{prefix}secrets/"));
+        entries.push(format!("{prefix}password.txt"));
+        entries.push(format!("{prefix}{CA_CERTS_DIR}/*.key"));
+    }
+    entries.push(OPENBAO_AGENT_SECRET_ID_NAME.to_string());
+    entries.push(OPENBAO_AGENT_ROLE_ID_NAME.to_string());
+    entries.push(format!("/{STATE_FILE_ENTRY}"));
+    entries
+}
+
+fn relative_secrets_dir(project_dir: &Path, secrets_dir: &Path) -> Option<String> {
+    let relative: PathBuf = if secrets_dir.is_absolute() {
+        secrets_dir.strip_prefix(project_dir).ok()?.to_path_buf()
+    } else {
+        secrets_dir.to_path_buf()
+    };
+    let mut parts = Vec::new();
+    for component in relative.components() {
+        match component {
+            Component::CurDir => {}
+            Component::Normal(part) => parts.push(part.to_str()?.to_string()),
+            _ => return None,
+        }
+    }
+    Some(parts.join("/"))
+}
+
+/// Appends the missing `entries` to `existing`, returning `None` when
+/// every entry is already listed.
+fn merge_gitignore(existing: &str, entries: &[String]) -> Option<String> {
+    let missing: Vec<&String> = entries
+        .iter()
+        .filter(|entry| !existing.lines().any(|line| line.trim() == entry.as_str()))
+        .collect();
+    if missing.is_empty() {
+        return None;
+    }
+    let mut updated = existing.to_string();
+    if !updated.is_empty() && !updated.ends_with('\n') {
+        updated.push('\n');
+    }
+    if !existing.lines().any(|line| line.trim() == GITIGNORE_HEADER) {
+        if !updated.is_empty() {
+            updated.push('\n');
+        }
+        updated.push_str(GITIGNORE_HEADER);
+        updated.push('\n');
+    }
+    for entry in missing {
+        let _ = writeln!(updated, "{entry}");
+    }
+    Some(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::super::test_support::test_messages;
+    use super::*;
+
+    #[test]
+    fn test_update_gitignore_is_idempotent() {
+        let dir = tempdir().unwrap();
+        let secrets_dir = dir.path().join("secrets");
+
+        update_gitignore(dir.path(), &secrets_dir, &test_messages()).unwrap();
+        let first = fs::read_to_string(dir.path().join(GITIGNORE_FILENAME)).unwrap();
+        update_gitignore(dir.path(), &secrets_dir, &test_messages()).unwrap();
+        let second = fs::read_to_string(dir.path().join(GITIGNORE_FILENAME)).unwrap();
+
+        assert_eq!(first, second);
+        for entry in gitignore_entries(dir.path(), &secrets_dir) {
+            assert_eq!(
+                second.lines().filter(|line| *line == entry).count(),
+                1,
+                "entry {entry} must appear exactly once: {second}"
+            );
+        }
+        assert!(second.contains("/secrets/secrets/\n"));
+        assert!(second.contains("/secrets/password.txt\n"));
+        assert!(second.contains("/secrets/certs/*.key\n"));
+        assert!(second.contains("/state.json\n"));
+    }
+
+    #[test]
+    fn test_update_gitignore_preserves_existing_lines() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(GITIGNORE_FILENAME);
+        fs::write(&path, "target/\nsecret_id").unwrap();
+
+        update_gitignore(dir.path(), Path::new("./secrets"), &test_messages()).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+
+        assert!(contents.starts_with("target/\nsecret_id\n"));
+        assert_eq!(
+            contents.lines().filter(|line| *line == "secret_id").count(),
+            1
+        );
+        assert!(contents.contains("/secrets/password.txt\n"));
+        assert!(contents.contains("role_id\n"));
+    }
+
+    #[test]
+    fn test_gitignore_entries_skip_secrets_dir_outside_project() {
+        let dir = tempdir().unwrap();
+        let entries = gitignore_entries(&dir.path().join("project"), &dir.path().join("secrets"));
+
+        assert!(!entries.iter().any(|entry| entry.contains("password.txt")));
+        assert!(entries.contains(&"/state.json".to_string()));
+    }
+}
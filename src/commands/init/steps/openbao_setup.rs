@@ -1,11 +1,14 @@
 use std::collections::BTreeMap;
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use bootroot::cert_group::CertGroupPolicy;
 use bootroot::fs_util;
 use bootroot::openbao::OpenBaoClient;
 use bootroot::openbao::SecretIdOptions;
+use tokio::io::AsyncWriteExt as _;
 
 use super::super::constants::openbao_constants::{
     APPROLE_BOOTROOT_INFRA_ROTATE, APPROLE_BOOTROOT_RESPONDER, APPROLE_BOOTROOT_RUNTIME_ROTATE,
@@ -26,64 +29,103 @@ use super::super::paths::{
     to_container_path,
 };
 use super::super::types::{AppRoleLabel, AppRoleOutput, EabCredentials, OpenBaoConfigResult};
+use super::audit_log::{InitAuditLog, InitAuditStep};
 use super::ca_certs::{compute_ca_bundle_pem, compute_ca_fingerprints};
-use super::prompts::{confirm_overwrite, prompt_text, prompt_unseal_keys};
+use super::prompts::{confirm_overwrite, prompt_secret, prompt_unseal_keys, with_stdin_prompt};
 use super::{InitBootstrap, InitRollback, InitSecrets};
 use crate::cli::args::InitArgs;
-use crate::commands::constants::CA_TRUST_KEY;
+use crate::commands::cancellation;
+use crate::commands::capabilities::{CapabilitySource, RequiredCapability, preflight_capabilities};
 use crate::commands::infra::run_docker;
 use crate::commands::openbao_unseal::read_unseal_keys_from_file;
 use crate::i18n::Messages;
 
-const INIT_AGENT_TOKEN_PATH: &str = "/openbao/secrets/openbao/token";
-const OPENBAO_AGENT_SECRETS_MOUNT: &str = "/openbao/secrets";
-// Keep in sync with the `openbao` service image in docker-compose.yml and
-// docker-compose.deploy.yml so the Agents run the same OpenBao build as the
-// server they authenticate against.
-const OPENBAO_AGENT_IMAGE: &str = "openbao/openbao:2.5.5";
+/// Token sink file name under `<mount root>/openbao/`.
+const INIT_AGENT_TOKEN_NAME: &str = "token";
+/// How long init waits for the infra agents' first template render.
+const OPENBAO_AGENT_RENDER_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 pub(super) async fn bootstrap_openbao(
     client: &mut OpenBaoClient,
     args: &InitArgs,
+    audit: &mut InitAuditLog,
     messages: &Messages,
 ) -> Result<InitBootstrap> {
-    let (init_response, mut root_token, mut unseal_keys) =
-        ensure_openbao_initialized(client, args, messages).await?;
+    let initialized = ensure_openbao_initialized(client, args, messages).await;
+    // An already-initialized OpenBao is not an init step of this run.
+    if !matches!(initialized, Ok((None, _, _))) {
+        audit.record(InitAuditStep::OpenBaoInitialized, &initialized, messages);
+    }
+    let (init_response, mut root_token, mut unseal_keys) = initialized?;
 
-    let seal_status = client
-        .seal_status()
-        .await
-        .with_context(|| messages.error_openbao_seal_status_failed())?;
-    if seal_status.sealed {
+    // An auto-unseal seal (transit/KMS) unseals the server itself; its
+    // recovery keys cannot unseal, so there is nothing to submit.
+    let seal_status = if args.auto_unseal {
+        None
+    } else {
+        Some(
+            client
+                .seal_status()
+                .await
+                .with_context(|| messages.error_openbao_seal_status_failed())?,
+        )
+    };
+    if let Some(seal_status) = seal_status
+        && seal_status.sealed
+    {
         if unseal_keys.is_empty() {
             if let Some(path) = args.openbao_unseal_from_file.as_deref() {
                 println!("{}", messages.warning_openbao_unseal_from_file());
-                let prompt =
-                    messages.prompt_openbao_unseal_from_file_confirm(&path.display().to_string());
-                confirm_overwrite(&prompt, messages)?;
+                if !args.yes {
+                    let prompt = messages
+                        .prompt_openbao_unseal_from_file_confirm(&path.display().to_string());
+                    with_stdin_prompt(messages, |reader| {
+                        confirm_overwrite(reader, &prompt, messages)
+                    })?;
+                }
                 unseal_keys = read_unseal_keys_from_file(path, messages)?;
             } else {
-                unseal_keys = prompt_unseal_keys(seal_status.t, messages)?;
+                unseal_keys = with_stdin_prompt(messages, |prompt| {
+                    prompt_unseal_keys(prompt, seal_status.t, messages)
+                })?;
             }
         }
-        unseal_openbao(client, &unseal_keys, messages).await?;
+        let unsealed = unseal_openbao(client, &unseal_keys, messages).await;
+        audit.record(InitAuditStep::OpenBaoUnsealed, &unsealed, messages);
+        unsealed?;
     }
 
     if root_token.is_none() {
-        root_token = Some(prompt_text(messages.prompt_openbao_root_token(), messages)?);
+        root_token = Some(prompt_secret(
+            messages.prompt_openbao_root_token(),
+            messages,
+        )?);
     }
     let root_token =
         root_token.ok_or_else(|| anyhow::anyhow!(messages.error_openbao_root_token_required()))?;
 
     client.set_token(root_token.clone());
 
+    let recovery_keys = init_response
+        .as_ref()
+        .map(|response| {
+            preferred_keys(&response.recovery_keys, &response.recovery_keys_base64).to_vec()
+        })
+        .unwrap_or_default();
     Ok(InitBootstrap {
         init_response,
         root_token,
         unseal_keys,
+        recovery_keys,
     })
 }
 
+/// Returns the hex-encoded keys of an init response, falling back to
+/// their base64 form when the server only returned that.
+fn preferred_keys<'a>(keys: &'a [String], keys_base64: &'a [String]) -> &'a [String] {
+    if keys.is_empty() { keys_base64 } else { keys }
+}
+
 async fn ensure_openbao_initialized(
     client: &OpenBaoClient,
     args: &InitArgs,
@@ -105,16 +147,16 @@ async fn ensure_openbao_initialized(
         ));
     }
 
-    let response = client
-        .init(INIT_SECRET_SHARES, INIT_SECRET_THRESHOLD)
-        .await
-        .with_context(|| messages.error_openbao_init_failed())?;
-    let root_token = response.root_token.clone();
-    let keys = if response.keys.is_empty() {
-        response.keys_base64.clone()
+    let response = if args.auto_unseal {
+        client
+            .init_with_recovery_keys(INIT_SECRET_SHARES, INIT_SECRET_THRESHOLD)
+            .await
     } else {
-        response.keys.clone()
-    };
+        client.init(INIT_SECRET_SHARES, INIT_SECRET_THRESHOLD).await
+    }
+    .with_context(|| messages.error_openbao_init_failed())?;
+    let root_token = response.root_token.clone();
+    let keys = preferred_keys(&response.keys, &response.keys_base64).to_vec();
     Ok((Some(response), Some(root_token), keys))
 }
 
@@ -142,11 +184,15 @@ async fn unseal_openbao(
     Ok(())
 }
 
+// Keep policy, AppRole, and KV provisioning together so rollback tracking
+// follows the creation order.
+#[allow(clippy::too_many_lines)]
 pub(super) async fn configure_openbao(
     client: &OpenBaoClient,
     args: &InitArgs,
     secrets: &InitSecrets,
     rollback: &mut InitRollback,
+    audit: &mut InitAuditLog,
     messages: &Messages,
 ) -> Result<OpenBaoConfigResult> {
     client
@@ -162,42 +208,73 @@ pub(super) async fn configure_openbao(
         .await
         .with_context(|| messages.error_openbao_audit_setup_failed())?;
 
-    let policies = build_policy_map(&args.openbao.kv_mount);
-    for (name, policy) in &policies {
-        if !client
-            .policy_exists(name)
-            .await
-            .with_context(|| messages.error_openbao_policy_exists_failed())?
-        {
-            rollback.created_policies.push(name.clone());
+    let labels = AppRoleLabel::provisioned(args.skip_step_ca, args.skip_responder);
+    let policies = build_policy_map(&args.openbao.kv_mount, &labels);
+    let kv_paths = init_kv_paths(args, secrets.eab.is_some());
+    preflight_capabilities(
+        client,
+        &configure_required_capabilities(&args.openbao.kv_mount, &policies, &labels, &kv_paths),
+        CapabilitySource::RootToken,
+        messages,
+    )
+    .await?;
+    let policies_written = async {
+        for (name, policy) in &policies {
+            if !client
+                .policy_exists(name)
+                .await
+                .with_context(|| messages.error_openbao_policy_exists_failed())?
+            {
+                rollback.created_policies.push(name.clone());
+            } else if args.resume {
+                continue;
+            }
+            client
+                .write_policy(name, policy)
+                .await
+                .with_context(|| messages.error_openbao_policy_write_failed())?;
         }
-        client
-            .write_policy(name, policy)
-            .await
-            .with_context(|| messages.error_openbao_policy_write_failed())?;
+        Ok(())
     }
-
-    for &label in AppRoleLabel::all() {
-        let role_name = label.role_name();
-        let policy_name = label.policy_name();
-        if !client
-            .approle_exists(role_name)
-            .await
-            .with_context(|| messages.error_openbao_approle_exists_failed())?
-        {
-            rollback.created_approles.push(role_name.to_string());
+    .await;
+    audit.record(InitAuditStep::PoliciesWritten, &policies_written, messages);
+    policies_written?;
+
+    let approles_created = async {
+        for &label in &labels {
+            let role_name = label.role_name();
+            let policy_name = label.policy_name();
+            if !client
+                .approle_exists(role_name)
+                .await
+                .with_context(|| messages.error_openbao_approle_exists_failed())?
+            {
+                rollback.created_approles.push(role_name.to_string());
+            } else if args.resume {
+                continue;
+            }
+            let bound_cidrs: &[String] = if label.is_service_role() {
+                &args.approle_bind_cidr
+            } else {
+                &[]
+            };
+            client
+                .create_approle(
+                    role_name,
+                    &[policy_name],
+                    TOKEN_TTL,
+                    &args.secret_id_ttl,
+                    true,
+                    bound_cidrs,
+                )
+                .await
+                .with_context(|| messages.error_openbao_approle_create_failed())?;
         }
-        client
-            .create_approle(
-                role_name,
-                &[policy_name],
-                TOKEN_TTL,
-                &args.secret_id_ttl,
-                true,
-            )
-            .await
-            .with_context(|| messages.error_openbao_approle_create_failed())?;
+        Ok(())
     }
+    .await;
+    audit.record(InitAuditStep::AppRolesCreated, &approles_created, messages);
+    approles_created?;
 
     let mut role_outputs = Vec::new();
     let default_opts = SecretIdOptions::default();
@@ -209,7 +286,7 @@ pub(super) async fn configure_openbao(
         token_bound_cidrs: Some(args.rotate_bound_cidrs.clone()),
         ..SecretIdOptions::default()
     });
-    for &label in AppRoleLabel::all() {
+    for &label in &labels {
         let role_name = label.role_name();
         let role_id = client
             .read_role_id(role_name)
@@ -234,42 +311,134 @@ pub(super) async fn configure_openbao(
             secret_id,
         });
     }
-    let approles = AppRoleLabel::approle_map();
+    let approles = AppRoleLabel::approle_map(&labels);
+    let policy_labels = AppRoleLabel::policy_map(&labels);
 
-    let mut kv_paths = vec![PATH_STEPCA_PASSWORD, PATH_STEPCA_DB, PATH_RESPONDER_HMAC];
-    if secrets.eab.is_some() {
-        kv_paths.push(PATH_AGENT_EAB);
-    }
-    for path in kv_paths {
-        if !client
+    let mut existing_kv_paths = Vec::new();
+    for &path in &kv_paths {
+        if client
             .kv_exists(&args.openbao.kv_mount, path)
             .await
             .with_context(|| messages.error_openbao_kv_exists_failed())?
         {
+            existing_kv_paths.push(path);
+        } else {
             rollback.written_kv_paths.push(path.to_string());
         }
     }
-
-    write_openbao_secrets_with_retry(client, &args.openbao.kv_mount, secrets, messages).await?;
+    // `--resume` keeps secrets the interrupted run already stored; the
+    // step-ca pair is only rewritten together.
+    let kept = |path: &str| args.resume && existing_kv_paths.contains(&path);
+    let stepca_kept = kept(PATH_STEPCA_PASSWORD) && kept(PATH_STEPCA_DB);
+    let include_stepca = !args.skip_step_ca && !stepca_kept;
+    let include_responder = !args.skip_responder && !kept(PATH_RESPONDER_HMAC);
+
+    let kv_written = write_openbao_secrets_with_retry(
+        client,
+        &args.openbao.kv_mount,
+        secrets,
+        include_stepca,
+        include_responder,
+        messages,
+    )
+    .await;
+    audit.record(InitAuditStep::KvPathsWritten, &kv_written, messages);
+    kv_written?;
 
     Ok(OpenBaoConfigResult {
         role_outputs,
+        kv_paths,
         approles,
+        policies: policy_labels,
     })
 }
 
+/// Returns the fixed KV paths `configure_openbao` seeds for this run.
+pub(super) fn init_kv_paths(args: &InitArgs, has_eab: bool) -> Vec<&'static str> {
+    let mut kv_paths = Vec::new();
+    if !args.skip_step_ca {
+        kv_paths.extend([PATH_STEPCA_PASSWORD, PATH_STEPCA_DB]);
+    }
+    if !args.skip_responder {
+        kv_paths.push(PATH_RESPONDER_HMAC);
+    }
+    if has_eab {
+        kv_paths.push(PATH_AGENT_EAB);
+    }
+    kv_paths
+}
+
+/// Lists the `OpenBao` API paths an init run writes, in order, for
+/// `init --dry-run`. `kv_paths` covers every KV secret the run stores,
+/// including the ones written after `configure_openbao`.
+pub(super) fn planned_openbao_writes(args: &InitArgs, kv_paths: &[&str]) -> Vec<String> {
+    let kv_mount = &args.openbao.kv_mount;
+    let labels = AppRoleLabel::provisioned(args.skip_step_ca, args.skip_responder);
+    let policies = build_policy_map(kv_mount, &labels);
+    let mut writes = vec![
+        format!("sys/mounts/{kv_mount}"),
+        "sys/auth/approle".to_string(),
+    ];
+    writes.extend(
+        policies
+            .keys()
+            .map(|name| format!("sys/policies/acl/{name}")),
+    );
+    writes.extend(
+        labels
+            .iter()
+            .map(|label| format!("auth/approle/role/{}", label.role_name())),
+    );
+    writes.extend(
+        labels
+            .iter()
+            .map(|label| format!("auth/approle/role/{}/secret-id", label.role_name())),
+    );
+    writes.extend(
+        kv_paths
+            .iter()
+            .map(|path| RequiredCapability::kv_update(kv_mount, path).path),
+    );
+    writes
+}
+
+/// Lists every policy, `AppRole`, and KV write `configure_openbao`
+/// performs, so a token lacking any of them is rejected before the
+/// first write.
+fn configure_required_capabilities(
+    kv_mount: &str,
+    policies: &BTreeMap<String, String>,
+    labels: &[AppRoleLabel],
+    kv_paths: &[&str],
+) -> Vec<RequiredCapability> {
+    let policy_writes = policies
+        .keys()
+        .map(|name| RequiredCapability::new(format!("sys/policies/acl/{name}"), "update"));
+    let approle_writes = labels.iter().map(|label| {
+        RequiredCapability::new(format!("auth/approle/role/{}", label.role_name()), "update")
+    });
+    let kv_writes = kv_paths
+        .iter()
+        .map(|path| RequiredCapability::kv_update(kv_mount, path));
+    policy_writes
+        .chain(approle_writes)
+        .chain(kv_writes)
+        .collect()
+}
+
 async fn write_openbao_secrets_with_retry(
     client: &OpenBaoClient,
     kv_mount: &str,
     secrets: &InitSecrets,
+    include_stepca: bool,
+    include_responder: bool,
     messages: &Messages,
 ) -> Result<()> {
     let attempt = write_openbao_secrets(
         client,
         kv_mount,
-        &secrets.stepca_password,
-        &secrets.db_dsn,
-        &secrets.http_hmac,
+        include_stepca.then_some((secrets.stepca_password.as_str(), secrets.db_dsn.as_str())),
+        include_responder.then_some(secrets.http_hmac.as_str()),
         secrets.eab.as_ref(),
         messages,
     )
@@ -284,9 +453,9 @@ async fn write_openbao_secrets_with_retry(
             write_openbao_secrets(
                 client,
                 kv_mount,
-                &secrets.stepca_password,
-                &secrets.db_dsn,
-                &secrets.http_hmac,
+                include_stepca
+                    .then_some((secrets.stepca_password.as_str(), secrets.db_dsn.as_str())),
+                include_responder.then_some(secrets.http_hmac.as_str()),
                 secrets.eab.as_ref(),
                 messages,
             )
@@ -329,12 +498,15 @@ pub(super) async fn write_ca_trust_fingerprints_with_retry(
         &fingerprints,
         &ca_bundle_pem,
     );
+    let generation = crate::commands::trust::next_trust_generation(
+        current_trust.as_ref(),
+        &fingerprints,
+        &ca_bundle_pem,
+    );
+    let payload =
+        crate::commands::trust::global_trust_payload(&fingerprints, &ca_bundle_pem, generation);
     let attempt = client
-        .write_kv(
-            kv_mount,
-            PATH_CA_TRUST,
-            serde_json::json!({ CA_TRUST_KEY: fingerprints, "ca_bundle_pem": ca_bundle_pem }),
-        )
+        .write_kv(kv_mount, PATH_CA_TRUST, payload.clone())
         .await;
     if let Err(err) = attempt {
         let message = err.to_string();
@@ -344,11 +516,7 @@ pub(super) async fn write_ca_trust_fingerprints_with_retry(
                 .await
                 .with_context(|| messages.error_openbao_kv_mount_failed())?;
             client
-                .write_kv(
-                    kv_mount,
-                    PATH_CA_TRUST,
-                    serde_json::json!({ CA_TRUST_KEY: fingerprints, "ca_bundle_pem": ca_bundle_pem }),
-                )
+                .write_kv(kv_mount, PATH_CA_TRUST, payload)
                 .await
                 .with_context(|| messages.error_openbao_kv_write_failed())?;
         } else {
@@ -417,7 +585,19 @@ pub(crate) fn validate_rotate_bound_cidrs(values: &[String], messages: &Messages
     Ok(())
 }
 
-fn build_policy_map(kv_mount: &str) -> BTreeMap<String, String> {
+/// Validates `--approle-bind-cidr` values (shared by `bootroot init` and
+/// `bootroot service add`).
+pub(crate) fn validate_approle_bind_cidrs(values: &[String], messages: &Messages) -> Result<()> {
+    for value in values {
+        if bootroot::input_validation::validate_cidr(value).is_err() {
+            anyhow::bail!(messages.error_approle_bind_cidr_invalid(value));
+        }
+    }
+    Ok(())
+}
+
+/// Builds the policy bodies for the given init `AppRole` labels.
+fn build_policy_map(kv_mount: &str, labels: &[AppRoleLabel]) -> BTreeMap<String, String> {
     let mut policies = BTreeMap::new();
     policies.insert(
         POLICY_BOOTROOT_AGENT.to_string(),
@@ -528,6 +708,7 @@ path "auth/approle/role/{APPROLE_BOOTROOT_RUNTIME_ROTATE}/secret-id" {{
         POLICY_BOOTROOT_INFRA_ROTATE.to_string(),
         infra_rotate_policy(),
     );
+    policies.retain(|name, _| labels.iter().any(|label| label.policy_name() == name));
     policies
 }
 
@@ -568,36 +749,39 @@ path "auth/approle/role/{APPROLE_BOOTROOT_INFRA_ROTATE}/secret-id" {{
 async fn write_openbao_secrets(
     client: &OpenBaoClient,
     kv_mount: &str,
-    stepca_password: &str,
-    db_dsn: &str,
-    http_hmac: &str,
+    stepca: Option<(&str, &str)>,
+    http_hmac: Option<&str>,
     eab: Option<&EabCredentials>,
     messages: &Messages,
 ) -> Result<()> {
-    client
-        .write_kv(
-            kv_mount,
-            PATH_STEPCA_PASSWORD,
-            serde_json::json!({ "value": stepca_password }),
-        )
-        .await
-        .with_context(|| messages.error_openbao_kv_write_failed())?;
-    client
-        .write_kv(
-            kv_mount,
-            PATH_STEPCA_DB,
-            serde_json::json!({ "value": db_dsn }),
-        )
-        .await
-        .with_context(|| messages.error_openbao_kv_write_failed())?;
-    client
-        .write_kv(
-            kv_mount,
-            PATH_RESPONDER_HMAC,
-            serde_json::json!({ "value": http_hmac }),
-        )
-        .await
-        .with_context(|| messages.error_openbao_kv_write_failed())?;
+    if let Some((stepca_password, db_dsn)) = stepca {
+        client
+            .write_kv(
+                kv_mount,
+                PATH_STEPCA_PASSWORD,
+                serde_json::json!({ "value": stepca_password }),
+            )
+            .await
+            .with_context(|| messages.error_openbao_kv_write_failed())?;
+        client
+            .write_kv(
+                kv_mount,
+                PATH_STEPCA_DB,
+                serde_json::json!({ "value": db_dsn }),
+            )
+            .await
+            .with_context(|| messages.error_openbao_kv_write_failed())?;
+    }
+    if let Some(http_hmac) = http_hmac {
+        client
+            .write_kv(
+                kv_mount,
+                PATH_RESPONDER_HMAC,
+                serde_json::json!({ "value": http_hmac }),
+            )
+            .await
+            .with_context(|| messages.error_openbao_kv_write_failed())?;
+    }
     if let Some(eab) = eab {
         client
             .write_kv(
@@ -611,20 +795,77 @@ async fn write_openbao_secrets(
     Ok(())
 }
 
-// Each argument is a distinct init-time input (paths, role outputs,
-// templates, TLS gate); bundling them into a struct would only move the
-// same fields behind an indirection used at a single call site.
-#[allow(clippy::too_many_arguments)]
+/// Returns every file `setup_openbao_agents` may write under
+/// `secrets_dir`: each agent's `role_id`, `secret_id`, and `agent.hcl`
+/// plus the agent compose override.
+pub(super) fn openbao_agent_generated_paths(secrets_dir: &Path) -> Vec<PathBuf> {
+    openbao_agent_paths_for(secrets_dir, true, true)
+}
+
+/// Returns the files [`setup_openbao_agents`] writes for the step-ca
+/// and responder agents that are enabled, or nothing when neither is.
+pub(super) fn openbao_agent_paths_for(
+    secrets_dir: &Path,
+    stepca: bool,
+    responder: bool,
+) -> Vec<PathBuf> {
+    let base_dir = secrets_dir.join(OPENBAO_AGENT_DIR);
+    let mut paths = Vec::new();
+    for (enabled, agent_dir) in [
+        (stepca, OPENBAO_AGENT_STEPCA_DIR),
+        (responder, OPENBAO_AGENT_RESPONDER_DIR),
+    ] {
+        if !enabled {
+            continue;
+        }
+        let agent_dir = base_dir.join(agent_dir);
+        paths.push(agent_dir.join(OPENBAO_AGENT_ROLE_ID_NAME));
+        paths.push(agent_dir.join(OPENBAO_AGENT_SECRET_ID_NAME));
+        paths.push(agent_dir.join(OPENBAO_AGENT_CONFIG_NAME));
+    }
+    if !paths.is_empty() {
+        paths.push(base_dir.join(OPENBAO_AGENT_COMPOSE_OVERRIDE_NAME));
+    }
+    paths
+}
+
+/// Init-time inputs shared by the step-ca and responder `OpenBao` Agents.
+pub(super) struct OpenBaoAgentInputs<'a> {
+    pub(super) secrets_dir: &'a Path,
+    pub(super) agent_image: &'a str,
+    pub(super) mount_root: &'a str,
+    pub(super) exec: &'a AgentExecCommands,
+    pub(super) role_outputs: &'a [AppRoleOutput],
+    pub(super) stepca_templates: Option<&'a StepCaTemplatePaths>,
+    pub(super) responder_template: Option<&'a Path>,
+}
+
 pub(super) async fn setup_openbao_agents(
     compose_file: &Path,
-    secrets_dir: &Path,
     openbao_url: &str,
-    role_outputs: &[AppRoleOutput],
-    stepca_templates: &StepCaTemplatePaths,
-    responder_template: &Path,
+    inputs: &OpenBaoAgentInputs<'_>,
     tls_required: bool,
+    render_timeout: Duration,
+    rollback: &mut InitRollback,
     messages: &Messages,
 ) -> Result<OpenBaoAgentPaths> {
+    let OpenBaoAgentInputs {
+        secrets_dir,
+        agent_image,
+        mount_root,
+        stepca_templates,
+        responder_template,
+        ..
+    } = *inputs;
+    if stepca_templates.is_none() && responder_template.is_none() {
+        // `--skip-step-ca` together with `--skip-responder` leaves no
+        // infra agent to run.
+        return Ok(OpenBaoAgentPaths {
+            stepca_agent_config: None,
+            responder_agent_config: None,
+            compose_override_path: None,
+        });
+    }
     let compose_has_openbao = compose_has_openbao(compose_file, messages)?;
     let mut openbao_agent_addr = resolve_openbao_agent_addr(openbao_url, compose_has_openbao);
     // The infra agents are generated *before* OpenBao flips to TLS
@@ -636,24 +877,25 @@ pub(super) async fn setup_openbao_agents(
     // `resolve_openbao_agent_addr` helper.
     let ca_cert_container_path = if tls_required {
         openbao_agent_addr = force_https_scheme(&openbao_agent_addr);
-        Some(provision_agent_ca_bundle(secrets_dir, messages).await?)
+        Some(provision_agent_ca_bundle(secrets_dir, mount_root, messages).await?)
     } else {
         None
     };
     let mut openbao_agent_paths = write_openbao_agent_files(
-        secrets_dir,
+        inputs,
         &openbao_agent_addr,
-        role_outputs,
-        stepca_templates,
-        responder_template,
         ca_cert_container_path.as_deref(),
         messages,
     )
     .await?;
+    let services = openbao_agent_paths.services();
     let openbao_agent_override = write_openbao_agent_compose_override(
         compose_file,
         secrets_dir,
         &openbao_agent_addr,
+        agent_image,
+        mount_root,
+        &services,
         messages,
     )
     .await?;
@@ -668,11 +910,157 @@ pub(super) async fn setup_openbao_agents(
     // server and fail their init-time render, breaking init.  The
     // plaintext-loopback path keeps the single-phase apply.
     if !tls_required && let Some(override_path) = openbao_agent_override.as_ref() {
-        apply_openbao_agent_compose_override(compose_file, override_path, messages)?;
+        // Register before applying so rollback stops agents that were
+        // started against config a later step never completed.
+        rollback.openbao_agent_compose_override = Some(override_path.clone());
+        rollback.openbao_agent_services.clone_from(&services);
+        rollback.compose_file = Some(compose_file.to_path_buf());
+        let renders = clear_openbao_agent_outputs(
+            openbao_agent_rendered_outputs(secrets_dir, &openbao_agent_paths, stepca_templates),
+            messages,
+        )
+        .await?;
+        apply_openbao_agent_compose_override(compose_file, override_path, &services, messages)?;
+        verify_openbao_agent_renders(&renders, render_timeout, messages).await?;
     }
     Ok(openbao_agent_paths)
 }
 
+/// Returns each file the infra agents render from KV, paired with the
+/// compose service that renders it.
+pub(super) fn openbao_agent_rendered_outputs(
+    secrets_dir: &Path,
+    paths: &OpenBaoAgentPaths,
+    stepca_templates: Option<&StepCaTemplatePaths>,
+) -> Vec<(&'static str, PathBuf)> {
+    let mut outputs = Vec::new();
+    if paths.stepca_agent_config.is_some()
+        && let Some(templates) = stepca_templates
+    {
+        outputs.push((
+            OPENBAO_AGENT_STEPCA_SERVICE,
+            secrets_dir.join("password.txt"),
+        ));
+        outputs.push((
+            OPENBAO_AGENT_STEPCA_SERVICE,
+            templates.ca_json_output.clone(),
+        ));
+    }
+    if paths.responder_agent_config.is_some() {
+        outputs.push((
+            OPENBAO_AGENT_RESPONDER_SERVICE,
+            secrets_dir.join("responder").join("responder.toml"),
+        ));
+    }
+    outputs
+}
+
+/// An agent-rendered file emptied by [`clear_openbao_agent_outputs`],
+/// with the contents init wrote before the agent started.
+pub(super) struct PendingRender {
+    service: &'static str,
+    path: PathBuf,
+    previous: Option<Vec<u8>>,
+}
+
+/// Empties every agent-rendered file before the agents start, keeping
+/// the contents init wrote itself.
+///
+/// Init seeds `password.txt` and `responder.toml` with the same values
+/// the agents render, and the agent skips writing a file whose content is
+/// unchanged, so neither a non-empty file nor its mtime proves a render.
+/// Truncating in place keeps the file mode.
+pub(super) async fn clear_openbao_agent_outputs(
+    outputs: Vec<(&'static str, PathBuf)>,
+    messages: &Messages,
+) -> Result<Vec<PendingRender>> {
+    let mut pending = Vec::with_capacity(outputs.len());
+    for (service, path) in outputs {
+        let previous = match tokio::fs::read(&path).await {
+            Ok(contents) => {
+                overwrite_in_place(&path, &[]).await.with_context(|| {
+                    messages.error_write_file_failed(&path.display().to_string())
+                })?;
+                Some(contents)
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| messages.error_read_file_failed(&path.display().to_string()));
+            }
+        };
+        pending.push(PendingRender {
+            service,
+            path,
+            previous,
+        });
+    }
+    Ok(pending)
+}
+
+async fn overwrite_in_place(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .open(path)
+        .await?;
+    file.write_all(contents).await?;
+    file.flush().await
+}
+
+/// Polls until every file emptied by [`clear_openbao_agent_outputs`] is
+/// rendered again, then warns about each one still missing or empty once
+/// `timeout` expires, puts back the contents init wrote, and returns
+/// their paths.
+///
+/// A broken template or a missing KV read permission does not fail the
+/// agent container, so without this check init would finish without
+/// noticing that the agent never took over `password.txt` or
+/// `responder.toml`.
+pub(super) async fn verify_openbao_agent_renders(
+    outputs: &[PendingRender],
+    timeout: Duration,
+    messages: &Messages,
+) -> Result<Vec<PathBuf>> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        let mut pending = Vec::new();
+        for output in outputs {
+            let rendered = tokio::fs::metadata(&output.path)
+                .await
+                .is_ok_and(|metadata| metadata.len() > 0);
+            if !rendered {
+                pending.push(output);
+            }
+        }
+        if pending.is_empty() {
+            return Ok(Vec::new());
+        }
+        if tokio::time::Instant::now() >= deadline {
+            let seconds = timeout.as_secs().to_string();
+            for output in &pending {
+                let path = output.path.display().to_string();
+                if let Some(previous) = output.previous.as_deref() {
+                    overwrite_in_place(&output.path, previous)
+                        .await
+                        .with_context(|| messages.error_write_file_failed(&path))?;
+                }
+                eprintln!(
+                    "{}",
+                    messages.warning_openbao_agent_render_missing(output.service, &path, &seconds)
+                );
+            }
+            return Ok(pending
+                .into_iter()
+                .map(|output| output.path.clone())
+                .collect());
+        }
+        cancellation::global()
+            .sleep(OPENBAO_AGENT_RENDER_POLL_INTERVAL, messages)
+            .await?;
+    }
+}
+
 /// Writes the leaf-chain CA bundle the infra agents use to verify the
 /// step-ca-signed `OpenBao` TLS leaf and returns its container-internal
 /// path.
@@ -683,11 +1071,20 @@ pub(super) async fn setup_openbao_agents(
 /// writes `secrets/certs/ca-bundle.pem` at `0644` via
 /// [`fs_util::write_ca_bundle`] so the separate agent container can read
 /// the bind-mounted file.
-async fn provision_agent_ca_bundle(secrets_dir: &Path, messages: &Messages) -> Result<String> {
+async fn provision_agent_ca_bundle(
+    secrets_dir: &Path,
+    mount_root: &str,
+    messages: &Messages,
+) -> Result<String> {
     let ca_bundle_pem = compute_ca_bundle_pem(secrets_dir, messages).await?;
-    let bundle_path = secrets_dir.join(CA_CERTS_DIR).join(CA_BUNDLE_FILENAME);
+    let bundle_path = agent_ca_bundle_path(secrets_dir);
     fs_util::write_ca_bundle(&bundle_path, &ca_bundle_pem, CertGroupPolicy::none()).await?;
-    to_container_path(secrets_dir, &bundle_path, OPENBAO_AGENT_SECRETS_MOUNT)
+    to_container_path(secrets_dir, &bundle_path, mount_root)
+}
+
+/// Returns the CA bundle the infra agents trust for a TLS `OpenBao`.
+pub(super) fn agent_ca_bundle_path(secrets_dir: &Path) -> PathBuf {
+    secrets_dir.join(CA_CERTS_DIR).join(CA_BUNDLE_FILENAME)
 }
 
 /// Rewrites an `http://` agent address to `https://`, leaving any other
@@ -700,39 +1097,70 @@ fn force_https_scheme(addr: &str) -> String {
 }
 
 async fn write_openbao_agent_files(
-    secrets_dir: &Path,
+    inputs: &OpenBaoAgentInputs<'_>,
     openbao_addr: &str,
-    role_outputs: &[AppRoleOutput],
-    stepca_templates: &StepCaTemplatePaths,
-    responder_template: &Path,
     ca_cert: Option<&str>,
     messages: &Messages,
 ) -> Result<OpenBaoAgentPaths> {
-    let base_dir = secrets_dir.join(OPENBAO_AGENT_DIR);
+    let base_dir = inputs.secrets_dir.join(OPENBAO_AGENT_DIR);
     fs_util::ensure_secrets_dir(&base_dir).await?;
-    let stepca_dir = base_dir.join(OPENBAO_AGENT_STEPCA_DIR);
+
+    let stepca_agent_config = match inputs.stepca_templates {
+        Some(templates) => Some(
+            write_stepca_agent_files(
+                inputs,
+                &base_dir,
+                openbao_addr,
+                templates,
+                ca_cert,
+                messages,
+            )
+            .await?,
+        ),
+        None => None,
+    };
+    let responder_agent_config = match inputs.responder_template {
+        Some(template) => Some(
+            write_responder_agent_files(
+                inputs,
+                &base_dir,
+                openbao_addr,
+                template,
+                ca_cert,
+                messages,
+            )
+            .await?,
+        ),
+        None => None,
+    };
+
+    Ok(OpenBaoAgentPaths {
+        stepca_agent_config,
+        responder_agent_config,
+        compose_override_path: None,
+    })
+}
+
+/// Writes the responder agent's `AppRole` credentials and `agent.hcl`
+/// (rendering `responder.toml`) and returns the config path.
+async fn write_responder_agent_files(
+    inputs: &OpenBaoAgentInputs<'_>,
+    base_dir: &Path,
+    openbao_addr: &str,
+    responder_template: &Path,
+    ca_cert: Option<&str>,
+    messages: &Messages,
+) -> Result<PathBuf> {
+    let OpenBaoAgentInputs {
+        secrets_dir,
+        mount_root,
+        role_outputs,
+        ..
+    } = *inputs;
     let responder_dir = base_dir.join(OPENBAO_AGENT_RESPONDER_DIR);
-    fs_util::ensure_secrets_dir(&stepca_dir).await?;
     fs_util::ensure_secrets_dir(&responder_dir).await?;
-
-    let stepca_role = find_role_output(role_outputs, AppRoleLabel::Stepca, messages)?;
     let responder_role = find_role_output(role_outputs, AppRoleLabel::Responder, messages)?;
 
-    let stepca_role_id_path = stepca_dir.join(OPENBAO_AGENT_ROLE_ID_NAME);
-    let stepca_secret_id_path = stepca_dir.join(OPENBAO_AGENT_SECRET_ID_NAME);
-    tokio::fs::write(&stepca_role_id_path, &stepca_role.role_id)
-        .await
-        .with_context(|| {
-            messages.error_write_file_failed(&stepca_role_id_path.display().to_string())
-        })?;
-    tokio::fs::write(&stepca_secret_id_path, &stepca_role.secret_id)
-        .await
-        .with_context(|| {
-            messages.error_write_file_failed(&stepca_secret_id_path.display().to_string())
-        })?;
-    fs_util::set_key_permissions(&stepca_role_id_path).await?;
-    fs_util::set_key_permissions(&stepca_secret_id_path).await?;
-
     let responder_role_id_path = responder_dir.join(OPENBAO_AGENT_ROLE_ID_NAME);
     let responder_secret_id_path = responder_dir.join(OPENBAO_AGENT_SECRET_ID_NAME);
     tokio::fs::write(&responder_role_id_path, &responder_role.role_id)
@@ -748,69 +1176,158 @@ async fn write_openbao_agent_files(
     fs_util::set_key_permissions(&responder_role_id_path).await?;
     fs_util::set_key_permissions(&responder_secret_id_path).await?;
 
-    let stepca_agent_config = stepca_dir.join(OPENBAO_AGENT_CONFIG_NAME);
     let responder_agent_config = responder_dir.join(OPENBAO_AGENT_CONFIG_NAME);
-    let mount = OPENBAO_AGENT_SECRETS_MOUNT;
-    let password_template =
-        to_container_path(secrets_dir, &stepca_templates.password_template_path, mount)?;
-    let ca_json_template =
-        to_container_path(secrets_dir, &stepca_templates.ca_json_template_path, mount)?;
+    let mount = mount_root;
     let responder_template = to_container_path(secrets_dir, responder_template, mount)?;
-    let password_output = to_container_path(secrets_dir, &secrets_dir.join("password.txt"), mount)?;
-    let ca_json_output = to_container_path(
-        secrets_dir,
-        &secrets_dir.join("config").join("ca.json"),
-        mount,
-    )?;
     let responder_output = to_container_path(
         secrets_dir,
         &secrets_dir.join("responder").join("responder.toml"),
         mount,
     )?;
+    let responder_config = build_openbao_agent_config(
+        openbao_addr,
+        mount_root,
+        &agent_credential_path(
+            mount_root,
+            OPENBAO_AGENT_RESPONDER_DIR,
+            OPENBAO_AGENT_ROLE_ID_NAME,
+        ),
+        &agent_credential_path(
+            mount_root,
+            OPENBAO_AGENT_RESPONDER_DIR,
+            OPENBAO_AGENT_SECRET_ID_NAME,
+        ),
+        &[(responder_template, responder_output)],
+        ca_cert,
+        inputs.exec.responder.as_deref(),
+    );
+    tokio::fs::write(&responder_agent_config, responder_config)
+        .await
+        .with_context(|| {
+            messages.error_write_file_failed(&responder_agent_config.display().to_string())
+        })?;
+    fs_util::set_key_permissions(&responder_agent_config).await?;
+    Ok(responder_agent_config)
+}
+
+/// Writes the step-ca agent's `AppRole` credentials and `agent.hcl`
+/// (rendering `password.txt` and `ca.json`) and returns the config path.
+async fn write_stepca_agent_files(
+    inputs: &OpenBaoAgentInputs<'_>,
+    base_dir: &Path,
+    openbao_addr: &str,
+    stepca_templates: &StepCaTemplatePaths,
+    ca_cert: Option<&str>,
+    messages: &Messages,
+) -> Result<PathBuf> {
+    let OpenBaoAgentInputs {
+        secrets_dir,
+        mount_root,
+        role_outputs,
+        ..
+    } = *inputs;
+    let stepca_dir = base_dir.join(OPENBAO_AGENT_STEPCA_DIR);
+    fs_util::ensure_secrets_dir(&stepca_dir).await?;
+    let stepca_role = find_role_output(role_outputs, AppRoleLabel::Stepca, messages)?;
+
+    let stepca_role_id_path = stepca_dir.join(OPENBAO_AGENT_ROLE_ID_NAME);
+    let stepca_secret_id_path = stepca_dir.join(OPENBAO_AGENT_SECRET_ID_NAME);
+    tokio::fs::write(&stepca_role_id_path, &stepca_role.role_id)
+        .await
+        .with_context(|| {
+            messages.error_write_file_failed(&stepca_role_id_path.display().to_string())
+        })?;
+    tokio::fs::write(&stepca_secret_id_path, &stepca_role.secret_id)
+        .await
+        .with_context(|| {
+            messages.error_write_file_failed(&stepca_secret_id_path.display().to_string())
+        })?;
+    fs_util::set_key_permissions(&stepca_role_id_path).await?;
+    fs_util::set_key_permissions(&stepca_secret_id_path).await?;
+
+    let stepca_agent_config = stepca_dir.join(OPENBAO_AGENT_CONFIG_NAME);
+    let mount = mount_root;
+    let password_template =
+        to_container_path(secrets_dir, &stepca_templates.password_template_path, mount)?;
+    let ca_json_template =
+        to_container_path(secrets_dir, &stepca_templates.ca_json_template_path, mount)?;
+    let password_output = to_container_path(secrets_dir, &secrets_dir.join("password.txt"), mount)?;
+    let ca_json_output = to_container_path(secrets_dir, &stepca_templates.ca_json_output, mount)?;
     let stepca_config = build_openbao_agent_config(
         openbao_addr,
-        "/openbao/secrets/openbao/stepca/role_id",
-        "/openbao/secrets/openbao/stepca/secret_id",
+        mount_root,
+        &agent_credential_path(
+            mount_root,
+            OPENBAO_AGENT_STEPCA_DIR,
+            OPENBAO_AGENT_ROLE_ID_NAME,
+        ),
+        &agent_credential_path(
+            mount_root,
+            OPENBAO_AGENT_STEPCA_DIR,
+            OPENBAO_AGENT_SECRET_ID_NAME,
+        ),
         &[
             (password_template, password_output),
             (ca_json_template, ca_json_output),
         ],
         ca_cert,
-    );
-    let responder_config = build_openbao_agent_config(
-        openbao_addr,
-        "/openbao/secrets/openbao/responder/role_id",
-        "/openbao/secrets/openbao/responder/secret_id",
-        &[(responder_template, responder_output)],
-        ca_cert,
+        inputs.exec.stepca.as_deref(),
     );
     tokio::fs::write(&stepca_agent_config, stepca_config)
         .await
         .with_context(|| {
             messages.error_write_file_failed(&stepca_agent_config.display().to_string())
         })?;
-    tokio::fs::write(&responder_agent_config, responder_config)
-        .await
-        .with_context(|| {
-            messages.error_write_file_failed(&responder_agent_config.display().to_string())
-        })?;
     fs_util::set_key_permissions(&stepca_agent_config).await?;
-    fs_util::set_key_permissions(&responder_agent_config).await?;
+    Ok(stepca_agent_config)
+}
 
-    Ok(OpenBaoAgentPaths {
-        stepca_agent_config,
-        responder_agent_config,
-        compose_override_path: None,
-    })
+/// Consumer commands the step-ca and responder agents supervise through
+/// an `exec` block, from `--openbao-agent-exec AGENT=CMD`.
+#[derive(Debug, Default)]
+pub(crate) struct AgentExecCommands {
+    pub(crate) stepca: Option<String>,
+    pub(crate) responder: Option<String>,
+}
+
+impl AgentExecCommands {
+    /// Parses `AGENT=CMD` entries, where `AGENT` is `stepca` or
+    /// `responder`; a later entry for the same agent wins.
+    pub(crate) fn parse(values: &[String], messages: &Messages) -> Result<Self> {
+        let mut commands = Self::default();
+        for value in values {
+            let invalid = || anyhow::anyhow!(messages.error_openbao_agent_exec_invalid(value));
+            let (agent, command) = value.split_once('=').ok_or_else(invalid)?;
+            let command = command.trim();
+            if command.is_empty() {
+                return Err(invalid());
+            }
+            let slot = match agent.trim() {
+                OPENBAO_AGENT_STEPCA_DIR => &mut commands.stepca,
+                OPENBAO_AGENT_RESPONDER_DIR => &mut commands.responder,
+                _ => return Err(invalid()),
+            };
+            *slot = Some(command.to_string());
+        }
+        Ok(commands)
+    }
+}
+
+/// Returns the container path of an agent's `AppRole` credential file.
+pub(super) fn agent_credential_path(mount_root: &str, agent_dir: &str, name: &str) -> String {
+    format!("{mount_root}/{OPENBAO_AGENT_DIR}/{agent_dir}/{name}")
 }
 
-fn build_openbao_agent_config(
+pub(super) fn build_openbao_agent_config(
     openbao_addr: &str,
+    mount_root: &str,
     role_id_path: &str,
     secret_id_path: &str,
     templates: &[(String, String)],
     ca_cert: Option<&str>,
+    exec_command: Option<&str>,
 ) -> String {
+    let token_path = format!("{mount_root}/{OPENBAO_AGENT_DIR}/{INIT_AGENT_TOKEN_NAME}");
     // Every template here renders secret material (password, CA signing
     // key JSON, responder config), so all stay at 0600.
     let tpl_specs: Vec<bootroot::openbao::TemplateSpec<'_>> = templates
@@ -825,11 +1342,12 @@ fn build_openbao_agent_config(
         openbao_addr,
         role_id_path,
         secret_id_path,
-        token_path: INIT_AGENT_TOKEN_PATH,
+        token_path: &token_path,
         mount_path: None,
         render_interval: bootroot::openbao::STATIC_SECRET_RENDER_INTERVAL,
         templates: &tpl_specs,
         ca_cert,
+        exec_command,
     })
 }
 
@@ -837,6 +1355,9 @@ async fn write_openbao_agent_compose_override(
     compose_file: &Path,
     secrets_dir: &Path,
     openbao_addr: &str,
+    agent_image: &str,
+    container_mount: &str,
+    services: &[&str],
     messages: &Messages,
 ) -> Result<Option<PathBuf>> {
     let agent_dir = secrets_dir.join(OPENBAO_AGENT_DIR);
@@ -855,38 +1376,29 @@ async fn write_openbao_agent_compose_override(
         use std::os::unix::fs::MetadataExt;
         format!("{}:{}", meta.uid(), meta.gid())
     };
-    let contents = format!(
-        r#"version: "3.8"
-services:
-  {stepca_service}:
-    image: {agent_image}
-    container_name: bootroot-openbao-agent-stepca
-    user: "{user}"
-    restart: always
-    command: ["agent", "-config=/openbao/secrets/openbao/stepca/agent.hcl"]
-{depends_on}    environment:
-      - VAULT_ADDR={openbao_addr}
-    volumes:
-      - {secrets_path}:/openbao/secrets
-  {responder_service}:
+    let mut contents = String::from("version: \"3.8\"\nservices:\n");
+    for &service in services {
+        let config_dir = if service == OPENBAO_AGENT_STEPCA_SERVICE {
+            OPENBAO_AGENT_STEPCA_DIR
+        } else {
+            OPENBAO_AGENT_RESPONDER_DIR
+        };
+        let _ = write!(
+            contents,
+            r#"  {service}:
     image: {agent_image}
-    container_name: bootroot-openbao-agent-responder
+    container_name: bootroot-{service}
     user: "{user}"
     restart: always
-    command: ["agent", "-config=/openbao/secrets/openbao/responder/agent.hcl"]
+    command: ["agent", "-config={container_mount}/{OPENBAO_AGENT_DIR}/{config_dir}/{OPENBAO_AGENT_CONFIG_NAME}"]
 {depends_on}    environment:
       - VAULT_ADDR={openbao_addr}
     volumes:
-      - {secrets_path}:/openbao/secrets
+      - {secrets_path}:{container_mount}
 "#,
-        stepca_service = OPENBAO_AGENT_STEPCA_SERVICE,
-        responder_service = OPENBAO_AGENT_RESPONDER_SERVICE,
-        agent_image = OPENBAO_AGENT_IMAGE,
-        depends_on = depends_on,
-        openbao_addr = openbao_addr,
-        secrets_path = mount_root.display(),
-        user = user
-    );
+            secrets_path = mount_root.display(),
+        );
+    }
     tokio::fs::write(&override_path, contents)
         .await
         .with_context(|| messages.error_write_file_failed(&override_path.display().to_string()))?;
@@ -896,6 +1408,7 @@ services:
 pub(super) fn apply_openbao_agent_compose_override(
     compose_file: &Path,
     override_path: &Path,
+    services: &[&str],
     messages: &Messages,
 ) -> Result<()> {
     let compose_str = compose_file.to_string_lossy();
@@ -911,7 +1424,7 @@ pub(super) fn apply_openbao_agent_compose_override(
     // trip: infra-up starts openbao with the preserved exposed
     // override, and the agent compose-up here recreates it back to
     // loopback unless we tell compose to ignore the dependency.
-    let args = [
+    let mut args = vec![
         "compose",
         "-f",
         &*compose_str,
@@ -920,9 +1433,8 @@ pub(super) fn apply_openbao_agent_compose_override(
         "up",
         "-d",
         "--no-deps",
-        OPENBAO_AGENT_STEPCA_SERVICE,
-        OPENBAO_AGENT_RESPONDER_SERVICE,
     ];
+    args.extend_from_slice(services);
     run_docker(&args, "docker compose openbao agent override", messages)?;
     Ok(())
 }
@@ -950,14 +1462,86 @@ mod tests {
         POLICY_BOOTROOT_AGENT, POLICY_BOOTROOT_INFRA_ROTATE, POLICY_BOOTROOT_RUNTIME_ROTATE,
         POLICY_BOOTROOT_RUNTIME_SERVICE_ADD,
     };
-    use super::super::super::constants::{CA_INTERMEDIATE_CERT_FILENAME, CA_ROOT_CERT_FILENAME};
+    use super::super::super::constants::{
+        CA_INTERMEDIATE_CERT_FILENAME, CA_ROOT_CERT_FILENAME, DEFAULT_OPENBAO_AGENT_IMAGE,
+        DEFAULT_OPENBAO_AGENT_MOUNT_ROOT, OPENBAO_AGENT_RESPONDER_SERVICE,
+    };
     use super::super::super::paths::resolve_openbao_agent_addr;
     use super::super::super::types::{AppRoleLabel, AppRoleOutput};
     use super::super::responder_setup::write_responder_files;
     use super::super::stepca_setup::write_stepca_templates;
-    use super::super::test_support::{test_cert_pem, test_messages};
+    use super::super::test_support::{
+        assert_golden, default_init_args, test_cert_pem, test_messages,
+    };
     use super::*;
 
+    #[tokio::test]
+    async fn test_bootstrap_openbao_auto_unseal_uses_recovery_keys_and_skips_unseal() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/init"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"initialized": false})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/sys/init"))
+            .and(body_json(serde_json::json!({
+                "recovery_shares": INIT_SECRET_SHARES,
+                "recovery_threshold": INIT_SECRET_THRESHOLD
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "keys": [],
+                "keys_base64": [],
+                "recovery_keys": ["recovery-1", "recovery-2"],
+                "recovery_keys_base64": ["cmVjb3ZlcnktMQ==", "cmVjb3ZlcnktMg=="],
+                "root_token": "root-from-init"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // A sealed status would trigger the unseal prompt; auto-unseal
+        // must not even ask.
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/seal-status"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"sealed": true})),
+            )
+            .expect(0)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/sys/unseal"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let mut args = default_init_args();
+        args.auto_unseal = true;
+        let mut client = OpenBaoClient::new(&server.uri()).unwrap();
+        let bootstrap = bootstrap_openbao(
+            &mut client,
+            &args,
+            &mut InitAuditLog::default(),
+            &test_messages(),
+        )
+        .await
+        .unwrap();
+
+        assert!(bootstrap.init_response.is_some());
+        assert_eq!(bootstrap.root_token, "root-from-init");
+        assert!(bootstrap.unseal_keys.is_empty());
+        assert_eq!(
+            bootstrap.recovery_keys,
+            vec!["recovery-1".to_string(), "recovery-2".to_string()]
+        );
+    }
+
     fn stepca_and_responder_roles() -> Vec<AppRoleOutput> {
         vec![
             AppRoleOutput {
@@ -990,10 +1574,16 @@ mod tests {
         .unwrap();
 
         let messages = test_messages();
-        let stepca_templates =
-            write_stepca_templates(&secrets_dir, "secret", "24h", "acme", &messages)
-                .await
-                .unwrap();
+        let stepca_templates = write_stepca_templates(
+            &secrets_dir,
+            &secrets_dir.join("config").join("ca.json"),
+            "secret",
+            "24h",
+            "acme",
+            &messages,
+        )
+        .await
+        .unwrap();
         let responder_paths =
             write_responder_files(&secrets_dir, "secret", "hmac-123", false, &messages)
                 .await
@@ -1002,18 +1592,25 @@ mod tests {
         let role_outputs = stepca_and_responder_roles();
 
         let paths = write_openbao_agent_files(
-            &secrets_dir,
+            &OpenBaoAgentInputs {
+                secrets_dir: &secrets_dir,
+                agent_image: DEFAULT_OPENBAO_AGENT_IMAGE,
+                mount_root: DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
+                exec: &AgentExecCommands::default(),
+                role_outputs: &role_outputs,
+                stepca_templates: Some(&stepca_templates),
+                responder_template: Some(&responder_paths.template_path),
+            },
             "http://openbao:8200",
-            &role_outputs,
-            &stepca_templates,
-            &responder_paths.template_path,
             None,
             &messages,
         )
         .await
         .unwrap();
-        let stepca_config = fs::read_to_string(&paths.stepca_agent_config).unwrap();
-        let responder_config = fs::read_to_string(&paths.responder_agent_config).unwrap();
+        let stepca_config =
+            fs::read_to_string(paths.stepca_agent_config.as_ref().unwrap()).unwrap();
+        let responder_config =
+            fs::read_to_string(paths.responder_agent_config.as_ref().unwrap()).unwrap();
 
         assert!(stepca_config.contains("role_id_file_path"));
         assert!(stepca_config.contains("password.txt.ctmpl"));
@@ -1023,6 +1620,157 @@ mod tests {
         assert!(!responder_config.contains("ca_cert"));
     }
 
+    #[test]
+    fn test_agent_exec_commands_parse() {
+        let messages = test_messages();
+        let commands = AgentExecCommands::parse(
+            &[
+                "stepca=step-ca /home/step/config/ca.json".to_string(),
+                "responder=bootroot-http01-responder".to_string(),
+            ],
+            &messages,
+        )
+        .unwrap();
+        assert_eq!(
+            commands.stepca.as_deref(),
+            Some("step-ca /home/step/config/ca.json")
+        );
+        assert_eq!(
+            commands.responder.as_deref(),
+            Some("bootroot-http01-responder")
+        );
+        for invalid in ["step-ca", "agent=run", "stepca=  "] {
+            let err = AgentExecCommands::parse(&[invalid.to_string()], &messages).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                messages.error_openbao_agent_exec_invalid(invalid)
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_openbao_agent_config_emits_exec_block() {
+        let config = build_openbao_agent_config(
+            "http://openbao:8200",
+            DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
+            "/openbao/secrets/openbao/stepca/role_id",
+            "/openbao/secrets/openbao/stepca/secret_id",
+            &[(
+                "/openbao/secrets/templates/password.txt.ctmpl".to_string(),
+                "/openbao/secrets/password.txt".to_string(),
+            )],
+            None,
+            Some("step-ca /home/step/config/ca.json --password-file /home/step/password.txt"),
+        );
+        assert!(config.contains(
+            "\nexec {\n  command = [\"step-ca\",\"/home/step/config/ca.json\",\
+             \"--password-file\",\"/home/step/password.txt\"]\n  \
+             restart_on_secret_changes = \"always\"\n  restart_stop_signal = \"SIGTERM\"\n}\n"
+        ));
+        // The template still renders to file alongside the exec block.
+        assert!(config.contains("source = \"/openbao/secrets/templates/password.txt.ctmpl\""));
+    }
+
+    #[tokio::test]
+    async fn test_write_openbao_agent_files_uses_custom_mount_root() {
+        let temp_dir = tempdir().unwrap();
+        let secrets_dir = temp_dir.path().join("secrets");
+        fs::create_dir_all(secrets_dir.join("config")).unwrap();
+        fs::write(
+            secrets_dir.join("config").join("ca.json"),
+            r#"{
+                "authority":{"provisioners":[{"type":"ACME","name":"acme"}]},
+                "db":{"type":"postgresql","dataSource":"old"}
+            }"#,
+        )
+        .unwrap();
+
+        let messages = test_messages();
+        let stepca_templates = write_stepca_templates(
+            &secrets_dir,
+            &secrets_dir.join("config").join("ca.json"),
+            "secret",
+            "24h",
+            "acme",
+            &messages,
+        )
+        .await
+        .unwrap();
+
+        let paths = write_openbao_agent_files(
+            &OpenBaoAgentInputs {
+                secrets_dir: &secrets_dir,
+                agent_image: DEFAULT_OPENBAO_AGENT_IMAGE,
+                mount_root: "/vault/secrets",
+                exec: &AgentExecCommands::default(),
+                role_outputs: &stepca_and_responder_roles(),
+                stepca_templates: Some(&stepca_templates),
+                responder_template: None,
+            },
+            "http://openbao:8200",
+            None,
+            &messages,
+        )
+        .await
+        .unwrap();
+        let stepca_config =
+            fs::read_to_string(paths.stepca_agent_config.as_ref().unwrap()).unwrap();
+
+        assert!(stepca_config.contains("\"/vault/secrets/openbao/token\""));
+        assert!(stepca_config.contains("\"/vault/secrets/openbao/stepca/role_id\""));
+        assert!(stepca_config.contains("\"/vault/secrets/templates/password.txt.ctmpl\""));
+        assert!(stepca_config.contains("\"/vault/secrets/password.txt\""));
+        assert!(!stepca_config.contains("/openbao/secrets"));
+    }
+
+    #[tokio::test]
+    async fn test_stepca_agent_config_matches_golden() {
+        let temp_dir = tempdir().unwrap();
+        let secrets_dir = temp_dir.path().join("secrets");
+        fs::create_dir_all(secrets_dir.join("config")).unwrap();
+        fs::write(
+            secrets_dir.join("config").join("ca.json"),
+            r#"{
+                "authority":{"provisioners":[{"type":"ACME","name":"acme"}]},
+                "db":{"type":"postgresql","dataSource":"old"}
+            }"#,
+        )
+        .unwrap();
+
+        let messages = test_messages();
+        let stepca_templates = write_stepca_templates(
+            &secrets_dir,
+            &secrets_dir.join("config").join("ca.json"),
+            "secret",
+            "24h",
+            "acme",
+            &messages,
+        )
+        .await
+        .unwrap();
+
+        let paths = write_openbao_agent_files(
+            &OpenBaoAgentInputs {
+                secrets_dir: &secrets_dir,
+                agent_image: DEFAULT_OPENBAO_AGENT_IMAGE,
+                mount_root: DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
+                exec: &AgentExecCommands::default(),
+                role_outputs: &stepca_and_responder_roles(),
+                stepca_templates: Some(&stepca_templates),
+                responder_template: None,
+            },
+            "http://openbao:8200",
+            None,
+            &messages,
+        )
+        .await
+        .unwrap();
+        let stepca_config =
+            fs::read_to_string(paths.stepca_agent_config.as_ref().unwrap()).unwrap();
+
+        assert_golden("stepca-agent.hcl", &stepca_config);
+    }
+
     /// TLS path: `setup_openbao_agents` must generate both agent files
     /// and the compose override in their final TLS form (https +
     /// HCL `ca_cert` + provisioned `certs/ca-bundle.pem`) but must NOT
@@ -1066,10 +1814,16 @@ mod tests {
         .unwrap();
 
         let messages = test_messages();
-        let stepca_templates =
-            write_stepca_templates(&secrets_dir, "secret", "24h", "acme", &messages)
-                .await
-                .unwrap();
+        let stepca_templates = write_stepca_templates(
+            &secrets_dir,
+            &secrets_dir.join("config").join("ca.json"),
+            "secret",
+            "24h",
+            "acme",
+            &messages,
+        )
+        .await
+        .unwrap();
         let responder_paths =
             write_responder_files(&secrets_dir, "secret", "hmac-123", false, &messages)
                 .await
@@ -1078,20 +1832,29 @@ mod tests {
 
         let paths = setup_openbao_agents(
             &compose_file,
-            &secrets_dir,
             // http:// input — the setup must force https:// for TLS.
             "http://127.0.0.1:8200",
-            &role_outputs,
-            &stepca_templates,
-            &responder_paths.template_path,
+            &OpenBaoAgentInputs {
+                secrets_dir: &secrets_dir,
+                agent_image: DEFAULT_OPENBAO_AGENT_IMAGE,
+                mount_root: DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
+                exec: &AgentExecCommands::default(),
+                role_outputs: &role_outputs,
+                stepca_templates: Some(&stepca_templates),
+                responder_template: Some(&responder_paths.template_path),
+            },
             true,
+            Duration::ZERO,
+            &mut InitRollback::default(),
             &messages,
         )
         .await
         .unwrap();
 
-        let stepca_config = fs::read_to_string(&paths.stepca_agent_config).unwrap();
-        let responder_config = fs::read_to_string(&paths.responder_agent_config).unwrap();
+        let stepca_config =
+            fs::read_to_string(paths.stepca_agent_config.as_ref().unwrap()).unwrap();
+        let responder_config =
+            fs::read_to_string(paths.responder_agent_config.as_ref().unwrap()).unwrap();
         let expected_ca = format!("/openbao/secrets/{CA_CERTS_DIR}/{CA_BUNDLE_FILENAME}");
         assert!(
             stepca_config.contains(&format!("ca_cert = \"{expected_ca}\"")),
@@ -1120,6 +1883,83 @@ mod tests {
         );
     }
 
+    fn seeded_agent_outputs(dir: &Path) -> Vec<(&'static str, PathBuf)> {
+        let paths = OpenBaoAgentPaths {
+            stepca_agent_config: Some(dir.join("stepca.hcl")),
+            responder_agent_config: Some(dir.join("responder.hcl")),
+            compose_override_path: None,
+        };
+        let stepca_templates = StepCaTemplatePaths {
+            password_template_path: dir.join("password.txt.ctmpl"),
+            ca_json_template_path: dir.join("ca.json.ctmpl"),
+            ca_json_output: dir.join("config").join("ca.json"),
+        };
+        let outputs = openbao_agent_rendered_outputs(dir, &paths, Some(&stepca_templates));
+        // Init seeds every output before the agents start.
+        fs::create_dir_all(dir.join("config")).unwrap();
+        fs::create_dir_all(dir.join("responder")).unwrap();
+        fs::write(dir.join("password.txt"), "secret").unwrap();
+        fs::write(dir.join("config").join("ca.json"), "{}").unwrap();
+        fs::write(
+            dir.join("responder").join("responder.toml"),
+            "hmac_secret = \"x\"",
+        )
+        .unwrap();
+        outputs
+    }
+
+    #[tokio::test]
+    async fn test_verify_openbao_agent_renders_flags_init_seeded_file_the_agent_never_renders() {
+        let dir = tempdir().unwrap();
+        let messages = test_messages();
+        let outputs = seeded_agent_outputs(dir.path());
+        assert!(
+            outputs
+                .iter()
+                .any(|(service, _)| *service == OPENBAO_AGENT_RESPONDER_SERVICE)
+        );
+        let responder_toml = dir.path().join("responder").join("responder.toml");
+
+        let renders = clear_openbao_agent_outputs(outputs, &messages)
+            .await
+            .unwrap();
+        // The step-ca agent renders (identical content); the responder
+        // agent never does, although init left the file non-empty.
+        fs::write(dir.path().join("password.txt"), "secret").unwrap();
+        fs::write(dir.path().join("config").join("ca.json"), "{}").unwrap();
+
+        let missing = verify_openbao_agent_renders(&renders, Duration::from_millis(100), &messages)
+            .await
+            .unwrap();
+        assert_eq!(missing, vec![responder_toml.clone()]);
+        assert_eq!(
+            fs::read_to_string(&responder_toml).unwrap(),
+            "hmac_secret = \"x\"",
+            "the init-written copy must be put back"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_openbao_agent_renders_accepts_fresh_renders() {
+        let dir = tempdir().unwrap();
+        let messages = test_messages();
+        let outputs = seeded_agent_outputs(dir.path());
+        let paths: Vec<PathBuf> = outputs.iter().map(|(_, path)| path.clone()).collect();
+
+        let renders = clear_openbao_agent_outputs(outputs, &messages)
+            .await
+            .unwrap();
+        for path in &paths {
+            assert!(fs::read(path).unwrap().is_empty());
+            fs::write(path, "rendered").unwrap();
+        }
+
+        let missing = verify_openbao_agent_renders(&renders, Duration::from_millis(100), &messages)
+            .await
+            .unwrap();
+        assert!(missing.is_empty());
+    }
+
     #[test]
     fn test_force_https_scheme_upgrades_http_only() {
         assert_eq!(
@@ -1152,6 +1992,12 @@ services:
             &compose_file,
             &secrets_dir,
             "http://openbao:8200",
+            "registry.internal/openbao/openbao:2.5.5",
+            DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
+            &[
+                OPENBAO_AGENT_STEPCA_SERVICE,
+                OPENBAO_AGENT_RESPONDER_SERVICE,
+            ],
             &test_messages(),
         )
         .await
@@ -1161,6 +2007,13 @@ services:
 
         assert!(contents.contains("openbao-agent-stepca"));
         assert!(contents.contains("openbao-agent-responder"));
+        assert_eq!(
+            contents
+                .matches("image: registry.internal/openbao/openbao:2.5.5\n")
+                .count(),
+            2,
+            "every agent service must use the configured image"
+        );
         assert!(contents.contains(&secrets_dir.display().to_string()));
         assert!(
             contents.contains("user:"),
@@ -1171,6 +2024,32 @@ services:
         assert!(!contents.contains("VAULT_CACERT"));
     }
 
+    #[tokio::test]
+    async fn test_write_openbao_agent_compose_override_omits_stepca_when_skipped() {
+        let temp_dir = tempdir().unwrap();
+        let secrets_dir = temp_dir.path().join("secrets");
+        fs::create_dir_all(&secrets_dir).unwrap();
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, "services:\n  openbao:\n    image: openbao\n").unwrap();
+
+        let override_path = write_openbao_agent_compose_override(
+            &compose_file,
+            &secrets_dir,
+            "http://openbao:8200",
+            DEFAULT_OPENBAO_AGENT_IMAGE,
+            DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
+            &[OPENBAO_AGENT_RESPONDER_SERVICE],
+            &test_messages(),
+        )
+        .await
+        .unwrap()
+        .expect("override path");
+        let contents = fs::read_to_string(&override_path).unwrap();
+
+        assert!(!contents.contains("openbao-agent-stepca"));
+        assert!(contents.contains("services:\n  openbao-agent-responder:"));
+    }
+
     #[test]
     fn test_resolve_openbao_agent_addr_replaces_localhost() {
         let addr = resolve_openbao_agent_addr("http://localhost:8200", true);
@@ -1197,7 +2076,7 @@ services:
 
     #[test]
     fn test_build_policy_map_contains_paths() {
-        let policies = build_policy_map("secret");
+        let policies = build_policy_map("secret", AppRoleLabel::all());
         let agent_policy = policies.get(POLICY_BOOTROOT_AGENT).unwrap();
         let service_add_policy = policies.get(POLICY_BOOTROOT_RUNTIME_SERVICE_ADD).unwrap();
         let rotate_policy = policies.get(POLICY_BOOTROOT_RUNTIME_ROTATE).unwrap();
@@ -1214,9 +2093,35 @@ services:
         assert!(rotate_policy.contains("secret/metadata/bootroot/ca"));
     }
 
+    #[test]
+    fn test_build_policy_map_omits_stepca_policy_when_skipped() {
+        let labels = AppRoleLabel::provisioned(true, false);
+        let policies = build_policy_map("secret", &labels);
+        assert!(!policies.contains_key(POLICY_BOOTROOT_STEPCA));
+        assert!(policies.contains_key(POLICY_BOOTROOT_RESPONDER));
+        assert_eq!(policies.len(), labels.len());
+    }
+
+    #[test]
+    fn test_build_policy_map_produces_well_formed_hcl() {
+        for (name, policy) in build_policy_map("secret", AppRoleLabel::all()) {
+            bootroot::openbao::validate_policy_hcl(&policy)
+                .unwrap_or_else(|err| panic!("policy {name} is malformed: {err}"));
+        }
+    }
+
+    #[test]
+    fn test_build_policy_map_omits_responder_policy_when_skipped() {
+        let labels = AppRoleLabel::provisioned(false, true);
+        let policies = build_policy_map("secret", &labels);
+        assert!(!policies.contains_key(POLICY_BOOTROOT_RESPONDER));
+        assert!(policies.contains_key(POLICY_BOOTROOT_STEPCA));
+        assert_eq!(policies.len(), labels.len());
+    }
+
     #[test]
     fn test_build_policy_map_infra_rotate_scoped_to_infra_roles_and_self() {
-        let policies = build_policy_map("secret");
+        let policies = build_policy_map("secret", AppRoleLabel::all());
         let infra_policy = policies.get(POLICY_BOOTROOT_INFRA_ROTATE).unwrap();
         assert!(infra_policy.contains("auth/approle/role/bootroot-stepca-role/secret-id"));
         assert!(infra_policy.contains("auth/approle/role/bootroot-responder-role/secret-id"));
@@ -1241,7 +2146,7 @@ services:
         // Guards the privilege-separation boundary: the general rotate
         // credential must not be able to mint infra secret_ids, or it
         // becomes a ladder to the stepca policy's KV surface.
-        let policies = build_policy_map("secret");
+        let policies = build_policy_map("secret", AppRoleLabel::all());
         let rotate_policy = policies.get(POLICY_BOOTROOT_RUNTIME_ROTATE).unwrap();
         assert!(!rotate_policy.contains("bootroot-stepca-role"));
         assert!(!rotate_policy.contains("bootroot-responder-role"));
@@ -1254,7 +2159,7 @@ services:
     fn test_build_policy_map_runtime_rotate_self_mint_scoped_to_own_secret_id() {
         // Self-mint grant (#672): the credential may re-mint its own
         // secret_id — and only that — on its own role path.
-        let policies = build_policy_map("secret");
+        let policies = build_policy_map("secret", AppRoleLabel::all());
         let rotate_policy = policies.get(POLICY_BOOTROOT_RUNTIME_ROTATE).unwrap();
         assert!(rotate_policy.contains("auth/approle/role/bootroot-runtime-rotate-role/secret-id"));
         assert!(
@@ -1284,6 +2189,7 @@ services:
     fn test_build_openbao_agent_config_includes_template_config() {
         let config = build_openbao_agent_config(
             "http://openbao:8200",
+            DEFAULT_OPENBAO_AGENT_MOUNT_ROOT,
             "/openbao/secrets/openbao/stepca/role_id",
             "/openbao/secrets/openbao/stepca/secret_id",
             &[(
@@ -1291,6 +2197,11 @@ services:
                 "/openbao/secrets/password.txt".to_string(),
             )],
             None,
+            None,
+        );
+        assert!(
+            !config.contains("exec {"),
+            "template-only mode must not supervise a child process"
         );
         assert!(
             config.contains("template_config"),
@@ -1,65 +1,137 @@
 use anyhow::{Context, Result};
 
+use crate::cli::prompt::Prompt;
 use crate::i18n::Messages;
 
+/// Runs `read` against a [`Prompt`] over the process's stdin and stdout.
+pub(super) fn with_stdin_prompt<T>(
+    messages: &Messages,
+    read: impl FnOnce(&mut Prompt<'_>) -> Result<T>,
+) -> Result<T> {
+    let mut input = std::io::stdin().lock();
+    let mut output = std::io::stdout();
+    read(&mut Prompt::for_stdin(&mut input, &mut output, messages))
+}
+
+/// Strips the `": "` suffix some init labels carry, since [`Prompt`]
+/// appends its own.
+pub(super) fn label(text: &str) -> &str {
+    text.trim_end_matches(": ")
+}
+
 pub(super) fn prompt_unseal_keys(
+    prompt: &mut Prompt<'_>,
     threshold: Option<u32>,
     messages: &Messages,
 ) -> Result<Vec<String>> {
     let count = match threshold {
         Some(value) if value > 0 => value,
-        _ => {
-            let input = prompt_text(messages.prompt_unseal_threshold(), messages)?;
-            input
-                .parse::<u32>()
-                .context(messages.error_invalid_unseal_threshold())?
-        }
+        _ => prompt
+            .prompt_text(label(messages.prompt_unseal_threshold()), None)?
+            .parse::<u32>()
+            .context(messages.error_invalid_unseal_threshold())?,
     };
     let mut keys = Vec::with_capacity(count as usize);
     for index in 1..=count {
-        let key = prompt_text(&messages.prompt_unseal_key(index, count), messages)?;
-        keys.push(key);
+        keys.push(prompt.prompt_secret(label(&messages.prompt_unseal_key(index, count)))?);
     }
     Ok(keys)
 }
 
-pub(super) fn prompt_text(prompt: &str, messages: &Messages) -> Result<String> {
-    use std::io::{self, Write};
-    // CodeQL flags this as cleartext-logging, but `prompt` is a UI label
-    // (e.g. "PostgreSQL password: "), not a secret value. Dismiss as false positive.
-    print!("{prompt}");
-    io::stdout()
-        .flush()
-        .with_context(|| messages.error_prompt_flush_failed())?;
-    let mut input = String::new();
-    io::stdin()
-        .read_line(&mut input)
-        .with_context(|| messages.error_prompt_read_failed())?;
-    Ok(input.trim().to_string())
+/// Reads a secret from stdin without echoing it when stdin is a terminal.
+pub(super) fn prompt_secret(text: &str, messages: &Messages) -> Result<String> {
+    with_stdin_prompt(messages, |prompt| prompt.prompt_secret(label(text)))
 }
 
 pub(super) fn prompt_text_with_default(
-    prompt: &str,
+    text: &str,
     default: &str,
     messages: &Messages,
 ) -> Result<String> {
-    let input = prompt_text(prompt, messages)?;
-    if input.trim().is_empty() {
-        Ok(default.to_string())
-    } else {
-        Ok(input)
-    }
+    with_stdin_prompt(messages, |prompt| {
+        prompt.prompt_text(label(text), Some(default))
+    })
 }
 
-pub(crate) fn prompt_yes_no(prompt: &str, messages: &Messages) -> Result<bool> {
-    let input = prompt_text(prompt, messages)?;
-    let trimmed = input.trim().to_ascii_lowercase();
-    Ok(trimmed == "y" || trimmed == "yes")
+/// Asks a yes/no question on stdin; EOF counts as "no".
+pub(crate) fn prompt_yes_no(text: &str, messages: &Messages) -> Result<bool> {
+    with_stdin_prompt(messages, |prompt| prompt.confirm(label(text)))
 }
 
-pub(super) fn confirm_overwrite(prompt: &str, messages: &Messages) -> Result<()> {
-    if prompt_yes_no(prompt, messages)? {
+pub(super) fn confirm_overwrite(
+    prompt: &mut Prompt<'_>,
+    text: &str,
+    messages: &Messages,
+) -> Result<()> {
+    if prompt.confirm(label(text))? {
         return Ok(());
     }
     anyhow::bail!(messages.error_operation_cancelled());
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::super::test_support::test_messages;
+    use super::*;
+
+    #[test]
+    fn prompt_unseal_keys_reads_threshold_then_each_key() {
+        let messages = test_messages();
+        let mut input = Cursor::new("2\nkey-one\nkey-two\n");
+        let mut output = Vec::new();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        let keys = prompt_unseal_keys(&mut prompt, None, &messages).unwrap();
+        assert_eq!(keys, vec!["key-one", "key-two"]);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Unseal key threshold (t): Unseal key 1/2: Unseal key 2/2: "
+        );
+    }
+
+    #[test]
+    fn prompt_unseal_keys_uses_reported_threshold() {
+        let messages = test_messages();
+        let mut input = Cursor::new("key-one\nkey-two\nunread\n");
+        let mut output = Vec::new();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        let keys = prompt_unseal_keys(&mut prompt, Some(2), &messages).unwrap();
+        assert_eq!(keys, vec!["key-one", "key-two"]);
+    }
+
+    #[test]
+    fn prompt_unseal_keys_rejects_invalid_threshold() {
+        let messages = test_messages();
+        let mut input = Cursor::new("two\n");
+        let mut output = Vec::new();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        let err = prompt_unseal_keys(&mut prompt, None, &messages).unwrap_err();
+        assert_eq!(err.to_string(), messages.error_invalid_unseal_threshold());
+    }
+
+    #[test]
+    fn confirm_overwrite_cancels_unless_confirmed() {
+        let messages = test_messages();
+        let mut input = Cursor::new("n\ny\n");
+        let mut output = Vec::new();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        let err = confirm_overwrite(
+            &mut prompt,
+            messages.prompt_confirm_overwrite_password(),
+            &messages,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), messages.error_operation_cancelled());
+        confirm_overwrite(
+            &mut prompt,
+            messages.prompt_confirm_overwrite_ca_json(),
+            &messages,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Overwrite password.txt? [y/N]: Overwrite ca.json? [y/N]: "
+        );
+    }
+}
@@ -18,6 +18,19 @@ use crate::commands::constants::RESPONDER_SERVICE_NAME;
 use crate::commands::infra::run_docker;
 use crate::i18n::Messages;
 
+/// Returns every file `write_responder_files` and
+/// `write_responder_compose_override` may write under `secrets_dir`.
+pub(super) fn responder_generated_paths(secrets_dir: &Path) -> Vec<PathBuf> {
+    let responder_dir = secrets_dir.join(RESPONDER_CONFIG_DIR);
+    vec![
+        secrets_dir
+            .join(RESPONDER_TEMPLATE_DIR)
+            .join(RESPONDER_TEMPLATE_NAME),
+        responder_dir.join(RESPONDER_CONFIG_NAME),
+        responder_dir.join(RESPONDER_COMPOSE_OVERRIDE_NAME),
+    ]
+}
+
 pub(super) async fn write_responder_files(
     secrets_dir: &Path,
     kv_mount: &str,
@@ -50,7 +63,7 @@ pub(super) async fn write_responder_files(
     })
 }
 
-fn build_responder_template(kv_mount: &str, tls_enabled: bool) -> String {
+pub(super) fn build_responder_template(kv_mount: &str, tls_enabled: bool) -> String {
     use std::fmt::Write;
 
     let mut config = format!(
@@ -237,9 +250,21 @@ mod tests {
 
     use super::super::super::constants::DEFAULT_RESPONDER_ADMIN_URL;
     use super::super::super::paths::{compose_has_responder, resolve_responder_url};
-    use super::super::test_support::{default_init_args, test_messages};
+    use super::super::test_support::{assert_golden, default_init_args, test_messages};
     use super::*;
 
+    #[test]
+    fn test_build_responder_config_matches_golden() {
+        assert_golden(
+            "responder.toml",
+            &build_responder_config("hmac-value", false),
+        );
+        assert_golden(
+            "responder-tls.toml",
+            &build_responder_config("hmac-value", true),
+        );
+    }
+
     #[tokio::test]
     async fn test_write_responder_files_writes_template_and_config() {
         let temp_dir = tempdir().unwrap();
@@ -374,8 +399,8 @@ services:
         let compose_has_responder =
             compose_has_responder(&args.compose.compose_file, &test_messages())
                 .expect("compose check");
-        let responder_url =
-            resolve_responder_url(&args, compose_has_responder).expect("resolve responder url");
+        let responder_url = resolve_responder_url(&args, compose_has_responder, &test_messages())
+            .expect("resolve responder url");
         assert!(responder_url.is_none());
     }
 
@@ -398,8 +423,50 @@ services:
         let compose_has_responder =
             compose_has_responder(&args.compose.compose_file, &test_messages())
                 .expect("compose check");
-        let responder_url =
-            resolve_responder_url(&args, compose_has_responder).expect("resolve responder url");
+        let responder_url = resolve_responder_url(&args, compose_has_responder, &test_messages())
+            .expect("resolve responder url");
         assert_eq!(responder_url.as_deref(), Some(DEFAULT_RESPONDER_ADMIN_URL));
     }
+
+    /// Resolves the responder admin URL for a compose file whose
+    /// responder publishes `port_mapping`.
+    fn responder_url_for_mapping(port_mapping: &str) -> Option<String> {
+        let temp_dir = tempdir().unwrap();
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(
+            &compose_file,
+            format!(
+                "services:\n  bootroot-http01:\n    image: bootroot-http01-responder:latest\n    \
+                 ports:\n      - \"{port_mapping}\"\n"
+            ),
+        )
+        .unwrap();
+        let mut args = default_init_args();
+        args.compose.compose_file = compose_file;
+        resolve_responder_url(&args, true, &test_messages()).expect("resolve responder url")
+    }
+
+    #[test]
+    fn test_resolve_responder_url_keeps_default_for_stock_mapping() {
+        assert_eq!(
+            responder_url_for_mapping("127.0.0.1:8080:8080").as_deref(),
+            Some(DEFAULT_RESPONDER_ADMIN_URL)
+        );
+    }
+
+    #[test]
+    fn test_resolve_responder_url_follows_remapped_admin_port() {
+        assert_eq!(
+            responder_url_for_mapping("192.168.1.10:9080:8080").as_deref(),
+            Some("http://192.168.1.10:9080")
+        );
+        assert_eq!(
+            responder_url_for_mapping("9080:8080").as_deref(),
+            Some("http://localhost:9080")
+        );
+        assert_eq!(
+            responder_url_for_mapping("0.0.0.0:9080:8080").as_deref(),
+            Some("http://localhost:9080")
+        );
+    }
 }
@@ -3,11 +3,14 @@ use base64::Engine;
 use bootroot::openbao::OpenBaoClient;
 
 use super::super::constants::SECRET_BYTES;
-use super::super::constants::openbao_constants::PATH_AGENT_EAB;
+use super::super::constants::openbao_constants::{
+    PATH_AGENT_EAB, PATH_RESPONDER_HMAC, PATH_STEPCA_DB, PATH_STEPCA_PASSWORD,
+};
 use super::super::types::EabCredentials;
-use super::prompts::{prompt_text, prompt_yes_no};
+use super::prompts::{label, prompt_secret, with_stdin_prompt};
 use super::{InitRollback, InitSecrets};
 use crate::cli::args::{InitArgs, InitFeature};
+use crate::cli::prompt::Prompt;
 use crate::i18n::Messages;
 
 /// Minimum decoded length for an ACME EAB HMAC (typical step-ca / Boulder
@@ -18,13 +21,21 @@ pub(super) fn resolve_init_secrets(
     args: &InitArgs,
     messages: &Messages,
     db_dsn: String,
+    existing_stepca_password: Option<String>,
+    existing_http_hmac: Option<String>,
 ) -> Result<InitSecrets> {
-    // In reinit mode, if `password.txt` already exists, prefer the
-    // stored password so that step-ca CA material (encrypted with that
-    // password) remains usable.  Without this, the auto-gen path below
-    // would overwrite the existing password and lock the operator out
-    // of the preserved root_ca_key / intermediate_ca_key.
-    let preserved_stepca_password = if args.reinit_mode {
+    // A resumed init keeps the step-ca password already stored in
+    // OpenBao (`existing_stepca_password`, only set when `configure_openbao`
+    // leaves that KV pair untouched), so KV is the source of truth: the
+    // step-ca agent renders `password.txt` from it, and init rewrites the
+    // file with the same value. Otherwise, in reinit and resume modes, an
+    // existing `password.txt` is preferred so that step-ca CA material
+    // (encrypted with that password) remains usable.  Without this, the
+    // auto-gen path below would overwrite the existing password and lock
+    // the operator out of the preserved root_ca_key / intermediate_ca_key.
+    let preserved_stepca_password = if let Some(stored) = existing_stepca_password {
+        Some(stored)
+    } else if args.reinit_mode || args.resume {
         let password_path = args.secrets_dir.secrets_dir.join("password.txt");
         if password_path.exists() {
             Some(
@@ -64,12 +75,19 @@ pub(super) fn resolve_init_secrets(
             messages,
         )?
     };
-    let http_hmac = resolve_secret(
-        messages.prompt_http_hmac(),
-        args.http_hmac.as_deref(),
-        auto_generate_secret,
-        messages,
-    )?;
+    // Without a responder nothing consumes the HMAC, so do not prompt.
+    let http_hmac = if args.skip_responder {
+        String::new()
+    } else if let Some(existing) = existing_http_hmac {
+        existing
+    } else {
+        resolve_secret(
+            messages.prompt_http_hmac(),
+            args.http_hmac.as_deref(),
+            auto_generate_secret,
+            messages,
+        )?
+    };
     let eab = resolve_eab(args, messages)?;
 
     Ok(InitSecrets {
@@ -93,7 +111,7 @@ fn resolve_secret(
         return bootroot::utils::generate_secret(SECRET_BYTES)
             .with_context(|| messages.error_generate_secret_failed());
     }
-    prompt_text(&format!("{label}: "), messages)
+    prompt_secret(label, messages)
 }
 
 fn resolve_eab(args: &InitArgs, messages: &Messages) -> Result<Option<EabCredentials>> {
@@ -110,6 +128,61 @@ fn resolve_eab(args: &InitArgs, messages: &Messages) -> Result<Option<EabCredent
     }
 }
 
+/// Reads the step-ca password a previous `init --openbao-only` (or an
+/// interrupted init) stored, so `--resume` writes the same password to
+/// `password.txt` that the step-ca `OpenBao` Agent renders from KV.
+///
+/// Returns `None` unless both step-ca KV entries exist: `configure_openbao`
+/// only keeps the pair together, and otherwise rewrites it from the
+/// resolved password.
+pub(super) async fn read_resumable_stepca_password(
+    client: &OpenBaoClient,
+    args: &InitArgs,
+    messages: &Messages,
+) -> Result<Option<String>> {
+    if !args.resume || args.skip_step_ca {
+        return Ok(None);
+    }
+    for path in [PATH_STEPCA_PASSWORD, PATH_STEPCA_DB] {
+        if !client
+            .kv_exists(&args.openbao.kv_mount, path)
+            .await
+            .with_context(|| messages.error_openbao_kv_exists_failed())?
+        {
+            return Ok(None);
+        }
+    }
+    let password = client
+        .read_stepca_password(&args.openbao.kv_mount)
+        .await
+        .with_context(|| messages.error_openbao_kv_read_failed())?;
+    Ok(Some(password))
+}
+
+/// Reads the responder HMAC a previous, interrupted init already stored
+/// so `--resume` keeps the responder and KV in agreement.
+pub(super) async fn read_resumable_http_hmac(
+    client: &OpenBaoClient,
+    args: &InitArgs,
+    messages: &Messages,
+) -> Result<Option<String>> {
+    if !args.resume || args.skip_responder {
+        return Ok(None);
+    }
+    if !client
+        .kv_exists(&args.openbao.kv_mount, PATH_RESPONDER_HMAC)
+        .await
+        .with_context(|| messages.error_openbao_kv_exists_failed())?
+    {
+        return Ok(None);
+    }
+    let hmac = client
+        .read_responder_hmac(&args.openbao.kv_mount)
+        .await
+        .with_context(|| messages.error_openbao_kv_read_failed())?;
+    Ok(Some(hmac))
+}
+
 pub(super) async fn maybe_register_eab(
     client: &OpenBaoClient,
     args: &InitArgs,
@@ -130,11 +203,20 @@ pub(super) async fn maybe_register_eab(
     if args.reinit_mode {
         return Ok(None);
     }
-    if !prompt_yes_no(messages.prompt_eab_register_now(), messages)? {
+    // A resumed init keeps EAB credentials the interrupted run stored.
+    if args.resume
+        && client
+            .kv_exists(&args.openbao.kv_mount, PATH_AGENT_EAB)
+            .await
+            .with_context(|| messages.error_openbao_kv_exists_failed())?
+    {
         return Ok(None);
     }
-    println!("{}", messages.eab_prompt_instructions());
-    let credentials = prompt_eab_with_validation(messages)?;
+    let Some(credentials) =
+        with_stdin_prompt(messages, |prompt| prompt_eab_registration(prompt, messages))?
+    else {
+        return Ok(None);
+    };
     register_eab_secret(
         client,
         &args.openbao.kv_mount,
@@ -146,20 +228,33 @@ pub(super) async fn maybe_register_eab(
     Ok(Some(credentials))
 }
 
+/// Asks whether to register EAB now and, if so, reads the credentials.
+fn prompt_eab_registration(
+    prompt: &mut Prompt<'_>,
+    messages: &Messages,
+) -> Result<Option<EabCredentials>> {
+    if !prompt.confirm(label(messages.prompt_eab_register_now()))? {
+        return Ok(None);
+    }
+    prompt.write_line(messages.eab_prompt_instructions())?;
+    prompt_eab_with_validation(prompt, messages).map(Some)
+}
+
 /// Re-prompts the operator until both `kid` and `hmac` validate. The
 /// operator who realises mid-prompt that they don't have EAB material
 /// aborts with Ctrl-C and re-runs `init` (eventually with `--no-eab`).
 /// Coercing blank-to-"no EAB" silently here would leak the same garbage
 /// (kid="", hmac="") into KV that issue #588 §3 closes.
-fn prompt_eab_with_validation(messages: &Messages) -> Result<EabCredentials> {
+fn prompt_eab_with_validation(
+    prompt: &mut Prompt<'_>,
+    messages: &Messages,
+) -> Result<EabCredentials> {
     loop {
-        let kid = prompt_text(messages.prompt_eab_kid(), messages)?;
-        let hmac = prompt_text(messages.prompt_eab_hmac(), messages)?;
+        let kid = prompt.prompt_text(label(messages.prompt_eab_kid()), None)?;
+        let hmac = prompt.prompt_secret(label(messages.prompt_eab_hmac()))?;
         match validate_eab(&kid, &hmac) {
             Ok(creds) => return Ok(creds),
-            Err(err) => {
-                eprintln!("{err}");
-            }
+            Err(err) => prompt.write_line(&err.to_string())?,
         }
     }
 }
@@ -238,6 +333,42 @@ mod tests {
         assert!(!value.is_empty());
     }
 
+    #[test]
+    fn prompt_eab_registration_declines_without_reading_credentials() {
+        let messages = test_messages();
+        let mut input = std::io::Cursor::new("n\nkid-1\n");
+        let mut output = Vec::new();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        assert!(
+            prompt_eab_registration(&mut prompt, &messages)
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Register EAB now? [y/N]: "
+        );
+    }
+
+    #[test]
+    fn prompt_eab_registration_retries_until_credentials_validate() {
+        let messages = test_messages();
+        let hmac = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode([7u8; 32]);
+        let script = format!("y\nkid-1\ny\nkid-1\n{hmac}\n");
+        let mut input = std::io::Cursor::new(script);
+        let mut output = Vec::new();
+        let mut prompt = Prompt::new(&mut input, &mut output, &messages);
+        let creds = prompt_eab_registration(&mut prompt, &messages)
+            .unwrap()
+            .expect("credentials");
+        assert_eq!(creds.kid, "kid-1");
+        assert_eq!(creds.hmac, hmac);
+        let transcript = String::from_utf8(output).unwrap();
+        assert!(transcript.contains(messages.eab_prompt_instructions()));
+        assert_eq!(transcript.matches("EAB kid: ").count(), 2);
+        assert!(transcript.contains("EAB hmac must be base64url-encoded"));
+    }
+
     #[test]
     fn validate_eab_rejects_single_char_hmac() {
         let err = validate_eab("kid-1", "y").unwrap_err();
@@ -281,13 +412,79 @@ mod tests {
         args.http_hmac = Some("provided-hmac".to_string());
 
         let resolved =
-            resolve_init_secrets(&args, &messages, "ignored-dsn".to_string()).expect("resolve");
+            resolve_init_secrets(&args, &messages, "ignored-dsn".to_string(), None, None)
+                .expect("resolve");
         assert_eq!(
             resolved.stepca_password, "preserved-secret",
             "reinit_mode must preserve existing password.txt verbatim"
         );
     }
 
+    /// A resume after `--openbao-only` has no `password.txt`; the password
+    /// that phase stored in `OpenBao` must win over auto-generation.
+    #[test]
+    fn resolve_init_secrets_resume_reuses_stored_password_without_file() {
+        use tempfile::tempdir;
+
+        use crate::cli::args::InitFeature;
+
+        let messages = test_messages();
+        let dir = tempdir().unwrap();
+
+        let mut args = super::super::test_support::default_init_args();
+        args.secrets_dir.secrets_dir = dir.path().join("secrets");
+        args.resume = true;
+        args.enable = vec![InitFeature::AutoGenerate];
+        args.no_eab = true;
+        args.http_hmac = Some("provided-hmac".to_string());
+
+        let resolved = resolve_init_secrets(
+            &args,
+            &messages,
+            "dsn".to_string(),
+            Some("stored-secret".to_string()),
+            None,
+        )
+        .expect("resolve");
+        assert_eq!(resolved.stepca_password, "stored-secret");
+    }
+
+    /// When a resumed init keeps the step-ca password in `OpenBao`, the
+    /// stored value wins over a leftover `password.txt`; otherwise the
+    /// agent would render a different password than init wrote.
+    #[test]
+    fn resolve_init_secrets_resume_prefers_stored_password_over_file() {
+        use std::fs;
+
+        use tempfile::tempdir;
+
+        let messages = test_messages();
+        let dir = tempdir().unwrap();
+        let secrets = dir.path().join("secrets");
+        fs::create_dir_all(&secrets).unwrap();
+        fs::write(secrets.join("password.txt"), "leftover-secret\n").unwrap();
+
+        let mut args = super::super::test_support::default_init_args();
+        args.secrets_dir.secrets_dir = secrets;
+        args.resume = true;
+        args.no_eab = true;
+        args.http_hmac = Some("provided-hmac".to_string());
+
+        let resolved = resolve_init_secrets(
+            &args,
+            &messages,
+            "dsn".to_string(),
+            Some("stored-secret".to_string()),
+            None,
+        )
+        .expect("resolve");
+        assert_eq!(resolved.stepca_password, "stored-secret");
+
+        let resolved =
+            resolve_init_secrets(&args, &messages, "dsn".to_string(), None, None).expect("resolve");
+        assert_eq!(resolved.stepca_password, "leftover-secret");
+    }
+
     /// Regression: outside of reinit mode the preserve-password
     /// fast-path must not engage; auto-generation should produce a
     /// fresh secret as before.
@@ -312,7 +509,8 @@ mod tests {
         args.no_eab = true;
         args.http_hmac = Some("provided-hmac".to_string());
 
-        let resolved = resolve_init_secrets(&args, &messages, "dsn".to_string()).expect("resolve");
+        let resolved =
+            resolve_init_secrets(&args, &messages, "dsn".to_string(), None, None).expect("resolve");
         assert_ne!(
             resolved.stepca_password, "should-be-ignored",
             "outside reinit_mode, auto-generate must run"
@@ -348,7 +546,8 @@ mod tests {
         args.enable = Vec::new();
 
         let resolved =
-            resolve_init_secrets(&args, &messages, "ignored-dsn".to_string()).expect("resolve");
+            resolve_init_secrets(&args, &messages, "ignored-dsn".to_string(), None, None)
+                .expect("resolve");
         assert!(
             !resolved.http_hmac.is_empty(),
             "reinit_mode must auto-generate a fresh HTTP HMAC instead of prompting"
@@ -384,7 +583,8 @@ mod tests {
         args.http_hmac = Some("provided-hmac".to_string());
 
         let resolved =
-            resolve_init_secrets(&args, &messages, "ignored-dsn".to_string()).expect("resolve");
+            resolve_init_secrets(&args, &messages, "ignored-dsn".to_string(), None, None)
+                .expect("resolve");
         assert!(
             !resolved.stepca_password.is_empty(),
             "reinit_mode must auto-generate a fresh step-ca password instead of prompting"
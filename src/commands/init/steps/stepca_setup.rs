@@ -1,6 +1,6 @@
 use std::io::ErrorKind;
 use std::os::unix::fs::MetadataExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use bootroot::fs_util;
@@ -13,11 +13,23 @@ use super::super::constants::{
 use super::super::paths::StepCaTemplatePaths;
 use super::super::types::StepCaInitResult;
 use super::RollbackFile;
+use crate::commands::container_runtime;
 use crate::commands::infra::run_docker;
 use crate::i18n::Messages;
 
+/// Returns the template files `write_stepca_templates` writes under
+/// `secrets_dir`.
+pub(super) fn stepca_template_paths(secrets_dir: &Path) -> Vec<PathBuf> {
+    let templates_dir = secrets_dir.join(RESPONDER_TEMPLATE_DIR);
+    vec![
+        templates_dir.join(STEPCA_PASSWORD_TEMPLATE_NAME),
+        templates_dir.join(STEPCA_CA_JSON_TEMPLATE_NAME),
+    ]
+}
+
 pub(super) async fn write_stepca_templates(
     secrets_dir: &Path,
+    ca_json_path: &Path,
     kv_mount: &str,
     cert_duration: &str,
     provisioner: &str,
@@ -35,8 +47,7 @@ pub(super) async fn write_stepca_templates(
         })?;
     fs_util::set_key_permissions(&password_template_path).await?;
 
-    let ca_json_path = secrets_dir.join("config").join("ca.json");
-    let ca_json_contents = tokio::fs::read_to_string(&ca_json_path)
+    let ca_json_contents = tokio::fs::read_to_string(ca_json_path)
         .await
         .with_context(|| messages.error_read_file_failed(&ca_json_path.display().to_string()))?;
     let ca_json_template = build_ca_json_template(
@@ -57,16 +68,17 @@ pub(super) async fn write_stepca_templates(
     Ok(StepCaTemplatePaths {
         password_template_path,
         ca_json_template_path,
+        ca_json_output: ca_json_path.to_path_buf(),
     })
 }
 
-fn build_password_template(kv_mount: &str) -> String {
+pub(super) fn build_password_template(kv_mount: &str) -> String {
     format!(
         r#"{{{{ with secret "{kv_mount}/data/{PATH_STEPCA_PASSWORD}" }}}}{{{{ .Data.data.value }}}}{{{{ end }}}}"#
     )
 }
 
-fn build_ca_json_template(
+pub(super) fn build_ca_json_template(
     contents: &str,
     kv_mount: &str,
     cert_duration: &str,
@@ -86,10 +98,11 @@ fn build_ca_json_template(
     *data_source = serde_json::Value::String(PLACEHOLDER.to_string());
 
     if !set_acme_cert_duration(&mut value, cert_duration, Some(provisioner)) {
-        anyhow::bail!(
-            "ca.json does not contain an ACME provisioner named {provisioner:?} — \
-             cannot set defaultTLSCertDuration in template"
-        );
+        return Err(missing_acme_provisioner_error(
+            "ca.json",
+            &value,
+            provisioner,
+        ));
     }
 
     let serialized =
@@ -148,6 +161,40 @@ pub(crate) fn set_acme_cert_duration(
     patched
 }
 
+/// Builds the error for a `--stepca-provisioner` that names no ACME
+/// provisioner in `file`, listing the ACME provisioners it does have so
+/// a typo is obvious.
+pub(crate) fn missing_acme_provisioner_error(
+    file: &str,
+    value: &serde_json::Value,
+    provisioner: &str,
+) -> anyhow::Error {
+    let names = acme_provisioner_names(value);
+    let available = if names.is_empty() {
+        "it has no ACME provisioners".to_string()
+    } else {
+        format!("available ACME provisioners: {}", names.join(", "))
+    };
+    anyhow::anyhow!(
+        "{file} does not contain an ACME provisioner named {provisioner:?} ({available}) — \
+         cannot set defaultTLSCertDuration; check --stepca-provisioner"
+    )
+}
+
+fn acme_provisioner_names(value: &serde_json::Value) -> Vec<String> {
+    let root = value.get("authority").unwrap_or(value);
+    let provisioners = root
+        .get("provisioners")
+        .and_then(serde_json::Value::as_array);
+    provisioners
+        .into_iter()
+        .flatten()
+        .filter(|provisioner| is_acme_provisioner(provisioner))
+        .filter_map(|provisioner| provisioner.get("name").and_then(serde_json::Value::as_str))
+        .map(str::to_string)
+        .collect()
+}
+
 fn provisioner_name_matches(provisioner: &serde_json::Value, name: &str) -> bool {
     provisioner
         .get("name")
@@ -201,14 +248,13 @@ pub(super) async fn write_password_file_with_backup(
 }
 
 pub(super) async fn update_ca_json_with_backup(
-    secrets_dir: &Path,
+    path: &Path,
     db_dsn: &str,
     cert_duration: &str,
     provisioner: &str,
     messages: &Messages,
 ) -> Result<RollbackFile> {
-    let path = secrets_dir.join("config").join("ca.json");
-    let contents = tokio::fs::read_to_string(&path)
+    let contents = tokio::fs::read_to_string(path)
         .await
         .with_context(|| messages.error_read_file_failed(&path.display().to_string()))?;
     let mut value: serde_json::Value =
@@ -216,24 +262,26 @@ pub(super) async fn update_ca_json_with_backup(
     value["db"]["type"] = serde_json::Value::String("postgresql".to_string());
     value["db"]["dataSource"] = serde_json::Value::String(db_dsn.to_string());
     if !set_acme_cert_duration(&mut value, cert_duration, Some(provisioner)) {
-        anyhow::bail!(
-            "ca.json does not contain an ACME provisioner named {provisioner:?} — \
-             cannot set defaultTLSCertDuration"
-        );
+        return Err(missing_acme_provisioner_error(
+            "ca.json",
+            &value,
+            provisioner,
+        ));
     }
     let updated =
         serde_json::to_string_pretty(&value).context(messages.error_serialize_ca_json_failed())?;
-    tokio::fs::write(&path, updated)
+    tokio::fs::write(path, updated)
         .await
         .with_context(|| messages.error_write_file_failed(&path.display().to_string()))?;
     Ok(RollbackFile {
-        path,
+        path: path.to_path_buf(),
         original: Some(contents),
     })
 }
 
 pub(super) fn ensure_step_ca_initialized(
     secrets_dir: &Path,
+    image: &str,
     messages: &Messages,
 ) -> Result<StepCaInitResult> {
     let config_path = secrets_dir.join("config").join("ca.json");
@@ -255,14 +303,41 @@ pub(super) fn ensure_step_ca_initialized(
     let meta = std::fs::metadata(secrets_dir)
         .with_context(|| messages.error_resolve_path_failed(&secrets_dir.display().to_string()))?;
     let user_arg = format!("{}:{}", meta.uid(), meta.gid());
-    let args = vec![
+    let args = step_ca_init_docker_args(&user_arg, &mount, image);
+    run_docker(&args, "docker step-ca init", messages)?;
+    Ok(StepCaInitResult::Initialized)
+}
+
+/// Renders the `step ca init` container command `ensure_step_ca_initialized`
+/// would run, for `init --dry-run`. Falls back to the given path and a
+/// placeholder user when `secrets_dir` does not exist yet.
+pub(super) fn step_ca_init_command_line(secrets_dir: &Path, image: &str) -> String {
+    let mount_root =
+        std::fs::canonicalize(secrets_dir).unwrap_or_else(|_| secrets_dir.to_path_buf());
+    let mount = format!("{}:/home/step", mount_root.display());
+    let user_arg = std::fs::metadata(secrets_dir).map_or_else(
+        |_| "<uid>:<gid>".to_string(),
+        |meta| format!("{}:{}", meta.uid(), meta.gid()),
+    );
+    let args = step_ca_init_docker_args(&user_arg, &mount, image);
+    format!(
+        "{} {}",
+        container_runtime::selected().binary(),
+        args.join(" ")
+    )
+}
+
+/// Builds the `docker run` arguments that execute `step ca init` in
+/// `image` with `mount` bound to the step home directory.
+fn step_ca_init_docker_args<'a>(user_arg: &'a str, mount: &'a str, image: &'a str) -> Vec<&'a str> {
+    vec![
         "run",
         "--user",
-        &user_arg,
+        user_arg,
         "--rm",
         "-v",
-        &*mount,
-        "smallstep/step-ca:0.30.2",
+        mount,
+        image,
         "step",
         "ca",
         "init",
@@ -279,9 +354,7 @@ pub(super) fn ensure_step_ca_initialized(
         "--provisioner-password-file",
         "/home/step/password.txt",
         "--acme",
-    ];
-    run_docker(&args, "docker step-ca init", messages)?;
-    Ok(StepCaInitResult::Initialized)
+    ]
 }
 
 #[cfg(test)]
@@ -290,6 +363,7 @@ mod tests {
 
     use tempfile::tempdir;
 
+    use super::super::super::constants::DEFAULT_STEPCA_IMAGE;
     use super::super::test_support::test_messages;
     use super::*;
 
@@ -308,9 +382,16 @@ mod tests {
         .unwrap();
 
         let messages = test_messages();
-        let paths = write_stepca_templates(&secrets_dir, "secret", "48h", "acme", &messages)
-            .await
-            .unwrap();
+        let paths = write_stepca_templates(
+            &secrets_dir,
+            &secrets_dir.join("config").join("ca.json"),
+            "secret",
+            "48h",
+            "acme",
+            &messages,
+        )
+        .await
+        .unwrap();
         let password_template = fs::read_to_string(&paths.password_template_path).unwrap();
         let ca_json_template = fs::read_to_string(&paths.ca_json_template_path).unwrap();
 
@@ -390,7 +471,9 @@ mod tests {
         fs::write(secrets_dir.join("secrets").join("root_ca_key"), "").unwrap();
         fs::write(secrets_dir.join("secrets").join("intermediate_ca_key"), "").unwrap();
 
-        let result = ensure_step_ca_initialized(&secrets_dir, &test_messages()).unwrap();
+        let result =
+            ensure_step_ca_initialized(&secrets_dir, DEFAULT_STEPCA_IMAGE, &test_messages())
+                .unwrap();
         assert_eq!(result, StepCaInitResult::Skipped);
     }
 
@@ -400,7 +483,32 @@ mod tests {
         let secrets_dir = temp_dir.path().join("secrets");
         fs::create_dir_all(&secrets_dir).unwrap();
 
-        let err = ensure_step_ca_initialized(&secrets_dir, &test_messages()).unwrap_err();
+        let err = ensure_step_ca_initialized(&secrets_dir, DEFAULT_STEPCA_IMAGE, &test_messages())
+            .unwrap_err();
         assert!(err.to_string().contains("step-ca password file not found"));
     }
+
+    #[test]
+    fn test_step_ca_init_docker_args_use_configured_image() {
+        let args = step_ca_init_docker_args(
+            "1000:1000",
+            "/srv/secrets:/home/step",
+            "registry.internal/smallstep/step-ca:0.30.2",
+        );
+        assert_eq!(
+            args.get(..7),
+            Some(
+                &[
+                    "run",
+                    "--user",
+                    "1000:1000",
+                    "--rm",
+                    "-v",
+                    "/srv/secrets:/home/step",
+                    "registry.internal/smallstep/step-ca:0.30.2",
+                ][..]
+            )
+        );
+        assert!(!args.contains(&DEFAULT_STEPCA_IMAGE));
+    }
 }
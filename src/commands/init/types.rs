@@ -60,17 +60,40 @@ impl AppRoleLabel {
         }
     }
 
-    /// Builds a label-to-role-name map for state file persistence.
-    pub(crate) fn approle_map() -> BTreeMap<String, String> {
+    /// Returns whether service-side agents log in with this role.
+    ///
+    /// Only these roles take `init --approle-bind-cidr`: the in-compose
+    /// `OpenBao` Agents log in from the compose network, and the operator
+    /// rotate roles have their own `--rotate-bound-cidrs`.
+    pub(crate) fn is_service_role(self) -> bool {
+        matches!(self, Self::BootrootAgent)
+    }
+
+    /// Returns the labels an `init` run provisions, omitting the step-ca
+    /// and responder roles when their components are skipped.
+    pub(crate) fn provisioned(skip_step_ca: bool, skip_responder: bool) -> Vec<Self> {
         Self::all()
+            .iter()
+            .copied()
+            .filter(|label| match label {
+                Self::Stepca => !skip_step_ca,
+                Self::Responder => !skip_responder,
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// Builds a label-to-role-name map for state file persistence.
+    pub(crate) fn approle_map(labels: &[Self]) -> BTreeMap<String, String> {
+        labels
             .iter()
             .map(|l| (l.to_string(), l.role_name().to_string()))
             .collect()
     }
 
     /// Builds a label-to-policy-name map for state file persistence.
-    pub(crate) fn policy_map() -> BTreeMap<String, String> {
-        Self::all()
+    pub(crate) fn policy_map(labels: &[Self]) -> BTreeMap<String, String> {
+        labels
             .iter()
             .map(|l| (l.to_string(), l.policy_name().to_string()))
             .collect()
@@ -114,7 +137,11 @@ pub(crate) struct InitSummary {
     pub(crate) init_response: bool,
     pub(crate) root_token: String,
     pub(crate) unseal_keys: Vec<String>,
+    pub(crate) recovery_keys: Vec<String>,
     pub(crate) approles: Vec<AppRoleOutput>,
+    /// KV paths this run wrote (or, under `--resume`, kept), in write
+    /// order.
+    pub(crate) kv_paths: Vec<&'static str>,
     pub(crate) stepca_password: String,
     pub(crate) db_dsn: String,
     pub(crate) db_dsn_host_original: String,
@@ -124,14 +151,18 @@ pub(crate) struct InitSummary {
     pub(crate) step_ca_result: StepCaInitResult,
     pub(crate) responder_check: ResponderCheck,
     pub(crate) responder_url: Option<String>,
-    pub(crate) responder_template_path: PathBuf,
-    pub(crate) responder_config_path: PathBuf,
-    pub(crate) openbao_agent_stepca_config_path: PathBuf,
-    pub(crate) openbao_agent_responder_config_path: PathBuf,
+    pub(crate) responder_template_path: Option<PathBuf>,
+    pub(crate) responder_config_path: Option<PathBuf>,
+    pub(crate) openbao_agent_stepca_config_path: Option<PathBuf>,
+    pub(crate) openbao_agent_responder_config_path: Option<PathBuf>,
     pub(crate) openbao_agent_override_path: Option<PathBuf>,
     pub(crate) db_check: DbCheckStatus,
 }
 
+// Each boolean is an independent yes/no answer to "will init do X",
+// serialized as-is by `init --dump-plan-json`.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Serialize)]
 pub(crate) struct InitPlan {
     pub(crate) openbao_url: String,
     pub(crate) kv_mount: String,
@@ -139,11 +170,25 @@ pub(crate) struct InitPlan {
     pub(crate) overwrite_password: bool,
     pub(crate) overwrite_ca_json: bool,
     pub(crate) overwrite_state: bool,
+    pub(crate) db_provision: bool,
+    pub(crate) initialize_stepca: bool,
+}
+
+/// Side effects an init run would perform, listed by `init --dry-run`.
+#[derive(Debug, Default)]
+pub(crate) struct InitPlannedActions {
+    /// `OpenBao` API paths written, in order.
+    pub(crate) openbao_writes: Vec<String>,
+    pub(crate) file_writes: Vec<PathBuf>,
+    /// Container commands, rendered as shell command lines.
+    pub(crate) container_commands: Vec<String>,
 }
 
 pub(super) struct OpenBaoConfigResult {
     pub(super) role_outputs: Vec<AppRoleOutput>,
+    pub(super) kv_paths: Vec<&'static str>,
     pub(super) approles: BTreeMap<String, String>,
+    pub(super) policies: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -158,4 +203,9 @@ pub(crate) struct AppRoleOutput {
 pub(crate) enum StepCaInitResult {
     Initialized,
     Skipped,
+    /// step-ca bootstrap was bypassed with `--skip-step-ca`.
+    External,
+    /// `--openbao-only` stopped before step-ca bootstrap; a later
+    /// `init --resume` finishes it.
+    Deferred,
 }
@@ -0,0 +1,557 @@
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use bootroot::openbao::{KvDualWrite, KvLayout, OpenBaoClient};
+
+use crate::cli::args::MigrateArgs;
+use crate::commands::cancellation;
+use crate::commands::init::{DEFAULT_KV_PATH_PREFIX, prompt_yes_no};
+use crate::i18n::Messages;
+use crate::state::{SERVICE_KV_SEGMENT, StateFile};
+
+/// A KV v2 mount plus the path prefix bootroot's secrets live under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct KvLocation<'a> {
+    mount: &'a str,
+    prefix: &'a str,
+}
+
+impl KvLocation<'_> {
+    fn path(&self, relative: &str) -> String {
+        format!("{}/{relative}", self.prefix.trim_end_matches('/'))
+    }
+
+    fn layout(&self) -> KvLayout {
+        KvLayout {
+            mount: self.mount.to_string(),
+            prefix: self.prefix.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl<'a> From<&'a KvLayout> for KvLocation<'a> {
+    fn from(layout: &'a KvLayout) -> Self {
+        Self {
+            mount: &layout.mount,
+            prefix: &layout.prefix,
+        }
+    }
+}
+
+impl fmt::Display for KvLocation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.mount, self.prefix.trim_end_matches('/'))
+    }
+}
+
+pub(crate) async fn run_migrate(args: &MigrateArgs, messages: &Messages) -> Result<()> {
+    let state_path = StateFile::default_path();
+    if !state_path.exists() {
+        anyhow::bail!(messages.error_state_missing());
+    }
+    let mut state =
+        StateFile::load(&state_path).with_context(|| messages.error_parse_state_failed())?;
+    let client = build_client(args, &state, messages)?;
+    if args.finalize {
+        return finalize_dual_write(&client, args, &mut state, &state_path, messages).await;
+    }
+
+    let from_mount = args
+        .from_mount
+        .clone()
+        .unwrap_or_else(|| state.kv_mount.clone());
+    let to_mount = args.to_mount.clone().unwrap_or_default();
+    let from_prefix = args.from_prefix.trim_end_matches('/');
+    let to_prefix = args.to_prefix.trim_end_matches('/');
+    let prefix_changed = from_prefix != to_prefix;
+    // Infra secrets stay at fixed paths on the recorded mount, so moving
+    // the mount and the prefix together would strand them.
+    if prefix_changed && from_mount != to_mount {
+        anyhow::bail!(messages.error_migrate_mount_and_prefix());
+    }
+    let (from_scope, to_scope) = migration_prefixes(from_prefix, to_prefix);
+    let from = KvLocation {
+        mount: &from_mount,
+        prefix: &from_scope,
+    };
+    let to = KvLocation {
+        mount: &to_mount,
+        prefix: &to_scope,
+    };
+    if from.to_string() == to.to_string() {
+        anyhow::bail!(messages.error_migrate_same_location(&from.to_string()));
+    }
+    bootroot::openbao::validate_kv_mount(from.mount)?;
+    bootroot::openbao::validate_kv_mount(to.mount)?;
+    if args.dual_write
+        && let Some(active) = &state.kv_dual_write
+    {
+        anyhow::bail!(
+            messages.error_migrate_dual_write_active(&KvLocation::from(&active.legacy).to_string())
+        );
+    }
+
+    client
+        .ensure_kv_v2(to.mount)
+        .await
+        .with_context(|| messages.error_openbao_kv_mount_failed())?;
+
+    let copied = copy_secrets(&client, from, to, messages).await?;
+    println!(
+        "{}",
+        messages.migrate_summary(
+            &copied.len().to_string(),
+            &from.to_string(),
+            &to.to_string()
+        )
+    );
+
+    let kv_mount_moved = from.mount == state.kv_mount && to.mount != state.kv_mount;
+    // Per-service paths derive from the recorded prefix, so moving the
+    // tree `service add` and `rotate` use must move the prefix with it.
+    let kv_prefix_moved =
+        from.mount == state.kv_mount && from_prefix == state.kv_prefix() && prefix_changed;
+    if kv_mount_moved {
+        state.kv_mount = to.mount.to_string();
+    }
+    if kv_prefix_moved {
+        state.kv_prefix = (to_prefix != DEFAULT_KV_PATH_PREFIX).then(|| to_prefix.to_string());
+    }
+    if args.dual_write {
+        state.kv_dual_write = Some(KvDualWrite {
+            current: to.layout(),
+            legacy: from.layout(),
+        });
+    }
+    if kv_mount_moved || kv_prefix_moved || args.dual_write {
+        state
+            .save(&state_path)
+            .with_context(|| messages.error_serialize_state_failed())?;
+    }
+    if kv_mount_moved {
+        println!("{}", messages.migrate_state_updated(to.mount));
+    }
+    if kv_prefix_moved {
+        println!("{}", messages.migrate_state_prefix_updated(to_prefix));
+    }
+
+    if args.dual_write {
+        println!(
+            "{}",
+            messages.migrate_dual_write_started(&to.to_string(), &from.to_string())
+        );
+    } else if args.delete_source {
+        let count = copied.len().to_string();
+        let location = from.to_string();
+        confirm_delete(args, &count, &location, messages)?;
+        delete_secrets(&client, from, &copied, messages).await?;
+        println!("{}", messages.migrate_source_deleted(&count, &location));
+    } else {
+        println!("{}", messages.migrate_source_kept(&from.to_string()));
+    }
+    println!("{}", messages.migrate_references_note());
+    Ok(())
+}
+
+/// Returns the source and destination prefixes a migration copies
+/// between.
+///
+/// Only the per-service tree derives from `kv_prefix`; the step-ca
+/// password, responder HMAC, EAB, and trust secrets live at fixed
+/// `bootroot/...` paths that `init`, `rotate`, `status`, and the rendered
+/// agent templates read directly. A prefix change therefore moves just
+/// `<prefix>/services`, while a mount-only move carries the whole tree.
+fn migration_prefixes(from_prefix: &str, to_prefix: &str) -> (String, String) {
+    if from_prefix == to_prefix {
+        return (from_prefix.to_string(), to_prefix.to_string());
+    }
+    (
+        format!("{from_prefix}/{SERVICE_KV_SEGMENT}"),
+        format!("{to_prefix}/{SERVICE_KV_SEGMENT}"),
+    )
+}
+
+fn build_client(
+    args: &MigrateArgs,
+    state: &StateFile,
+    messages: &Messages,
+) -> Result<OpenBaoClient> {
+    let root_token = args
+        .root_token
+        .root_token
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!(messages.error_openbao_root_token_required()))?;
+    let openbao_url = args
+        .openbao_url
+        .clone()
+        .unwrap_or_else(|| state.openbao_url.clone());
+    let mut client = OpenBaoClient::with_local_trust(&openbao_url, state.secrets_dir())
+        .with_context(|| messages.error_openbao_client_create_failed())?;
+    client.set_token(root_token);
+    Ok(client)
+}
+
+fn confirm_delete(
+    args: &MigrateArgs,
+    count: &str,
+    location: &str,
+    messages: &Messages,
+) -> Result<()> {
+    if !args.yes && !prompt_yes_no(&messages.migrate_confirm_delete(count, location), messages)? {
+        anyhow::bail!(messages.error_operation_cancelled());
+    }
+    Ok(())
+}
+
+/// Ends a `--dual-write` transition: deletes every secret left under the
+/// legacy layout and clears the transition from `state.json`.
+async fn finalize_dual_write(
+    client: &OpenBaoClient,
+    args: &MigrateArgs,
+    state: &mut StateFile,
+    state_path: &Path,
+    messages: &Messages,
+) -> Result<()> {
+    let Some(dual) = state.kv_dual_write.clone() else {
+        anyhow::bail!(messages.error_migrate_no_dual_write());
+    };
+    let legacy = KvLocation::from(&dual.legacy);
+    let relative_paths = list_secrets(client, legacy, messages).await?;
+    let count = relative_paths.len().to_string();
+    let location = legacy.to_string();
+    if !relative_paths.is_empty() {
+        confirm_delete(args, &count, &location, messages)?;
+        delete_secrets(client, legacy, &relative_paths, messages).await?;
+    }
+    state.kv_dual_write = None;
+    state
+        .save(state_path)
+        .with_context(|| messages.error_serialize_state_failed())?;
+    println!(
+        "{}",
+        messages.migrate_dual_write_finalized(&count, &location)
+    );
+    Ok(())
+}
+
+/// Copies every secret under `from` to the same relative path under
+/// `to`, reading each copy back to confirm it matches before moving on.
+///
+/// Returns the relative paths that were copied.
+async fn copy_secrets(
+    client: &OpenBaoClient,
+    from: KvLocation<'_>,
+    to: KvLocation<'_>,
+    messages: &Messages,
+) -> Result<Vec<String>> {
+    let relative_paths = list_secrets(client, from, messages).await?;
+    if relative_paths.is_empty() {
+        anyhow::bail!(messages.error_migrate_source_empty(&from.to_string()));
+    }
+    let mut copied = Vec::with_capacity(relative_paths.len());
+    for relative in relative_paths {
+        // The source is untouched until every copy is verified, so a stop
+        // here leaves a partial destination that a re-run overwrites.
+        cancellation::global().check(messages)?;
+        let source = from.path(&relative);
+        let destination = to.path(&relative);
+        // A secret whose latest version was deleted lists but has no
+        // readable data; there is nothing to carry over.
+        let Some(data) = client
+            .try_read_kv(from.mount, &source)
+            .await
+            .with_context(|| messages.error_openbao_kv_read_failed())?
+        else {
+            continue;
+        };
+        client
+            .write_kv(to.mount, &destination, data.clone())
+            .await
+            .with_context(|| messages.error_openbao_kv_write_failed())?;
+        let written = client
+            .try_read_kv(to.mount, &destination)
+            .await
+            .with_context(|| messages.error_openbao_kv_read_failed())?;
+        if written.as_ref() != Some(&data) {
+            anyhow::bail!(
+                messages.error_migrate_verify_failed(&format!("{}/{destination}", to.mount))
+            );
+        }
+        println!(
+            "{}",
+            messages.migrate_copied(
+                &format!("{}/{source}", from.mount),
+                &format!("{}/{destination}", to.mount)
+            )
+        );
+        copied.push(relative);
+    }
+    Ok(copied)
+}
+
+/// Walks the KV tree under `location` and returns every secret path
+/// relative to its prefix, in sorted order.
+async fn list_secrets(
+    client: &OpenBaoClient,
+    location: KvLocation<'_>,
+    messages: &Messages,
+) -> Result<Vec<String>> {
+    let mut secrets = Vec::new();
+    let mut pending = vec![String::new()];
+    while let Some(dir) = pending.pop() {
+        let listed_path = if dir.is_empty() {
+            location.prefix.trim_end_matches('/').to_string()
+        } else {
+            location.path(dir.trim_end_matches('/'))
+        };
+        let keys = client
+            .list_kv(location.mount, &listed_path)
+            .await
+            .with_context(|| messages.error_openbao_kv_read_failed())?;
+        for key in keys {
+            let relative = format!("{dir}{key}");
+            if key.ends_with('/') {
+                pending.push(relative);
+            } else {
+                secrets.push(relative);
+            }
+        }
+    }
+    secrets.sort();
+    Ok(secrets)
+}
+
+async fn delete_secrets(
+    client: &OpenBaoClient,
+    from: KvLocation<'_>,
+    relative_paths: &[String],
+    messages: &Messages,
+) -> Result<()> {
+    for relative in relative_paths {
+        cancellation::global().check(messages)?;
+        client
+            .delete_kv(from.mount, &from.path(relative))
+            .await
+            .with_context(|| messages.error_openbao_kv_delete_failed())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    const FROM: KvLocation<'static> = KvLocation {
+        mount: "secret",
+        prefix: "bootroot",
+    };
+    const TO: KvLocation<'static> = KvLocation {
+        mount: "bootroot",
+        prefix: "bootroot",
+    };
+
+    async fn mount_list(server: &MockServer, listed: &str, keys: &[&str]) {
+        Mock::given(method("GET"))
+            .and(path(format!("/v1/secret/metadata/{listed}")))
+            .and(query_param("list", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "keys": keys }
+            })))
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_secret(server: &MockServer, mount: &str, secret: &str, data: serde_json::Value) {
+        Mock::given(method("GET"))
+            .and(path(format!("/v1/{mount}/data/{secret}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": data, "metadata": { "version": 1 } }
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn copy_secrets_writes_every_known_path_to_destination() {
+        let server = MockServer::start().await;
+        mount_list(
+            &server,
+            "bootroot",
+            &["agent/", "ca", "services/", "stepca/"],
+        )
+        .await;
+        mount_list(&server, "bootroot/agent", &["eab"]).await;
+        mount_list(&server, "bootroot/services", &["edge-proxy/"]).await;
+        mount_list(
+            &server,
+            "bootroot/services/edge-proxy",
+            &["secret_id", "trust"],
+        )
+        .await;
+        mount_list(&server, "bootroot/stepca", &["db", "password"]).await;
+
+        let secrets = [
+            "bootroot/agent/eab",
+            "bootroot/ca",
+            "bootroot/services/edge-proxy/secret_id",
+            "bootroot/services/edge-proxy/trust",
+            "bootroot/stepca/db",
+            "bootroot/stepca/password",
+        ];
+        for secret in secrets {
+            let data = serde_json::json!({ "value": format!("value-of-{secret}") });
+            mount_secret(&server, "secret", secret, data.clone()).await;
+            // The read-back verification sees what was written.
+            mount_secret(&server, "bootroot", secret, data.clone()).await;
+            Mock::given(method("POST"))
+                .and(path(format!("/v1/bootroot/data/{secret}")))
+                .and(body_json(serde_json::json!({ "data": data })))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+        let messages = crate::i18n::test_messages();
+        let copied = copy_secrets(&client, FROM, TO, &messages)
+            .await
+            .expect("copy secrets");
+
+        assert_eq!(
+            copied,
+            vec![
+                "agent/eab",
+                "ca",
+                "services/edge-proxy/secret_id",
+                "services/edge-proxy/trust",
+                "stepca/db",
+                "stepca/password",
+            ]
+        );
+        server.verify().await;
+    }
+
+    #[test]
+    fn migration_prefixes_scope_prefix_change_to_services() {
+        assert_eq!(
+            migration_prefixes("bootroot", "bootroot"),
+            ("bootroot".to_string(), "bootroot".to_string())
+        );
+        assert_eq!(
+            migration_prefixes("bootroot", "tenants/acme"),
+            (
+                "bootroot/services".to_string(),
+                "tenants/acme/services".to_string()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn copy_secrets_moves_only_service_tree_on_prefix_change() {
+        let server = MockServer::start().await;
+        // Infra secrets sit beside the service tree but must stay put.
+        mount_list(&server, "bootroot", &["ca", "services/", "stepca/"]).await;
+        mount_list(&server, "bootroot/services", &["edge-proxy/"]).await;
+        mount_list(&server, "bootroot/services/edge-proxy", &["secret_id"]).await;
+        let data = serde_json::json!({ "secret_id": "sid" });
+        mount_secret(
+            &server,
+            "secret",
+            "bootroot/services/edge-proxy/secret_id",
+            data.clone(),
+        )
+        .await;
+        mount_secret(
+            &server,
+            "secret",
+            "tenants/acme/services/edge-proxy/secret_id",
+            data.clone(),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(path(
+                "/v1/secret/data/tenants/acme/services/edge-proxy/secret_id",
+            ))
+            .and(body_json(serde_json::json!({ "data": data })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let (from_prefix, to_prefix) = migration_prefixes("bootroot", "tenants/acme");
+        let from = KvLocation {
+            mount: "secret",
+            prefix: &from_prefix,
+        };
+        let to = KvLocation {
+            mount: "secret",
+            prefix: &to_prefix,
+        };
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+        let messages = crate::i18n::test_messages();
+        let copied = copy_secrets(&client, from, to, &messages)
+            .await
+            .expect("copy secrets");
+
+        assert_eq!(copied, vec!["edge-proxy/secret_id"]);
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn copy_secrets_fails_when_read_back_differs() {
+        let server = MockServer::start().await;
+        mount_list(&server, "bootroot", &["ca"]).await;
+        mount_secret(
+            &server,
+            "secret",
+            "bootroot/ca",
+            serde_json::json!({ "value": "a" }),
+        )
+        .await;
+        mount_secret(
+            &server,
+            "bootroot",
+            "bootroot/ca",
+            serde_json::json!({ "value": "stale" }),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/bootroot/data/bootroot/ca"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+        let messages = crate::i18n::test_messages();
+        let err = copy_secrets(&client, FROM, TO, &messages)
+            .await
+            .expect_err("mismatched copy must fail");
+        assert_eq!(
+            err.to_string(),
+            messages.error_migrate_verify_failed("bootroot/bootroot/ca")
+        );
+    }
+
+    #[tokio::test]
+    async fn copy_secrets_fails_when_source_is_empty() {
+        let server = MockServer::start().await;
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+        let messages = crate::i18n::test_messages();
+        let err = copy_secrets(&client, FROM, TO, &messages)
+            .await
+            .expect_err("empty source must fail");
+        assert_eq!(
+            err.to_string(),
+            messages.error_migrate_source_empty("secret/bootroot")
+        );
+    }
+}
@@ -1,5 +1,4 @@
 use std::path::Path;
-use std::process::Command as ProcessCommand;
 
 use anyhow::{Context, Result};
 use serde_json::Value;
@@ -7,6 +6,7 @@ use serde_json::Value;
 use crate::cli::args::{
     MonitoringDownArgs, MonitoringProfile, MonitoringStatusArgs, MonitoringUpArgs,
 };
+use crate::commands::container_runtime;
 use crate::commands::infra::{
     ContainerReadiness, collect_container_failures, collect_readiness, docker_compose_output,
     docker_output, run_docker,
@@ -46,7 +46,7 @@ pub(crate) fn run_monitoring_up(args: &MonitoringUpArgs, messages: &Messages) ->
 
     let readiness = collect_readiness(
         &args.compose_file.compose_file,
-        Some(&profile_str),
+        std::slice::from_ref(&profile_str),
         &services,
         messages,
     )?;
@@ -74,7 +74,7 @@ pub(crate) fn run_monitoring_status(
         let profile_str = profile.to_string();
         let readiness = collect_readiness(
             &args.compose_file.compose_file,
-            Some(&profile_str),
+            std::slice::from_ref(&profile_str),
             &services,
             messages,
         )?;
@@ -135,7 +135,7 @@ pub(crate) fn run_monitoring_down(args: &MonitoringDownArgs, messages: &Messages
             let profile_str = profile.to_string();
             let grafana_container_id = docker_compose_output(
                 &args.compose_file.compose_file,
-                Some(&profile_str),
+                std::slice::from_ref(&profile_str),
                 &["ps", "-q", grafana_service],
                 messages,
             )?
@@ -221,7 +221,7 @@ fn detect_running_profiles(
         let profile_str = profile.to_string();
         let container_id = docker_compose_output(
             compose_file,
-            Some(&profile_str),
+            std::slice::from_ref(&profile_str),
             &["ps", "-q", service],
             messages,
         )?;
@@ -294,7 +294,7 @@ fn monitoring_already_running(
     for service in services {
         let container_id = docker_compose_output(
             compose_file,
-            Some(&profile_str),
+            std::slice::from_ref(&profile_str),
             &["ps", "-q", service],
             messages,
         )?;
@@ -396,7 +396,7 @@ fn run_docker_with_env(
     messages: &Messages,
     grafana_admin_password: Option<&str>,
 ) -> Result<()> {
-    let mut command = ProcessCommand::new("docker");
+    let mut command = container_runtime::command();
     command.args(args);
     if let Some(password) = grafana_admin_password {
         command.env("GRAFANA_ADMIN_PASSWORD", password);
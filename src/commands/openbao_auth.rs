@@ -198,9 +198,9 @@ pub(crate) async fn authenticate_openbao_client(
 fn prompt_root_token(messages: &Messages) -> Result<String> {
     let mut input = std::io::stdin().lock();
     let mut output = std::io::stdout();
-    let mut prompt = Prompt::new(&mut input, &mut output, messages);
+    let mut prompt = Prompt::for_stdin(&mut input, &mut output, messages);
     let label = messages.prompt_openbao_root_token().trim_end_matches(": ");
-    prompt.prompt_with_validation(label, None, |value| {
+    prompt.prompt_secret_with_validation(label, |value| {
         if value.trim().is_empty() {
             anyhow::bail!(messages.error_value_required());
         }
@@ -3,6 +3,7 @@ use std::path::Path;
 use anyhow::{Context, Result};
 use bootroot::fs_util;
 
+use crate::cli::prompt::Prompt;
 use crate::i18n::Messages;
 
 const UNSEAL_KEYS_DIR: &str = "openbao";
@@ -70,16 +71,11 @@ pub(crate) fn prompt_unseal_keys_interactive(
         }
     };
     let mut keys = Vec::with_capacity(count as usize);
+    let mut input = io::stdin().lock();
+    let mut output = io::stdout();
+    let mut prompt = Prompt::for_stdin(&mut input, &mut output, messages);
     for index in 1..=count {
-        print!("Unseal key {index}/{count}: ");
-        io::stdout()
-            .flush()
-            .with_context(|| messages.error_prompt_flush_failed())?;
-        let mut input = String::new();
-        io::stdin()
-            .read_line(&mut input)
-            .with_context(|| messages.error_prompt_read_failed())?;
-        keys.push(input.trim().to_string());
+        keys.push(prompt.prompt_secret(&format!("Unseal key {index}/{count}"))?);
     }
     Ok(keys)
 }
@@ -6,9 +6,10 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 
 use crate::cli::args::{
-    ComposeFileArgs, DbAdminDsnArgs, DbTimeoutArgs, InfraUpArgs, InitArgs, OpenBaoArgs, ReinitArgs,
-    RootTokenArgs, SecretsDirArgs,
+    ComposeFileArgs, DbAdminDsnArgs, DbProbeTimeoutArgs, DbTimeoutArgs, InfraUpArgs, InitArgs,
+    OpenBaoArgs, ReinitArgs, RootTokenArgs, SecretsDirArgs,
 };
+use crate::commands::cancellation;
 use crate::commands::clean::{
     COMPOSE_PROJECT_LABEL, COMPOSE_SERVICE_LABEL, container_exists_via_docker,
     inspect_label_via_docker, remove_openbao_container_and_volumes, resolve_compose_project,
@@ -148,6 +149,9 @@ pub(crate) async fn run_reinit(args: &ReinitArgs, messages: &Messages) -> Result
         anyhow::bail!(messages.error_operation_cancelled());
     }
 
+    // 7.5. Last stop before anything is destroyed.
+    cancellation::global().check(messages)?;
+
     // 8. Stop and remove the OpenBao container + volumes.
     remove_openbao_container_and_volumes(compose_file, messages)?;
 
@@ -173,8 +177,14 @@ pub(crate) async fn run_reinit(args: &ReinitArgs, messages: &Messages) -> Result
         services: vec![OPENBAO_COMPOSE_SERVICE.to_string()],
         image_archive_dir: None,
         restart_policy: "always".to_string(),
+        readiness_timeout: crate::commands::infra::DEFAULT_READINESS_TIMEOUT_SECS,
+        service_timeout: Vec::new(),
+        poll_interval: crate::commands::infra::READINESS_POLL_INTERVAL,
+        pull_retries: crate::commands::infra::DEFAULT_PULL_RETRIES,
         openbao_url: args.openbao.openbao_url.clone(),
         openbao_unseal_from_file: None,
+        compose_profile: Vec::new(),
+        allow_nonloopback_postgres: args.allow_nonloopback_postgres.clone(),
     };
     run_infra_up(&infra_args, messages).await?;
 
@@ -186,6 +196,9 @@ pub(crate) async fn run_reinit(args: &ReinitArgs, messages: &Messages) -> Result
     //     so the freshly issued root token is persisted with mode 0600
     //     after init succeeds.
     let init_args = init_args_for_reinit(args, &snapshot, &effective_secrets_dir);
+    // Stopping here leaves an empty, uninitialized OpenBao and the
+    // intent-only state.json, which a plain `bootroot init` picks up.
+    cancellation::global().check(messages)?;
     run_init(&init_args, messages).await?;
 
     println!("{}", messages.reinit_completed());
@@ -374,7 +387,11 @@ pub(crate) struct DeploymentIntent {
     pub(crate) stepca_bind_addr: Option<String>,
     pub(crate) stepca_advertise_addr: Option<String>,
     pub(crate) secrets_dir: Option<PathBuf>,
+    pub(crate) ca_json_path: Option<PathBuf>,
     pub(crate) infra_certs: BTreeMap<String, crate::state::InfraCertEntry>,
+    pub(crate) openbao_auto_unseal: bool,
+    pub(crate) stepca_image: Option<String>,
+    pub(crate) openbao_agent_image: Option<String>,
 }
 
 /// Snapshots intent fields from `state.json` if present, otherwise
@@ -392,7 +409,11 @@ pub(crate) fn snapshot_deployment_intent(state_path: &Path) -> Result<Deployment
         stepca_bind_addr: state.stepca_bind_addr,
         stepca_advertise_addr: state.stepca_advertise_addr,
         secrets_dir: state.secrets_dir,
+        ca_json_path: state.ca_json_path,
         infra_certs: state.infra_certs,
+        openbao_auto_unseal: state.openbao_auto_unseal,
+        stepca_image: state.stepca_image,
+        openbao_agent_image: state.openbao_agent_image,
     })
 }
 
@@ -412,6 +433,7 @@ pub(crate) fn write_minimal_state(
         openbao_url: openbao.openbao_url.clone(),
         kv_mount: openbao.kv_mount.clone(),
         secrets_dir: Some(effective_secrets_dir.to_path_buf()),
+        ca_json_path: snapshot.ca_json_path.clone(),
         policies: BTreeMap::new(),
         approles: BTreeMap::new(),
         services: BTreeMap::new(),
@@ -422,6 +444,9 @@ pub(crate) fn write_minimal_state(
         stepca_bind_addr: snapshot.stepca_bind_addr.clone(),
         stepca_advertise_addr: snapshot.stepca_advertise_addr.clone(),
         infra_certs: snapshot.infra_certs.clone(),
+        openbao_auto_unseal: snapshot.openbao_auto_unseal,
+        stepca_image: snapshot.stepca_image.clone(),
+        openbao_agent_image: snapshot.openbao_agent_image.clone(),
         ..Default::default()
     };
     state
@@ -814,8 +839,8 @@ fn write_reinit_plan<W: Write>(
 /// partial-init that crashed before `update_ca_json_with_backup` ran),
 /// is malformed, or has no `db.dataSource` field.  In those cases the
 /// existing env-derived DSN resolution is left in place.
-fn preserved_db_dsn_from_ca_json(secrets_dir: &Path) -> Option<String> {
-    let value = read_ca_json(secrets_dir)?;
+fn preserved_db_dsn_from_ca_json(ca_json_path: &Path) -> Option<String> {
+    let value = read_ca_json(ca_json_path)?;
     value
         .get("db")
         .and_then(|db| db.get("dataSource"))
@@ -823,15 +848,14 @@ fn preserved_db_dsn_from_ca_json(secrets_dir: &Path) -> Option<String> {
         .map(str::to_string)
 }
 
-/// Reads `<secrets_dir>/config/ca.json` once for the `preserved_*`
-/// helpers below.  Returns `None` when the file is absent or malformed
-/// so callers fall back to their CLI/default values.
-fn read_ca_json(secrets_dir: &Path) -> Option<serde_json::Value> {
-    let ca_json_path = secrets_dir.join("config").join("ca.json");
+/// Reads the preserved `ca.json` once for the `preserved_*` helpers
+/// below.  Returns `None` when the file is absent or malformed so
+/// callers fall back to their CLI/default values.
+fn read_ca_json(ca_json_path: &Path) -> Option<serde_json::Value> {
     if !ca_json_path.exists() {
         return None;
     }
-    let contents = std::fs::read_to_string(&ca_json_path).ok()?;
+    let contents = std::fs::read_to_string(ca_json_path).ok()?;
     serde_json::from_str(&contents).ok()
 }
 
@@ -845,8 +869,8 @@ fn read_ca_json(secrets_dir: &Path) -> Option<serde_json::Value> {
 /// Accepts both the on-disk shape (`authority.provisioners`) and the
 /// flat `provisioners` shape emitted by older `step ca init` builds,
 /// matching `set_acme_cert_duration`'s discovery logic.
-fn preserved_stepca_provisioner_from_ca_json(secrets_dir: &Path) -> Option<String> {
-    let value = read_ca_json(secrets_dir)?;
+fn preserved_stepca_provisioner_from_ca_json(ca_json_path: &Path) -> Option<String> {
+    let value = read_ca_json(ca_json_path)?;
     locate_provisioners(&value)?.iter().find_map(|p| {
         let is_acme = p
             .get("type")
@@ -866,8 +890,8 @@ fn preserved_stepca_provisioner_from_ca_json(secrets_dir: &Path) -> Option<Strin
 /// so a deployment initialized with a non-default `--cert-duration`
 /// keeps that value on the second init pass rather than silently
 /// snapping back to `DEFAULT_CERT_DURATION`.
-fn preserved_cert_duration_from_ca_json(secrets_dir: &Path) -> Option<String> {
-    let value = read_ca_json(secrets_dir)?;
+fn preserved_cert_duration_from_ca_json(ca_json_path: &Path) -> Option<String> {
+    let value = read_ca_json(ca_json_path)?;
     locate_provisioners(&value)?.iter().find_map(|p| {
         let is_acme = p
             .get("type")
@@ -938,10 +962,16 @@ fn init_args_for_reinit(
     {
         openbao.openbao_url = client_url_from_bind_addr(bind);
     }
-    let db_dsn = preserved_db_dsn_from_ca_json(effective_secrets_dir);
-    let stepca_provisioner = preserved_stepca_provisioner_from_ca_json(effective_secrets_dir)
+    // The `--ca-json` override recorded by the first init keeps
+    // applying, so the second pass patches the same file.
+    let ca_json_path = snapshot
+        .ca_json_path
+        .clone()
+        .unwrap_or_else(|| effective_secrets_dir.join("config").join("ca.json"));
+    let db_dsn = preserved_db_dsn_from_ca_json(&ca_json_path);
+    let stepca_provisioner = preserved_stepca_provisioner_from_ca_json(&ca_json_path)
         .unwrap_or_else(|| crate::commands::init::DEFAULT_STEPCA_PROVISIONER.to_string());
-    let cert_duration = preserved_cert_duration_from_ca_json(effective_secrets_dir)
+    let cert_duration = preserved_cert_duration_from_ca_json(&ca_json_path)
         .unwrap_or_else(|| crate::commands::init::DEFAULT_CERT_DURATION.to_string());
     Box::new(InitArgs {
         openbao,
@@ -949,17 +979,33 @@ fn init_args_for_reinit(
             secrets_dir: effective_secrets_dir.to_path_buf(),
         },
         compose: args.compose.clone(),
+        allow_nonloopback_postgres: args.allow_nonloopback_postgres.clone(),
         enable: args.enable.clone(),
         skip: args.skip.clone(),
+        skip_step_ca: false,
+        ca_json: snapshot.ca_json_path.clone(),
+        skip_responder: false,
+        resume: false,
+        openbao_only: false,
+        yes: false,
+        print_agent_config_template: false,
+        dump_plan_json: false,
+        dry_run: false,
         summary_json: args.summary_json.clone(),
+        reveal_to_file: None,
+        audit_log: None,
         root_token: RootTokenArgs { root_token: None },
         unseal_key: Vec::new(),
         openbao_unseal_from_file: None,
+        // The seal type belongs to the OpenBao server config, which reinit
+        // keeps, so the fresh init must use the same key kind.
+        auto_unseal: snapshot.openbao_auto_unseal,
         secret_id_ttl: crate::commands::init::SECRET_ID_TTL.to_string(),
         // Reinit wipes OpenBao, so any prior CIDR binding no longer
         // matches a live credential; the binding is opt-in per init run
         // and must be re-supplied via `bootroot init --rotate-bound-cidrs`.
         rotate_bound_cidrs: Vec::new(),
+        approle_bind_cidr: Vec::new(),
         stepca_password: None,
         db_dsn,
         db_admin: DbAdminDsnArgs { admin_dsn: None },
@@ -967,11 +1013,26 @@ fn init_args_for_reinit(
         db_password: None,
         db_name: None,
         db_timeout: DbTimeoutArgs { timeout_secs: 2 },
+        db_probe_timeout: DbProbeTimeoutArgs::default(),
         http_hmac: None,
         responder_url: None,
         responder_timeout_secs: 5,
+        agent_render_timeout_secs: crate::commands::init::DEFAULT_AGENT_RENDER_TIMEOUT_SECS,
         stepca_provisioner,
         cert_duration,
+        // A private-registry host recorded its images at init; falling
+        // back to the public defaults would break the air-gapped reinit.
+        stepca_image: snapshot
+            .stepca_image
+            .clone()
+            .unwrap_or_else(|| crate::commands::init::DEFAULT_STEPCA_IMAGE.to_string()),
+        openbao_agent_image: snapshot
+            .openbao_agent_image
+            .clone()
+            .unwrap_or_else(|| crate::commands::init::DEFAULT_OPENBAO_AGENT_IMAGE.to_string()),
+        openbao_agent_mount_root: crate::commands::init::DEFAULT_OPENBAO_AGENT_MOUNT_ROOT
+            .to_string(),
+        openbao_agent_exec: Vec::new(),
         eab_kid: None,
         eab_hmac: None,
         no_eab: args.no_eab,
@@ -1047,11 +1108,14 @@ mod tests {
                     secret_id_ttl: None,
                     secret_id_wrap_ttl: None,
                     token_bound_cidrs: None,
+                    approle_bound_cidrs: None,
                 },
                 agent_email: None,
                 agent_server: None,
                 agent_responder_url: None,
                 cert_group_gid: None,
+                sync_generation: None,
+                trust_generation: None,
             }
         });
         let mut policies = BTreeMap::new();
@@ -1828,6 +1892,7 @@ mod tests {
             stepca_bind_addr: Some("192.168.1.10:9000".to_string()),
             stepca_advertise_addr: None,
             secrets_dir: Some(PathBuf::from("secrets-custom")),
+            ca_json_path: None,
             infra_certs: {
                 let mut m = BTreeMap::new();
                 m.insert(
@@ -1846,6 +1911,9 @@ mod tests {
                 );
                 m
             },
+            openbao_auto_unseal: false,
+            stepca_image: None,
+            openbao_agent_image: None,
         };
         write_reinit_plan(&mut buf, &snapshot, Path::new("secrets-custom"), &messages)
             .expect("write plan");
@@ -1913,6 +1981,7 @@ mod tests {
             compose: ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             yes: true,
             root_token_output: Some(PathBuf::from("/tmp/root.token")),
             enable: Vec::new(),
@@ -1952,6 +2021,7 @@ mod tests {
             compose: ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             yes: true,
             root_token_output: None,
             enable: Vec::new(),
@@ -2016,6 +2086,7 @@ mod tests {
             compose: ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             yes: true,
             root_token_output: None,
             enable: Vec::new(),
@@ -2055,6 +2126,7 @@ mod tests {
             compose: ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             yes: true,
             root_token_output: None,
             enable: Vec::new(),
@@ -2073,6 +2145,60 @@ mod tests {
         );
     }
 
+    /// Images recorded at init survive into the reinit's init run, so a
+    /// private-registry host does not fall back to the public defaults.
+    #[test]
+    fn init_args_for_reinit_reuses_recorded_images() {
+        let dir = tempdir().expect("tempdir");
+        let reinit_args = ReinitArgs {
+            openbao: OpenBaoArgs {
+                openbao_url: crate::commands::init::DEFAULT_OPENBAO_URL.to_string(),
+                kv_mount: "secret".to_string(),
+            },
+            secrets_dir: SecretsDirArgs {
+                secrets_dir: dir.path().to_path_buf(),
+            },
+            compose: ComposeFileArgs {
+                compose_file: PathBuf::from("docker-compose.yml"),
+            },
+            allow_nonloopback_postgres: None,
+            yes: true,
+            root_token_output: None,
+            enable: Vec::new(),
+            skip: Vec::new(),
+            summary_json: None,
+            no_eab: true,
+        };
+        let snapshot = DeploymentIntent {
+            stepca_image: Some("registry.internal/smallstep/step-ca:0.30.2".to_string()),
+            openbao_agent_image: Some("registry.internal/openbao/openbao:2.5.5".to_string()),
+            ..DeploymentIntent::default()
+        };
+        let init_args = init_args_for_reinit(
+            &reinit_args,
+            &snapshot,
+            &reinit_args.secrets_dir.secrets_dir,
+        );
+        assert_eq!(
+            init_args.stepca_image,
+            "registry.internal/smallstep/step-ca:0.30.2"
+        );
+        assert_eq!(
+            init_args.openbao_agent_image,
+            "registry.internal/openbao/openbao:2.5.5"
+        );
+
+        let defaults = init_args_for_reinit(
+            &reinit_args,
+            &DeploymentIntent::default(),
+            &reinit_args.secrets_dir.secrets_dir,
+        );
+        assert_eq!(
+            defaults.stepca_image,
+            crate::commands::init::DEFAULT_STEPCA_IMAGE
+        );
+    }
+
     /// Malformed or db-less `ca.json` (e.g. step-ca configured without
     /// a `db` block) must not crash reinit; the env-derived fallback is
     /// the correct behaviour in that case.
@@ -2082,7 +2208,7 @@ mod tests {
         let config_dir = dir.path().join("config");
         fs::create_dir_all(&config_dir).expect("create config dir");
         fs::write(config_dir.join("ca.json"), r#"{"address":":9000"}"#).expect("write ca.json");
-        assert!(preserved_db_dsn_from_ca_json(dir.path()).is_none());
+        assert!(preserved_db_dsn_from_ca_json(&config_dir.join("ca.json")).is_none());
     }
 
     /// Regression for Round 5 reviewer item: a deployment initialised
@@ -2121,6 +2247,7 @@ mod tests {
             compose: ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             yes: true,
             root_token_output: None,
             enable: Vec::new(),
@@ -2166,6 +2293,7 @@ mod tests {
             compose: ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             yes: true,
             root_token_output: None,
             enable: Vec::new(),
@@ -2201,8 +2329,8 @@ mod tests {
             r#"{"authority":{"provisioners":[{"type":"JWK","name":"admin"}]}}"#,
         )
         .expect("write ca.json");
-        assert!(preserved_stepca_provisioner_from_ca_json(dir.path()).is_none());
-        assert!(preserved_cert_duration_from_ca_json(dir.path()).is_none());
+        assert!(preserved_stepca_provisioner_from_ca_json(&config_dir.join("ca.json")).is_none());
+        assert!(preserved_cert_duration_from_ca_json(&config_dir.join("ca.json")).is_none());
     }
 
     /// Regression for Round 6 reviewer item: an operator-supplied
@@ -2276,6 +2404,7 @@ mod tests {
             compose: ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             yes: true,
             root_token_output: None,
             enable: Vec::new(),
@@ -2285,6 +2414,7 @@ mod tests {
         };
         let snapshot = DeploymentIntent {
             secrets_dir: Some(PathBuf::from("secrets-custom")),
+            ca_json_path: None,
             ..DeploymentIntent::default()
         };
         let effective = effective_secrets_dir(&reinit_args, &snapshot);
@@ -2329,6 +2459,7 @@ mod tests {
             compose: ComposeFileArgs {
                 compose_file: PathBuf::from("docker-compose.yml"),
             },
+            allow_nonloopback_postgres: None,
             yes: true,
             root_token_output: None,
             enable: Vec::new(),
@@ -2338,6 +2469,7 @@ mod tests {
         };
         let snapshot = DeploymentIntent {
             secrets_dir: Some(snapshot_secrets.clone()),
+            ca_json_path: None,
             ..DeploymentIntent::default()
         };
         let effective = effective_secrets_dir(&reinit_args, &snapshot);
@@ -2585,6 +2717,7 @@ mod tests {
         state_with_intent().save(&state_path).unwrap();
         let snapshot = DeploymentIntent {
             secrets_dir: Some(PathBuf::from("secrets-custom")),
+            ca_json_path: None,
             ..DeploymentIntent::default()
         };
         let openbao = OpenBaoArgs {
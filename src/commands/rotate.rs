@@ -5,6 +5,7 @@ mod eab_clear;
 mod helpers;
 mod infra_cert;
 mod openbao_recovery;
+mod plan;
 mod responder_hmac;
 mod stepca_password;
 
@@ -12,22 +13,33 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use anyhow::{Context, Result};
-use bootroot::openbao::OpenBaoClient;
+use bootroot::openbao::{KvDualWrite, OpenBaoClient};
+use bootroot::trust_bootstrap::SERVICE_EAB_KV_SUFFIX;
 
 use crate::cli::args::{RotateArgs, RotateCommand};
-use crate::commands::init::{CA_CERTS_DIR, CA_INTERMEDIATE_CERT_FILENAME, CA_ROOT_CERT_FILENAME};
+use crate::commands::capabilities::{CapabilitySource, RequiredCapability, preflight_capabilities};
+use crate::commands::constants::SERVICE_RESPONDER_HMAC_KV_SUFFIX;
+use crate::commands::init::{
+    CA_CERTS_DIR, CA_INTERMEDIATE_CERT_FILENAME, CA_ROOT_CERT_FILENAME,
+    OPENBAO_AGENT_RESPONDER_SERVICE, OPENBAO_AGENT_STEPCA_SERVICE, PATH_AGENT_EAB, PATH_CA_TRUST,
+    PATH_RESPONDER_HMAC, PATH_STEPCA_DB, PATH_STEPCA_PASSWORD, POLICY_BOOTROOT_RUNTIME_ROTATE,
+};
 use crate::commands::openbao_auth::{authenticate_openbao_client, resolve_runtime_auth};
+use crate::commands::trust::SERVICE_TRUST_KV_SUFFIX;
 use crate::i18n::Messages;
 use crate::state::StateFile;
 
 pub(super) const ROLE_ID_FILENAME: &str = "role_id";
-/// Image the `step` helper containers run in the `rotate` flows. The
-/// ownership sweep reuses it so it adds no dependency the flow did not
-/// already have (unlike the compose step-ca *server* image, which is not
-/// present on the air-gapped rotate host).
-pub(super) const STEP_CA_HELPER_IMAGE: &str = "smallstep/step-ca:0.30.2";
 pub(super) const OPENBAO_AGENT_STEPCA_CONTAINER: &str = "bootroot-openbao-agent-stepca";
 pub(super) const OPENBAO_AGENT_RESPONDER_CONTAINER: &str = "bootroot-openbao-agent-responder";
+pub(super) const OPENBAO_AGENT_STEPCA: OpenBaoAgent = OpenBaoAgent {
+    service: OPENBAO_AGENT_STEPCA_SERVICE,
+    container: OPENBAO_AGENT_STEPCA_CONTAINER,
+};
+pub(super) const OPENBAO_AGENT_RESPONDER: OpenBaoAgent = OpenBaoAgent {
+    service: OPENBAO_AGENT_RESPONDER_SERVICE,
+    container: OPENBAO_AGENT_RESPONDER_CONTAINER,
+};
 pub(super) const ROOT_CA_COMMON_NAME: &str = "Bootroot Root CA";
 pub(super) const INTERMEDIATE_CA_COMMON_NAME: &str = "Bootroot Intermediate CA";
 pub(super) const RENDERED_FILE_POLL_INTERVAL: Duration = Duration::from_secs(1);
@@ -37,6 +49,15 @@ pub(super) const OPENBAO_RECOVERY_SCOPE_ROOT_TOKEN: &str = "root-token";
 pub(super) const OPENBAO_ROOT_ROTATION_INCOMPLETE_ERROR: &str =
     "OpenBao root-key rotation did not complete; verify unseal keys and retry";
 
+/// An `OpenBao` Agent sidecar the rotate flows reload after writing a
+/// secret it renders: its compose service and the container name
+/// `infra install` gives it.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct OpenBaoAgent {
+    pub(super) service: &'static str,
+    pub(super) container: &'static str,
+}
+
 /// Typed outcome of a `bootroot rotate` subcommand so the process
 /// exit code can distinguish a completed rotation from a timed-out
 /// `--wait` window. Routed up through `run_rotate` to `main`, where
@@ -50,11 +71,22 @@ pub(crate) enum RotateOutcome {
 #[derive(Debug, Clone)]
 pub(super) struct StatePaths {
     secrets_dir: PathBuf,
+    ca_json: Option<PathBuf>,
 }
 
 impl StatePaths {
     fn new(secrets_dir: PathBuf) -> Self {
-        Self { secrets_dir }
+        Self {
+            secrets_dir,
+            ca_json: None,
+        }
+    }
+
+    /// Points `ca_json` at an `--ca-json` override instead of
+    /// `<secrets_dir>/config/ca.json`.
+    fn with_ca_json(mut self, ca_json: Option<PathBuf>) -> Self {
+        self.ca_json = ca_json;
+        self
     }
 
     pub(super) fn secrets_dir(&self) -> &Path {
@@ -82,7 +114,9 @@ impl StatePaths {
     }
 
     pub(super) fn ca_json(&self) -> PathBuf {
-        self.secrets_dir.join("config").join("ca.json")
+        self.ca_json
+            .clone()
+            .unwrap_or_else(|| self.secrets_dir.join("config").join("ca.json"))
     }
 
     pub(super) fn ca_certs_dir(&self) -> PathBuf {
@@ -127,48 +161,21 @@ pub(super) struct RotateContext {
     pub(super) paths: StatePaths,
     pub(super) state_dir: PathBuf,
     pub(super) state_file: PathBuf,
+    /// How long to wait for restarted services to report ready again
+    /// (`--confirm-restart`); `None` skips the check.
+    pub(super) restart_confirmation: Option<Duration>,
 }
 
 #[allow(clippy::too_many_lines)]
 pub(crate) async fn run_rotate(args: &RotateArgs, messages: &Messages) -> Result<RotateOutcome> {
-    let state_path = args
-        .state_file
-        .clone()
-        .unwrap_or_else(StateFile::default_path);
-    if !state_path.exists() {
-        anyhow::bail!(messages.error_state_missing());
-    }
-    let state =
-        StateFile::load(&state_path).with_context(|| messages.error_parse_state_failed())?;
+    let mut ctx = load_context(args, messages)?;
 
-    let openbao_url = args
-        .openbao
-        .openbao_url
-        .clone()
-        .unwrap_or_else(|| state.openbao_url.clone());
-    let kv_mount = args
-        .openbao
-        .kv_mount
-        .clone()
-        .unwrap_or_else(|| state.kv_mount.clone());
-    let secrets_dir = args
-        .secrets_dir
-        .secrets_dir
-        .clone()
-        .unwrap_or_else(|| state.secrets_dir().to_path_buf());
-    let paths = StatePaths::new(secrets_dir.clone());
-    let state_dir = state_path
-        .parent()
-        .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
-    let mut ctx = RotateContext {
-        openbao_url,
-        kv_mount,
-        compose_file: args.compose.compose_file.clone(),
-        state,
-        paths,
-        state_dir,
-        state_file: state_path,
-    };
+    // The plan is computed from state.json and the local layout alone,
+    // so a dry run needs no credentials and cannot write to OpenBao.
+    if args.dry_run {
+        plan::print_rotate_plan(&ctx, &args.command, messages)?;
+        return Ok(RotateOutcome::Completed);
+    }
 
     // InfraCert operates on local files and Docker only — it must not
     // require an OpenBao connection so it can fix a broken/expired cert.
@@ -180,11 +187,23 @@ pub(crate) async fn run_rotate(args: &RotateArgs, messages: &Messages) -> Result
     let runtime_auth = resolve_runtime_auth(&args.runtime_auth, true, messages)?;
     let mut client = OpenBaoClient::with_local_trust(&ctx.openbao_url, ctx.paths.secrets_dir())
         .with_context(|| messages.error_openbao_client_create_failed())?;
+    client.set_kv_dual_write(ctx.state.kv_dual_write.clone());
     authenticate_openbao_client(&mut client, &runtime_auth, messages).await?;
     client
         .health_check()
         .await
         .with_context(|| messages.error_openbao_health_check_failed())?;
+    preflight_capabilities(
+        &client,
+        &required_capabilities(
+            &args.command,
+            &ctx.kv_mount,
+            ctx.state.kv_dual_write.as_ref(),
+        ),
+        CapabilitySource::Policy(POLICY_BOOTROOT_RUNTIME_ROTATE),
+        messages,
+    )
+    .await?;
 
     match &args.command {
         RotateCommand::StepcaPassword(step_args) => {
@@ -236,8 +255,8 @@ pub(crate) async fn run_rotate(args: &RotateArgs, messages: &Messages) -> Result
             )
             .await?;
         }
-        RotateCommand::TrustSync(_) => {
-            ca::rotate_trust_sync(&mut ctx, &client, args.yes, messages).await?;
+        RotateCommand::TrustSync(step_args) => {
+            ca::rotate_trust_sync(&mut ctx, &client, step_args, args.yes, messages).await?;
         }
         RotateCommand::ForceReissue(step_args) => {
             let outcome =
@@ -258,6 +277,223 @@ pub(crate) async fn run_rotate(args: &RotateArgs, messages: &Messages) -> Result
     Ok(RotateOutcome::Completed)
 }
 
+/// Resolves `state.json` and the CLI overrides into the context every
+/// rotate target runs against.
+fn load_context(args: &RotateArgs, messages: &Messages) -> Result<RotateContext> {
+    let state_path = args
+        .state_file
+        .clone()
+        .unwrap_or_else(StateFile::default_path);
+    if !state_path.exists() {
+        anyhow::bail!(messages.error_state_missing());
+    }
+    let state =
+        StateFile::load(&state_path).with_context(|| messages.error_parse_state_failed())?;
+
+    let openbao_url = args
+        .openbao
+        .openbao_url
+        .clone()
+        .unwrap_or_else(|| state.openbao_url.clone());
+    let kv_mount = args
+        .openbao
+        .kv_mount
+        .clone()
+        .unwrap_or_else(|| state.kv_mount.clone());
+    // A dry run only reads, so the mismatch warning is enough there.
+    let secrets_dir = resolve_secrets_dir(
+        args.secrets_dir.secrets_dir.as_deref(),
+        state.secrets_dir(),
+        |prompt| helpers::confirm_action(prompt, args.yes || args.dry_run, messages),
+        messages,
+    )?;
+    let ca_json = args.ca_json.clone().or_else(|| state.ca_json_path.clone());
+    let paths = StatePaths::new(secrets_dir.clone()).with_ca_json(ca_json);
+    let state_dir = state_path
+        .parent()
+        .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+    Ok(RotateContext {
+        openbao_url,
+        kv_mount,
+        compose_file: args.compose.compose_file.clone(),
+        state,
+        paths,
+        state_dir,
+        state_file: state_path,
+        restart_confirmation: args
+            .confirm_restart
+            .then(|| Duration::from_secs(args.restart_timeout)),
+    })
+}
+
+/// Resolves the secrets directory rotate works in: `explicit` when given,
+/// otherwise the one `state.json` records.  An explicit directory that
+/// differs from the recorded one is warned about and must be confirmed,
+/// because rotating there would miss the `ca.json` and keys init wrote.
+fn resolve_secrets_dir(
+    explicit: Option<&Path>,
+    recorded: &Path,
+    confirm: impl FnOnce(&str) -> Result<()>,
+    messages: &Messages,
+) -> Result<PathBuf> {
+    let Some(explicit) = explicit else {
+        return Ok(recorded.to_path_buf());
+    };
+    if !same_directory(explicit, recorded) {
+        let provided = explicit.display().to_string();
+        eprintln!(
+            "{}",
+            messages.warning_secrets_dir_mismatch(&provided, &recorded.display().to_string())
+        );
+        confirm(&messages.prompt_confirm_secrets_dir_mismatch(&provided))?;
+    }
+    Ok(explicit.to_path_buf())
+}
+
+/// Compares directories by their canonical form when both exist, so a
+/// relative and an absolute spelling of the same path still match.
+fn same_directory(left: &Path, right: &Path) -> bool {
+    match (std::fs::canonicalize(left), std::fs::canonicalize(right)) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => left == right,
+    }
+}
+
+/// KV locations a rotate subcommand overwrites.
+///
+/// The capability preflight and the `--dry-run` plan both read this
+/// table, so what is checked and what is previewed cannot drift apart.
+pub(super) struct RotateKvTargets {
+    /// Fixed infrastructure paths under the KV mount.
+    pub(super) paths: &'static [&'static str],
+    /// Suffix of the per-service copy synced to every registered
+    /// service, if the target fans out.
+    pub(super) service_suffix: Option<&'static str>,
+}
+
+/// Returns the KV locations `command` overwrites.
+pub(super) fn rotate_kv_targets(command: &RotateCommand) -> RotateKvTargets {
+    let (paths, service_suffix): (&'static [&'static str], _) = match command {
+        RotateCommand::StepcaPassword(_) => (&[PATH_STEPCA_PASSWORD], None),
+        RotateCommand::Db(_) => (&[PATH_STEPCA_DB], None),
+        RotateCommand::ResponderHmac(_) => (
+            &[PATH_RESPONDER_HMAC],
+            Some(SERVICE_RESPONDER_HMAC_KV_SUFFIX),
+        ),
+        RotateCommand::TrustSync(_) | RotateCommand::CaKey(_) => {
+            (&[PATH_CA_TRUST], Some(SERVICE_TRUST_KV_SUFFIX))
+        }
+        RotateCommand::EabClear(_) => (&[PATH_AGENT_EAB], Some(SERVICE_EAB_KV_SUFFIX)),
+        RotateCommand::OpenBaoRecovery(_)
+        | RotateCommand::AppRoleSecretId(_)
+        | RotateCommand::ForceReissue(_)
+        | RotateCommand::InfraCert(_) => (&[], None),
+    };
+    RotateKvTargets {
+        paths,
+        service_suffix,
+    }
+}
+
+/// Lists the fixed KV paths a rotate subcommand overwrites, so a token
+/// missing one is rejected before the rotation starts.  Per-service
+/// paths and `AppRole` endpoints are left to the step itself.  During a
+/// dual-write transition the mirrored legacy paths are required too.
+fn required_capabilities(
+    command: &RotateCommand,
+    kv_mount: &str,
+    dual_write: Option<&KvDualWrite>,
+) -> Vec<RequiredCapability> {
+    let paths = rotate_kv_targets(command).paths;
+    let mut required = Vec::new();
+    for path in paths {
+        required.push(RequiredCapability::kv_update(kv_mount, path));
+        if let Some((legacy_mount, legacy_path)) =
+            dual_write.and_then(|dual| dual.legacy_target(kv_mount, path))
+        {
+            required.push(RequiredCapability::kv_update(&legacy_mount, &legacy_path));
+        }
+    }
+    required
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::test_support::test_messages;
+    use super::*;
+
+    #[test]
+    fn resolve_secrets_dir_defaults_to_recorded_dir_without_prompting() {
+        let messages = test_messages();
+        let dir = tempfile::tempdir().expect("tempdir");
+        let resolved = resolve_secrets_dir(
+            None,
+            dir.path(),
+            |_| panic!("no prompt without an explicit --secrets-dir"),
+            &messages,
+        )
+        .expect("resolve");
+        assert_eq!(resolved, dir.path());
+    }
+
+    #[test]
+    fn resolve_secrets_dir_accepts_other_spelling_of_recorded_dir() {
+        let messages = test_messages();
+        let dir = tempfile::tempdir().expect("tempdir");
+        let spelled = dir.path().join(".");
+        let resolved = resolve_secrets_dir(
+            Some(&spelled),
+            dir.path(),
+            |_| panic!("matching directories must not prompt"),
+            &messages,
+        )
+        .expect("resolve");
+        assert_eq!(resolved, spelled);
+    }
+
+    #[test]
+    fn resolve_secrets_dir_mismatch_requires_confirmation() {
+        let messages = test_messages();
+        let recorded = tempfile::tempdir().expect("tempdir");
+        let explicit = tempfile::tempdir().expect("tempdir");
+        let asked = RefCell::new(Vec::new());
+
+        let err = resolve_secrets_dir(
+            Some(explicit.path()),
+            recorded.path(),
+            |prompt| {
+                asked.borrow_mut().push(prompt.to_string());
+                anyhow::bail!(messages.error_operation_cancelled())
+            },
+            &messages,
+        )
+        .expect_err("declined mismatch must cancel");
+        assert_eq!(err.to_string(), messages.error_operation_cancelled());
+
+        let resolved = resolve_secrets_dir(
+            Some(explicit.path()),
+            recorded.path(),
+            |prompt| {
+                asked.borrow_mut().push(prompt.to_string());
+                Ok(())
+            },
+            &messages,
+        )
+        .expect("confirmed mismatch");
+        assert_eq!(resolved, explicit.path());
+
+        let asked = asked.into_inner();
+        assert_eq!(asked.len(), 2);
+        let provided = explicit.path().display().to_string();
+        assert!(
+            asked.iter().all(|prompt| prompt.contains(&provided)),
+            "{asked:?}"
+        );
+    }
+}
+
 #[cfg(test)]
 pub(super) mod test_support {
     use std::env;
@@ -271,6 +507,9 @@ pub(super) mod test_support {
     static ENV_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
     pub(super) const TEST_DOCKER_ARGS_ENV: &str = "BOOTROOT_TEST_DOCKER_ARGS";
     pub(super) const TEST_DOCKER_EXIT_ENV: &str = "BOOTROOT_TEST_DOCKER_EXIT";
+    /// Overrides what the fake `docker ps` prints; unset, it reports one
+    /// running `bootroot-<service>` container per compose service filter.
+    pub(super) const TEST_DOCKER_PS_OUTPUT_ENV: &str = "BOOTROOT_TEST_DOCKER_PS_OUTPUT";
 
     pub(super) struct ScopedEnvVar {
         key: &'static str,
@@ -310,6 +549,29 @@ pub(super) mod test_support {
     pub(super) fn write_fake_docker_script(path: &Path) {
         let script = r#"#!/bin/sh
 set -eu
+if [ "$1" = "ps" ]; then
+  if [ -n "${BOOTROOT_TEST_DOCKER_PS_OUTPUT+set}" ]; then
+    printf '%b' "${BOOTROOT_TEST_DOCKER_PS_OUTPUT}"
+  else
+    # Discovery must be scoped to the compose project.
+    case " $* " in
+      *" label=com.docker.compose.project="*) ;;
+      *) exit 0 ;;
+    esac
+    for arg in "$@"; do
+      case "$arg" in
+        label=com.docker.compose.service=*)
+          printf 'bootroot-%s\trunning\n' "${arg#label=com.docker.compose.service=}" ;;
+      esac
+    done
+  fi
+  exit 0
+fi
+# No container carries a compose project label, so the project falls
+# back to the compose directory name.
+case "$*" in
+  "inspect --format {{index .Config.Labels"*) exit 1 ;;
+esac
 printf '%s\n' "$@" > "${BOOTROOT_TEST_DOCKER_ARGS:?missing log path}"
 if [ -n "${BOOTROOT_TEST_DOCKER_STDERR:-}" ]; then
   printf '%s' "${BOOTROOT_TEST_DOCKER_STDERR}" 1>&2
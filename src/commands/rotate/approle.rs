@@ -1,17 +1,24 @@
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use bootroot::fs_util;
 use bootroot::openbao::{OpenBaoClient, SecretIdOptions};
+use bootroot::trust_bootstrap::SERVICE_SECRET_ID_KV_SUFFIX;
+use tokio::sync::Semaphore;
 
-use super::helpers::{confirm_action, restart_container, write_secret_id_atomic};
+use super::helpers::{
+    confirm_action, publish_remote_generations, reload_openbao_agent, run_bounded,
+    write_secret_id_atomic,
+};
 use super::{
-    OPENBAO_AGENT_RESPONDER_CONTAINER, OPENBAO_AGENT_STEPCA_CONTAINER, ROLE_ID_FILENAME,
-    RotateContext,
+    OPENBAO_AGENT_RESPONDER, OPENBAO_AGENT_STEPCA, OpenBaoAgent, ROLE_ID_FILENAME, RotateContext,
 };
 use crate::cli::args::{InfraRoleTarget, RotateAppRoleSecretIdArgs};
 use crate::cli::output::display_secret;
-use crate::commands::constants::{SERVICE_KV_BASE, SERVICE_SECRET_ID_KEY};
+use crate::commands::cancellation;
+use crate::commands::constants::SERVICE_SECRET_ID_KEY;
 use crate::commands::init::{
     APPROLE_BOOTROOT_INFRA_ROTATE, APPROLE_BOOTROOT_RESPONDER, APPROLE_BOOTROOT_STEPCA,
     AppRoleLabel, OPENBAO_AGENT_DIR, OPENBAO_AGENT_RESPONDER_DIR, OPENBAO_AGENT_ROLE_ID_NAME,
@@ -34,6 +41,22 @@ pub(super) struct RotateAuthContext<'a> {
     pub(super) secret_id_file: Option<&'a Path>,
 }
 
+/// How many `--all-services` targets run at once: `targets` overall,
+/// of which at most `local` are locally delivered services.
+#[derive(Clone, Copy)]
+struct FleetConcurrency {
+    targets: NonZeroUsize,
+    local: NonZeroUsize,
+}
+
+impl FleetConcurrency {
+    #[cfg(test)]
+    const SERIAL: Self = Self {
+        targets: NonZeroUsize::MIN,
+        local: NonZeroUsize::MIN,
+    };
+}
+
 /// What the root-token provisioning run does with the recorded CIDR
 /// binding for the infra-rotate credential.
 #[derive(Clone, Copy)]
@@ -95,7 +118,12 @@ pub(super) async fn rotate_approle_secret_id(
         .await?;
         AppRoleLabel::InfraRotate
     } else if args.all_services {
-        rotate_all_service_approle_secret_ids(ctx, client, auto_confirm, messages).await?;
+        let concurrency = FleetConcurrency {
+            targets: args.concurrency,
+            local: args.local_concurrency,
+        };
+        rotate_all_service_approle_secret_ids(ctx, client, auto_confirm, concurrency, messages)
+            .await?;
         AppRoleLabel::RuntimeRotate
     } else {
         let service_name = args.service_name.as_deref().ok_or_else(|| {
@@ -112,6 +140,7 @@ pub(super) async fn rotate_approle_secret_id(
     // itself. Re-minting the rotate credentials under root auth is the
     // break-glass recovery procedure, not this step.
     if let RuntimeAuthResolved::AppRole { role_id, .. } = auth.runtime_auth {
+        cancellation::global().check(messages)?;
         self_mint_own_secret_id(
             ctx,
             client,
@@ -220,7 +249,7 @@ struct ServiceRotationReport {
 }
 
 async fn rotate_service_approle_secret_id(
-    ctx: &RotateContext,
+    ctx: &mut RotateContext,
     client: &OpenBaoClient,
     service_name: &str,
     auto_confirm: bool,
@@ -233,6 +262,7 @@ async fn rotate_service_approle_secret_id(
     )?;
 
     let report = rotate_service_secret_id_once(ctx, client, service_name, messages).await?;
+    publish_remote_generations(ctx, client, [service_name], messages).await?;
 
     println!("{}", messages.rotate_summary_title());
     // CodeQL flags this as cleartext-logging, but the second argument is
@@ -253,9 +283,10 @@ async fn rotate_service_approle_secret_id(
 /// collected, reported per target, and turned into a single non-zero
 /// exit at the end.
 async fn rotate_all_service_approle_secret_ids(
-    ctx: &RotateContext,
+    ctx: &mut RotateContext,
     client: &OpenBaoClient,
     auto_confirm: bool,
+    concurrency: FleetConcurrency,
     messages: &Messages,
 ) -> Result<()> {
     let service_names: Vec<String> = ctx.state.services.keys().cloned().collect();
@@ -269,11 +300,11 @@ async fn rotate_all_service_approle_secret_ids(
         messages,
     )?;
 
-    let mut outcomes = Vec::with_capacity(service_names.len());
-    for service_name in &service_names {
-        let outcome = rotate_service_secret_id_once(ctx, client, service_name, messages).await;
-        outcomes.push((service_name.as_str(), outcome));
-    }
+    let outcomes: Vec<_> = service_names
+        .iter()
+        .map(String::as_str)
+        .zip(rotate_service_secret_ids(ctx, client, concurrency, messages).await)
+        .collect();
 
     println!("{}", messages.rotate_summary_title());
     let mut failed_names = Vec::new();
@@ -293,6 +324,11 @@ async fn rotate_all_service_approle_secret_ids(
             }
         }
     }
+    let rotated = outcomes
+        .iter()
+        .filter(|(_, outcome)| outcome.is_ok())
+        .map(|(service_name, _)| *service_name);
+    publish_remote_generations(ctx, client, rotated, messages).await?;
     let total = outcomes.len();
     let failed = failed_names.len();
     println!(
@@ -309,6 +345,45 @@ async fn rotate_all_service_approle_secret_ids(
     Ok(())
 }
 
+/// Rotates every registered service with at most `concurrency.targets`
+/// in flight, returning one result per service in registry order.
+async fn rotate_service_secret_ids(
+    ctx: &RotateContext,
+    client: &OpenBaoClient,
+    concurrency: FleetConcurrency,
+    messages: &Messages,
+) -> Vec<Result<ServiceRotationReport>> {
+    let local_lane = Arc::new(Semaphore::new(concurrency.local.get()));
+    let secrets_dir = ctx.state.secrets_dir().to_path_buf();
+    run_bounded(
+        ctx.state.services.values().cloned().collect(),
+        concurrency.targets,
+        |entry: &ServiceEntry| {
+            (!matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap))
+                .then(|| Arc::clone(&local_lane))
+        },
+        |entry| {
+            let client = client.clone();
+            let secrets_dir = secrets_dir.clone();
+            let kv_mount = ctx.kv_mount.clone();
+            let service_kv_base = ctx.state.service_kv_base();
+            let messages = messages.clone();
+            async move {
+                rotate_service_entry_secret_id(
+                    &client,
+                    &entry,
+                    &secrets_dir,
+                    &kv_mount,
+                    &service_kv_base,
+                    &messages,
+                )
+                .await
+            }
+        },
+    )
+    .await
+}
+
 async fn rotate_service_secret_id_once(
     ctx: &RotateContext,
     client: &OpenBaoClient,
@@ -319,11 +394,33 @@ async fn rotate_service_secret_id_once(
         .state
         .services
         .get(service_name)
-        .ok_or_else(|| anyhow::anyhow!(messages.error_service_not_found(service_name)))?
-        .clone();
+        .ok_or_else(|| anyhow::anyhow!(messages.error_service_not_found(service_name)))?;
+    rotate_service_entry_secret_id(
+        client,
+        entry,
+        ctx.state.secrets_dir(),
+        &ctx.kv_mount,
+        &ctx.state.service_kv_base(),
+        messages,
+    )
+    .await
+}
+
+async fn rotate_service_entry_secret_id(
+    client: &OpenBaoClient,
+    entry: &ServiceEntry,
+    secrets_dir: &Path,
+    kv_mount: &str,
+    service_kv_base: &str,
+    messages: &Messages,
+) -> Result<ServiceRotationReport> {
+    // Checked per target so a batch stops between services; the ones
+    // already rotated still get their remote generations published.
+    cancellation::global().check(messages)?;
+    let service_name = entry.service_name.as_str();
     let is_remote = matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap);
     if !is_remote {
-        ensure_role_id_file(&entry, ctx.state.secrets_dir(), client, messages).await?;
+        ensure_role_id_file(entry, secrets_dir, client, messages).await?;
     }
     let secret_id_options = SecretIdOptions {
         ttl: entry.approle.secret_id_ttl.clone(),
@@ -352,7 +449,7 @@ async fn rotate_service_secret_id_once(
         write_service_secret_id_file(
             &entry.approle.secret_id_path,
             &new_secret_id,
-            ctx.state.secrets_dir(),
+            secrets_dir,
             messages,
         )
         .await?;
@@ -367,8 +464,8 @@ async fn rotate_service_secret_id_once(
     if is_remote {
         write_remote_service_secret_id(
             client,
-            &ctx.kv_mount,
-            service_name,
+            kv_mount,
+            &format!("{service_kv_base}/{service_name}/{SERVICE_SECRET_ID_KV_SUFFIX}"),
             &new_secret_id,
             messages,
         )
@@ -398,10 +495,10 @@ fn infra_agent_dir(ctx: &RotateContext, target: InfraRoleTarget) -> PathBuf {
         .join(agent_dir)
 }
 
-fn infra_agent_container(target: InfraRoleTarget) -> &'static str {
+fn infra_agent(target: InfraRoleTarget) -> OpenBaoAgent {
     match target {
-        InfraRoleTarget::Stepca => OPENBAO_AGENT_STEPCA_CONTAINER,
-        InfraRoleTarget::Responder => OPENBAO_AGENT_RESPONDER_CONTAINER,
+        InfraRoleTarget::Stepca => OPENBAO_AGENT_STEPCA,
+        InfraRoleTarget::Responder => OPENBAO_AGENT_RESPONDER,
     }
 }
 
@@ -439,6 +536,7 @@ async fn rotate_infra_approle_secret_id(
     let secret_id_path = agent_dir.join(OPENBAO_AGENT_SECRET_ID_NAME);
     let role_id = ensure_infra_role_id_file(&agent_dir, role_name, client, messages).await?;
 
+    cancellation::global().check(messages)?;
     let secret_id_options = SecretIdOptions {
         ttl: None,
         num_uses: Some(0),
@@ -450,8 +548,10 @@ async fn rotate_infra_approle_secret_id(
         .await
         .with_context(|| messages.error_infra_secret_id_mint_failed(role_name))?;
     write_secret_id_atomic(&secret_id_path, &new_secret_id, messages).await?;
-    let container = infra_agent_container(target);
-    restart_container(container, messages)?;
+    // A missing agent is not fatal here: the new secret_id is already on
+    // disk and the login below proves it works; the warning names the
+    // manual reload.
+    let restarted = reload_openbao_agent(&ctx.compose_file, infra_agent(target), messages)?;
     // The infra roles carry no CIDR binding, so the post-rotation login
     // verification is unconditional (unlike the service flow).
     client
@@ -468,10 +568,12 @@ async fn rotate_infra_approle_secret_id(
             &secret_id_path.display().to_string()
         )
     );
-    println!(
-        "{}",
-        messages.rotate_summary_infra_agent_restarted(container)
-    );
+    for container in &restarted {
+        println!(
+            "{}",
+            messages.rotate_summary_infra_agent_restarted(container)
+        );
+    }
     println!(
         "{}",
         messages.rotate_summary_infra_approle_login_ok(role_name)
@@ -550,6 +652,7 @@ async fn provision_infra_rotate_role(
             TOKEN_TTL,
             secret_id_ttl,
             true,
+            &[],
         )
         .await
         .with_context(|| messages.error_openbao_approle_create_failed())?;
@@ -762,14 +865,14 @@ async fn write_service_secret_id_file(
 async fn write_remote_service_secret_id(
     client: &OpenBaoClient,
     kv_mount: &str,
-    service_name: &str,
+    secret_id_kv_path: &str,
     secret_id: &str,
     messages: &Messages,
 ) -> Result<()> {
     client
         .write_kv(
             kv_mount,
-            &format!("{SERVICE_KV_BASE}/{service_name}/secret_id"),
+            secret_id_kv_path,
             serde_json::json!({ SERVICE_SECRET_ID_KEY: secret_id }),
         )
         .await
@@ -782,6 +885,7 @@ mod tests {
     use std::fs;
     use std::path::PathBuf;
 
+    use bootroot::trust_bootstrap::SERVICE_KV_BASE;
     use tempfile::tempdir;
     use wiremock::matchers::{method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -819,6 +923,7 @@ mod tests {
             paths: super::super::StatePaths::new(dir.join("secrets")),
             state_dir: dir.to_path_buf(),
             state_file: dir.join("state.json"),
+            restart_confirmation: None,
         }
     }
 
@@ -852,11 +957,14 @@ mod tests {
                 // endpoint mocks match.
                 secret_id_wrap_ttl: Some("0".to_string()),
                 token_bound_cidrs: None,
+                approle_bound_cidrs: None,
             },
             agent_email: None,
             agent_server: None,
             agent_responder_url: None,
             cert_group_gid: None,
+            sync_generation: None,
+            trust_generation: None,
         }
     }
 
@@ -960,7 +1068,7 @@ mod tests {
         }
         let logged = fs::read_to_string(&args_log).expect("read docker args");
         let args: Vec<&str> = logged.lines().collect();
-        assert_eq!(args, vec!["restart", OPENBAO_AGENT_STEPCA_CONTAINER]);
+        assert_eq!(args, vec!["restart", OPENBAO_AGENT_STEPCA.container]);
     }
 
     // The env-var lock must be held across the `.await` to prevent
@@ -1019,7 +1127,7 @@ mod tests {
         assert_eq!(role_id, "responder-role-id");
         let logged = fs::read_to_string(&args_log).expect("read docker args");
         let args: Vec<&str> = logged.lines().collect();
-        assert_eq!(args, vec!["restart", OPENBAO_AGENT_RESPONDER_CONTAINER]);
+        assert_eq!(args, vec!["restart", OPENBAO_AGENT_RESPONDER.container]);
     }
 
     // The env-var lock must be held across the `.await` to prevent
@@ -1414,15 +1522,21 @@ mod tests {
     #[tokio::test]
     async fn rotate_all_services_empty_registry_is_noop_success() {
         let dir = tempdir().expect("tempdir");
-        let ctx = make_ctx(dir.path());
+        let mut ctx = make_ctx(dir.path());
         // No OpenBao requests may happen; an unroutable URL makes any
         // accidental call fail loudly.
         let mut client = OpenBaoClient::new("http://127.0.0.1:1").expect("client");
         client.set_token("scoped-token".to_string());
         let messages = test_messages();
-        rotate_all_service_approle_secret_ids(&ctx, &client, true, &messages)
-            .await
-            .expect("an empty service registry must be a no-op success");
+        rotate_all_service_approle_secret_ids(
+            &mut ctx,
+            &client,
+            true,
+            FleetConcurrency::SERIAL,
+            &messages,
+        )
+        .await
+        .expect("an empty service registry must be a no-op success");
     }
 
     // The env-var lock must be held across the `.await` to prevent
@@ -1457,6 +1571,14 @@ mod tests {
             .expect(1)
             .mount(&server)
             .await;
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/v1/secret/data/{SERVICE_KV_BASE}/beta/generation"
+            )))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
 
         let mut ctx = make_ctx(dir.path());
         insert_local_service(&mut ctx, dir.path(), "alpha");
@@ -1468,9 +1590,15 @@ mod tests {
         let mut client = OpenBaoClient::new(&server.uri()).expect("client");
         client.set_token("scoped-token".to_string());
         let messages = test_messages();
-        rotate_all_service_approle_secret_ids(&ctx, &client, true, &messages)
-            .await
-            .expect("batch rotation should succeed");
+        rotate_all_service_approle_secret_ids(
+            &mut ctx,
+            &client,
+            true,
+            FleetConcurrency::SERIAL,
+            &messages,
+        )
+        .await
+        .expect("batch rotation should succeed");
 
         let local_secret = fs::read_to_string(dir.path().join("alpha").join("secret_id"))
             .expect("local secret_id written");
@@ -1479,6 +1607,13 @@ mod tests {
             !dir.path().join("beta").join("secret_id").exists(),
             "remote-bootstrap targets must not get a local secret_id file"
         );
+        assert_eq!(
+            ctx.state
+                .services
+                .get("beta")
+                .and_then(|entry| entry.sync_generation),
+            Some(1)
+        );
         assert!(
             !args_log.exists(),
             "service secret_id rotation must not invoke docker: the local \
@@ -1525,9 +1660,15 @@ mod tests {
         let mut client = OpenBaoClient::new(&server.uri()).expect("client");
         client.set_token("scoped-token".to_string());
         let messages = test_messages();
-        let err = rotate_all_service_approle_secret_ids(&ctx, &client, true, &messages)
-            .await
-            .expect_err("a partial failure must produce a non-zero exit");
+        let err = rotate_all_service_approle_secret_ids(
+            &mut ctx,
+            &client,
+            true,
+            FleetConcurrency::SERIAL,
+            &messages,
+        )
+        .await
+        .expect_err("a partial failure must produce a non-zero exit");
 
         let msg = format!("{err:#}");
         assert!(
@@ -1552,6 +1693,65 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn rotate_all_services_with_concurrency_rotates_every_target() {
+        let dir = tempdir().expect("tempdir");
+        let server = MockServer::start().await;
+        let mut ctx = make_ctx(dir.path());
+        let names: Vec<String> = (0..8).map(|index| format!("svc{index}")).collect();
+        for (index, name) in names.iter().enumerate() {
+            mount_secret_id_mock(&service_role_name(name))
+                .expect(1)
+                .mount(&server)
+                .await;
+            if index % 2 == 0 {
+                insert_local_service(&mut ctx, dir.path(), name);
+            } else {
+                ctx.state.services.insert(
+                    name.clone(),
+                    make_service_entry(dir.path(), name, DeliveryMode::RemoteBootstrap),
+                );
+                Mock::given(method("POST"))
+                    .and(path(format!(
+                        "/v1/secret/data/{SERVICE_KV_BASE}/{name}/secret_id"
+                    )))
+                    .respond_with(ResponseTemplate::new(204))
+                    .expect(1)
+                    .mount(&server)
+                    .await;
+            }
+        }
+        mount_login_mock().expect(8).mount(&server).await;
+
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("scoped-token".to_string());
+        let messages = test_messages();
+        let concurrency = FleetConcurrency {
+            targets: NonZeroUsize::new(4).expect("non-zero"),
+            local: NonZeroUsize::MIN,
+        };
+        let outcomes = rotate_service_secret_ids(&ctx, &client, concurrency, &messages).await;
+
+        assert_eq!(outcomes.len(), names.len());
+        for (name, outcome) in names.iter().zip(&outcomes) {
+            let report = outcome.as_ref().expect("every target rotates");
+            assert_eq!(
+                report.secret_id_path,
+                dir.path()
+                    .join(name)
+                    .join("secret_id")
+                    .display()
+                    .to_string()
+            );
+        }
+        for name in names.iter().step_by(2) {
+            let secret = fs::read_to_string(dir.path().join(name).join("secret_id"))
+                .expect("local secret_id written");
+            assert_eq!(secret, "fresh-secret-id");
+        }
+        server.verify().await;
+    }
+
     fn approle_args(
         service_name: Option<&str>,
         all_services: bool,
@@ -1560,6 +1760,8 @@ mod tests {
         RotateAppRoleSecretIdArgs {
             service_name: service_name.map(str::to_string),
             all_services,
+            concurrency: NonZeroUsize::MIN,
+            local_concurrency: NonZeroUsize::MIN,
             infra,
             rotate_bound_cidrs: Vec::new(),
             clear_rotate_bound_cidrs: false,
@@ -2368,6 +2570,8 @@ mod tests {
                 "token_max_ttl": TOKEN_TTL,
                 "token_renewable": true,
                 "secret_id_ttl": "48h",
+                "secret_id_bound_cidrs": [],
+                "token_bound_cidrs": [],
             })))
             .respond_with(ResponseTemplate::new(204))
             .expect(1)
@@ -2442,7 +2646,7 @@ mod tests {
         let mut client = OpenBaoClient::new(&server.uri()).expect("client");
         client.set_token("infra-rotate-token".to_string());
         let messages = test_messages();
-        let err = rotate_service_approle_secret_id(&ctx, &client, "alpha", true, &messages)
+        let err = rotate_service_approle_secret_id(&mut ctx, &client, "alpha", true, &messages)
             .await
             .expect_err("permission denied must fail the rotation");
 
@@ -1,26 +1,31 @@
 use std::fs;
+use std::num::NonZeroUsize;
 use std::os::unix::fs::MetadataExt;
 use std::path::Path;
+use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use bootroot::openbao::OpenBaoClient;
 
 use super::helpers::{
-    confirm_action, ensure_file_exists, restart_compose_service, signal_bootroot_agent,
-    try_restart_container,
+    confirm_action, ensure_file_exists, publish_all_remote_generations, publish_remote_generations,
+    restart_compose_service, run_bounded, signal_bootroot_agent, try_restart_container,
 };
 use super::{
     INTERMEDIATE_CA_COMMON_NAME, OPENBAO_AGENT_RESPONDER_CONTAINER, OPENBAO_AGENT_STEPCA_CONTAINER,
-    ROOT_CA_COMMON_NAME, RotateContext, RotateOutcome, STEP_CA_HELPER_IMAGE,
+    ROOT_CA_COMMON_NAME, RotateContext, RotateOutcome,
 };
-use crate::cli::args::{RotateCaKeyArgs, RotateForceReissueArgs, RotateSkipPhase};
+use crate::cli::args::{
+    RotateCaKeyArgs, RotateForceReissueArgs, RotateSkipPhase, RotateTrustSyncArgs,
+};
+use crate::commands::cancellation;
 use crate::commands::infra::run_docker;
 use crate::commands::init::{
     compute_ca_bundle_pem, compute_ca_fingerprints, read_ca_cert_fingerprint,
 };
 use crate::commands::trust::{
-    self, RotationMode, RotationState, create_rotation_state, delete_rotation_state,
-    load_rotation_state, update_rotation_state,
+    self, RotationMode, RotationState, SERVICE_TRUST_KV_SUFFIX, create_rotation_state,
+    delete_rotation_state, load_rotation_state, update_rotation_state,
 };
 use crate::i18n::Messages;
 use crate::state::{DeliveryMode, ServiceEntry};
@@ -115,12 +120,12 @@ pub(super) async fn rotate_ca_key(
     // cannot even read, which would fail the host-side Phase 1 backup
     // below and, on resume, the later key reads. The sweep repairs that in
     // place and is a no-op when ownership is already correct. It reuses the
-    // `smallstep/step-ca:0.30.2` image the `step` helpers already run, so it
-    // adds no new dependency. Runs unconditionally (not gated on `start_phase`)
-    // so a resumed rotation converges too.
+    // step-ca image recorded at init, which the `step` helpers already run,
+    // so it adds no new dependency. Runs unconditionally (not gated on
+    // `start_phase`) so a resumed rotation converges too.
     crate::commands::infra::sweep_secrets_ownership(
         ctx.paths.secrets_dir(),
-        STEP_CA_HELPER_IMAGE,
+        ctx.state.stepca_image(),
         messages,
     )?;
 
@@ -155,6 +160,8 @@ pub(super) async fn rotate_ca_key(
         println!("{}", messages.rotate_ca_key_phase_skipped("1"));
     }
 
+    cancellation::global().check(messages)?;
+
     // Phase 2 — Generate new key pair(s)
     if start_phase < 2 {
         if rot_state.mode == RotationMode::Full {
@@ -187,6 +194,8 @@ pub(super) async fn rotate_ca_key(
         println!("{}", messages.rotate_ca_key_phase_skipped("2"));
     }
 
+    cancellation::global().check(messages)?;
+
     // Phase 3 — Distribute transitional trust (additive)
     if start_phase < 3 {
         println!("{}", messages.rotate_ca_key_phase_trust_additive());
@@ -221,12 +230,13 @@ pub(super) async fn rotate_ca_key(
         trust::write_trust_to_openbao(
             client,
             &ctx.kv_mount,
-            &ctx.state.services,
+            &ctx.state,
             &transitional_fps,
             &ca_bundle_pem,
             messages,
         )
         .await?;
+        publish_all_remote_generations(ctx, client, messages).await?;
 
         restart_infra_openbao_agents();
 
@@ -236,10 +246,19 @@ pub(super) async fn rotate_ca_key(
         println!("{}", messages.rotate_ca_key_phase_skipped("3"));
     }
 
+    cancellation::global().check(messages)?;
+
     // Phase 4 — Restart step-ca
     if start_phase < 4 {
         println!("{}", messages.rotate_ca_key_phase_restart_stepca());
-        restart_compose_service(&ctx.compose_file, "step-ca", messages)?;
+        restart_compose_service(
+            &ctx.compose_file,
+            &ctx.state.compose_profiles,
+            "step-ca",
+            ctx.restart_confirmation,
+            messages,
+        )
+        .await?;
 
         rot_state.phase = 4;
         update_rotation_state(&ctx.state_dir, &rot_state, messages)?;
@@ -247,6 +266,8 @@ pub(super) async fn rotate_ca_key(
         println!("{}", messages.rotate_ca_key_phase_skipped("4"));
     }
 
+    cancellation::global().check(messages)?;
+
     // Phase 5 — Re-issue service certificates
     if start_phase < 5 && !args.skip.contains(&RotateSkipPhase::Reissue) {
         println!("{}", messages.rotate_ca_key_phase_reissue());
@@ -287,6 +308,8 @@ pub(super) async fn rotate_ca_key(
         println!("{}", messages.rotate_ca_key_phase_skipped("5"));
     }
 
+    cancellation::global().check(messages)?;
+
     // Phase 6 — Finalize trust (subtractive)
     if start_phase < 6 && !args.skip.contains(&RotateSkipPhase::Finalize) {
         println!("{}", messages.rotate_ca_key_phase_finalize());
@@ -331,12 +354,13 @@ pub(super) async fn rotate_ca_key(
         trust::write_trust_to_openbao(
             client,
             &ctx.kv_mount,
-            &ctx.state.services,
+            &ctx.state,
             &final_fps,
             &ca_bundle_pem,
             messages,
         )
         .await?;
+        publish_all_remote_generations(ctx, client, messages).await?;
 
         // Without a restart the infra OpenBao Agents keep serving the
         // Phase-3 transitional pin list (which still includes the
@@ -350,6 +374,8 @@ pub(super) async fn rotate_ca_key(
         println!("{}", messages.rotate_ca_key_phase_skipped("6"));
     }
 
+    cancellation::global().check(messages)?;
+
     // Phase 7 — Cleanup
     println!("{}", messages.rotate_ca_key_phase_cleanup());
     if args.cleanup {
@@ -428,7 +454,7 @@ fn owner_user_arg(secrets_dir: &Path, messages: &Messages) -> Result<String> {
 /// Builds the `docker run` argv that regenerates the root CA as the
 /// secrets-directory owner. Kept pure so tests can assert it carries
 /// `--user <uid>:<gid>` rather than `--user root`.
-fn generate_root_docker_args(mount: &str, user_arg: &str) -> Vec<String> {
+fn generate_root_docker_args(mount: &str, user_arg: &str, image: &str) -> Vec<String> {
     [
         "run",
         "--user",
@@ -436,7 +462,7 @@ fn generate_root_docker_args(mount: &str, user_arg: &str) -> Vec<String> {
         "--rm",
         "-v",
         mount,
-        "smallstep/step-ca:0.30.2",
+        image,
         "step",
         "certificate",
         "create",
@@ -457,7 +483,7 @@ fn generate_root_docker_args(mount: &str, user_arg: &str) -> Vec<String> {
 /// Builds the `docker run` argv that regenerates the intermediate CA as
 /// the secrets-directory owner. Kept pure so tests can assert it carries
 /// `--user <uid>:<gid>` rather than `--user root`.
-fn generate_intermediate_docker_args(mount: &str, user_arg: &str) -> Vec<String> {
+fn generate_intermediate_docker_args(mount: &str, user_arg: &str, image: &str) -> Vec<String> {
     [
         "run",
         "--user",
@@ -465,7 +491,7 @@ fn generate_intermediate_docker_args(mount: &str, user_arg: &str) -> Vec<String>
         "--rm",
         "-v",
         mount,
-        "smallstep/step-ca:0.30.2",
+        image,
         "step",
         "certificate",
         "create",
@@ -495,7 +521,7 @@ fn generate_new_root(ctx: &RotateContext, messages: &Messages) -> Result<()> {
         .with_context(|| messages.error_resolve_path_failed(&secrets_dir.display().to_string()))?;
     let mount = format!("{}:/home/step", mount_root.display());
     let user_arg = owner_user_arg(secrets_dir, messages)?;
-    let args = generate_root_docker_args(&mount, &user_arg);
+    let args = generate_root_docker_args(&mount, &user_arg, ctx.state.stepca_image());
     let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
     run_docker(&arg_refs, "docker step certificate create (root)", messages)?;
     Ok(())
@@ -507,7 +533,7 @@ fn generate_new_intermediate(ctx: &RotateContext, messages: &Messages) -> Result
         .with_context(|| messages.error_resolve_path_failed(&secrets_dir.display().to_string()))?;
     let mount = format!("{}:/home/step", mount_root.display());
     let user_arg = owner_user_arg(secrets_dir, messages)?;
-    let args = generate_intermediate_docker_args(&mount, &user_arg);
+    let args = generate_intermediate_docker_args(&mount, &user_arg, ctx.state.stepca_image());
     let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
     run_docker(&arg_refs, "docker step certificate create", messages)?;
     Ok(())
@@ -615,6 +641,7 @@ fn cert_issued_by_new_intermediate(
 pub(super) async fn rotate_trust_sync(
     ctx: &mut RotateContext,
     client: &OpenBaoClient,
+    args: &RotateTrustSyncArgs,
     auto_confirm: bool,
     messages: &Messages,
 ) -> Result<()> {
@@ -629,30 +656,159 @@ pub(super) async fn rotate_trust_sync(
     let fingerprints = compute_ca_fingerprints(secrets_dir, messages).await?;
     let ca_bundle_pem = compute_ca_bundle_pem(secrets_dir, messages).await?;
 
-    crate::commands::trust::write_trust_to_openbao(
+    let trust_generation = crate::commands::trust::write_global_trust(
         client,
         &ctx.kv_mount,
-        &ctx.state.services,
         &fingerprints,
         &ca_bundle_pem,
         messages,
     )
     .await?;
+    // A service whose trust path already holds this generation has
+    // nothing new to receive; skipping it keeps a re-run after an
+    // unchanged bundle from bumping its remote sync generation again.
+    let mut service_names = Vec::new();
+    let mut current_names = Vec::new();
+    for (name, entry) in &ctx.state.services {
+        if args.force || entry.trust_generation != Some(trust_generation) {
+            service_names.push(name.clone());
+        } else {
+            current_names.push(name.clone());
+        }
+    }
+    let trust_paths: Vec<String> = service_names
+        .iter()
+        .map(|name| ctx.state.service_kv_path(name, SERVICE_TRUST_KV_SUFFIX))
+        .collect();
+    let outcomes = sync_service_trust(
+        client,
+        &ctx.kv_mount,
+        trust_paths,
+        &fingerprints,
+        &ca_bundle_pem,
+        args.concurrency,
+        messages,
+    )
+    .await;
 
     println!("{}", messages.rotate_summary_title());
     println!(
         "{}",
         messages.rotate_summary_trust_sync_global(&fingerprints.join(", "))
     );
-    for entry in ctx.state.services.values() {
+    println!(
+        "{}",
+        messages.rotate_summary_trust_sync_generation(&trust_generation.to_string())
+    );
+    for service_name in &current_names {
+        println!(
+            "{}",
+            messages.rotate_summary_trust_sync_current(service_name)
+        );
+    }
+    let mut failed_names = Vec::new();
+    for (service_name, outcome) in service_names.iter().zip(&outcomes) {
+        match outcome {
+            Ok(()) => println!(
+                "{}",
+                messages.rotate_summary_trust_sync_service(service_name)
+            ),
+            Err(error) => {
+                println!(
+                    "{}",
+                    messages.rotate_all_target_failed(service_name, &format!("{error:#}"))
+                );
+                failed_names.push(service_name.as_str());
+            }
+        }
+    }
+    let synced = service_names
+        .iter()
+        .zip(&outcomes)
+        .filter(|(_, outcome)| outcome.is_ok())
+        .map(|(service_name, _)| service_name.as_str())
+        .collect::<Vec<_>>();
+    record_trust_generation(ctx, &synced, trust_generation, messages)?;
+    publish_remote_generations(ctx, client, synced, messages).await?;
+    if !failed_names.is_empty() {
+        let total = outcomes.len();
+        let failed = failed_names.len();
         println!(
             "{}",
-            messages.rotate_summary_trust_sync_service(&entry.service_name)
+            messages.rotate_all_result(total - failed, failed, total)
         );
+        anyhow::bail!(messages.error_rotate_all_partial_failure(
+            failed,
+            total,
+            &failed_names.join(", ")
+        ));
     }
     Ok(())
 }
 
+/// Records `trust_generation` for every service whose trust path was
+/// just rewritten, so the next `trust-sync` can skip it.
+fn record_trust_generation(
+    ctx: &mut RotateContext,
+    service_names: &[&str],
+    trust_generation: u64,
+    messages: &Messages,
+) -> Result<()> {
+    let mut updated = false;
+    for service_name in service_names {
+        if let Some(entry) = ctx.state.services.get_mut(*service_name) {
+            entry.trust_generation = Some(trust_generation);
+            updated = true;
+        }
+    }
+    if updated {
+        ctx.state
+            .save(&ctx.state_file)
+            .with_context(|| messages.error_serialize_state_failed())?;
+    }
+    Ok(())
+}
+
+/// Writes the trust payload to every service trust path with at most
+/// `concurrency` writes in flight, returning one result per path in
+/// input order.
+async fn sync_service_trust(
+    client: &OpenBaoClient,
+    kv_mount: &str,
+    trust_paths: Vec<String>,
+    fingerprints: &[String],
+    ca_bundle_pem: &str,
+    concurrency: NonZeroUsize,
+    messages: &Messages,
+) -> Vec<Result<()>> {
+    let fingerprints: Arc<[String]> = fingerprints.into();
+    let ca_bundle_pem: Arc<str> = ca_bundle_pem.into();
+    run_bounded(
+        trust_paths,
+        concurrency,
+        |_| None,
+        |trust_path| {
+            let client = client.clone();
+            let kv_mount = kv_mount.to_string();
+            let fingerprints = Arc::clone(&fingerprints);
+            let ca_bundle_pem = Arc::clone(&ca_bundle_pem);
+            let messages = messages.clone();
+            async move {
+                crate::commands::trust::write_service_trust(
+                    &client,
+                    &kv_mount,
+                    &trust_path,
+                    &fingerprints,
+                    &ca_bundle_pem,
+                    &messages,
+                )
+                .await
+            }
+        },
+    )
+    .await
+}
+
 pub(super) async fn rotate_force_reissue(
     ctx: &mut RotateContext,
     client: &OpenBaoClient,
@@ -721,7 +877,7 @@ async fn rotate_force_reissue_local(
         .with_context(|| format!("invalid --wait-timeout value: {}", args.wait_timeout))?;
     let started_at = time::OffsetDateTime::now_utc();
 
-    match wait_for_local_completion(cert_path, before.as_ref(), wait_timeout).await {
+    match wait_for_local_completion(cert_path, before.as_ref(), wait_timeout, messages).await {
         Ok(_after) => {
             let completed_at = time::OffsetDateTime::now_utc()
                 .format(&time::format_description::well_known::Rfc3339)
@@ -761,7 +917,7 @@ async fn rotate_force_reissue_remote(
     messages: &Messages,
 ) -> Result<RotateOutcome> {
     use bootroot::trust_bootstrap::{
-        REISSUE_REQUESTED_AT_KEY, REISSUE_REQUESTER_KEY, SERVICE_KV_BASE, SERVICE_REISSUE_KV_SUFFIX,
+        REISSUE_REQUESTED_AT_KEY, REISSUE_REQUESTER_KEY, SERVICE_REISSUE_KV_SUFFIX,
     };
     use time::OffsetDateTime;
     use time::format_description::well_known::Rfc3339;
@@ -780,10 +936,9 @@ async fn rotate_force_reissue_remote(
         .format(&Rfc3339)
         .context("Failed to format requested_at timestamp")?;
 
-    let kv_path = format!(
-        "{SERVICE_KV_BASE}/{}/{SERVICE_REISSUE_KV_SUFFIX}",
-        args.service_name
-    );
+    let kv_path = ctx
+        .state
+        .service_kv_path(&args.service_name, SERVICE_REISSUE_KV_SUFFIX);
 
     // Capture the version assigned by *this* POST directly from the
     // response body. A follow-up GET would race with the agent's own
@@ -824,6 +979,7 @@ async fn rotate_force_reissue_remote(
         request_version,
         &requested_at,
         wait_timeout,
+        messages,
     )
     .await
     {
@@ -904,6 +1060,7 @@ async fn wait_for_remote_completion(
     request_version: Option<u64>,
     requested_at: &str,
     wait_timeout: std::time::Duration,
+    messages: &Messages,
 ) -> std::result::Result<WaitOutcome, WaitError> {
     use bootroot::trust_bootstrap::{
         REISSUE_COMPLETED_AT_KEY, REISSUE_COMPLETED_VERSION_KEY, REISSUE_REQUESTED_AT_KEY,
@@ -952,7 +1109,10 @@ async fn wait_for_remote_completion(
         if tokio::time::Instant::now() >= deadline {
             return Err(WaitError::Timeout);
         }
-        tokio::time::sleep(REISSUE_WAIT_POLL_INTERVAL).await;
+        cancellation::global()
+            .sleep(REISSUE_WAIT_POLL_INTERVAL, messages)
+            .await
+            .map_err(WaitError::Other)?;
     }
 }
 
@@ -1000,6 +1160,7 @@ async fn wait_for_local_completion(
     cert_path: &Path,
     before: Option<&CertSignal>,
     wait_timeout: std::time::Duration,
+    messages: &Messages,
 ) -> std::result::Result<CertSignal, WaitError> {
     let deadline = tokio::time::Instant::now() + wait_timeout;
     loop {
@@ -1039,7 +1200,10 @@ async fn wait_for_local_completion(
         if tokio::time::Instant::now() >= deadline {
             return Err(WaitError::Timeout);
         }
-        tokio::time::sleep(REISSUE_WAIT_POLL_INTERVAL).await;
+        cancellation::global()
+            .sleep(REISSUE_WAIT_POLL_INTERVAL, messages)
+            .await
+            .map_err(WaitError::Other)?;
     }
 }
 
@@ -1304,6 +1468,7 @@ mod tests {
             &cert_path,
             before.as_ref(),
             std::time::Duration::from_secs(5),
+            &test_messages(),
         )
         .await;
         let signal = result.expect("wait should succeed when serial already changed");
@@ -1322,6 +1487,7 @@ mod tests {
             &cert_path,
             before.as_ref(),
             std::time::Duration::from_secs(5),
+            &test_messages(),
         )
         .await;
         let signal = result.expect("wait should succeed once a cert appears");
@@ -1339,6 +1505,7 @@ mod tests {
             &cert_path,
             before.as_ref(),
             std::time::Duration::from_millis(50),
+            &test_messages(),
         )
         .await;
         assert!(matches!(result, Err(WaitError::Timeout)));
@@ -1372,6 +1539,7 @@ mod tests {
             &cert_path,
             before.as_ref(),
             std::time::Duration::from_secs(5),
+            &test_messages(),
         )
         .await;
         let signal = result.expect("mtime tiebreaker should succeed");
@@ -1402,11 +1570,14 @@ mod tests {
                 secret_id_ttl: None,
                 secret_id_wrap_ttl: None,
                 token_bound_cidrs: None,
+                approle_bound_cidrs: None,
             },
             agent_email: None,
             agent_server: None,
             agent_responder_url: None,
             cert_group_gid: None,
+            sync_generation: None,
+            trust_generation: None,
         }
     }
 
@@ -1435,7 +1606,11 @@ mod tests {
     /// `root` — otherwise the regenerated key would land root-owned.
     #[test]
     fn generate_root_docker_args_run_as_owner_not_root() {
-        let args = generate_root_docker_args("/host/secrets:/home/step", "1000:1000");
+        let args = generate_root_docker_args(
+            "/host/secrets:/home/step",
+            "1000:1000",
+            "registry.internal/smallstep/step-ca:0.30.2",
+        );
         let user_pos = args
             .iter()
             .position(|a| a == "--user")
@@ -1445,14 +1620,21 @@ mod tests {
             Some("1000:1000")
         );
         assert!(!args.iter().any(|a| a == "root"));
-        assert!(args.iter().any(|a| a == "smallstep/step-ca:0.30.2"));
+        assert!(
+            args.iter()
+                .any(|a| a == "registry.internal/smallstep/step-ca:0.30.2")
+        );
     }
 
     /// The intermediate-CA regeneration container must likewise run as the
     /// secrets-directory owner rather than `root`.
     #[test]
     fn generate_intermediate_docker_args_run_as_owner_not_root() {
-        let args = generate_intermediate_docker_args("/host/secrets:/home/step", "1000:1000");
+        let args = generate_intermediate_docker_args(
+            "/host/secrets:/home/step",
+            "1000:1000",
+            "registry.internal/smallstep/step-ca:0.30.2",
+        );
         let user_pos = args
             .iter()
             .position(|a| a == "--user")
@@ -1462,7 +1644,10 @@ mod tests {
             Some("1000:1000")
         );
         assert!(!args.iter().any(|a| a == "root"));
-        assert!(args.iter().any(|a| a == "smallstep/step-ca:0.30.2"));
+        assert!(
+            args.iter()
+                .any(|a| a == "registry.internal/smallstep/step-ca:0.30.2")
+        );
     }
 
     /// `owner_user_arg` resolves the `uid:gid` from the secrets directory
@@ -1539,4 +1724,91 @@ mod tests {
         assert_eq!(reissued.len(), 1);
         assert_eq!(reissued[0].service_name, "svc-old");
     }
+
+    #[tokio::test]
+    async fn trust_sync_skips_services_already_at_unchanged_generation() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        use super::super::StatePaths;
+
+        let dir = tempdir().expect("tempdir");
+        let paths = StatePaths::new(dir.path().join("secrets"));
+        fs::create_dir_all(paths.ca_certs_dir()).expect("create certs dir");
+        write_self_signed_cert(&paths.root_cert(), "root.example");
+        write_self_signed_cert(&paths.intermediate_cert(), "intermediate.example");
+        let messages = test_messages();
+        let fingerprints = compute_ca_fingerprints(paths.secrets_dir(), &messages)
+            .await
+            .expect("fingerprints");
+        let ca_bundle_pem = compute_ca_bundle_pem(paths.secrets_dir(), &messages)
+            .await
+            .expect("bundle");
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/bootroot/ca"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "data": crate::commands::trust::global_trust_payload(
+                        &fingerprints,
+                        &ca_bundle_pem,
+                        4,
+                    )
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/data/bootroot/ca"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/data/bootroot/services/edge/trust"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+        Mock::given(path("/v1/secret/data/bootroot/services/edge/generation"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let mut entry = make_local_file_entry("edge", dir.path().join("edge.crt"));
+        entry.delivery_mode = DeliveryMode::RemoteBootstrap;
+        entry.trust_generation = Some(4);
+        entry.sync_generation = Some(7);
+        let mut state = crate::state::StateFile {
+            kv_mount: "secret".to_string(),
+            ..Default::default()
+        };
+        state.services.insert("edge".to_string(), entry);
+        let mut ctx = RotateContext {
+            openbao_url: server.uri(),
+            kv_mount: "secret".to_string(),
+            compose_file: std::path::PathBuf::new(),
+            state,
+            paths,
+            state_dir: dir.path().to_path_buf(),
+            state_file: dir.path().join("state.json"),
+            restart_confirmation: None,
+        };
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+        let args = RotateTrustSyncArgs {
+            concurrency: NonZeroUsize::MIN,
+            force: false,
+        };
+
+        rotate_trust_sync(&mut ctx, &client, &args, true, &messages)
+            .await
+            .expect("trust sync");
+
+        let entry = ctx.state.services.get("edge").expect("edge entry");
+        assert_eq!(entry.trust_generation, Some(4));
+        assert_eq!(entry.sync_generation, Some(7));
+    }
 }
@@ -1,18 +1,20 @@
 use anyhow::{Context, Result};
 use bootroot::openbao::OpenBaoClient;
+use bootroot::trust_bootstrap::SERVICE_EAB_KV_SUFFIX;
 
 use super::RotateContext;
-use super::helpers::confirm_action;
+use super::helpers::{confirm_action, publish_all_remote_generations};
 use crate::commands::init::PATH_AGENT_EAB;
 use crate::i18n::Messages;
+use crate::state::StateFile;
 
 /// Per-service EAB KV path. The init/service flow writes EAB at
-/// `bootroot/services/<svc>/eab` when an operator opts into per-service
-/// EAB; clearing it requires writing the same shape, not a `delete`,
-/// so consumers observing the path see an explicit empty value instead
-/// of a missing secret.
-fn service_eab_path(service_name: &str) -> String {
-    format!("bootroot/services/{service_name}/eab")
+/// `<kv_prefix>/services/<svc>/eab` when an operator opts into
+/// per-service EAB; clearing it requires writing the same shape, not a
+/// `delete`, so consumers observing the path see an explicit empty value
+/// instead of a missing secret.
+fn service_eab_path(state: &StateFile, service_name: &str) -> String {
+    state.service_kv_path(service_name, SERVICE_EAB_KV_SUFFIX)
 }
 
 /// Companion to the now-removed `rotate eab`. Writes empty
@@ -52,16 +54,186 @@ pub(super) async fn rotate_eab_clear(
     // silently.
     let service_names: Vec<String> = ctx.state.services.keys().cloned().collect();
     for service_name in &service_names {
-        let path = service_eab_path(service_name);
+        let path = service_eab_path(&ctx.state, service_name);
         client
             .write_kv(&kv_mount, &path, empty.clone())
             .await
             .with_context(|| messages.error_openbao_kv_write_failed())?;
         println!("Cleared {path}");
     }
+    publish_all_remote_generations(ctx, client, messages).await?;
 
     println!(
         "EAB clear completed; each bootroot-agent applies the cleared value via its fast-poll loop within fast_poll_interval."
     );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::super::StatePaths;
+    use super::super::test_support::test_messages;
+    use super::*;
+    use crate::state::{ServiceEntry, StateFile};
+
+    fn service_entry(name: &str, delivery_mode: &str) -> ServiceEntry {
+        serde_json::from_value(serde_json::json!({
+            "service_name": name,
+            "delivery_mode": delivery_mode,
+            "hostname": "h",
+            "domain": "d.com",
+            "agent_config_path": format!("/srv/{name}/agent.toml"),
+            "cert_path": format!("/srv/{name}/cert.pem"),
+            "key_path": format!("/srv/{name}/key.pem"),
+            "approle": {
+                "role_name": format!("bootroot-service-{name}-role"),
+                "role_id": format!("{name}-role-id"),
+                "secret_id_path": format!("/srv/{name}/secret_id"),
+                "policy_name": "p"
+            }
+        }))
+        .expect("service entry")
+    }
+
+    #[tokio::test]
+    async fn eab_clear_bumps_generation_for_remote_services_only() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/data/bootroot/agent/eab"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        for name in ["edge", "api"] {
+            Mock::given(method("POST"))
+                .and(path(format!(
+                    "/v1/secret/data/bootroot/services/{name}/eab"
+                )))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/bootroot/services/api/generation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": { "generation": 4, "updated_at": "2026-01-01T00:00:00Z" } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/data/bootroot/services/api/generation"))
+            .and(body_partial_json(
+                serde_json::json!({ "data": { "generation": 5 } }),
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // Local-file services have no edge poller, so no generation.
+        Mock::given(path("/v1/secret/data/bootroot/services/edge/generation"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let mut state = StateFile {
+            kv_mount: "secret".to_string(),
+            ..Default::default()
+        };
+        state
+            .services
+            .insert("edge".to_string(), service_entry("edge", "local-file"));
+        state
+            .services
+            .insert("api".to_string(), service_entry("api", "remote-bootstrap"));
+        let state_file = dir.path().join("state.json");
+        let mut ctx = RotateContext {
+            openbao_url: server.uri(),
+            kv_mount: "secret".to_string(),
+            compose_file: PathBuf::new(),
+            state,
+            paths: StatePaths::new(dir.path().join("secrets")),
+            state_dir: dir.path().to_path_buf(),
+            state_file: state_file.clone(),
+            restart_confirmation: None,
+        };
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+
+        rotate_eab_clear(&mut ctx, &client, true, &test_messages())
+            .await
+            .expect("eab clear");
+
+        let saved = StateFile::load(&state_file).expect("state saved");
+        let generation = |name: &str| saved.services.get(name).expect("service").sync_generation;
+        assert_eq!(generation("api"), Some(5));
+        assert_eq!(generation("edge"), None);
+    }
+
+    #[tokio::test]
+    async fn eab_clear_writes_service_paths_under_recorded_kv_prefix() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/data/bootroot/agent/eab"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/data/tenants/acme/services/api/eab"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/tenants/acme/services/api/generation"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/data/tenants/acme/services/api/generation"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(path("/v1/secret/data/bootroot/services/api/eab"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let mut state = StateFile {
+            kv_mount: "secret".to_string(),
+            kv_prefix: Some("tenants/acme".to_string()),
+            ..Default::default()
+        };
+        state
+            .services
+            .insert("api".to_string(), service_entry("api", "remote-bootstrap"));
+        let mut ctx = RotateContext {
+            openbao_url: server.uri(),
+            kv_mount: "secret".to_string(),
+            compose_file: PathBuf::new(),
+            state,
+            paths: StatePaths::new(dir.path().join("secrets")),
+            state_dir: dir.path().to_path_buf(),
+            state_file: dir.path().join("state.json"),
+            restart_confirmation: None,
+        };
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+
+        rotate_eab_clear(&mut ctx, &client, true, &test_messages())
+            .await
+            .expect("eab clear");
+    }
+}
@@ -1,14 +1,30 @@
+use std::future::Future;
+use std::num::NonZeroUsize;
 use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
 use bootroot::fs_util;
+use bootroot::openbao::OpenBaoClient;
+use bootroot::trust_bootstrap::{
+    GENERATION_KEY, GENERATION_UPDATED_AT_KEY, SERVICE_GENERATION_KV_SUFFIX,
+};
+use tokio::sync::Semaphore;
 
-use super::RENDERED_FILE_POLL_INTERVAL;
+use super::{OpenBaoAgent, RENDERED_FILE_POLL_INTERVAL, RotateContext};
 use crate::cli::prompt::Prompt;
-use crate::commands::infra::run_docker;
+use crate::commands::cancellation;
+use crate::commands::clean::{
+    COMPOSE_PROJECT_LABEL, COMPOSE_SERVICE_LABEL, inspect_label_via_docker, resolve_compose_project,
+};
+use crate::commands::compose_file::compose_file_dir;
+use crate::commands::container_runtime;
+use crate::commands::infra::{
+    compose_profile_args, docker_output, run_docker, wait_for_service_ready,
+};
 use crate::i18n::Messages;
-use crate::state::ServiceEntry;
+use crate::state::{DeliveryMode, ServiceEntry};
 
 pub(super) fn confirm_action(prompt: &str, auto_confirm: bool, messages: &Messages) -> Result<()> {
     if auto_confirm {
@@ -89,32 +105,234 @@ pub(super) fn restart_container(container: &str, messages: &Messages) -> Result<
     run_docker(&args, &format!("docker restart {container}"), messages)
 }
 
-pub(super) fn restart_compose_service(
+/// Restarts every running container of `agent` so it re-renders from
+/// `OpenBao`, returning the names of the restarted containers.
+///
+/// Containers are discovered with `docker ps` rather than assumed, by
+/// the compose project and service labels, so a renamed container is
+/// still found while a same-named service of another compose project is
+/// left alone. When none is running the new value is already in
+/// `OpenBao` but not rendered, so a warning names the manual step and
+/// the returned list is empty.
+pub(super) fn reload_openbao_agent(
     compose_file: &Path,
+    agent: OpenBaoAgent,
+    messages: &Messages,
+) -> Result<Vec<String>> {
+    let project =
+        resolve_compose_project(&compose_file_dir(compose_file), &inspect_label_via_docker)?;
+    let containers = find_containers(
+        &[
+            format!("label={COMPOSE_PROJECT_LABEL}={project}"),
+            format!("label={COMPOSE_SERVICE_LABEL}={}", agent.service),
+        ],
+        messages,
+    )?;
+    let (running, stopped): (Vec<_>, Vec<_>) = containers
+        .into_iter()
+        .partition(|(_, state)| state == "running");
+    if running.is_empty() {
+        match stopped.first() {
+            Some((name, state)) => {
+                eprintln!(
+                    "{}",
+                    messages.warning_openbao_agent_not_running(name, state)
+                );
+            }
+            None => eprintln!(
+                "{}",
+                messages.warning_openbao_agent_not_found(agent.service, agent.container)
+            ),
+        }
+        return Ok(Vec::new());
+    }
+    let mut restarted = Vec::with_capacity(running.len());
+    for (name, _) in running {
+        restart_container(&name, messages)?;
+        restarted.push(name);
+    }
+    Ok(restarted)
+}
+
+/// Like [`reload_openbao_agent`], but fails when no container was
+/// reloaded, for flows that go on to wait for the rendered file.
+pub(super) fn require_openbao_agent_reload(
+    compose_file: &Path,
+    agent: OpenBaoAgent,
+    messages: &Messages,
+) -> Result<()> {
+    if reload_openbao_agent(compose_file, agent, messages)?.is_empty() {
+        anyhow::bail!(messages.error_openbao_agent_not_reloaded(agent.service));
+    }
+    Ok(())
+}
+
+/// Lists `(name, state)` for every container, running or not, matching
+/// all of the `docker ps` `filters`.
+fn find_containers(filters: &[String], messages: &Messages) -> Result<Vec<(String, String)>> {
+    let mut args = vec!["ps", "--all"];
+    for filter in filters {
+        args.extend(["--filter", filter.as_str()]);
+    }
+    args.extend(["--format", "{{.Names}}\t{{.State}}"]);
+    let output = docker_output(&args, messages)?;
+    Ok(output
+        .lines()
+        .filter_map(|line| {
+            let (name, state) = line.trim().split_once('\t')?;
+            Some((name.to_string(), state.to_string()))
+        })
+        .collect())
+}
+
+/// Bumps the sync generation of every remote-bootstrap service among
+/// `service_names` after its KV payloads were rewritten, and records the
+/// published values in `state.json`.
+///
+/// Local-file services are skipped: their agent re-reads on-disk files
+/// and has no edge poller to notify.
+pub(super) async fn publish_remote_generations<'a>(
+    ctx: &mut RotateContext,
+    client: &OpenBaoClient,
+    service_names: impl IntoIterator<Item = &'a str>,
+    messages: &Messages,
+) -> Result<()> {
+    let mut published = false;
+    for service_name in service_names {
+        let path = ctx
+            .state
+            .service_kv_path(service_name, SERVICE_GENERATION_KV_SUFFIX);
+        let Some(entry) = ctx.state.services.get_mut(service_name) else {
+            continue;
+        };
+        if !matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap) {
+            continue;
+        }
+        let generation = bump_service_generation(client, &ctx.kv_mount, &path, messages).await?;
+        entry.sync_generation = Some(generation);
+        published = true;
+    }
+    if published {
+        ctx.state
+            .save(&ctx.state_file)
+            .with_context(|| messages.error_serialize_state_failed())?;
+    }
+    Ok(())
+}
+
+/// Runs [`publish_remote_generations`] for every registered service, for
+/// flows that rewrite one payload per service.
+pub(super) async fn publish_all_remote_generations(
+    ctx: &mut RotateContext,
+    client: &OpenBaoClient,
+    messages: &Messages,
+) -> Result<()> {
+    let service_names: Vec<String> = ctx.state.services.keys().cloned().collect();
+    publish_remote_generations(
+        ctx,
+        client,
+        service_names.iter().map(String::as_str),
+        messages,
+    )
+    .await
+}
+
+/// Increments the `generation` KV counter at `path`, treating a missing
+/// path as generation 0, and returns the new value.
+async fn bump_service_generation(
+    client: &OpenBaoClient,
+    kv_mount: &str,
+    path: &str,
+    messages: &Messages,
+) -> Result<u64> {
+    let current = client
+        .try_read_kv(kv_mount, path)
+        .await
+        .with_context(|| messages.error_openbao_kv_read_failed())?
+        .and_then(|data| data.get(GENERATION_KEY).and_then(serde_json::Value::as_u64))
+        .unwrap_or(0);
+    let generation = current.saturating_add(1);
+    let updated_at = time::OffsetDateTime::now_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .context("Failed to format the generation timestamp")?;
+    client
+        .write_kv(
+            kv_mount,
+            path,
+            serde_json::json!({
+                GENERATION_KEY: generation,
+                GENERATION_UPDATED_AT_KEY: updated_at,
+            }),
+        )
+        .await
+        .with_context(|| messages.error_openbao_kv_write_failed())?;
+    Ok(generation)
+}
+
+/// Restarts a compose service; with a `restart_confirmation` timeout
+/// (`--confirm-restart`) also waits for it to report ready again.
+pub(super) async fn restart_compose_service(
+    compose_file: &Path,
+    profiles: &[String],
     service: &str,
+    restart_confirmation: Option<Duration>,
     messages: &Messages,
 ) -> Result<()> {
-    let compose_file = compose_file.to_string_lossy();
-    let args = ["compose", "-f", compose_file.as_ref(), "restart", service];
-    run_docker(&args, "docker compose restart", messages)
+    let compose_file_arg = compose_file.to_string_lossy();
+    let mut args = vec!["compose", "-f", compose_file_arg.as_ref()];
+    args.extend(compose_profile_args(profiles));
+    args.extend(["restart", service]);
+    run_docker(&args, "docker compose restart", messages)?;
+    confirm_service_recovered(
+        compose_file,
+        profiles,
+        service,
+        restart_confirmation,
+        messages,
+    )
+    .await
 }
 
-pub(super) fn reload_compose_service(
+/// Sends `SIGHUP` to a compose service; with a `restart_confirmation`
+/// timeout also waits for it to report ready again.
+pub(super) async fn reload_compose_service(
     compose_file: &Path,
+    profiles: &[String],
     service: &str,
+    restart_confirmation: Option<Duration>,
     messages: &Messages,
 ) -> Result<()> {
-    let compose_file = compose_file.to_string_lossy();
-    let args = [
-        "compose",
-        "-f",
-        compose_file.as_ref(),
-        "kill",
-        "-s",
-        "HUP",
+    let compose_file_arg = compose_file.to_string_lossy();
+    let mut args = vec!["compose", "-f", compose_file_arg.as_ref()];
+    args.extend(compose_profile_args(profiles));
+    args.extend(["kill", "-s", "HUP", service]);
+    run_docker(&args, "docker compose kill", messages)?;
+    confirm_service_recovered(
+        compose_file,
+        profiles,
         service,
-    ];
-    run_docker(&args, "docker compose kill", messages)
+        restart_confirmation,
+        messages,
+    )
+    .await
+}
+
+/// Fails the rotation when a restarted service does not come back ready
+/// within `restart_confirmation`, so a crash loop surfaces as an error
+/// instead of a reported success.
+async fn confirm_service_recovered(
+    compose_file: &Path,
+    profiles: &[String],
+    service: &str,
+    restart_confirmation: Option<Duration>,
+    messages: &Messages,
+) -> Result<()> {
+    let Some(timeout) = restart_confirmation else {
+        return Ok(());
+    };
+    wait_for_service_ready(compose_file, profiles, service, timeout, messages)
+        .await
+        .with_context(|| messages.error_service_not_recovered(service))
 }
 
 pub(super) async fn wait_for_rendered_file(
@@ -133,7 +351,9 @@ pub(super) async fn wait_for_rendered_file(
         if tokio::time::Instant::now() >= deadline {
             anyhow::bail!(messages.error_rendered_file_timeout(&path.display().to_string()));
         }
-        tokio::time::sleep(RENDERED_FILE_POLL_INTERVAL).await;
+        cancellation::global()
+            .sleep(RENDERED_FILE_POLL_INTERVAL, messages)
+            .await?;
     }
 }
 
@@ -162,7 +382,7 @@ pub(super) fn signal_bootroot_agent(_entry: &ServiceEntry, messages: &Messages)
 }
 
 pub(super) fn try_restart_container(container: &str) -> Result<()> {
-    let status = std::process::Command::new("docker")
+    let status = container_runtime::command()
         .args(["restart", container])
         .stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::null())
@@ -173,14 +393,61 @@ pub(super) fn try_restart_container(container: &str) -> Result<()> {
     Ok(())
 }
 
+/// Runs `task` for every item on its own tokio task with at most
+/// `concurrency` in flight, returning the results in input order.
+///
+/// An item for which `lane` returns a semaphore waits for a permit on it
+/// before taking one of the `concurrency` slots, so work that must stay
+/// narrower (for example host-side steps that hit the local Docker
+/// daemon) queues without holding back the rest.  A task that panics is
+/// reported as an error for its item.
+pub(super) async fn run_bounded<T, R, Fut>(
+    items: Vec<T>,
+    concurrency: NonZeroUsize,
+    lane: impl Fn(&T) -> Option<Arc<Semaphore>>,
+    task: impl Fn(T) -> Fut,
+) -> Vec<Result<R>>
+where
+    R: Send + 'static,
+    Fut: Future<Output = Result<R>> + Send + 'static,
+{
+    let slots = Arc::new(Semaphore::new(concurrency.get()));
+    let handles: Vec<_> = items
+        .into_iter()
+        .map(|item| {
+            let lane = lane(&item);
+            let slots = Arc::clone(&slots);
+            let work = task(item);
+            tokio::spawn(async move {
+                let _lane = match lane {
+                    Some(lane) => Some(lane.acquire_owned().await?),
+                    None => None,
+                };
+                let _slot = slots.acquire_owned().await?;
+                work.await
+            })
+        })
+        .collect();
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {
+        results.push(handle.await.unwrap_or_else(|err| Err(err.into())));
+    }
+    results
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::time::Duration;
 
     use tempfile::tempdir;
 
-    use super::super::test_support::test_messages;
+    use super::super::OPENBAO_AGENT_STEPCA;
+    use super::super::test_support::{
+        ScopedEnvVar, TEST_DOCKER_ARGS_ENV, TEST_DOCKER_PS_OUTPUT_ENV, env_lock, path_with_prepend,
+        test_messages, write_fake_docker_script,
+    };
     use super::*;
 
     #[tokio::test]
@@ -299,4 +566,157 @@ mod tests {
         .expect_err("should timeout when content never matches");
         assert!(err.to_string().contains("Timed out"));
     }
+
+    #[tokio::test]
+    async fn run_bounded_caps_in_flight_tasks_and_keeps_order() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let concurrency = NonZeroUsize::new(3).expect("non-zero");
+        let results = run_bounded(
+            (0..10).collect(),
+            concurrency,
+            |_| None,
+            |item: usize| {
+                let in_flight = Arc::clone(&in_flight);
+                let peak = Arc::clone(&peak);
+                async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok(item * 2)
+                }
+            },
+        )
+        .await;
+
+        let values: Vec<usize> = results
+            .into_iter()
+            .map(|result| result.expect("task result"))
+            .collect();
+        assert_eq!(values, (0..10).map(|item| item * 2).collect::<Vec<_>>());
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    /// Writes a docker stub whose `compose restart` succeeds but whose
+    /// container then inspects as `running|<health>`.
+    fn write_restart_docker_script(path: &Path, health: &str) {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = format!(
+            r#"#!/bin/sh
+case " $* " in
+  *" ps -q "*) echo stepca-container ;;
+  " inspect "*) echo "running|{health}" ;;
+esac
+exit 0
+"#
+        );
+        std::fs::write(path, script).expect("write docker stub");
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o700))
+            .expect("chmod docker stub");
+    }
+
+    // The env-var lock must be held across the `.await` to prevent
+    // parallel tests from seeing a corrupted PATH.
+    #[allow(clippy::await_holding_lock)]
+    #[tokio::test]
+    async fn restart_compose_service_fails_when_service_stays_unhealthy() {
+        let dir = tempdir().expect("tempdir");
+        write_restart_docker_script(&dir.path().join("docker"), "unhealthy");
+        let _lock = env_lock();
+        let _path = ScopedEnvVar::set("PATH", path_with_prepend(dir.path()));
+        let messages = test_messages();
+        let compose_file = dir.path().join("docker-compose.yml");
+
+        restart_compose_service(&compose_file, &[], "step-ca", None, &messages)
+            .await
+            .expect("unconfirmed restart ignores health");
+        let err = restart_compose_service(
+            &compose_file,
+            &[],
+            "step-ca",
+            Some(Duration::ZERO),
+            &messages,
+        )
+        .await
+        .expect_err("an unhealthy service must fail the confirmed restart");
+        assert_eq!(
+            err.to_string(),
+            messages.error_service_not_recovered("step-ca")
+        );
+        assert!(format!("{err:#}").contains("step-ca health=unhealthy"));
+    }
+
+    // The env-var lock must be held across the `.await` to prevent
+    // parallel tests from seeing a corrupted PATH.
+    #[allow(clippy::await_holding_lock)]
+    #[tokio::test]
+    async fn restart_compose_service_confirms_healthy_service() {
+        let dir = tempdir().expect("tempdir");
+        write_restart_docker_script(&dir.path().join("docker"), "healthy");
+        let _lock = env_lock();
+        let _path = ScopedEnvVar::set("PATH", path_with_prepend(dir.path()));
+        let compose_file = dir.path().join("docker-compose.yml");
+
+        restart_compose_service(
+            &compose_file,
+            &[],
+            "step-ca",
+            Some(Duration::ZERO),
+            &test_messages(),
+        )
+        .await
+        .expect("healthy service confirms");
+    }
+
+    #[test]
+    fn reload_openbao_agent_restarts_discovered_container() {
+        let dir = tempdir().expect("tempdir");
+        write_fake_docker_script(&dir.path().join("docker"));
+        let args_log = dir.path().join("docker_args.log");
+        let _lock = env_lock();
+        let _path = ScopedEnvVar::set("PATH", path_with_prepend(dir.path()));
+        let _args = ScopedEnvVar::set(TEST_DOCKER_ARGS_ENV, args_log.as_os_str());
+        let _ps = ScopedEnvVar::set(
+            TEST_DOCKER_PS_OUTPUT_ENV,
+            "proj-openbao-agent-stepca-1\\trunning\\nproj-openbao-agent-stepca-2\\texited\\n",
+        );
+
+        let restarted = reload_openbao_agent(
+            &dir.path().join("docker-compose.yml"),
+            OPENBAO_AGENT_STEPCA,
+            &test_messages(),
+        )
+        .expect("reload");
+
+        assert_eq!(restarted, vec!["proj-openbao-agent-stepca-1".to_string()]);
+        let args = std::fs::read_to_string(&args_log).expect("docker args");
+        assert_eq!(
+            args.lines().collect::<Vec<_>>(),
+            vec!["restart", "proj-openbao-agent-stepca-1"]
+        );
+    }
+
+    #[test]
+    fn reload_openbao_agent_warns_without_restart_when_not_found() {
+        let dir = tempdir().expect("tempdir");
+        write_fake_docker_script(&dir.path().join("docker"));
+        let args_log = dir.path().join("docker_args.log");
+        let _lock = env_lock();
+        let _path = ScopedEnvVar::set("PATH", path_with_prepend(dir.path()));
+        let _args = ScopedEnvVar::set(TEST_DOCKER_ARGS_ENV, args_log.as_os_str());
+        let _ps = ScopedEnvVar::set(TEST_DOCKER_PS_OUTPUT_ENV, "");
+        let messages = test_messages();
+        let compose_file = dir.path().join("docker-compose.yml");
+
+        let restarted =
+            reload_openbao_agent(&compose_file, OPENBAO_AGENT_STEPCA, &messages).expect("reload");
+
+        assert!(restarted.is_empty());
+        assert!(!args_log.exists(), "no restart should be attempted");
+        let err = require_openbao_agent_reload(&compose_file, OPENBAO_AGENT_STEPCA, &messages)
+            .expect_err("a missing agent must fail the strict reload");
+        assert!(err.to_string().contains(OPENBAO_AGENT_STEPCA.service));
+    }
 }
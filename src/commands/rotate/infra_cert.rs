@@ -5,6 +5,7 @@ use anyhow::{Context, Result, bail};
 use super::RotateContext;
 use super::helpers::{confirm_action, try_restart_container};
 use crate::commands::compose_file::compose_file_dir;
+use crate::commands::container_runtime;
 use crate::commands::init::{
     HTTP01_ADMIN_INFRA_CERT_KEY, OPENBAO_INFRA_CERT_KEY, reissue_http01_admin_tls_cert,
     reissue_openbao_tls_cert,
@@ -108,7 +109,7 @@ fn execute_reload_strategy(strategy: &ReloadStrategy) -> Result<()> {
 
 /// Sends a signal to a Docker container via `docker kill -s`.
 fn try_signal_container(container: &str, signal: &str) -> Result<()> {
-    let status = std::process::Command::new("docker")
+    let status = container_runtime::command()
         .args(["kill", "-s", signal, container])
         .stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::null())
@@ -134,6 +135,7 @@ mod tests {
         RotateInfraCertArgs, RuntimeAuthArgs, SecretsDirOverrideArgs,
     };
     use crate::commands::constants::RESPONDER_SERVICE_NAME;
+    use crate::commands::infra::DEFAULT_READINESS_TIMEOUT_SECS;
     use crate::commands::init::{
         HTTP01_ADMIN_INFRA_CERT_KEY, HTTP01_ADMIN_TLS_CERT_REL_PATH,
         HTTP01_ADMIN_TLS_DEFAULT_RENEW_BEFORE, HTTP01_ADMIN_TLS_KEY_REL_PATH,
@@ -238,6 +240,7 @@ mod tests {
             secrets_dir: SecretsDirOverrideArgs {
                 secrets_dir: Some(secrets_dir),
             },
+            ca_json: None,
             runtime_auth: RuntimeAuthArgs {
                 auth_mode: AuthMode::Auto,
                 root_token: None,
@@ -249,6 +252,9 @@ mod tests {
             },
             yes: true,
             show_secrets: false,
+            dry_run: false,
+            confirm_restart: false,
+            restart_timeout: DEFAULT_READINESS_TIMEOUT_SECS,
         };
 
         let args_log = dir.path().join("docker_args.log");
@@ -365,6 +371,7 @@ mod tests {
             secrets_dir: SecretsDirOverrideArgs {
                 secrets_dir: Some(secrets_dir),
             },
+            ca_json: None,
             runtime_auth: RuntimeAuthArgs {
                 auth_mode: AuthMode::Auto,
                 root_token: None,
@@ -376,6 +383,9 @@ mod tests {
             },
             yes: true,
             show_secrets: false,
+            dry_run: false,
+            confirm_restart: false,
+            restart_timeout: DEFAULT_READINESS_TIMEOUT_SECS,
         };
 
         let args_log = dir.path().join("docker_args.log");
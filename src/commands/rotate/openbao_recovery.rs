@@ -87,7 +87,7 @@ pub(super) async fn rotate_openbao_recovery(
     Ok(())
 }
 
-fn recovery_scopes(args: &RotateOpenBaoRecoveryArgs) -> Vec<&'static str> {
+pub(super) fn recovery_scopes(args: &RotateOpenBaoRecoveryArgs) -> Vec<&'static str> {
     let mut scopes = Vec::new();
     if args.rotate_unseal_keys {
         scopes.push(OPENBAO_RECOVERY_SCOPE_UNSEAL_KEYS);
@@ -197,8 +197,8 @@ fn collect_existing_unseal_keys(
 
         let mut input = std::io::stdin().lock();
         let mut output = std::io::stdout();
-        let mut prompt = Prompt::new(&mut input, &mut output, messages);
-        let key = prompt.prompt_with_validation(label.trim_end_matches(": "), None, |value| {
+        let mut prompt = Prompt::for_stdin(&mut input, &mut output, messages);
+        let key = prompt.prompt_secret_with_validation(label.trim_end_matches(": "), |value| {
             ensure_non_empty(value, messages)
         })?;
         keys.push(key);
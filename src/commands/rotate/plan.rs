@@ -0,0 +1,453 @@
+use anyhow::Result;
+use bootroot::trust_bootstrap::{
+    SERVICE_GENERATION_KV_SUFFIX, SERVICE_REISSUE_KV_SUFFIX, SERVICE_SECRET_ID_KV_SUFFIX,
+};
+
+use super::openbao_recovery::recovery_scopes;
+use super::{OPENBAO_AGENT_RESPONDER, OPENBAO_AGENT_STEPCA, RotateContext, rotate_kv_targets};
+use crate::cli::args::{InfraRoleTarget, RotateAppRoleSecretIdArgs, RotateCommand};
+use crate::commands::constants::{RESPONDER_SERVICE_NAME, STEPCA_SERVICE_NAME};
+use crate::commands::init::{
+    APPROLE_BOOTROOT_RESPONDER, APPROLE_BOOTROOT_STEPCA, OPENBAO_AGENT_DIR,
+    OPENBAO_AGENT_RESPONDER_DIR, OPENBAO_AGENT_SECRET_ID_NAME, OPENBAO_AGENT_STEPCA_DIR,
+    compose_has_responder,
+};
+use crate::i18n::Messages;
+use crate::state::{DeliveryMode, ReloadStrategy, ServiceEntry};
+
+/// Everything one `rotate` target would change, as listed by
+/// `--dry-run`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct RotatePlan {
+    /// KV paths written, as `<mount>/<path>`, including the legacy
+    /// mirror while a migrate dual-write is active.
+    pub(super) kv_writes: Vec<String>,
+    pub(super) files: Vec<String>,
+    pub(super) approles: Vec<String>,
+    pub(super) recovery: Vec<&'static str>,
+    pub(super) restarts: Vec<String>,
+}
+
+impl RotatePlan {
+    fn kv_write(&mut self, ctx: &RotateContext, path: &str) {
+        self.kv_writes.push(format!("{}/{path}", ctx.kv_mount));
+        if let Some((mount, legacy_path)) = ctx
+            .state
+            .kv_dual_write
+            .as_ref()
+            .and_then(|dual| dual.legacy_target(&ctx.kv_mount, path))
+        {
+            self.kv_writes.push(format!("{mount}/{legacy_path}"));
+        }
+    }
+
+    fn service_kv_writes<'a>(
+        &mut self,
+        ctx: &RotateContext,
+        services: impl IntoIterator<Item = &'a str>,
+        suffix: &str,
+    ) {
+        for service_name in services {
+            self.kv_write(ctx, &ctx.state.service_kv_path(service_name, suffix));
+        }
+    }
+
+    /// Adds the sync generation each remote-bootstrap service among
+    /// `services` gets bumped to after its payload sync.
+    fn remote_generations<'a>(
+        &mut self,
+        ctx: &RotateContext,
+        services: impl IntoIterator<Item = &'a str>,
+    ) {
+        let remote = services.into_iter().filter(|name| {
+            ctx.state
+                .services
+                .get(*name)
+                .is_some_and(|entry| matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap))
+        });
+        self.service_kv_writes(ctx, remote, SERVICE_GENERATION_KV_SUFFIX);
+    }
+
+    fn file(&mut self, path: &std::path::Path) {
+        self.files.push(path.display().to_string());
+    }
+
+    fn is_empty(&self) -> bool {
+        self.kv_writes.is_empty()
+            && self.files.is_empty()
+            && self.approles.is_empty()
+            && self.recovery.is_empty()
+            && self.restarts.is_empty()
+    }
+}
+
+/// Prints the plan for `command` without authenticating to `OpenBao` or
+/// touching any file, container, or KV path.
+pub(super) fn print_rotate_plan(
+    ctx: &RotateContext,
+    command: &RotateCommand,
+    messages: &Messages,
+) -> Result<()> {
+    let plan = rotate_plan(ctx, command, messages)?;
+    println!("{}", messages.rotate_plan_title(target_name(command)));
+    if plan.is_empty() {
+        println!("{}", messages.rotate_plan_empty());
+        return Ok(());
+    }
+    for path in &plan.kv_writes {
+        println!("{}", messages.rotate_plan_kv_write(path));
+    }
+    for path in &plan.files {
+        println!("{}", messages.rotate_plan_file(path));
+    }
+    for role in &plan.approles {
+        println!("{}", messages.rotate_plan_approle(role));
+    }
+    for scope in &plan.recovery {
+        println!("{}", messages.rotate_plan_recovery(scope));
+    }
+    for name in &plan.restarts {
+        println!("{}", messages.rotate_plan_restart(name));
+    }
+    Ok(())
+}
+
+/// Computes what `command` would change from `state.json` and the local
+/// layout alone.  KV paths come from [`rotate_kv_targets`], the table
+/// the capability preflight checks.
+pub(super) fn rotate_plan(
+    ctx: &RotateContext,
+    command: &RotateCommand,
+    messages: &Messages,
+) -> Result<RotatePlan> {
+    let mut plan = RotatePlan::default();
+    let service_names = || ctx.state.services.keys().map(String::as_str);
+    let kv_targets = rotate_kv_targets(command);
+    for path in kv_targets.paths {
+        plan.kv_write(ctx, path);
+    }
+    if let Some(suffix) = kv_targets.service_suffix {
+        plan.service_kv_writes(ctx, service_names(), suffix);
+        plan.remote_generations(ctx, service_names());
+    }
+    match command {
+        RotateCommand::StepcaPassword(_) => {
+            plan.file(&ctx.paths.stepca_password_new());
+            plan.file(&ctx.paths.stepca_root_key());
+            plan.file(&ctx.paths.stepca_intermediate_key());
+            plan.file(&ctx.paths.stepca_password());
+            plan.restarts.push(OPENBAO_AGENT_STEPCA.service.to_string());
+            plan.restarts.push(STEPCA_SERVICE_NAME.to_string());
+        }
+        RotateCommand::Db(_) => {
+            plan.file(&ctx.paths.ca_json());
+            plan.restarts.push(OPENBAO_AGENT_STEPCA.service.to_string());
+            plan.restarts.push(STEPCA_SERVICE_NAME.to_string());
+        }
+        RotateCommand::ResponderHmac(_) => {
+            plan.file(&ctx.paths.responder_config());
+            plan.restarts
+                .push(OPENBAO_AGENT_RESPONDER.service.to_string());
+            if compose_has_responder(&ctx.compose_file, messages)? {
+                plan.restarts.push(RESPONDER_SERVICE_NAME.to_string());
+            }
+        }
+        RotateCommand::OpenBaoRecovery(args) => {
+            plan.recovery = recovery_scopes(args);
+        }
+        RotateCommand::AppRoleSecretId(args) => approle_plan(ctx, args, &mut plan),
+        RotateCommand::ForceReissue(args) => {
+            if let Some(entry) = ctx.state.services.get(&args.service_name) {
+                if matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap) {
+                    plan.service_kv_writes(
+                        ctx,
+                        [entry.service_name.as_str()],
+                        SERVICE_REISSUE_KV_SUFFIX,
+                    );
+                } else {
+                    plan.file(&entry.cert_path);
+                    plan.file(&entry.key_path);
+                    plan.restarts.push(entry.service_name.clone());
+                }
+            }
+        }
+        RotateCommand::CaKey(args) => {
+            if args.full {
+                plan.file(&ctx.paths.root_cert());
+                plan.file(&ctx.paths.stepca_root_key());
+            }
+            plan.file(&ctx.paths.intermediate_cert());
+            plan.file(&ctx.paths.stepca_intermediate_key());
+            plan.restarts.push(STEPCA_SERVICE_NAME.to_string());
+            plan.restarts.push(OPENBAO_AGENT_STEPCA.service.to_string());
+            plan.restarts
+                .push(OPENBAO_AGENT_RESPONDER.service.to_string());
+        }
+        RotateCommand::InfraCert(_) => {
+            for entry in ctx.state.infra_certs.values() {
+                plan.file(&entry.cert_path);
+                plan.file(&entry.key_path);
+                let (ReloadStrategy::ContainerRestart { container_name }
+                | ReloadStrategy::ContainerSignal { container_name, .. }) = &entry.reload_strategy;
+                plan.restarts.push(container_name.clone());
+            }
+        }
+        RotateCommand::TrustSync(_) | RotateCommand::EabClear(_) => {}
+    }
+    Ok(plan)
+}
+
+fn approle_plan(ctx: &RotateContext, args: &RotateAppRoleSecretIdArgs, plan: &mut RotatePlan) {
+    if let Some(target) = args.infra {
+        let (role, dir, agent) = match target {
+            InfraRoleTarget::Stepca => (
+                APPROLE_BOOTROOT_STEPCA,
+                OPENBAO_AGENT_STEPCA_DIR,
+                OPENBAO_AGENT_STEPCA,
+            ),
+            InfraRoleTarget::Responder => (
+                APPROLE_BOOTROOT_RESPONDER,
+                OPENBAO_AGENT_RESPONDER_DIR,
+                OPENBAO_AGENT_RESPONDER,
+            ),
+        };
+        plan.approles.push(role.to_string());
+        plan.file(
+            &ctx.paths
+                .secrets_dir()
+                .join(OPENBAO_AGENT_DIR)
+                .join(dir)
+                .join(OPENBAO_AGENT_SECRET_ID_NAME),
+        );
+        plan.restarts.push(agent.service.to_string());
+    } else {
+        let entries: Vec<&ServiceEntry> = if args.all_services {
+            ctx.state.services.values().collect()
+        } else {
+            args.service_name
+                .as_deref()
+                .and_then(|name| ctx.state.services.get(name))
+                .into_iter()
+                .collect()
+        };
+        for entry in entries {
+            plan.approles.push(entry.approle.role_name.clone());
+            if matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap) {
+                plan.service_kv_writes(
+                    ctx,
+                    [entry.service_name.as_str()],
+                    SERVICE_SECRET_ID_KV_SUFFIX,
+                );
+                plan.remote_generations(ctx, [entry.service_name.as_str()]);
+            } else {
+                plan.file(&entry.approle.secret_id_path);
+            }
+        }
+    }
+    plan.file(&ctx.state_file);
+}
+
+/// Returns the CLI name of `command` for the plan heading.
+fn target_name(command: &RotateCommand) -> &'static str {
+    match command {
+        RotateCommand::StepcaPassword(_) => "stepca-password",
+        RotateCommand::Db(_) => "db",
+        RotateCommand::ResponderHmac(_) => "responder-hmac",
+        RotateCommand::OpenBaoRecovery(_) => "openbao-recovery",
+        RotateCommand::AppRoleSecretId(_) => "approle-secret-id",
+        RotateCommand::TrustSync(_) => "trust-sync",
+        RotateCommand::ForceReissue(_) => "force-reissue",
+        RotateCommand::CaKey(_) => "ca-key",
+        RotateCommand::InfraCert(_) => "infra-cert",
+        RotateCommand::EabClear(_) => "eab-clear",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use clap::Parser;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::super::test_support::test_messages;
+    use super::*;
+    use crate::cli::args::{
+        AuthMode, Cli, CliCommand, ComposeFileArgs, OpenBaoOverrideArgs, RotateArgs,
+        RotateEabClearArgs, RuntimeAuthArgs, SecretsDirOverrideArgs,
+    };
+    use crate::commands::infra::DEFAULT_READINESS_TIMEOUT_SECS;
+    use crate::commands::rotate::{load_context, run_rotate};
+    use crate::state::StateFile;
+
+    fn service_entry(base: &std::path::Path, name: &str, delivery_mode: &str) -> ServiceEntry {
+        serde_json::from_value(serde_json::json!({
+            "service_name": name,
+            "delivery_mode": delivery_mode,
+            "hostname": "h",
+            "domain": "d.com",
+            "agent_config_path": base.join(name).join("agent.toml"),
+            "cert_path": base.join(name).join("cert.pem"),
+            "key_path": base.join(name).join("key.pem"),
+            "approle": {
+                "role_name": format!("bootroot-service-{name}-role"),
+                "role_id": format!("{name}-role-id"),
+                "secret_id_path": base.join(name).join("secret_id"),
+                "policy_name": "p"
+            }
+        }))
+        .expect("service entry")
+    }
+
+    fn rotate_args(state_file: std::path::PathBuf, command: RotateCommand) -> RotateArgs {
+        RotateArgs {
+            command,
+            state_file: Some(state_file),
+            compose: ComposeFileArgs {
+                compose_file: "docker-compose.yml".into(),
+            },
+            openbao: OpenBaoOverrideArgs {
+                openbao_url: None,
+                kv_mount: None,
+            },
+            secrets_dir: SecretsDirOverrideArgs { secrets_dir: None },
+            ca_json: None,
+            runtime_auth: RuntimeAuthArgs {
+                auth_mode: AuthMode::Auto,
+                root_token: None,
+                root_token_file: None,
+                approle_role_id: None,
+                approle_secret_id: None,
+                approle_role_id_file: None,
+                approle_secret_id_file: None,
+            },
+            yes: false,
+            show_secrets: false,
+            dry_run: true,
+            confirm_restart: false,
+            restart_timeout: DEFAULT_READINESS_TIMEOUT_SECS,
+        }
+    }
+
+    #[tokio::test]
+    async fn eab_clear_dry_run_lists_paths_without_touching_openbao() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let mut services = BTreeMap::new();
+        for (name, mode) in [("edge", "local-file"), ("api", "remote-bootstrap")] {
+            services.insert(name.to_string(), service_entry(dir.path(), name, mode));
+        }
+        let state = StateFile {
+            openbao_url: server.uri(),
+            kv_mount: "secret".to_string(),
+            secrets_dir: Some(dir.path().join("secrets")),
+            services,
+            ..Default::default()
+        };
+        let state_file = dir.path().join("state.json");
+        state.save(&state_file).expect("write state");
+        let args = rotate_args(
+            state_file.clone(),
+            RotateCommand::EabClear(RotateEabClearArgs {}),
+        );
+
+        // No credentials are configured: a dry run must not need any.
+        run_rotate(&args, &test_messages())
+            .await
+            .expect("dry run succeeds without OpenBao auth");
+
+        let ctx = load_context(&args, &test_messages()).expect("context");
+        let plan = rotate_plan(&ctx, &args.command, &test_messages()).expect("plan");
+        assert_eq!(
+            plan.kv_writes,
+            vec![
+                "secret/bootroot/agent/eab".to_string(),
+                "secret/bootroot/services/api/eab".to_string(),
+                "secret/bootroot/services/edge/eab".to_string(),
+                "secret/bootroot/services/api/generation".to_string(),
+            ]
+        );
+        assert!(plan.files.is_empty());
+        assert!(plan.restarts.is_empty());
+    }
+
+    #[test]
+    fn approle_plan_splits_local_files_from_remote_kv_and_mirrors_dual_write() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut services = BTreeMap::new();
+        for (name, mode) in [("edge", "local-file"), ("api", "remote-bootstrap")] {
+            services.insert(name.to_string(), service_entry(dir.path(), name, mode));
+        }
+        let state = StateFile {
+            openbao_url: "https://127.0.0.1:8200".to_string(),
+            kv_mount: "bootroot".to_string(),
+            secrets_dir: Some(dir.path().join("secrets")),
+            services,
+            kv_dual_write: Some(bootroot::openbao::KvDualWrite {
+                current: bootroot::openbao::KvLayout {
+                    mount: "bootroot".to_string(),
+                    prefix: "bootroot".to_string(),
+                },
+                legacy: bootroot::openbao::KvLayout {
+                    mount: "secret".to_string(),
+                    prefix: "bootroot".to_string(),
+                },
+            }),
+            ..Default::default()
+        };
+        let state_file = dir.path().join("state.json");
+        state.save(&state_file).expect("write state");
+        let cli = Cli::parse_from([
+            "bootroot",
+            "rotate",
+            "--state-file",
+            &state_file.display().to_string(),
+            "approle-secret-id",
+            "--all-services",
+        ]);
+        let CliCommand::Rotate(args) = cli.command else {
+            panic!("expected Rotate command");
+        };
+        let ctx = load_context(&args, &test_messages()).expect("context");
+        let mut plan = RotatePlan::default();
+        let RotateCommand::AppRoleSecretId(approle_args) = &args.command else {
+            panic!("expected approle-secret-id");
+        };
+
+        approle_plan(&ctx, approle_args, &mut plan);
+
+        assert_eq!(
+            plan.approles,
+            vec![
+                "bootroot-service-api-role".to_string(),
+                "bootroot-service-edge-role".to_string(),
+            ]
+        );
+        assert_eq!(
+            plan.kv_writes,
+            vec![
+                "bootroot/bootroot/services/api/secret_id".to_string(),
+                "secret/bootroot/services/api/secret_id".to_string(),
+                "bootroot/bootroot/services/api/generation".to_string(),
+                "secret/bootroot/services/api/generation".to_string(),
+            ]
+        );
+        assert_eq!(
+            plan.files,
+            vec![
+                dir.path()
+                    .join("edge")
+                    .join("secret_id")
+                    .display()
+                    .to_string(),
+                state_file.display().to_string(),
+            ]
+        );
+    }
+}
@@ -2,14 +2,13 @@ use anyhow::{Context, Result};
 use bootroot::openbao::OpenBaoClient;
 
 use super::helpers::{
-    compose_has_responder, confirm_action, reload_compose_service, restart_container,
-    wait_for_rendered_file,
+    compose_has_responder, confirm_action, publish_all_remote_generations, reload_compose_service,
+    require_openbao_agent_reload, wait_for_rendered_file,
 };
-use super::{OPENBAO_AGENT_RESPONDER_CONTAINER, RENDERED_FILE_TIMEOUT, RotateContext};
+use super::{OPENBAO_AGENT_RESPONDER, RENDERED_FILE_TIMEOUT, RotateContext};
 use crate::cli::args::RotateResponderHmacArgs;
 use crate::commands::constants::{
-    RESPONDER_SERVICE_NAME, SERVICE_KV_BASE, SERVICE_RESPONDER_HMAC_KEY,
-    SERVICE_RESPONDER_HMAC_KV_SUFFIX,
+    RESPONDER_SERVICE_NAME, SERVICE_RESPONDER_HMAC_KEY, SERVICE_RESPONDER_HMAC_KV_SUFFIX,
 };
 use crate::commands::init::{PATH_RESPONDER_HMAC, SECRET_BYTES};
 use crate::i18n::Messages;
@@ -41,9 +40,10 @@ pub(super) async fn rotate_responder_hmac(
         .await
         .with_context(|| messages.error_openbao_kv_write_failed())?;
     sync_service_responder_hmac_payloads(ctx, client, &hmac, messages).await?;
+    publish_all_remote_generations(ctx, client, messages).await?;
 
     let responder_path = ctx.paths.responder_config();
-    restart_container(OPENBAO_AGENT_RESPONDER_CONTAINER, messages)?;
+    require_openbao_agent_reload(&ctx.compose_file, OPENBAO_AGENT_RESPONDER, messages)?;
     wait_for_rendered_file(&responder_path, &hmac, RENDERED_FILE_TIMEOUT, messages).await?;
 
     // Service agents (local host daemons and remote alike) pick up the
@@ -52,7 +52,14 @@ pub(super) async fn rotate_responder_hmac(
 
     let mut reloaded = false;
     if compose_has_responder(&ctx.compose_file, messages)? {
-        reload_compose_service(&ctx.compose_file, RESPONDER_SERVICE_NAME, messages)?;
+        reload_compose_service(
+            &ctx.compose_file,
+            &ctx.state.compose_profiles,
+            RESPONDER_SERVICE_NAME,
+            ctx.restart_confirmation,
+            messages,
+        )
+        .await?;
         reloaded = true;
     }
 
@@ -82,7 +89,8 @@ async fn sync_service_responder_hmac_payloads(
         client
             .write_kv(
                 &ctx.kv_mount,
-                &format!("{SERVICE_KV_BASE}/{service_name}/{SERVICE_RESPONDER_HMAC_KV_SUFFIX}"),
+                &ctx.state
+                    .service_kv_path(service_name, SERVICE_RESPONDER_HMAC_KV_SUFFIX),
                 serde_json::json!({ SERVICE_RESPONDER_HMAC_KEY: hmac }),
             )
             .await
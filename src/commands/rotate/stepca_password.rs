@@ -7,13 +7,12 @@ use bootroot::fs_util;
 use bootroot::openbao::OpenBaoClient;
 
 use super::helpers::{
-    confirm_action, ensure_file_exists, restart_compose_service, restart_container,
+    confirm_action, ensure_file_exists, require_openbao_agent_reload, restart_compose_service,
     wait_for_rendered_file, write_secret_file,
 };
-use super::{
-    OPENBAO_AGENT_STEPCA_CONTAINER, RENDERED_FILE_TIMEOUT, RotateContext, STEP_CA_HELPER_IMAGE,
-};
+use super::{OPENBAO_AGENT_STEPCA, RENDERED_FILE_TIMEOUT, RotateContext};
 use crate::cli::args::RotateStepcaPasswordArgs;
+use crate::commands::cancellation;
 use crate::commands::infra::run_docker;
 use crate::commands::init::{PATH_STEPCA_PASSWORD, SECRET_BYTES, to_container_path};
 use crate::i18n::Messages;
@@ -56,15 +55,15 @@ pub(super) async fn rotate_stepca_password(
     // (below), so a key left root-owned by an earlier `--user root`
     // rotation would otherwise become unreadable to it. The sweep repairs
     // that first, keeping this flow working exactly as it does today, and
-    // is a no-op when ownership is already correct. It reuses the
-    // `smallstep/step-ca:0.30.2` image the `step` helpers already run, so it
+    // is a no-op when ownership is already correct. It reuses the step-ca
+    // image recorded at init, which the `step` helpers already run, so it
     // adds no new dependency.
-    crate::commands::infra::sweep_secrets_ownership(
-        ctx.paths.secrets_dir(),
-        STEP_CA_HELPER_IMAGE,
-        messages,
-    )?;
+    let image = ctx.state.stepca_image();
+    crate::commands::infra::sweep_secrets_ownership(ctx.paths.secrets_dir(), image, messages)?;
 
+    // Once a key is re-encrypted, step-ca needs the new password rendered
+    // and a restart, so the rest of the rotation runs uninterrupted.
+    let _section = cancellation::global().critical_section(messages)?;
     fs_util::ensure_secrets_dir(secrets_dir).await?;
     write_secret_file(&new_password_path, &new_password, messages).await?;
 
@@ -73,6 +72,7 @@ pub(super) async fn rotate_stepca_password(
         &password_path,
         &new_password_path,
         &root_key,
+        image,
         messages,
     )?;
     change_stepca_passphrase(
@@ -80,6 +80,7 @@ pub(super) async fn rotate_stepca_password(
         &password_path,
         &new_password_path,
         &intermediate_key,
+        image,
         messages,
     )?;
 
@@ -91,7 +92,7 @@ pub(super) async fn rotate_stepca_password(
         )
         .await
         .with_context(|| messages.error_openbao_kv_write_failed())?;
-    restart_container(OPENBAO_AGENT_STEPCA_CONTAINER, messages)?;
+    require_openbao_agent_reload(&ctx.compose_file, OPENBAO_AGENT_STEPCA, messages)?;
     wait_for_rendered_file(
         &password_path,
         &new_password,
@@ -100,7 +101,14 @@ pub(super) async fn rotate_stepca_password(
     )
     .await?;
 
-    restart_compose_service(&ctx.compose_file, "step-ca", messages)?;
+    restart_compose_service(
+        &ctx.compose_file,
+        &ctx.state.compose_profiles,
+        "step-ca",
+        ctx.restart_confirmation,
+        messages,
+    )
+    .await?;
 
     println!("{}", messages.rotate_summary_title());
     // CodeQL flags this as cleartext-logging, but `password_path` is a file
@@ -118,6 +126,7 @@ pub(super) fn change_stepca_passphrase(
     current_password: &Path,
     new_password: &Path,
     key_path: &Path,
+    image: &str,
     messages: &Messages,
 ) -> Result<()> {
     let mount_root = fs::canonicalize(secrets_dir)
@@ -140,7 +149,7 @@ pub(super) fn change_stepca_passphrase(
         "--rm",
         "-v",
         &*mount,
-        "smallstep/step-ca:0.30.2",
+        image,
         "step",
         "crypto",
         "change-pass",
@@ -163,6 +172,7 @@ mod tests {
 
     use super::super::test_support::*;
     use super::*;
+    use crate::commands::init::DEFAULT_STEPCA_IMAGE;
 
     #[test]
     fn change_stepca_passphrase_invokes_docker_with_force_and_expected_paths() {
@@ -192,6 +202,7 @@ mod tests {
             &current_password,
             &new_password,
             &key_path,
+            DEFAULT_STEPCA_IMAGE,
             &test_messages(),
         )
         .expect("change passphrase should succeed");
@@ -211,7 +222,7 @@ mod tests {
             "--rm",
             "-v",
             expected_mount.as_str(),
-            "smallstep/step-ca:0.30.2",
+            DEFAULT_STEPCA_IMAGE,
             "step",
             "crypto",
             "change-pass",
@@ -242,6 +253,7 @@ mod tests {
             &current_password,
             &new_password,
             &external_key,
+            DEFAULT_STEPCA_IMAGE,
             &test_messages(),
         )
         .expect_err("key outside secrets dir must fail");
@@ -276,6 +288,7 @@ mod tests {
             &current_password,
             &new_password,
             &key_path,
+            DEFAULT_STEPCA_IMAGE,
             &test_messages(),
         )
         .expect_err("docker failure should bubble up");
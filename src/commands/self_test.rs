@@ -0,0 +1,361 @@
+//! `bootroot self-test`: an end-to-end run in the compose directory.
+//!
+//! Drives this very binary through `infra install`, `init`, `service
+//! add`, `rotate approle-secret-id`, and `verify`, exactly as an operator
+//! would, then tears the stack down with `clean --yes` whether or not a
+//! step failed. The run happens next to the chosen compose file, so it
+//! refuses to start when any deployment state is already there; the
+//! teardown then only ever removes what the self-test created.
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+
+use crate::cli::args::SelfTestArgs;
+use crate::commands::cancellation;
+use crate::commands::clean::container_exists_via_docker;
+use crate::commands::compose_file::compose_file_dir;
+use crate::commands::init::OPENBAO_CONTAINER_NAME;
+use crate::i18n::Messages;
+use crate::state::StateFile;
+
+const SERVICE_NAME: &str = "self-test";
+const SERVICE_HOSTNAME: &str = "self-test-01";
+const SERVICE_DOMAIN: &str = "trusted.domain";
+const SERVICE_INSTANCE_ID: &str = "001";
+const RESPONDER_URL: &str = "http://localhost:8080";
+const HTTP_HMAC: &str = "bootroot-self-test-hmac";
+const STEPCA_DIRECTORY_URL: &str = "https://localhost:9000/acme/acme/directory";
+
+/// Paths `init` leaves next to the compose file; any of them means a
+/// deployment already lives there. `certs/` is included because the
+/// `clean --yes` teardown deletes it.
+const DEPLOYMENT_MARKERS: &[&str] = &["secrets", ".env", "certs"];
+
+/// Steps of the self-test, in the order they run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelfTestStep {
+    InfraInstall,
+    Init,
+    ServiceAdd,
+    Rotate,
+    Verify,
+}
+
+impl SelfTestStep {
+    const ALL: [Self; 5] = [
+        Self::InfraInstall,
+        Self::Init,
+        Self::ServiceAdd,
+        Self::Rotate,
+        Self::Verify,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::InfraInstall => "infra install",
+            Self::Init => "init",
+            Self::ServiceAdd => "service add",
+            Self::Rotate => "rotate approle-secret-id",
+            Self::Verify => "verify",
+        }
+    }
+
+    /// Returns the `bootroot` arguments that run this step.
+    fn args(self, plan: &SelfTestPlan) -> Vec<OsString> {
+        let compose_file = plan.compose_file.as_os_str();
+        let root_token = plan.root_token_path();
+        let agent_config = plan.agent_config_path();
+        let cert_path = plan.cert_path();
+        let key_path = plan.key_path();
+        let args: Vec<&OsStr> = match self {
+            Self::InfraInstall => vec![
+                "infra".as_ref(),
+                "install".as_ref(),
+                "--compose-file".as_ref(),
+                compose_file,
+            ],
+            Self::Init => vec![
+                "init".as_ref(),
+                "--compose-file".as_ref(),
+                compose_file,
+                "--enable".as_ref(),
+                "auto-generate".as_ref(),
+                "--http-hmac".as_ref(),
+                HTTP_HMAC.as_ref(),
+                "--responder-url".as_ref(),
+                RESPONDER_URL.as_ref(),
+                "--no-eab".as_ref(),
+                "--skip".as_ref(),
+                "responder-check".as_ref(),
+                "--save-unseal-keys".as_ref(),
+                "--root-token-output".as_ref(),
+                root_token.as_os_str(),
+                "--yes".as_ref(),
+            ],
+            Self::ServiceAdd => vec![
+                "service".as_ref(),
+                "add".as_ref(),
+                "--service-name".as_ref(),
+                SERVICE_NAME.as_ref(),
+                "--hostname".as_ref(),
+                SERVICE_HOSTNAME.as_ref(),
+                "--domain".as_ref(),
+                SERVICE_DOMAIN.as_ref(),
+                "--instance-id".as_ref(),
+                SERVICE_INSTANCE_ID.as_ref(),
+                "--agent-config".as_ref(),
+                agent_config.as_os_str(),
+                "--cert-path".as_ref(),
+                cert_path.as_os_str(),
+                "--key-path".as_ref(),
+                key_path.as_os_str(),
+                "--root-token-file".as_ref(),
+                root_token.as_os_str(),
+            ],
+            Self::Rotate => vec![
+                "rotate".as_ref(),
+                "--compose-file".as_ref(),
+                compose_file,
+                "--root-token-file".as_ref(),
+                root_token.as_os_str(),
+                "--yes".as_ref(),
+                "approle-secret-id".as_ref(),
+                "--service-name".as_ref(),
+                SERVICE_NAME.as_ref(),
+            ],
+            Self::Verify => vec![
+                "verify".as_ref(),
+                "--service-name".as_ref(),
+                SERVICE_NAME.as_ref(),
+                "--agent-config".as_ref(),
+                agent_config.as_os_str(),
+            ],
+        };
+        args.into_iter().map(OsStr::to_os_string).collect()
+    }
+}
+
+/// Locations shared by the steps of one self-test run.
+struct SelfTestPlan {
+    compose_file: PathBuf,
+    /// Scratch directory for the root token, agent config, and issued
+    /// certificate; removed when the run ends.
+    workspace: PathBuf,
+}
+
+impl SelfTestPlan {
+    fn root_token_path(&self) -> PathBuf {
+        self.workspace.join("root-token")
+    }
+
+    fn agent_config_path(&self) -> PathBuf {
+        self.workspace.join("agent.toml")
+    }
+
+    fn cert_path(&self) -> PathBuf {
+        self.workspace
+            .join("certs")
+            .join(format!("{SERVICE_NAME}.crt"))
+    }
+
+    fn key_path(&self) -> PathBuf {
+        self.workspace
+            .join("certs")
+            .join(format!("{SERVICE_NAME}.key"))
+    }
+}
+
+pub(crate) fn run_self_test(args: &SelfTestArgs, messages: &Messages) -> Result<()> {
+    let compose_file = &args.compose_file.compose_file;
+    let compose_dir = compose_file_dir(compose_file);
+    ensure_no_deployment(&compose_dir, messages)?;
+
+    let exe = std::env::current_exe().with_context(|| messages.error_self_test_exe_missing())?;
+    let workspace = tempfile::tempdir().with_context(|| messages.error_self_test_workspace())?;
+    let plan = SelfTestPlan {
+        compose_file: compose_file.clone(),
+        workspace: workspace.path().to_path_buf(),
+    };
+    write_agent_config(&plan, messages)?;
+
+    run_with_teardown(
+        &SelfTestStep::ALL,
+        messages,
+        |step| run_bootroot(&exe, &step.args(&plan), messages),
+        || {
+            println!("{}", messages.self_test_teardown());
+            run_bootroot(
+                &exe,
+                &[
+                    "clean".into(),
+                    "--compose-file".into(),
+                    plan.compose_file.clone().into_os_string(),
+                    "--yes".into(),
+                ],
+                messages,
+            )
+        },
+    )?;
+    println!("{}", messages.self_test_passed());
+    Ok(())
+}
+
+/// Runs `steps` in order, stopping at the first failure, then always
+/// runs `teardown`.
+///
+/// The step error wins over a teardown error, which is only printed in
+/// that case; a teardown failure after passing steps fails the run.
+fn run_with_teardown(
+    steps: &[SelfTestStep],
+    messages: &Messages,
+    mut run_step: impl FnMut(SelfTestStep) -> Result<()>,
+    teardown: impl FnOnce() -> Result<()>,
+) -> Result<()> {
+    let outcome = steps.iter().try_for_each(|&step| {
+        cancellation::global().check(messages)?;
+        println!("{}", messages.self_test_step_started(step.name()));
+        run_step(step).with_context(|| messages.error_self_test_step_failed(step.name()))?;
+        println!("{}", messages.self_test_step_passed(step.name()));
+        Ok(())
+    });
+    let torn_down = teardown().map_err(|err| {
+        anyhow::anyhow!(messages.error_self_test_teardown_failed(&format!("{err:#}")))
+    });
+    match (outcome, torn_down) {
+        (Ok(()), torn_down) => torn_down,
+        (Err(err), Ok(())) => Err(err),
+        (Err(err), Err(teardown_err)) => {
+            eprintln!("{teardown_err}");
+            Err(err)
+        }
+    }
+}
+
+/// Refuses to run where `init` state or the stack's containers already
+/// exist, so teardown cannot wipe a real deployment.
+fn ensure_no_deployment(compose_dir: &Path, messages: &Messages) -> Result<()> {
+    let found: Vec<String> = std::iter::once(StateFile::default_path())
+        .chain(DEPLOYMENT_MARKERS.iter().map(|name| compose_dir.join(name)))
+        .filter(|path| path.exists())
+        .map(|path| path.display().to_string())
+        .collect();
+    if !found.is_empty() {
+        anyhow::bail!(messages.error_self_test_deployment_exists(&found.join(", ")));
+    }
+    if container_exists_via_docker(OPENBAO_CONTAINER_NAME)? {
+        anyhow::bail!(messages.error_self_test_container_exists(OPENBAO_CONTAINER_NAME));
+    }
+    Ok(())
+}
+
+/// Writes the minimal agent config `service add` fills in for the
+/// self-test service.
+fn write_agent_config(plan: &SelfTestPlan, messages: &Messages) -> Result<()> {
+    let path = plan.agent_config_path();
+    let contents = format!(
+        "email = \"admin@example.com\"\n\
+         server = \"{STEPCA_DIRECTORY_URL}\"\n\
+         domain = \"{SERVICE_DOMAIN}\"\n\
+         \n\
+         [acme]\n\
+         http_responder_url = \"{RESPONDER_URL}\"\n\
+         http_responder_hmac = \"{HTTP_HMAC}\"\n\
+         \n\
+         [trust]\n"
+    );
+    std::fs::write(&path, contents)
+        .with_context(|| messages.error_write_file_failed(&path.display().to_string()))
+}
+
+/// Runs this binary with `args`, with stdin closed so no step can block
+/// on a prompt.
+///
+/// The binary's own directory is put first on `PATH` so `verify` finds
+/// the `bootroot-agent` built alongside it.
+fn run_bootroot(exe: &Path, args: &[OsString], messages: &Messages) -> Result<()> {
+    let mut command = Command::new(exe);
+    command.args(args).stdin(Stdio::null());
+    if let Some(dir) = exe.parent() {
+        let path = std::env::var_os("PATH").unwrap_or_default();
+        let paths = std::iter::once(dir.to_path_buf()).chain(std::env::split_paths(&path));
+        if let Ok(joined) = std::env::join_paths(paths) {
+            command.env("PATH", joined);
+        }
+    }
+    let status = command
+        .status()
+        .with_context(|| messages.error_command_run_failed(&exe.display().to_string()))?;
+    if !status.success() {
+        anyhow::bail!(
+            messages.error_command_failed_status(&exe.display().to_string(), &status.to_string())
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use super::*;
+    use crate::i18n::test_messages;
+
+    #[test]
+    fn run_with_teardown_tears_down_after_a_failed_step() {
+        let messages = test_messages();
+        let attempted = RefCell::new(Vec::new());
+        let torn_down = Cell::new(false);
+
+        let err = run_with_teardown(
+            &SelfTestStep::ALL,
+            &messages,
+            |step| {
+                attempted.borrow_mut().push(step);
+                if step == SelfTestStep::Init {
+                    anyhow::bail!("init exploded");
+                }
+                Ok(())
+            },
+            || {
+                torn_down.set(true);
+                Ok(())
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            *attempted.borrow(),
+            [SelfTestStep::InfraInstall, SelfTestStep::Init]
+        );
+        assert!(torn_down.get());
+        let chain = format!("{err:#}");
+        assert!(chain.contains("init"), "{chain}");
+        assert!(chain.contains("init exploded"), "{chain}");
+    }
+
+    #[test]
+    fn run_with_teardown_fails_when_teardown_fails() {
+        let messages = test_messages();
+        let err = run_with_teardown(
+            &SelfTestStep::ALL,
+            &messages,
+            |_| Ok(()),
+            || anyhow::bail!("docker compose down failed"),
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("docker compose down failed"));
+    }
+
+    #[test]
+    fn ensure_no_deployment_refuses_existing_certs() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("certs")).unwrap();
+
+        let err = ensure_no_deployment(dir.path(), &test_messages()).unwrap_err();
+
+        assert!(err.to_string().contains("certs"));
+    }
+}
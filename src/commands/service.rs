@@ -1,4 +1,6 @@
+mod add_result;
 mod approle;
+pub(crate) mod cert_files;
 mod local_config;
 mod remote_bootstrap;
 mod remove;
@@ -9,12 +11,17 @@ use std::path::Path;
 
 use anyhow::{Context, Result};
 use bootroot::openbao::{OpenBaoClient, SecretIdOptions};
+use serde::Serialize;
 
-use crate::cli::args::{ServiceAddArgs, ServiceInfoArgs, ServiceUpdateArgs};
+use crate::cli::args::{
+    ServiceAddArgs, ServiceAddOutputFormat, ServiceInfoArgs, ServiceInfoOutputFormat,
+    ServiceListArgs, ServiceListOutputFormat, ServiceUpdateArgs,
+};
 use crate::cli::output::{
     ServiceAddAppliedPaths, ServiceAddPlan, ServiceAddRemoteBootstrap, ServiceAddSummaryOptions,
-    print_service_add_plan, print_service_add_summary, print_service_info_summary,
+    display_secret, print_service_add_plan, print_service_add_summary, print_service_info_summary,
 };
+use crate::commands::cancellation;
 use crate::commands::constants::DEFAULT_SECRET_ID_WRAP_TTL;
 use crate::commands::dns_alias::register_dns_alias;
 use crate::commands::init::validate_secret_id_ttl;
@@ -186,10 +193,13 @@ pub(super) fn effective_agent_email(value: Option<&str>) -> &str {
 }
 
 /// Resolves an operator-supplied ACME directory URL to the concrete
-/// value embedded in the baseline.  Falls back to
-/// [`DEFAULT_AGENT_SERVER`].
-pub(super) fn effective_agent_server(value: Option<&str>) -> &str {
-    value.unwrap_or(DEFAULT_AGENT_SERVER)
+/// value embedded in the baseline.  Falls back to the URL recorded at
+/// `bootroot init`, then to [`DEFAULT_AGENT_SERVER`].
+pub(super) fn effective_agent_server<'a>(
+    value: Option<&'a str>,
+    recorded: Option<&'a str>,
+) -> &'a str {
+    value.or(recorded).unwrap_or(DEFAULT_AGENT_SERVER)
 }
 
 /// Resolves an operator-supplied HTTP-01 responder admin URL to the
@@ -198,7 +208,6 @@ pub(super) fn effective_agent_server(value: Option<&str>) -> &str {
 pub(super) fn effective_agent_responder_url(value: Option<&str>) -> &str {
     value.unwrap_or(DEFAULT_AGENT_RESPONDER_URL)
 }
-pub(super) use bootroot::trust_bootstrap::CA_BUNDLE_PEM_KEY as SERVICE_CA_BUNDLE_PEM_KEY;
 
 pub(super) struct ServiceAppRoleMaterialized {
     pub(super) role_name: String,
@@ -224,6 +233,9 @@ pub(super) struct ServiceSyncMaterial {
     pub(super) responder_hmac: String,
     pub(super) trusted_ca_sha256: Vec<String>,
     pub(super) ca_bundle_pem: String,
+    /// `trust_generation` of the `bootroot/ca` payload the trust was
+    /// copied from.
+    pub(super) trust_generation: u64,
 }
 
 pub(super) struct CaTrustMaterial {
@@ -242,7 +254,10 @@ pub(crate) async fn run_service_add(args: &ServiceAddArgs, messages: &Messages)
         StateFile::load(&state_path).with_context(|| messages.error_parse_state_failed())?;
 
     let preview = args.dry_run || args.print_only;
-    let resolved = resolve::resolve_service_add_args(args, messages, preview)?;
+    let mut resolved = resolve::resolve_service_add_args(args, messages, preview)?;
+    resolved
+        .recorded_agent_server
+        .clone_from(&state.acme_directory_url);
 
     resolve::validate_service_add(&resolved, messages)?;
     resolve::validate_secret_id_path_override(
@@ -273,11 +288,14 @@ pub(crate) async fn run_service_add(args: &ServiceAddArgs, messages: &Messages)
         notes: resolved.notes.as_deref(),
         post_renew_hooks: &resolved.post_renew_hooks,
     };
-    print_service_add_plan(&plan, messages);
+    if args.output == ServiceAddOutputFormat::Text {
+        print_service_add_plan(&plan, messages);
+    }
 
     if let Some(existing) = state.services.get(&resolved.service_name).cloned() {
         if is_idempotent_remote_rerun(&existing, &resolved) {
-            return run_service_add_remote_idempotent(&state, &existing, &resolved, messages).await;
+            return run_service_add_remote_idempotent(&state, &existing, &resolved, args, messages)
+                .await;
         }
         if is_policy_only_mismatch(&existing, &resolved) {
             anyhow::bail!(messages.error_service_policy_mismatch());
@@ -328,7 +346,7 @@ pub(crate) async fn run_service_add(args: &ServiceAddArgs, messages: &Messages)
         return Ok(());
     }
 
-    run_service_add_apply(&mut state, &state_path, &resolved, messages).await
+    run_service_add_apply(&mut state, &state_path, &resolved, args, messages).await
 }
 
 async fn run_service_add_preview(
@@ -428,6 +446,7 @@ async fn run_service_add_apply(
     state: &mut StateFile,
     state_path: &Path,
     resolved: &ResolvedServiceAdd,
+    args: &ServiceAddArgs,
     messages: &Messages,
 ) -> Result<()> {
     let auth = resolved
@@ -441,11 +460,16 @@ async fn run_service_add_apply(
 
     let secret_id_options = build_secret_id_options(resolved);
     let wrap_ttl = resolve::effective_wrap_ttl(resolved.secret_id_wrap_ttl.as_deref());
+    // The AppRole, KV bundle, and local files only become reachable for
+    // `service remove` once the entry is saved, so the whole stretch up to
+    // `state.save` runs to completion even when the deadline passes.
+    let section = cancellation::global().critical_section(messages)?;
     let approle_result = approle::ensure_service_approle(
         &client,
         state,
         &resolved.service_name,
         &secret_id_options,
+        resolved.approle_bound_cidrs.as_deref().unwrap_or_default(),
         wrap_ttl,
         messages,
     )
@@ -494,26 +518,7 @@ async fn run_service_add_apply(
                 resolved,
                 &secret_id_path,
                 &service_sync_material,
-                &state.kv_mount,
-                &state.openbao_url,
-                messages,
-            )
-            .await?,
-        )
-    } else {
-        None
-    };
-    let remote_bootstrap_result = if matches!(resolved.delivery_mode, DeliveryMode::RemoteBootstrap)
-    {
-        let artifact_wrap_info = create_artifact_wrap_info(&client, resolved, messages).await?;
-        Some(
-            remote_bootstrap::write_remote_bootstrap_artifact(
                 state,
-                secrets_dir,
-                resolved,
-                &secret_id_path,
-                artifact_wrap_info.as_ref(),
-                &service_sync_material.ca_bundle_pem,
                 messages,
             )
             .await?,
@@ -521,8 +526,18 @@ async fn run_service_add_apply(
     } else {
         None
     };
+    let remote_bootstrap_result = write_remote_bootstrap_if_needed(
+        &client,
+        state,
+        resolved,
+        &secret_id_path,
+        &service_sync_material.ca_bundle_pem,
+        messages,
+    )
+    .await?;
 
-    let entry = build_service_entry(resolved, approle_result, &secret_id_path);
+    let mut entry = build_service_entry(resolved, approle_result, &secret_id_path);
+    entry.trust_generation = Some(service_sync_material.trust_generation);
 
     state
         .services
@@ -530,6 +545,7 @@ async fn run_service_add_apply(
     state
         .save(state_path)
         .with_context(|| messages.error_serialize_state_failed())?;
+    drop(section);
     // The entry is persisted, so `service remove --delete-artifacts` can
     // now reach the relocated files; keep them.
     if let Some(rollback) = credential_rollback.as_mut() {
@@ -538,18 +554,48 @@ async fn run_service_add_apply(
 
     register_dns_alias(state, messages)?;
 
-    print_service_add_apply_summary(
-        &entry,
-        &secret_id_path,
-        applied.as_ref(),
-        remote_bootstrap_result.as_ref(),
+    let outcome = add_result::ServiceAddOutcome {
+        entry: &entry,
+        state,
+        applied: applied.as_ref(),
+        remote: remote_bootstrap_result.as_ref(),
         trusted_ca_sha256,
-        messages,
-    );
-    print_consumer_reload_hint(std::iter::once(&entry), messages);
+        idempotent_rerun: false,
+    };
+    report_service_add(args, &outcome, None, messages).await?;
+    if args.output == ServiceAddOutputFormat::Text && !args.quiet {
+        print_consumer_reload_hint(std::iter::once(&entry), messages);
+    }
     Ok(())
 }
 
+/// Writes the bootstrap artifact for a `remote-bootstrap` service;
+/// returns `None` for `local-file`.
+async fn write_remote_bootstrap_if_needed(
+    client: &OpenBaoClient,
+    state: &StateFile,
+    resolved: &ResolvedServiceAdd,
+    secret_id_path: &Path,
+    ca_bundle_pem: &str,
+    messages: &Messages,
+) -> Result<Option<RemoteBootstrapResult>> {
+    if !matches!(resolved.delivery_mode, DeliveryMode::RemoteBootstrap) {
+        return Ok(None);
+    }
+    let artifact_wrap_info = create_artifact_wrap_info(client, resolved, messages).await?;
+    let result = remote_bootstrap::write_remote_bootstrap_artifact(
+        state,
+        state.secrets_dir(),
+        resolved,
+        secret_id_path,
+        artifact_wrap_info.as_ref(),
+        ca_bundle_pem,
+        messages,
+    )
+    .await?;
+    Ok(Some(result))
+}
+
 fn build_service_entry_from_role(
     resolved: &ResolvedServiceAdd,
     approle: ServiceRoleEntry,
@@ -570,6 +616,8 @@ fn build_service_entry_from_role(
         agent_server: resolved.agent_server.clone(),
         agent_responder_url: resolved.agent_responder_url.clone(),
         cert_group_gid: resolved.cert_group_gid,
+        sync_generation: None,
+        trust_generation: None,
     }
 }
 
@@ -588,6 +636,7 @@ fn build_service_entry(
             secret_id_ttl: resolved.secret_id_ttl.clone(),
             secret_id_wrap_ttl: resolved.secret_id_wrap_ttl.clone(),
             token_bound_cidrs: resolved.token_bound_cidrs.clone(),
+            approle_bound_cidrs: resolved.approle_bound_cidrs.clone(),
         },
     )
 }
@@ -623,39 +672,62 @@ fn build_secret_id_options(resolved: &ResolvedServiceAdd) -> SecretIdOptions {
     }
 }
 
-fn print_service_add_apply_summary(
-    entry: &ServiceEntry,
-    secret_id_path: &Path,
-    applied: Option<&LocalApplyResult>,
-    remote_bootstrap: Option<&RemoteBootstrapResult>,
-    trusted_ca_sha256: Option<&[String]>,
+/// Reports a finished `service add` in the format the operator asked
+/// for. Text mode honors `--quiet` by dropping the next-steps snippets;
+/// `--reveal-to-file` writes the unmasked JSON result in either mode.
+async fn report_service_add(
+    args: &ServiceAddArgs,
+    outcome: &add_result::ServiceAddOutcome<'_>,
+    note: Option<String>,
     messages: &Messages,
-) {
+) -> Result<()> {
+    let reveal_path = args.reveal_to_file.as_deref();
+    if args.output == ServiceAddOutputFormat::Json {
+        return add_result::emit_service_add_json(
+            outcome,
+            args.show_secrets,
+            reveal_path,
+            messages,
+        )
+        .await;
+    }
+    if let Some(path) = reveal_path {
+        add_result::write_revealed_result(path, outcome, messages).await?;
+    }
+    let entry = outcome.entry;
     print_service_add_summary(
         entry,
-        secret_id_path,
+        &entry.approle.secret_id_path,
         ServiceAddSummaryOptions {
-            applied: applied.map(|result| ServiceAddAppliedPaths {
+            applied: outcome.applied.map(|result| ServiceAddAppliedPaths {
                 agent_config: &result.agent_config,
                 eab_file: &result.eab_file,
             }),
-            remote: remote_bootstrap.map(|result| ServiceAddRemoteBootstrap {
+            remote: outcome.remote.map(|result| ServiceAddRemoteBootstrap {
                 bootstrap_file: &result.bootstrap_file,
                 remote_run_command: &result.remote_run_command,
                 wrapped: result.wrapped,
             }),
-            trusted_ca_sha256,
-            show_snippets: true,
-            note: None,
+            trusted_ca_sha256: outcome.trusted_ca_sha256,
+            show_snippets: !args.quiet,
+            note,
         },
         messages,
     );
+    if let Some(path) = reveal_path {
+        println!(
+            "{}",
+            messages.summary_secrets_revealed_to_file(&path.display().to_string())
+        );
+    }
+    Ok(())
 }
 
 async fn run_service_add_remote_idempotent(
     state: &StateFile,
     entry: &ServiceEntry,
     resolved: &ResolvedServiceAdd,
+    args: &ServiceAddArgs,
     messages: &Messages,
 ) -> Result<()> {
     let secrets_dir = state.secrets_dir();
@@ -692,27 +764,25 @@ async fn run_service_add_remote_idempotent(
         messages,
     )
     .await?;
-    print_service_add_summary(
+    let outcome = add_result::ServiceAddOutcome {
         entry,
-        &entry.approle.secret_id_path,
-        ServiceAddSummaryOptions {
-            applied: None,
-            remote: Some(ServiceAddRemoteBootstrap {
-                bootstrap_file: &remote_bootstrap.bootstrap_file,
-                remote_run_command: &remote_bootstrap.remote_run_command,
-                wrapped: remote_bootstrap.wrapped,
-            }),
-            trusted_ca_sha256: None,
-            show_snippets: true,
-            note: Some(
-                messages
-                    .service_summary_remote_idempotent_hint()
-                    .to_string(),
-            ),
-        },
+        state,
+        applied: None,
+        remote: Some(&remote_bootstrap),
+        trusted_ca_sha256: None,
+        idempotent_rerun: true,
+    };
+    report_service_add(
+        args,
+        &outcome,
+        Some(
+            messages
+                .service_summary_remote_idempotent_hint()
+                .to_string(),
+        ),
         messages,
-    );
-    Ok(())
+    )
+    .await
 }
 
 pub(crate) fn run_service_info(args: &ServiceInfoArgs, messages: &Messages) -> Result<()> {
@@ -726,10 +796,87 @@ pub(crate) fn run_service_info(args: &ServiceInfoArgs, messages: &Messages) -> R
         .services
         .get(&args.service_name)
         .ok_or_else(|| anyhow::anyhow!(messages.error_service_not_found(&args.service_name)))?;
-    print_service_info_summary(entry, messages);
+    match args.output {
+        ServiceInfoOutputFormat::Text => print_service_info_summary(entry, messages),
+        ServiceInfoOutputFormat::Json => {
+            let rendered = serde_json::to_string_pretty(&service_info_json(entry))
+                .with_context(|| messages.error_serialize_service_info_failed())?;
+            println!("{rendered}");
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn run_service_list(args: &ServiceListArgs, messages: &Messages) -> Result<()> {
+    let state_path = StateFile::default_path();
+    if !state_path.exists() {
+        anyhow::bail!(messages.error_state_missing());
+    }
+    let state =
+        StateFile::load(&state_path).with_context(|| messages.error_parse_state_failed())?;
+    let services = service_list(&state);
+    match args.output {
+        ServiceListOutputFormat::Text => {
+            println!("{}", messages.status_section_services());
+            if services.is_empty() {
+                println!("{}", messages.status_services_none());
+            }
+            for service in &services {
+                println!(
+                    "{}",
+                    messages.service_list_entry(
+                        service.service_name,
+                        &service.delivery_mode.to_string(),
+                        service.hostname,
+                        service.domain
+                    )
+                );
+            }
+        }
+        ServiceListOutputFormat::Json => {
+            let rendered = serde_json::to_string_pretty(&services)
+                .with_context(|| messages.error_serialize_service_list_failed())?;
+            println!("{rendered}");
+        }
+    }
     Ok(())
 }
 
+/// One `service list` row; also the element of `--output json`.
+#[derive(Debug, Serialize)]
+struct ServiceListEntry<'a> {
+    service_name: &'a str,
+    delivery_mode: DeliveryMode,
+    hostname: &'a str,
+    domain: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance_id: Option<&'a str>,
+}
+
+/// Summarizes the registered services in name order.
+fn service_list(state: &StateFile) -> Vec<ServiceListEntry<'_>> {
+    state
+        .services
+        .values()
+        .map(|entry| ServiceListEntry {
+            service_name: &entry.service_name,
+            delivery_mode: entry.delivery_mode,
+            hostname: &entry.hostname,
+            domain: &entry.domain,
+            instance_id: entry.instance_id.as_deref(),
+        })
+        .collect()
+}
+
+/// Returns the `service info --output json` form of `entry`: the
+/// `state.json` entry with the `role_id` masked, as `service add --output
+/// json` does. The entry only records where the `secret_id` lives.
+fn service_info_json(entry: &ServiceEntry) -> ServiceEntry {
+    let mut entry = entry.clone();
+    entry.approle.role_id = display_secret(&entry.approle.role_id, false);
+    entry
+}
+
 const INHERIT_SENTINEL: &str = "inherit";
 
 #[allow(clippy::too_many_lines)]
@@ -1180,6 +1327,7 @@ fn build_preview_service_entry(resolved: &ResolvedServiceAdd, state: &StateFile)
             secret_id_ttl: resolved.secret_id_ttl.clone(),
             secret_id_wrap_ttl: resolved.secret_id_wrap_ttl.clone(),
             token_bound_cidrs: resolved.token_bound_cidrs.clone(),
+            approle_bound_cidrs: resolved.approle_bound_cidrs.clone(),
         },
     )
 }
@@ -1205,6 +1353,7 @@ fn policy_fields_match(entry: &ServiceEntry, resolved: &ResolvedServiceAdd) -> b
     entry.approle.secret_id_ttl == resolved.secret_id_ttl
         && entry.approle.secret_id_wrap_ttl == resolved.secret_id_wrap_ttl
         && entry.approle.token_bound_cidrs == resolved.token_bound_cidrs
+        && entry.approle.approle_bound_cidrs == resolved.approle_bound_cidrs
 }
 
 fn is_idempotent_remote_rerun(entry: &ServiceEntry, resolved: &ResolvedServiceAdd) -> bool {
@@ -1226,7 +1375,7 @@ mod tests {
         OverrideCredentialRollback, ServiceAppRoleMaterialized, build_secret_id_options,
         build_service_entry, build_service_entry_from_role, display_policy_value, display_wrap_ttl,
         is_idempotent_remote_rerun, is_policy_only_mismatch, non_policy_fields_match,
-        policy_fields_match, write_origin_credential_files,
+        policy_fields_match, service_info_json, write_origin_credential_files,
     };
     use crate::i18n::{Messages, test_messages};
     use crate::state::{DeliveryMode, ServiceEntry, ServiceRoleEntry};
@@ -1359,8 +1508,10 @@ mod tests {
             secret_id_ttl: None,
             secret_id_wrap_ttl: None,
             token_bound_cidrs: None,
+            approle_bound_cidrs: None,
             agent_email: None,
             agent_server: None,
+            recorded_agent_server: None,
             agent_responder_url: None,
             cert_group_gid: None,
             secret_id_path_override: None,
@@ -1395,6 +1546,7 @@ mod tests {
             secret_id_ttl: None,
             secret_id_wrap_ttl: None,
             token_bound_cidrs: None,
+            approle_bound_cidrs: None,
         };
         let entry = build_service_entry_from_role(&resolved, role);
 
@@ -1438,6 +1590,7 @@ mod tests {
             secret_id_ttl: None,
             secret_id_wrap_ttl: None,
             token_bound_cidrs: None,
+            approle_bound_cidrs: None,
         };
         let entry = build_service_entry_from_role(&resolved, role);
 
@@ -1457,10 +1610,25 @@ mod tests {
                 secret_id_ttl: resolved.secret_id_ttl.clone(),
                 secret_id_wrap_ttl: resolved.secret_id_wrap_ttl.clone(),
                 token_bound_cidrs: resolved.token_bound_cidrs.clone(),
+                approle_bound_cidrs: resolved.approle_bound_cidrs.clone(),
             },
         )
     }
 
+    #[test]
+    fn service_info_json_masks_role_id_and_keeps_paths() {
+        let mut entry = sample_entry_from_resolved(&sample_resolved());
+        entry.approle.role_id = "role-edge-proxy-0123".to_string();
+
+        let json = serde_json::to_value(service_info_json(&entry)).unwrap();
+
+        assert_ne!(json["approle"]["role_id"], "role-edge-proxy-0123");
+        assert_eq!(json["approle"]["role_name"], "role");
+        assert_eq!(json["approle"]["secret_id_path"], "/s");
+        assert_eq!(json["service_name"], entry.service_name.as_str());
+        assert_eq!(json["delivery_mode"], "local-file");
+    }
+
     #[test]
     fn build_service_entry_persists_secret_id_policy_fields() {
         let mut resolved = sample_resolved();
@@ -0,0 +1,296 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::remove::service_kv_paths;
+use super::{LocalApplyResult, RemoteBootstrapResult, role_id_sibling_path};
+use crate::cli::output::display_secret;
+use crate::i18n::Messages;
+use crate::state::{DeliveryMode, ServiceEntry, StateFile};
+
+/// Version of the `service add --output json` schema. Bump it whenever a
+/// field is renamed, removed, or changes meaning; adding a field does
+/// not require a bump.
+pub(crate) const SERVICE_ADD_SCHEMA_VERSION: u32 = 1;
+
+/// Machine-readable outcome emitted by `service add --output json`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ServiceAddResult {
+    pub(crate) schema_version: u32,
+    pub(crate) service_name: String,
+    pub(crate) delivery_mode: DeliveryMode,
+    pub(crate) approle: ServiceAddAppRoleResult,
+    /// `<mount>/<path>` of every per-service KV secret `service add` wrote.
+    pub(crate) kv_paths: Vec<String>,
+    pub(crate) agent_config_path: String,
+    pub(crate) cert_path: String,
+    pub(crate) key_path: String,
+    /// `eab.json` written next to `secret_id`; `null` for remote-bootstrap.
+    pub(crate) eab_file: Option<String>,
+    /// Bootstrap artifact handed to the service host; `null` for local-file.
+    pub(crate) remote_bootstrap: Option<ServiceAddRemoteResult>,
+    /// `null` when the trust material was not read during this run.
+    pub(crate) trusted_ca_sha256: Option<Vec<String>>,
+    /// `true` when an identical remote-bootstrap service already existed
+    /// and only its artifact was refreshed.
+    pub(crate) idempotent_rerun: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ServiceAddAppRoleResult {
+    pub(crate) role_name: String,
+    pub(crate) policy_name: String,
+    /// Masked unless `--show-secrets` or `--reveal-to-file` is used.
+    pub(crate) role_id: String,
+    pub(crate) role_id_path: String,
+    pub(crate) secret_id_path: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ServiceAddRemoteResult {
+    pub(crate) bootstrap_file: String,
+    pub(crate) remote_run_command: String,
+    pub(crate) wrapped: bool,
+}
+
+/// Everything `service add` produced, gathered for the JSON result.
+pub(super) struct ServiceAddOutcome<'a> {
+    pub(super) entry: &'a ServiceEntry,
+    pub(super) state: &'a StateFile,
+    pub(super) applied: Option<&'a LocalApplyResult>,
+    pub(super) remote: Option<&'a RemoteBootstrapResult>,
+    pub(super) trusted_ca_sha256: Option<&'a [String]>,
+    pub(super) idempotent_rerun: bool,
+}
+
+pub(super) fn service_add_result(
+    outcome: &ServiceAddOutcome<'_>,
+    show_secrets: bool,
+) -> ServiceAddResult {
+    let entry = outcome.entry;
+    let secret_id_path = &entry.approle.secret_id_path;
+    ServiceAddResult {
+        schema_version: SERVICE_ADD_SCHEMA_VERSION,
+        service_name: entry.service_name.clone(),
+        delivery_mode: entry.delivery_mode,
+        approle: ServiceAddAppRoleResult {
+            role_name: entry.approle.role_name.clone(),
+            policy_name: entry.approle.policy_name.clone(),
+            role_id: display_secret(&entry.approle.role_id, show_secrets),
+            role_id_path: role_id_sibling_path(secret_id_path).display().to_string(),
+            secret_id_path: secret_id_path.display().to_string(),
+        },
+        kv_paths: service_kv_paths(outcome.state, entry)
+            .into_iter()
+            .map(|path| format!("{}/{path}", outcome.state.kv_mount))
+            .collect(),
+        agent_config_path: entry.agent_config_path.display().to_string(),
+        cert_path: entry.cert_path.display().to_string(),
+        key_path: entry.key_path.display().to_string(),
+        eab_file: outcome.applied.map(|applied| applied.eab_file.clone()),
+        remote_bootstrap: outcome.remote.map(|remote| ServiceAddRemoteResult {
+            bootstrap_file: remote.bootstrap_file.clone(),
+            remote_run_command: remote.remote_run_command.clone(),
+            wrapped: remote.wrapped,
+        }),
+        trusted_ca_sha256: outcome.trusted_ca_sha256.map(<[String]>::to_vec),
+        idempotent_rerun: outcome.idempotent_rerun,
+    }
+}
+
+/// Prints the masked JSON result to stdout and, for `--reveal-to-file`,
+/// writes the unmasked result to `reveal_path` (mode `0600`).
+pub(super) async fn emit_service_add_json(
+    outcome: &ServiceAddOutcome<'_>,
+    show_secrets: bool,
+    reveal_path: Option<&Path>,
+    messages: &Messages,
+) -> Result<()> {
+    if let Some(path) = reveal_path {
+        write_revealed_result(path, outcome, messages).await?;
+    }
+    let rendered = serde_json::to_string_pretty(&service_add_result(outcome, show_secrets))
+        .with_context(|| messages.error_serialize_service_add_result_failed())?;
+    println!("{rendered}");
+    Ok(())
+}
+
+/// Writes the unmasked result for `service add --reveal-to-file`.  On
+/// failure the result goes to stderr instead: stdout stays masked, so the
+/// `role_id` would otherwise be captured nowhere.
+pub(super) async fn write_revealed_result(
+    path: &Path,
+    outcome: &ServiceAddOutcome<'_>,
+    messages: &Messages,
+) -> Result<()> {
+    let mut rendered = serde_json::to_string_pretty(&service_add_result(outcome, true))
+        .with_context(|| messages.error_serialize_service_add_result_failed())?;
+    rendered.push('\n');
+    if let Err(err) = write_private_file(path, &rendered).await {
+        eprintln!(
+            "{}",
+            messages.error_reveal_to_file_failed(&path.display().to_string(), &err.to_string())
+        );
+        eprint!("{rendered}");
+        return Err(err);
+    }
+    Ok(())
+}
+
+async fn write_private_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    bootroot::fs_util::atomic_write(path, contents.as_bytes(), 0o600).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::state::ServiceRoleEntry;
+
+    fn sample_state() -> StateFile {
+        StateFile {
+            openbao_url: "http://localhost:8200".to_string(),
+            kv_mount: "secret".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn sample_entry(delivery_mode: DeliveryMode) -> ServiceEntry {
+        ServiceEntry {
+            service_name: "edge-proxy".to_string(),
+            delivery_mode,
+            hostname: "edge-node-01".to_string(),
+            domain: "trusted.domain".to_string(),
+            agent_config_path: PathBuf::from("/etc/bootroot/agent.toml"),
+            cert_path: PathBuf::from("/certs/edge-proxy.crt"),
+            key_path: PathBuf::from("/certs/edge-proxy.key"),
+            instance_id: Some("001".to_string()),
+            notes: None,
+            post_renew_hooks: Vec::new(),
+            approle: ServiceRoleEntry {
+                role_name: "bootroot-service-edge-proxy".to_string(),
+                role_id: "role-edge-proxy-0123".to_string(),
+                secret_id_path: PathBuf::from("secrets/services/edge-proxy/secret_id"),
+                policy_name: "bootroot-service-edge-proxy".to_string(),
+                secret_id_ttl: None,
+                secret_id_wrap_ttl: None,
+                token_bound_cidrs: None,
+                approle_bound_cidrs: None,
+            },
+            agent_email: None,
+            agent_server: None,
+            agent_responder_url: None,
+            cert_group_gid: None,
+            sync_generation: None,
+            trust_generation: None,
+        }
+    }
+
+    #[test]
+    fn json_result_includes_approle_role_name_and_secret_id_path() {
+        let state = sample_state();
+        let entry = sample_entry(DeliveryMode::LocalFile);
+        let applied = LocalApplyResult {
+            agent_config: "/etc/bootroot/agent.toml".to_string(),
+            eab_file: "secrets/services/edge-proxy/eab.json".to_string(),
+        };
+        let outcome = ServiceAddOutcome {
+            entry: &entry,
+            state: &state,
+            applied: Some(&applied),
+            remote: None,
+            trusted_ca_sha256: None,
+            idempotent_rerun: false,
+        };
+
+        let json = serde_json::to_value(service_add_result(&outcome, false)).unwrap();
+
+        assert_eq!(json["approle"]["role_name"], "bootroot-service-edge-proxy");
+        assert_eq!(
+            json["approle"]["secret_id_path"],
+            "secrets/services/edge-proxy/secret_id"
+        );
+        assert_eq!(
+            json["approle"]["role_id_path"],
+            "secrets/services/edge-proxy/role_id"
+        );
+        assert_eq!(json["delivery_mode"], "local-file");
+        assert_eq!(json["eab_file"], "secrets/services/edge-proxy/eab.json");
+        assert_eq!(
+            json["kv_paths"],
+            serde_json::json!([
+                "secret/bootroot/services/edge-proxy/eab",
+                "secret/bootroot/services/edge-proxy/http_responder_hmac",
+                "secret/bootroot/services/edge-proxy/trust",
+            ])
+        );
+        assert!(json["remote_bootstrap"].is_null());
+    }
+
+    #[test]
+    fn json_result_masks_role_id_unless_shown() {
+        let state = sample_state();
+        let entry = sample_entry(DeliveryMode::RemoteBootstrap);
+        let remote = RemoteBootstrapResult {
+            bootstrap_file: "secrets/remote-bootstrap/services/edge-proxy/bootstrap.json"
+                .to_string(),
+            remote_run_command: "bootroot-remote bootstrap".to_string(),
+            wrapped: true,
+        };
+        let outcome = ServiceAddOutcome {
+            entry: &entry,
+            state: &state,
+            applied: None,
+            remote: Some(&remote),
+            trusted_ca_sha256: None,
+            idempotent_rerun: true,
+        };
+
+        let masked = service_add_result(&outcome, false);
+        assert_ne!(masked.approle.role_id, "role-edge-proxy-0123");
+        assert_eq!(masked.kv_paths.len(), 4);
+        assert!(masked.idempotent_rerun);
+        assert_eq!(
+            masked.remote_bootstrap.map(|remote| remote.bootstrap_file),
+            Some("secrets/remote-bootstrap/services/edge-proxy/bootstrap.json".to_string())
+        );
+
+        let shown = service_add_result(&outcome, true);
+        assert_eq!(shown.approle.role_id, "role-edge-proxy-0123");
+    }
+
+    #[tokio::test]
+    async fn reveal_to_file_writes_unmasked_result_with_private_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out").join("service-add.json");
+        let state = sample_state();
+        let entry = sample_entry(DeliveryMode::LocalFile);
+        let outcome = ServiceAddOutcome {
+            entry: &entry,
+            state: &state,
+            applied: None,
+            remote: None,
+            trusted_ca_sha256: None,
+            idempotent_rerun: false,
+        };
+
+        write_revealed_result(&path, &outcome, &crate::i18n::test_messages())
+            .await
+            .unwrap();
+
+        let written: ServiceAddResult =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written.approle.role_id, "role-edge-proxy-0123");
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+    }
+}
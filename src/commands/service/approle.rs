@@ -7,7 +7,6 @@ use bootroot::trust_bootstrap::SERVICE_REISSUE_KV_SUFFIX;
 use tokio::fs;
 
 use super::{SERVICE_ROLE_PREFIX, ServiceAppRoleMaterialized};
-use crate::commands::constants::SERVICE_KV_BASE;
 use crate::commands::init::{SECRET_ID_TTL, TOKEN_TTL};
 use crate::i18n::Messages;
 use crate::state::StateFile;
@@ -17,11 +16,12 @@ pub(super) async fn ensure_service_approle(
     state: &StateFile,
     service_name: &str,
     secret_id_options: &SecretIdOptions,
+    bound_cidrs: &[String],
     wrap_ttl: Option<&str>,
     messages: &Messages,
 ) -> Result<ServiceAppRoleMaterialized> {
     let policy_name = service_policy_name(service_name);
-    let policy = build_service_policy(&state.kv_mount, service_name);
+    let policy = build_service_policy(&state.kv_mount, &state.service_kv_base(), service_name);
     client
         .write_policy(&policy_name, &policy)
         .await
@@ -35,6 +35,7 @@ pub(super) async fn ensure_service_approle(
             TOKEN_TTL,
             SECRET_ID_TTL,
             true,
+            bound_cidrs,
         )
         .await
         .with_context(|| messages.error_openbao_approle_create_failed())?;
@@ -72,7 +73,7 @@ pub(super) async fn reapply_service_policy(
     messages: &Messages,
 ) -> Result<()> {
     let policy_name = service_policy_name(service_name);
-    let policy = build_service_policy(&state.kv_mount, service_name);
+    let policy = build_service_policy(&state.kv_mount, &state.service_kv_base(), service_name);
     client
         .write_policy(&policy_name, &policy)
         .await
@@ -80,8 +81,8 @@ pub(super) async fn reapply_service_policy(
     Ok(())
 }
 
-fn build_service_policy(kv_mount: &str, service_name: &str) -> String {
-    let base = format!("{SERVICE_KV_BASE}/{service_name}");
+fn build_service_policy(kv_mount: &str, service_kv_base: &str, service_name: &str) -> String {
+    let base = format!("{service_kv_base}/{service_name}");
     // The service subtree is read-only except for its own reissue object: the
     // fast-poll loop must write `completed_at`/`completed_version` back so the
     // control plane's `rotate force-reissue --wait` can observe completion.
@@ -163,6 +164,7 @@ async fn write_service_credential_file(
 mod tests {
     use std::os::unix::fs::PermissionsExt;
 
+    use bootroot::trust_bootstrap::SERVICE_KV_BASE;
     use tempfile::tempdir;
 
     use super::*;
@@ -226,7 +228,7 @@ mod tests {
 
     #[test]
     fn service_policy_grants_write_only_on_reissue_path() {
-        let policy = build_service_policy("secret", "edge-proxy");
+        let policy = build_service_policy("secret", SERVICE_KV_BASE, "edge-proxy");
 
         assert!(
             policy.contains(
@@ -250,7 +252,7 @@ mod tests {
 
     #[test]
     fn service_policy_grants_no_broader_write_scope() {
-        let policy = build_service_policy("secret", "edge-proxy");
+        let policy = build_service_policy("secret", SERVICE_KV_BASE, "edge-proxy");
 
         // Only the reissue rule may carry create/update; no other rule may.
         for block in policy.split("path ").filter(|b| !b.is_empty()) {
@@ -263,4 +265,13 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn service_policy_follows_custom_kv_base() {
+        let policy = build_service_policy("secret", "tenants/acme/services", "edge-proxy");
+
+        assert!(policy.contains("\"secret/data/tenants/acme/services/edge-proxy/reissue\""));
+        assert!(policy.contains("\"secret/data/tenants/acme/services/edge-proxy/*\""));
+        assert!(!policy.contains("bootroot/services"), "got:\n{policy}");
+    }
 }
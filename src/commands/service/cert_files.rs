@@ -0,0 +1,164 @@
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+use bootroot::cert_group::KEY_FILE_MODE_GROUP;
+use bootroot::fs_util::KEY_FILE_MODE;
+
+use crate::i18n::Messages;
+use crate::state::{DeliveryMode, ServiceEntry};
+
+/// What a quick look at one issued file on this host found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FileCheck {
+    Present,
+    Missing,
+    /// The file exists but its mode differs from what the agent writes.
+    WrongMode {
+        mode: u32,
+        expected: u32,
+    },
+}
+
+impl FileCheck {
+    pub(crate) fn describe(self, messages: &Messages) -> String {
+        match self {
+            Self::Present => messages.cert_file_present().to_string(),
+            Self::Missing => messages.cert_file_missing().to_string(),
+            Self::WrongMode { mode, expected } => {
+                messages.key_file_wrong_mode(&format!("{mode:04o}"), &format!("{expected:04o}"))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CertFilesCheck {
+    pub(crate) cert: FileCheck,
+    pub(crate) key: FileCheck,
+}
+
+/// Checks that a local-file service's cert and key exist and that the
+/// key carries the mode its `--cert-group` policy implies, so a
+/// never-issued certificate shows up without running `verify`.
+/// Returns `None` for remote-bootstrap services, whose files live on the
+/// edge node.
+pub(crate) fn check_cert_files(entry: &ServiceEntry) -> Option<CertFilesCheck> {
+    if matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap) {
+        return None;
+    }
+    let expected_key_mode = if entry.cert_group_gid.is_some() {
+        KEY_FILE_MODE_GROUP
+    } else {
+        KEY_FILE_MODE
+    };
+    Some(CertFilesCheck {
+        cert: check_file(&entry.cert_path, None),
+        key: check_file(&entry.key_path, Some(expected_key_mode)),
+    })
+}
+
+fn check_file(path: &Path, expected_mode: Option<u32>) -> FileCheck {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return FileCheck::Missing;
+    };
+    if !metadata.is_file() {
+        return FileCheck::Missing;
+    }
+    let mode = metadata.permissions().mode() & 0o777;
+    match expected_mode {
+        Some(expected) if mode != expected => FileCheck::WrongMode { mode, expected },
+        _ => FileCheck::Present,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::i18n::test_messages;
+    use crate::state::ServiceRoleEntry;
+
+    fn local_entry(cert_path: PathBuf, key_path: PathBuf) -> ServiceEntry {
+        ServiceEntry {
+            service_name: "edge-proxy".to_string(),
+            delivery_mode: DeliveryMode::LocalFile,
+            hostname: "edge-node-01".to_string(),
+            domain: "trusted.domain".to_string(),
+            agent_config_path: PathBuf::from("agent.toml"),
+            cert_path,
+            key_path,
+            instance_id: Some("001".to_string()),
+            notes: None,
+            post_renew_hooks: Vec::new(),
+            approle: ServiceRoleEntry {
+                role_name: "bootroot-service-edge-proxy".to_string(),
+                role_id: "role-id".to_string(),
+                secret_id_path: PathBuf::from("secret_id"),
+                policy_name: "bootroot-service-edge-proxy".to_string(),
+                secret_id_ttl: None,
+                secret_id_wrap_ttl: None,
+                token_bound_cidrs: None,
+                approle_bound_cidrs: None,
+            },
+            agent_email: None,
+            agent_server: None,
+            agent_responder_url: None,
+            cert_group_gid: None,
+            sync_generation: None,
+            trust_generation: None,
+        }
+    }
+
+    #[test]
+    fn missing_key_is_reported_next_to_present_cert() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cert_path = dir.path().join("edge-proxy.crt");
+        fs::write(&cert_path, "cert").expect("write cert");
+        let entry = local_entry(cert_path, dir.path().join("edge-proxy.key"));
+
+        let check = check_cert_files(&entry).expect("local entries are checked");
+        assert_eq!(check.cert, FileCheck::Present);
+        assert_eq!(check.key, FileCheck::Missing);
+        let messages = test_messages();
+        assert_eq!(check.key.describe(&messages), "missing");
+    }
+
+    #[test]
+    fn key_mode_follows_cert_group_policy() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cert_path = dir.path().join("edge-proxy.crt");
+        let key_path = dir.path().join("edge-proxy.key");
+        fs::write(&cert_path, "cert").expect("write cert");
+        fs::write(&key_path, "key").expect("write key");
+        fs::set_permissions(&key_path, fs::Permissions::from_mode(KEY_FILE_MODE_GROUP))
+            .expect("chmod key");
+        let mut entry = local_entry(cert_path, key_path);
+
+        let check = check_cert_files(&entry).expect("local entries are checked");
+        assert_eq!(
+            check.key,
+            FileCheck::WrongMode {
+                mode: KEY_FILE_MODE_GROUP,
+                expected: KEY_FILE_MODE,
+            }
+        );
+        let messages = test_messages();
+        assert_eq!(
+            check.key.describe(&messages),
+            "present, mode 0640 (expected 0600)"
+        );
+
+        entry.cert_group_gid = Some(1000);
+        let check = check_cert_files(&entry).expect("local entries are checked");
+        assert_eq!(check.key, FileCheck::Present);
+    }
+
+    #[test]
+    fn remote_bootstrap_entries_are_not_checked_locally() {
+        let mut entry = local_entry(PathBuf::from("missing.crt"), PathBuf::from("missing.key"));
+        entry.delivery_mode = DeliveryMode::RemoteBootstrap;
+        assert_eq!(check_cert_files(&entry), None);
+    }
+}
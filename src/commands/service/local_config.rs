@@ -19,7 +19,7 @@ use super::{
     effective_agent_responder_url, effective_agent_server,
 };
 use crate::i18n::Messages;
-use crate::state::PostRenewHookEntry;
+use crate::state::{PostRenewHookEntry, StateFile};
 
 #[allow(clippy::too_many_lines)]
 pub(super) async fn apply_local_service_configs(
@@ -27,8 +27,7 @@ pub(super) async fn apply_local_service_configs(
     resolved: &ResolvedServiceAdd,
     secret_id_path: &Path,
     sync_material: &ServiceSyncMaterial,
-    kv_mount: &str,
-    openbao_url: &str,
+    state: &StateFile,
     messages: &Messages,
 ) -> Result<LocalApplyResult> {
     // The relocation flag is fully determined by whether the operator
@@ -71,7 +70,10 @@ pub(super) async fn apply_local_service_configs(
         &current,
         &AgentConfigBaselineParams {
             email: effective_agent_email(resolved.agent_email.as_deref()),
-            server: effective_agent_server(resolved.agent_server.as_deref()),
+            server: effective_agent_server(
+                resolved.agent_server.as_deref(),
+                resolved.recorded_agent_server.as_deref(),
+            ),
             domain: &resolved.domain,
             http_responder_url: effective_agent_responder_url(
                 resolved.agent_responder_url.as_deref(),
@@ -114,9 +116,11 @@ pub(super) async fn apply_local_service_configs(
         .parent()
         .unwrap_or(Path::new("."))
         .join(SERVICE_ROLE_ID_FILENAME);
+    let service_kv_base = state.service_kv_base();
     let openbao_updates = build_local_openbao_updates(&LocalOpenBaoUpdateInputs {
-        openbao_url,
-        kv_mount,
+        openbao_url: &state.openbao_url,
+        kv_mount: &state.kv_mount,
+        service_kv_base: &service_kv_base,
         role_id_path: &role_id_path,
         secret_id_path,
         ca_bundle_path: &ca_bundle_path,
@@ -185,6 +189,7 @@ pub(super) async fn apply_local_service_configs(
 struct LocalOpenBaoUpdateInputs<'a> {
     openbao_url: &'a str,
     kv_mount: &'a str,
+    service_kv_base: &'a str,
     role_id_path: &'a Path,
     secret_id_path: &'a Path,
     ca_bundle_path: &'a Path,
@@ -209,6 +214,7 @@ fn build_local_openbao_updates(
     let mut pairs = vec![
         ("url", inputs.openbao_url.to_string()),
         ("kv_mount", inputs.kv_mount.to_string()),
+        ("service_kv_base", inputs.service_kv_base.to_string()),
         ("role_id_path", inputs.role_id_path.display().to_string()),
         (
             "secret_id_path",
@@ -477,14 +483,24 @@ mod tests {
             secret_id_ttl: None,
             secret_id_wrap_ttl: None,
             token_bound_cidrs: None,
+            approle_bound_cidrs: None,
             agent_email: None,
             agent_server: None,
+            recorded_agent_server: None,
             agent_responder_url: None,
             cert_group_gid: None,
             secret_id_path_override: None,
         }
     }
 
+    fn test_state() -> StateFile {
+        StateFile {
+            openbao_url: "https://openbao.internal:8200".to_string(),
+            kv_mount: "secret".to_string(),
+            ..Default::default()
+        }
+    }
+
     fn test_sync_material() -> ServiceSyncMaterial {
         ServiceSyncMaterial {
             eab_kid: None,
@@ -492,6 +508,7 @@ mod tests {
             responder_hmac: "hmac-val".to_string(),
             trusted_ca_sha256: vec!["a".repeat(64)],
             ca_bundle_pem: "PEM".to_string(),
+            trust_generation: 1,
         }
     }
 
@@ -635,6 +652,125 @@ mod tests {
         );
     }
 
+    /// `service add` in local-file mode writes a ready-to-run `agent.toml`
+    /// (`0600`) on a fresh host: the agent can start from it without any
+    /// further edits.
+    #[tokio::test]
+    async fn test_apply_local_service_configs_writes_ready_agent_config() {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let dir = tempfile::tempdir().unwrap();
+        let secrets_dir = dir.path().join("secrets");
+        let secret_id_path = secrets_dir.join("services/edge-proxy/secret_id");
+        std::fs::create_dir_all(secret_id_path.parent().unwrap()).unwrap();
+        let mut resolved = test_resolved();
+        resolved.agent_config = dir.path().join("etc/agent.toml");
+        resolved.cert_path = dir.path().join("certs/edge-proxy.crt");
+        resolved.key_path = dir.path().join("certs/edge-proxy.key");
+        let mut material = test_sync_material();
+        material.eab_kid = Some("kid-1".to_string());
+        material.eab_hmac = Some("hmac-1".to_string());
+        let messages = crate::i18n::test_messages();
+
+        let result = apply_local_service_configs(
+            &secrets_dir,
+            &resolved,
+            &secret_id_path,
+            &material,
+            &test_state(),
+            &messages,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result.agent_config,
+            resolved.agent_config.display().to_string()
+        );
+        let rendered = std::fs::read_to_string(&resolved.agent_config).unwrap();
+        assert!(
+            rendered.contains("domain = \"trusted.domain\""),
+            "missing domain: {rendered}"
+        );
+        assert!(
+            rendered.contains(&format!("cert = \"{}\"", resolved.cert_path.display())),
+            "missing cert path: {rendered}"
+        );
+        assert!(
+            rendered.contains(&format!("server = \"{DEFAULT_AGENT_SERVER}\"")),
+            "missing ACME server: {rendered}"
+        );
+        assert!(
+            rendered.contains("http_responder_hmac = \"hmac-val\""),
+            "missing responder HMAC: {rendered}"
+        );
+        assert!(
+            rendered.contains("[openbao]"),
+            "missing [openbao]: {rendered}"
+        );
+        assert!(
+            std::path::Path::new(&result.eab_file).exists(),
+            "eab.json must be provisioned next to the secret_id"
+        );
+        assert_eq!(
+            std::fs::metadata(&resolved.agent_config)
+                .unwrap()
+                .permissions()
+                .mode()
+                & 0o777,
+            fs_util::KEY_FILE_MODE
+        );
+    }
+
+    /// A service added after `bootroot init` picks up the ACME directory
+    /// URL init recorded for the step-ca bind address and provisioner;
+    /// an explicit `--ca-url` still wins.
+    #[tokio::test]
+    async fn test_apply_local_service_configs_uses_recorded_acme_directory_url() {
+        let recorded = crate::commands::guardrails::stepca_acme_directory_url(
+            Some("192.168.1.10:9000"),
+            "edge",
+        );
+        let messages = crate::i18n::test_messages();
+
+        for (explicit, expected) in [
+            (None, recorded.as_str()),
+            (
+                Some("https://ca.internal/acme/acme/directory"),
+                "https://ca.internal/acme/acme/directory",
+            ),
+        ] {
+            let dir = tempfile::tempdir().unwrap();
+            let secrets_dir = dir.path().join("secrets");
+            let secret_id_path = secrets_dir.join("services/edge-proxy/secret_id");
+            std::fs::create_dir_all(secret_id_path.parent().unwrap()).unwrap();
+            let mut resolved = test_resolved();
+            resolved.agent_config = dir.path().join("agent.toml");
+            resolved.cert_path = dir.path().join("certs/edge-proxy.crt");
+            resolved.key_path = dir.path().join("certs/edge-proxy.key");
+            resolved.agent_server = explicit.map(str::to_string);
+            resolved.recorded_agent_server = Some(recorded.clone());
+
+            apply_local_service_configs(
+                &secrets_dir,
+                &resolved,
+                &secret_id_path,
+                &test_sync_material(),
+                &test_state(),
+                &messages,
+            )
+            .await
+            .unwrap();
+
+            let rendered = std::fs::read_to_string(&resolved.agent_config).unwrap();
+            assert!(
+                rendered.contains(&format!("server = \"{expected}\"")),
+                "expected server {expected}: {rendered}"
+            );
+        }
+        assert_eq!(recorded, "https://192.168.1.10:9000/acme/edge/directory");
+    }
+
     #[test]
     fn test_generated_config_includes_domain_and_acme() {
         let args = test_resolved();
@@ -701,6 +837,7 @@ mod tests {
         let updates = build_local_openbao_updates(&LocalOpenBaoUpdateInputs {
             openbao_url: "https://localhost:8200",
             kv_mount: "secret",
+            service_kv_base: "bootroot/services",
             role_id_path: Path::new("secrets/services/edge-proxy/role_id"),
             secret_id_path: Path::new("secrets/services/edge-proxy/secret_id"),
             ca_bundle_path: Path::new("certs/ca-bundle.pem"),
@@ -718,6 +855,7 @@ mod tests {
         };
         assert_eq!(get("url"), Some("https://localhost:8200"));
         assert_eq!(get("kv_mount"), Some("secret"));
+        assert_eq!(get("service_kv_base"), Some("bootroot/services"));
         assert_eq!(
             get("role_id_path"),
             Some("secrets/services/edge-proxy/role_id")
@@ -738,6 +876,7 @@ mod tests {
         build_local_openbao_updates(&LocalOpenBaoUpdateInputs {
             openbao_url: url,
             kv_mount: "secret",
+            service_kv_base: "bootroot/services",
             role_id_path: Path::new("secrets/services/edge-proxy/role_id"),
             secret_id_path: Path::new("secrets/services/edge-proxy/secret_id"),
             ca_bundle_path: Path::new("certs/ca-bundle.pem"),
@@ -785,6 +924,7 @@ mod tests {
         let updates = build_local_openbao_updates(&LocalOpenBaoUpdateInputs {
             openbao_url: "http://localhost:8200",
             kv_mount: "secret",
+            service_kv_base: "bootroot/services",
             role_id_path: Path::new("secrets/services/edge-proxy/role_id"),
             secret_id_path: Path::new("secrets/services/edge-proxy/secret_id"),
             ca_bundle_path: Path::new("certs/ca-bundle.pem"),
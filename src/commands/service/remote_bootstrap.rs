@@ -34,6 +34,9 @@ struct RemoteBootstrapArtifact {
     schema_version: u32,
     openbao_url: String,
     kv_mount: String,
+    /// KV base the service's payloads live under (`<kv_prefix>/services`),
+    /// so the remote agent polls the same tree `rotate` writes.
+    service_kv_base: String,
     service_name: String,
     role_id_path: String,
     secret_id_path: String,
@@ -138,6 +141,7 @@ fn fingerprints_from_bundle(ca_bundle_pem: &str) -> Vec<String> {
 fn build_artifact(
     openbao_url: &str,
     kv_mount: &str,
+    service_kv_base: &str,
     service_name: &str,
     secret_id_path: &Path,
     agent_config_path: &Path,
@@ -171,6 +175,7 @@ fn build_artifact(
         schema_version: 4,
         openbao_url: openbao_url.to_string(),
         kv_mount: kv_mount.to_string(),
+        service_kv_base: service_kv_base.to_string(),
         service_name: service_name.to_string(),
         role_id_path: role_id_path.display().to_string(),
         secret_id_path: secret_id_path.display().to_string(),
@@ -207,6 +212,7 @@ pub(super) async fn write_remote_bootstrap_artifact(
     let artifact = build_artifact(
         &artifact_url,
         &state.kv_mount,
+        &state.service_kv_base(),
         &resolved.service_name,
         secret_id_path,
         &resolved.agent_config,
@@ -239,6 +245,7 @@ pub(super) async fn write_remote_bootstrap_artifact_from_entry(
     let artifact = build_artifact(
         &artifact_url,
         &state.kv_mount,
+        &state.service_kv_base(),
         &entry.service_name,
         &entry.approle.secret_id_path,
         &entry.agent_config_path,
@@ -306,9 +313,10 @@ fn render_remote_run_command_per_field(artifact: &RemoteBootstrapArtifact) -> St
     use std::fmt::Write as _;
 
     let mut cmd = format!(
-        "bootroot-remote bootstrap --openbao-url '{}' --kv-mount '{}' --service-name '{}' --role-id-path '{}' --secret-id-path '{}' --eab-file-path '{}' --agent-config-path '{}' --agent-email '{}' --agent-server '{}' --agent-domain '{}' --agent-responder-url '{}' --profile-hostname '{}' --profile-instance-id '{}' --profile-cert-path '{}' --profile-key-path '{}' --ca-bundle-path '{}'",
+        "bootroot-remote bootstrap --openbao-url '{}' --kv-mount '{}' --service-kv-base '{}' --service-name '{}' --role-id-path '{}' --secret-id-path '{}' --eab-file-path '{}' --agent-config-path '{}' --agent-email '{}' --agent-server '{}' --agent-domain '{}' --agent-responder-url '{}' --profile-hostname '{}' --profile-instance-id '{}' --profile-cert-path '{}' --profile-key-path '{}' --ca-bundle-path '{}'",
         artifact.openbao_url,
         artifact.kv_mount,
+        artifact.service_kv_base,
         artifact.service_name,
         artifact.role_id_path,
         artifact.secret_id_path,
@@ -352,6 +360,8 @@ fn render_remote_run_command_per_field(artifact: &RemoteBootstrapArtifact) -> St
 mod tests {
     use std::path::Path;
 
+    use bootroot::trust_bootstrap::SERVICE_KV_BASE;
+
     use super::{build_artifact, fingerprints_from_bundle};
 
     const TEST_CA_PEM: &str = "-----BEGIN CERTIFICATE-----\ntest\n-----END CERTIFICATE-----\n";
@@ -391,6 +401,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -421,6 +432,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -451,6 +463,7 @@ mod tests {
         let artifact = build_artifact(
             "https://openbao.example.com:8200",
             "secret",
+            SERVICE_KV_BASE,
             "my-service",
             Path::new("/secrets/services/my-service/secret_id"),
             Path::new("/etc/my-service/agent.toml"),
@@ -513,6 +526,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -560,6 +574,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -601,6 +616,7 @@ mod tests {
         let artifact = build_artifact(
             "https://openbao.local",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -626,6 +642,7 @@ mod tests {
         let a = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "alpha",
             Path::new("/secrets/services/alpha/secret_id"),
             Path::new("/etc/alpha/agent.toml"),
@@ -645,6 +662,7 @@ mod tests {
         let b = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "beta",
             Path::new("/secrets/services/beta/secret_id"),
             Path::new("/etc/beta/agent.toml"),
@@ -672,6 +690,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -699,6 +718,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/secrets/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -733,6 +753,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -768,6 +789,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -821,6 +843,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -854,6 +877,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -883,6 +907,7 @@ mod tests {
         let with_empty = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -902,6 +927,7 @@ mod tests {
         let with_none = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -949,6 +975,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -982,6 +1009,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -1015,6 +1043,7 @@ mod tests {
         let artifact = build_artifact(
             "https://ob",
             "kv",
+            SERVICE_KV_BASE,
             "svc",
             Path::new("/s/services/svc/secret_id"),
             Path::new("/etc/svc/agent.toml"),
@@ -10,7 +10,7 @@ use super::{
 };
 use crate::cli::args::ServiceRemoveArgs;
 use crate::cli::prompt::Prompt;
-use crate::commands::constants::SERVICE_KV_BASE;
+use crate::commands::cancellation;
 use crate::commands::dns_alias::reconcile_dns_aliases;
 use crate::commands::openbao_auth::{authenticate_openbao_client, resolve_runtime_auth};
 use crate::commands::trust::SERVICE_TRUST_KV_SUFFIX;
@@ -72,7 +72,7 @@ pub(crate) async fn run_service_remove(
     let mut state = load_state_or_missing(&state_path, messages)?;
     let entry = require_service_entry(&state, &args.service_name, messages)?;
 
-    let kv_paths = service_kv_paths(&entry);
+    let kv_paths = service_kv_paths(&state, &entry);
     let artifacts = if args.delete_artifacts {
         Some(build_artifact_plan(&state, &entry))
     } else {
@@ -112,16 +112,21 @@ pub(crate) async fn run_service_remove(
     // Remote cleanup FIRST — the AppRole, policy, and KV deletions must
     // all complete before the state.json entry is dropped, so a partial
     // failure leaves the stored role/policy names available for a re-run.
+    // The same holds for a cancellation: every step is idempotent and the
+    // entry is only dropped at the end, so stopping between steps is safe.
     for path in &kv_paths {
+        cancellation::global().check(messages)?;
         let result = delete_kv_if_present(&client, &state.kv_mount, path).await;
         report.record(&format!("KV {path}"), result, messages);
     }
+    cancellation::global().check(messages)?;
     let approle_result = delete_approle_if_present(&client, &entry.approle.role_name).await;
     report.record(
         &format!("AppRole {}", entry.approle.role_name),
         approle_result,
         messages,
     );
+    cancellation::global().check(messages)?;
     let policy_result = delete_policy_if_present(&client, &entry.approle.policy_name).await;
     report.record(
         &format!("policy {}", entry.approle.policy_name),
@@ -131,6 +136,7 @@ pub(crate) async fn run_service_remove(
 
     // On-disk artifacts (opt-in). These are local, but a failure here
     // also keeps the entry so a re-run can retry.
+    cancellation::global().check(messages)?;
     if let Some(plan) = artifacts.as_ref() {
         for dir in &plan.dirs {
             let result = delete_dir_if_present(dir);
@@ -231,15 +237,15 @@ fn finalize_removal(
 }
 
 /// Builds the exact per-service KV paths written by `service add`.
-fn service_kv_paths(entry: &ServiceEntry) -> Vec<String> {
-    let base = format!("{SERVICE_KV_BASE}/{}", entry.service_name);
+pub(super) fn service_kv_paths(state: &StateFile, entry: &ServiceEntry) -> Vec<String> {
+    let name = entry.service_name.as_str();
     let mut paths = vec![
-        format!("{base}/{KV_EAB_SUFFIX}"),
-        format!("{base}/{KV_HTTP_RESPONDER_HMAC_SUFFIX}"),
-        format!("{base}/{SERVICE_TRUST_KV_SUFFIX}"),
+        state.service_kv_path(name, KV_EAB_SUFFIX),
+        state.service_kv_path(name, KV_HTTP_RESPONDER_HMAC_SUFFIX),
+        state.service_kv_path(name, SERVICE_TRUST_KV_SUFFIX),
     ];
     if matches!(entry.delivery_mode, DeliveryMode::RemoteBootstrap) {
-        paths.push(format!("{base}/{KV_SECRET_ID_SUFFIX}"));
+        paths.push(state.service_kv_path(name, KV_SECRET_ID_SUFFIX));
     }
     paths
 }
@@ -476,11 +482,14 @@ mod tests {
                 secret_id_ttl: None,
                 secret_id_wrap_ttl: None,
                 token_bound_cidrs: None,
+                approle_bound_cidrs: None,
             },
             agent_email: None,
             agent_server: None,
             agent_responder_url: None,
             cert_group_gid: None,
+            sync_generation: None,
+            trust_generation: None,
         }
     }
 
@@ -626,7 +635,7 @@ mod tests {
     #[test]
     fn service_kv_paths_local_file_omits_secret_id() {
         let entry = sample_entry("svc", DeliveryMode::LocalFile);
-        let paths = service_kv_paths(&entry);
+        let paths = service_kv_paths(&sample_state(), &entry);
         assert_eq!(
             paths,
             vec![
@@ -640,11 +649,25 @@ mod tests {
     #[test]
     fn service_kv_paths_remote_bootstrap_includes_secret_id() {
         let entry = sample_entry("svc", DeliveryMode::RemoteBootstrap);
-        let paths = service_kv_paths(&entry);
+        let paths = service_kv_paths(&sample_state(), &entry);
         assert!(paths.contains(&"bootroot/services/svc/secret_id".to_string()));
         assert_eq!(paths.len(), 4);
     }
 
+    #[test]
+    fn service_kv_paths_follow_recorded_kv_prefix() {
+        let mut state = sample_state();
+        state.kv_prefix = Some("tenants/acme".to_string());
+        let entry = sample_entry("svc", DeliveryMode::RemoteBootstrap);
+        let paths = service_kv_paths(&state, &entry);
+        assert!(
+            paths
+                .iter()
+                .all(|path| path.starts_with("tenants/acme/services/svc/")),
+            "got: {paths:?}"
+        );
+    }
+
     #[test]
     fn delete_dir_if_present_reports_absent_then_removed() {
         let dir = tempdir().expect("tempdir");
@@ -9,6 +9,8 @@ use bootroot::input_validation::{
 use crate::cli::args::{HookFailurePolicyArg, ReloadStyle, ServiceAddArgs};
 use crate::cli::prompt::Prompt;
 use crate::commands::constants::DEFAULT_SECRET_ID_WRAP_TTL;
+use crate::commands::container_runtime;
+use crate::commands::init::validate_approle_bind_cidrs;
 use crate::commands::openbao_auth::{
     RuntimeAuthResolved, resolve_runtime_auth, resolve_runtime_auth_optional,
 };
@@ -33,6 +35,7 @@ pub(crate) struct ResolvedServiceAdd {
     pub(crate) secret_id_ttl: Option<String>,
     pub(crate) secret_id_wrap_ttl: Option<String>,
     pub(crate) token_bound_cidrs: Option<Vec<String>>,
+    pub(crate) approle_bound_cidrs: Option<Vec<String>>,
     /// Operator-supplied ACME account email.  `None` means
     /// `--agent-email` was not provided on `service add`; renderers
     /// fall back to [`DEFAULT_AGENT_EMAIL`].  Preserved as `Option`
@@ -43,6 +46,10 @@ pub(crate) struct ResolvedServiceAdd {
     /// `--agent-server` was not provided; renderers fall back to
     /// [`DEFAULT_AGENT_SERVER`].
     pub(crate) agent_server: Option<String>,
+    /// ACME directory URL `bootroot init` recorded in `state.json`.
+    /// Local-file baselines fall back to it when `--agent-server` is
+    /// not given.
+    pub(crate) recorded_agent_server: Option<String>,
     /// Operator-supplied HTTP-01 responder admin URL.  `None` means
     /// `--agent-responder-url` was not provided; renderers fall back
     /// to [`DEFAULT_AGENT_RESPONDER_URL`].
@@ -153,6 +160,9 @@ pub(super) fn resolve_service_add_args(
     } else {
         Some(args.rn_cidrs.clone())
     };
+    validate_approle_bind_cidrs(&args.approle_bind_cidr, messages)?;
+    let approle_bound_cidrs =
+        (!args.approle_bind_cidr.is_empty()).then(|| args.approle_bind_cidr.clone());
 
     let agent_email = args.agent_email.clone();
     let agent_server = args.agent_server.clone();
@@ -178,8 +188,10 @@ pub(super) fn resolve_service_add_args(
         secret_id_ttl: args.secret_id_ttl.clone(),
         secret_id_wrap_ttl,
         token_bound_cidrs,
+        approle_bound_cidrs,
         agent_email,
         agent_server,
+        recorded_agent_server: None,
         agent_responder_url,
         cert_group_gid,
         secret_id_path_override,
@@ -558,7 +570,7 @@ fn resolve_reload_preset(
                 )
             })?;
             Ok(vec![PostRenewHookEntry {
-                command: "docker".to_string(),
+                command: container_runtime::selected().binary().to_string(),
                 args: vec!["restart".to_string(), container.to_string()],
                 timeout_secs: DEFAULT_HOOK_TIMEOUT_SECS,
                 on_failure: HookFailurePolicyEntry::default(),
@@ -641,7 +653,7 @@ mod tests {
     use tempfile::tempdir;
 
     use super::*;
-    use crate::cli::args::{AuthMode, RuntimeAuthArgs};
+    use crate::cli::args::{AuthMode, ContainerRuntime, RuntimeAuthArgs, ServiceAddOutputFormat};
 
     fn empty_args() -> ServiceAddArgs {
         ServiceAddArgs {
@@ -679,7 +691,12 @@ mod tests {
             secret_id_wrap_ttl: None,
             no_wrap: false,
             rn_cidrs: Vec::new(),
+            approle_bind_cidr: Vec::new(),
             cert_group: None,
+            output: ServiceAddOutputFormat::Text,
+            quiet: false,
+            show_secrets: false,
+            reveal_to_file: None,
         }
     }
 
@@ -881,6 +898,18 @@ mod tests {
         assert_eq!(hooks[0].args, vec!["restart", "my-ctr"]);
     }
 
+    #[test]
+    fn resolve_hooks_docker_restart_preset_uses_selected_runtime() {
+        let _runtime = container_runtime::ScopedRuntime::set(ContainerRuntime::Podman);
+        let mut args = empty_args();
+        args.reload_style = Some(ReloadStyle::DockerRestart);
+        args.reload_target = Some("my-ctr".to_string());
+
+        let hooks = resolve_post_renew_hooks(&args).unwrap();
+        assert_eq!(hooks[0].command, "podman");
+        assert_eq!(hooks[0].args, vec!["restart", "my-ctr"]);
+    }
+
     #[test]
     fn resolve_hooks_none_preset_returns_empty() {
         let mut args = empty_args();
@@ -1,11 +1,14 @@
 use anyhow::{Context, Result};
 use bootroot::openbao::OpenBaoClient;
+use bootroot::trust_bootstrap::{
+    SERVICE_EAB_KV_SUFFIX, SERVICE_SECRET_ID_KV_SUFFIX, SERVICE_TRUST_KV_SUFFIX,
+};
 
 use super::resolve::ResolvedServiceAdd;
-use super::{CaTrustMaterial, SERVICE_CA_BUNDLE_PEM_KEY, ServiceSyncMaterial};
+use super::{CaTrustMaterial, ServiceSyncMaterial};
 use crate::commands::constants::{
-    CA_TRUST_KEY, SERVICE_EAB_HMAC_KEY, SERVICE_EAB_KID_KEY, SERVICE_KV_BASE,
-    SERVICE_RESPONDER_HMAC_KEY, SERVICE_SECRET_ID_KEY,
+    SERVICE_EAB_HMAC_KEY, SERVICE_EAB_KID_KEY, SERVICE_RESPONDER_HMAC_KEY,
+    SERVICE_RESPONDER_HMAC_KV_SUFFIX, SERVICE_SECRET_ID_KEY,
 };
 use crate::commands::init::{PATH_AGENT_EAB, PATH_CA_TRUST, PATH_RESPONDER_HMAC};
 use crate::i18n::Messages;
@@ -19,20 +22,12 @@ pub(super) async fn sync_service_kv_bundle(
     messages: &Messages,
 ) -> Result<ServiceSyncMaterial> {
     let material = read_service_sync_material(client, &state.kv_mount, messages).await?;
-    write_service_kv_secrets(
-        client,
-        &state.kv_mount,
-        &resolved.service_name,
-        &material,
-        messages,
-    )
-    .await?;
+    write_service_kv_secrets(client, state, &resolved.service_name, &material, messages).await?;
     if matches!(resolved.delivery_mode, DeliveryMode::RemoteBootstrap) {
-        let base = format!("{SERVICE_KV_BASE}/{}", resolved.service_name);
         client
             .write_kv(
                 &state.kv_mount,
-                &format!("{base}/secret_id"),
+                &state.service_kv_path(&resolved.service_name, SERVICE_SECRET_ID_KV_SUFFIX),
                 serde_json::json!({ SERVICE_SECRET_ID_KEY: secret_id }),
             )
             .await
@@ -73,7 +68,7 @@ async fn read_service_sync_material(
             )
         })?;
     let responder_hmac = client
-        .read_kv(kv_mount, PATH_RESPONDER_HMAC)
+        .read_responder_hmac(kv_mount)
         .await
         .with_context(|| {
             format!(
@@ -81,24 +76,12 @@ async fn read_service_sync_material(
                 messages.error_openbao_kv_read_failed()
             )
         })?;
-    let trust = client
-        .read_kv(kv_mount, PATH_CA_TRUST)
-        .await
-        .with_context(|| {
-            format!(
-                "{} ({PATH_CA_TRUST})",
-                messages.error_openbao_kv_read_failed()
-            )
-        })?;
-    let trusted_ca_sha256 = parse_trusted_ca_list(
-        trust
-            .get(CA_TRUST_KEY)
-            .ok_or_else(|| anyhow::anyhow!(messages.error_ca_trust_missing(CA_TRUST_KEY)))?,
-        messages,
-    )?;
-    if trusted_ca_sha256.is_empty() {
-        anyhow::bail!(messages.error_ca_trust_empty());
-    }
+    let trust = client.read_ca_trust(kv_mount).await.with_context(|| {
+        format!(
+            "{} ({PATH_CA_TRUST})",
+            messages.error_openbao_kv_read_failed()
+        )
+    })?;
     let (eab_kid, eab_hmac) = match &eab {
         Some(data) => (
             Some(read_required_string(
@@ -117,28 +100,21 @@ async fn read_service_sync_material(
     Ok(ServiceSyncMaterial {
         eab_kid,
         eab_hmac,
-        responder_hmac: read_required_string(
-            &responder_hmac,
-            "value",
-            "OpenBao responder HMAC data missing key: value",
-        )?,
-        trusted_ca_sha256,
-        ca_bundle_pem: read_required_string(
-            &trust,
-            SERVICE_CA_BUNDLE_PEM_KEY,
-            &format!("OpenBao CA trust data missing key: {SERVICE_CA_BUNDLE_PEM_KEY}"),
-        )?,
+        responder_hmac,
+        trusted_ca_sha256: trust.trusted_ca_sha256,
+        ca_bundle_pem: trust.ca_bundle_pem,
+        trust_generation: trust.trust_generation,
     })
 }
 
 async fn write_service_kv_secrets(
     client: &OpenBaoClient,
-    kv_mount: &str,
+    state: &StateFile,
     service_name: &str,
     material: &ServiceSyncMaterial,
     messages: &Messages,
 ) -> Result<()> {
-    let base = format!("{SERVICE_KV_BASE}/{service_name}");
+    let kv_mount = state.kv_mount.as_str();
     // Always write `<base>/eab`, even when no EAB material is configured
     // (e.g. `--no-eab` at init, bundled OSS step-ca). The agent's
     // fast-poll loop reads this path on every cycle; an explicit empty
@@ -151,7 +127,7 @@ async fn write_service_kv_secrets(
     client
         .write_kv(
             kv_mount,
-            &format!("{base}/eab"),
+            &state.service_kv_path(service_name, SERVICE_EAB_KV_SUFFIX),
             serde_json::json!({
                 SERVICE_EAB_KID_KEY: eab_kid,
                 SERVICE_EAB_HMAC_KEY: eab_hmac,
@@ -162,7 +138,7 @@ async fn write_service_kv_secrets(
     client
         .write_kv(
             kv_mount,
-            &format!("{base}/http_responder_hmac"),
+            &state.service_kv_path(service_name, SERVICE_RESPONDER_HMAC_KV_SUFFIX),
             serde_json::json!({ SERVICE_RESPONDER_HMAC_KEY: &material.responder_hmac }),
         )
         .await
@@ -170,7 +146,7 @@ async fn write_service_kv_secrets(
     crate::commands::trust::write_service_trust(
         client,
         kv_mount,
-        service_name,
+        &state.service_kv_path(service_name, SERVICE_TRUST_KV_SUFFIX),
         &material.trusted_ca_sha256,
         &material.ca_bundle_pem,
         messages,
@@ -184,20 +160,13 @@ pub(super) async fn read_ca_bundle_pem(
     kv_mount: &str,
     messages: &Messages,
 ) -> Result<String> {
-    let trust = client
-        .read_kv(kv_mount, PATH_CA_TRUST)
-        .await
-        .with_context(|| {
-            format!(
-                "{} ({PATH_CA_TRUST})",
-                messages.error_openbao_kv_read_failed()
-            )
-        })?;
-    read_required_string(
-        &trust,
-        SERVICE_CA_BUNDLE_PEM_KEY,
-        &format!("OpenBao CA trust data missing key: {SERVICE_CA_BUNDLE_PEM_KEY}"),
-    )
+    let trust = client.read_ca_trust(kv_mount).await.with_context(|| {
+        format!(
+            "{} ({PATH_CA_TRUST})",
+            messages.error_openbao_kv_read_failed()
+        )
+    })?;
+    Ok(trust.ca_bundle_pem)
 }
 
 pub(super) async fn read_ca_trust_material(
@@ -212,74 +181,84 @@ pub(super) async fn read_ca_trust_material(
     {
         return Ok(None);
     }
-    let data = client
-        .read_kv(kv_mount, PATH_CA_TRUST)
+    let trust = client
+        .read_ca_trust(kv_mount)
         .await
         .with_context(|| messages.error_openbao_kv_read_failed())?;
-    let value = data
-        .get(CA_TRUST_KEY)
-        .ok_or_else(|| anyhow::anyhow!(messages.error_ca_trust_missing(CA_TRUST_KEY)))?;
-    let fingerprints = parse_trusted_ca_list(value, messages)?;
-    if fingerprints.is_empty() {
-        anyhow::bail!(messages.error_ca_trust_empty());
-    }
     Ok(Some(CaTrustMaterial {
-        trusted_ca_sha256: fingerprints,
+        trusted_ca_sha256: trust.trusted_ca_sha256,
     }))
 }
 
-pub(super) fn parse_trusted_ca_list(
-    value: &serde_json::Value,
-    messages: &Messages,
-) -> Result<Vec<String>> {
-    let items = value
-        .as_array()
-        .ok_or_else(|| anyhow::anyhow!(messages.error_ca_trust_invalid()))?;
-    let mut fingerprints = Vec::with_capacity(items.len());
-    for item in items {
-        let fingerprint = item
-            .as_str()
-            .ok_or_else(|| anyhow::anyhow!(messages.error_ca_trust_invalid()))?;
-        if !is_valid_sha256_fingerprint(fingerprint) {
-            anyhow::bail!(messages.error_ca_trust_invalid());
-        }
-        fingerprints.push(fingerprint.to_string());
-    }
-    Ok(fingerprints)
-}
-
-fn is_valid_sha256_fingerprint(value: &str) -> bool {
-    value.len() == 64 && value.chars().all(|ch| ch.is_ascii_hexdigit())
-}
-
 #[cfg(test)]
 mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
     use super::*;
     use crate::i18n::test_messages;
 
-    #[test]
-    fn test_parse_trusted_ca_list_accepts_valid() {
-        let messages = test_messages();
-        let value = serde_json::json!(["a".repeat(64), "b".repeat(64)]);
-        let parsed = parse_trusted_ca_list(&value, &messages).expect("parse list");
-        assert_eq!(parsed.len(), 2);
-        assert_eq!(parsed[0], "a".repeat(64));
-        assert_eq!(parsed[1], "b".repeat(64));
-    }
+    #[tokio::test]
+    async fn test_read_ca_trust_material_rejects_missing_fingerprints() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/metadata/bootroot/ca"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/bootroot/ca"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": { "ca_bundle_pem": "pem" } }
+            })))
+            .mount(&server)
+            .await;
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+
+        let Err(err) = read_ca_trust_material(&client, "secret", &test_messages()).await else {
+            panic!("missing fingerprints must be rejected");
+        };
 
-    #[test]
-    fn test_parse_trusted_ca_list_rejects_non_array() {
-        let messages = test_messages();
-        let value = serde_json::json!("not-array");
-        let err = parse_trusted_ca_list(&value, &messages).unwrap_err();
-        assert!(err.to_string().contains("OpenBao CA trust data"));
+        assert_eq!(
+            err.root_cause().to_string(),
+            "Missing required array key: trusted_ca_sha256"
+        );
     }
 
-    #[test]
-    fn test_parse_trusted_ca_list_rejects_invalid_fingerprint() {
-        let messages = test_messages();
-        let value = serde_json::json!(["not-hex"]);
-        let err = parse_trusted_ca_list(&value, &messages).unwrap_err();
-        assert!(err.to_string().contains("OpenBao CA trust data"));
+    #[tokio::test]
+    async fn test_write_service_kv_secrets_uses_recorded_kv_prefix() {
+        let server = MockServer::start().await;
+        for suffix in ["eab", "http_responder_hmac", "trust"] {
+            Mock::given(method("POST"))
+                .and(path(format!(
+                    "/v1/secret/data/tenants/acme/services/edge-proxy/{suffix}"
+                )))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        let state = StateFile {
+            kv_mount: "secret".to_string(),
+            kv_prefix: Some("tenants/acme/".to_string()),
+            ..Default::default()
+        };
+        let material = ServiceSyncMaterial {
+            eab_kid: None,
+            eab_hmac: None,
+            responder_hmac: "hmac".to_string(),
+            trusted_ca_sha256: vec!["a".repeat(64)],
+            ca_bundle_pem: "pem".to_string(),
+            trust_generation: 1,
+        };
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+
+        write_service_kv_secrets(&client, &state, "edge-proxy", &material, &test_messages())
+            .await
+            .expect("write service kv");
     }
 }
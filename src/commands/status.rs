@@ -1,56 +1,187 @@
+use std::collections::BTreeMap;
+use std::path::Path;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
+use bootroot::env_compat::{OPENBAO_URL_ENV, lookup_with_aliases};
 use bootroot::openbao::{KvMountStatus, OpenBaoClient};
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use time::format_description::well_known::Rfc3339;
 
-use crate::cli::args::StatusArgs;
+use crate::cli::args::{OpenBaoOverrideArgs, StatusArgs, StatusOutputFormat};
 use crate::commands::infra::{
     ContainerReadiness, collect_container_failures, collect_readiness, default_infra_services,
 };
 use crate::commands::init::{
     APPROLE_BOOTROOT_AGENT, APPROLE_BOOTROOT_INFRA_ROTATE, APPROLE_BOOTROOT_RESPONDER,
-    APPROLE_BOOTROOT_STEPCA, PATH_AGENT_EAB, PATH_CA_TRUST, PATH_RESPONDER_HMAC, PATH_STEPCA_DB,
-    PATH_STEPCA_PASSWORD, SECRET_ID_TTL, parse_ttl_to_secs,
+    APPROLE_BOOTROOT_STEPCA, DEFAULT_KV_MOUNT, DEFAULT_OPENBAO_URL, PATH_AGENT_EAB, PATH_CA_TRUST,
+    PATH_RESPONDER_HMAC, PATH_STEPCA_DB, PATH_STEPCA_PASSWORD, SECRET_ID_TTL, parse_ttl_to_secs,
 };
+use crate::commands::service::cert_files::{CertFilesCheck, check_cert_files};
 use crate::i18n::Messages;
 use crate::state::StateFile;
 
-pub(crate) async fn run_status(args: &StatusArgs, messages: &Messages) -> Result<()> {
-    let services = default_infra_services();
-    let readiness = collect_readiness(&args.compose.compose_file, None, &services, messages)?;
-    let infra_failures = collect_container_failures(&readiness);
+/// KV paths (relative to the mount) whose presence `status` reports.
+const STATUS_KV_PATHS: [&str; 5] = [
+    PATH_STEPCA_PASSWORD,
+    PATH_STEPCA_DB,
+    PATH_RESPONDER_HMAC,
+    PATH_CA_TRUST,
+    PATH_AGENT_EAB,
+];
+
+/// `AppRole`s whose presence `status` reports.
+const STATUS_APPROLES: [&str; 4] = [
+    APPROLE_BOOTROOT_AGENT,
+    APPROLE_BOOTROOT_RESPONDER,
+    APPROLE_BOOTROOT_STEPCA,
+    APPROLE_BOOTROOT_INFRA_ROTATE,
+];
+
+/// Version of the `status --output json` schema. Bump it whenever a
+/// field is renamed, removed, or changes meaning; adding a field does
+/// not require a bump.
+pub(crate) const STATUS_SCHEMA_VERSION: u32 = 1;
+
+/// Machine-readable status emitted by `status --output json`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct StatusReport {
+    pub(crate) schema_version: u32,
+    /// Compose service name to container state.
+    pub(crate) infra: BTreeMap<String, InfraServiceReport>,
+    pub(crate) openbao: OpenBaoReport,
+    /// `<mount>/<path>` to presence; `null` without a root token.
+    pub(crate) kv_paths: BTreeMap<String, Option<bool>>,
+    /// `AppRole` name to presence; `null` without a root token.
+    pub(crate) approles: BTreeMap<String, Option<bool>>,
+    /// Registered service name to its delivery and certificate state.
+    pub(crate) services: BTreeMap<String, ServiceReport>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct InfraServiceReport {
+    pub(crate) status: String,
+    pub(crate) health: Option<String>,
+}
 
+/// `OpenBao` state; `sealed` and `initialized` are `null` when the
+/// server is unreachable, and `initialized` also when `sys/init` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct OpenBaoReport {
+    pub(crate) reachable: bool,
+    pub(crate) sealed: Option<bool>,
+    pub(crate) initialized: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ServiceReport {
+    pub(crate) delivery_mode: String,
+    /// Whole days until the issued certificate expires (negative once
+    /// expired); `null` when no certificate is readable on this host.
+    pub(crate) cert_expiry_days: Option<i64>,
+}
+
+/// `OpenBao` endpoint `status` talks to.
+#[derive(Debug, PartialEq, Eq)]
+struct OpenBaoTarget {
+    url: String,
+    kv_mount: String,
+    /// Set when neither a flag nor `state.json` supplied the URL.
+    inferred_from: Option<InferredSource>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum InferredSource {
+    Env(&'static str),
+    Default,
+}
+
+/// Resolves the `OpenBao` URL and KV mount: flags win, then
+/// `state.json`, then the `OpenBao`/Vault address environment variables
+/// (looked up through `env`), then the built-in defaults.
+fn resolve_openbao_target(
+    args: &OpenBaoOverrideArgs,
+    state: Option<&StateFile>,
+    env: impl Fn(&str) -> Option<String>,
+) -> OpenBaoTarget {
+    let kv_mount = args
+        .kv_mount
+        .clone()
+        .or_else(|| state.map(|state| state.kv_mount.clone()))
+        .unwrap_or_else(|| DEFAULT_KV_MOUNT.to_string());
+    if let Some(url) = args
+        .openbao_url
+        .clone()
+        .or_else(|| state.map(|state| state.openbao_url.clone()))
+    {
+        return OpenBaoTarget {
+            url,
+            kv_mount,
+            inferred_from: None,
+        };
+    }
+    match lookup_with_aliases(OPENBAO_URL_ENV, env) {
+        Some((name, url)) => OpenBaoTarget {
+            url,
+            kv_mount,
+            inferred_from: Some(InferredSource::Env(name)),
+        },
+        None => OpenBaoTarget {
+            url: DEFAULT_OPENBAO_URL.to_string(),
+            kv_mount,
+            inferred_from: Some(InferredSource::Default),
+        },
+    }
+}
+
+/// Prints where the `OpenBao` target came from when it was inferred
+/// rather than given on the command line.
+fn report_inferred_target(target: &OpenBaoTarget, messages: &Messages) {
+    if let Some(source) = &target.inferred_from {
+        let source = match source {
+            InferredSource::Env(name) => name,
+            InferredSource::Default => messages.status_openbao_target_default_source(),
+        };
+        eprintln!(
+            "{}",
+            messages.status_openbao_target_inferred(&target.url, source, &target.kv_mount)
+        );
+    }
+}
+
+pub(crate) async fn run_status(args: &StatusArgs, messages: &Messages) -> Result<()> {
     let state_path = StateFile::default_path();
     let state = if state_path.exists() {
         Some(StateFile::load(&state_path).with_context(|| messages.error_parse_state_failed())?)
     } else {
         None
     };
+
+    let target = resolve_openbao_target(&args.openbao, state.as_ref(), |name| {
+        std::env::var(name).ok()
+    });
+    report_inferred_target(&target, messages);
+
+    let services = default_infra_services();
+    let compose_profiles = state
+        .as_ref()
+        .map_or(&[][..], |state| state.compose_profiles.as_slice());
+    let readiness = collect_readiness(
+        &args.compose.compose_file,
+        compose_profiles,
+        &services,
+        messages,
+    )?;
+    let infra_failures = collect_container_failures(&readiness);
     let mut client = match state.as_ref().map(StateFile::secrets_dir) {
-        Some(secrets_dir) => {
-            OpenBaoClient::with_local_trust(&args.openbao.openbao_url, secrets_dir)
-                .with_context(|| messages.error_openbao_client_create_failed())?
-        }
-        None => OpenBaoClient::new(&args.openbao.openbao_url)
+        Some(secrets_dir) => OpenBaoClient::with_local_trust(&target.url, secrets_dir)
+            .with_context(|| messages.error_openbao_client_create_failed())?,
+        None => OpenBaoClient::new(&target.url)
             .with_context(|| messages.error_openbao_client_create_failed())?,
     };
-    let openbao_health = client
-        .health_check()
-        .await
-        .with_context(|| messages.error_openbao_health_check_failed());
-    let openbao_ok = openbao_health.is_ok();
-    let seal_status = if openbao_ok {
-        Some(
-            client
-                .seal_status()
-                .await
-                .with_context(|| messages.error_openbao_seal_status_failed())?,
-        )
-    } else {
-        None
-    };
+    let openbao = probe_openbao(&client, messages).await?;
+    let openbao_ok = openbao.reachable;
 
     if let Some(token) = &args.root_token.root_token {
         client.set_token(token.clone());
@@ -59,7 +190,7 @@ pub(crate) async fn run_status(args: &StatusArgs, messages: &Messages) -> Result
     let kv_mount_status = if openbao_ok && args.root_token.root_token.is_some() {
         Some(
             client
-                .kv_mount_status(&args.openbao.kv_mount)
+                .kv_mount_status(&target.kv_mount)
                 .await
                 .with_context(|| messages.error_openbao_kv_mount_status_failed())?,
         )
@@ -67,27 +198,14 @@ pub(crate) async fn run_status(args: &StatusArgs, messages: &Messages) -> Result
         None
     };
 
-    let kv_paths = [
-        PATH_STEPCA_PASSWORD,
-        PATH_STEPCA_DB,
-        PATH_RESPONDER_HMAC,
-        PATH_CA_TRUST,
-        PATH_AGENT_EAB,
-    ];
     let kv_statuses = if openbao_ok && args.root_token.root_token.is_some() {
-        Some(fetch_kv_statuses(&client, &args.openbao.kv_mount, &kv_paths, messages).await?)
+        Some(fetch_kv_statuses(&client, &target.kv_mount, &STATUS_KV_PATHS, messages).await?)
     } else {
         None
     };
 
-    let approles = [
-        APPROLE_BOOTROOT_AGENT,
-        APPROLE_BOOTROOT_RESPONDER,
-        APPROLE_BOOTROOT_STEPCA,
-        APPROLE_BOOTROOT_INFRA_ROTATE,
-    ];
     let approle_statuses = if openbao_ok && args.root_token.root_token.is_some() {
-        Some(fetch_approle_statuses(&client, &approles, messages).await?)
+        Some(fetch_approle_statuses(&client, &STATUS_APPROLES, messages).await?)
     } else {
         None
     };
@@ -102,9 +220,8 @@ pub(crate) async fn run_status(args: &StatusArgs, messages: &Messages) -> Result
 
     let summary = StatusSummary {
         readiness: &readiness,
-        openbao_ok,
-        sealed: seal_status.map(|status| status.sealed),
-        kv_mount: &args.openbao.kv_mount,
+        openbao,
+        kv_mount: &target.kv_mount,
         kv_mount_status,
         kv_statuses: kv_statuses.as_deref(),
         approle_statuses: approle_statuses.as_deref(),
@@ -112,7 +229,14 @@ pub(crate) async fn run_status(args: &StatusArgs, messages: &Messages) -> Result
         last_secret_id_rotation: last_secret_id_rotation.as_deref(),
         secret_id_rotation_warning,
     };
-    print_status_summary(messages, &summary);
+    match args.output {
+        StatusOutputFormat::Text => print_status_summary(messages, &summary),
+        StatusOutputFormat::Json => {
+            let report = serde_json::to_string_pretty(&status_report(&summary))
+                .with_context(|| messages.error_serialize_status_report_failed())?;
+            println!("{report}");
+        }
+    }
 
     if !infra_failures.is_empty() {
         anyhow::bail!(messages.status_error_infra_unhealthy(&infra_failures.join(", ")));
@@ -124,6 +248,27 @@ pub(crate) async fn run_status(args: &StatusArgs, messages: &Messages) -> Result
     Ok(())
 }
 
+/// Checks `OpenBao` reachability and, when reachable, its seal and
+/// (best-effort) initialization state.
+async fn probe_openbao(client: &OpenBaoClient, messages: &Messages) -> Result<OpenBaoReport> {
+    if client.health_check().await.is_err() {
+        return Ok(OpenBaoReport {
+            reachable: false,
+            sealed: None,
+            initialized: None,
+        });
+    }
+    let seal_status = client
+        .seal_status()
+        .await
+        .with_context(|| messages.error_openbao_seal_status_failed())?;
+    Ok(OpenBaoReport {
+        reachable: true,
+        sealed: Some(seal_status.sealed),
+        initialized: client.is_initialized().await.ok(),
+    })
+}
+
 fn load_service_statuses(messages: &Messages) -> Result<Vec<ServiceStatusEntry>> {
     let state_path = StateFile::default_path();
     if !state_path.exists() {
@@ -131,16 +276,29 @@ fn load_service_statuses(messages: &Messages) -> Result<Vec<ServiceStatusEntry>>
     }
     let state =
         StateFile::load(&state_path).with_context(|| messages.error_parse_state_failed())?;
+    let now = OffsetDateTime::now_utc();
     let mut service_statuses = Vec::with_capacity(state.services.len());
     for entry in state.services.values() {
         service_statuses.push(ServiceStatusEntry {
             service_name: entry.service_name.clone(),
             delivery_mode: entry.delivery_mode.to_string(),
+            cert_expiry_days: cert_expiry_days(&entry.cert_path, now),
+            cert_files: check_cert_files(entry),
         });
     }
     Ok(service_statuses)
 }
 
+/// Returns the whole days until the PEM certificate at `cert_path`
+/// expires, or `None` when it is missing or unparseable (for example a
+/// remote-bootstrap service whose certificate lives on another host).
+fn cert_expiry_days(cert_path: &Path, now: OffsetDateTime) -> Option<i64> {
+    let contents = std::fs::read(cert_path).ok()?;
+    let (_, pem) = x509_parser::pem::parse_x509_pem(&contents).ok()?;
+    let (_, cert) = x509_parser::parse_x509_certificate(&pem.contents).ok()?;
+    Some((cert.validity().not_after.to_datetime() - now).whole_days())
+}
+
 async fn fetch_kv_statuses(
     client: &OpenBaoClient,
     kv_mount: &str,
@@ -176,8 +334,7 @@ async fn fetch_approle_statuses(
 
 struct StatusSummary<'a> {
     readiness: &'a [ContainerReadiness],
-    openbao_ok: bool,
-    sealed: Option<bool>,
+    openbao: OpenBaoReport,
     kv_mount: &'a str,
     kv_mount_status: Option<KvMountStatus>,
     kv_statuses: Option<&'a [(String, bool)]>,
@@ -221,6 +378,67 @@ fn secret_id_rotation_warning(
 struct ServiceStatusEntry {
     service_name: String,
     delivery_mode: String,
+    cert_expiry_days: Option<i64>,
+    /// `None` for remote-bootstrap services, whose files are not local.
+    cert_files: Option<CertFilesCheck>,
+}
+
+/// Converts the collected status into the versioned JSON schema.
+fn status_report(summary: &StatusSummary<'_>) -> StatusReport {
+    let infra = summary
+        .readiness
+        .iter()
+        .map(|entry| {
+            (
+                entry.service.clone(),
+                InfraServiceReport {
+                    status: entry.status.clone(),
+                    health: entry.health.clone(),
+                },
+            )
+        })
+        .collect();
+    let kv_paths = match summary.kv_statuses {
+        Some(statuses) => statuses
+            .iter()
+            .map(|(path, present)| (path.clone(), Some(*present)))
+            .collect(),
+        None => STATUS_KV_PATHS
+            .iter()
+            .map(|path| (format!("{}/{path}", summary.kv_mount), None))
+            .collect(),
+    };
+    let approles = match summary.approle_statuses {
+        Some(statuses) => statuses
+            .iter()
+            .map(|(role, present)| (role.clone(), Some(*present)))
+            .collect(),
+        None => STATUS_APPROLES
+            .iter()
+            .map(|role| ((*role).to_string(), None))
+            .collect(),
+    };
+    let services = summary
+        .service_statuses
+        .iter()
+        .map(|service| {
+            (
+                service.service_name.clone(),
+                ServiceReport {
+                    delivery_mode: service.delivery_mode.clone(),
+                    cert_expiry_days: service.cert_expiry_days,
+                },
+            )
+        })
+        .collect();
+    StatusReport {
+        schema_version: STATUS_SCHEMA_VERSION,
+        infra,
+        openbao: summary.openbao,
+        kv_paths,
+        approles,
+        services,
+    }
 }
 
 fn print_status_summary(messages: &Messages, summary: &StatusSummary<'_>) {
@@ -252,13 +470,13 @@ fn print_status_summary(messages: &Messages, summary: &StatusSummary<'_>) {
 
 fn print_openbao_section(messages: &Messages, summary: &StatusSummary<'_>) {
     println!("{}", messages.status_section_openbao());
-    let health_value = if summary.openbao_ok {
+    let health_value = if summary.openbao.reachable {
         messages.status_value_ok()
     } else {
         messages.status_value_unreachable()
     };
     println!("{}", messages.status_openbao_health(health_value));
-    if let Some(sealed) = summary.sealed {
+    if let Some(sealed) = summary.openbao.sealed {
         println!("{}", messages.status_openbao_sealed(&sealed.to_string()));
     } else {
         println!(
@@ -297,13 +515,7 @@ fn print_kv_paths_section(messages: &Messages, summary: &StatusSummary<'_>) {
             println!("{}", messages.status_kv_path_entry(path, value));
         }
     } else {
-        for path in [
-            format!("{}/{PATH_STEPCA_PASSWORD}", summary.kv_mount),
-            format!("{}/{PATH_STEPCA_DB}", summary.kv_mount),
-            format!("{}/{PATH_RESPONDER_HMAC}", summary.kv_mount),
-            format!("{}/{PATH_CA_TRUST}", summary.kv_mount),
-            format!("{}/{PATH_AGENT_EAB}", summary.kv_mount),
-        ] {
+        for path in STATUS_KV_PATHS.map(|path| format!("{}/{path}", summary.kv_mount)) {
             println!(
                 "{}",
                 messages.status_kv_path_entry(&path, messages.status_value_unknown())
@@ -324,12 +536,7 @@ fn print_approles_section(messages: &Messages, summary: &StatusSummary<'_>) {
             println!("{}", messages.status_approle_entry(role, value));
         }
     } else {
-        for role in [
-            APPROLE_BOOTROOT_AGENT,
-            APPROLE_BOOTROOT_RESPONDER,
-            APPROLE_BOOTROOT_STEPCA,
-            APPROLE_BOOTROOT_INFRA_ROTATE,
-        ] {
+        for role in STATUS_APPROLES {
             println!(
                 "{}",
                 messages.status_approle_entry(role, messages.status_value_unknown())
@@ -349,6 +556,16 @@ fn print_services_section(messages: &Messages, summary: &StatusSummary<'_>) {
                 messages
                     .status_service_delivery_mode(&service.service_name, &service.delivery_mode)
             );
+            if let Some(check) = service.cert_files {
+                println!(
+                    "{}",
+                    messages.status_service_cert_files(
+                        &service.service_name,
+                        &check.cert.describe(messages),
+                        &check.key.describe(messages),
+                    )
+                );
+            }
         }
     }
 }
@@ -360,6 +577,50 @@ mod tests {
     use super::*;
     use crate::i18n::test_messages;
 
+    fn no_flags() -> OpenBaoOverrideArgs {
+        OpenBaoOverrideArgs {
+            openbao_url: None,
+            kv_mount: None,
+        }
+    }
+
+    #[test]
+    fn resolve_openbao_target_prefers_flag_then_state() {
+        let state = state_with_rotation(None, None);
+        let flags = OpenBaoOverrideArgs {
+            openbao_url: Some("https://flag:8200".to_string()),
+            kv_mount: None,
+        };
+        let env = |_: &str| Some("https://env:8200".to_string());
+
+        let target = resolve_openbao_target(&flags, Some(&state), env);
+        assert_eq!(target.url, "https://flag:8200");
+        assert_eq!(target.kv_mount, state.kv_mount);
+        assert_eq!(target.inferred_from, None);
+
+        let target = resolve_openbao_target(&no_flags(), Some(&state), env);
+        assert_eq!(target.url, state.openbao_url);
+        assert_eq!(target.inferred_from, None);
+    }
+
+    #[test]
+    fn resolve_openbao_target_infers_from_env_without_state() {
+        let env = |name: &str| (name == "VAULT_ADDR").then(|| "https://vault:8200".to_string());
+        let target = resolve_openbao_target(&no_flags(), None, env);
+        assert_eq!(
+            target,
+            OpenBaoTarget {
+                url: "https://vault:8200".to_string(),
+                kv_mount: DEFAULT_KV_MOUNT.to_string(),
+                inferred_from: Some(InferredSource::Env("VAULT_ADDR")),
+            }
+        );
+
+        let target = resolve_openbao_target(&no_flags(), None, |_| None);
+        assert_eq!(target.url, DEFAULT_OPENBAO_URL);
+        assert_eq!(target.inferred_from, Some(InferredSource::Default));
+    }
+
     fn state_with_rotation(
         last_secret_id_rotation: Option<&str>,
         rotate_secret_id_ttl: Option<&str>,
@@ -377,6 +638,120 @@ mod tests {
         ts.format(&Rfc3339).expect("RFC 3339 formatting")
     }
 
+    #[tokio::test]
+    async fn json_report_round_trips_and_reflects_sealed_openbao() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/health"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/seal-status"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"sealed": true, "t": 2, "n": 3})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/init"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"initialized": true})),
+            )
+            .mount(&server)
+            .await;
+
+        let messages = test_messages();
+        let client = OpenBaoClient::new(&server.uri()).unwrap();
+        let openbao = probe_openbao(&client, &messages).await.unwrap();
+        let readiness = vec![ContainerReadiness {
+            service: "openbao".to_string(),
+            container_id: "abc123".to_string(),
+            status: "running".to_string(),
+            health: Some("healthy".to_string()),
+        }];
+        let service_statuses = vec![ServiceStatusEntry {
+            service_name: "edge-proxy".to_string(),
+            delivery_mode: "local-file".to_string(),
+            cert_expiry_days: Some(42),
+            cert_files: None,
+        }];
+        let summary = StatusSummary {
+            readiness: &readiness,
+            openbao,
+            kv_mount: "secret",
+            kv_mount_status: None,
+            kv_statuses: None,
+            approle_statuses: None,
+            service_statuses: &service_statuses,
+            last_secret_id_rotation: None,
+            secret_id_rotation_warning: None,
+        };
+
+        let json = serde_json::to_string_pretty(&status_report(&summary)).unwrap();
+        let report: StatusReport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(report.schema_version, STATUS_SCHEMA_VERSION);
+        assert_eq!(
+            report.openbao,
+            OpenBaoReport {
+                reachable: true,
+                sealed: Some(true),
+                initialized: Some(true),
+            }
+        );
+        assert_eq!(
+            report.infra.get("openbao"),
+            Some(&InfraServiceReport {
+                status: "running".to_string(),
+                health: Some("healthy".to_string()),
+            })
+        );
+        assert_eq!(report.kv_paths.len(), STATUS_KV_PATHS.len());
+        assert_eq!(report.kv_paths.get("secret/bootroot/ca"), Some(&None));
+        assert_eq!(report.approles.get(APPROLE_BOOTROOT_AGENT), Some(&None));
+        assert_eq!(
+            report.services.get("edge-proxy"),
+            Some(&ServiceReport {
+                delivery_mode: "local-file".to_string(),
+                cert_expiry_days: Some(42),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn unreachable_openbao_reports_unknown_seal_state() {
+        let messages = test_messages();
+        let client = OpenBaoClient::new("http://127.0.0.1:9").unwrap();
+        let openbao = probe_openbao(&client, &messages).await.unwrap();
+        assert_eq!(
+            openbao,
+            OpenBaoReport {
+                reachable: false,
+                sealed: None,
+                initialized: None,
+            }
+        );
+    }
+
+    #[test]
+    fn cert_expiry_days_counts_days_until_not_after() {
+        let dir = tempfile::tempdir().unwrap();
+        let cert_path = dir.path().join("cert.pem");
+        let now = OffsetDateTime::now_utc();
+        let mut params = rcgen::CertificateParams::new(vec!["edge.example".to_string()]).unwrap();
+        params.not_after = now + TimeDuration::days(30) + TimeDuration::hours(1);
+        let key = rcgen::KeyPair::generate().unwrap();
+        std::fs::write(&cert_path, params.self_signed(&key).unwrap().pem()).unwrap();
+
+        assert_eq!(cert_expiry_days(&cert_path, now), Some(30));
+        assert_eq!(cert_expiry_days(&dir.path().join("missing.pem"), now), None);
+    }
+
     #[test]
     fn no_recorded_rotation_produces_no_warning() {
         let state = state_with_rotation(None, None);
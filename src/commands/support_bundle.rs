@@ -0,0 +1,154 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use bootroot::openbao::{redact_secret_fields, redact_secret_text};
+
+use crate::cli::args::SupportBundleArgs;
+use crate::commands::infra::{docker_compose_output, docker_output};
+use crate::i18n::Messages;
+use crate::state::StateFile;
+
+mod zip;
+
+const VERSION_FILE: &str = "version.txt";
+const COMPOSE_PS_FILE: &str = "compose-ps.txt";
+const COMPOSE_LOGS_FILE: &str = "compose-logs.txt";
+const STATE_FILE: &str = "state.json";
+
+/// State and log fields masked on top of the shared `OpenBao` secret
+/// field list.
+///
+/// `role_id` is an `AppRole` credential half and `args` may carry
+/// hook arguments with inline secrets.
+const STATE_EXTRA_SECRET_FIELDS: &[&str] = &["role_id", "args"];
+
+pub(crate) fn run_support_bundle(args: &SupportBundleArgs, messages: &Messages) -> Result<()> {
+    let compose_file = &args.compose_file.compose_file;
+
+    let docker_version = capture(docker_output(&["compose", "version"], messages), messages);
+    let ps = capture(
+        docker_compose_output(compose_file, &[], &["ps", "--all"], messages),
+        messages,
+    );
+    let tail = args.log_tail.to_string();
+    let logs = capture(
+        docker_compose_output(
+            compose_file,
+            &[],
+            &["logs", "--no-color", "--tail", &tail],
+            messages,
+        ),
+        messages,
+    );
+    let state_path = StateFile::default_path();
+    let state = if state_path.exists() {
+        redacted_state(&state_path, messages)?
+    } else {
+        messages.support_bundle_state_missing(&state_path.display().to_string())
+    };
+
+    let entries = [
+        (
+            VERSION_FILE,
+            format!("bootroot {}\n{docker_version}", env!("CARGO_PKG_VERSION")),
+        ),
+        (COMPOSE_PS_FILE, ps),
+        (
+            COMPOSE_LOGS_FILE,
+            redact_secret_text(&logs, STATE_EXTRA_SECRET_FIELDS),
+        ),
+        (STATE_FILE, state),
+    ];
+    write_archive(&args.out, &entries, messages)?;
+
+    println!(
+        "{}",
+        messages.support_bundle_written(&args.out.display().to_string())
+    );
+    Ok(())
+}
+
+/// Renders a docker command result for the bundle, recording failures
+/// as text so one unavailable service does not abort the whole bundle.
+fn capture(result: Result<String>, messages: &Messages) -> String {
+    result.unwrap_or_else(|err| messages.support_bundle_command_failed(&format!("{err:#}")))
+}
+
+/// Reads `path` as JSON and returns it pretty-printed with secret fields
+/// masked.
+///
+/// The raw JSON value is redacted rather than the typed [`StateFile`] so
+/// fields added by newer versions are masked too instead of dropped.
+fn redacted_state(path: &Path, messages: &Messages) -> Result<String> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| messages.error_read_file_failed(&path.display().to_string()))?;
+    let mut value: serde_json::Value =
+        serde_json::from_str(&contents).with_context(|| messages.error_parse_state_failed())?;
+    redact_secret_fields(&mut value, STATE_EXTRA_SECRET_FIELDS);
+    serde_json::to_string_pretty(&value).with_context(|| messages.error_serialize_state_failed())
+}
+
+/// Writes `entries` as a ZIP archive at `path`, creating its parent
+/// directory if missing.
+fn write_archive(path: &Path, entries: &[(&str, String)], messages: &Messages) -> Result<()> {
+    let write_failed = || messages.error_write_file_failed(&path.display().to_string());
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent).with_context(write_failed)?;
+    }
+    let mut archive = zip::ZipWriter::new(time::OffsetDateTime::now_utc());
+    for (name, contents) in entries {
+        archive.add_file(name, contents.as_bytes())?;
+    }
+    let mut file = std::fs::File::create(path).with_context(write_failed)?;
+    archive.finish(&mut file).with_context(write_failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn redacted_state_masks_role_id_and_hook_args() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        std::fs::write(
+            &path,
+            r#"{"services":{"edge":{"approle":{"role_id":"role-123","secret_id_path":"s"},"hooks":{"post_renew":{"success":[{"command":"reload","args":["--token","tok-456"]}]}}}}}"#,
+        )
+        .unwrap();
+
+        let messages = crate::i18n::test_messages();
+        let rendered = redacted_state(&path, &messages).unwrap();
+        assert!(!rendered.contains("role-123"), "{rendered}");
+        assert!(!rendered.contains("tok-456"), "{rendered}");
+        assert!(rendered.contains("\"secret_id_path\": \"s\""), "{rendered}");
+        assert!(rendered.contains("\"command\": \"reload\""), "{rendered}");
+    }
+
+    #[test]
+    fn write_archive_stores_entries_as_zip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested").join("bundle.zip");
+        let entries = [
+            (VERSION_FILE, "bootroot 0.0.0\n".to_string()),
+            (STATE_FILE, "{}".to_string()),
+        ];
+
+        let messages = crate::i18n::test_messages();
+        write_archive(&path, &entries, &messages).unwrap();
+
+        let archive = std::fs::read(&path).unwrap();
+        assert_eq!(
+            zip::tests::read_entries(&archive),
+            vec![
+                (VERSION_FILE.to_string(), b"bootroot 0.0.0\n".to_vec()),
+                (STATE_FILE.to_string(), b"{}".to_vec()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,242 @@
+//! Minimal stored (uncompressed) ZIP writer for the support bundle.
+//!
+//! The bundle holds a handful of small text files, so entries are stored
+//! without compression and without ZIP64 extensions; any archive tool can
+//! open the result.
+
+use std::io::Write;
+
+use anyhow::{Context, Result};
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+const VERSION_NEEDED: u16 = 20;
+/// Unix host, spec version 3.0, so `EXTERNAL_ATTRIBUTES` carries a mode.
+const VERSION_MADE_BY: u16 = 0x031e;
+/// General purpose flag bit 11: entry names are UTF-8.
+const UTF8_NAMES_FLAG: u16 = 1 << 11;
+const METHOD_STORED: u16 = 0;
+/// Regular file with mode `0600` in the high 16 bits.
+const EXTERNAL_ATTRIBUTES: u32 = 0o100_600 << 16;
+
+/// Computes the CRC-32 (IEEE) checksum ZIP stores for each entry.
+///
+/// Bundles are a few hundred kilobytes at most, so the bitwise form is
+/// used instead of a lookup table.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            if crc & 1 == 1 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+struct CentralEntry {
+    name: String,
+    crc: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// Collects entries in memory and renders them as a ZIP archive.
+pub(super) struct ZipWriter {
+    buffer: Vec<u8>,
+    entries: Vec<CentralEntry>,
+    dos_time: u16,
+    dos_date: u16,
+}
+
+impl ZipWriter {
+    /// Starts an archive whose entries are stamped with `modified`.
+    pub(super) fn new(modified: time::OffsetDateTime) -> Self {
+        let (dos_time, dos_date) = dos_timestamp(modified);
+        Self {
+            buffer: Vec::new(),
+            entries: Vec::new(),
+            dos_time,
+            dos_date,
+        }
+    }
+
+    /// Appends `contents` as a stored entry named `name`.
+    ///
+    /// # Errors
+    /// Returns an error if the entry or the archive would exceed the
+    /// 4 GiB limit of a non-ZIP64 archive.
+    pub(super) fn add_file(&mut self, name: &str, contents: &[u8]) -> Result<()> {
+        let offset = to_u32(self.buffer.len(), name)?;
+        let size = to_u32(contents.len(), name)?;
+        let name_len = to_u16(name.len(), name)?;
+        let crc = crc32(contents);
+
+        let header = &mut self.buffer;
+        put_u32(header, LOCAL_FILE_HEADER_SIGNATURE);
+        put_u16(header, VERSION_NEEDED);
+        put_u16(header, UTF8_NAMES_FLAG);
+        put_u16(header, METHOD_STORED);
+        put_u16(header, self.dos_time);
+        put_u16(header, self.dos_date);
+        put_u32(header, crc);
+        put_u32(header, size);
+        put_u32(header, size);
+        put_u16(header, name_len);
+        put_u16(header, 0);
+        header.extend_from_slice(name.as_bytes());
+        header.extend_from_slice(contents);
+
+        self.entries.push(CentralEntry {
+            name: name.to_string(),
+            crc,
+            size,
+            offset,
+        });
+        Ok(())
+    }
+
+    /// Appends the central directory and writes the archive to `out`.
+    ///
+    /// # Errors
+    /// Returns an error if the archive is too large or `out` fails.
+    pub(super) fn finish(mut self, out: &mut impl Write) -> Result<()> {
+        let directory_offset = to_u32(self.buffer.len(), "central directory")?;
+        let entry_count = to_u16(self.entries.len(), "central directory")?;
+        for entry in &self.entries {
+            let buffer = &mut self.buffer;
+            put_u32(buffer, CENTRAL_DIRECTORY_SIGNATURE);
+            put_u16(buffer, VERSION_MADE_BY);
+            put_u16(buffer, VERSION_NEEDED);
+            put_u16(buffer, UTF8_NAMES_FLAG);
+            put_u16(buffer, METHOD_STORED);
+            put_u16(buffer, self.dos_time);
+            put_u16(buffer, self.dos_date);
+            put_u32(buffer, entry.crc);
+            put_u32(buffer, entry.size);
+            put_u32(buffer, entry.size);
+            put_u16(buffer, to_u16(entry.name.len(), &entry.name)?);
+            put_u16(buffer, 0);
+            put_u16(buffer, 0);
+            put_u16(buffer, 0);
+            put_u16(buffer, 0);
+            put_u32(buffer, EXTERNAL_ATTRIBUTES);
+            put_u32(buffer, entry.offset);
+            buffer.extend_from_slice(entry.name.as_bytes());
+        }
+        let directory_size = to_u32(
+            self.buffer.len() - usize::try_from(directory_offset)?,
+            "central directory",
+        )?;
+        let buffer = &mut self.buffer;
+        put_u32(buffer, END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        put_u16(buffer, 0);
+        put_u16(buffer, 0);
+        put_u16(buffer, entry_count);
+        put_u16(buffer, entry_count);
+        put_u32(buffer, directory_size);
+        put_u32(buffer, directory_offset);
+        put_u16(buffer, 0);
+        out.write_all(&self.buffer)?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// Encodes `at` as MS-DOS time and date fields, clamped to 1980, the
+/// earliest year the format can represent.
+fn dos_timestamp(at: time::OffsetDateTime) -> (u16, u16) {
+    let year = u16::try_from(at.year().saturating_sub(1980)).unwrap_or(0);
+    let time =
+        (u16::from(at.hour()) << 11) | (u16::from(at.minute()) << 5) | u16::from(at.second() / 2);
+    let date = (year.min(127) << 9) | (u16::from(u8::from(at.month())) << 5) | u16::from(at.day());
+    (time, date)
+}
+
+fn to_u32(value: usize, what: &str) -> Result<u32> {
+    u32::try_from(value).with_context(|| format!("{what} exceeds the ZIP size limit"))
+}
+
+fn to_u16(value: usize, what: &str) -> Result<u16> {
+    u16::try_from(value).with_context(|| format!("{what} exceeds the ZIP size limit"))
+}
+
+fn put_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(buffer: &mut Vec<u8>, value: u32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    /// Reads back the stored entries of an archive written by
+    /// [`ZipWriter`], checking each CRC along the way.
+    pub(crate) fn read_entries(archive: &[u8]) -> Vec<(String, Vec<u8>)> {
+        let bytes_at = |at: usize, len: usize| {
+            archive
+                .get(at..at + len)
+                .unwrap_or_else(|| panic!("archive truncated at {at}"))
+        };
+        let u16_at = |at: usize| {
+            usize::from(u16::from_le_bytes(
+                bytes_at(at, 2).try_into().expect("two bytes"),
+            ))
+        };
+        let u32_at =
+            |at: usize| u32::from_le_bytes(bytes_at(at, 4).try_into().expect("four bytes"));
+        let usize_at = |at: usize| usize::try_from(u32_at(at)).expect("offset fits usize");
+        let end = archive
+            .len()
+            .checked_sub(22)
+            .expect("end of central directory");
+        assert_eq!(u32_at(end), END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        let count = u16_at(end + 10);
+        let mut cursor = usize_at(end + 16);
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            assert_eq!(u32_at(cursor), CENTRAL_DIRECTORY_SIGNATURE);
+            let crc = u32_at(cursor + 16);
+            let size = usize_at(cursor + 20);
+            let name_len = u16_at(cursor + 28);
+            let offset = usize_at(cursor + 42);
+            let name =
+                String::from_utf8(bytes_at(cursor + 46, name_len).to_vec()).expect("utf-8 name");
+            assert_eq!(u32_at(offset), LOCAL_FILE_HEADER_SIGNATURE);
+            let data_start = offset + 30 + u16_at(offset + 26);
+            let data = bytes_at(data_start, size).to_vec();
+            assert_eq!(crc32(&data), crc, "{name}");
+            entries.push((name, data));
+            cursor += 46 + name_len;
+        }
+        entries
+    }
+
+    #[test]
+    fn crc32_matches_reference_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn zip_writer_round_trips_stored_entries() {
+        let mut writer = ZipWriter::new(time::OffsetDateTime::UNIX_EPOCH);
+        writer.add_file("a.txt", b"alpha\n").unwrap();
+        writer.add_file("dir/b.json", b"{}").unwrap();
+        let mut archive = Vec::new();
+        writer.finish(&mut archive).unwrap();
+
+        assert_eq!(
+            read_entries(&archive),
+            vec![
+                ("a.txt".to_string(), b"alpha\n".to_vec()),
+                ("dir/b.json".to_string(), b"{}".to_vec()),
+            ]
+        );
+    }
+}
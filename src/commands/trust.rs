@@ -1,4 +1,8 @@
-use std::collections::BTreeMap;
+mod export;
+mod show;
+#[cfg(test)]
+mod test_support;
+
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -8,12 +12,18 @@ use bootroot::openbao::OpenBaoClient;
 use bootroot::trust_bootstrap::CA_BUNDLE_PEM_KEY;
 use serde::{Deserialize, Serialize};
 
-use crate::commands::constants::{CA_TRUST_KEY, SERVICE_KV_BASE};
-use crate::commands::init::PATH_CA_TRUST;
+use crate::commands::constants::CA_TRUST_KEY;
+use crate::commands::init::{PATH_CA_TRUST, trust_payload_changed};
 use crate::i18n::Messages;
-use crate::state::ServiceEntry;
+use crate::state::StateFile;
+
+pub(crate) use export::run_trust_export;
+pub(crate) use show::run_trust_show;
 
 pub(crate) const SERVICE_TRUST_KV_SUFFIX: &str = "trust";
+/// Counter in the `PATH_CA_TRUST` payload, bumped whenever the published
+/// fingerprints or CA bundle change.
+pub(crate) const CA_TRUST_GENERATION_KEY: &str = bootroot::trust_bootstrap::TRUST_GENERATION_KEY;
 const ROTATION_STATE_FILENAME: &str = "rotation-state.json";
 
 /// Describes which CA components are included in the rotation.
@@ -115,32 +125,22 @@ pub(crate) fn delete_rotation_state(state_dir: &Path, messages: &Messages) -> Re
 }
 
 /// Writes trust payload (fingerprints and CA bundle PEM) to the `OpenBao`
-/// global CA path and all per-service trust paths.
+/// global CA path and the trust path of every service in `state`.
 pub(crate) async fn write_trust_to_openbao(
     client: &OpenBaoClient,
     kv_mount: &str,
-    services: &BTreeMap<String, ServiceEntry>,
+    state: &StateFile,
     fingerprints: &[String],
     ca_bundle_pem: &str,
     messages: &Messages,
 ) -> Result<()> {
-    client
-        .write_kv(
-            kv_mount,
-            PATH_CA_TRUST,
-            serde_json::json!({
-                CA_TRUST_KEY: fingerprints,
-                CA_BUNDLE_PEM_KEY: ca_bundle_pem,
-            }),
-        )
-        .await
-        .with_context(|| messages.error_openbao_kv_write_failed())?;
+    write_global_trust(client, kv_mount, fingerprints, ca_bundle_pem, messages).await?;
 
-    for entry in services.values() {
+    for entry in state.services.values() {
         write_service_trust(
             client,
             kv_mount,
-            &entry.service_name,
+            &state.service_kv_path(&entry.service_name, SERVICE_TRUST_KV_SUFFIX),
             fingerprints,
             ca_bundle_pem,
             messages,
@@ -151,11 +151,74 @@ pub(crate) async fn write_trust_to_openbao(
     Ok(())
 }
 
-/// Writes trust payload to a single service's trust path in `OpenBao`.
+/// Writes the trust payload to the global `PATH_CA_TRUST` path in `OpenBao`
+/// and returns the `trust_generation` it was published under.
+pub(crate) async fn write_global_trust(
+    client: &OpenBaoClient,
+    kv_mount: &str,
+    fingerprints: &[String],
+    ca_bundle_pem: &str,
+    messages: &Messages,
+) -> Result<u64> {
+    let current = client
+        .try_read_kv(kv_mount, PATH_CA_TRUST)
+        .await
+        .with_context(|| messages.error_openbao_kv_read_failed())?;
+    let generation = next_trust_generation(current.as_ref(), fingerprints, ca_bundle_pem);
+    client
+        .write_kv(
+            kv_mount,
+            PATH_CA_TRUST,
+            global_trust_payload(fingerprints, ca_bundle_pem, generation),
+        )
+        .await
+        .with_context(|| messages.error_openbao_kv_write_failed())?;
+    Ok(generation)
+}
+
+/// Builds the `PATH_CA_TRUST` payload.
+pub(crate) fn global_trust_payload(
+    fingerprints: &[String],
+    ca_bundle_pem: &str,
+    generation: u64,
+) -> serde_json::Value {
+    serde_json::json!({
+        CA_TRUST_KEY: fingerprints,
+        CA_BUNDLE_PEM_KEY: ca_bundle_pem,
+        CA_TRUST_GENERATION_KEY: generation,
+    })
+}
+
+/// Returns the `trust_generation` recorded in a `PATH_CA_TRUST` payload;
+/// payloads written before the counter existed count as generation 0.
+pub(crate) fn trust_generation(payload: &serde_json::Value) -> u64 {
+    payload
+        .get(CA_TRUST_GENERATION_KEY)
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0)
+}
+
+/// Returns the generation to publish `fingerprints` and `ca_bundle_pem`
+/// under: the current one when neither changed, the next one otherwise.
+pub(crate) fn next_trust_generation(
+    current: Option<&serde_json::Value>,
+    fingerprints: &[String],
+    ca_bundle_pem: &str,
+) -> u64 {
+    let current_generation = current.map_or(0, trust_generation);
+    if trust_payload_changed(current, fingerprints, ca_bundle_pem) {
+        current_generation.saturating_add(1)
+    } else {
+        current_generation
+    }
+}
+
+/// Writes trust payload to a single service's trust path in `OpenBao`,
+/// as built by [`StateFile::service_kv_path`].
 pub(crate) async fn write_service_trust(
     client: &OpenBaoClient,
     kv_mount: &str,
-    service_name: &str,
+    trust_path: &str,
     fingerprints: &[String],
     ca_bundle_pem: &str,
     messages: &Messages,
@@ -163,7 +226,7 @@ pub(crate) async fn write_service_trust(
     client
         .write_kv(
             kv_mount,
-            &format!("{SERVICE_KV_BASE}/{service_name}/{SERVICE_TRUST_KV_SUFFIX}"),
+            trust_path,
             serde_json::json!({
                 CA_TRUST_KEY: fingerprints,
                 CA_BUNDLE_PEM_KEY: ca_bundle_pem,
@@ -173,6 +236,30 @@ pub(crate) async fn write_service_trust(
         .with_context(|| messages.error_openbao_kv_write_failed())
 }
 
+/// Loads `state.json` and, when a root token is given, an `OpenBao`
+/// client for the read-only `trust` subcommands, which fall back to the
+/// local `certs/` files without one.
+fn load_trust_context(
+    openbao_url: Option<&str>,
+    root_token: Option<&str>,
+    messages: &Messages,
+) -> Result<(StateFile, Option<OpenBaoClient>)> {
+    let state_path = StateFile::default_path();
+    if !state_path.exists() {
+        anyhow::bail!(messages.error_state_missing());
+    }
+    let state =
+        StateFile::load(&state_path).with_context(|| messages.error_parse_state_failed())?;
+    let Some(root_token) = root_token else {
+        return Ok((state, None));
+    };
+    let openbao_url = openbao_url.unwrap_or(&state.openbao_url);
+    let mut client = OpenBaoClient::with_local_trust(openbao_url, state.secrets_dir())
+        .with_context(|| messages.error_openbao_client_create_failed())?;
+    client.set_token(root_token.to_string());
+    Ok((state, Some(client)))
+}
+
 /// Returns `true` if `rotation-state.json` exists in the given directory,
 /// indicating that a CA key rotation is in progress.
 pub(crate) fn rotation_in_progress(state_dir: &Path) -> bool {
@@ -318,4 +405,37 @@ mod tests {
         let mode: RotationMode = serde_json::from_str("\"full\"").expect("deserialize");
         assert_eq!(mode, RotationMode::Full);
     }
+
+    #[test]
+    fn next_trust_generation_bumps_only_on_bundle_change() {
+        let fingerprints = vec!["a".repeat(64), "b".repeat(64)];
+        assert_eq!(next_trust_generation(None, &fingerprints, "bundle"), 1);
+
+        let current = global_trust_payload(&fingerprints, "bundle", 3);
+        assert_eq!(
+            next_trust_generation(Some(&current), &fingerprints, "bundle"),
+            3
+        );
+        assert_eq!(
+            next_trust_generation(Some(&current), &fingerprints, "bundle-rotated"),
+            4
+        );
+        assert_eq!(
+            next_trust_generation(Some(&current), &["c".repeat(64)], "bundle"),
+            4
+        );
+    }
+
+    #[test]
+    fn trust_generation_defaults_to_zero_for_legacy_payloads() {
+        let legacy = serde_json::json!({
+            CA_TRUST_KEY: ["a".repeat(64)],
+            CA_BUNDLE_PEM_KEY: "bundle",
+        });
+        assert_eq!(trust_generation(&legacy), 0);
+        assert_eq!(
+            next_trust_generation(Some(&legacy), &["a".repeat(64)], "bundle"),
+            0
+        );
+    }
 }
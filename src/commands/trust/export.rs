@@ -0,0 +1,375 @@
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use bootroot::openbao::OpenBaoClient;
+
+use super::load_trust_context;
+use crate::cli::args::{TrustExportArgs, TrustExportFormat};
+use crate::commands::init::{CA_CERTS_DIR, PATH_CA_TRUST, compute_ca_bundle_pem};
+use crate::i18n::Messages;
+
+/// Mode of the exported bundle: it holds only public certificates.
+const EXPORT_FILE_MODE: u32 = 0o644;
+const PEM_LINE_WIDTH: usize = 64;
+
+const DER_TAG_INTEGER: u8 = 0x02;
+const DER_TAG_OID: u8 = 0x06;
+const DER_TAG_SEQUENCE: u8 = 0x30;
+const DER_TAG_SET: u8 = 0x31;
+const DER_TAG_CONTEXT_0: u8 = 0xa0;
+/// `1.2.840.113549.1.7.2` (PKCS#7 `signedData`).
+const OID_PKCS7_SIGNED_DATA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x07, 0x02];
+/// `1.2.840.113549.1.7.1` (PKCS#7 `data`).
+const OID_PKCS7_DATA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x07, 0x01];
+
+pub(crate) async fn run_trust_export(args: &TrustExportArgs, messages: &Messages) -> Result<()> {
+    let (state, client) = load_trust_context(
+        args.openbao_url.as_deref(),
+        args.root_token.root_token.as_deref(),
+        messages,
+    )?;
+    let ca_bundle_pem = read_ca_bundle(
+        client.as_ref(),
+        &state.kv_mount,
+        state.secrets_dir(),
+        messages,
+    )
+    .await?;
+    let fingerprints = bootroot::tls::ca_bundle_fingerprints(&ca_bundle_pem)
+        .with_context(|| messages.error_trust_export_no_certs())?;
+    let encoded = encode_ca_bundle(&ca_bundle_pem, args.format, messages)?;
+
+    if let Some(out) = &args.out {
+        write_export_file(out, &encoded, messages).await?;
+        println!(
+            "{}",
+            messages.trust_export_written(
+                format_name(args.format),
+                &fingerprints.len().to_string(),
+                &out.display().to_string(),
+            )
+        );
+        println!("{}", messages.trust_export_fingerprints());
+        for fingerprint in &fingerprints {
+            println!("  {fingerprint}");
+        }
+    } else {
+        std::io::stdout()
+            .write_all(&encoded)
+            .with_context(|| messages.error_trust_export_failed())?;
+        // The bundle owns stdout, so the fingerprints go to stderr
+        // to keep the stream redirectable.
+        eprintln!("{}", messages.trust_export_fingerprints());
+        for fingerprint in &fingerprints {
+            eprintln!("  {fingerprint}");
+        }
+    }
+    Ok(())
+}
+
+/// Returns the CA bundle PEM, preferring the `OpenBao` KV copy and
+/// falling back to the root and intermediate under `certs/`.
+async fn read_ca_bundle(
+    client: Option<&OpenBaoClient>,
+    kv_mount: &str,
+    secrets_dir: &Path,
+    messages: &Messages,
+) -> Result<String> {
+    let kv_error = match client {
+        Some(client) => match read_kv_ca_bundle(client, kv_mount).await {
+            Ok(ca_bundle_pem) => {
+                eprintln!(
+                    "{}",
+                    messages.trust_export_source_kv(&format!("{kv_mount}/{PATH_CA_TRUST}"))
+                );
+                return Ok(ca_bundle_pem);
+            }
+            Err(err) => err.to_string(),
+        },
+        None => messages.trust_export_no_root_token().to_string(),
+    };
+    eprintln!("{}", messages.trust_export_kv_unavailable(&kv_error));
+    let ca_bundle_pem = compute_ca_bundle_pem(secrets_dir, messages).await?;
+    eprintln!(
+        "{}",
+        messages.trust_export_source_local(&secrets_dir.join(CA_CERTS_DIR).display().to_string())
+    );
+    Ok(ca_bundle_pem)
+}
+
+pub(super) async fn read_kv_ca_bundle(client: &OpenBaoClient, kv_mount: &str) -> Result<String> {
+    Ok(client.read_ca_trust(kv_mount).await?.ca_bundle_pem)
+}
+
+/// Re-encodes every certificate in `ca_bundle_pem`, in bundle order.
+///
+/// `der` holds exactly one certificate, so it fails for a bundle that
+/// carries more than one.
+fn encode_ca_bundle(
+    ca_bundle_pem: &str,
+    format: TrustExportFormat,
+    messages: &Messages,
+) -> Result<Vec<u8>> {
+    let certs = rustls_pemfile::certs(&mut ca_bundle_pem.as_bytes())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .with_context(|| messages.error_trust_export_no_certs())?;
+    if certs.is_empty() {
+        anyhow::bail!(messages.error_trust_export_no_certs());
+    }
+    let ders = certs.iter().map(AsRef::as_ref);
+    Ok(match format {
+        TrustExportFormat::Pem => ders.map(pem_certificate).collect::<String>().into_bytes(),
+        // Back-to-back DER certificates are not a valid DER file, so a
+        // multi-certificate bundle needs a container format.
+        TrustExportFormat::Der => match certs.as_slice() {
+            [cert] => cert.to_vec(),
+            _ => anyhow::bail!(messages.error_trust_export_der_multiple(&certs.len().to_string())),
+        },
+        TrustExportFormat::P7b => pkcs7_certs_only(ders),
+    })
+}
+
+fn pem_certificate(der: &[u8]) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(der);
+    let mut pem = String::from("-----BEGIN CERTIFICATE-----\n");
+    for line in encoded.as_bytes().chunks(PEM_LINE_WIDTH) {
+        pem.push_str(&String::from_utf8_lossy(line));
+        pem.push('\n');
+    }
+    pem.push_str("-----END CERTIFICATE-----\n");
+    pem
+}
+
+/// Builds a degenerate (certificates-only, unsigned) PKCS#7 `SignedData`,
+/// the `.p7b` layout trust stores import.
+fn pkcs7_certs_only<'a>(ders: impl Iterator<Item = &'a [u8]>) -> Vec<u8> {
+    let certificates: Vec<u8> = ders.flatten().copied().collect();
+    let signed_data = [
+        der_tlv(DER_TAG_INTEGER, &[0x01]),
+        der_tlv(DER_TAG_SET, &[]),
+        der_tlv(DER_TAG_SEQUENCE, &der_tlv(DER_TAG_OID, OID_PKCS7_DATA)),
+        der_tlv(DER_TAG_CONTEXT_0, &certificates),
+        der_tlv(DER_TAG_SET, &[]),
+    ]
+    .concat();
+    let content_info = [
+        der_tlv(DER_TAG_OID, OID_PKCS7_SIGNED_DATA),
+        der_tlv(DER_TAG_CONTEXT_0, &der_tlv(DER_TAG_SEQUENCE, &signed_data)),
+    ]
+    .concat();
+    der_tlv(DER_TAG_SEQUENCE, &content_info)
+}
+
+fn der_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        // Short form: a single byte below 0x80.
+        encoded.extend(u8::try_from(len).ok());
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let significant: Vec<u8> = len_bytes.iter().copied().skip_while(|b| *b == 0).collect();
+        encoded.extend(u8::try_from(significant.len()).ok().map(|n| 0x80 | n));
+        encoded.extend(significant);
+    }
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+fn format_name(format: TrustExportFormat) -> &'static str {
+    match format {
+        TrustExportFormat::Pem => "pem",
+        TrustExportFormat::Der => "der",
+        TrustExportFormat::P7b => "p7b",
+    }
+}
+
+async fn write_export_file(path: &Path, contents: &[u8], messages: &Messages) -> Result<()> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .with_context(|| messages.error_write_file_failed(&parent.display().to_string()))?;
+    }
+    bootroot::fs_util::atomic_write(path, contents, EXPORT_FILE_MODE)
+        .await
+        .with_context(|| messages.error_write_file_failed(&path.display().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use bootroot::trust_bootstrap::{CA_BUNDLE_PEM_KEY, TRUSTED_CA_KEY};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+    use x509_parser::asn1_rs::{Any, Class, FromDer, Oid, Tag};
+    use x509_parser::certificate::X509Certificate;
+
+    use super::super::test_support::{generate_chain, write_local_certs};
+    use super::*;
+    use crate::i18n::test_messages;
+
+    fn fingerprint(der: &[u8]) -> String {
+        bootroot::tls::ca_bundle_fingerprints(&pem_certificate(der))
+            .expect("fingerprint")
+            .remove(0)
+    }
+
+    #[tokio::test]
+    async fn pem_export_contains_root_and_intermediate() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let chain = generate_chain();
+        write_local_certs(dir.path(), &chain);
+        let messages = test_messages();
+
+        let ca_bundle_pem = read_ca_bundle(None, "secret", dir.path(), &messages)
+            .await
+            .expect("read local bundle");
+        let exported = encode_ca_bundle(&ca_bundle_pem, TrustExportFormat::Pem, &messages)
+            .expect("encode pem");
+
+        let exported = String::from_utf8(exported).expect("utf-8 pem");
+        assert_eq!(
+            bootroot::tls::ca_bundle_fingerprints(&exported).expect("parse export"),
+            vec![
+                fingerprint(&chain.root_der),
+                fingerprint(&chain.intermediate_der)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn read_ca_bundle_prefers_openbao_kv() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let local = generate_chain();
+        write_local_certs(dir.path(), &local);
+        let kv = generate_chain();
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/bootroot/ca"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "data": {
+                        TRUSTED_CA_KEY: [fingerprint(&kv.root_der), fingerprint(&kv.intermediate_der)],
+                        CA_BUNDLE_PEM_KEY: kv.bundle_pem(),
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+
+        let ca_bundle_pem = read_ca_bundle(Some(&client), "secret", dir.path(), &test_messages())
+            .await
+            .expect("read kv bundle");
+
+        assert_eq!(ca_bundle_pem, kv.bundle_pem());
+    }
+
+    #[tokio::test]
+    async fn read_ca_bundle_falls_back_to_local_certs_when_kv_fails() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let chain = generate_chain();
+        write_local_certs(dir.path(), &chain);
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/bootroot/ca"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+
+        let ca_bundle_pem = read_ca_bundle(Some(&client), "secret", dir.path(), &test_messages())
+            .await
+            .expect("read local bundle");
+
+        assert_eq!(ca_bundle_pem, chain.bundle_pem());
+    }
+
+    /// Parses `der` as one DER object and checks its class and tag.
+    fn parse_object(der: &[u8], class: Class, tag: Tag) -> (Any<'_>, &[u8]) {
+        let (rest, object) = Any::from_der(der).expect("DER object");
+        assert_eq!(object.header.class(), class);
+        assert_eq!(object.header.tag(), tag);
+        (object, rest)
+    }
+
+    #[test]
+    fn p7b_export_wraps_every_certificate() {
+        let chain = generate_chain();
+        let ca_bundle_pem = chain.bundle_pem();
+
+        let p7b = encode_ca_bundle(&ca_bundle_pem, TrustExportFormat::P7b, &test_messages())
+            .expect("encode p7b");
+
+        let (content_info, rest) = parse_object(&p7b, Class::Universal, Tag::Sequence);
+        assert!(rest.is_empty(), "trailing bytes after ContentInfo");
+        let (rest, content_type) = Oid::from_der(content_info.data).expect("contentType");
+        assert_eq!(content_type.to_id_string(), "1.2.840.113549.1.7.2");
+        let (explicit, _) = parse_object(rest, Class::ContextSpecific, Tag(0));
+        let (signed_data, _) = parse_object(explicit.data, Class::Universal, Tag::Sequence);
+        let (_, rest) = parse_object(signed_data.data, Class::Universal, Tag::Integer);
+        let (_, rest) = parse_object(rest, Class::Universal, Tag::Set);
+        let (_, rest) = parse_object(rest, Class::Universal, Tag::Sequence);
+        let (certificates, rest) = parse_object(rest, Class::ContextSpecific, Tag(0));
+        let (signer_infos, rest) = parse_object(rest, Class::Universal, Tag::Set);
+        assert!(signer_infos.data.is_empty() && rest.is_empty());
+
+        let mut remaining = certificates.data;
+        let mut subjects = Vec::new();
+        let mut ders = Vec::new();
+        while !remaining.is_empty() {
+            let (rest, cert) = X509Certificate::from_der(remaining).expect("certificate");
+            subjects.push(cert.subject().to_string());
+            ders.push(cert.as_raw().to_vec());
+            remaining = rest;
+        }
+        assert_eq!(
+            subjects,
+            vec![
+                "CN=Bootroot Test Root CA".to_string(),
+                "CN=Bootroot Test Intermediate CA".to_string()
+            ]
+        );
+        assert_eq!(ders, vec![chain.root_der, chain.intermediate_der]);
+    }
+
+    #[test]
+    fn der_export_writes_a_single_certificate() {
+        let chain = generate_chain();
+
+        let der = encode_ca_bundle(&chain.root_pem, TrustExportFormat::Der, &test_messages())
+            .expect("encode der");
+
+        let (rest, cert) = X509Certificate::from_der(&der).expect("certificate");
+        assert!(rest.is_empty());
+        assert_eq!(cert.subject().to_string(), "CN=Bootroot Test Root CA");
+    }
+
+    #[test]
+    fn der_export_rejects_multi_certificate_bundles() {
+        let chain = generate_chain();
+        let ca_bundle_pem = chain.bundle_pem();
+
+        let err = encode_ca_bundle(&ca_bundle_pem, TrustExportFormat::Der, &test_messages())
+            .expect_err("der must reject two certificates");
+
+        assert_eq!(
+            err.to_string(),
+            test_messages().error_trust_export_der_multiple("2")
+        );
+    }
+
+    #[test]
+    fn der_tlv_uses_long_form_lengths() {
+        assert_eq!(der_tlv(DER_TAG_SET, &[]), vec![0x31, 0x00]);
+        let encoded = der_tlv(DER_TAG_SEQUENCE, &[0u8; 0x0123]);
+        assert_eq!(encoded.get(..4), Some([0x30, 0x82, 0x01, 0x23].as_slice()));
+        assert_eq!(encoded.len(), 4 + 0x0123);
+    }
+}
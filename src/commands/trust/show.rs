@@ -0,0 +1,242 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use bootroot::openbao::OpenBaoClient;
+use time::format_description::well_known::Rfc3339;
+use x509_parser::pem::Pem;
+
+use super::export::read_kv_ca_bundle;
+use super::load_trust_context;
+use crate::cli::args::TrustShowArgs;
+use crate::commands::init::{
+    CA_CERTS_DIR, PATH_CA_TRUST, compute_ca_bundle_pem, compute_ca_fingerprints,
+};
+use crate::i18n::Messages;
+
+/// One CA certificate as `trust show` reports it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CaCertSummary {
+    is_root: bool,
+    subject: String,
+    sha256: String,
+    not_after: String,
+}
+
+/// Local CA certificates, the `OpenBao` KV bundle (or why it was not
+/// read), and whether the two disagree.
+#[derive(Debug)]
+struct TrustReport {
+    local: Vec<CaCertSummary>,
+    kv: Result<Vec<CaCertSummary>, String>,
+}
+
+impl TrustReport {
+    /// Returns `None` when the KV bundle was not read, otherwise whether
+    /// its fingerprints differ from the local certificates.
+    fn drift(&self) -> Option<bool> {
+        let kv = self.kv.as_ref().ok()?;
+        let fingerprints = |certs: &[CaCertSummary]| {
+            certs
+                .iter()
+                .map(|cert| cert.sha256.clone())
+                .collect::<BTreeSet<_>>()
+        };
+        Some(fingerprints(&self.local) != fingerprints(kv))
+    }
+}
+
+pub(crate) async fn run_trust_show(args: &TrustShowArgs, messages: &Messages) -> Result<()> {
+    let (state, client) = load_trust_context(
+        args.openbao_url.as_deref(),
+        args.root_token.root_token.as_deref(),
+        messages,
+    )?;
+    let report = build_trust_report(
+        client.as_ref(),
+        &state.kv_mount,
+        state.secrets_dir(),
+        messages,
+    )
+    .await?;
+
+    println!(
+        "{}",
+        messages
+            .trust_show_local_header(&state.secrets_dir().join(CA_CERTS_DIR).display().to_string())
+    );
+    print_certs(&report.local, messages);
+    match &report.kv {
+        Ok(kv) => {
+            println!(
+                "{}",
+                messages.trust_show_kv_header(&format!("{}/{PATH_CA_TRUST}", state.kv_mount))
+            );
+            print_certs(kv, messages);
+        }
+        Err(reason) => println!("{}", messages.trust_show_kv_unavailable(reason)),
+    }
+    match report.drift() {
+        Some(true) => println!("{}", messages.trust_show_drift_detected()),
+        Some(false) => println!("{}", messages.trust_show_drift_none()),
+        None => {}
+    }
+    Ok(())
+}
+
+async fn build_trust_report(
+    client: Option<&OpenBaoClient>,
+    kv_mount: &str,
+    secrets_dir: &Path,
+    messages: &Messages,
+) -> Result<TrustReport> {
+    let local_fingerprints = compute_ca_fingerprints(secrets_dir, messages).await?;
+    let local_pem = compute_ca_bundle_pem(secrets_dir, messages).await?;
+    let local = summarize_certs(&local_pem, local_fingerprints, messages)?;
+
+    let kv = match client {
+        Some(client) => match read_kv_ca_bundle(client, kv_mount).await {
+            Ok(ca_bundle_pem) => bootroot::tls::ca_bundle_fingerprints(&ca_bundle_pem)
+                .and_then(|fingerprints| summarize_certs(&ca_bundle_pem, fingerprints, messages))
+                .map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        },
+        None => Err(messages.trust_export_no_root_token().to_string()),
+    };
+    Ok(TrustReport { local, kv })
+}
+
+/// Pairs each certificate in `pem` with its fingerprint, in bundle order.
+fn summarize_certs(
+    pem: &str,
+    fingerprints: Vec<String>,
+    messages: &Messages,
+) -> Result<Vec<CaCertSummary>> {
+    let mut summaries = Vec::new();
+    let pems = Pem::iter_from_buffer(pem.as_bytes())
+        .filter(|pem| pem.as_ref().is_ok_and(|pem| pem.label == "CERTIFICATE"));
+    for (pem, sha256) in pems.zip(fingerprints) {
+        let pem = pem.with_context(|| messages.error_trust_export_no_certs())?;
+        let cert = pem
+            .parse_x509()
+            .with_context(|| messages.error_trust_export_no_certs())?;
+        summaries.push(CaCertSummary {
+            is_root: cert.subject().as_raw() == cert.issuer().as_raw(),
+            subject: cert.subject().to_string(),
+            sha256,
+            not_after: cert
+                .validity()
+                .not_after
+                .to_datetime()
+                .format(&Rfc3339)
+                .unwrap_or_default(),
+        });
+    }
+    Ok(summaries)
+}
+
+fn print_certs(certs: &[CaCertSummary], messages: &Messages) {
+    for cert in certs {
+        let role = if cert.is_root {
+            messages.trust_show_role_root()
+        } else {
+            messages.trust_show_role_intermediate()
+        };
+        println!("{}", messages.trust_show_cert(role, &cert.subject));
+        println!("{}", messages.trust_show_cert_sha256(&cert.sha256));
+        println!("{}", messages.trust_show_cert_not_after(&cert.not_after));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::super::test_support::{generate_chain, write_local_certs};
+    use super::*;
+    use crate::i18n::test_messages;
+
+    async fn kv_server(ca_bundle_pem: &str) -> (MockServer, OpenBaoClient) {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/bootroot/ca"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": {
+                    "trusted_ca_sha256": bootroot::tls::ca_bundle_fingerprints(ca_bundle_pem)
+                        .expect("kv bundle fingerprints"),
+                    "ca_bundle_pem": ca_bundle_pem,
+                } }
+            })))
+            .mount(&server)
+            .await;
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+        (server, client)
+    }
+
+    fn is_sha256_hex(value: &str) -> bool {
+        value.len() == 64 && value.bytes().all(|b| b.is_ascii_hexdigit())
+    }
+
+    #[tokio::test]
+    async fn trust_report_lists_both_certs_and_flags_drift() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_local_certs(dir.path(), &generate_chain());
+        let (_server, client) = kv_server(&generate_chain().bundle_pem()).await;
+
+        let report = build_trust_report(Some(&client), "secret", dir.path(), &test_messages())
+            .await
+            .expect("trust report");
+
+        assert_eq!(report.local.len(), 2);
+        assert!(report.local.iter().all(|cert| is_sha256_hex(&cert.sha256)));
+        assert_eq!(
+            report
+                .local
+                .iter()
+                .map(|cert| cert.is_root)
+                .collect::<Vec<_>>(),
+            vec![true, false]
+        );
+        assert!(
+            report
+                .local
+                .first()
+                .is_some_and(|cert| cert.subject.contains("Bootroot Test Root CA"))
+        );
+        assert!(report.local.iter().all(|cert| !cert.not_after.is_empty()));
+        let kv = report.kv.as_ref().expect("kv bundle");
+        assert_eq!(kv.len(), 2);
+        assert!(kv.iter().all(|cert| is_sha256_hex(&cert.sha256)));
+        assert_eq!(report.drift(), Some(true));
+    }
+
+    #[tokio::test]
+    async fn trust_report_has_no_drift_when_kv_matches_local() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let chain = generate_chain();
+        write_local_certs(dir.path(), &chain);
+        let (_server, client) = kv_server(&chain.bundle_pem()).await;
+
+        let report = build_trust_report(Some(&client), "secret", dir.path(), &test_messages())
+            .await
+            .expect("trust report");
+
+        assert_eq!(report.drift(), Some(false));
+    }
+
+    #[tokio::test]
+    async fn trust_report_skips_drift_without_root_token() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_local_certs(dir.path(), &generate_chain());
+
+        let report = build_trust_report(None, "secret", dir.path(), &test_messages())
+            .await
+            .expect("trust report");
+
+        assert_eq!(report.local.len(), 2);
+        assert!(report.kv.is_err());
+        assert_eq!(report.drift(), None);
+    }
+}
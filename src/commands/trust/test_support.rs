@@ -0,0 +1,62 @@
+//! CA chain fixtures shared by the `trust export` and `trust show` tests.
+
+use std::path::Path;
+
+use rcgen::{BasicConstraints, CertificateParams, DnType, IsCa, Issuer, KeyPair};
+
+use crate::commands::init::{CA_CERTS_DIR, CA_INTERMEDIATE_CERT_FILENAME, CA_ROOT_CERT_FILENAME};
+
+/// A freshly generated root and intermediate CA, in PEM and DER.
+pub(super) struct TestChain {
+    pub(super) root_pem: String,
+    pub(super) root_der: Vec<u8>,
+    pub(super) intermediate_pem: String,
+    pub(super) intermediate_der: Vec<u8>,
+}
+
+impl TestChain {
+    /// Returns the root followed by the intermediate, as `init` stores
+    /// the CA bundle.
+    pub(super) fn bundle_pem(&self) -> String {
+        format!("{}{}", self.root_pem, self.intermediate_pem)
+    }
+}
+
+fn ca_params(common_name: &str) -> CertificateParams {
+    let mut params = CertificateParams::new(Vec::new()).expect("certificate params");
+    params
+        .distinguished_name
+        .push(DnType::CommonName, common_name);
+    params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    params
+}
+
+/// Generates a two-level chain with a fixed subject for each CA.
+pub(super) fn generate_chain() -> TestChain {
+    let root_key = KeyPair::generate().expect("root key");
+    let root_params = ca_params("Bootroot Test Root CA");
+    let root = root_params.self_signed(&root_key).expect("root cert");
+    let issuer = Issuer::new(root_params, root_key);
+    let intermediate_key = KeyPair::generate().expect("intermediate key");
+    let intermediate = ca_params("Bootroot Test Intermediate CA")
+        .signed_by(&intermediate_key, &issuer)
+        .expect("intermediate cert");
+    TestChain {
+        root_pem: root.pem(),
+        root_der: root.der().to_vec(),
+        intermediate_pem: intermediate.pem(),
+        intermediate_der: intermediate.der().to_vec(),
+    }
+}
+
+/// Writes `chain` to the `certs/` files under `secrets_dir`.
+pub(super) fn write_local_certs(secrets_dir: &Path, chain: &TestChain) {
+    let certs_dir = secrets_dir.join(CA_CERTS_DIR);
+    std::fs::create_dir_all(&certs_dir).expect("create certs dir");
+    std::fs::write(certs_dir.join(CA_ROOT_CERT_FILENAME), &chain.root_pem).expect("root");
+    std::fs::write(
+        certs_dir.join(CA_INTERMEDIATE_CERT_FILENAME),
+        &chain.intermediate_pem,
+    )
+    .expect("intermediate");
+}
@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use bootroot::db::{check_auth_sync, check_tcp_sync, for_host_runtime, parse_db_dsn};
@@ -69,7 +70,14 @@ pub(crate) fn run_verify(args: &VerifyArgs, messages: &Messages) -> Result<()> {
             .parent()
             .unwrap_or_else(|| Path::new("."))
             .to_path_buf();
-        verify_db_connectivity(&state, &compose_dir, args.db_timeout.timeout_secs, messages)?;
+        let timeout_secs = args.db_timeout.timeout_secs;
+        verify_db_connectivity(
+            &state,
+            &compose_dir,
+            args.db_probe_timeout.tcp_timeout(timeout_secs),
+            args.db_probe_timeout.auth_timeout(timeout_secs),
+            messages,
+        )?;
     }
 
     println!("{}", messages.verify_summary_title());
@@ -96,14 +104,11 @@ pub(crate) fn run_verify(args: &VerifyArgs, messages: &Messages) -> Result<()> {
 fn verify_db_connectivity(
     state: &StateFile,
     compose_dir: &Path,
-    timeout_secs: u64,
+    tcp_timeout: Duration,
+    auth_timeout: Duration,
     messages: &Messages,
 ) -> Result<()> {
-    let secrets_dir = state
-        .secrets_dir()
-        .canonicalize()
-        .with_context(|| messages.error_secrets_dir_resolve_failed())?;
-    let ca_path = secrets_dir.join("config").join("ca.json");
+    let ca_path = state.ca_json_path();
     let contents = std::fs::read_to_string(&ca_path)
         .with_context(|| messages.error_read_file_failed(&ca_path.display().to_string()))?;
     let value: serde_json::Value =
@@ -124,10 +129,9 @@ fn verify_db_connectivity(
         .map_err(|_| anyhow::anyhow!(messages.error_invalid_db_dsn()))?;
     let parsed =
         parse_db_dsn(&dsn).map_err(|_| anyhow::anyhow!(messages.error_invalid_db_dsn()))?;
-    let timeout = std::time::Duration::from_secs(timeout_secs);
-    check_tcp_sync(&parsed.host, parsed.port, timeout)
+    check_tcp_sync(&parsed.host, parsed.port, tcp_timeout)
         .with_context(|| messages.error_db_check_failed())?;
-    check_auth_sync(&dsn, timeout).with_context(|| messages.error_db_auth_failed())?;
+    check_auth_sync(&dsn, auth_timeout).with_context(|| messages.error_db_auth_failed())?;
     Ok(())
 }
 
@@ -414,11 +418,14 @@ mod tests {
                 secret_id_ttl: None,
                 secret_id_wrap_ttl: None,
                 token_bound_cidrs: None,
+                approle_bound_cidrs: None,
             },
             agent_email: None,
             agent_server: None,
             agent_responder_url: None,
             cert_group_gid: None,
+            sync_generation: None,
+            trust_generation: None,
         }
     }
 
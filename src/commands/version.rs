@@ -0,0 +1,81 @@
+use std::process::Command as ProcessCommand;
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::cli::args::VersionArgs;
+use crate::commands::infra::docker_compose_output;
+use crate::i18n::Messages;
+
+const GIT_SHA: &str = env!("BOOTROOT_GIT_SHA");
+const BUILD_EPOCH: &str = env!("BOOTROOT_BUILD_EPOCH");
+const RUSTC_VERSION: &str = env!("BOOTROOT_RUSTC_VERSION");
+
+pub(crate) fn run_version(args: &VersionArgs, verbose: bool, messages: &Messages) {
+    println!("bootroot {}", env!("CARGO_PKG_VERSION"));
+    if !verbose {
+        return;
+    }
+    println!("  {}", messages.version_git_sha(GIT_SHA));
+    println!("  {}", messages.version_build_date(&build_date()));
+    println!("  {}", messages.version_rustc(RUSTC_VERSION));
+    let unavailable = || messages.version_unavailable().to_string();
+    println!(
+        "  {}",
+        messages.version_docker(&tool_version("docker").unwrap_or_else(unavailable))
+    );
+    println!(
+        "  {}",
+        messages.version_podman(&tool_version("podman").unwrap_or_else(unavailable))
+    );
+
+    let compose_file = &args.compose_file.compose_file;
+    println!(
+        "  {}",
+        messages.version_compose_images(&compose_file.display().to_string())
+    );
+    match docker_compose_output(compose_file, &[], &["config", "--images"], messages) {
+        Ok(images) => {
+            for image in images.lines().filter(|line| !line.trim().is_empty()) {
+                println!("    {}", image.trim());
+            }
+        }
+        Err(_) => println!("    {}", messages.version_unavailable()),
+    }
+}
+
+/// Renders the embedded build timestamp as RFC 3339, or the raw value
+/// when the build script could not determine it.
+fn build_date() -> String {
+    BUILD_EPOCH.parse::<u64>().map_or_else(
+        |_| BUILD_EPOCH.to_string(),
+        |secs| {
+            humantime::format_rfc3339_seconds(UNIX_EPOCH + Duration::from_secs(secs)).to_string()
+        },
+    )
+}
+
+/// Returns the first line of `<program> --version`, or `None` when the
+/// tool is not installed or fails.
+fn tool_version(program: &str) -> Option<String> {
+    let output = ProcessCommand::new(program)
+        .arg("--version")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tool_version_returns_none_for_missing_program() {
+        assert!(tool_version("bootroot-definitely-not-installed").is_none());
+    }
+}
@@ -1,11 +1,12 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use anyhow::Result;
-use config::{Config, ConfigError, Environment, File};
+use config::{Config, ConfigError, Environment, File, FileFormat};
 use serde::Deserialize;
 
 mod defaults;
+mod interpolate;
 mod validation;
 
 pub use validation::{
@@ -13,6 +14,10 @@ pub use validation::{
     validate_cert_duration_vs_default_renew_before,
 };
 
+/// Largest accepted `renew_jitter_percent`; bounds how much earlier than
+/// `renew_before` the jittered renewal point can move.
+pub const MAX_RENEW_JITTER_PERCENT: u8 = 50;
+
 /// CLI-provided overrides that must survive config reloads in daemon mode.
 ///
 /// Fields mirror the subset of [`crate::Args`] that [`Settings::merge_with_args`]
@@ -24,6 +29,7 @@ pub struct CliOverrides {
     pub ca_url: Option<String>,
     pub http_responder_url: Option<String>,
     pub http_responder_hmac: Option<String>,
+    pub renew_jitter: Option<u8>,
 }
 
 impl From<&crate::Args> for CliOverrides {
@@ -33,11 +39,12 @@ impl From<&crate::Args> for CliOverrides {
             ca_url: args.ca_url.clone(),
             http_responder_url: args.http_responder_url.clone(),
             http_responder_hmac: args.http_responder_hmac.clone(),
+            renew_jitter: args.renew_jitter,
         }
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct Settings {
     pub email: String,
     pub server: String,
@@ -56,15 +63,28 @@ pub struct Settings {
     /// requests on the KV v2 `reissue` path for each registered service.
     #[serde(default)]
     pub openbao: Option<OpenBaoSettings>,
+    /// Expands `${VAR}` references in `agent.toml` string values. Off by
+    /// default so a literal `${...}` keeps its meaning.
+    #[serde(default)]
+    pub env_interpolation: bool,
+    /// Fails the load when a `${VAR}` reference in `agent.toml` names an
+    /// undefined environment variable, instead of keeping it literal.
+    #[serde(default)]
+    pub strict_env_interpolation: bool,
+    /// Moves each profile's renewal point earlier by up to this percentage
+    /// of `renew_before`, so agents provisioned together do not all renew
+    /// at once. `0` (the default) disables the spread.
+    #[serde(default)]
+    pub renew_jitter_percent: u8,
 }
 
 /// `OpenBao` connection settings for the remote-agent fast-poll loop.
 ///
 /// The remote `bootroot-agent` authenticates directly via `AppRole` and
-/// polls `{kv_mount}/data/bootroot/services/<service>/reissue` on the
+/// polls `{kv_mount}/data/{service_kv_base}/<service>/reissue` on the
 /// configured `fast_poll_interval` to pick up force-reissue requests
 /// issued by the control plane.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct OpenBaoSettings {
     pub url: String,
     /// Opt-in that allows a non-loopback plaintext `http://` `url`.
@@ -79,6 +99,12 @@ pub struct OpenBaoSettings {
     pub allow_plaintext_http: bool,
     #[serde(default = "defaults::default_kv_mount")]
     pub kv_mount: String,
+    /// KV base every per-service payload lives under,
+    /// `<kv_prefix>/services` on the control plane. Must match the
+    /// prefix `state.json` records, or the agent polls a path tree
+    /// `service add` and `rotate` never write.
+    #[serde(default = "defaults::default_service_kv_base")]
+    pub service_kv_base: String,
     pub role_id_path: PathBuf,
     pub secret_id_path: PathBuf,
     #[serde(default)]
@@ -107,19 +133,19 @@ pub fn profile_domain(settings: &Settings, profile: &DaemonProfileSettings) -> S
     )
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct Paths {
     pub cert: PathBuf,
     pub key: PathBuf,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct Eab {
     pub kid: String,
     pub hmac: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct DaemonProfileSettings {
     pub service_name: String,
     pub instance_id: String,
@@ -140,7 +166,7 @@ pub struct DaemonProfileSettings {
     pub cert_group_gid: Option<u32>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct DaemonRuntimeSettings {
     #[serde(default = "defaults::default_check_interval", with = "duration_serde")]
     pub check_interval: Duration,
@@ -150,7 +176,7 @@ pub struct DaemonRuntimeSettings {
     pub check_jitter: Duration,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct AcmeSettings {
     pub http_responder_url: String,
     pub http_responder_hmac: String,
@@ -163,12 +189,12 @@ pub struct AcmeSettings {
     pub poll_interval_secs: u64,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct RetrySettings {
     pub backoff_secs: Vec<u64>,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Default)]
 #[serde(deny_unknown_fields)]
 pub struct TrustSettings {
     #[serde(default)]
@@ -177,19 +203,19 @@ pub struct TrustSettings {
     pub trusted_ca_sha256: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Default)]
 pub struct SchedulerSettings {
     #[serde(default = "defaults::default_max_concurrent_issuances")]
     pub max_concurrent_issuances: u64,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Default)]
 pub struct HookSettings {
     #[serde(default)]
     pub post_renew: PostRenewHooks,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq, Default)]
 pub struct PostRenewHooks {
     #[serde(default)]
     pub success: Vec<HookCommand>,
@@ -197,7 +223,7 @@ pub struct PostRenewHooks {
     pub failure: Vec<HookCommand>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct HookCommand {
     pub command: String,
     #[serde(default)]
@@ -261,8 +287,23 @@ impl Settings {
         // If config_path is provided, use it. Otherwise look for "agent.toml"
         let path = config_path.unwrap_or_else(|| PathBuf::from("agent.toml"));
 
-        // Add file source (required = false, so it doesn't panic if missing)
-        s = s.add_source(File::from(path).required(false));
+        // TOML files get `${VAR}` expansion in their string values first
+        // when they opt in;
+        // anything else is added as-is (required = false, so it doesn't
+        // panic if missing)
+        let toml_contents = path
+            .extension()
+            .is_some_and(|ext| ext == "toml")
+            .then(|| std::fs::read_to_string(&path).ok())
+            .flatten();
+        s = match toml_contents {
+            Some(contents) => {
+                let lookup = |name: &str| std::env::var(name).ok();
+                let expanded = interpolate::interpolate_toml(&contents, &lookup)?;
+                s.add_source(File::from_str(&expanded, FileFormat::Toml))
+            }
+            None => s.add_source(File::from(path).required(false)),
+        };
 
         // 3. Environment Variables (double-underscore for nesting)
         // e.g. BOOTROOT_EMAIL, BOOTROOT_PATHS__CERT, BOOTROOT_DAEMON__RENEW_BEFORE
@@ -299,6 +340,29 @@ impl Settings {
         if let Some(responder_hmac) = &overrides.http_responder_hmac {
             responder_hmac.clone_into(&mut self.acme.http_responder_hmac);
         }
+        if let Some(percent) = overrides.renew_jitter {
+            self.renew_jitter_percent = percent;
+        }
+    }
+
+    /// Re-reads `config_path`, re-applies `overrides`, and replaces these
+    /// settings with the result.
+    ///
+    /// The reloaded settings are validated before anything is replaced, so
+    /// a truncated or half-rendered file leaves `self` untouched.
+    ///
+    /// Returns whether any value changed.
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be parsed or the reloaded settings
+    /// are invalid.
+    pub fn reload(&mut self, config_path: &Path, overrides: &CliOverrides) -> Result<bool> {
+        let mut fresh = Self::new(Some(config_path.to_path_buf()))?;
+        fresh.apply_overrides(overrides);
+        fresh.validate()?;
+        let changed = fresh != *self;
+        *self = fresh;
+        Ok(changed)
     }
 
     /// Validates configuration values for correctness.
@@ -374,6 +438,78 @@ mod tests {
         assert!(profile.hooks.post_renew.failure.is_empty());
     }
 
+    #[test]
+    fn test_reload_picks_up_changes_and_keeps_settings_on_invalid_file() {
+        let write_with_eab_hmac = |path: &Path, hmac: &str| {
+            let body = format!(
+                r#"
+                domain = "trusted.domain"
+                [eab]
+                kid = "kid-1"
+                hmac = "{hmac}"
+                [acme]
+                http_responder_url = "http://localhost:8080"
+                http_responder_hmac = "dev-hmac"
+
+                [[profiles]]
+                service_name = "edge-proxy"
+                instance_id = "001"
+                hostname = "edge-node-01"
+
+                [profiles.paths]
+                cert = "certs/edge-proxy-a.pem"
+                key = "certs/edge-proxy-a.key"
+            "#
+            );
+            std::fs::write(path, body).unwrap();
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("agent.toml");
+        let overrides = CliOverrides::default();
+        write_with_eab_hmac(&path, "old-hmac");
+        let mut settings = Settings::new(Some(path.clone())).unwrap();
+
+        assert!(!settings.reload(&path, &overrides).unwrap());
+
+        write_with_eab_hmac(&path, "new-hmac");
+        assert!(settings.reload(&path, &overrides).unwrap());
+        assert_eq!(settings.eab.as_ref().unwrap().hmac, "new-hmac");
+
+        // A render cut off before the profiles parses but does not validate.
+        std::fs::write(
+            &path,
+            "domain = \"trusted.domain\"\n[eab]\nkid = \"kid-1\"\nhmac = \"partial\"\n",
+        )
+        .unwrap();
+        assert!(settings.reload(&path, &overrides).is_err());
+        std::fs::write(&path, "domain = \"trusted.").unwrap();
+        assert!(settings.reload(&path, &overrides).is_err());
+        assert_eq!(settings.eab.as_ref().unwrap().hmac, "new-hmac");
+        assert_eq!(settings.profiles.len(), 1);
+    }
+
+    #[test]
+    fn test_load_settings_strict_env_interpolation_rejects_undefined_var() {
+        let mut file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+        writeln!(file, "email = \"${{BOOTROOT_TEST_UNDEFINED_EMAIL}}\"").unwrap();
+        write_minimal_profile_config(&mut file);
+
+        let settings = Settings::new(Some(file.path().to_path_buf())).unwrap();
+        assert_eq!(settings.email, "${BOOTROOT_TEST_UNDEFINED_EMAIL}");
+
+        let contents = std::fs::read_to_string(file.path()).unwrap();
+        std::fs::write(
+            file.path(),
+            format!("env_interpolation = true\nstrict_env_interpolation = true\n{contents}"),
+        )
+        .unwrap();
+        let err = Settings::new(Some(file.path().to_path_buf())).unwrap_err();
+        assert!(
+            err.to_string().contains("BOOTROOT_TEST_UNDEFINED_EMAIL"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_load_settings_rejects_invalid_duration() {
         let mut file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
@@ -471,6 +607,8 @@ mod tests {
             eab_file: None,
             oneshot: false,
             insecure: false,
+            metrics_addr: None,
+            renew_jitter: None,
         };
 
         settings.merge_with_args(&args);
@@ -495,6 +633,7 @@ mod tests {
             ca_url: Some("https://override-ca".to_string()),
             http_responder_url: Some("http://override-responder".to_string()),
             http_responder_hmac: Some("override-hmac".to_string()),
+            renew_jitter: Some(10),
         };
 
         settings.apply_overrides(&overrides);
@@ -506,6 +645,7 @@ mod tests {
             "http://override-responder"
         );
         assert_eq!(settings.acme.http_responder_hmac, "override-hmac");
+        assert_eq!(settings.renew_jitter_percent, 10);
     }
 
     #[test]
@@ -557,6 +697,7 @@ mod tests {
             ca_url: Some("https://cli-ca".to_string()),
             http_responder_url: None,
             http_responder_hmac: Some("cli-hmac-secret".to_string()),
+            renew_jitter: None,
         };
 
         // Simulate the daemon retry path: reload from disk, then apply overrides.
@@ -91,6 +91,10 @@ pub(crate) fn default_kv_mount() -> String {
     DEFAULT_KV_MOUNT.to_string()
 }
 
+pub(crate) fn default_service_kv_base() -> String {
+    crate::trust_bootstrap::SERVICE_KV_BASE.to_string()
+}
+
 pub(crate) fn default_fast_poll_state_path() -> PathBuf {
     PathBuf::from(DEFAULT_FAST_POLL_STATE_PATH)
 }
@@ -0,0 +1,236 @@
+//! `${VAR}` expansion for string values in `agent.toml`.
+//!
+//! Expansion is opt-in through `env_interpolation = true`, so existing
+//! configs that hold a literal `${...}` (for example in hook args) keep
+//! their meaning. Only string values are expanded; keys, comments, and
+//! non-string values are left as written. `$${VAR}` is an escape for a
+//! literal `${VAR}`. Secret-bearing keys are never expanded, and errors
+//! name the key and the variable but never a variable's value, so a
+//! secret pulled from the environment cannot leak into logs.
+
+use config::ConfigError;
+use toml_edit::{DocumentMut, Item, Table, Value};
+
+/// Top-level `agent.toml` key that turns expansion on.
+pub(crate) const ENABLE_KEY: &str = "env_interpolation";
+
+/// Top-level `agent.toml` key that turns a reference to an undefined
+/// variable into a load error instead of leaving it literal.
+pub(crate) const STRICT_KEY: &str = "strict_env_interpolation";
+
+/// Keys whose values are secrets. They are logged through `Debug` and
+/// carried in error context, so they must come from the file or from
+/// their `BOOTROOT_*` override, never from an interpolated variable.
+const SECRET_KEYS: &[&str] = &["eab.hmac", "acme.http_responder_hmac"];
+
+/// Expands `${VAR}` references in every string value of the TOML
+/// `contents`, resolving names through `lookup`, when the file sets
+/// `env_interpolation = true`. Otherwise returns `contents` unchanged.
+///
+/// # Errors
+/// Returns an error if `contents` is not valid TOML, if a secret-bearing
+/// key holds a `${` reference, or if `strict_env_interpolation = true`
+/// and a referenced variable is undefined.
+pub(crate) fn interpolate_toml(
+    contents: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<String, ConfigError> {
+    let mut doc: DocumentMut = contents
+        .parse()
+        .map_err(|err| ConfigError::Message(format!("invalid agent config TOML: {err}")))?;
+    if !doc.get(ENABLE_KEY).and_then(Item::as_bool).unwrap_or(false) {
+        return Ok(contents.to_string());
+    }
+    let strict = doc.get(STRICT_KEY).and_then(Item::as_bool).unwrap_or(false);
+    let expander = Expander { strict, lookup };
+    expander.table(doc.as_table_mut(), "")?;
+    Ok(doc.to_string())
+}
+
+struct Expander<'a> {
+    strict: bool,
+    lookup: &'a dyn Fn(&str) -> Option<String>,
+}
+
+impl Expander<'_> {
+    fn table(&self, table: &mut Table, prefix: &str) -> Result<(), ConfigError> {
+        for (key, item) in table.iter_mut() {
+            self.item(item, &join_key(prefix, key.get()))?;
+        }
+        Ok(())
+    }
+
+    fn item(&self, item: &mut Item, key: &str) -> Result<(), ConfigError> {
+        match item {
+            Item::Value(value) => self.value(value, key),
+            Item::Table(table) => self.table(table, key),
+            Item::ArrayOfTables(tables) => {
+                for table in tables.iter_mut() {
+                    self.table(table, key)?;
+                }
+                Ok(())
+            }
+            Item::None => Ok(()),
+        }
+    }
+
+    fn value(&self, value: &mut Value, key: &str) -> Result<(), ConfigError> {
+        match value {
+            Value::String(raw) if SECRET_KEYS.contains(&key) => {
+                if raw.value().contains("${") {
+                    return Err(ConfigError::Message(format!(
+                        "agent config `{key}` holds a secret and is not expanded from the \
+                         environment; set it through `BOOTROOT_{}` instead",
+                        key.to_ascii_uppercase().replace('.', "__")
+                    )));
+                }
+                Ok(())
+            }
+            Value::String(raw) => {
+                let expanded = self.expand(raw.value(), key)?;
+                if expanded != *raw.value() {
+                    *value = Value::from(expanded);
+                }
+                Ok(())
+            }
+            Value::Array(values) => {
+                for value in values.iter_mut() {
+                    self.value(value, key)?;
+                }
+                Ok(())
+            }
+            Value::InlineTable(table) => {
+                for (child, value) in table.iter_mut() {
+                    self.value(value, &join_key(key, child.get()))?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Expands the references in one string value found at `key`.
+    fn expand(&self, input: &str, key: &str) -> Result<String, ConfigError> {
+        let mut expanded = String::with_capacity(input.len());
+        let mut rest = input;
+        while let Some(pos) = rest.find('$') {
+            let (before, from_dollar) = rest.split_at(pos);
+            expanded.push_str(before);
+            if let Some(escaped) = from_dollar.strip_prefix("$${") {
+                expanded.push_str("${");
+                rest = escaped;
+                continue;
+            }
+            if let Some(reference) = from_dollar.strip_prefix("${")
+                && let Some((name, after)) = reference.split_once('}')
+                && is_var_name(name)
+            {
+                match (self.lookup)(name) {
+                    Some(resolved) => expanded.push_str(&resolved),
+                    None if self.strict => {
+                        return Err(ConfigError::Message(format!(
+                            "agent config `{key}` references undefined environment variable \
+                             `{name}` ({STRICT_KEY} is on)"
+                        )));
+                    }
+                    None => {
+                        expanded.push_str("${");
+                        expanded.push_str(name);
+                        expanded.push('}');
+                    }
+                }
+                rest = after;
+                continue;
+            }
+            expanded.push('$');
+            rest = from_dollar.get(1..).unwrap_or_default();
+        }
+        expanded.push_str(rest);
+        Ok(expanded)
+    }
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+fn is_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        (name == "CERT_DIR").then(|| "/srv/certs".to_string())
+    }
+
+    fn expand(contents: &str) -> Result<toml_edit::DocumentMut, ConfigError> {
+        interpolate_toml(&format!("{ENABLE_KEY} = true\n{contents}"), &lookup)
+            .map(|out| out.parse().unwrap())
+    }
+
+    #[test]
+    fn defined_variable_is_substituted_in_nested_strings() {
+        let doc = expand(
+            "[[profiles]]\n[profiles.paths]\ncert = \"${CERT_DIR}/cert.pem\"\n\
+             [retry]\nbackoff_secs = [5]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            doc["profiles"][0]["paths"]["cert"].as_str(),
+            Some("/srv/certs/cert.pem")
+        );
+        assert_eq!(doc["retry"]["backoff_secs"][0].as_integer(), Some(5));
+    }
+
+    #[test]
+    fn undefined_variable_stays_literal_unless_strict() {
+        let doc = expand("domain = \"${MISSING}.internal\"\n").unwrap();
+        assert_eq!(doc["domain"].as_str(), Some("${MISSING}.internal"));
+
+        let err =
+            expand("strict_env_interpolation = true\n[eab]\nkid = \"${MISSING}\"\n").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("eab.kid"), "{message}");
+        assert!(message.contains("MISSING"), "{message}");
+    }
+
+    #[test]
+    fn escaped_reference_is_kept_literal() {
+        let doc =
+            expand("strict_env_interpolation = true\nemail = \"$${LITERAL}-$5-${CERT_DIR}\"\n")
+                .unwrap();
+        assert_eq!(doc["email"].as_str(), Some("${LITERAL}-$5-/srv/certs"));
+    }
+
+    #[test]
+    fn references_stay_literal_without_opt_in() {
+        let contents = "[[profiles]]\nargs = [\"${CERT_DIR}\"]\n";
+        assert_eq!(interpolate_toml(contents, &lookup).unwrap(), contents);
+    }
+
+    #[test]
+    fn secret_keys_are_never_expanded() {
+        let err = expand("[acme]\nhttp_responder_hmac = \"${CERT_DIR}\"\n").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("acme.http_responder_hmac"), "{message}");
+        assert!(
+            message.contains("BOOTROOT_ACME__HTTP_RESPONDER_HMAC"),
+            "{message}"
+        );
+        assert!(!message.contains("/srv/certs"), "{message}");
+
+        let doc = expand("[eab]\nhmac = \"plain-hmac\"\n").unwrap();
+        assert_eq!(doc["eab"]["hmac"].as_str(), Some("plain-hmac"));
+    }
+}
@@ -5,7 +5,10 @@ use anyhow::{Context, Result};
 use reqwest::Url;
 
 use super::defaults::default_renew_before;
-use super::{DaemonProfileSettings, HookCommand, OpenBaoSettings, Settings, TrustSettings};
+use super::{
+    DaemonProfileSettings, HookCommand, MAX_RENEW_JITTER_PERCENT, OpenBaoSettings, Settings,
+    TrustSettings,
+};
 
 /// Validates that `cert_duration` is strictly greater than the default
 /// daemon `renew_before` interval.
@@ -82,6 +85,9 @@ pub(crate) fn validate_settings(settings: &Settings) -> Result<()> {
             "acme.directory_fetch_base_delay_secs must be <= acme.directory_fetch_max_delay_secs"
         );
     }
+    if settings.renew_jitter_percent > MAX_RENEW_JITTER_PERCENT {
+        anyhow::bail!("renew_jitter_percent must be <= {MAX_RENEW_JITTER_PERCENT}");
+    }
     if settings.retry.backoff_secs.is_empty() {
         anyhow::bail!("retry.backoff_secs must not be empty");
     }
@@ -170,6 +176,9 @@ fn validate_openbao_settings(settings: &OpenBaoSettings) -> Result<()> {
     if settings.kv_mount.trim().is_empty() {
         anyhow::bail!("openbao.kv_mount must not be empty");
     }
+    if settings.service_kv_base.trim_matches('/').trim().is_empty() {
+        anyhow::bail!("openbao.service_kv_base must not be empty");
+    }
     if settings.role_id_path.as_os_str().is_empty() {
         anyhow::bail!("openbao.role_id_path must not be empty");
     }
@@ -352,6 +361,7 @@ mod tests {
             url: url.to_string(),
             allow_plaintext_http,
             kv_mount: "secret".to_string(),
+            service_kv_base: "bootroot/services".to_string(),
             role_id_path: std::path::PathBuf::from("/etc/bootroot/role_id"),
             secret_id_path: std::path::PathBuf::from("/etc/bootroot/secret_id"),
             ca_bundle_path: None,
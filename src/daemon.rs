@@ -1,5 +1,6 @@
 use std::collections::BTreeMap;
 use std::future::Future;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Duration;
@@ -7,6 +8,7 @@ use std::time::Duration;
 use tokio::sync::{Mutex as TokioMutex, Semaphore, watch};
 use tracing::{error, info, warn};
 
+use crate::metrics::AgentMetrics;
 use crate::{acme, cert_chain, config, eab, fast_poll, hooks, profile, utils};
 
 const DEFAULT_AGENT_CONFIG_PATH: &str = "agent.toml";
@@ -16,6 +18,7 @@ struct IssuanceRuntime {
     config_path: PathBuf,
     insecure_mode: bool,
     cli_overrides: config::CliOverrides,
+    metrics: Arc<AgentMetrics>,
 }
 
 /// Per-profile single-flight registry.
@@ -61,10 +64,15 @@ impl Default for ProfileLocks {
     }
 }
 
-/// Runs the agent daemon loop for all profiles.
+/// Runs the agent daemon loop for all profiles, recording each renewal
+/// and the resulting certificate expiry into `metrics`.
+///
+/// SIGTERM or SIGINT stops each loop at its next wait, after any
+/// in-flight issuance and its hooks finish, and then returns `Ok`.
 ///
 /// # Errors
-/// Returns an error if issuance or shutdown handling fails.
+/// Returns an error if a profile or fast-poll loop fails; the other loops
+/// are stopped first.
 pub(crate) async fn run_daemon(
     settings: Arc<config::Settings>,
     default_eab: Option<eab::EabCredentials>,
@@ -72,6 +80,7 @@ pub(crate) async fn run_daemon(
     config_path: Option<PathBuf>,
     insecure_mode: bool,
     cli_overrides: config::CliOverrides,
+    metrics: Arc<AgentMetrics>,
 ) -> anyhow::Result<()> {
     let max_concurrent = profile::max_concurrent_issuances(&settings)?;
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
@@ -81,6 +90,7 @@ pub(crate) async fn run_daemon(
         config_path: resolve_config_path(config_path.as_deref()),
         insecure_mode,
         cli_overrides,
+        metrics,
     };
 
     // `default_eab` becomes shared, live-readable state: both the periodic
@@ -92,12 +102,25 @@ pub(crate) async fn run_daemon(
     let (eab_tx, eab_rx) = watch::channel(default_eab);
     let shared_eab = eab::SharedEab::from_receiver(eab_rx);
 
-    let shutdown_handle = tokio::spawn(async move {
-        if let Err(err) = wait_for_shutdown().await {
-            error!("Shutdown signal handler error: {err}");
-        }
-        let _ = shutdown_tx.send(true);
-    });
+    // Stops every loop on SIGTERM/SIGINT, or as soon as one of them fails
+    // (see `stop_all_on_error`), so a crashed loop ends the process with
+    // an error instead of leaving the rest running under a supervisor
+    // that never learns about it.
+    let shutdown_tx = Arc::new(shutdown_tx);
+    let shutdown_handle = {
+        let shutdown_tx = Arc::clone(&shutdown_tx);
+        let mut stopped = shutdown_rx.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                result = wait_for_shutdown() => match result {
+                    Ok(()) => info!("Shutdown signal received. Finishing in-flight work."),
+                    Err(err) => error!("Shutdown signal handler error: {err}"),
+                },
+                _ = stopped.changed() => {}
+            }
+            let _ = shutdown_tx.send(true);
+        })
+    };
 
     let mut handles = Vec::new();
     for profile in settings.profiles.clone() {
@@ -108,7 +131,8 @@ pub(crate) async fn run_daemon(
         let shared_eab = shared_eab.clone();
         let runtime = runtime.clone();
 
-        handles.push(tokio::spawn(async move {
+        handles.push(tokio::spawn(stop_all_on_error(
+            Arc::clone(&shutdown_tx),
             run_profile_daemon(
                 settings,
                 profile,
@@ -117,9 +141,8 @@ pub(crate) async fn run_daemon(
                 profile_locks,
                 shutdown_rx,
                 runtime,
-            )
-            .await
-        }));
+            ),
+        )));
     }
 
     if settings.openbao.is_some() {
@@ -135,6 +158,7 @@ pub(crate) async fn run_daemon(
             path,
             sender: eab_tx,
         });
+        let stop_all_fast = Arc::clone(&shutdown_tx);
         handles.push(tokio::spawn(async move {
             let renew = move |profile: config::DaemonProfileSettings,
                               semaphore: Arc<Semaphore>|
@@ -155,13 +179,16 @@ pub(crate) async fn run_daemon(
                     .await
                 })
             };
-            fast_poll::run_fast_poll_loop(
-                settings_for_loop,
-                config_path_for_fast,
-                eab_refresh,
-                semaphore_for_fast,
-                shutdown_rx_fast,
-                renew,
+            stop_all_on_error(
+                stop_all_fast,
+                fast_poll::run_fast_poll_loop(
+                    settings_for_loop,
+                    config_path_for_fast,
+                    eab_refresh,
+                    semaphore_for_fast,
+                    shutdown_rx_fast,
+                    renew,
+                ),
             )
             .await
         }));
@@ -171,6 +198,19 @@ pub(crate) async fn run_daemon(
     collect_task_results(handles, "daemon").await
 }
 
+/// Runs one daemon loop and, if it fails, tells every other loop to shut
+/// down too so `run_daemon` returns the error.
+async fn stop_all_on_error(
+    shutdown_tx: Arc<watch::Sender<bool>>,
+    task: impl Future<Output = anyhow::Result<()>>,
+) -> anyhow::Result<()> {
+    let result = task.await;
+    if result.is_err() {
+        let _ = shutdown_tx.send(true);
+    }
+    result
+}
+
 async fn run_profile_daemon(
     settings: Arc<config::Settings>,
     profile: config::DaemonProfileSettings,
@@ -181,13 +221,16 @@ async fn run_profile_daemon(
     runtime: IssuanceRuntime,
 ) -> anyhow::Result<()> {
     let check_interval = profile.daemon.check_interval;
-    let renew_before = profile.daemon.renew_before;
     let check_jitter = profile.daemon.check_jitter;
     let profile_label = config::profile_domain(&settings, &profile);
 
     info!(
-        "Profile '{}' daemon enabled. check_interval={:?}, renew_before={:?}, check_jitter={:?}",
-        profile_label, check_interval, renew_before, check_jitter
+        "Profile '{}' daemon enabled. check_interval={:?}, renew_before={:?}, renew_jitter_percent={}, check_jitter={:?}",
+        profile_label,
+        check_interval,
+        profile.daemon.renew_before,
+        settings.renew_jitter_percent,
+        check_jitter
     );
 
     let mut first_tick = true;
@@ -219,7 +262,6 @@ async fn run_profile_daemon(
                     shared_eab.current(),
                     Arc::clone(&semaphore),
                     &profile_locks,
-                    renew_before,
                     &runtime,
                 )
                 .await?;
@@ -246,6 +288,7 @@ pub(crate) async fn run_oneshot(
         config_path: resolve_config_path(config_path.as_deref()),
         insecure_mode,
         cli_overrides: config::CliOverrides::default(),
+        metrics: Arc::new(AgentMetrics::default()),
     };
     let mut handles = Vec::new();
 
@@ -379,7 +422,8 @@ async fn issue_with_retry(
 
 /// Reloads `agent.toml` for a single retry attempt and locates the
 /// target profile. Falls back to the supplied in-memory pair when the
-/// reload fails or the profile is absent from the reloaded file.
+/// reload fails (including a reloaded file that does not validate) or
+/// the profile is absent from the reloaded file.
 ///
 /// The fallback path exists because some `agent.toml` writers (the
 /// remote bootstrap/fast-poll appliers, operator edits) still rewrite
@@ -399,9 +443,15 @@ fn reload_profile_or_fallback(
     profile_domain: &str,
     fallback: (config::Settings, config::DaemonProfileSettings),
 ) -> (config::Settings, config::DaemonProfileSettings) {
-    match config::Settings::new(Some(config_path.to_path_buf())) {
-        Ok(mut fresh) => {
-            fresh.apply_overrides(overrides);
+    let mut fresh = fallback.0.clone();
+    match fresh.reload(config_path, overrides) {
+        Ok(changed) => {
+            if changed {
+                tracing::debug!(
+                    "Agent config changed since '{profile_domain}' was last loaded; \
+                     using reloaded values for this attempt"
+                );
+            }
             if let Some(matched) = fresh
                 .profiles
                 .iter()
@@ -568,12 +618,31 @@ pub(crate) async fn should_renew(
 /// # Errors
 /// Returns an error if the certificate cannot be parsed.
 pub(crate) fn parse_cert_not_after(cert_bytes: &[u8]) -> anyhow::Result<time::OffsetDateTime> {
+    parse_cert_validity(cert_bytes).map(|(_, not_after)| not_after)
+}
+
+/// Parses the certificate validity period (`notAfter - notBefore`).
+///
+/// # Errors
+/// Returns an error if the certificate cannot be parsed.
+fn parse_cert_lifetime(cert_bytes: &[u8]) -> anyhow::Result<Duration> {
+    let (not_before, not_after) = parse_cert_validity(cert_bytes)?;
+    Ok(Duration::try_from(not_after - not_before).unwrap_or_default())
+}
+
+fn parse_cert_validity(
+    cert_bytes: &[u8],
+) -> anyhow::Result<(time::OffsetDateTime, time::OffsetDateTime)> {
     let pem = x509_parser::pem::parse_x509_pem(cert_bytes)
         .map_err(|e| anyhow::anyhow!("Failed to parse PEM certificate: {e}"))?
         .1;
     let (_, cert) = x509_parser::parse_x509_certificate(&pem.contents)
         .map_err(|e| anyhow::anyhow!("Failed to parse X509 certificate: {e}"))?;
-    Ok(cert.validity().not_after.to_datetime())
+    let validity = cert.validity();
+    Ok((
+        validity.not_before.to_datetime(),
+        validity.not_after.to_datetime(),
+    ))
 }
 
 /// Renews a profile unconditionally, bypassing the `should_renew` expiry
@@ -609,6 +678,14 @@ async fn force_renew_profile(
             profile_label
         );
     }
+    record_issuance_metrics(
+        runtime,
+        &profile_label,
+        profile,
+        effective_renew_before(settings, profile, &profile_label).await,
+        result.is_ok(),
+    )
+    .await;
     handle_issuance_result(&result, settings, profile, &profile_label).await?;
     result
 }
@@ -619,7 +696,6 @@ async fn check_and_renew_profile(
     default_eab: Option<eab::EabCredentials>,
     semaphore: Arc<Semaphore>,
     profile_locks: &ProfileLocks,
-    renew_before: Duration,
     runtime: &IssuanceRuntime,
 ) -> anyhow::Result<()> {
     let profile_label = config::profile_domain(settings, profile);
@@ -633,6 +709,7 @@ async fn check_and_renew_profile(
     let lock = profile_locks.for_profile(&profile_label);
     let _profile_guard = lock.lock().await;
 
+    let renew_before = effective_renew_before(settings, profile, &profile_label).await;
     let needs_renewal = match should_renew(profile, &settings.trust, renew_before).await {
         Ok(val) => val,
         Err(err) => {
@@ -640,6 +717,10 @@ async fn check_and_renew_profile(
             return Ok(());
         }
     };
+    runtime
+        .metrics
+        .observe_cert(&profile_label, &profile.paths.cert, renew_before)
+        .await;
 
     if !needs_renewal {
         tracing::debug!("Profile '{}' certificate still valid.", profile_label);
@@ -659,10 +740,68 @@ async fn check_and_renew_profile(
             profile_label
         );
     }
+    record_issuance_metrics(
+        runtime,
+        &profile_label,
+        profile,
+        effective_renew_before(settings, profile, &profile_label).await,
+        result.is_ok(),
+    )
+    .await;
     handle_issuance_result(&result, settings, profile, &profile_label).await?;
     Ok(())
 }
 
+/// Records an issuance outcome and the certificate it left on disk.
+async fn record_issuance_metrics(
+    runtime: &IssuanceRuntime,
+    profile_label: &str,
+    profile: &config::DaemonProfileSettings,
+    renew_before: Duration,
+    succeeded: bool,
+) {
+    runtime
+        .metrics
+        .record_issuance(profile_label, succeeded, time::OffsetDateTime::now_utc());
+    runtime
+        .metrics
+        .observe_cert(profile_label, &profile.paths.cert, renew_before)
+        .await;
+}
+
+/// Returns the profile's `renew_before` extended by `renew_jitter_percent`
+/// and bounded by the lifetime of the certificate currently on disk.
+///
+/// The spread is seeded from the profile label, so it stays fixed across
+/// checks and reloads while agents for different services or instances
+/// land on different renewal points. Without a readable certificate the
+/// configured `renew_before` is used as is.
+async fn effective_renew_before(
+    settings: &config::Settings,
+    profile: &config::DaemonProfileSettings,
+    profile_label: &str,
+) -> Duration {
+    let renew_before = profile.daemon.renew_before;
+    if settings.renew_jitter_percent == 0 {
+        return renew_before;
+    }
+    let Some(lifetime) = tokio::fs::read(&profile.paths.cert)
+        .await
+        .ok()
+        .and_then(|bytes| parse_cert_lifetime(&bytes).ok())
+    else {
+        return renew_before;
+    };
+    let mut hasher = std::hash::DefaultHasher::new();
+    profile_label.hash(&mut hasher);
+    utils::jittered_renew_before(
+        renew_before,
+        settings.renew_jitter_percent,
+        hasher.finish(),
+        lifetime,
+    )
+}
+
 fn resolve_config_path(config_path: Option<&Path>) -> PathBuf {
     config_path.map_or_else(
         || PathBuf::from(DEFAULT_AGENT_CONFIG_PATH),
@@ -761,6 +900,9 @@ mod tests {
             },
             profiles: Vec::new(),
             openbao: None,
+            env_interpolation: false,
+            strict_env_interpolation: false,
+            renew_jitter_percent: 0,
         }
     }
 
@@ -996,6 +1138,34 @@ mod tests {
         assert_eq!(parsed.unix_timestamp(), not_after.unix_timestamp());
     }
 
+    #[tokio::test]
+    async fn test_max_renew_jitter_keeps_fresh_default_cert_valid() {
+        let dir = tempfile::tempdir().unwrap();
+        let cert_path = dir.path().join("fresh.pem");
+        let now = time::OffsetDateTime::now_utc();
+        let mut params = rcgen::CertificateParams::new(vec![TEST_DOMAIN.to_string()]).unwrap();
+        params.not_before = now;
+        params.not_after = now + time::Duration::hours(24);
+        let key = rcgen::KeyPair::generate().unwrap();
+        fs::write(&cert_path, params.self_signed(&key).unwrap().pem()).unwrap();
+        let profile = build_profile(cert_path);
+        let mut settings = build_settings(Vec::new());
+        settings.renew_jitter_percent = config::MAX_RENEW_JITTER_PERCENT;
+
+        for instance in 0..50 {
+            let label = format!("{instance:03}.edge-proxy.edge-node-01.trusted.domain");
+            let renew_before = effective_renew_before(&settings, &profile, &label).await;
+            assert!(renew_before >= profile.daemon.renew_before);
+            assert!(renew_before < Duration::from_hours(24));
+            assert!(
+                !should_renew(&profile, &settings.trust, renew_before)
+                    .await
+                    .unwrap(),
+                "{label} renews right after issuance with renew_before={renew_before:?}"
+            );
+        }
+    }
+
     /// Regression for issue #627: when `[trust].ca_bundle_path` is
     /// configured, a time-valid leaf whose signer is no longer in the
     /// bundle (the post-`init`-rotation state) must force a reissue.
@@ -1170,6 +1340,62 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_profile_daemon_exits_cleanly_on_shutdown_while_sleeping() {
+        let dir = tempfile::tempdir().unwrap();
+        let cert_path = dir.path().join("valid.pem");
+        write_cert(
+            &cert_path,
+            time::OffsetDateTime::now_utc() + time::Duration::days(90),
+        );
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let handle = tokio::spawn(run_profile_daemon(
+            Arc::new(build_settings(TEST_DELAYS.to_vec())),
+            build_profile(cert_path),
+            eab::SharedEab::from_receiver(watch::channel(None).1),
+            Arc::new(Semaphore::new(1)),
+            Arc::new(ProfileLocks::new()),
+            shutdown_rx,
+            IssuanceRuntime {
+                config_path: dir.path().join("agent.toml"),
+                insecure_mode: false,
+                cli_overrides: config::CliOverrides::default(),
+                metrics: Arc::new(AgentMetrics::default()),
+            },
+        ));
+
+        // The first check finds the certificate valid, so the loop is now
+        // waiting out its one-hour check interval.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!handle.is_finished());
+        shutdown_tx.send(true).unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("profile loop must stop on shutdown")
+            .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stop_all_on_error_signals_shutdown_only_on_failure() {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let shutdown_tx = Arc::new(shutdown_tx);
+
+        stop_all_on_error(Arc::clone(&shutdown_tx), async { Ok(()) })
+            .await
+            .unwrap();
+        assert!(!*shutdown_rx.borrow());
+
+        let err = stop_all_on_error(Arc::clone(&shutdown_tx), async {
+            anyhow::bail!("profile loop crashed")
+        })
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("profile loop crashed"));
+        assert!(*shutdown_rx.borrow());
+    }
+
     #[tokio::test]
     async fn test_collect_task_results_returns_ok_when_all_succeed() {
         let handles = vec![
@@ -10,10 +10,14 @@ use crate::fs_util;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EabCredentials {
+    // step-ca's admin EAB API names the key id `id` (`keyId` in some
+    // clients), so a saved `POST /admin/acme/eab` response loads as-is.
+    #[serde(alias = "id", alias = "keyId")]
     pub kid: String,
     // `key` is step-ca's native EAB field name; accept it for interop with
     // step-ca-produced files, not as an older bootroot spelling of `hmac`.
-    #[serde(alias = "key")]
+    // The admin EAB API returns the same value as `hmacKey`.
+    #[serde(alias = "key", alias = "hmacKey")]
     pub hmac: String,
 }
 
@@ -218,6 +222,29 @@ mod tests {
         assert_eq!(creds.kid, "test-kid");
         assert_eq!(creds.hmac, "test-hmac");
     }
+    #[test]
+    fn step_ca_admin_eab_responses_parse() {
+        // `POST /admin/acme/eab` response body from step-ca v0.2x.
+        let created = r#"{
+            "id": "IMi2SqLH5MJR6rZvGvN1r3fCgxVNMcsa",
+            "provisioner": "acme",
+            "reference": "edge-proxy",
+            "account": "",
+            "hmacKey": "cmVhbGx5LXJlYWxseS1sb25nLWhtYWMta2V5",
+            "createdAt": "2024-05-01T10:00:00Z",
+            "boundAt": ""
+        }"#;
+        let creds: EabCredentials = serde_json::from_str(created).unwrap();
+        assert_eq!(creds.kid, "IMi2SqLH5MJR6rZvGvN1r3fCgxVNMcsa");
+        assert_eq!(creds.hmac, "cmVhbGx5LXJlYWxseS1sb25nLWhtYWMta2V5");
+
+        // Older clients spelled the key id `keyId`.
+        let legacy = r#"{"keyId": "kid-1", "hmacKey": "hmac-1"}"#;
+        let creds: EabCredentials = serde_json::from_str(legacy).unwrap();
+        assert_eq!(creds.kid, "kid-1");
+        assert_eq!(creds.hmac, "hmac-1");
+    }
+
     #[tokio::test]
     async fn test_load_credentials_file_malformed() {
         let mut file = NamedTempFile::new().unwrap();
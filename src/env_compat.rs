@@ -0,0 +1,131 @@
+//! Vault-compatible environment variable aliases.
+//!
+//! `OpenBao` is a Vault fork, so shells set up for the `bao` or `vault`
+//! CLIs export `BAO_*`/`VAULT_*` names rather than the `OPENBAO_*` names
+//! bootroot reads.  [`apply_vault_env_aliases`] copies the first set
+//! alias into each unset `OPENBAO_*` variable before argument parsing, so
+//! the `OPENBAO_*` name always takes precedence, then `BAO_*`, then
+//! `VAULT_*`.
+
+/// Variable that `--openbao-url` reads in both binaries.
+pub const OPENBAO_URL_ENV: &str = "OPENBAO_URL";
+
+/// Each bootroot variable with its secondary sources, highest precedence
+/// first.
+pub const ENV_ALIASES: &[(&str, &[&str])] = &[
+    ("OPENBAO_ROOT_TOKEN", &["BAO_TOKEN", "VAULT_TOKEN"]),
+    (OPENBAO_URL_ENV, &["OPENBAO_ADDR", "BAO_ADDR", "VAULT_ADDR"]),
+];
+
+/// Returns the first non-empty value of `primary` or, failing that, of
+/// one of its [`ENV_ALIASES`], together with the name it was read from.
+pub fn lookup_with_aliases(
+    primary: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Option<(&'static str, String)> {
+    let (name, aliases) = ENV_ALIASES.iter().find(|(name, _)| *name == primary)?;
+    std::iter::once(name)
+        .chain(aliases.iter())
+        .find_map(|name| {
+            lookup(name)
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| (*name, value))
+        })
+}
+
+/// Returns the `(variable, value)` pairs to set: one for each
+/// [`ENV_ALIASES`] entry whose variable is unset or empty but has a
+/// non-empty alias, as seen through `lookup`.
+pub fn resolve_env_aliases(lookup: impl Fn(&str) -> Option<String>) -> Vec<(&'static str, String)> {
+    ENV_ALIASES
+        .iter()
+        .filter_map(
+            |(primary, _)| match lookup_with_aliases(primary, &lookup)? {
+                (name, _) if name == *primary => None,
+                (_, value) => Some((*primary, value)),
+            },
+        )
+        .collect()
+}
+
+/// Fills unset `OPENBAO_*` variables from their `BAO_*`/`VAULT_*`
+/// aliases in the process environment.
+///
+/// # Safety
+/// Mutates the process environment, so it must run before any other
+/// thread is spawned (at the top of `main`, before a tokio runtime is
+/// built).
+pub unsafe fn apply_vault_env_aliases() {
+    for (name, value) in resolve_env_aliases(|name| std::env::var(name).ok()) {
+        // SAFETY: the caller guarantees the process is still
+        // single-threaded.
+        unsafe { std::env::set_var(name, value) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn vault_token_fills_unset_root_token() {
+        let resolved = resolve_env_aliases(lookup(&[("VAULT_TOKEN", "s.vault")]));
+        assert_eq!(
+            resolved,
+            vec![("OPENBAO_ROOT_TOKEN", "s.vault".to_string())]
+        );
+    }
+
+    #[test]
+    fn openbao_and_bao_names_take_precedence() {
+        let resolved = resolve_env_aliases(lookup(&[
+            ("OPENBAO_ROOT_TOKEN", "s.openbao"),
+            ("VAULT_TOKEN", "s.vault"),
+            ("BAO_ADDR", "https://bao:8200"),
+            ("VAULT_ADDR", "https://vault:8200"),
+        ]));
+        assert_eq!(
+            resolved,
+            vec![("OPENBAO_URL", "https://bao:8200".to_string())]
+        );
+    }
+
+    #[test]
+    fn empty_values_are_ignored() {
+        let resolved = resolve_env_aliases(lookup(&[
+            ("OPENBAO_ROOT_TOKEN", ""),
+            ("BAO_TOKEN", ""),
+            ("VAULT_TOKEN", "s.vault"),
+        ]));
+        assert_eq!(
+            resolved,
+            vec![("OPENBAO_ROOT_TOKEN", "s.vault".to_string())]
+        );
+    }
+
+    #[test]
+    fn openbao_addr_fills_unset_url_before_bao_addr() {
+        let env = lookup(&[
+            ("OPENBAO_ADDR", "https://openbao:8200"),
+            ("BAO_ADDR", "https://bao:8200"),
+        ]);
+        assert_eq!(
+            lookup_with_aliases(OPENBAO_URL_ENV, &env),
+            Some(("OPENBAO_ADDR", "https://openbao:8200".to_string()))
+        );
+        assert_eq!(
+            resolve_env_aliases(env),
+            vec![(OPENBAO_URL_ENV, "https://openbao:8200".to_string())]
+        );
+    }
+}
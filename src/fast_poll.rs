@@ -34,7 +34,7 @@ use crate::kv_payload::{
 use crate::openbao::{KvReadWithVersion, OpenBaoClient};
 use crate::trust_bootstrap::{
     ACME_SECTION, REISSUE_COMPLETED_AT_KEY, REISSUE_COMPLETED_VERSION_KEY,
-    REISSUE_REQUESTED_AT_KEY, REISSUE_REQUESTER_KEY, SERVICE_EAB_KV_SUFFIX, SERVICE_KV_BASE,
+    REISSUE_REQUESTED_AT_KEY, REISSUE_REQUESTER_KEY, SERVICE_EAB_KV_SUFFIX,
     SERVICE_REISSUE_KV_SUFFIX, SERVICE_RESPONDER_HMAC_KV_SUFFIX, SERVICE_SECRET_ID_KV_SUFFIX,
     SERVICE_TRUST_KV_SUFFIX, build_responder_hmac_updates, build_trust_updates,
 };
@@ -293,7 +293,7 @@ pub(crate) trait FastPollHooks: Send + Sync {
 /// extracts the relevant bits (request timestamp, new version) from the
 /// payload. Pure logic, so it is unit-testable without any IO.
 ///
-/// A KV v2 path at `<SERVICE_KV_BASE>/<service>/<SERVICE_REISSUE_KV_SUFFIX>`
+/// A KV v2 path at `<service_kv_base>/<service>/<SERVICE_REISSUE_KV_SUFFIX>`
 /// alternates between two payload shapes: the operator's request
 /// (`requested_at` / `requester`) and the agent's completion ack
 /// (`completed_at` / `completed_version`). Both are writes, and every
@@ -376,34 +376,34 @@ pub(crate) fn build_completed_payload(
     serde_json::Value::Object(payload)
 }
 
-/// Builds the KV path `<SERVICE_KV_BASE>/<service>/<SERVICE_REISSUE_KV_SUFFIX>`.
+/// Builds the KV path `<service_kv_base>/<service>/<SERVICE_REISSUE_KV_SUFFIX>`.
 #[must_use]
-pub(crate) fn reissue_kv_path(service_name: &str) -> String {
-    format!("{SERVICE_KV_BASE}/{service_name}/{SERVICE_REISSUE_KV_SUFFIX}")
+pub(crate) fn reissue_kv_path(service_kv_base: &str, service_name: &str) -> String {
+    format!("{service_kv_base}/{service_name}/{SERVICE_REISSUE_KV_SUFFIX}")
 }
 
-/// Builds the KV path `<SERVICE_KV_BASE>/<service>/<SERVICE_TRUST_KV_SUFFIX>`.
+/// Builds the KV path `<service_kv_base>/<service>/<SERVICE_TRUST_KV_SUFFIX>`.
 #[must_use]
-pub(crate) fn trust_kv_path(service_name: &str) -> String {
-    format!("{SERVICE_KV_BASE}/{service_name}/{SERVICE_TRUST_KV_SUFFIX}")
+pub(crate) fn trust_kv_path(service_kv_base: &str, service_name: &str) -> String {
+    format!("{service_kv_base}/{service_name}/{SERVICE_TRUST_KV_SUFFIX}")
 }
 
-/// Builds the KV path `<SERVICE_KV_BASE>/<service>/<SERVICE_SECRET_ID_KV_SUFFIX>`.
+/// Builds the KV path `<service_kv_base>/<service>/<SERVICE_SECRET_ID_KV_SUFFIX>`.
 #[must_use]
-pub(crate) fn secret_id_kv_path(service_name: &str) -> String {
-    format!("{SERVICE_KV_BASE}/{service_name}/{SERVICE_SECRET_ID_KV_SUFFIX}")
+pub(crate) fn secret_id_kv_path(service_kv_base: &str, service_name: &str) -> String {
+    format!("{service_kv_base}/{service_name}/{SERVICE_SECRET_ID_KV_SUFFIX}")
 }
 
-/// Builds the KV path `<SERVICE_KV_BASE>/<service>/<SERVICE_RESPONDER_HMAC_KV_SUFFIX>`.
+/// Builds the KV path `<service_kv_base>/<service>/<SERVICE_RESPONDER_HMAC_KV_SUFFIX>`.
 #[must_use]
-pub(crate) fn responder_hmac_kv_path(service_name: &str) -> String {
-    format!("{SERVICE_KV_BASE}/{service_name}/{SERVICE_RESPONDER_HMAC_KV_SUFFIX}")
+pub(crate) fn responder_hmac_kv_path(service_kv_base: &str, service_name: &str) -> String {
+    format!("{service_kv_base}/{service_name}/{SERVICE_RESPONDER_HMAC_KV_SUFFIX}")
 }
 
-/// Builds the KV path `<SERVICE_KV_BASE>/<service>/<SERVICE_EAB_KV_SUFFIX>`.
+/// Builds the KV path `<service_kv_base>/<service>/<SERVICE_EAB_KV_SUFFIX>`.
 #[must_use]
-pub(crate) fn eab_kv_path(service_name: &str) -> String {
-    format!("{SERVICE_KV_BASE}/{service_name}/{SERVICE_EAB_KV_SUFFIX}")
+pub(crate) fn eab_kv_path(service_kv_base: &str, service_name: &str) -> String {
+    format!("{service_kv_base}/{service_name}/{SERVICE_EAB_KV_SUFFIX}")
 }
 
 /// Reports whether an observed KV version is newer than the last one the
@@ -453,6 +453,7 @@ pub(crate) enum PollApplyOutcome {
 pub(crate) async fn run_trust_poll_tick<H: FastPollHooks + ?Sized>(
     hooks: &H,
     kv_mount: &str,
+    service_kv_base: &str,
     services: &[(String, Vec<String>)],
     state: &mut FastPollState,
 ) -> (Vec<PollApplyOutcome>, bool) {
@@ -460,7 +461,7 @@ pub(crate) async fn run_trust_poll_tick<H: FastPollHooks + ?Sized>(
     let mut state_changed = false;
 
     for (service_name, _profiles) in services {
-        let kv_path = trust_kv_path(service_name);
+        let kv_path = trust_kv_path(service_kv_base, service_name);
         let read = match hooks.read_kv_version(kv_mount, &kv_path).await {
             Ok(Some(read)) => read,
             Ok(None) => {
@@ -530,6 +531,7 @@ pub(crate) async fn run_trust_poll_tick<H: FastPollHooks + ?Sized>(
 pub(crate) async fn run_secret_id_poll_tick<H: FastPollHooks + ?Sized>(
     hooks: &H,
     kv_mount: &str,
+    service_kv_base: &str,
     services: &[(String, Vec<String>)],
     state: &mut FastPollState,
 ) -> (Vec<PollApplyOutcome>, bool) {
@@ -537,7 +539,7 @@ pub(crate) async fn run_secret_id_poll_tick<H: FastPollHooks + ?Sized>(
     let mut state_changed = false;
 
     for (service_name, _profiles) in services {
-        let kv_path = secret_id_kv_path(service_name);
+        let kv_path = secret_id_kv_path(service_kv_base, service_name);
         let read = match hooks.read_kv_version(kv_mount, &kv_path).await {
             Ok(Some(read)) => read,
             Ok(None) => {
@@ -608,6 +610,7 @@ pub(crate) async fn run_secret_id_poll_tick<H: FastPollHooks + ?Sized>(
 pub(crate) async fn run_responder_hmac_poll_tick<H: FastPollHooks + ?Sized>(
     hooks: &H,
     kv_mount: &str,
+    service_kv_base: &str,
     services: &[(String, Vec<String>)],
     state: &mut FastPollState,
 ) -> (Vec<PollApplyOutcome>, bool) {
@@ -615,7 +618,7 @@ pub(crate) async fn run_responder_hmac_poll_tick<H: FastPollHooks + ?Sized>(
     let mut state_changed = false;
 
     for (service_name, _profiles) in services {
-        let kv_path = responder_hmac_kv_path(service_name);
+        let kv_path = responder_hmac_kv_path(service_kv_base, service_name);
         let read = match hooks.read_kv_version(kv_mount, &kv_path).await {
             Ok(Some(read)) => read,
             Ok(None) => {
@@ -693,6 +696,7 @@ pub(crate) async fn run_responder_hmac_poll_tick<H: FastPollHooks + ?Sized>(
 pub(crate) async fn run_eab_poll_tick<H: FastPollHooks + ?Sized>(
     hooks: &H,
     kv_mount: &str,
+    service_kv_base: &str,
     services: &[(String, Vec<String>)],
     state: &mut FastPollState,
 ) -> (Vec<PollApplyOutcome>, bool) {
@@ -700,7 +704,7 @@ pub(crate) async fn run_eab_poll_tick<H: FastPollHooks + ?Sized>(
     let mut state_changed = false;
 
     for (service_name, _profiles) in services {
-        let kv_path = eab_kv_path(service_name);
+        let kv_path = eab_kv_path(service_kv_base, service_name);
         let read = match hooks.read_kv_version(kv_mount, &kv_path).await {
             Ok(Some(read)) => read,
             Ok(None) => {
@@ -820,6 +824,7 @@ pub(crate) struct FastPollTickReport {
 pub(crate) async fn run_fast_poll_tick<H: FastPollHooks + ?Sized>(
     hooks: &H,
     kv_mount: &str,
+    service_kv_base: &str,
     services: &[(String, Vec<String>)],
     state: &mut FastPollState,
 ) -> FastPollTickReport {
@@ -827,7 +832,7 @@ pub(crate) async fn run_fast_poll_tick<H: FastPollHooks + ?Sized>(
     let mut state_changed = false;
 
     for (service_name, profile_labels) in services {
-        let kv_path = reissue_kv_path(service_name);
+        let kv_path = reissue_kv_path(service_kv_base, service_name);
         let observation = match hooks.read_kv_version(kv_mount, &kv_path).await {
             Ok(Some(read)) => read,
             Ok(None) => {
@@ -1072,12 +1077,13 @@ pub(crate) struct HmacRefreshReissueOutcome {
 pub(crate) async fn run_hmac_refresh_then_reissue<H: FastPollHooks + ?Sized>(
     hooks: &H,
     kv_mount: &str,
+    service_kv_base: &str,
     services: &[(String, Vec<String>)],
     state: &mut FastPollState,
 ) -> HmacRefreshReissueOutcome {
     let (responder_hmac_outcomes, responder_hmac_changed) =
-        run_responder_hmac_poll_tick(hooks, kv_mount, services, state).await;
-    let report = run_fast_poll_tick(hooks, kv_mount, services, state).await;
+        run_responder_hmac_poll_tick(hooks, kv_mount, service_kv_base, services, state).await;
+    let report = run_fast_poll_tick(hooks, kv_mount, service_kv_base, services, state).await;
     HmacRefreshReissueOutcome {
         responder_hmac_outcomes,
         responder_hmac_changed,
@@ -1380,6 +1386,7 @@ pub(crate) async fn run_fast_poll_loop(
         info!("No [openbao] section configured; fast-poll loop disabled.");
         return Ok(());
     };
+    let service_kv_base = openbao.service_kv_base.trim_matches('/');
     if settings.profiles.is_empty() {
         info!("No profiles configured; fast-poll loop disabled.");
         return Ok(());
@@ -1495,8 +1502,14 @@ pub(crate) async fn run_fast_poll_loop(
         // Gated on `refresh_eab`: skipped entirely when EAB is CLI-pinned.
         let mut eab_changed = false;
         if refresh_eab {
-            let (eab_outcomes, changed) =
-                run_eab_poll_tick(&hooks, &openbao.kv_mount, &services, &mut state).await;
+            let (eab_outcomes, changed) = run_eab_poll_tick(
+                &hooks,
+                &openbao.kv_mount,
+                service_kv_base,
+                &services,
+                &mut state,
+            )
+            .await;
             log_poll_outcomes("eab", &eab_outcomes, &mut needs_relogin);
             eab_changed = changed;
         }
@@ -1512,7 +1525,14 @@ pub(crate) async fn run_fast_poll_loop(
             responder_hmac_outcomes,
             responder_hmac_changed,
             report,
-        } = run_hmac_refresh_then_reissue(&hooks, &openbao.kv_mount, &services, &mut state).await;
+        } = run_hmac_refresh_then_reissue(
+            &hooks,
+            &openbao.kv_mount,
+            service_kv_base,
+            &services,
+            &mut state,
+        )
+        .await;
         log_poll_outcomes(
             "responder_hmac",
             &responder_hmac_outcomes,
@@ -1597,12 +1617,24 @@ pub(crate) async fn run_fast_poll_loop(
             BTreeMap::new()
         };
 
-        let (trust_outcomes, trust_changed) =
-            run_trust_poll_tick(&hooks, &openbao.kv_mount, &services, &mut state).await;
+        let (trust_outcomes, trust_changed) = run_trust_poll_tick(
+            &hooks,
+            &openbao.kv_mount,
+            service_kv_base,
+            &services,
+            &mut state,
+        )
+        .await;
         log_poll_outcomes("trust", &trust_outcomes, &mut needs_relogin);
 
-        let (secret_id_outcomes, secret_id_changed) =
-            run_secret_id_poll_tick(&hooks, &openbao.kv_mount, &services, &mut state).await;
+        let (secret_id_outcomes, secret_id_changed) = run_secret_id_poll_tick(
+            &hooks,
+            &openbao.kv_mount,
+            service_kv_base,
+            &services,
+            &mut state,
+        )
+        .await;
         log_poll_outcomes("secret_id", &secret_id_outcomes, &mut needs_relogin);
 
         // A trust apply rewrites the on-disk CA bundle, and in the
@@ -1863,11 +1895,12 @@ async fn read_trimmed(path: &Path) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::trust_bootstrap::SERVICE_KV_BASE;
 
     #[test]
     fn reissue_kv_path_formats_as_documented() {
         assert_eq!(
-            reissue_kv_path("edge-proxy"),
+            reissue_kv_path(SERVICE_KV_BASE, "edge-proxy"),
             "bootroot/services/edge-proxy/reissue",
         );
     }
@@ -2212,6 +2245,29 @@ mod tests {
         vec![(service.to_string(), vec![profile.to_string()])]
     }
 
+    #[tokio::test]
+    async fn tick_acks_reissue_under_configured_service_kv_base() {
+        let hooks = FakeHooks::new(vec![Ok(Some(KvReadWithVersion {
+            version: 4,
+            data: serde_json::json!({ "requested_at": "2026-04-19T12:34:56Z" }),
+        }))]);
+        let services = services_single("edge-proxy", "edge-proxy-domain");
+        let mut state = FastPollState::default();
+
+        run_fast_poll_tick(
+            &hooks,
+            "secret",
+            "tenants/acme/services",
+            &services,
+            &mut state,
+        )
+        .await;
+
+        let writes = hooks.writes.lock().unwrap();
+        assert_eq!(writes.len(), 1);
+        assert_eq!(writes[0].0, "tenants/acme/services/edge-proxy/reissue");
+    }
+
     #[tokio::test]
     async fn tick_fires_renewal_on_new_version() {
         let hooks = FakeHooks::new(vec![Ok(Some(KvReadWithVersion {
@@ -2224,7 +2280,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert_eq!(report.outcomes.len(), 1);
         assert!(report.state_changed);
@@ -2263,7 +2320,8 @@ mod tests {
             .last_reissue_seen_version
             .insert("edge-proxy".to_string(), 5);
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(matches!(
             report.outcomes[0],
@@ -2280,7 +2338,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(matches!(
             report.outcomes[0],
@@ -2300,7 +2359,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(matches!(
             report.outcomes[0],
@@ -2336,7 +2396,8 @@ mod tests {
         )];
         let mut state = FastPollState::default();
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         // All three profiles received a renewal trigger.
         let calls = hooks.renew_calls.lock().unwrap().clone();
@@ -2372,7 +2433,8 @@ mod tests {
         )];
         let mut state = FastPollState::default();
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         // Both profiles attempted, one failed.
         assert_eq!(hooks.renew_calls.lock().unwrap().len(), 2);
@@ -2430,7 +2492,7 @@ mod tests {
             }),
         }))])
         .fail_renewals_for(&["002.edge-proxy.host-a.example"]);
-        let _ = run_fast_poll_tick(&hooks1, "secret", &services, &mut state).await;
+        let _ = run_fast_poll_tick(&hooks1, "secret", SERVICE_KV_BASE, &services, &mut state).await;
         assert_eq!(hooks1.renew_calls.lock().unwrap().len(), 3);
         let in_flight = state
             .in_flight_renewals
@@ -2448,7 +2510,8 @@ mod tests {
                 "requester": "alice",
             }),
         }))]);
-        let report = run_fast_poll_tick(&hooks2, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks2, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         let calls = hooks2.renew_calls.lock().unwrap().clone();
         assert_eq!(
@@ -2493,7 +2556,7 @@ mod tests {
             version: 6,
             data: serde_json::json!({}),
         }))]);
-        let _ = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let _ = run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert_eq!(hooks.renew_calls.lock().unwrap().len(), 2);
         assert_eq!(state.last_reissue_seen_version.get("edge-proxy"), Some(&6));
@@ -2519,7 +2582,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(matches!(
             report.outcomes[0],
@@ -2544,7 +2608,8 @@ mod tests {
             }),
         }))]);
 
-        let report2 = run_fast_poll_tick(&hooks2, "secret", &services, &mut state).await;
+        let report2 =
+            run_fast_poll_tick(&hooks2, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         // No renewal call this tick — write retry only.
         assert!(hooks2.renew_calls.lock().unwrap().is_empty());
@@ -2598,7 +2663,8 @@ mod tests {
             }),
         }))]);
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         // Stale pending dropped, new version applied.
         assert!(state.pending_completion_writes.is_empty());
@@ -2628,7 +2694,8 @@ mod tests {
         );
 
         let hooks = FakeHooks::new(vec![Ok(None)]);
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(state.pending_completion_writes.is_empty());
         assert!(report.state_changed);
@@ -2663,7 +2730,8 @@ mod tests {
         }))])
         .fail_writes(vec![false]);
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(state.pending_completion_writes.contains_key("edge-proxy"));
         assert!(!report.state_changed);
@@ -2698,7 +2766,8 @@ mod tests {
             }),
         }))]);
 
-        let report = run_fast_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let report =
+            run_fast_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(matches!(
             report.outcomes[0],
@@ -2778,19 +2847,19 @@ mod tests {
     #[test]
     fn kv_path_helpers_format_as_documented() {
         assert_eq!(
-            trust_kv_path("edge-proxy"),
+            trust_kv_path(SERVICE_KV_BASE, "edge-proxy"),
             "bootroot/services/edge-proxy/trust"
         );
         assert_eq!(
-            secret_id_kv_path("edge-proxy"),
+            secret_id_kv_path(SERVICE_KV_BASE, "edge-proxy"),
             "bootroot/services/edge-proxy/secret_id"
         );
         assert_eq!(
-            responder_hmac_kv_path("edge-proxy"),
+            responder_hmac_kv_path(SERVICE_KV_BASE, "edge-proxy"),
             "bootroot/services/edge-proxy/http_responder_hmac"
         );
         assert_eq!(
-            eab_kv_path("edge-proxy"),
+            eab_kv_path(SERVICE_KV_BASE, "edge-proxy"),
             "bootroot/services/edge-proxy/eab"
         );
     }
@@ -2802,7 +2871,7 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_trust_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_trust_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(changed);
         assert!(matches!(
@@ -2826,7 +2895,7 @@ mod tests {
             .insert("edge-proxy".to_string(), 3);
 
         let (outcomes, changed) =
-            run_trust_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_trust_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -2843,7 +2912,7 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_trust_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_trust_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(changed);
         assert!(matches!(
@@ -2863,7 +2932,7 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_trust_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_trust_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -2881,7 +2950,7 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_trust_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_trust_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -2898,7 +2967,7 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_trust_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_trust_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(outcomes[0], PollApplyOutcome::NoData { .. }));
@@ -2918,7 +2987,7 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_secret_id_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_secret_id_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(changed);
         assert!(matches!(
@@ -2947,7 +3016,7 @@ mod tests {
             .insert("edge-proxy".to_string(), 2);
 
         let (outcomes, changed) =
-            run_secret_id_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_secret_id_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -2967,7 +3036,7 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_secret_id_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_secret_id_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -2985,7 +3054,7 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_secret_id_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_secret_id_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -3009,7 +3078,8 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_responder_hmac_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_responder_hmac_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state)
+                .await;
 
         assert!(changed);
         assert!(matches!(
@@ -3038,7 +3108,8 @@ mod tests {
             .insert("edge-proxy".to_string(), 2);
 
         let (outcomes, changed) =
-            run_responder_hmac_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_responder_hmac_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state)
+                .await;
 
         assert!(!changed);
         assert!(matches!(
@@ -3058,7 +3129,8 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_responder_hmac_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_responder_hmac_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state)
+                .await;
 
         assert!(!changed);
         assert!(matches!(
@@ -3076,7 +3148,8 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_responder_hmac_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_responder_hmac_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state)
+                .await;
 
         assert!(!changed);
         assert!(matches!(
@@ -3093,7 +3166,8 @@ mod tests {
         let mut state = FastPollState::default();
 
         let (outcomes, changed) =
-            run_responder_hmac_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_responder_hmac_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state)
+                .await;
 
         assert!(changed);
         assert!(matches!(
@@ -3115,7 +3189,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let (outcomes, changed) = run_eab_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let (outcomes, changed) =
+            run_eab_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(changed);
         assert!(matches!(
@@ -3141,7 +3216,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let (outcomes, changed) = run_eab_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let (outcomes, changed) =
+            run_eab_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(changed);
         assert!(matches!(
@@ -3162,7 +3238,8 @@ mod tests {
             .last_eab_seen_version
             .insert("edge-proxy".to_string(), 2);
 
-        let (outcomes, changed) = run_eab_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let (outcomes, changed) =
+            run_eab_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -3184,7 +3261,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let (outcomes, changed) = run_eab_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let (outcomes, changed) =
+            run_eab_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -3201,7 +3279,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let (outcomes, changed) = run_eab_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let (outcomes, changed) =
+            run_eab_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(
@@ -3217,7 +3296,8 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let (outcomes, changed) = run_eab_poll_tick(&hooks, "secret", &services, &mut state).await;
+        let (outcomes, changed) =
+            run_eab_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
         assert!(!changed);
         assert!(matches!(outcomes[0], PollApplyOutcome::NoData { .. }));
@@ -3315,7 +3395,9 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
 
-        let outcome = run_hmac_refresh_then_reissue(&hooks, "secret", &services, &mut state).await;
+        let outcome =
+            run_hmac_refresh_then_reissue(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state)
+                .await;
 
         assert!(outcome.responder_hmac_changed);
         assert!(matches!(
@@ -3656,7 +3738,7 @@ mod tests {
             let mut state = FastPollState::default();
 
             let (outcomes, changed) =
-                run_trust_poll_tick(&hooks, "secret", &services, &mut state).await;
+                run_trust_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
 
             assert!(!changed, "malformed payload must not change state");
             assert!(
@@ -3707,7 +3789,7 @@ mod tests {
         let services = services_single("edge-proxy", "edge-proxy-domain");
         let mut state = FastPollState::default();
         let (outcomes, changed) =
-            run_trust_poll_tick(&hooks, "secret", &services, &mut state).await;
+            run_trust_poll_tick(&hooks, "secret", SERVICE_KV_BASE, &services, &mut state).await;
         assert!(changed);
         assert!(matches!(
             outcomes[0],
@@ -3791,6 +3873,7 @@ mod pin_rotation_tests {
     use tokio_rustls::TlsAcceptor;
 
     use super::*;
+    use crate::trust_bootstrap::SERVICE_KV_BASE;
 
     struct TestCa {
         pem: String,
@@ -3867,6 +3950,7 @@ mod pin_rotation_tests {
             url: url.to_string(),
             allow_plaintext_http: false,
             kv_mount: "secret".to_string(),
+            service_kv_base: SERVICE_KV_BASE.to_string(),
             role_id_path: PathBuf::from("/unused/role_id"),
             secret_id_path: PathBuf::from("/unused/secret_id"),
             ca_bundle_path: Some(bundle_path.to_path_buf()),
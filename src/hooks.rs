@@ -344,6 +344,9 @@ mod tests {
             },
             profiles: vec![profile.clone()],
             openbao: None,
+            env_interpolation: false,
+            strict_env_interpolation: false,
+            renew_jitter_percent: 0,
         };
 
         (settings, profile)
@@ -2,6 +2,7 @@ use anyhow::Result;
 use bootroot::locale::Locale;
 
 pub(crate) mod en;
+pub(crate) mod fr;
 pub(crate) mod infra;
 pub(crate) mod init;
 pub(crate) mod ko;
@@ -20,8 +21,97 @@ pub(crate) struct ServiceNextStepsDaemon<'a> {
     pub(crate) config_path: &'a str,
 }
 
+/// Declares [`Strings`] together with [`PartialStrings`], its
+/// all-optional overlay, from a single field list so the two never drift.
+macro_rules! define_strings {
+    (pub(crate) struct Strings { $(pub(crate) $field:ident: &'static str,)* }) => {
+        pub(crate) struct Strings {
+            $(pub(crate) $field: &'static str,)*
+        }
+
+        impl Strings {
+            /// Reports whether every message is non-empty. Locales assert
+            /// this at compile time, so a blank translation fails the build
+            /// just like a missing field does.
+            const fn is_fully_populated(&self) -> bool {
+                $(!self.$field.is_empty() &&)* true
+            }
+
+            /// Lists every message with its field name, in declaration
+            /// order.
+            #[cfg(test)]
+            fn entries(&self) -> Vec<(&'static str, &'static str)> {
+                vec![$((stringify!($field), self.$field)),*]
+            }
+        }
+
+        /// Strings of a partially translated locale. Fields left `None`
+        /// (or set to an empty string) fall back to the base locale when
+        /// resolved, so a translation can land one message at a time.
+        pub(crate) struct PartialStrings {
+            $(pub(crate) $field: Option<&'static str>,)*
+        }
+
+        impl PartialStrings {
+            /// Overlay with no translated strings.
+            pub(crate) const EMPTY: Self = Self {
+                $($field: None,)*
+            };
+
+            /// Fills every untranslated field from `base`. Debug builds
+            /// print the untranslated keys to stderr so translators can
+            /// see what is still missing.
+            pub(crate) fn resolve(&self, base: &Strings) -> Strings {
+                let untranslated = self.untranslated_keys();
+                if cfg!(debug_assertions) && !untranslated.is_empty() {
+                    eprintln!(
+                        "warning: {} untranslated strings fall back to the base locale: {}",
+                        untranslated.len(),
+                        untranslated.join(", ")
+                    );
+                }
+                Strings {
+                    $($field: self
+                        .$field
+                        .filter(|value| !value.is_empty())
+                        .unwrap_or(base.$field),)*
+                }
+            }
+
+            /// Lists the fields that will fall back to the base locale.
+            pub(crate) fn untranslated_keys(&self) -> Vec<&'static str> {
+                let mut keys = Vec::new();
+                $(if self.$field.is_none_or(str::is_empty) {
+                    keys.push(stringify!($field));
+                })*
+                keys
+            }
+        }
+    };
+}
+
+/// Builds a [`PartialStrings`] from `field: "text"` pairs, leaving every
+/// field that is not listed to the base locale.
+macro_rules! partial_strings {
+    ($($field:ident: $value:expr,)*) => {{
+        // A fully translated locale lists every field, which leaves the
+        // `EMPTY` update with nothing to fill.
+        #[allow(clippy::needless_update)]
+        let strings = PartialStrings {
+            $($field: Some($value),)*
+            ..PartialStrings::EMPTY
+        };
+        strings
+    }};
+}
+use partial_strings;
+
+define_strings! {
 pub(crate) struct Strings {
     pub(crate) infra_up_completed: &'static str,
+    pub(crate) infra_down_completed: &'static str,
+    pub(crate) infra_restart_completed: &'static str,
+    pub(crate) prompt_infra_down_volumes: &'static str,
     pub(crate) infra_readiness_summary: &'static str,
     pub(crate) readiness_entry_with_health: &'static str,
     pub(crate) readiness_entry_without_health: &'static str,
@@ -45,6 +135,13 @@ pub(crate) struct Strings {
     pub(crate) monitoring_down_reset_grafana_skipped: &'static str,
     pub(crate) monitoring_up_already_running: &'static str,
     pub(crate) error_service_no_container: &'static str,
+    pub(crate) error_compose_missing_service: &'static str,
+    pub(crate) error_init_compose_missing_service: &'static str,
+    pub(crate) error_compose_file_not_found: &'static str,
+    pub(crate) error_openbao_agent_exec_invalid: &'static str,
+    pub(crate) error_ca_json_outside_secrets_dir: &'static str,
+    pub(crate) error_resolve_ca_json_failed: &'static str,
+    pub(crate) init_agent_template_header: &'static str,
     pub(crate) init_failed_rollback: &'static str,
     pub(crate) prompt_openbao_root_token: &'static str,
     pub(crate) error_openbao_root_token_required: &'static str,
@@ -52,12 +149,15 @@ pub(crate) struct Strings {
     pub(crate) warning_openbao_sealed_non_interactive: &'static str,
     pub(crate) error_openbao_audit_setup_failed: &'static str,
     pub(crate) warning_db_password_rotation_skipped: &'static str,
+    pub(crate) warning_openbao_agent_render_missing: &'static str,
     pub(crate) warning_secret_id_ttl_exceeds_recommended: &'static str,
     pub(crate) error_secret_id_ttl_exceeds_max: &'static str,
     pub(crate) error_secret_id_ttl_invalid: &'static str,
     pub(crate) error_rn_cidrs_invalid: &'static str,
     pub(crate) error_rn_cidrs_clear_conflict: &'static str,
     pub(crate) error_rn_cidrs_clear_on_add: &'static str,
+    pub(crate) error_approle_bind_cidr_invalid: &'static str,
+    pub(crate) error_serialize_init_plan_failed: &'static str,
     pub(crate) prompt_openbao_unseal_from_file_confirm: &'static str,
     pub(crate) error_openbao_unseal_file_empty: &'static str,
     pub(crate) prompt_unseal_threshold: &'static str,
@@ -71,12 +171,15 @@ pub(crate) struct Strings {
     pub(crate) prompt_db_name: &'static str,
     pub(crate) error_invalid_unseal_threshold: &'static str,
     pub(crate) error_eab_requires_both: &'static str,
+    pub(crate) error_skip_step_ca_openbao_tls: &'static str,
+    pub(crate) error_current_dir_failed: &'static str,
     pub(crate) error_openbao_sealed: &'static str,
     pub(crate) error_invalid_db_dsn: &'static str,
     pub(crate) error_db_host_not_single_host: &'static str,
     pub(crate) error_db_host_compose_runtime: &'static str,
     pub(crate) error_postgres_port_binding_unsafe: &'static str,
     pub(crate) error_service_port_binding_unsafe: &'static str,
+    pub(crate) error_postgres_allowed_cidr_invalid: &'static str,
     pub(crate) error_db_check_failed: &'static str,
     pub(crate) error_db_auth_failed: &'static str,
     pub(crate) error_db_type_unsupported: &'static str,
@@ -112,8 +215,33 @@ pub(crate) struct Strings {
     pub(crate) error_monitoring_failed: &'static str,
     pub(crate) error_init_failed: &'static str,
     pub(crate) error_status_failed: &'static str,
+    pub(crate) error_audit_secrets_failed: &'static str,
+    pub(crate) error_support_bundle_failed: &'static str,
+    pub(crate) error_self_test_failed: &'static str,
+    pub(crate) error_self_test_deployment_exists: &'static str,
+    pub(crate) error_self_test_container_exists: &'static str,
+    pub(crate) error_self_test_exe_missing: &'static str,
+    pub(crate) error_self_test_workspace: &'static str,
+    pub(crate) error_self_test_step_failed: &'static str,
+    pub(crate) error_self_test_teardown_failed: &'static str,
+    pub(crate) self_test_step_started: &'static str,
+    pub(crate) self_test_step_passed: &'static str,
+    pub(crate) self_test_teardown: &'static str,
+    pub(crate) self_test_passed: &'static str,
+    pub(crate) error_migrate_failed: &'static str,
+    pub(crate) error_trust_export_failed: &'static str,
+    pub(crate) error_trust_show_failed: &'static str,
+    pub(crate) error_migrate_same_location: &'static str,
+    pub(crate) error_migrate_mount_and_prefix: &'static str,
+    pub(crate) error_migrate_source_empty: &'static str,
+    pub(crate) error_migrate_verify_failed: &'static str,
+    pub(crate) error_migrate_dual_write_active: &'static str,
+    pub(crate) error_migrate_no_dual_write: &'static str,
+    pub(crate) error_cancelled: &'static str,
+    pub(crate) error_operation_deadline_exceeded: &'static str,
     pub(crate) error_service_add_failed: &'static str,
     pub(crate) error_service_info_failed: &'static str,
+    pub(crate) error_service_list_failed: &'static str,
     pub(crate) error_service_remove_failed: &'static str,
     pub(crate) error_verify_failed: &'static str,
     pub(crate) error_rotate_failed: &'static str,
@@ -127,6 +255,7 @@ pub(crate) struct Strings {
     pub(crate) error_file_missing: &'static str,
     pub(crate) error_read_file_failed: &'static str,
     pub(crate) error_write_file_failed: &'static str,
+    pub(crate) error_config_file_invalid: &'static str,
     pub(crate) error_read_dir_failed: &'static str,
     pub(crate) error_read_dir_entry_failed: &'static str,
     pub(crate) error_remove_file_failed: &'static str,
@@ -154,6 +283,8 @@ pub(crate) struct Strings {
     pub(crate) error_openbao_approle_login_failed: &'static str,
     pub(crate) error_openbao_kv_exists_failed: &'static str,
     pub(crate) error_openbao_kv_write_failed: &'static str,
+    pub(crate) error_openbao_capability_missing: &'static str,
+    pub(crate) error_openbao_capability_missing_root: &'static str,
     pub(crate) error_openbao_kv_read_failed: &'static str,
     pub(crate) error_openbao_kv_delete_failed: &'static str,
     pub(crate) error_openbao_role_output_missing: &'static str,
@@ -165,16 +296,13 @@ pub(crate) struct Strings {
     pub(crate) error_docker_command_failed: &'static str,
     pub(crate) error_bootroot_agent_run_failed: &'static str,
     pub(crate) error_bootroot_agent_not_found: &'static str,
-    pub(crate) error_secrets_dir_resolve_failed: &'static str,
     pub(crate) error_rendered_file_timeout: &'static str,
     pub(crate) error_parse_ca_json_failed: &'static str,
     pub(crate) error_serialize_ca_json_failed: &'static str,
     pub(crate) error_ca_json_db_missing: &'static str,
+    pub(crate) error_stepca_db_kv_missing: &'static str,
     pub(crate) error_ca_cert_missing: &'static str,
     pub(crate) error_ca_cert_parse_failed: &'static str,
-    pub(crate) error_ca_trust_missing: &'static str,
-    pub(crate) error_ca_trust_invalid: &'static str,
-    pub(crate) error_ca_trust_empty: &'static str,
     pub(crate) error_parse_state_failed: &'static str,
     pub(crate) error_serialize_state_failed: &'static str,
     pub(crate) prompt_service_name: &'static str,
@@ -189,6 +317,7 @@ pub(crate) struct Strings {
     pub(crate) prompt_confirm_overwrite_state: &'static str,
     pub(crate) prompt_rotate_stepca_password: &'static str,
     pub(crate) prompt_rotate_db: &'static str,
+    pub(crate) prompt_confirm_secrets_dir_mismatch: &'static str,
     pub(crate) prompt_rotate_responder_hmac: &'static str,
     pub(crate) prompt_rotate_approle_secret_id: &'static str,
     pub(crate) prompt_rotate_all_approle_secret_ids: &'static str,
@@ -197,9 +326,16 @@ pub(crate) struct Strings {
     pub(crate) init_plan_overwrite_password: &'static str,
     pub(crate) init_plan_overwrite_ca_json: &'static str,
     pub(crate) init_plan_overwrite_state: &'static str,
+    pub(crate) init_dry_run_openbao_writes: &'static str,
+    pub(crate) init_dry_run_openbao_bootstrap: &'static str,
+    pub(crate) init_dry_run_file_writes: &'static str,
+    pub(crate) init_dry_run_container_commands: &'static str,
+    pub(crate) init_dry_run_item: &'static str,
+    pub(crate) init_dry_run_done: &'static str,
     pub(crate) service_add_summary: &'static str,
     pub(crate) service_add_plan_title: &'static str,
     pub(crate) service_info_summary: &'static str,
+    pub(crate) service_list_entry: &'static str,
     pub(crate) service_summary_kind: &'static str,
     pub(crate) service_summary_hostname: &'static str,
     pub(crate) service_summary_domain: &'static str,
@@ -227,12 +363,21 @@ pub(crate) struct Strings {
     pub(crate) service_summary_agent_config: &'static str,
     pub(crate) service_summary_cert_path: &'static str,
     pub(crate) service_summary_key_path: &'static str,
+    pub(crate) service_info_cert_file: &'static str,
+    pub(crate) service_info_key_file: &'static str,
+    pub(crate) service_info_cert_files_remote: &'static str,
+    pub(crate) cert_file_present: &'static str,
+    pub(crate) cert_file_missing: &'static str,
+    pub(crate) key_file_wrong_mode: &'static str,
     pub(crate) service_summary_next_steps: &'static str,
     pub(crate) service_summary_preview_mode: &'static str,
     pub(crate) service_summary_preview_trust_skipped_no_token: &'static str,
     pub(crate) service_summary_preview_trust_not_found: &'static str,
     pub(crate) service_summary_preview_trust_lookup_failed: &'static str,
     pub(crate) service_summary_remote_idempotent_hint: &'static str,
+    pub(crate) error_serialize_service_add_result_failed: &'static str,
+    pub(crate) error_serialize_service_info_failed: &'static str,
+    pub(crate) error_serialize_service_list_failed: &'static str,
     pub(crate) service_next_steps_daemon_profile: &'static str,
     pub(crate) service_next_steps_remote_selfheal_keep: &'static str,
     pub(crate) service_next_steps_remote_selfheal_note: &'static str,
@@ -260,7 +405,54 @@ pub(crate) struct Strings {
     pub(crate) verify_ca_bundle_parse_failed: &'static str,
     pub(crate) verify_ca_bundle_missing_fingerprints: &'static str,
     pub(crate) verify_cert_chain_failed: &'static str,
+    pub(crate) audit_secrets_not_git_repo: &'static str,
+    pub(crate) audit_secrets_clean: &'static str,
+    pub(crate) audit_secrets_tracked: &'static str,
+    pub(crate) audit_secrets_hint: &'static str,
+    pub(crate) error_audit_secrets_tracked: &'static str,
+    pub(crate) support_bundle_written: &'static str,
+    pub(crate) support_bundle_state_missing: &'static str,
+    pub(crate) support_bundle_command_failed: &'static str,
+    pub(crate) version_git_sha: &'static str,
+    pub(crate) version_build_date: &'static str,
+    pub(crate) version_rustc: &'static str,
+    pub(crate) version_docker: &'static str,
+    pub(crate) version_podman: &'static str,
+    pub(crate) version_compose_images: &'static str,
+    pub(crate) version_unavailable: &'static str,
+    pub(crate) migrate_copied: &'static str,
+    pub(crate) migrate_summary: &'static str,
+    pub(crate) migrate_state_updated: &'static str,
+    pub(crate) migrate_state_prefix_updated: &'static str,
+    pub(crate) trust_export_source_kv: &'static str,
+    pub(crate) trust_export_source_local: &'static str,
+    pub(crate) trust_export_kv_unavailable: &'static str,
+    pub(crate) trust_export_written: &'static str,
+    pub(crate) trust_export_fingerprints: &'static str,
+    pub(crate) error_trust_export_no_certs: &'static str,
+    pub(crate) error_trust_export_der_multiple: &'static str,
+    pub(crate) trust_export_no_root_token: &'static str,
+    pub(crate) trust_show_local_header: &'static str,
+    pub(crate) trust_show_kv_header: &'static str,
+    pub(crate) trust_show_kv_unavailable: &'static str,
+    pub(crate) trust_show_cert: &'static str,
+    pub(crate) trust_show_cert_sha256: &'static str,
+    pub(crate) trust_show_cert_not_after: &'static str,
+    pub(crate) trust_show_role_root: &'static str,
+    pub(crate) trust_show_role_intermediate: &'static str,
+    pub(crate) trust_show_drift_none: &'static str,
+    pub(crate) trust_show_drift_detected: &'static str,
+    pub(crate) migrate_confirm_delete: &'static str,
+    pub(crate) migrate_source_deleted: &'static str,
+    pub(crate) migrate_source_kept: &'static str,
+    pub(crate) migrate_dual_write_started: &'static str,
+    pub(crate) migrate_dual_write_finalized: &'static str,
+    pub(crate) migrate_references_note: &'static str,
+    pub(crate) cancellation_requested: &'static str,
+    pub(crate) status_openbao_target_inferred: &'static str,
+    pub(crate) status_openbao_target_default_source: &'static str,
     pub(crate) status_summary_title: &'static str,
+    pub(crate) error_serialize_status_report_failed: &'static str,
     pub(crate) status_section_infra: &'static str,
     pub(crate) status_section_openbao: &'static str,
     pub(crate) status_section_kv_paths: &'static str,
@@ -273,6 +465,7 @@ pub(crate) struct Strings {
     pub(crate) status_kv_path_entry: &'static str,
     pub(crate) status_approle_entry: &'static str,
     pub(crate) status_service_delivery_mode: &'static str,
+    pub(crate) status_service_cert_files: &'static str,
     pub(crate) status_value_ok: &'static str,
     pub(crate) status_value_unreachable: &'static str,
     pub(crate) status_value_present: &'static str,
@@ -290,19 +483,30 @@ pub(crate) struct Strings {
     pub(crate) summary_secrets_dir: &'static str,
     pub(crate) summary_stepca_completed: &'static str,
     pub(crate) summary_stepca_skipped: &'static str,
+    pub(crate) summary_stepca_external: &'static str,
+    pub(crate) summary_stepca_deferred: &'static str,
+    pub(crate) init_resume_notice: &'static str,
+    pub(crate) init_cancelled_openbao_credentials: &'static str,
     pub(crate) summary_openbao_init_completed: &'static str,
     pub(crate) summary_openbao_init_skipped: &'static str,
     pub(crate) summary_root_token: &'static str,
     pub(crate) summary_unseal_key: &'static str,
+    pub(crate) summary_recovery_key: &'static str,
     pub(crate) summary_stepca_password: &'static str,
     pub(crate) summary_db_dsn: &'static str,
     pub(crate) summary_responder_hmac: &'static str,
     pub(crate) summary_eab_kid: &'static str,
     pub(crate) summary_eab_hmac: &'static str,
     pub(crate) summary_eab_missing: &'static str,
+    pub(crate) summary_secrets_revealed_to_file: &'static str,
+    pub(crate) error_reveal_to_file_failed: &'static str,
+    pub(crate) warning_init_reveal_to_file_failed: &'static str,
+    pub(crate) error_init_audit_log_open_failed: &'static str,
+    pub(crate) warning_init_audit_log_write_failed: &'static str,
     pub(crate) rotate_summary_title: &'static str,
     pub(crate) rotate_summary_stepca_password: &'static str,
     pub(crate) rotate_summary_restart_stepca: &'static str,
+    pub(crate) error_service_not_recovered: &'static str,
     pub(crate) rotate_summary_db_dsn: &'static str,
     pub(crate) rotate_summary_responder_config: &'static str,
     pub(crate) rotate_summary_reload_responder: &'static str,
@@ -325,6 +529,16 @@ pub(crate) struct Strings {
     pub(crate) rotate_summary_self_mint: &'static str,
     pub(crate) rotate_summary_self_mint_login_ok: &'static str,
     pub(crate) warning_self_mint_skipped_non_file: &'static str,
+    pub(crate) warning_openbao_agent_not_found: &'static str,
+    pub(crate) warning_openbao_agent_not_running: &'static str,
+    pub(crate) error_openbao_agent_not_reloaded: &'static str,
+    pub(crate) rotate_plan_title: &'static str,
+    pub(crate) rotate_plan_kv_write: &'static str,
+    pub(crate) rotate_plan_file: &'static str,
+    pub(crate) rotate_plan_approle: &'static str,
+    pub(crate) rotate_plan_recovery: &'static str,
+    pub(crate) rotate_plan_restart: &'static str,
+    pub(crate) rotate_plan_empty: &'static str,
     pub(crate) error_self_mint_failed: &'static str,
     pub(crate) error_self_mint_verify_failed: &'static str,
     pub(crate) error_rotate_bound_cidrs_requires_provisioning: &'static str,
@@ -342,6 +556,8 @@ pub(crate) struct Strings {
     pub(crate) prompt_rotate_force_reissue_remote: &'static str,
     pub(crate) rotate_summary_trust_sync_global: &'static str,
     pub(crate) rotate_summary_trust_sync_service: &'static str,
+    pub(crate) rotate_summary_trust_sync_generation: &'static str,
+    pub(crate) rotate_summary_trust_sync_current: &'static str,
     pub(crate) rotate_summary_force_reissue_deleted: &'static str,
     pub(crate) rotate_summary_force_reissue_local_signal: &'static str,
     pub(crate) rotate_summary_force_reissue_requested: &'static str,
@@ -357,6 +573,7 @@ pub(crate) struct Strings {
     pub(crate) error_rotation_mode_mismatch: &'static str,
     pub(crate) error_parse_cert_failed: &'static str,
     pub(crate) warning_stale_backup: &'static str,
+    pub(crate) warning_secrets_dir_mismatch: &'static str,
     pub(crate) rotate_ca_key_resuming: &'static str,
     pub(crate) rotate_ca_key_phase_backup: &'static str,
     pub(crate) rotate_ca_key_phase_generate: &'static str,
@@ -394,11 +611,19 @@ pub(crate) struct Strings {
     pub(crate) next_steps_openbao_agent_override: &'static str,
     pub(crate) next_steps_reload_services: &'static str,
     pub(crate) next_steps_run_status: &'static str,
+    pub(crate) next_steps_finish_openbao_only: &'static str,
     pub(crate) next_steps_eab_hint: &'static str,
     pub(crate) infra_install_completed: &'static str,
     pub(crate) infra_install_env_written: &'static str,
     pub(crate) infra_install_dirs_created: &'static str,
     pub(crate) error_infra_install_failed: &'static str,
+    pub(crate) error_infra_down_failed: &'static str,
+    pub(crate) error_infra_restart_failed: &'static str,
+    pub(crate) error_infra_logs_failed: &'static str,
+    pub(crate) error_restart_policy_invalid: &'static str,
+    pub(crate) error_service_timeout_invalid: &'static str,
+    pub(crate) warning_infra_pull_retry: &'static str,
+    pub(crate) infra_openbao_auto_unseal_skipped: &'static str,
     pub(crate) clean_completed: &'static str,
     pub(crate) clean_confirm: &'static str,
     pub(crate) clean_confirm_certs: &'static str,
@@ -432,6 +657,8 @@ pub(crate) struct Strings {
     pub(crate) service_info_secret_id_ttl: &'static str,
     pub(crate) service_info_secret_id_wrap_ttl: &'static str,
     pub(crate) service_info_token_bound_cidrs: &'static str,
+    pub(crate) service_info_sync_generation: &'static str,
+    pub(crate) service_info_sync_generation_none: &'static str,
     pub(crate) policy_label_inherit: &'static str,
     pub(crate) policy_label_disabled: &'static str,
     pub(crate) policy_label_default_wrap_ttl: &'static str,
@@ -554,14 +781,16 @@ pub(crate) struct Strings {
     pub(crate) error_reinit_explicit_openbao_url: &'static str,
     pub(crate) error_reinit_stepca_password_missing_with_ca_material: &'static str,
 }
+}
 
+#[derive(Clone)]
 pub(crate) struct Messages {
     locale: Locale,
 }
 
 impl Messages {
     pub(crate) fn new(lang: &str) -> Result<Self> {
-        let locale = Locale::parse(lang)?;
+        let locale = Locale::resolve(lang)?;
         Ok(Self { locale })
     }
 
@@ -569,18 +798,58 @@ impl Messages {
         match self.locale {
             Locale::En => &en::STRINGS,
             Locale::Ko => &ko::STRINGS,
+            Locale::Fr => &fr::STRINGS,
         }
     }
 }
 
+const _: () = assert!(
+    en::STRINGS.is_fully_populated(),
+    "en locale has an empty message"
+);
+const _: () = assert!(
+    ko::STRINGS.is_fully_populated(),
+    "ko locale has an empty message"
+);
+
+/// Substitutes `{key}` placeholders in a single left-to-right pass.
+///
+/// Substituted values are never re-scanned, so a value containing `{name}`
+/// is emitted verbatim. `{{` and `}}` produce literal braces, and a
+/// `{...}` that names no known key is left untouched.
 pub(super) fn format_template(template: &str, pairs: &[(&str, &str)]) -> String {
-    let mut output = template.to_string();
-    for (key, value) in pairs {
-        output = output.replace(&format!("{{{key}}}"), value);
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(pos) = rest.find(['{', '}']) {
+        let (literal, tail) = rest.split_at(pos);
+        output.push_str(literal);
+        if let Some(after) = tail.strip_prefix("{{") {
+            output.push('{');
+            rest = after;
+        } else if let Some(after) = tail.strip_prefix("}}") {
+            output.push('}');
+            rest = after;
+        } else if let Some((value, after)) = lookup_placeholder(tail, pairs) {
+            output.push_str(value);
+            rest = after;
+        } else {
+            let mut chars = tail.chars();
+            output.extend(chars.next());
+            rest = chars.as_str();
+        }
     }
+    output.push_str(rest);
     output
 }
 
+fn lookup_placeholder<'a>(tail: &'a str, pairs: &[(&str, &'a str)]) -> Option<(&'a str, &'a str)> {
+    let (key, after) = tail.strip_prefix('{')?.split_once('}')?;
+    pairs
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, value)| (*value, after))
+}
+
 /// Creates a default English `Messages` instance for use in tests.
 #[cfg(test)]
 pub(crate) fn test_messages() -> Messages {
@@ -629,6 +898,92 @@ mod tests {
         );
     }
 
+    fn placeholders(template: &str) -> std::collections::BTreeSet<&str> {
+        template
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .filter(|name| name.chars().all(|c| c.is_ascii_lowercase() || c == '_'))
+            .collect()
+    }
+
+    #[test]
+    fn test_locale_parse_fr() {
+        let fr = Messages::new("fr").unwrap();
+        assert_eq!(
+            fr.strings().infra_up_completed,
+            fr::STRINGS.infra_up_completed
+        );
+        let fr_fr = Messages::new("fr-FR").unwrap();
+        assert_eq!(
+            fr_fr.strings().infra_up_completed,
+            fr::STRINGS.infra_up_completed
+        );
+    }
+
+    #[test]
+    fn translations_keep_english_placeholders() {
+        let en = en::STRINGS.entries();
+        for locale in [&ko::STRINGS, &*fr::STRINGS] {
+            for ((field, en_value), (_, value)) in en.iter().zip(locale.entries()) {
+                assert_eq!(
+                    placeholders(en_value),
+                    placeholders(value),
+                    "placeholder mismatch in {field}: {value}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn partial_locale_falls_back_to_english_for_untranslated_strings() {
+        let partial = partial_strings! {
+            infra_up_completed: "bootroot infra up: fertig",
+            infra_down_completed: "",
+        };
+        let strings = partial.resolve(&en::STRINGS);
+
+        assert_eq!(strings.infra_up_completed, "bootroot infra up: fertig");
+        assert_eq!(
+            strings.infra_down_completed,
+            en::STRINGS.infra_down_completed
+        );
+        assert_eq!(
+            strings.infra_restart_completed,
+            en::STRINGS.infra_restart_completed
+        );
+
+        let untranslated = partial.untranslated_keys();
+        assert!(!untranslated.contains(&"infra_up_completed"));
+        assert!(untranslated.contains(&"infra_down_completed"));
+        assert!(untranslated.contains(&"infra_restart_completed"));
+    }
+
+    #[test]
+    fn format_template_does_not_rescan_substituted_values() {
+        assert_eq!(
+            format_template("{a} and {b}", &[("a", "{b}"), ("b", "two")]),
+            "{b} and two"
+        );
+        assert_eq!(
+            format_template("dsn: {value}", &[("value", "postgres://{user}@db")]),
+            "dsn: postgres://{user}@db"
+        );
+    }
+
+    #[test]
+    fn format_template_supports_literal_brace_escapes() {
+        assert_eq!(
+            format_template("{{value}} is {value}", &[("value", "x")]),
+            "{value} is x"
+        );
+        assert_eq!(format_template("set {{}} and }}", &[]), "set {} and }");
+        assert_eq!(
+            format_template("--reload-style {sighup|systemd}", &[("value", "x")]),
+            "--reload-style {sighup|systemd}"
+        );
+    }
+
     #[test]
     fn readiness_entry_template_output() {
         let messages = Messages::new("en").unwrap();
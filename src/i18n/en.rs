@@ -2,6 +2,9 @@ use super::Strings;
 
 pub(super) static STRINGS: Strings = Strings {
     infra_up_completed: "bootroot infra up: completed",
+    infra_down_completed: "bootroot infra down: completed",
+    infra_restart_completed: "bootroot infra restart: completed",
+    prompt_infra_down_volumes: "Also remove the stack's volumes? OpenBao, PostgreSQL, and step-ca data will be deleted permanently [y/N]: ",
     infra_readiness_summary: "bootroot infra up: readiness summary",
     readiness_entry_with_health: "- {service}: {status} (health: {health})",
     readiness_entry_without_health: "- {service}: {status}",
@@ -25,6 +28,13 @@ pub(super) static STRINGS: Strings = Strings {
     monitoring_down_reset_grafana_skipped: "bootroot monitoring down: grafana reset skipped",
     monitoring_up_already_running: "bootroot monitoring up: already running (run `bootroot monitoring down` first if you want to restart)",
     error_service_no_container: "Service has no running container: {service}",
+    error_compose_missing_service: "Compose file {path} does not define required service {service}; check --compose-file, or drop the service from --services",
+    error_init_compose_missing_service: "Compose file {path} does not define required service {service}; check --compose-file (step-ca and bootroot-http01 can be left out with --skip-step-ca and --skip-responder)",
+    error_compose_file_not_found: "Compose file {path} not found; pass --compose-file or create one of: {candidates}",
+    error_openbao_agent_exec_invalid: "Invalid --openbao-agent-exec value {value}: expected AGENT=CMD with AGENT stepca or responder and a non-empty command",
+    error_ca_json_outside_secrets_dir: "ca.json path {path} is not under the secrets dir {secrets_dir}; the OpenBao Agent renders it inside the secrets mount, so pass a --ca-json path under --secrets-dir",
+    error_resolve_ca_json_failed: "Failed to resolve ca.json path {path}",
+    init_agent_template_header: "# ---- {path} (relative to the secrets directory) ----",
     init_failed_rollback: "bootroot init: failed, attempting rollback",
     prompt_openbao_root_token: "OpenBao root token: ",
     error_openbao_root_token_required: "OpenBao root token is required; pass --root-token, --root-token-file, or the OPENBAO_ROOT_TOKEN env var",
@@ -32,12 +42,15 @@ pub(super) static STRINGS: Strings = Strings {
     warning_openbao_sealed_non_interactive: "WARNING: OpenBao is sealed but stdin is not interactive. Supply --openbao-unseal-from-file or run `bootroot openbao save-unseal-keys` first.",
     error_openbao_audit_setup_failed: "OpenBao audit backend setup failed",
     warning_db_password_rotation_skipped: "WARNING: DB password rotation skipped; run `bootroot rotate db` later to rotate manually.",
+    warning_openbao_agent_render_missing: "WARNING: OpenBao Agent {service} has not rendered {path} within {seconds}s; the copy init wrote was put back. A template error or a missing KV read permission stops the render; check `docker compose logs {service}`.",
     warning_secret_id_ttl_exceeds_recommended: "WARNING: --secret-id-ttl ({value}) exceeds the recommended threshold ({threshold}). Consider a shorter TTL for tighter credential rotation.",
     error_secret_id_ttl_exceeds_max: "--secret-id-ttl ({value}) exceeds the maximum allowed value ({max})",
     error_secret_id_ttl_invalid: "Invalid --secret-id-ttl value: {value}. Use a duration like \"24h\", \"30m\", or \"3600s\".",
     error_rn_cidrs_invalid: "Invalid --rn-cidrs value: {value}. Use CIDR notation (e.g. \"10.0.0.0/24\", \"fd00::/64\").",
     error_rn_cidrs_clear_conflict: "\"clear\" cannot be combined with other --rn-cidrs values",
     error_rn_cidrs_clear_on_add: "\"clear\" is only valid for service update; omit --rn-cidrs to leave CIDR binding unset",
+    error_approle_bind_cidr_invalid: "Invalid --approle-bind-cidr value: {value}. Use CIDR notation (e.g. \"10.0.0.0/24\", \"fd00::/64\").",
+    error_serialize_init_plan_failed: "Failed to serialize init plan",
     prompt_openbao_unseal_from_file_confirm: "Proceed with auto-unseal from file {value}? [y/N]: ",
     error_openbao_unseal_file_empty: "Unseal key file is empty: {value}",
     prompt_unseal_threshold: "Unseal key threshold (t): ",
@@ -51,12 +64,15 @@ pub(super) static STRINGS: Strings = Strings {
     prompt_db_name: "PostgreSQL database name",
     error_invalid_unseal_threshold: "Invalid unseal threshold value",
     error_eab_requires_both: "EAB requires both kid and hmac",
+    error_skip_step_ca_openbao_tls: "--skip-step-ca cannot be combined with a non-loopback OpenBao bind: the OpenBao TLS certificate is issued by the local step-ca",
+    error_current_dir_failed: "Failed to resolve the current directory",
     error_openbao_sealed: "OpenBao remains sealed after applying unseal keys",
     error_invalid_db_dsn: "Invalid PostgreSQL DSN",
     error_db_host_not_single_host: "PostgreSQL host violates single-host guardrail: {host}",
     error_db_host_compose_runtime: "PostgreSQL host '{host}' is not reachable from step-ca container runtime; use '{expected}' in db-dsn/db-admin-dsn",
     error_postgres_port_binding_unsafe: "PostgreSQL port must be bound to localhost only (for example, 127.0.0.1:5432:5432)",
     error_service_port_binding_unsafe: "{service} port must be bound to localhost only (for example, 127.0.0.1:<port>:<port>)",
+    error_postgres_allowed_cidr_invalid: "--allow-nonloopback-postgres must be a CIDR inside private address space (10.0.0.0/8, 172.16.0.0/12, 192.168.0.0/16, or fc00::/7): {value}",
     error_db_check_failed: "DB connectivity check failed",
     error_db_auth_failed: "DB authentication check failed",
     error_db_type_unsupported: "DB check requires postgresql db.type",
@@ -92,8 +108,33 @@ pub(super) static STRINGS: Strings = Strings {
     error_monitoring_failed: "bootroot monitoring failed",
     error_init_failed: "bootroot init failed",
     error_status_failed: "bootroot status failed",
+    error_audit_secrets_failed: "bootroot audit-secrets failed",
+    error_support_bundle_failed: "bootroot support-bundle failed",
+    error_self_test_failed: "bootroot self-test failed",
+    error_self_test_deployment_exists: "Refusing to run self-test: a bootroot deployment already exists ({paths}). Teardown would wipe its state; run the self-test from a fresh directory.",
+    error_self_test_container_exists: "Refusing to run self-test: container `{container}` already exists. The self-test stack reuses the same container names; stop the existing stack first.",
+    error_self_test_exe_missing: "Failed to locate the running bootroot binary",
+    error_self_test_workspace: "Failed to create the self-test scratch directory",
+    error_self_test_step_failed: "self-test step `{step}` failed",
+    error_self_test_teardown_failed: "self-test teardown failed: {reason}. Remove leftover containers and files with `bootroot clean --yes`.",
+    self_test_step_started: "self-test: {step}...",
+    self_test_step_passed: "self-test: {step} ok",
+    self_test_teardown: "self-test: tearing down the throwaway stack (bootroot clean --yes)",
+    self_test_passed: "bootroot self-test: all steps passed; throwaway stack torn down",
+    error_migrate_failed: "bootroot migrate failed",
+    error_trust_export_failed: "bootroot trust export failed",
+    error_trust_show_failed: "bootroot trust show failed",
+    error_migrate_same_location: "Source and destination are both {location}; nothing to migrate",
+    error_migrate_mount_and_prefix: "Moving the mount and the prefix in one run would leave the infra secrets behind; migrate the mount first, then the prefix",
+    error_migrate_source_empty: "No secrets found under {location}",
+    error_migrate_verify_failed: "Verification failed for {path}: the copy read back does not match the source",
+    error_migrate_dual_write_active: "A dual-write transition from {location} is already in progress; run bootroot migrate --finalize first",
+    error_migrate_no_dual_write: "No dual-write transition is in progress; start one with bootroot migrate --dual-write",
+    error_cancelled: "Operation cancelled by interrupt",
+    error_operation_deadline_exceeded: "Operation exceeded its deadline of {timeout} (--operation-timeout)",
     error_service_add_failed: "bootroot service add failed",
     error_service_info_failed: "bootroot service info failed",
+    error_service_list_failed: "bootroot service list failed",
     error_service_remove_failed: "bootroot service remove failed",
     error_verify_failed: "bootroot verify failed",
     error_rotate_failed: "bootroot rotate failed",
@@ -107,6 +148,7 @@ pub(super) static STRINGS: Strings = Strings {
     error_file_missing: "Required file missing: {value}",
     error_read_file_failed: "Failed to read {value}",
     error_write_file_failed: "Failed to write {value}",
+    error_config_file_invalid: "Invalid config file {path}: {reason}",
     error_read_dir_failed: "Failed to read directory: {value}",
     error_read_dir_entry_failed: "Failed to read directory entry",
     error_remove_file_failed: "Failed to remove {value}",
@@ -134,6 +176,8 @@ pub(super) static STRINGS: Strings = Strings {
     error_openbao_approle_login_failed: "OpenBao AppRole login failed",
     error_openbao_kv_exists_failed: "OpenBao KV secret lookup failed",
     error_openbao_kv_write_failed: "OpenBao KV secret write failed",
+    error_openbao_capability_missing: "OpenBao token lacks {capability} on {path}; grant it in policy {policy}",
+    error_openbao_capability_missing_root: "OpenBao token lacks {capability} on {path}; run this step with the root token",
     error_openbao_kv_read_failed: "OpenBao KV secret read failed",
     error_openbao_kv_delete_failed: "OpenBao KV secret delete failed",
     error_openbao_role_output_missing: "OpenBao AppRole output missing: {value}",
@@ -145,16 +189,13 @@ pub(super) static STRINGS: Strings = Strings {
     error_docker_command_failed: "docker command failed: {value}",
     error_bootroot_agent_run_failed: "Failed to run bootroot-agent",
     error_bootroot_agent_not_found: "bootroot-agent binary not found. Tried: {candidates}",
-    error_secrets_dir_resolve_failed: "Failed to resolve secrets dir",
     error_parse_ca_json_failed: "Failed to parse ca.json",
     error_serialize_ca_json_failed: "Failed to serialize ca.json",
     error_rendered_file_timeout: "Timed out waiting for OpenBao Agent to render {value}",
     error_ca_json_db_missing: "ca.json missing db.dataSource field",
+    error_stepca_db_kv_missing: "ca.json dataSource is an unrendered template and OpenBao KV has no step-ca DSN at bootroot/stepca/db",
     error_ca_cert_missing: "CA certificate not found: {value}",
     error_ca_cert_parse_failed: "Failed to parse CA certificate: {value}",
-    error_ca_trust_missing: "OpenBao CA trust data missing key: {value}",
-    error_ca_trust_invalid: "OpenBao CA trust data is invalid",
-    error_ca_trust_empty: "OpenBao CA trust data is empty",
     error_parse_state_failed: "Failed to parse state.json",
     error_serialize_state_failed: "Failed to serialize state.json",
     prompt_service_name: "Service name",
@@ -169,6 +210,7 @@ pub(super) static STRINGS: Strings = Strings {
     prompt_confirm_overwrite_state: "Overwrite state.json? [y/N]: ",
     prompt_rotate_stepca_password: "Rotate step-ca key password? [y/N]",
     prompt_rotate_db: "Rotate step-ca database password? [y/N]",
+    prompt_confirm_secrets_dir_mismatch: "Rotate using {provided} instead of the recorded secrets directory? [y/N]",
     prompt_rotate_responder_hmac: "Rotate HTTP-01 responder HMAC? [y/N]",
     prompt_rotate_approle_secret_id: "Rotate AppRole secret_id for {service_name}? [y/N]",
     prompt_rotate_all_approle_secret_ids: "Rotate AppRole secret_ids for all {count} registered service(s)? [y/N]",
@@ -177,9 +219,16 @@ pub(super) static STRINGS: Strings = Strings {
     init_plan_overwrite_password: "- password.txt: overwrite",
     init_plan_overwrite_ca_json: "- ca.json: overwrite",
     init_plan_overwrite_state: "- state.json: overwrite",
+    init_dry_run_openbao_writes: "OpenBao writes:",
+    init_dry_run_openbao_bootstrap: "  - initialize and unseal OpenBao if it is uninitialized or sealed",
+    init_dry_run_file_writes: "File writes:",
+    init_dry_run_container_commands: "Container commands:",
+    init_dry_run_item: "  - {item}",
+    init_dry_run_done: "Dry run: nothing was changed. Re-run without --dry-run to apply this plan.",
     service_add_summary: "bootroot service add: summary",
     service_add_plan_title: "bootroot service add: plan",
     service_info_summary: "bootroot service info: summary",
+    service_list_entry: "  - {service} ({delivery_mode}): hostname {hostname}, domain {domain}",
     service_summary_kind: "- service name: {value}",
     service_summary_hostname: "- hostname: {value}",
     service_summary_domain: "- domain: {value}",
@@ -212,12 +261,21 @@ pub(super) static STRINGS: Strings = Strings {
     service_summary_agent_config: "- agent config: {value}",
     service_summary_cert_path: "- cert path: {value}",
     service_summary_key_path: "- key path: {value}",
+    service_info_cert_file: "- cert file: {value}",
+    service_info_key_file: "- key file: {value}",
+    service_info_cert_files_remote: "- cert/key files: on the remote host (not checked locally)",
+    cert_file_present: "present",
+    cert_file_missing: "missing",
+    key_file_wrong_mode: "present, mode {mode} (expected {expected})",
     service_summary_next_steps: "next steps:",
     service_summary_preview_mode: "- preview mode: no files or state were changed",
     service_summary_preview_trust_skipped_no_token: "- trust preview unavailable: provide OpenBao runtime auth (--root-token or --auth-mode approle with AppRole credentials) in preview mode",
     service_summary_preview_trust_not_found: "- trust preview unavailable: OpenBao CA trust data (secret/bootroot/ca) was not found",
     service_summary_preview_trust_lookup_failed: "- trust preview lookup failed: {value}",
     service_summary_remote_idempotent_hint: "- existing remote-bootstrap service matched input; bootstrap artifact was refreshed",
+    error_serialize_service_add_result_failed: "Failed to serialize the service add result",
+    error_serialize_service_info_failed: "Failed to serialize the service info",
+    error_serialize_service_list_failed: "Failed to serialize the service list",
     service_next_steps_daemon_profile: "  - Add profile for {service_name} (instance_id={instance_id}, hostname={hostname}, domain={domain}, cert={cert_path}, key={key_path}) to {config_path} and reload bootroot-agent.",
     service_next_steps_remote_selfheal_keep: "  - Keep bootroot-agent running on the remote host after `bootroot-remote bootstrap` (see the remote handoff order above).",
     service_next_steps_remote_selfheal_note: concat!(
@@ -249,7 +307,54 @@ pub(super) static STRINGS: Strings = Strings {
     verify_ca_bundle_parse_failed: "Failed to parse CA bundle at {path}",
     verify_ca_bundle_missing_fingerprints: "CA bundle at {path} is missing trusted fingerprints: {missing}",
     verify_cert_chain_failed: "Leaf certificate at {cert_path} does not chain to CA bundle at {bundle_path}; reissue the leaf so it matches the current PKI generation.",
+    audit_secrets_not_git_repo: "Not inside a git work tree; nothing to audit.",
+    audit_secrets_clean: "No secret files are tracked by git.",
+    audit_secrets_tracked: "WARNING: secret file is tracked by git: {value}",
+    audit_secrets_hint: "Remove them from the index with `git rm --cached <path>`, add them to .gitignore, and rotate any secret that was already pushed.",
+    error_audit_secrets_tracked: "{count} secret file(s) tracked by git",
+    support_bundle_written: "Support bundle written to {value}",
+    support_bundle_state_missing: "state.json not found at {value}; bundle omits it.",
+    support_bundle_command_failed: "command failed: {value}",
+    version_git_sha: "git commit: {value}",
+    version_build_date: "build date: {value}",
+    version_rustc: "rustc: {value}",
+    version_docker: "docker: {value}",
+    version_podman: "podman: {value}",
+    version_compose_images: "compose images ({value}):",
+    version_unavailable: "not available",
+    migrate_copied: "Copied {from} -> {to}",
+    migrate_summary: "Migrated {count} secrets from {from} to {to} (each copy verified)",
+    migrate_state_updated: "state.json kv_mount updated to {mount}",
+    migrate_state_prefix_updated: "state.json kv_prefix updated to {prefix}; service add and rotate now use {prefix}/services",
+    trust_export_source_kv: "Read the CA bundle from OpenBao KV ({path})",
+    trust_export_source_local: "Read the CA bundle from local certs ({dir})",
+    trust_export_kv_unavailable: "OpenBao KV CA bundle unavailable ({reason}); falling back to local certs",
+    trust_export_written: "Wrote the CA bundle ({format}, {count} certificates) to {path}",
+    trust_export_fingerprints: "CA fingerprints (SHA-256):",
+    error_trust_export_no_certs: "CA bundle contains no certificates",
+    error_trust_export_der_multiple: "--format der holds a single certificate, but the CA bundle has {count}; use pem or p7b",
+    trust_export_no_root_token: "no OpenBao root token",
+    trust_show_local_header: "Local CA certificates ({dir}):",
+    trust_show_kv_header: "OpenBao KV CA bundle ({path}):",
+    trust_show_kv_unavailable: "OpenBao KV CA bundle: not compared ({reason})",
+    trust_show_cert: "  {role}: {subject}",
+    trust_show_cert_sha256: "    SHA-256: {sha256}",
+    trust_show_cert_not_after: "    not after: {not_after}",
+    trust_show_role_root: "root",
+    trust_show_role_intermediate: "intermediate",
+    trust_show_drift_none: "Drift: none (local certificates match the OpenBao KV bundle)",
+    trust_show_drift_detected: "Drift: local certificates differ from the OpenBao KV bundle; run `bootroot rotate trust-sync` to republish",
+    migrate_confirm_delete: "Delete the {count} source secrets under {location}? [y/N]: ",
+    migrate_source_deleted: "Deleted {count} source secrets under {location}",
+    migrate_source_kept: "Source secrets under {location} were kept; pass --delete-source to remove them",
+    migrate_dual_write_started: "Dual-write enabled: init and rotate now write to {current} and mirror every secret to {legacy}. Run bootroot migrate --finalize once every agent reads the new location.",
+    migrate_dual_write_finalized: "Deleted {count} secrets under {location}; dual-write transition finished",
+    migrate_references_note: "AppRole policies, OpenBao Agent templates, and service agent configs written before the migration still reference the old location; update them before relying on the new one.",
+    cancellation_requested: "Interrupt received; finishing the current step before exiting (press Ctrl-C again to abort immediately).",
+    status_openbao_target_inferred: "Warning: state.json not found; using OpenBao URL {url} (from {source}) and KV mount {kv_mount}.",
+    status_openbao_target_default_source: "default",
     status_summary_title: "bootroot status: summary",
+    error_serialize_status_report_failed: "Failed to serialize the status report",
     status_section_infra: "- infra:",
     status_section_openbao: "- OpenBao:",
     status_section_kv_paths: "- KV paths:",
@@ -262,6 +367,7 @@ pub(super) static STRINGS: Strings = Strings {
     status_kv_path_entry: "  - {path}: {value}",
     status_approle_entry: "  - {role}: {value}",
     status_service_delivery_mode: "  - {service} delivery mode: {value}",
+    status_service_cert_files: "  - {service} cert: {cert}, key: {key}",
     status_value_ok: "ok",
     status_value_unreachable: "unreachable",
     status_value_present: "present",
@@ -279,19 +385,30 @@ pub(super) static STRINGS: Strings = Strings {
     summary_secrets_dir: "- Secrets dir: {value}",
     summary_stepca_completed: "- step-ca init: completed",
     summary_stepca_skipped: "- step-ca init: skipped (already initialized)",
+    summary_stepca_external: "- step-ca init: skipped (external CA, --skip-step-ca)",
+    summary_stepca_deferred: "- step-ca init: deferred (--openbao-only)",
+    init_resume_notice: "Resuming a previous init: existing policies, AppRoles, and KV secrets are kept; only missing pieces are created.",
+    init_cancelled_openbao_credentials: "Init stopped after OpenBao was initialized. Keep these to resume with bootroot init --resume --root-token <token>:\nRoot token: {root_token}\nKeys:\n{keys}",
     summary_openbao_init_completed: "- OpenBao init: completed (shares={shares}, threshold={threshold})",
     summary_openbao_init_skipped: "- OpenBao init: skipped (already initialized)",
     summary_root_token: "- root token: {value}",
     summary_unseal_key: "- unseal key {index}: {value}",
+    summary_recovery_key: "- recovery key {index}: {value}",
     summary_stepca_password: "- step-ca password: {value}",
     summary_db_dsn: "- db dsn: {value}",
     summary_responder_hmac: "- responder hmac: {value}",
     summary_eab_kid: "- eab kid: {value}",
     summary_eab_hmac: "- eab hmac: {value}",
     summary_eab_missing: "- eab: not configured",
+    summary_secrets_revealed_to_file: "Unmasked secrets written to {path} (mode 0600)",
+    error_reveal_to_file_failed: "Failed to write unmasked secrets to {path}: {reason}. They are printed below so they are not lost:",
+    warning_init_reveal_to_file_failed: "WARNING: could not write unmasked secrets to {path} ({reason}); init completed. They are printed below so they are not lost:",
+    error_init_audit_log_open_failed: "Failed to open init audit log {path}: {reason}",
+    warning_init_audit_log_write_failed: "WARNING: could not append step {step} to the init audit log {path} ({reason}); init continues.",
     rotate_summary_title: "bootroot rotate: summary",
     rotate_summary_stepca_password: "- step-ca password updated: {value}",
     rotate_summary_restart_stepca: "- step-ca: restarted",
+    error_service_not_recovered: "Service {service} did not become healthy after restart",
     rotate_summary_db_dsn: "- ca.json updated: {value}",
     rotate_summary_responder_config: "- responder config updated: {value}",
     rotate_summary_reload_responder: "- responder: sent SIGHUP",
@@ -314,6 +431,16 @@ pub(super) static STRINGS: Strings = Strings {
     rotate_summary_self_mint: "- re-minted own {role_name} secret_id (num_uses={num_uses}): {value}",
     rotate_summary_self_mint_login_ok: "- self-mint login verification OK for {role_name}",
     warning_self_mint_skipped_non_file: "WARNING: skipped the {role_name} self-mint: AppRole auth was not file-based. The self-mint step replaces the file passed via --approle-secret-id-file; with an inline or env-supplied secret_id there is no file to replace. The current credential expires at its TTL — switch the scheduled job to --approle-secret-id-file, or re-mint before expiry.",
+    warning_openbao_agent_not_found: "WARNING: no OpenBao Agent container for {service} was found (expected {container}). The new value is stored in OpenBao but the agent was not reloaded; restart it manually to pick up the change.",
+    warning_openbao_agent_not_running: "WARNING: OpenBao Agent container {container} is {state}. The new value is stored in OpenBao but the agent was not reloaded; start it to pick up the change.",
+    error_openbao_agent_not_reloaded: "OpenBao Agent {service} was not reloaded, so the new value was not rendered. Start it (docker compose up -d {service}) and wait for it to render the new value before restarting the services that read it.",
+    rotate_plan_title: "bootroot rotate {target}: plan (dry run; nothing was changed)",
+    rotate_plan_kv_write: "- KV path to write: {value}",
+    rotate_plan_file: "- file to update: {value}",
+    rotate_plan_approle: "- AppRole to mint a new secret_id for: {value}",
+    rotate_plan_recovery: "- OpenBao recovery credential to regenerate: {value}",
+    rotate_plan_restart: "- container or service to restart/reload: {value}",
+    rotate_plan_empty: "- nothing to change",
     error_self_mint_failed: "Failed to re-mint the {role_name} credential's own secret_id (all rotation targets succeeded). The current credential stays valid until its TTL and the next run retries the self-mint.",
     error_self_mint_verify_failed: "The freshly self-minted {role_name} secret_id failed login verification; the existing credential file is left untouched. Check the recorded rotate-bound CIDRs — a binding that excludes this host would lock out the scheduled job.",
     error_rotate_bound_cidrs_requires_provisioning: "--rotate-bound-cidrs is only honored on the root-token provisioning run (--infra with root auth); re-run with the root token or drop the flag",
@@ -331,6 +458,8 @@ pub(super) static STRINGS: Strings = Strings {
     prompt_rotate_force_reissue_remote: "Write a reissue request for remote-bootstrap service {service_name} to OpenBao? The remote agent will apply it on its next fast-poll tick. [y/N]",
     rotate_summary_trust_sync_global: "- CA trust updated: {value}",
     rotate_summary_trust_sync_service: "- service trust synced: {value}",
+    rotate_summary_trust_sync_generation: "- CA trust generation: {value}",
+    rotate_summary_trust_sync_current: "- service trust already current (skipped): {value}",
     rotate_summary_force_reissue_deleted: "- {service_name}: cert/key deleted ({cert_path}, {key_path})",
     rotate_summary_force_reissue_local_signal: "- {service_name}: signaled bootroot-agent for renewal",
     rotate_summary_force_reissue_requested: "- {service_name}: reissue requested at {requested_at}",
@@ -346,6 +475,7 @@ pub(super) static STRINGS: Strings = Strings {
     error_rotation_mode_mismatch: "rotation-state.json mode ({mode}) does not match the --full flag; complete or abort the existing rotation first",
     error_parse_cert_failed: "Failed to parse certificate {path}: {reason}",
     warning_stale_backup: "WARNING: Backup files (.bak) exist without rotation-state.json; a previous rotation may have been interrupted.",
+    warning_secrets_dir_mismatch: "WARNING: --secrets-dir {provided} differs from the secrets directory recorded in state.json ({recorded}); rotate will read and write {provided}.",
     rotate_ca_key_resuming: "Resuming CA key rotation from phase {phase}.",
     rotate_ca_key_phase_backup: "[Phase 1] Backing up intermediate CA cert and key...",
     rotate_ca_key_phase_generate: "[Phase 2] Generating new intermediate CA key pair...",
@@ -374,7 +504,7 @@ pub(super) static STRINGS: Strings = Strings {
     summary_role_id: "    role_id: {value}",
     summary_secret_id: "    secret_id: {value}",
     summary_next_steps: "next steps:",
-    next_steps_configure_templates: "  - Configure OpenBao Agent templates for step-ca, responder, and bootroot-agent.",
+    next_steps_configure_templates: "  - Configure OpenBao Agent templates for step-ca, responder, and bootroot-agent (`bootroot init --print-agent-config-template` prints them).",
     next_steps_responder_template: "  - Responder template: {value}",
     next_steps_responder_config: "  - Responder config output: {value}",
     next_steps_responder_url: "  - Responder admin URL: {value}",
@@ -383,11 +513,19 @@ pub(super) static STRINGS: Strings = Strings {
     next_steps_openbao_agent_override: "  - OpenBao Agent compose override: {value}",
     next_steps_reload_services: "  - Start or reload step-ca and responder to consume rendered secrets.",
     next_steps_run_status: "  - Run `bootroot status` to verify services.",
+    next_steps_finish_openbao_only: "  - Run `bootroot init --resume` to initialize step-ca, write the responder and OpenBao Agent files, and publish CA trust.",
     next_steps_eab_hint: "  - If your ACME CA requires EAB, provision kid/hmac externally (bundled OSS step-ca does not support EAB), then store them at {path} or rerun with --eab-kid/--eab-hmac.",
     infra_install_completed: "bootroot infra install: completed",
     infra_install_env_written: "bootroot infra install: .env written",
     infra_install_dirs_created: "bootroot infra install: directories created",
     error_infra_install_failed: "bootroot infra install failed",
+    error_infra_down_failed: "bootroot infra down failed",
+    error_infra_restart_failed: "bootroot infra restart failed",
+    error_infra_logs_failed: "bootroot infra logs failed",
+    error_restart_policy_invalid: "Invalid --restart-policy '{value}': expected one of no, always, unless-stopped, on-failure, on-failure:N (N is a retry count)",
+    error_service_timeout_invalid: "Invalid --service-timeout '{value}': expected <service>=<seconds>, e.g. step-ca=180",
+    warning_infra_pull_retry: "{runtime} compose pull failed (retry {attempt}/{retries} in {delay}): {reason}",
+    infra_openbao_auto_unseal_skipped: "OpenBao uses an auto-unseal seal (recorded by init --auto-unseal); skipping the unseal step",
     clean_completed: "bootroot clean: completed",
     clean_confirm: "This will remove containers, volumes, secrets, state.json, and .env. Continue? [y/N]: ",
     clean_confirm_certs: "Also remove certs/ directory? [y/N]: ",
@@ -421,6 +559,8 @@ pub(super) static STRINGS: Strings = Strings {
     service_info_secret_id_ttl: "- secret_id TTL: {value}",
     service_info_secret_id_wrap_ttl: "- secret_id wrap TTL: {value}",
     service_info_token_bound_cidrs: "- token_bound_cidrs: {value}",
+    service_info_sync_generation: "- remote sync generation: {value}",
+    service_info_sync_generation_none: "- remote sync generation: not published yet",
     policy_label_inherit: "inherit",
     policy_label_disabled: "disabled",
     policy_label_default_wrap_ttl: "{value} (default)",
@@ -0,0 +1,691 @@
+use std::sync::LazyLock;
+
+use super::{PartialStrings, Strings, en, partial_strings};
+
+/// French messages. Any string left out of [`TRANSLATED`] is shown in
+/// English.
+pub(super) static STRINGS: LazyLock<Strings> = LazyLock::new(|| TRANSLATED.resolve(&en::STRINGS));
+
+static TRANSLATED: PartialStrings = partial_strings! {
+    infra_up_completed: "bootroot infra up : terminé",
+    infra_down_completed: "bootroot infra down : terminé",
+    infra_restart_completed: "bootroot infra restart : terminé",
+    prompt_infra_down_volumes: "Supprimer aussi les volumes de la pile ? Les données OpenBao, PostgreSQL et step-ca seront définitivement effacées [y/N] : ",
+    infra_readiness_summary: "bootroot infra up : état de disponibilité",
+    readiness_entry_with_health: "- {service}: {status} (health: {health})",
+    readiness_entry_without_health: "- {service}: {status}",
+    infra_unhealthy: "L'infrastructure n'est pas en bonne santé : {failures}",
+    monitoring_up_completed: "bootroot monitoring up : terminé",
+    monitoring_readiness_summary: "bootroot monitoring up : état de disponibilité",
+    monitoring_unhealthy: "La supervision n'est pas en bonne santé : {failures}",
+    monitoring_status_title: "bootroot monitoring status : résumé",
+    monitoring_status_profile: "- profil : {value}",
+    monitoring_status_section_services: "- services :",
+    status_entry_with_health: "  - {service}: {status} (health: {health})",
+    status_entry_without_health: "  - {service}: {status}",
+    monitoring_status_grafana_url: "- grafana : {value}",
+    monitoring_status_grafana_admin_password: "- mot de passe admin grafana : {value}",
+    monitoring_status_value_set: "défini",
+    monitoring_status_value_default: "par défaut (admin)",
+    monitoring_status_value_unknown: "inconnu",
+    monitoring_status_no_services: "bootroot monitoring status : aucun service de supervision trouvé",
+    monitoring_down_completed: "bootroot monitoring down : terminé",
+    monitoring_down_reset_grafana: "bootroot monitoring down : mot de passe admin grafana réinitialisé",
+    monitoring_down_reset_grafana_skipped: "bootroot monitoring down : réinitialisation de grafana ignorée",
+    monitoring_up_already_running: "bootroot monitoring up : déjà en cours d'exécution (lancez d'abord `bootroot monitoring down` pour redémarrer)",
+    error_service_no_container: "Le service n'a aucun conteneur en cours d'exécution : {service}",
+    error_compose_missing_service: "Le fichier compose {path} ne définit pas le service requis {service} ; vérifiez --compose-file ou retirez ce service de --services",
+    error_init_compose_missing_service: "Le fichier compose {path} ne définit pas le service requis {service} ; vérifiez --compose-file (step-ca et bootroot-http01 peuvent être omis avec --skip-step-ca et --skip-responder)",
+    error_compose_file_not_found: "Fichier compose {path} introuvable ; passez --compose-file ou créez l'un des fichiers suivants : {candidates}",
+    error_openbao_agent_exec_invalid: "Valeur --openbao-agent-exec invalide {value} : format attendu AGENT=CMD avec AGENT valant stepca ou responder et une commande non vide",
+    error_ca_json_outside_secrets_dir: "Le chemin ca.json {path} n'est pas sous le répertoire des secrets {secrets_dir} ; l'OpenBao Agent le génère dans le montage des secrets, passez donc un chemin --ca-json situé sous --secrets-dir",
+    error_resolve_ca_json_failed: "Impossible de résoudre le chemin ca.json {path}",
+    init_agent_template_header: "# ---- {path} (relatif au répertoire des secrets) ----",
+    init_failed_rollback: "bootroot init : échec, tentative de retour arrière",
+    prompt_openbao_root_token: "Jeton root OpenBao : ",
+    error_openbao_root_token_required: "Le jeton root OpenBao est requis ; passez --root-token, --root-token-file ou la variable d'environnement OPENBAO_ROOT_TOKEN",
+    warning_openbao_unseal_from_file: "AVERTISSEMENT : le descellement automatique depuis un fichier est réservé au dev/test et dangereux en production.",
+    warning_openbao_sealed_non_interactive: "AVERTISSEMENT : OpenBao est scellé mais stdin n'est pas interactif. Fournissez --openbao-unseal-from-file ou lancez d'abord `bootroot openbao save-unseal-keys`.",
+    error_openbao_audit_setup_failed: "Échec de la configuration du backend d'audit OpenBao",
+    warning_db_password_rotation_skipped: "AVERTISSEMENT : rotation du mot de passe de la base ignorée ; lancez `bootroot rotate db` plus tard pour l'effectuer manuellement.",
+    warning_openbao_agent_render_missing: "AVERTISSEMENT : l'OpenBao Agent {service} n'a pas généré {path} en {seconds} s ; la copie écrite par init a été remise en place. Une erreur de modèle ou une permission de lecture KV manquante empêche le rendu ; consultez `docker compose logs {service}`.",
+    warning_secret_id_ttl_exceeds_recommended: "AVERTISSEMENT : --secret-id-ttl ({value}) dépasse le seuil recommandé ({threshold}). Envisagez un TTL plus court pour une rotation plus stricte des identifiants.",
+    error_secret_id_ttl_exceeds_max: "--secret-id-ttl ({value}) dépasse la valeur maximale autorisée ({max})",
+    error_secret_id_ttl_invalid: "Valeur --secret-id-ttl invalide : {value}. Utilisez une durée comme \"24h\", \"30m\" ou \"3600s\".",
+    error_rn_cidrs_invalid: "Valeur --rn-cidrs invalide : {value}. Utilisez la notation CIDR (par ex. \"10.0.0.0/24\", \"fd00::/64\").",
+    error_rn_cidrs_clear_conflict: "\"clear\" ne peut pas être combiné avec d'autres valeurs --rn-cidrs",
+    error_rn_cidrs_clear_on_add: "\"clear\" n'est valide que pour service update ; omettez --rn-cidrs pour laisser la liaison CIDR non définie",
+    error_approle_bind_cidr_invalid: "Valeur --approle-bind-cidr invalide : {value}. Utilisez la notation CIDR (par ex. \"10.0.0.0/24\", \"fd00::/64\").",
+    error_serialize_init_plan_failed: "Échec de la sérialisation du plan d'init",
+    prompt_openbao_unseal_from_file_confirm: "Procéder au descellement automatique depuis le fichier {value} ? [y/N] : ",
+    error_openbao_unseal_file_empty: "Le fichier de clés de descellement est vide : {value}",
+    prompt_unseal_threshold: "Seuil des clés de descellement (t) : ",
+    prompt_unseal_key: "Clé de descellement {index}/{count} : ",
+    prompt_stepca_password: "mot de passe step-ca",
+    prompt_http_hmac: "HMAC du responder HTTP-01",
+    prompt_db_admin_dsn: "DSN administrateur PostgreSQL",
+    prompt_db_dsn: "DSN PostgreSQL",
+    prompt_db_user: "utilisateur PostgreSQL",
+    prompt_db_password: "mot de passe PostgreSQL",
+    prompt_db_name: "nom de la base PostgreSQL",
+    error_invalid_unseal_threshold: "Valeur de seuil de descellement invalide",
+    error_eab_requires_both: "L'EAB exige à la fois kid et hmac",
+    error_skip_step_ca_openbao_tls: "--skip-step-ca ne peut pas être combiné avec une liaison OpenBao non-loopback : le certificat TLS d'OpenBao est émis par le step-ca local",
+    error_current_dir_failed: "Impossible de résoudre le répertoire courant",
+    error_openbao_sealed: "OpenBao reste scellé après application des clés de descellement",
+    error_invalid_db_dsn: "DSN PostgreSQL invalide",
+    error_db_host_not_single_host: "L'hôte PostgreSQL enfreint la règle d'hôte unique : {host}",
+    error_db_host_compose_runtime: "L'hôte PostgreSQL '{host}' n'est pas joignable depuis l'environnement du conteneur step-ca ; utilisez '{expected}' dans db-dsn/db-admin-dsn",
+    error_postgres_port_binding_unsafe: "Le port PostgreSQL doit être lié uniquement à localhost (par exemple 127.0.0.1:5432:5432)",
+    error_service_port_binding_unsafe: "Le port de {service} doit être lié uniquement à localhost (par exemple 127.0.0.1:<port>:<port>)",
+    error_postgres_allowed_cidr_invalid: "--allow-nonloopback-postgres doit être un CIDR dans l'espace d'adressage privé (10.0.0.0/8, 172.16.0.0/12, 192.168.0.0/16 ou fc00::/7) : {value}",
+    error_db_check_failed: "Échec de la vérification de connectivité à la base",
+    error_db_auth_failed: "Échec de la vérification d'authentification à la base",
+    error_db_type_unsupported: "La vérification de la base exige db.type postgresql",
+    error_db_provision_conflict: "db-provision ne peut pas être utilisé avec db-dsn",
+    error_invalid_db_identifier: "Identifiant de base invalide : {value}",
+    prompt_eab_register_now: "Enregistrer l'EAB maintenant ? [y/N] : ",
+    eab_prompt_instructions: "Générez des identifiants EAB dans votre CA ACME (offre commerciale compatible EAB) et collez les valeurs ci-dessous.",
+    prompt_eab_kid: "kid EAB : ",
+    prompt_eab_hmac: "hmac EAB : ",
+    prompt_confirm_db_provision: "Provisionner le rôle/la base PostgreSQL ? [y/N] : ",
+    error_responder_check_failed: "Échec de la vérification du responder HTTP-01",
+    error_state_missing: "state.json introuvable (lancez d'abord `bootroot init`)",
+    error_service_duplicate: "Le service existe déjà : {value}",
+    error_service_agent_config_conflict: "La configuration d'agent {path} est déjà utilisée par le service {service}. Chaque service local-file distinct a besoin de son propre agent.toml : la section [openbao] contient une seule identité AppRole, donc deux services partageant une configuration écraseraient mutuellement leurs identifiants",
+    error_service_agent_config_stale_profile: "La configuration d'agent {path} contient encore un profil géré par bootroot pour le service {service} (probablement laissé par `service remove` sans --strip-config/--delete-artifacts). L'agent interroge en fast-poll chaque profil d'une configuration sous son unique identité AppRole [openbao], donc le service obsolète fonctionnerait avec les identifiants de ce service. Supprimez le bloc `# BEGIN/END bootroot managed profile: {service}` (ou utilisez un agent.toml distinct) puis réessayez",
+    error_service_secret_id_path_requires_local_file: "--secret-id-path n'est pris en compte que pour la livraison local-file ; les services remote-bootstrap enregistrent le chemin secret_id de l'hôte de contrôle dans l'artefact de bootstrap, il ne peut donc pas être déplacé ici",
+    error_service_secret_id_path_not_absolute: "--secret-id-path doit être un chemin absolu : {value}",
+    error_service_secret_id_path_role_id_collision: "--secret-id-path ne doit pas se terminer par `role_id` : le fichier voisin `role_id` est dérivé en <dir>/role_id et entrerait en collision avec secret_id à {value}",
+    error_service_secret_id_path_inside_secrets_dir: "--secret-id-path doit se résoudre hors de l'arborescence des secrets appartenant à root {secrets_dir} : un chemin à l'intérieur ({value}) ne peut pas être parcouru par l'agent non-root",
+    error_service_secret_id_path_parent_missing: "Le répertoire parent de --secret-id-path n'existe pas : {value} (créez-le, avec le compte de l'agent comme propriétaire, avant de lancer `service add`)",
+    error_service_policy_mismatch: "Pour modifier la politique secret_id d'un service existant, utilisez `bootroot service update`",
+    error_service_not_found: "Service introuvable : {value}",
+    error_service_instance_id_required: "instance_id est requis pour tous les services",
+    error_value_required: "Une valeur est requise",
+    error_service_name_invalid: "service_name doit être une étiquette DNS (lettres, chiffres et tirets uniquement ; 63 caractères max)",
+    error_hostname_invalid: "hostname doit être une étiquette DNS (lettres, chiffres et tirets uniquement ; 63 caractères max)",
+    error_domain_invalid: "domain doit être un nom DNS composé d'étiquettes séparées par des points (lettres, chiffres et tirets uniquement)",
+    error_instance_id_invalid: "instance_id doit être numérique",
+    error_path_not_found: "Chemin introuvable : {value}",
+    error_parent_not_found: "Répertoire parent introuvable : {value}",
+    error_operation_cancelled: "Opération annulée",
+    error_infra_failed: "Échec de bootroot infra up",
+    error_monitoring_failed: "Échec de bootroot monitoring",
+    error_init_failed: "Échec de bootroot init",
+    error_status_failed: "Échec de bootroot status",
+    error_audit_secrets_failed: "Échec de bootroot audit-secrets",
+    error_support_bundle_failed: "Échec de bootroot support-bundle",
+    error_self_test_failed: "Échec de bootroot self-test",
+    error_self_test_deployment_exists: "Refus de lancer le self-test : un déploiement bootroot existe déjà ({paths}). Le démantèlement supprimerait son état ; lancez le self-test depuis un répertoire vierge.",
+    error_self_test_container_exists: "Refus de lancer le self-test : le conteneur `{container}` existe déjà. La pile du self-test réutilise les mêmes noms de conteneurs ; arrêtez d'abord la pile existante.",
+    error_self_test_exe_missing: "Impossible de localiser le binaire bootroot en cours d'exécution",
+    error_self_test_workspace: "Impossible de créer le répertoire de travail temporaire du self-test",
+    error_self_test_step_failed: "Échec de l'étape `{step}` du self-test",
+    error_self_test_teardown_failed: "Échec du démantèlement du self-test : {reason}. Supprimez les conteneurs et fichiers restants avec `bootroot clean --yes`.",
+    self_test_step_started: "self-test : {step}...",
+    self_test_step_passed: "self-test : {step} ok",
+    self_test_teardown: "self-test : démantèlement de la pile jetable (bootroot clean --yes)",
+    self_test_passed: "self-test : toutes les étapes ont réussi ; la pile jetable a été démantelée",
+    error_migrate_failed: "Échec de bootroot migrate",
+    error_trust_export_failed: "Échec de bootroot trust export",
+    error_trust_show_failed: "Échec de bootroot trust show",
+    error_migrate_same_location: "La source et la destination sont toutes deux {location} ; rien à migrer",
+    error_migrate_mount_and_prefix: "Déplacer le montage et le préfixe en une seule fois laisserait les secrets d'infrastructure derrière ; migrez d'abord le montage, puis le préfixe",
+    error_migrate_source_empty: "Aucun secret trouvé sous {location}",
+    error_migrate_verify_failed: "Échec de la vérification pour {path} : la copie relue ne correspond pas à la source",
+    error_migrate_dual_write_active: "Une transition en double écriture depuis {location} est déjà en cours ; lancez d'abord bootroot migrate --finalize",
+    error_migrate_no_dual_write: "Aucune transition en double écriture n'est en cours ; démarrez-en une avec bootroot migrate --dual-write",
+    error_cancelled: "Opération annulée par une interruption",
+    error_operation_deadline_exceeded: "L'opération a dépassé son délai de {timeout} (--operation-timeout)",
+    error_service_add_failed: "Échec de bootroot service add",
+    error_service_info_failed: "Échec de bootroot service info",
+    error_service_list_failed: "Échec de bootroot service list",
+    error_service_remove_failed: "Échec de bootroot service remove",
+    error_verify_failed: "Échec de bootroot verify",
+    error_rotate_failed: "Échec de bootroot rotate",
+    error_details: "détails : {value}",
+    error_runtime_init_failed: "Impossible d'initialiser le runtime asynchrone pour {command}",
+    error_prompt_write_failed: "Impossible d'écrire l'invite",
+    error_prompt_flush_failed: "Impossible de vider l'invite",
+    error_prompt_read_failed: "Impossible de lire la saisie",
+    error_prompt_eof: "aucune saisie disponible (stdin a atteint EOF / n'est pas un terminal)",
+    error_prompt_error_write_failed: "Impossible d'écrire l'erreur de saisie",
+    error_file_missing: "Fichier requis manquant : {value}",
+    error_read_file_failed: "Impossible de lire {value}",
+    error_write_file_failed: "Impossible d'écrire {value}",
+    error_config_file_invalid: "Fichier de configuration {path} invalide : {reason}",
+    error_read_dir_failed: "Impossible de lire le répertoire : {value}",
+    error_read_dir_entry_failed: "Impossible de lire une entrée de répertoire",
+    error_remove_file_failed: "Impossible de supprimer {value}",
+    error_restore_file_failed: "Impossible de restaurer {value}",
+    error_resolve_path_failed: "Impossible de résoudre {value}",
+    error_generate_secret_failed: "Impossible de générer un secret aléatoire",
+    error_db_auth_task_failed: "Échec de la tâche de vérification d'authentification à la base",
+    error_db_provision_task_failed: "Échec de la tâche de provisionnement de la base",
+    error_stepca_password_missing: "Fichier de mot de passe step-ca introuvable à {value}",
+    error_openbao_client_create_failed: "Impossible de créer le client OpenBao",
+    error_openbao_health_check_failed: "Échec de la vérification de santé d'OpenBao",
+    error_openbao_init_status_failed: "Échec de la vérification de l'état d'initialisation d'OpenBao",
+    error_openbao_init_failed: "Échec de la requête d'initialisation d'OpenBao",
+    error_openbao_seal_status_failed: "Échec de la requête d'état de scellement d'OpenBao",
+    error_openbao_unseal_failed: "Échec de la requête de descellement d'OpenBao",
+    error_openbao_kv_mount_failed: "Échec de la vérification du montage KV d'OpenBao",
+    error_openbao_kv_mount_status_failed: "Échec de la vérification de l'état du montage KV d'OpenBao",
+    error_openbao_approle_auth_failed: "Échec de la configuration de l'authentification AppRole d'OpenBao",
+    error_openbao_policy_exists_failed: "Échec de la recherche de politique OpenBao",
+    error_openbao_policy_write_failed: "Échec de l'écriture de politique OpenBao",
+    error_openbao_approle_exists_failed: "Échec de la recherche d'AppRole OpenBao",
+    error_openbao_approle_create_failed: "Échec de la création d'AppRole OpenBao",
+    error_openbao_role_id_failed: "Échec de la lecture du role_id de l'AppRole OpenBao",
+    error_openbao_secret_id_failed: "Échec de la création du secret_id de l'AppRole OpenBao",
+    error_openbao_approle_login_failed: "Échec de la connexion AppRole à OpenBao",
+    error_openbao_kv_exists_failed: "Échec de la recherche de secret KV OpenBao",
+    error_openbao_kv_write_failed: "Échec de l'écriture de secret KV OpenBao",
+    error_openbao_capability_missing: "Le jeton OpenBao n'a pas la capacité {capability} sur {path} ; accordez-la dans la politique {policy}",
+    error_openbao_capability_missing_root: "Le jeton OpenBao n'a pas la capacité {capability} sur {path} ; lancez cette étape avec le jeton root",
+    error_openbao_kv_read_failed: "Échec de la lecture de secret KV OpenBao",
+    error_openbao_kv_delete_failed: "Échec de la suppression de secret KV OpenBao",
+    error_openbao_role_output_missing: "Sortie d'AppRole OpenBao manquante : {value}",
+    error_parse_container_env_failed: "Impossible d'analyser les variables d'environnement du conteneur",
+    error_parse_container_mounts_failed: "Impossible d'analyser les montages du conteneur",
+    error_command_run_failed: "Impossible d'exécuter {value}",
+    error_command_failed_status: "{value} a échoué avec le statut : {status}",
+    error_docker_compose_failed: "échec de docker compose : {value}",
+    error_docker_command_failed: "échec de la commande docker : {value}",
+    error_bootroot_agent_run_failed: "Impossible d'exécuter bootroot-agent",
+    error_bootroot_agent_not_found: "Binaire bootroot-agent introuvable. Essayés : {candidates}",
+    error_parse_ca_json_failed: "Impossible d'analyser ca.json",
+    error_serialize_ca_json_failed: "Impossible de sérialiser ca.json",
+    error_rendered_file_timeout: "Délai dépassé en attendant que l'OpenBao Agent génère {value}",
+    error_ca_json_db_missing: "Le champ db.dataSource est absent de ca.json",
+    error_stepca_db_kv_missing: "Le dataSource de ca.json est un modèle non rendu et le KV OpenBao n'a pas de DSN step-ca à bootroot/stepca/db",
+    error_ca_cert_missing: "Certificat de CA introuvable : {value}",
+    error_ca_cert_parse_failed: "Impossible d'analyser le certificat de CA : {value}",
+    error_parse_state_failed: "Impossible d'analyser state.json",
+    error_serialize_state_failed: "Impossible de sérialiser state.json",
+    prompt_service_name: "Nom du service",
+    prompt_hostname: "Nom d'hôte",
+    prompt_domain: "Domaine",
+    prompt_agent_config: "Chemin de la configuration bootroot-agent",
+    prompt_cert_path: "Chemin du certificat",
+    prompt_key_path: "Chemin de la clé",
+    prompt_instance_id: "ID d'instance",
+    prompt_confirm_overwrite_password: "Écraser password.txt ? [y/N] : ",
+    prompt_confirm_overwrite_ca_json: "Écraser ca.json ? [y/N] : ",
+    prompt_confirm_overwrite_state: "Écraser state.json ? [y/N] : ",
+    prompt_rotate_stepca_password: "Effectuer la rotation du mot de passe de clé step-ca ? [y/N]",
+    prompt_rotate_db: "Effectuer la rotation du mot de passe de la base step-ca ? [y/N]",
+    prompt_confirm_secrets_dir_mismatch: "Effectuer la rotation avec {provided} au lieu du répertoire des secrets enregistré ? [y/N]",
+    prompt_rotate_responder_hmac: "Effectuer la rotation du HMAC du responder HTTP-01 ? [y/N]",
+    prompt_rotate_approle_secret_id: "Effectuer la rotation du secret_id AppRole de {service_name} ? [y/N]",
+    prompt_rotate_all_approle_secret_ids: "Effectuer la rotation des secret_id AppRole des {count} service(s) enregistré(s) ? [y/N]",
+    prompt_rotate_infra_approle_secret_id: "Effectuer la rotation du secret_id AppRole d'infrastructure de {role_name} ? [y/N]",
+    init_plan_title: "bootroot init : plan",
+    init_plan_overwrite_password: "- password.txt : écrasement",
+    init_plan_overwrite_ca_json: "- ca.json : écrasement",
+    init_plan_overwrite_state: "- state.json : écrasement",
+    init_dry_run_openbao_writes: "Écritures OpenBao :",
+    init_dry_run_openbao_bootstrap: "  - initialiser et desceller OpenBao s'il n'est pas initialisé ou s'il est scellé",
+    init_dry_run_file_writes: "Écritures de fichiers :",
+    init_dry_run_container_commands: "Commandes de conteneur :",
+    init_dry_run_item: "  - {item}",
+    init_dry_run_done: "Simulation : rien n'a été modifié. Relancez sans --dry-run pour appliquer ce plan.",
+    service_add_summary: "bootroot service add : résumé",
+    service_add_plan_title: "bootroot service add : plan",
+    service_info_summary: "bootroot service info : résumé",
+    service_list_entry: "  - {service} ({delivery_mode}) : hostname {hostname}, domaine {domain}",
+    service_summary_kind: "- nom du service : {value}",
+    service_summary_hostname: "- nom d'hôte : {value}",
+    service_summary_domain: "- domaine : {value}",
+    service_summary_delivery_mode: "- mode de livraison : {value}",
+    service_summary_instance_id: "- instance_id : {value}",
+    service_summary_notes: "- notes : {value}",
+    service_summary_post_renew_hook: "- hook post-renouvellement : {value}",
+    service_summary_policy: "- politique : {value}",
+    service_summary_approle: "- AppRole : {value}",
+    service_summary_secret_path: "- chemin du secret_id : {value}",
+    service_summary_openbao_path: "- chemin OpenBao : bootroot/services/{value}",
+    service_summary_auto_applied_agent_config: "- configuration bootroot-agent appliquée automatiquement : {value}",
+    service_summary_auto_applied_eab_file: "- fichier EAB provisionné automatiquement (présent uniquement si l'EAB est configuré ; passez son chemin via --eab-file) : {value}",
+    service_scope_bootroot_managed: "Géré par bootroot :",
+    service_scope_operator_required: "Géré par l'opérateur (requis) :",
+    service_scope_operator_recommended: "Géré par l'opérateur (recommandé) :",
+    service_scope_operator_optional: "Géré par l'opérateur (facultatif) :",
+    service_summary_remote_bootstrap_file: "- fichier de bootstrap distant (artefact lisible par machine pour l'automatisation) : {value}",
+    service_summary_remote_run_command: "- modèle de commande d'exécution distante : {value}",
+    service_summary_remote_handoff_title: "- ordre de transfert distant :",
+    service_summary_remote_handoff_service_host: "  1. Copiez bootstrap.json et role_id sur l'hôte du service, puis remplacez <REMOTE_ARTIFACT_PATH> et exécutez : {value}",
+    service_summary_remote_handoff_service_host_no_wrap: "  1. Copiez bootstrap.json, role_id et secret_id sur l'hôte du service, puis remplacez <REMOTE_ARTIFACT_PATH> et exécutez : {value}",
+    service_summary_remote_placeholder_warning: concat!(
+        "  - avertissement : le modèle de commande affiché et les champs correspondants de bootstrap.json utilisent ",
+        "des valeurs localhost provisoires pour `--agent-server` et `--agent-responder-url` ; ",
+        "remplacez-les par des points de terminaison step-ca/responder joignables à distance avant ",
+        "de l'exécuter sur une machine de service distincte."
+    ),
+    service_summary_remote_handoff_status_check: "  2. Vérifiez l'état sur l'hôte step-ca : {value}",
+    service_summary_agent_config: "- configuration de l'agent : {value}",
+    service_summary_cert_path: "- chemin du certificat : {value}",
+    service_summary_key_path: "- chemin de la clé : {value}",
+    service_info_cert_file: "- fichier de certificat : {value}",
+    service_info_key_file: "- fichier de clé : {value}",
+    service_info_cert_files_remote: "- fichiers de certificat/clé : sur l'hôte distant (non vérifiés localement)",
+    cert_file_present: "présent",
+    cert_file_missing: "manquant",
+    key_file_wrong_mode: "présent, mode {mode} (attendu {expected})",
+    service_summary_next_steps: "étapes suivantes :",
+    service_summary_preview_mode: "- mode aperçu : aucun fichier ni état n'a été modifié",
+    service_summary_preview_trust_skipped_no_token: "- aperçu de la confiance indisponible : fournissez une authentification OpenBao d'exécution (--root-token ou --auth-mode approle avec des identifiants AppRole) en mode aperçu",
+    service_summary_preview_trust_not_found: "- aperçu de la confiance indisponible : les données de confiance de la CA OpenBao (secret/bootroot/ca) sont introuvables",
+    service_summary_preview_trust_lookup_failed: "- échec de la recherche pour l'aperçu de la confiance : {value}",
+    service_summary_remote_idempotent_hint: "- un service remote-bootstrap existant correspond à la saisie ; l'artefact de bootstrap a été actualisé",
+    error_serialize_service_add_result_failed: "Impossible de sérialiser le résultat de service add",
+    error_serialize_service_info_failed: "Impossible de sérialiser les informations du service",
+    error_serialize_service_list_failed: "Impossible de sérialiser la liste des services",
+    service_next_steps_daemon_profile: "  - Ajoutez le profil de {service_name} (instance_id={instance_id}, hostname={hostname}, domain={domain}, cert={cert_path}, key={key_path}) à {config_path} et rechargez bootroot-agent.",
+    service_next_steps_remote_selfheal_keep: "  - Laissez bootroot-agent tourner sur l'hôte distant après `bootroot-remote bootstrap` (voir l'ordre de transfert distant ci-dessus).",
+    service_next_steps_remote_selfheal_note: concat!(
+        "  - Aucun OpenBao Agent ne tourne sur l'hôte distant : bootroot-agent s'authentifie lui-même ",
+        "et récupère la confiance (ca-bundle.pem + trusted_ca_sha256) et les rotations de secret_id via fast-poll, ",
+        "de sorte que les rotations de CA/confiance et de secret_id se propagent sans re-bootstrap manuel ni apply-secret-id."
+    ),
+    service_snippet_daemon_title: "extrait de profil démon :",
+    service_snippet_daemon_run_title: "commande d'exécution du démon (ExecStart systemd ou shell ; --eab-file est requis pour que la rotation EAB s'applique) :",
+    service_snippet_trust_title: "extrait des paramètres de confiance :",
+    service_snippet_domain_hint: "Le domaine doit être défini à \"{value}\" dans la configuration de l'agent.",
+    verify_plan_title: "bootroot verify : plan",
+    verify_summary_title: "bootroot verify : résumé",
+    verify_service_name: "- nom du service : {value}",
+    verify_agent_config: "- configuration de l'agent : {value}",
+    verify_cert_path: "- chemin du certificat : {value}",
+    verify_key_path: "- chemin de la clé : {value}",
+    verify_result_ok: "- résultat : ok",
+    verify_agent_failed: "bootroot-agent a échoué pendant la vérification",
+    verify_missing_cert: "Certificat introuvable à {value}",
+    verify_missing_key: "Clé introuvable à {value}",
+    verify_empty_cert: "Le fichier de certificat est vide à {value}",
+    verify_empty_key: "Le fichier de clé est vide à {value}",
+    verify_cert_parse_failed: "Impossible d'analyser le certificat",
+    verify_cert_missing_san: "Le certificat ne contient aucun SAN DNS",
+    verify_cert_san_mismatch: "SAN du certificat non concordants (attendus : {expected}, réels : {actual})",
+    verify_agent_config_load_failed: "Impossible de charger la configuration de l'agent à {path} : {reason}",
+    verify_ca_bundle_read_failed: "Impossible de lire le bundle de CA à {path}",
+    verify_ca_bundle_parse_failed: "Impossible d'analyser le bundle de CA à {path}",
+    verify_ca_bundle_missing_fingerprints: "Il manque des empreintes de confiance au bundle de CA à {path} : {missing}",
+    verify_cert_chain_failed: "Le certificat feuille à {cert_path} ne se chaîne pas au bundle de CA à {bundle_path} ; réémettez le certificat feuille pour qu'il corresponde à la génération actuelle de la PKI.",
+    audit_secrets_not_git_repo: "Pas dans un arbre de travail git ; rien à auditer.",
+    audit_secrets_clean: "Aucun fichier secret n'est suivi par git.",
+    audit_secrets_tracked: "AVERTISSEMENT : fichier secret suivi par git : {value}",
+    audit_secrets_hint: "Retirez-les de l'index avec `git rm --cached <path>`, ajoutez-les à .gitignore et effectuez la rotation de tout secret déjà poussé.",
+    error_audit_secrets_tracked: "{count} fichier(s) secret(s) suivi(s) par git",
+    support_bundle_written: "Bundle de support écrit dans {value}",
+    support_bundle_state_missing: "state.json introuvable à {value} ; le bundle ne le contient pas.",
+    support_bundle_command_failed: "échec de la commande : {value}",
+    version_git_sha: "commit git : {value}",
+    version_build_date: "date de compilation : {value}",
+    version_rustc: "rustc : {value}",
+    version_docker: "docker : {value}",
+    version_podman: "podman : {value}",
+    version_compose_images: "images compose ({value}) :",
+    version_unavailable: "non disponible",
+    migrate_copied: "Copié {from} -> {to}",
+    migrate_summary: "{count} secrets migrés de {from} vers {to} (chaque copie vérifiée)",
+    migrate_state_updated: "kv_mount de state.json mis à jour en {mount}",
+    migrate_state_prefix_updated: "kv_prefix de state.json mis à jour en {prefix} ; service add et rotate utilisent désormais {prefix}/services",
+    trust_export_source_kv: "Bundle de CA lu depuis le KV OpenBao ({path})",
+    trust_export_source_local: "Bundle de CA lu depuis les certificats locaux ({dir})",
+    trust_export_kv_unavailable: "Bundle de CA du KV OpenBao indisponible ({reason}) ; repli sur les certificats locaux",
+    trust_export_written: "Bundle de CA ({format}, {count} certificats) écrit dans {path}",
+    trust_export_fingerprints: "Empreintes de la CA (SHA-256) :",
+    error_trust_export_no_certs: "Le bundle de CA ne contient aucun certificat",
+    error_trust_export_der_multiple: "--format der ne contient qu'un seul certificat, mais le bundle de CA en contient {count} ; utilisez pem ou p7b",
+    trust_export_no_root_token: "aucun jeton root OpenBao",
+    trust_show_local_header: "Certificats de CA locaux ({dir}) :",
+    trust_show_kv_header: "Bundle de CA du KV OpenBao ({path}) :",
+    trust_show_kv_unavailable: "Bundle de CA du KV OpenBao : non comparé ({reason})",
+    trust_show_cert: "  {role}: {subject}",
+    trust_show_cert_sha256: "    SHA-256 : {sha256}",
+    trust_show_cert_not_after: "    expire le : {not_after}",
+    trust_show_role_root: "racine",
+    trust_show_role_intermediate: "intermédiaire",
+    trust_show_drift_none: "Dérive : aucune (les certificats locaux correspondent au bundle du KV OpenBao)",
+    trust_show_drift_detected: "Dérive : les certificats locaux diffèrent du bundle du KV OpenBao ; lancez `bootroot rotate trust-sync` pour republier",
+    migrate_confirm_delete: "Supprimer les {count} secrets source sous {location} ? [y/N] : ",
+    migrate_source_deleted: "{count} secrets source supprimés sous {location}",
+    migrate_source_kept: "Les secrets source sous {location} ont été conservés ; passez --delete-source pour les supprimer",
+    migrate_dual_write_started: "Double écriture activée : init et rotate écrivent désormais dans {current} et recopient chaque secret vers {legacy}. Lancez bootroot migrate --finalize une fois que chaque agent lit le nouvel emplacement.",
+    migrate_dual_write_finalized: "{count} secrets supprimés sous {location} ; transition en double écriture terminée",
+    migrate_references_note: "Les politiques AppRole, les modèles de l'OpenBao Agent et les configurations d'agent de service écrits avant la migration référencent encore l'ancien emplacement ; mettez-les à jour avant de vous appuyer sur le nouveau.",
+    cancellation_requested: "Interruption reçue ; fin de l'étape en cours avant de quitter (appuyez de nouveau sur Ctrl-C pour abandonner immédiatement).",
+    status_openbao_target_inferred: "Avertissement : state.json introuvable ; utilisation de l'URL OpenBao {url} (depuis {source}) et du montage KV {kv_mount}.",
+    status_openbao_target_default_source: "défaut",
+    status_summary_title: "bootroot status : résumé",
+    error_serialize_status_report_failed: "Impossible de sérialiser le rapport d'état",
+    status_section_infra: "- infrastructure :",
+    status_section_openbao: "- OpenBao :",
+    status_section_kv_paths: "- chemins KV :",
+    status_section_approles: "- AppRoles :",
+    status_section_services: "- services :",
+    status_services_none: "  - aucun enregistré",
+    status_openbao_health: "  - santé : {value}",
+    status_openbao_sealed: "  - scellé : {value}",
+    status_openbao_kv_mount: "  - montage kv ({mount}) : {value}",
+    status_kv_path_entry: "  - {path} : {value}",
+    status_approle_entry: "  - {role} : {value}",
+    status_service_delivery_mode: "  - mode de livraison de {service} : {value}",
+    status_service_cert_files: "  - {service} certificat : {cert}, clé : {key}",
+    status_value_ok: "ok",
+    status_value_unreachable: "injoignable",
+    status_value_present: "présent",
+    status_value_missing: "manquant",
+    status_value_optional_missing: "manquant (facultatif)",
+    status_value_unknown: "inconnu",
+    status_value_invalid: "invalide",
+    status_error_infra_unhealthy: "L'infrastructure n'est pas en bonne santé : {failures}",
+    status_error_openbao_unreachable: "OpenBao est injoignable",
+    status_last_secret_id_rotation: "  - dernière rotation réussie du secret_id AppRole : {value}",
+    status_warning_secret_id_rotation_stale: "AVERTISSEMENT : la dernière rotation réussie du secret_id AppRole date de {age} (seuil : {threshold}). La tâche de rotation planifiée s'est peut-être arrêtée ; enquêtez avant l'expiration des identifiants de rotation. Récupération : réémettez-les avec le jeton root (procédure d'urgence, voir le guide d'exploitation).",
+    summary_title: "bootroot init : résumé",
+    summary_openbao_url: "- URL OpenBao : {value}",
+    summary_kv_mount: "- montage KV : {value}",
+    summary_secrets_dir: "- répertoire des secrets : {value}",
+    summary_stepca_completed: "- init step-ca : terminé",
+    summary_stepca_skipped: "- init step-ca : ignoré (déjà initialisé)",
+    summary_stepca_external: "- init step-ca : ignoré (CA externe, --skip-step-ca)",
+    summary_stepca_deferred: "- init step-ca : différé (--openbao-only)",
+    init_resume_notice: "Reprise d'un init précédent : les politiques, AppRoles et secrets KV existants sont conservés ; seuls les éléments manquants sont créés.",
+    init_cancelled_openbao_credentials: "L'initialisation s'est arrêtée après l'initialisation d'OpenBao. Conservez ces valeurs pour reprendre avec bootroot init --resume --root-token <jeton> :\nJeton racine : {root_token}\nClés :\n{keys}",
+    summary_openbao_init_completed: "- init OpenBao : terminé (shares={shares}, threshold={threshold})",
+    summary_openbao_init_skipped: "- init OpenBao : ignoré (déjà initialisé)",
+    summary_root_token: "- jeton root : {value}",
+    summary_unseal_key: "- clé de descellement {index} : {value}",
+    summary_recovery_key: "- clé de récupération {index} : {value}",
+    summary_stepca_password: "- mot de passe step-ca : {value}",
+    summary_db_dsn: "- dsn de la base : {value}",
+    summary_responder_hmac: "- hmac du responder : {value}",
+    summary_eab_kid: "- kid eab : {value}",
+    summary_eab_hmac: "- hmac eab : {value}",
+    summary_eab_missing: "- eab : non configuré",
+    summary_secrets_revealed_to_file: "Secrets non masqués écrits dans {path} (mode 0600)",
+    error_reveal_to_file_failed: "Impossible d'écrire les secrets non masqués dans {path} : {reason}. Ils sont affichés ci-dessous pour ne pas être perdus :",
+    warning_init_reveal_to_file_failed: "AVERTISSEMENT : impossible d'écrire les secrets non masqués dans {path} ({reason}) ; init s'est terminé. Ils sont affichés ci-dessous pour ne pas être perdus :",
+    error_init_audit_log_open_failed: "Impossible d'ouvrir le journal d'audit d'init {path} : {reason}",
+    warning_init_audit_log_write_failed: "AVERTISSEMENT : impossible d'ajouter l'étape {step} au journal d'audit d'init {path} ({reason}) ; init continue.",
+    rotate_summary_title: "bootroot rotate : résumé",
+    rotate_summary_stepca_password: "- mot de passe step-ca mis à jour : {value}",
+    rotate_summary_restart_stepca: "- step-ca : redémarré",
+    error_service_not_recovered: "Le service {service} n'est pas redevenu sain après le redémarrage",
+    rotate_summary_db_dsn: "- ca.json mis à jour : {value}",
+    rotate_summary_responder_config: "- configuration du responder mise à jour : {value}",
+    rotate_summary_reload_responder: "- responder : SIGHUP envoyé",
+    rotate_summary_approle_secret_id: "- secret_id AppRole renouvelé pour {service_name} : {value}",
+    rotate_summary_approle_login_ok: "- connexion AppRole OK pour {service_name}",
+    rotate_summary_infra_approle_secret_id: "- secret_id AppRole d'infrastructure renouvelé pour {role_name} : {value}",
+    rotate_summary_infra_agent_restarted: "- OpenBao Agent redémarré : {container}",
+    rotate_summary_infra_approle_login_ok: "- connexion AppRole OK pour {role_name}",
+    rotate_infra_provisioned_role: "- AppRole {role_name} provisionné avec la politique {policy_name} (créé ou actualisé)",
+    rotate_infra_provisioned_role_id: "- role_id de {role_name} : {value}",
+    rotate_infra_provisioned_secret_id: "- secret_id de {role_name} : {value} (conservez-le en lieu sûr ; utilisez-le pour les futures rotations --infra via --auth-mode approle)",
+    rotate_infra_cidr_binding_kept: "- liaison CIDR enregistrée réappliquée pour {role_name} : {value} (passez --rotate-bound-cidrs pour la modifier, --clear-rotate-bound-cidrs pour la supprimer)",
+    rotate_infra_cidr_binding_cleared: "- liaison CIDR enregistrée supprimée pour {role_name} : l'identifiant opérateur et les auto-émissions suivantes ne sont plus liés",
+    error_infra_secret_id_mint_failed: "Impossible d'émettre un secret_id pour {role_name}. Les cibles d'infrastructure exigent les identifiants bootroot-infra-rotate-role (--auth-mode approle). Sur les déploiements initialisés avant l'existence de ce rôle, lancez une fois cette commande avec le jeton root pour le provisionner.",
+    error_service_secret_id_mint_failed: "Impossible d'émettre un secret_id pour le service {service_name}. Les cibles de service exigent les identifiants bootroot-runtime-rotate-role (--auth-mode approle).",
+    rotate_all_no_services: "Aucun service n'est enregistré ; rien à renouveler.",
+    rotate_all_target_failed: "- ÉCHEC de la rotation du secret_id AppRole pour {service_name} : {error}",
+    rotate_all_result: "- services renouvelés : {ok} réussis, {failed} en échec (total {total})",
+    error_rotate_all_partial_failure: "La rotation du secret_id AppRole a échoué pour {failed} service(s) sur {total} : {names}",
+    rotate_summary_self_mint: "- secret_id propre de {role_name} réémis (num_uses={num_uses}) : {value}",
+    rotate_summary_self_mint_login_ok: "- vérification de connexion après auto-émission OK pour {role_name}",
+    warning_self_mint_skipped_non_file: "AVERTISSEMENT : auto-émission de {role_name} ignorée : l'authentification AppRole n'était pas basée sur un fichier. L'étape d'auto-émission remplace le fichier passé via --approle-secret-id-file ; avec un secret_id fourni en ligne ou par variable d'environnement, il n'y a aucun fichier à remplacer. L'identifiant actuel expire à la fin de son TTL — passez la tâche planifiée à --approle-secret-id-file ou réémettez-le avant expiration.",
+    warning_openbao_agent_not_found: "AVERTISSEMENT : aucun conteneur OpenBao Agent trouvé pour {service} (attendu {container}). La nouvelle valeur est stockée dans OpenBao mais l'agent n'a pas été rechargé ; redémarrez-le manuellement pour prendre en compte le changement.",
+    warning_openbao_agent_not_running: "AVERTISSEMENT : le conteneur OpenBao Agent {container} est {state}. La nouvelle valeur est stockée dans OpenBao mais l'agent n'a pas été rechargé ; démarrez-le pour prendre en compte le changement.",
+    error_openbao_agent_not_reloaded: "L'OpenBao Agent {service} n'a pas été rechargé, la nouvelle valeur n'a donc pas été générée. Démarrez-le (docker compose up -d {service}) et attendez qu'il génère la nouvelle valeur avant de redémarrer les services qui la lisent.",
+    rotate_plan_title: "bootroot rotate {target} : plan (simulation ; rien n'a été modifié)",
+    rotate_plan_kv_write: "- chemin KV à écrire : {value}",
+    rotate_plan_file: "- fichier à mettre à jour : {value}",
+    rotate_plan_approle: "- AppRole pour lequel émettre un nouveau secret_id : {value}",
+    rotate_plan_recovery: "- identifiant de récupération OpenBao à régénérer : {value}",
+    rotate_plan_restart: "- conteneur ou service à redémarrer/recharger : {value}",
+    rotate_plan_empty: "- rien à modifier",
+    error_self_mint_failed: "Impossible de réémettre le secret_id propre de l'identifiant {role_name} (toutes les cibles de rotation ont réussi). L'identifiant actuel reste valide jusqu'à la fin de son TTL et la prochaine exécution retentera l'auto-émission.",
+    error_self_mint_verify_failed: "Le secret_id de {role_name} fraîchement auto-émis a échoué à la vérification de connexion ; le fichier d'identifiant existant n'est pas modifié. Vérifiez les CIDR rotate-bound enregistrés — une liaison qui exclut cet hôte bloquerait la tâche planifiée.",
+    error_rotate_bound_cidrs_requires_provisioning: "--rotate-bound-cidrs n'est pris en compte que lors de l'exécution de provisionnement avec le jeton root (--infra avec authentification root) ; relancez avec le jeton root ou retirez l'option",
+    error_clear_rotate_bound_cidrs_requires_provisioning: "--clear-rotate-bound-cidrs n'est pris en compte que lors de l'exécution de provisionnement avec le jeton root (--infra avec authentification root) ; relancez avec le jeton root ou retirez l'option",
+    error_rotate_bound_cidrs_invalid: "Valeur --rotate-bound-cidrs invalide : {value}. Utilisez la notation CIDR (par ex. \"10.0.0.5/32\", \"fd00::/64\").",
+    rotate_summary_openbao_recovery_targets: "- rotation de récupération OpenBao : {value}",
+    rotate_summary_openbao_recovery_output: "- identifiants de récupération écrits : {value}",
+    rotate_summary_openbao_recovery_approle_unchanged: "- configuration AppRole + SecretID inchangée",
+    rotate_summary_openbao_recovery_next_steps: "- étapes suivantes : stockez les nouveaux identifiants en lieu sûr, révoquez l'ancien jeton root s'il est encore valide et vérifiez la santé d'OpenBao",
+    prompt_rotate_openbao_recovery: "Effectuer la rotation des identifiants de récupération OpenBao ({value}) ? AppRole + SecretID resteront inchangés. [y/N]",
+    error_openbao_recovery_target_required: "Indiquez au moins une cible : --rotate-unseal-keys et/ou --rotate-root-token",
+    error_openbao_recovery_unseal_keys_required: "Au moins {value} clés de descellement existantes sont requises pour la rotation de la clé racine",
+    prompt_rotate_trust_sync: "Synchroniser les données de confiance de la CA vers OpenBao et mettre à jour les services ? [y/N]",
+    prompt_rotate_force_reissue: "Supprimer les certificats de {service_name} et déclencher une réémission ? [y/N]",
+    prompt_rotate_force_reissue_remote: "Écrire une demande de réémission pour le service remote-bootstrap {service_name} dans OpenBao ? L'agent distant l'appliquera à son prochain cycle fast-poll. [y/N]",
+    rotate_summary_trust_sync_global: "- confiance de la CA mise à jour : {value}",
+    rotate_summary_trust_sync_service: "- confiance du service synchronisée : {value}",
+    rotate_summary_trust_sync_generation: "- génération de confiance de la CA : {value}",
+    rotate_summary_trust_sync_current: "- confiance du service déjà à jour (ignoré) : {value}",
+    rotate_summary_force_reissue_deleted: "- {service_name} : certificat/clé supprimés ({cert_path}, {key_path})",
+    rotate_summary_force_reissue_local_signal: "- {service_name} : bootroot-agent signalé pour renouvellement",
+    rotate_summary_force_reissue_requested: "- {service_name} : réémission demandée à {requested_at}",
+    rotate_summary_force_reissue_will_apply: "- {service_name} : l'agent distant l'appliquera dans les ~30 s suivant son prochain fast-poll",
+    rotate_summary_force_reissue_completed: "- {service_name} : bootroot-agent a signalé l'achèvement à {completed_at} (latence de bout en bout : {elapsed})",
+    rotate_summary_force_reissue_wait_timeout: "- {service_name} : délai --wait dépassé après {wait_timeout} ; bootroot-agent peut encore prendre en compte la demande à son prochain sondage",
+    warning_rotation_in_progress: "AVERTISSEMENT : rotation-state.json existe, ce qui indique qu'une rotation de clé de CA est en cours.",
+    error_trust_sync_blocked_by_rotation: "trust-sync est bloqué pendant une rotation de clé de CA ; terminez ou abandonnez d'abord la rotation",
+    prompt_rotate_ca_key: "Effectuer la rotation de la clé de la CA intermédiaire ? Empreintes actuelles :\n  root:         {root_fp}\n  intermediate: {inter_fp}\n[y/N]",
+    prompt_rotate_ca_key_full: "Effectuer la rotation des clés de la CA racine ET intermédiaire ? Empreintes actuelles :\n  root:         {root_fp}\n  intermediate: {inter_fp}\nLes deux seront remplacées. [y/N]",
+    rotate_ca_key_full_checklist: "Liste de contrôle de la rotation complète des clés de CA :\n  - Êtes-vous prêt à mettre à jour les ancres de confiance de la CA racine distribuées aux clients externes ?\n  - Avez-vous prévenu les systèmes externes qui dépendent de cette CA racine ?",
+    error_rotation_state_corrupt: "rotation-state.json est corrompu ou illisible : {path} ; inspectez ou supprimez manuellement le fichier pour continuer",
+    error_rotation_mode_mismatch: "Le mode de rotation-state.json ({mode}) ne correspond pas à l'option --full ; terminez ou abandonnez d'abord la rotation existante",
+    error_parse_cert_failed: "Impossible d'analyser le certificat {path} : {reason}",
+    warning_stale_backup: "AVERTISSEMENT : des fichiers de sauvegarde (.bak) existent sans rotation-state.json ; une rotation précédente a peut-être été interrompue.",
+    warning_secrets_dir_mismatch: "AVERTISSEMENT : --secrets-dir {provided} diffère du répertoire des secrets enregistré dans state.json ({recorded}) ; rotate lira et écrira {provided}.",
+    rotate_ca_key_resuming: "Reprise de la rotation de clé de CA à la phase {phase}.",
+    rotate_ca_key_phase_backup: "[Phase 1] Sauvegarde du certificat et de la clé de la CA intermédiaire...",
+    rotate_ca_key_phase_generate: "[Phase 2] Génération d'une nouvelle paire de clés de CA intermédiaire...",
+    rotate_ca_key_phase_trust_additive: "[Phase 3] Distribution de la confiance transitoire (additive)...",
+    rotate_ca_key_phase_restart_stepca: "[Phase 4] Redémarrage de step-ca avec la nouvelle intermédiaire...",
+    rotate_ca_key_phase_reissue: "[Phase 5] Réémission des certificats de service...",
+    rotate_ca_key_phase_finalize: "[Phase 6] Finalisation de la confiance (soustractive)...",
+    rotate_ca_key_phase_cleanup: "[Phase 7] Nettoyage...",
+    rotate_ca_key_skip_migrated: "- {service_name} : déjà émis par la nouvelle intermédiaire, ignoré",
+    rotate_ca_key_reissue_remote_hint: "- {service_name} : service remote-bootstrap — lancez `bootroot-remote bootstrap` sur l'hôte du service pour réémettre",
+    rotate_ca_key_finalize_blocked: "Finalisation impossible : les services suivants ont encore des certificats émis par l'ancienne intermédiaire : {services}",
+    warning_force_finalize: "AVERTISSEMENT : finalisation forcée de la confiance avec des services non migrés. Ces services peuvent perdre la connectivité mTLS.",
+    warning_force_finalize_full: "AVERTISSEMENT : l'empreinte racine a changé et {services} restent non migrés. Le mTLS cessera immédiatement de fonctionner pour ces services.",
+    rotate_ca_key_complete: "Rotation de clé de CA terminée.\n  ancienne intermédiaire : {old_fp}\n  nouvelle intermédiaire : {new_fp}",
+    rotate_ca_key_complete_full: "Rotation de clé de CA terminée (complète).\n  ancienne racine :        {old_root_fp}\n  nouvelle racine :        {new_root_fp}\n  ancienne intermédiaire : {old_inter_fp}\n  nouvelle intermédiaire : {new_inter_fp}",
+    rotate_ca_key_phase_generate_root: "[Phase 2a] Génération d'une nouvelle paire de clés de CA racine...",
+    rotate_ca_key_current_fingerprints: "Empreintes actuelles de la CA :\n  root:         {root_fp}\n  intermediate: {inter_fp}",
+    rotate_ca_key_phase_skipped: "Phase {phase} déjà terminée, ignorée.",
+    summary_responder_check_ok: "- vérification du responder : ok",
+    summary_responder_check_skipped: "- vérification du responder : ignorée",
+    summary_db_check_ok: "- vérification de la base : ok",
+    summary_db_check_skipped: "- vérification de la base : ignorée",
+    summary_db_host_resolution: "- résolution de l'hôte de la base : {from} -> {to}",
+    summary_kv_paths: "- chemins KV OpenBao :",
+    summary_approles: "- AppRoles :",
+    summary_role_id: "    role_id: {value}",
+    summary_secret_id: "    secret_id: {value}",
+    summary_next_steps: "étapes suivantes :",
+    next_steps_configure_templates: "  - Configurez les modèles de l'OpenBao Agent pour step-ca, le responder et bootroot-agent (`bootroot init --print-agent-config-template` les affiche).",
+    next_steps_responder_template: "  - Modèle du responder : {value}",
+    next_steps_responder_config: "  - Sortie de la configuration du responder : {value}",
+    next_steps_responder_url: "  - URL d'administration du responder : {value}",
+    next_steps_openbao_agent_stepca_config: "  - OpenBao Agent (step-ca) : {value}",
+    next_steps_openbao_agent_responder_config: "  - OpenBao Agent (responder) : {value}",
+    next_steps_openbao_agent_override: "  - Override compose de l'OpenBao Agent : {value}",
+    next_steps_reload_services: "  - Démarrez ou rechargez step-ca et le responder pour qu'ils utilisent les secrets générés.",
+    next_steps_run_status: "  - Lancez `bootroot status` pour vérifier les services.",
+    next_steps_finish_openbao_only: "  - Lancez `bootroot init --resume` pour initialiser step-ca, écrire les fichiers du responder et de l'OpenBao Agent, et publier la confiance de la CA.",
+    next_steps_eab_hint: "  - Si votre CA ACME exige l'EAB, provisionnez kid/hmac en externe (le step-ca OSS fourni ne prend pas en charge l'EAB), puis stockez-les à {path} ou relancez avec --eab-kid/--eab-hmac.",
+    infra_install_completed: "bootroot infra install : terminé",
+    infra_install_env_written: "bootroot infra install : .env écrit",
+    infra_install_dirs_created: "bootroot infra install : répertoires créés",
+    error_infra_install_failed: "Échec de bootroot infra install",
+    error_infra_down_failed: "Échec de bootroot infra down",
+    error_infra_restart_failed: "Échec de bootroot infra restart",
+    error_infra_logs_failed: "Échec de bootroot infra logs",
+    error_restart_policy_invalid: "--restart-policy '{value}' invalide : valeurs attendues no, always, unless-stopped, on-failure, on-failure:N (N est un nombre de tentatives)",
+    error_service_timeout_invalid: "--service-timeout '{value}' invalide : format attendu <service>=<secondes>, par ex. step-ca=180",
+    warning_infra_pull_retry: "{runtime} compose pull a échoué (nouvelle tentative {attempt}/{retries} dans {delay}) : {reason}",
+    infra_openbao_auto_unseal_skipped: "OpenBao utilise un sceau à descellement automatique (enregistré par init --auto-unseal) ; étape de descellement ignorée",
+    clean_completed: "bootroot clean : terminé",
+    clean_confirm: "Cela supprimera les conteneurs, volumes, secrets, state.json et .env. Continuer ? [y/N] : ",
+    clean_confirm_certs: "Supprimer aussi le répertoire certs/ ? [y/N] : ",
+    clean_confirm_openbao_only: "Supprimer le conteneur bootroot-openbao et son volume ? [y/N] : ",
+    clean_openbao_only_completed: "conteneur et volume openbao supprimés ; les autres services continuent de tourner",
+    error_init_partial_openbao_state: "OpenBao à {url} est déjà initialisé mais aucun jeton root n'est disponible.\n  Options de récupération :\n    1. Relancez avec --root-token <token> (ou OPENBAO_ROOT_TOKEN=...) en utilisant le jeton sauvegardé lors de l'init précédent.\n    2. `bootroot clean --openbao-only` si cette instance OpenBao est dédiée à bootroot et peut être effacée.\n    3. Intervention manuelle d'un opérateur OpenBao si l'instance est partagée.",
+    error_clean_failed: "Échec de bootroot clean",
+    error_env_parse_failed: "Impossible d'analyser le fichier .env : {value}",
+    prompt_save_unseal_keys: "Enregistrer les clés de descellement dans un fichier pour le descellement automatique ? [y/N] : ",
+    openbao_unseal_keys_saved: "Clés de descellement enregistrées dans {value}",
+    openbao_unseal_keys_not_saved_warning: "AVERTISSEMENT : les clés de descellement n'ont PAS été enregistrées dans un fichier. Copiez-les et conservez-les en lieu sûr — elles ne seront plus affichées.",
+    openbao_unseal_keys_deleted: "Fichier des clés de descellement supprimé : {value}",
+    error_openbao_save_unseal_keys_failed: "Impossible d'enregistrer les clés de descellement",
+    error_openbao_delete_unseal_keys_failed: "Impossible de supprimer le fichier des clés de descellement",
+    error_remove_dir_failed: "Impossible de supprimer le répertoire : {value}",
+    infra_install_stepca_not_checked: "- step-ca : non vérifié (sera initialisé par init)",
+    dns_alias_registered: "bootroot service add : alias DNS HTTP-01 {value} enregistré",
+    dns_alias_replaying: "bootroot infra up : réapplication de {value} alias DNS sur bootroot-http01",
+    dns_alias_responder_not_running: "AVERTISSEMENT : le conteneur bootroot-http01 ne tourne pas — enregistrement de l'alias DNS ignoré. Lancez d'abord `bootroot infra up`.",
+    dns_alias_connect_failed: "Impossible de reconnecter bootroot-http01 avec les alias DNS",
+    dns_alias_connect_rollback: "Échec de la reconnexion ; retour arrière pour rétablir la connectivité réseau de bootroot-http01",
+    dns_alias_connect_recovered: "AVERTISSEMENT : l'enregistrement de l'alias DNS a échoué mais la connectivité réseau a été rétablie. Lancez `bootroot infra up` pour appliquer les alias plus tard. ({error})",
+    dns_alias_rollback_failed: "CRITIQUE : le retour arrière a aussi échoué — bootroot-http01 est détaché du réseau {network}. Reconnectez-le manuellement : docker network connect {network} bootroot-http01 ({error})",
+    dns_alias_network_not_found: "Aucun réseau Docker trouvé pour le conteneur {value}",
+    error_service_update_failed: "Échec de bootroot service update",
+    error_service_update_no_flags: "Aucune option de mise à jour indiquée ; passez --secret-id-ttl, --secret-id-wrap-ttl, --no-wrap, --rn-cidrs, --cert-group, --reload-style ou --post-renew-command",
+    service_update_summary: "bootroot service update : résumé",
+    service_update_field_changed: "- {field} : {old} -> {new}",
+    service_update_rotate_hint: "Lancez `bootroot rotate approle-secret-id` pour que la nouvelle politique prenne effet.",
+    hint_secret_id_ttl_rotation_cadence: "REMARQUE : assurez-vous que le TTL du secret_id vaut au moins 2× votre intervalle de rotation. Par exemple, avec une rotation toutes les 12h, utilisez un TTL de 24h ou plus. Cette marge couvre les exécutions manquées, les fenêtres de maintenance et la reprise après redémarrage.",
+    service_info_secret_id_ttl: "- TTL du secret_id : {value}",
+    service_info_secret_id_wrap_ttl: "- TTL d'encapsulation du secret_id : {value}",
+    service_info_token_bound_cidrs: "- token_bound_cidrs : {value}",
+    service_info_sync_generation: "- génération de synchronisation distante : {value}",
+    service_info_sync_generation_none: "- génération de synchronisation distante : pas encore publiée",
+    policy_label_inherit: "hérité",
+    policy_label_disabled: "désactivé",
+    policy_label_default_wrap_ttl: "{value} (par défaut)",
+    service_update_no_changes: "Aucun champ modifié.",
+    service_update_hook_value_none: "(aucun)",
+    service_remove_plan_header: "bootroot service remove : plan de démantèlement pour {value}",
+    service_remove_plan_approle: "- AppRole à supprimer : {value}",
+    service_remove_plan_policy: "- politique à supprimer : {value}",
+    service_remove_plan_kv: "- chemin KV à supprimer : {value}",
+    service_remove_plan_artifact: "- artefact sur disque à supprimer : {value}",
+    service_remove_plan_agent_config: "- bloc de profil géré à retirer de : {value}",
+    service_remove_plan_artifacts_preserved: "- certificat/clé/configuration d'agent sur disque conservés (passez --delete-artifacts pour les supprimer aussi)",
+    service_remove_dry_run: "Simulation : aucune modification de state.json ni d'OpenBao.",
+    service_remove_confirm_prompt: "Supprimer le service {value} ? Cela désenregistre son AppRole, sa politique et ses données KV",
+    service_remove_requires_yes: "Refus de supprimer {value} sans confirmation sur un stdin non interactif ; passez --yes (alias --force) pour continuer",
+    service_remove_aborted: "Abandonné ; {value} n'a pas été supprimé.",
+    service_remove_resource_removed: "supprimé : {value}",
+    service_remove_resource_absent: "déjà absent : {value}",
+    service_remove_resource_failed: "ÉCHEC de la suppression de {value} : {error}",
+    service_remove_success: "Service {value} supprimé. Ajoutez-le de nouveau avec `bootroot service add ...` pour changer son mode de livraison.",
+    service_remove_partial_failure: "Démantèlement incomplet pour {value} ; l'entrée de state.json a été conservée pour qu'une nouvelle exécution de `bootroot service remove --service-name {value}` termine les suppressions restantes.",
+    hint_consumer_reload_required: "Rechargement/redémarrage du consommateur requis (état du hook post-renouvellement) :",
+    hint_consumer_reload_service_with_hook: "- {service_name} : hook post-renouvellement configuré ({hook})",
+    hint_consumer_reload_service_without_hook: "- {service_name} : AUCUN hook post-renouvellement configuré ; le consommateur peut continuer à servir l'ancien certificat via un descripteur de fichier ouvert (issue #614).",
+    hint_consumer_reload_remediation: "Installez un hook sur place sans ré-enrôlement : `bootroot service update --service-name <name> --reload-style {sighup|systemd|docker-restart} --reload-target <process|unit|container>` (ou utilisez la forme bas niveau `--post-renew-command`).",
+    hint_reinit_reload_style: "En relançant `bootroot service add ...`, passez `--reload-style sighup|systemd|docker-restart --reload-target <target>` pour qu'un hook post-renouvellement soit enregistré d'emblée — sinon les consommateurs peuvent continuer à servir l'ancien certificat via un descripteur de fichier ouvert après les rotations suivantes (issue #614).",
+    error_openbao_bind_wildcard_required: "Lier OpenBao à une adresse générique (0.0.0.0 ou [::]) exige --openbao-bind-wildcard pour confirmer l'intention",
+    error_openbao_bind_tls_flag_required: "Une liaison OpenBao non-loopback exige --openbao-tls-required pour reconnaître que le TLS est obligatoire",
+    error_openbao_bind_tls_missing: "Une liaison OpenBao non-loopback exige le TLS : {details}",
+    error_openbao_bind_invalid_format: "Valeur --openbao-bind invalide : format attendu <IP>:<port> (par ex. 192.168.1.10:8200)",
+    error_openbao_bind_ipv6_requires_brackets: "Les adresses IPv6 dans --openbao-bind doivent être entre crochets (par ex. [::1]:8200, [2001:db8::10]:8200)",
+    error_openbao_advertise_addr_required: "Une liaison OpenBao générique (0.0.0.0 ou [::]) exige --openbao-advertise-addr <IP:port> pour que les artefacts de bootstrap distant contiennent une adresse joignable",
+    error_openbao_advertise_addr_invalid: "Valeur --openbao-advertise-addr invalide : format attendu <IP>:<port> (par ex. 192.168.1.10:8200)",
+    error_openbao_advertise_addr_ipv6_requires_brackets: "Les adresses IPv6 dans --openbao-advertise-addr doivent être entre crochets (par ex. [fd12::1]:8200, [2001:db8::10]:8200)",
+    error_openbao_advertise_addr_not_reachable: "--openbao-advertise-addr doit être une adresse routable précise (ni générique ni loopback)",
+    error_openbao_advertise_addr_specific_bind_rejected: "--openbao-advertise-addr n'est valide qu'avec des liaisons génériques (0.0.0.0 ou [::]) ; pour une liaison à une IP précise, l'adresse de liaison est utilisée directement",
+    error_openbao_override_file_missing: "Une intention de liaison OpenBao non-loopback est enregistrée dans l'état mais le fichier d'override compose est absent ; relancez infra install --openbao-bind pour le régénérer",
+    error_openbao_override_binding_mismatch: "Le mappage de port de l'override compose d'OpenBao ne correspond pas à l'intention de liaison enregistrée : attendu {expected}, trouvé {actual} ; relancez infra install --openbao-bind pour le régénérer",
+    info_openbao_bind_intent_recorded: "Intention de liaison OpenBao non-loopback enregistrée : {addr}",
+    info_openbao_bind_intent_cleared: "Intention de liaison OpenBao non-loopback précédente effacée (réinstallation sans --openbao-bind)",
+    error_http01_admin_bind_requires_responder: "--http01-admin-bind exige le service bootroot-http01 dans le fichier compose",
+    error_http01_admin_bind_invalid_format: "--http01-admin-bind doit être une adresse <IP>:<port> valide",
+    error_http01_admin_bind_wildcard_required: "--http01-admin-bind-wildcard est requis pour une liaison à 0.0.0.0 ou [::]",
+    error_http01_admin_bind_tls_flag_required: "--http01-admin-tls-required est requis lorsque --http01-admin-bind indique une adresse non-loopback",
+    error_http01_admin_bind_ipv6_requires_brackets: "Les adresses IPv6 de --http01-admin-bind doivent être entre crochets, par ex. [::1]:8080",
+    error_http01_admin_advertise_addr_required: "Une liaison d'administration HTTP-01 générique (0.0.0.0 ou [::]) exige --http01-admin-advertise-addr <IP:port> pour que le certificat TLS contienne un SAN routable",
+    error_http01_admin_advertise_addr_invalid: "Valeur --http01-admin-advertise-addr invalide : format attendu <IP>:<port> (par ex. 192.168.1.10:8080)",
+    error_http01_admin_advertise_addr_ipv6_requires_brackets: "Les adresses IPv6 dans --http01-admin-advertise-addr doivent être entre crochets (par ex. [fd12::1]:8080, [2001:db8::10]:8080)",
+    error_http01_admin_advertise_addr_not_reachable: "--http01-admin-advertise-addr doit être une adresse routable précise (ni générique ni loopback)",
+    error_http01_admin_advertise_addr_specific_bind_rejected: "--http01-admin-advertise-addr n'est valide qu'avec des liaisons génériques (0.0.0.0 ou [::]) ; pour une liaison à une IP précise, l'adresse de liaison est utilisée directement",
+    info_http01_admin_bind_intent_recorded: "Intention de liaison non-loopback de l'administration HTTP-01 enregistrée : {addr}",
+    info_http01_admin_bind_intent_cleared: "Intention de liaison non-loopback de l'administration HTTP-01 précédente effacée (réinstallation sans --http01-admin-bind)",
+    error_http01_admin_override_file_missing: "Une intention de liaison non-loopback de l'administration HTTP-01 est enregistrée dans l'état mais le fichier d'override compose est absent ; relancez infra install --http01-admin-bind pour le régénérer",
+    error_http01_admin_override_binding_mismatch: "Le mappage de port de l'override compose de l'administration HTTP-01 ne correspond pas à l'intention de liaison enregistrée : attendu {expected}, trouvé {actual} ; relancez infra install --http01-admin-bind pour le régénérer",
+    error_http01_admin_bind_tls_missing: "L'API d'administration HTTP-01 exige le TLS pour une liaison non-loopback mais les prérequis TLS ne sont pas remplis : {details}",
+    error_stepca_bind_requires_stepca: "--stepca-bind exige le service step-ca dans le fichier compose",
+    error_stepca_bind_invalid_format: "--stepca-bind doit être une adresse <IP>:<port> valide",
+    error_stepca_bind_wildcard_required: "--stepca-bind-wildcard est requis pour une liaison à 0.0.0.0 ou [::]",
+    error_stepca_bind_ipv6_requires_brackets: "Les adresses IPv6 de --stepca-bind doivent être entre crochets, par ex. [::1]:9000",
+    error_stepca_advertise_addr_required: "Une liaison step-ca générique (0.0.0.0 ou [::]) exige --stepca-advertise-addr <IP:port> pour que les nœuds distants disposent d'une adresse d'annuaire ACME routable",
+    error_stepca_advertise_addr_invalid: "Valeur --stepca-advertise-addr invalide : format attendu <IP>:<port> (par ex. 192.168.1.10:9000)",
+    error_stepca_advertise_addr_ipv6_requires_brackets: "Les adresses IPv6 dans --stepca-advertise-addr doivent être entre crochets (par ex. [fd12::1]:9000, [2001:db8::10]:9000)",
+    error_stepca_advertise_addr_not_reachable: "--stepca-advertise-addr doit être une adresse routable précise (ni générique ni loopback)",
+    error_stepca_advertise_addr_specific_bind_rejected: "--stepca-advertise-addr n'est valide qu'avec des liaisons génériques (0.0.0.0 ou [::]) ; pour une liaison à une IP précise, l'adresse de liaison est utilisée directement",
+    info_stepca_bind_intent_recorded: "Intention de liaison step-ca non-loopback enregistrée : {addr}",
+    info_stepca_bind_intent_cleared: "Intention de liaison step-ca non-loopback précédente effacée (réinstallation sans --stepca-bind)",
+    error_stepca_override_file_missing: "Une intention de liaison step-ca non-loopback est enregistrée dans l'état mais le fichier d'override compose est absent ; relancez infra install --stepca-bind pour le régénérer",
+    error_stepca_override_binding_mismatch: "Le mappage de port de l'override compose de step-ca ne correspond pas à l'intention de liaison enregistrée : attendu {expected}, trouvé {actual} ; relancez infra install --stepca-bind pour le régénérer",
+    info_openbao_tls_provisioned: "Certificat serveur TLS d'OpenBao émis : {path}",
+    info_openbao_hcl_tls_written: "HCL d'OpenBao mis à jour avec la configuration TLS",
+    info_openbao_hcl_tls_reverted: "HCL d'OpenBao restauré en clair (TLS désactivé)",
+    error_openbao_tls_provision_failed: "Impossible d'émettre le certificat serveur TLS d'OpenBao",
+    error_openbao_hcl_write_failed: "Impossible d'écrire la configuration HCL d'OpenBao",
+    info_http01_admin_tls_reverted: "Configuration du responder restaurée en clair (TLS désactivé jusqu'au prochain init)",
+    info_http01_admin_tls_provisioned: "Certificat serveur TLS de l'API d'administration HTTP-01 émis : {path}",
+    error_http01_admin_tls_provision_failed: "Impossible d'émettre le certificat serveur TLS de l'API d'administration HTTP-01",
+    info_infra_tls_renewed: "Certificat d'infrastructure renouvelé : {name}",
+    info_infra_tls_reload: "Rechargement du service après renouvellement du certificat : {strategy}",
+    error_infra_tls_renew_failed: "Impossible de renouveler le certificat d'infrastructure : {name}",
+    prompt_rotate_infra_tls: "Renouveler les certificats d'infrastructure ? [y/N] : ",
+    rotate_infra_tls_no_entries: "Aucun certificat d'infrastructure enregistré dans l'état",
+    reinit_plan_title: "bootroot reinit : plan de récupération",
+    reinit_plan_destructive_actions: "Actions destructrices :",
+    reinit_plan_destructive_container: "  - arrêter et supprimer le conteneur bootroot-openbao",
+    reinit_plan_destructive_volumes: "  - supprimer les volumes openbao-data et openbao-audit (l'état d'OpenBao sera effacé)",
+    reinit_plan_destructive_state_file: "  - réécrire state.json avec uniquement les champs d'intention (services / approles / politiques effacés)",
+    reinit_plan_destructive_runtime_files: "  - supprimer {secrets_dir}/openbao/unseal-keys.txt et les fichiers d'exécution openbao-agent propres à chaque service",
+    reinit_plan_destructive_service_creds: "  - supprimer les fichiers d'identifiants AppRole obsolètes propres à chaque service (role_id, secret_id) sous {secrets_dir}/services/",
+    reinit_plan_preserved_actions: "Conservé :",
+    reinit_plan_preserved_ca: "  - {secrets_dir}/config/ca.json et le matériel de clés des CA racine/intermédiaire de step-ca",
+    reinit_plan_preserved_password: "  - {secrets_dir}/password.txt (le mot de passe step-ca n'est PAS régénéré)",
+    reinit_plan_preserved_postgres: "  - le conteneur PostgreSQL, son volume et l'état de la base step-ca",
+    reinit_plan_preserved_compose_overrides: "  - les overrides compose OpenBao rédigés par l'opérateur sous {secrets_dir}/openbao/",
+    reinit_plan_preserved_intent: "  - l'intention de liaison non-loopback OpenBao / administration HTTP-01 / step-ca enregistrée dans state.json",
+    reinit_plan_preserved_intent_section: "Intention de déploiement conservée (instantané de state.json) :",
+    reinit_plan_preserved_intent_secrets_dir: "  - secrets_dir: {value}",
+    reinit_plan_preserved_intent_openbao_bind: "  - openbao_bind_addr: {value}",
+    reinit_plan_preserved_intent_openbao_advertise: "  - openbao_advertise_addr: {value}",
+    reinit_plan_preserved_intent_http01_bind: "  - http01_admin_bind_addr: {value}",
+    reinit_plan_preserved_intent_http01_advertise: "  - http01_admin_advertise_addr: {value}",
+    reinit_plan_preserved_intent_stepca_bind: "  - stepca_bind_addr: {value}",
+    reinit_plan_preserved_intent_stepca_advertise: "  - stepca_advertise_addr: {value}",
+    reinit_plan_preserved_intent_infra_certs: "  - infra_certs : {count} entrée(s) conservée(s)",
+    reinit_plan_preserved_intent_none: "  - (aucun instantané state.json antérieur — exécution sur une arborescence vierge/clonée par rsync)",
+    reinit_plan_service_registry_warning: "Une fois reinit terminé, le registre des services sera vide ; relancez `bootroot service add` pour chaque service précédemment enregistré.",
+    reinit_confirm: "Procéder au reinit ? [y/N] : ",
+    reinit_completed: "bootroot reinit : terminé",
+    reinit_service_registry_post_summary: "Le registre des services est vide après reinit. Relancez `bootroot service add ...` pour chaque service concerné.",
+    error_reinit_failed: "Échec de bootroot reinit",
+    error_reinit_external_openbao: "Refus du reinit : le fichier compose {path} ne déclare pas de service `openbao`. `bootroot reinit` ne fonctionne qu'avec un OpenBao local géré par compose. Un OpenBao externe ou partagé doit suivre un runbook géré par l'opérateur.",
+    error_reinit_container_project_mismatch: "Refus du reinit : le conteneur `bootroot-openbao` appartient au projet compose `{actual}`, mais le répertoire de travail actuel correspond au projet `{expected}`. Refus d'effacer un OpenBao qui n'est pas géré par ce répertoire de travail.",
+    error_reinit_container_missing_compose_label: "Refus du reinit : le conteneur `bootroot-openbao` existe mais ne porte pas l'étiquette compose `{label}`. `bootroot reinit` ne fonctionne qu'avec un OpenBao local géré par compose ; on ne peut pas prouver qu'un conteneur sans étiquettes compose appartient au projet compose de ce répertoire de travail. Si ce conteneur est obsolète, supprimez-le manuellement avant de réessayer.",
+    error_reinit_root_token_output_unsafe: "Refus d'écrire le jeton root : le fichier existant {path} est lisible par le groupe ou par tous. Lancez `chmod 0600 {path}` ou supprimez le fichier avant de réessayer.",
+    error_reinit_root_token_output_not_file: "Refus d'écrire le jeton root : {path} existe et n'est pas un fichier ordinaire. Passez un fichier ordinaire comme destination de `--root-token-output`.",
+    error_reinit_root_token_output_unwritable: "Refus de démarrer le reinit : impossible d'écrire dans la destination `--root-token-output` {path} ({reason}). Passez un chemin de fichier accessible en écriture avant de réessayer.",
+    error_reinit_root_token_persist_failed: "AVERTISSEMENT : impossible d'enregistrer le jeton root OpenBao fraîchement émis dans {path} ({reason}). OpenBao a été initialisé ; le jeton ci-dessous est affiché en clair pour ne pas être perdu — récupérez-le maintenant et conservez-le en lieu sûr.\nROOT_TOKEN={token}",
+    error_reinit_summary_json_not_file: "Refus de démarrer le reinit : la destination --summary-json {path} existe et n'est pas un fichier ordinaire. Passez un chemin de fichier ordinaire avant de réessayer.",
+    error_reinit_summary_json_unwritable: "Refus de démarrer le reinit : impossible d'écrire dans la destination `--summary-json` {path} ({reason}). Le JSON de résumé contient le jeton root et les clés de descellement fraîchement émis ; une destination non inscriptible recréerait le piège de l'init partiel. Passez un chemin de fichier accessible en écriture avant de réessayer.",
+    error_reinit_summary_json_unsafe: "Refus de démarrer le reinit : la destination `--summary-json` existante {path} est lisible par le groupe ou par tous. Le JSON de résumé contient le jeton root et les clés de descellement fraîchement émis ; écrire dans un fichier aux permissions plus larges que 0600 exposerait brièvement ces secrets. Lancez `chmod 0600 {path}` ou supprimez le fichier avant de réessayer.",
+    error_reinit_explicit_openbao_url: "Refus de démarrer le reinit : un `--openbao-url {url}` explicite n'est pas accepté car `bootroot reinit` ne fonctionne qu'avec un OpenBao local géré par compose. L'URL cible du reinit est dérivée du service géré par compose (et de la liaison non-loopback enregistrée, le cas échéant). Relancez sans `--openbao-url` ; si une récupération d'un OpenBao externe/partagé est nécessaire, suivez plutôt le runbook de l'opérateur.",
+    error_reinit_stepca_password_missing_with_ca_material: "Refus de démarrer le reinit : `{password_path}` est absent mais au moins un fichier écrit par `step ca init` est encore conservé sous `{secrets_dir}` : {preserved_paths}. Les *clés* de CA conservées (`secrets/root_ca_key`, `secrets/intermediate_ca_key`) sont chiffrées avec le mot de passe d'origine ; en générer un nouveau en `reinit_mode` produirait un déploiement dont le `password.txt` ne peut pas les déverrouiller — tout `step certificate create --ca-password-file /home/step/password.txt` ultérieur échouerait. Tout autre fichier conservé écrit par `step ca init` (`config/ca.json`, `config/defaults.json`, `certs/root_ca.crt`, `certs/intermediate_ca.crt`) est tout aussi bloquant même sans matériel de clé chiffré, car le `step ca init` de la seconde passe d'init ne peut pas se terminer proprement lorsqu'une de ses cibles existe déjà (il génère de nouveaux fichiers de certificat/clé puis se termine en erreur sur la confirmation d'écrasement liée au TTY), ce qui recrée le piège de l'init partiel après l'effacement d'`OpenBao`. Restaurez `password.txt` depuis une sauvegarde avant de réessayer ; si le mot de passe d'origine est irrécupérable, supprimez tous les artefacts step-ca conservés listés ci-dessus pour que reinit puisse générer une nouvelle CA de zéro.",
+};
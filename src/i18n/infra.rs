@@ -5,6 +5,18 @@ impl Messages {
         self.strings().infra_up_completed
     }
 
+    pub(crate) fn infra_down_completed(&self) -> &'static str {
+        self.strings().infra_down_completed
+    }
+
+    pub(crate) fn infra_restart_completed(&self) -> &'static str {
+        self.strings().infra_restart_completed
+    }
+
+    pub(crate) fn prompt_infra_down_volumes(&self) -> &'static str {
+        self.strings().prompt_infra_down_volumes
+    }
+
     pub(crate) fn infra_readiness_summary(&self) -> &'static str {
         self.strings().infra_readiness_summary
     }
@@ -143,6 +155,56 @@ impl Messages {
         self.strings().error_infra_install_failed
     }
 
+    pub(crate) fn error_infra_down_failed(&self) -> &'static str {
+        self.strings().error_infra_down_failed
+    }
+
+    pub(crate) fn error_infra_restart_failed(&self) -> &'static str {
+        self.strings().error_infra_restart_failed
+    }
+
+    pub(crate) fn error_infra_logs_failed(&self) -> &'static str {
+        self.strings().error_infra_logs_failed
+    }
+
+    pub(crate) fn error_restart_policy_invalid(&self, value: &str) -> String {
+        format_template(
+            self.strings().error_restart_policy_invalid,
+            &[("value", value)],
+        )
+    }
+
+    pub(crate) fn error_service_timeout_invalid(&self, value: &str) -> String {
+        format_template(
+            self.strings().error_service_timeout_invalid,
+            &[("value", value)],
+        )
+    }
+
+    pub(crate) fn warning_infra_pull_retry(
+        &self,
+        runtime: &str,
+        attempt: &str,
+        retries: &str,
+        delay: &str,
+        reason: &str,
+    ) -> String {
+        format_template(
+            self.strings().warning_infra_pull_retry,
+            &[
+                ("runtime", runtime),
+                ("attempt", attempt),
+                ("retries", retries),
+                ("delay", delay),
+                ("reason", reason),
+            ],
+        )
+    }
+
+    pub(crate) fn infra_openbao_auto_unseal_skipped(&self) -> &'static str {
+        self.strings().infra_openbao_auto_unseal_skipped
+    }
+
     pub(crate) fn clean_completed(&self) -> &'static str {
         self.strings().clean_completed
     }
@@ -8,6 +8,56 @@ impl Messages {
         )
     }
 
+    pub(crate) fn error_compose_missing_service(&self, path: &str, service: &str) -> String {
+        format_template(
+            self.strings().error_compose_missing_service,
+            &[("path", path), ("service", service)],
+        )
+    }
+
+    pub(crate) fn error_init_compose_missing_service(&self, path: &str, service: &str) -> String {
+        format_template(
+            self.strings().error_init_compose_missing_service,
+            &[("path", path), ("service", service)],
+        )
+    }
+
+    pub(crate) fn error_compose_file_not_found(&self, path: &str, candidates: &str) -> String {
+        format_template(
+            self.strings().error_compose_file_not_found,
+            &[("path", path), ("candidates", candidates)],
+        )
+    }
+
+    pub(crate) fn error_openbao_agent_exec_invalid(&self, value: &str) -> String {
+        format_template(
+            self.strings().error_openbao_agent_exec_invalid,
+            &[("value", value)],
+        )
+    }
+
+    pub(crate) fn error_ca_json_outside_secrets_dir(
+        &self,
+        path: &str,
+        secrets_dir: &str,
+    ) -> String {
+        format_template(
+            self.strings().error_ca_json_outside_secrets_dir,
+            &[("path", path), ("secrets_dir", secrets_dir)],
+        )
+    }
+
+    pub(crate) fn error_resolve_ca_json_failed(&self, path: &str) -> String {
+        format_template(
+            self.strings().error_resolve_ca_json_failed,
+            &[("path", path)],
+        )
+    }
+
+    pub(crate) fn init_agent_template_header(&self, path: &str) -> String {
+        format_template(self.strings().init_agent_template_header, &[("path", path)])
+    }
+
     pub(crate) fn init_failed_rollback(&self) -> &'static str {
         self.strings().init_failed_rollback
     }
@@ -36,6 +86,18 @@ impl Messages {
         self.strings().warning_db_password_rotation_skipped
     }
 
+    pub(crate) fn warning_openbao_agent_render_missing(
+        &self,
+        service: &str,
+        path: &str,
+        seconds: &str,
+    ) -> String {
+        format_template(
+            self.strings().warning_openbao_agent_render_missing,
+            &[("service", service), ("path", path), ("seconds", seconds)],
+        )
+    }
+
     pub(crate) fn warning_secret_id_ttl_exceeds_recommended(
         &self,
         value: &str,
@@ -73,6 +135,17 @@ impl Messages {
         self.strings().error_rn_cidrs_clear_on_add
     }
 
+    pub(crate) fn error_approle_bind_cidr_invalid(&self, value: &str) -> String {
+        format_template(
+            self.strings().error_approle_bind_cidr_invalid,
+            &[("value", value)],
+        )
+    }
+
+    pub(crate) fn error_serialize_init_plan_failed(&self) -> &'static str {
+        self.strings().error_serialize_init_plan_failed
+    }
+
     pub(crate) fn prompt_openbao_unseal_from_file_confirm(&self, value: &str) -> String {
         format_template(
             self.strings().prompt_openbao_unseal_from_file_confirm,
@@ -136,6 +209,14 @@ impl Messages {
         self.strings().error_eab_requires_both
     }
 
+    pub(crate) fn error_skip_step_ca_openbao_tls(&self) -> &'static str {
+        self.strings().error_skip_step_ca_openbao_tls
+    }
+
+    pub(crate) fn error_current_dir_failed(&self) -> &'static str {
+        self.strings().error_current_dir_failed
+    }
+
     pub(crate) fn error_openbao_sealed(&self) -> &'static str {
         self.strings().error_openbao_sealed
     }
@@ -169,6 +250,13 @@ impl Messages {
         )
     }
 
+    pub(crate) fn error_postgres_allowed_cidr_invalid(&self, value: &str) -> String {
+        format_template(
+            self.strings().error_postgres_allowed_cidr_invalid,
+            &[("value", value)],
+        )
+    }
+
     pub(crate) fn error_db_check_failed(&self) -> &'static str {
         self.strings().error_db_check_failed
     }
@@ -496,6 +584,29 @@ impl Messages {
         self.strings().summary_stepca_skipped
     }
 
+    pub(crate) fn summary_stepca_external(&self) -> &'static str {
+        self.strings().summary_stepca_external
+    }
+
+    pub(crate) fn summary_stepca_deferred(&self) -> &'static str {
+        self.strings().summary_stepca_deferred
+    }
+
+    pub(crate) fn init_resume_notice(&self) -> &'static str {
+        self.strings().init_resume_notice
+    }
+
+    pub(crate) fn init_cancelled_openbao_credentials(
+        &self,
+        root_token: &str,
+        keys: &str,
+    ) -> String {
+        format_template(
+            self.strings().init_cancelled_openbao_credentials,
+            &[("root_token", root_token), ("keys", keys)],
+        )
+    }
+
     pub(crate) fn summary_openbao_init_completed(&self, shares: u8, threshold: u8) -> String {
         let shares_value = shares.to_string();
         let threshold_value = threshold.to_string();
@@ -521,6 +632,14 @@ impl Messages {
         )
     }
 
+    pub(crate) fn summary_recovery_key(&self, index: usize, value: &str) -> String {
+        let index_value = index.to_string();
+        format_template(
+            self.strings().summary_recovery_key,
+            &[("index", &index_value), ("value", value)],
+        )
+    }
+
     pub(crate) fn summary_stepca_password(&self, value: &str) -> String {
         format_template(self.strings().summary_stepca_password, &[("value", value)])
     }
@@ -544,6 +663,46 @@ impl Messages {
     pub(crate) fn summary_eab_missing(&self) -> &'static str {
         self.strings().summary_eab_missing
     }
+
+    pub(crate) fn summary_secrets_revealed_to_file(&self, path: &str) -> String {
+        format_template(
+            self.strings().summary_secrets_revealed_to_file,
+            &[("path", path)],
+        )
+    }
+
+    pub(crate) fn error_reveal_to_file_failed(&self, path: &str, reason: &str) -> String {
+        format_template(
+            self.strings().error_reveal_to_file_failed,
+            &[("path", path), ("reason", reason)],
+        )
+    }
+
+    pub(crate) fn warning_init_reveal_to_file_failed(&self, path: &str, reason: &str) -> String {
+        format_template(
+            self.strings().warning_init_reveal_to_file_failed,
+            &[("path", path), ("reason", reason)],
+        )
+    }
+
+    pub(crate) fn error_init_audit_log_open_failed(&self, path: &str, reason: &str) -> String {
+        format_template(
+            self.strings().error_init_audit_log_open_failed,
+            &[("path", path), ("reason", reason)],
+        )
+    }
+
+    pub(crate) fn warning_init_audit_log_write_failed(
+        &self,
+        step: &str,
+        path: &str,
+        reason: &str,
+    ) -> String {
+        format_template(
+            self.strings().warning_init_audit_log_write_failed,
+            &[("step", step), ("path", path), ("reason", reason)],
+        )
+    }
 }
 
 impl Messages {
@@ -641,6 +800,10 @@ impl Messages {
         self.strings().next_steps_run_status
     }
 
+    pub(crate) fn next_steps_finish_openbao_only(&self) -> &'static str {
+        self.strings().next_steps_finish_openbao_only
+    }
+
     pub(crate) fn next_steps_eab_hint(&self, path: &str) -> String {
         format_template(self.strings().next_steps_eab_hint, &[("path", path)])
     }
@@ -2,6 +2,9 @@ use super::Strings;
 
 pub(super) static STRINGS: Strings = Strings {
     infra_up_completed: "bootroot 인프라 기동: 완료",
+    infra_down_completed: "bootroot 인프라 중지: 완료",
+    infra_restart_completed: "bootroot 인프라 재시작: 완료",
+    prompt_infra_down_volumes: "스택의 볼륨도 삭제할까요? OpenBao, PostgreSQL, step-ca 데이터가 영구적으로 삭제됩니다 [y/N]: ",
     infra_readiness_summary: "bootroot 인프라 기동: 준비 상태 요약",
     readiness_entry_with_health: "- {service}: {status} (health: {health})",
     readiness_entry_without_health: "- {service}: {status}",
@@ -25,6 +28,13 @@ pub(super) static STRINGS: Strings = Strings {
     monitoring_down_reset_grafana_skipped: "bootroot 모니터링 종료: Grafana 초기화 건너뜀",
     monitoring_up_already_running: "bootroot 모니터링 기동: 이미 실행 중입니다 (재시작하려면 `bootroot monitoring down`을 먼저 실행하세요)",
     error_service_no_container: "실행 중인 컨테이너가 없습니다: {service}",
+    error_compose_missing_service: "compose 파일 {path}에 필요한 서비스 {service}가 정의되어 있지 않습니다. --compose-file을 확인하거나 --services에서 해당 서비스를 제외하세요",
+    error_init_compose_missing_service: "compose 파일 {path}에 필요한 서비스 {service}가 정의되어 있지 않습니다. --compose-file을 확인하세요(step-ca와 bootroot-http01은 --skip-step-ca, --skip-responder로 제외할 수 있습니다)",
+    error_compose_file_not_found: "Compose 파일 {path}을(를) 찾을 수 없습니다. --compose-file을 지정하거나 다음 중 하나를 만드세요: {candidates}",
+    error_openbao_agent_exec_invalid: "--openbao-agent-exec 값 {value}이(가) 올바르지 않습니다: AGENT=CMD 형식이어야 하며 AGENT는 stepca 또는 responder, CMD는 비어 있지 않아야 합니다",
+    error_ca_json_outside_secrets_dir: "ca.json 경로 {path}가 secrets 디렉터리 {secrets_dir} 아래에 있지 않습니다. OpenBao Agent가 secrets 마운트 안에 렌더링하므로 --secrets-dir 아래의 --ca-json 경로를 지정하세요",
+    error_resolve_ca_json_failed: "ca.json 경로 {path} 확인 실패",
+    init_agent_template_header: "# ---- {path} (secrets 디렉터리 기준 경로) ----",
     init_failed_rollback: "bootroot init: 실패, 롤백 시도 중",
     prompt_openbao_root_token: "OpenBao 루트 토큰: ",
     error_openbao_root_token_required: "OpenBao 루트 토큰이 필요합니다; --root-token, --root-token-file 또는 OPENBAO_ROOT_TOKEN 환경 변수를 전달하세요",
@@ -32,12 +42,15 @@ pub(super) static STRINGS: Strings = Strings {
     warning_openbao_sealed_non_interactive: "경고: OpenBao가 봉인되어 있지만 stdin이 비대화형입니다. --openbao-unseal-from-file을 지정하거나 먼저 `bootroot openbao save-unseal-keys`를 실행하세요.",
     error_openbao_audit_setup_failed: "OpenBao 감사 백엔드 설정에 실패했습니다",
     warning_db_password_rotation_skipped: "경고: DB 비밀번호 회전이 건너뛰어졌습니다. 나중에 `bootroot rotate db`로 수동 회전하세요.",
+    warning_openbao_agent_render_missing: "경고: OpenBao Agent {service}가 {seconds}초 안에 {path}를 렌더링하지 않아 init이 쓴 내용을 되돌려 두었습니다. 템플릿 오류나 KV 읽기 권한 누락이면 렌더링되지 않습니다. `docker compose logs {service}`를 확인하세요.",
     warning_secret_id_ttl_exceeds_recommended: "경고: --secret-id-ttl ({value})이(가) 권장 임계치({threshold})를 초과합니다. 더 짧은 TTL을 사용하면 자격 증명 회전이 더 촘촘해집니다.",
     error_secret_id_ttl_exceeds_max: "--secret-id-ttl ({value})이(가) 최대 허용 값({max})을 초과합니다",
     error_secret_id_ttl_invalid: "잘못된 --secret-id-ttl 값: {value}. \"24h\", \"30m\", \"3600s\" 등의 형식을 사용하세요.",
     error_rn_cidrs_invalid: "잘못된 --rn-cidrs 값: {value}. CIDR 표기법을 사용하세요 (예: \"10.0.0.0/24\", \"fd00::/64\").",
     error_rn_cidrs_clear_conflict: "\"clear\"는 다른 --rn-cidrs 값과 함께 사용할 수 없습니다",
     error_rn_cidrs_clear_on_add: "\"clear\"는 서비스 업데이트에서만 유효합니다; CIDR 바인딩을 설정하지 않으려면 --rn-cidrs를 생략하세요",
+    error_approle_bind_cidr_invalid: "잘못된 --approle-bind-cidr 값: {value}. CIDR 표기법을 사용하세요 (예: \"10.0.0.0/24\", \"fd00::/64\").",
+    error_serialize_init_plan_failed: "init 계획 직렬화에 실패했습니다",
     prompt_openbao_unseal_from_file_confirm: "파일 {value}에서 자동 언실을 진행할까요? [y/N]: ",
     error_openbao_unseal_file_empty: "언실 키 파일이 비어 있습니다: {value}",
     prompt_unseal_threshold: "언실 키 임계치(t): ",
@@ -51,12 +64,15 @@ pub(super) static STRINGS: Strings = Strings {
     prompt_db_name: "PostgreSQL 데이터베이스 이름",
     error_invalid_unseal_threshold: "유효하지 않은 언실 키 임계치 값입니다",
     error_eab_requires_both: "EAB에는 kid와 hmac이 모두 필요합니다",
+    error_skip_step_ca_openbao_tls: "--skip-step-ca는 비루프백 OpenBao 바인드와 함께 사용할 수 없습니다: OpenBao TLS 인증서는 로컬 step-ca가 발급합니다",
+    error_current_dir_failed: "현재 디렉터리를 확인하지 못했습니다",
     error_openbao_sealed: "OpenBao가 언실 키 적용 후에도 봉인 상태입니다",
     error_invalid_db_dsn: "PostgreSQL DSN이 올바르지 않습니다",
     error_db_host_not_single_host: "PostgreSQL 호스트가 단일 호스트 가드레일을 위반합니다: {host}",
     error_db_host_compose_runtime: "PostgreSQL 호스트 '{host}'는 step-ca 컨테이너 런타임에서 접근할 수 없습니다. db-dsn/db-admin-dsn에 '{expected}'를 사용하세요",
     error_postgres_port_binding_unsafe: "PostgreSQL 포트는 localhost로만 바인딩되어야 합니다 (예: 127.0.0.1:5432:5432)",
     error_service_port_binding_unsafe: "{service} 포트는 localhost로만 바인딩되어야 합니다 (예: 127.0.0.1:<port>:<port>)",
+    error_postgres_allowed_cidr_invalid: "--allow-nonloopback-postgres 값은 사설 주소 대역(10.0.0.0/8, 172.16.0.0/12, 192.168.0.0/16, fc00::/7) 안의 CIDR이어야 합니다: {value}",
     error_db_check_failed: "DB 연결 점검에 실패했습니다",
     error_db_auth_failed: "DB 인증 점검에 실패했습니다",
     error_db_type_unsupported: "DB 점검은 postgresql db.type에서만 지원합니다",
@@ -92,8 +108,33 @@ pub(super) static STRINGS: Strings = Strings {
     error_monitoring_failed: "bootroot monitoring 실패",
     error_init_failed: "bootroot init 실패",
     error_status_failed: "bootroot status 실패",
+    error_audit_secrets_failed: "bootroot audit-secrets 실패",
+    error_support_bundle_failed: "bootroot support-bundle 실패",
+    error_self_test_failed: "bootroot self-test 실패",
+    error_self_test_deployment_exists: "self-test 실행 거부: bootroot 배포가 이미 존재합니다 ({paths}). 정리 단계가 그 상태를 지우게 되므로 빈 디렉터리에서 self-test를 실행하세요.",
+    error_self_test_container_exists: "self-test 실행 거부: 컨테이너 `{container}`가 이미 존재합니다. self-test 스택은 같은 컨테이너 이름을 사용하므로 기존 스택을 먼저 중지하세요.",
+    error_self_test_exe_missing: "실행 중인 bootroot 바이너리를 찾을 수 없습니다",
+    error_self_test_workspace: "self-test 임시 작업 디렉터리를 만들 수 없습니다",
+    error_self_test_step_failed: "self-test 단계 `{step}` 실패",
+    error_self_test_teardown_failed: "self-test 정리 실패: {reason}. 남은 컨테이너와 파일은 `bootroot clean --yes`로 제거하세요.",
+    self_test_step_started: "self-test: {step} 실행 중...",
+    self_test_step_passed: "self-test: {step} 성공",
+    self_test_teardown: "self-test: 임시 스택 정리 중 (bootroot clean --yes)",
+    self_test_passed: "bootroot self-test: 모든 단계 통과, 임시 스택을 정리했습니다",
+    error_migrate_failed: "bootroot migrate 실패",
+    error_trust_export_failed: "bootroot trust export 실패",
+    error_trust_show_failed: "bootroot trust show 실패",
+    error_migrate_same_location: "원본과 대상이 모두 {location}입니다. 마이그레이션할 항목이 없습니다",
+    error_migrate_mount_and_prefix: "마운트와 접두사를 한 번에 옮기면 인프라 시크릿이 남겨집니다. 먼저 마운트를 옮긴 뒤 접두사를 옮기세요",
+    error_migrate_source_empty: "{location} 아래에 비밀 값이 없습니다",
+    error_migrate_verify_failed: "{path} 검증 실패: 다시 읽은 사본이 원본과 일치하지 않습니다",
+    error_migrate_dual_write_active: "{location}에서의 이중 쓰기 전환이 이미 진행 중입니다. 먼저 bootroot migrate --finalize를 실행하세요",
+    error_migrate_no_dual_write: "진행 중인 이중 쓰기 전환이 없습니다. bootroot migrate --dual-write로 시작하세요",
+    error_cancelled: "인터럽트로 작업이 취소되었습니다",
+    error_operation_deadline_exceeded: "작업이 제한 시간 {timeout}을(를) 초과했습니다 (--operation-timeout)",
     error_service_add_failed: "bootroot service add 실패",
     error_service_info_failed: "bootroot service info 실패",
+    error_service_list_failed: "bootroot service list 실패",
     error_service_remove_failed: "bootroot service remove 실패",
     error_verify_failed: "bootroot verify 실패",
     error_rotate_failed: "bootroot rotate 실패",
@@ -107,6 +148,7 @@ pub(super) static STRINGS: Strings = Strings {
     error_file_missing: "필수 파일이 없습니다: {value}",
     error_read_file_failed: "읽기 실패: {value}",
     error_write_file_failed: "쓰기 실패: {value}",
+    error_config_file_invalid: "설정 파일 {path}이(가) 올바르지 않습니다: {reason}",
     error_read_dir_failed: "디렉터리 읽기 실패: {value}",
     error_read_dir_entry_failed: "디렉터리 항목 읽기 실패",
     error_remove_file_failed: "삭제 실패: {value}",
@@ -134,6 +176,8 @@ pub(super) static STRINGS: Strings = Strings {
     error_openbao_approle_login_failed: "OpenBao AppRole 로그인 실패",
     error_openbao_kv_exists_failed: "OpenBao KV 시크릿 조회 실패",
     error_openbao_kv_write_failed: "OpenBao KV 시크릿 저장 실패",
+    error_openbao_capability_missing: "OpenBao 토큰에 {path}에 대한 {capability} 권한이 없습니다. 정책 {policy}에 권한을 추가하세요",
+    error_openbao_capability_missing_root: "OpenBao 토큰에 {path}에 대한 {capability} 권한이 없습니다. 이 단계는 루트 토큰으로 실행하세요",
     error_openbao_kv_read_failed: "OpenBao KV 시크릿 읽기 실패",
     error_openbao_kv_delete_failed: "OpenBao KV 시크릿 삭제 실패",
     error_openbao_role_output_missing: "OpenBao AppRole 출력 누락: {value}",
@@ -145,16 +189,13 @@ pub(super) static STRINGS: Strings = Strings {
     error_docker_command_failed: "docker 명령 실패: {value}",
     error_bootroot_agent_run_failed: "bootroot-agent 실행 실패",
     error_bootroot_agent_not_found: "bootroot-agent 바이너리를 찾을 수 없습니다. 시도한 경로: {candidates}",
-    error_secrets_dir_resolve_failed: "secrets dir 확인 실패",
     error_parse_ca_json_failed: "ca.json 파싱 실패",
     error_serialize_ca_json_failed: "ca.json 직렬화 실패",
     error_rendered_file_timeout: "OpenBao Agent가 {value}을(를) 렌더링하기를 기다리다 시간이 초과되었습니다",
     error_ca_json_db_missing: "ca.json에 db.dataSource 필드가 없습니다",
+    error_stepca_db_kv_missing: "ca.json dataSource가 렌더링되지 않은 템플릿이며 OpenBao KV의 bootroot/stepca/db에 step-ca DSN이 없습니다",
     error_ca_cert_missing: "CA 인증서 파일이 없습니다: {value}",
     error_ca_cert_parse_failed: "CA 인증서 파싱 실패: {value}",
-    error_ca_trust_missing: "OpenBao CA trust 데이터에 키가 없습니다: {value}",
-    error_ca_trust_invalid: "OpenBao CA trust 데이터 형식이 올바르지 않습니다",
-    error_ca_trust_empty: "OpenBao CA trust 데이터가 비어 있습니다",
     error_parse_state_failed: "state.json 파싱 실패",
     error_serialize_state_failed: "state.json 직렬화 실패",
     prompt_service_name: "서비스 이름",
@@ -169,6 +210,7 @@ pub(super) static STRINGS: Strings = Strings {
     prompt_confirm_overwrite_state: "state.json을 덮어쓸까요? [y/N]: ",
     prompt_rotate_stepca_password: "step-ca 키 암호를 회전할까요? [y/N]",
     prompt_rotate_db: "step-ca DB 비밀번호를 회전할까요? [y/N]",
+    prompt_confirm_secrets_dir_mismatch: "기록된 secrets 디렉터리 대신 {provided}을(를) 사용해 회전할까요? [y/N]",
     prompt_rotate_responder_hmac: "HTTP-01 responder HMAC을 회전할까요? [y/N]",
     prompt_rotate_approle_secret_id: "{service_name} AppRole secret_id를 회전할까요? [y/N]",
     prompt_rotate_all_approle_secret_ids: "등록된 서비스 {count}개의 AppRole secret_id를 모두 회전할까요? [y/N]",
@@ -177,9 +219,16 @@ pub(super) static STRINGS: Strings = Strings {
     init_plan_overwrite_password: "- password.txt: 덮어쓰기",
     init_plan_overwrite_ca_json: "- ca.json: 덮어쓰기",
     init_plan_overwrite_state: "- state.json: 덮어쓰기",
+    init_dry_run_openbao_writes: "OpenBao 쓰기:",
+    init_dry_run_openbao_bootstrap: "  - OpenBao가 초기화되지 않았거나 봉인된 경우 초기화 및 봉인 해제",
+    init_dry_run_file_writes: "파일 쓰기:",
+    init_dry_run_container_commands: "컨테이너 명령:",
+    init_dry_run_item: "  - {item}",
+    init_dry_run_done: "드라이 런: 아무것도 변경하지 않았습니다. 이 계획을 적용하려면 --dry-run 없이 다시 실행하세요.",
     service_add_summary: "bootroot 서비스 추가: 요약",
     service_add_plan_title: "bootroot 서비스 추가: 계획",
     service_info_summary: "bootroot 서비스 정보: 요약",
+    service_list_entry: "  - {service} ({delivery_mode}): 호스트명 {hostname}, 도메인 {domain}",
     service_summary_kind: "- 서비스 이름: {value}",
     service_summary_hostname: "- 호스트명: {value}",
     service_summary_domain: "- 도메인: {value}",
@@ -212,12 +261,21 @@ pub(super) static STRINGS: Strings = Strings {
     service_summary_agent_config: "- 에이전트 설정: {value}",
     service_summary_cert_path: "- cert 경로: {value}",
     service_summary_key_path: "- key 경로: {value}",
+    service_info_cert_file: "- cert 파일: {value}",
+    service_info_key_file: "- key 파일: {value}",
+    service_info_cert_files_remote: "- cert/key 파일: 원격 호스트에 있음 (로컬에서 확인하지 않음)",
+    cert_file_present: "있음",
+    cert_file_missing: "없음",
+    key_file_wrong_mode: "있음, 권한 {mode} (기대값 {expected})",
     service_summary_next_steps: "다음 단계:",
     service_summary_preview_mode: "- 프리뷰 모드: 파일이나 state 변경이 적용되지 않았습니다",
     service_summary_preview_trust_skipped_no_token: "- trust 프리뷰를 조회하지 못했습니다: 프리뷰 모드에서는 OpenBao 런타임 인증(--root-token 또는 --auth-mode approle + AppRole 자격정보)을 제공해야 합니다",
     service_summary_preview_trust_not_found: "- trust 프리뷰를 조회하지 못했습니다: OpenBao CA trust 데이터(secret/bootroot/ca)를 찾지 못했습니다",
     service_summary_preview_trust_lookup_failed: "- trust 프리뷰 조회 실패: {value}",
     service_summary_remote_idempotent_hint: "- 기존 remote-bootstrap 서비스와 입력이 일치하여 부트스트랩 아티팩트를 갱신했습니다",
+    error_serialize_service_add_result_failed: "service add 결과 직렬화 실패",
+    error_serialize_service_info_failed: "service info 직렬화 실패",
+    error_serialize_service_list_failed: "service list 직렬화 실패",
     service_next_steps_daemon_profile: "  - {config_path}에 {service_name} 프로필(instance_id={instance_id}, hostname={hostname}, domain={domain}, cert={cert_path}, key={key_path})을 추가하고 bootroot-agent를 리로드하세요.",
     service_next_steps_remote_selfheal_keep: "  - `bootroot-remote bootstrap` 이후 원격 호스트에서 bootroot-agent를 계속 실행하세요(위의 원격 핸드오프 순서 참고).",
     service_next_steps_remote_selfheal_note: concat!(
@@ -249,7 +307,54 @@ pub(super) static STRINGS: Strings = Strings {
     verify_ca_bundle_parse_failed: "CA 번들({path})을 파싱할 수 없습니다",
     verify_ca_bundle_missing_fingerprints: "CA 번들({path})에 신뢰 지문이 누락되었습니다: {missing}",
     verify_cert_chain_failed: "리프 인증서({cert_path})가 CA 번들({bundle_path})에 체인되지 않습니다. 현재 PKI 세대에 맞춰 리프를 재발급하세요.",
+    audit_secrets_not_git_repo: "git 작업 트리가 아니므로 점검할 항목이 없습니다.",
+    audit_secrets_clean: "git이 추적하는 시크릿 파일이 없습니다.",
+    audit_secrets_tracked: "경고: git이 시크릿 파일을 추적하고 있습니다: {value}",
+    audit_secrets_hint: "`git rm --cached <path>`로 인덱스에서 제거하고 .gitignore에 추가한 뒤, 이미 push된 시크릿은 교체하세요.",
+    error_audit_secrets_tracked: "git이 추적하는 시크릿 파일 {count}개",
+    support_bundle_written: "지원 번들을 {value}에 작성했습니다",
+    support_bundle_state_missing: "{value}에 state.json이 없어 번들에서 제외했습니다.",
+    support_bundle_command_failed: "명령 실행 실패: {value}",
+    version_git_sha: "git 커밋: {value}",
+    version_build_date: "빌드 시각: {value}",
+    version_rustc: "rustc: {value}",
+    version_docker: "docker: {value}",
+    version_podman: "podman: {value}",
+    version_compose_images: "compose 이미지 ({value}):",
+    version_unavailable: "확인 불가",
+    migrate_copied: "복사함: {from} -> {to}",
+    migrate_summary: "{count}개 비밀 값을 {from}에서 {to}로 옮겼습니다 (모든 사본 검증 완료)",
+    migrate_state_updated: "state.json의 kv_mount를 {mount}(으)로 변경했습니다",
+    migrate_state_prefix_updated: "state.json의 kv_prefix를 {prefix}(으)로 변경했습니다. service add와 rotate는 이제 {prefix}/services를 사용합니다",
+    trust_export_source_kv: "OpenBao KV({path})에서 CA 번들을 읽었습니다",
+    trust_export_source_local: "로컬 인증서({dir})에서 CA 번들을 읽었습니다",
+    trust_export_kv_unavailable: "OpenBao KV CA 번들을 사용할 수 없어({reason}) 로컬 인증서를 사용합니다",
+    trust_export_written: "CA 번들({format}, 인증서 {count}개)을 {path}에 기록했습니다",
+    trust_export_fingerprints: "CA 지문(SHA-256):",
+    error_trust_export_no_certs: "CA 번들에 인증서가 없습니다",
+    error_trust_export_der_multiple: "--format der는 인증서 하나만 담을 수 있지만 CA 번들에는 {count}개가 있습니다. pem 또는 p7b를 사용하세요",
+    trust_export_no_root_token: "OpenBao 루트 토큰 없음",
+    trust_show_local_header: "로컬 CA 인증서({dir}):",
+    trust_show_kv_header: "OpenBao KV CA 번들({path}):",
+    trust_show_kv_unavailable: "OpenBao KV CA 번들: 비교하지 않음({reason})",
+    trust_show_cert: "  {role}: {subject}",
+    trust_show_cert_sha256: "    SHA-256: {sha256}",
+    trust_show_cert_not_after: "    만료: {not_after}",
+    trust_show_role_root: "루트",
+    trust_show_role_intermediate: "중간",
+    trust_show_drift_none: "차이: 없음(로컬 인증서가 OpenBao KV 번들과 일치)",
+    trust_show_drift_detected: "차이: 로컬 인증서가 OpenBao KV 번들과 다릅니다. `bootroot rotate trust-sync`로 다시 게시하세요",
+    migrate_confirm_delete: "{location} 아래의 원본 비밀 값 {count}개를 삭제할까요? [y/N]: ",
+    migrate_source_deleted: "{location} 아래의 원본 비밀 값 {count}개를 삭제했습니다",
+    migrate_source_kept: "{location} 아래의 원본 비밀 값은 유지했습니다. 삭제하려면 --delete-source를 지정하세요",
+    migrate_dual_write_started: "이중 쓰기 활성화: init과 rotate가 이제 {current}에 쓰고 모든 비밀 값을 {legacy}에도 복제합니다. 모든 에이전트가 새 위치를 읽게 되면 bootroot migrate --finalize를 실행하세요.",
+    migrate_dual_write_finalized: "{location} 아래 비밀 값 {count}개를 삭제했습니다. 이중 쓰기 전환이 끝났습니다",
+    migrate_references_note: "마이그레이션 전에 작성된 AppRole 정책, OpenBao Agent 템플릿, 서비스 agent 설정은 여전히 이전 위치를 참조합니다. 새 위치를 사용하기 전에 갱신하세요.",
+    cancellation_requested: "인터럽트를 받았습니다. 현재 단계를 마친 뒤 종료합니다 (즉시 중단하려면 Ctrl-C를 다시 누르세요).",
+    status_openbao_target_inferred: "경고: state.json이 없어 OpenBao URL {url}({source})과 KV 마운트 {kv_mount}를 사용합니다.",
+    status_openbao_target_default_source: "기본값",
     status_summary_title: "bootroot status: 요약",
+    error_serialize_status_report_failed: "상태 보고서 직렬화 실패",
     status_section_infra: "- infra:",
     status_section_openbao: "- OpenBao:",
     status_section_kv_paths: "- KV 경로:",
@@ -262,6 +367,7 @@ pub(super) static STRINGS: Strings = Strings {
     status_kv_path_entry: "  - {path}: {value}",
     status_approle_entry: "  - {role}: {value}",
     status_service_delivery_mode: "  - {service} 전달 모드: {value}",
+    status_service_cert_files: "  - {service} 인증서: {cert}, 키: {key}",
     status_value_ok: "ok",
     status_value_unreachable: "접속 불가",
     status_value_present: "있음",
@@ -279,19 +385,30 @@ pub(super) static STRINGS: Strings = Strings {
     summary_secrets_dir: "- 시크릿 디렉터리: {value}",
     summary_stepca_completed: "- step-ca 초기화: 완료",
     summary_stepca_skipped: "- step-ca 초기화: 생략 (이미 초기화됨)",
+    summary_stepca_external: "- step-ca 초기화: 생략 (외부 CA, --skip-step-ca)",
+    summary_stepca_deferred: "- step-ca 초기화: 보류됨 (--openbao-only)",
+    init_resume_notice: "이전 init을 이어서 진행합니다: 기존 정책, AppRole, KV 시크릿은 유지하고 없는 항목만 생성합니다.",
+    init_cancelled_openbao_credentials: "OpenBao 초기화 후 init이 중단되었습니다. bootroot init --resume --root-token <token>으로 재개하려면 다음 값을 보관하세요:\n루트 토큰: {root_token}\n키:\n{keys}",
     summary_openbao_init_completed: "- OpenBao 초기화: 완료 (shares={shares}, threshold={threshold})",
     summary_openbao_init_skipped: "- OpenBao 초기화: 생략 (이미 초기화됨)",
     summary_root_token: "- 루트 토큰: {value}",
     summary_unseal_key: "- 언실 키 {index}: {value}",
+    summary_recovery_key: "- 복구 키 {index}: {value}",
     summary_stepca_password: "- step-ca 비밀번호: {value}",
     summary_db_dsn: "- DB DSN: {value}",
     summary_responder_hmac: "- responder HMAC: {value}",
     summary_eab_kid: "- EAB kid: {value}",
     summary_eab_hmac: "- EAB hmac: {value}",
     summary_eab_missing: "- EAB: 미설정",
+    summary_secrets_revealed_to_file: "마스킹되지 않은 비밀 값을 {path}에 기록했습니다 (mode 0600)",
+    error_reveal_to_file_failed: "마스킹되지 않은 비밀 값을 {path}에 쓰지 못했습니다: {reason}. 유실되지 않도록 아래에 출력합니다:",
+    warning_init_reveal_to_file_failed: "경고: 마스킹되지 않은 비밀 값을 {path}에 쓰지 못했습니다 ({reason}). init은 완료되었습니다. 유실되지 않도록 아래에 출력합니다:",
+    error_init_audit_log_open_failed: "init 감사 로그 {path}를 열지 못했습니다: {reason}",
+    warning_init_audit_log_write_failed: "경고: init 감사 로그 {path}에 {step} 단계를 추가하지 못했습니다 ({reason}). init은 계속 진행합니다.",
     rotate_summary_title: "bootroot rotate: 요약",
     rotate_summary_stepca_password: "- step-ca 암호 갱신: {value}",
     rotate_summary_restart_stepca: "- step-ca: 재시작",
+    error_service_not_recovered: "서비스 {service}가 재시작 후 정상 상태가 되지 않았습니다",
     rotate_summary_db_dsn: "- ca.json 갱신: {value}",
     rotate_summary_responder_config: "- responder 설정 갱신: {value}",
     rotate_summary_reload_responder: "- responder: SIGHUP 전송",
@@ -314,6 +431,16 @@ pub(super) static STRINGS: Strings = Strings {
     rotate_summary_self_mint: "- {role_name}의 자체 secret_id 재발급 완료 (num_uses={num_uses}): {value}",
     rotate_summary_self_mint_login_ok: "- {role_name} 자체 재발급 로그인 검증 확인",
     warning_self_mint_skipped_non_file: "경고: {role_name} 자체 재발급을 건너뛰었습니다. AppRole 인증이 파일 기반이 아닙니다. 자체 재발급 단계는 --approle-secret-id-file로 전달된 파일을 교체하는데, 인라인 또는 환경 변수로 전달된 secret_id에는 교체할 파일이 없습니다. 현재 자격증명은 TTL이 지나면 만료됩니다 — 예약 작업을 --approle-secret-id-file 방식으로 전환하거나 만료 전에 재발급하세요.",
+    warning_openbao_agent_not_found: "경고: {service}의 OpenBao Agent 컨테이너를 찾지 못했습니다(예상 이름 {container}). 새 값은 OpenBao에 저장되었지만 에이전트가 다시 로드되지 않았습니다. 변경 사항을 반영하려면 수동으로 재시작하세요.",
+    warning_openbao_agent_not_running: "경고: OpenBao Agent 컨테이너 {container}의 상태가 {state}입니다. 새 값은 OpenBao에 저장되었지만 에이전트가 다시 로드되지 않았습니다. 변경 사항을 반영하려면 컨테이너를 시작하세요.",
+    error_openbao_agent_not_reloaded: "OpenBao Agent {service}가 다시 로드되지 않아 새 값이 렌더링되지 않았습니다. 컨테이너를 시작하고(docker compose up -d {service}) 새 값이 렌더링된 뒤에 이 값을 읽는 서비스를 재시작하세요.",
+    rotate_plan_title: "bootroot rotate {target}: 계획(드라이 런, 아무것도 변경하지 않음)",
+    rotate_plan_kv_write: "- 기록할 KV 경로: {value}",
+    rotate_plan_file: "- 수정할 파일: {value}",
+    rotate_plan_approle: "- 새 secret_id를 발급할 AppRole: {value}",
+    rotate_plan_recovery: "- 재생성할 OpenBao 복구 자격증명: {value}",
+    rotate_plan_restart: "- 재시작/리로드할 컨테이너 또는 서비스: {value}",
+    rotate_plan_empty: "- 변경할 항목 없음",
     error_self_mint_failed: "{role_name} 자격증명의 자체 secret_id 재발급에 실패했습니다 (회전 대상은 모두 성공했습니다). 현재 자격증명은 TTL까지 유효하며 다음 실행에서 자체 재발급을 다시 시도합니다.",
     error_self_mint_verify_failed: "새로 자체 발급한 {role_name} secret_id의 로그인 검증에 실패하여 기존 자격증명 파일을 그대로 유지합니다. 기록된 rotate-bound CIDR을 확인하세요 — 이 호스트를 제외하는 바인딩은 예약 작업을 잠금 상태로 만듭니다.",
     error_rotate_bound_cidrs_requires_provisioning: "--rotate-bound-cidrs는 루트 토큰 프로비저닝 실행(루트 인증의 --infra)에서만 적용됩니다. 루트 토큰으로 다시 실행하거나 플래그를 제거하세요",
@@ -331,6 +458,8 @@ pub(super) static STRINGS: Strings = Strings {
     prompt_rotate_force_reissue_remote: "remote-bootstrap 서비스 {service_name}의 재발급 요청을 OpenBao에 기록할까요? 원격 agent가 다음 fast-poll 틱에 반영합니다. [y/N]",
     rotate_summary_trust_sync_global: "- CA trust 갱신: {value}",
     rotate_summary_trust_sync_service: "- 서비스 trust 동기화: {value}",
+    rotate_summary_trust_sync_generation: "- CA trust 세대: {value}",
+    rotate_summary_trust_sync_current: "- 서비스 trust 최신 상태(건너뜀): {value}",
     rotate_summary_force_reissue_deleted: "- {service_name}: cert/key 삭제 ({cert_path}, {key_path})",
     rotate_summary_force_reissue_local_signal: "- {service_name}: 인증서 갱신을 위해 bootroot-agent에 시그널 전송",
     rotate_summary_force_reissue_requested: "- {service_name}: 재발급 요청 기록 {requested_at}",
@@ -346,6 +475,7 @@ pub(super) static STRINGS: Strings = Strings {
     error_rotation_mode_mismatch: "rotation-state.json 모드({mode})가 --full 플래그와 일치하지 않습니다. 기존 교체를 먼저 완료하거나 중단하세요",
     error_parse_cert_failed: "인증서를 파싱할 수 없습니다 {path}: {reason}",
     warning_stale_backup: "경고: 백업 파일(.bak)이 rotation-state.json 없이 존재합니다. 이전 교체가 중단되었을 수 있습니다.",
+    warning_secrets_dir_mismatch: "경고: --secrets-dir {provided}이(가) state.json에 기록된 secrets 디렉터리({recorded})와 다릅니다. rotate는 {provided}을(를) 읽고 씁니다.",
     rotate_ca_key_resuming: "CA 키 교체를 {phase}단계부터 재개합니다.",
     rotate_ca_key_phase_backup: "[1단계] 중간 CA 인증서와 키를 백업 중...",
     rotate_ca_key_phase_generate: "[2단계] 새 중간 CA 키 쌍 생성 중...",
@@ -374,7 +504,7 @@ pub(super) static STRINGS: Strings = Strings {
     summary_role_id: "    role_id: {value}",
     summary_secret_id: "    secret_id: {value}",
     summary_next_steps: "다음 단계:",
-    next_steps_configure_templates: "  - step-ca, responder, bootroot-agent용 OpenBao Agent 템플릿을 구성하세요.",
+    next_steps_configure_templates: "  - step-ca, responder, bootroot-agent용 OpenBao Agent 템플릿을 구성하세요(`bootroot init --print-agent-config-template`로 출력할 수 있습니다).",
     next_steps_responder_template: "  - responder 템플릿: {value}",
     next_steps_responder_config: "  - responder 설정 출력: {value}",
     next_steps_responder_url: "  - responder 관리자 URL: {value}",
@@ -383,11 +513,19 @@ pub(super) static STRINGS: Strings = Strings {
     next_steps_openbao_agent_override: "  - OpenBao Agent compose override: {value}",
     next_steps_reload_services: "  - 렌더링된 시크릿을 사용하도록 step-ca와 responder를 시작하거나 재시작하세요.",
     next_steps_run_status: "  - 서비스 확인을 위해 `bootroot status`를 실행하세요.",
+    next_steps_finish_openbao_only: "  - `bootroot init --resume`를 실행해 step-ca를 초기화하고 responder 및 OpenBao Agent 파일을 작성한 뒤 CA trust를 게시하세요.",
     next_steps_eab_hint: "  - ACME CA가 EAB를 요구한다면 외부에서 kid/hmac을 발급(번들된 OSS step-ca는 EAB를 지원하지 않음)한 뒤 {path}에 저장하거나 --eab-kid/--eab-hmac으로 다시 실행하세요.",
     infra_install_completed: "bootroot infra install: 완료",
     infra_install_env_written: "bootroot infra install: .env 파일 작성 완료",
     infra_install_dirs_created: "bootroot infra install: 디렉터리 생성 완료",
     error_infra_install_failed: "bootroot infra install 실패",
+    error_infra_down_failed: "bootroot infra down 실패",
+    error_infra_restart_failed: "bootroot infra restart 실패",
+    error_infra_logs_failed: "bootroot infra logs 실패",
+    error_restart_policy_invalid: "잘못된 --restart-policy '{value}': no, always, unless-stopped, on-failure, on-failure:N(N은 재시도 횟수) 중 하나여야 합니다",
+    error_service_timeout_invalid: "잘못된 --service-timeout '{value}': <서비스>=<초> 형식이어야 합니다(예: step-ca=180)",
+    warning_infra_pull_retry: "{runtime} compose pull 실패 ({delay} 후 재시도 {attempt}/{retries}): {reason}",
+    infra_openbao_auto_unseal_skipped: "OpenBao가 자동 언실 seal을 사용합니다(init --auto-unseal로 기록됨). 언실 단계를 건너뜁니다",
     clean_completed: "bootroot clean: 완료",
     clean_confirm: "컨테이너, 볼륨, secrets, state.json, .env 파일을 삭제합니다. 계속할까요? [y/N]: ",
     clean_confirm_certs: "certs/ 디렉터리도 삭제할까요? [y/N]: ",
@@ -421,6 +559,8 @@ pub(super) static STRINGS: Strings = Strings {
     service_info_secret_id_ttl: "- secret_id TTL: {value}",
     service_info_secret_id_wrap_ttl: "- secret_id wrap TTL: {value}",
     service_info_token_bound_cidrs: "- token_bound_cidrs: {value}",
+    service_info_sync_generation: "- 원격 동기화 세대: {value}",
+    service_info_sync_generation_none: "- 원격 동기화 세대: 아직 게시되지 않음",
     policy_label_inherit: "상속",
     policy_label_disabled: "비활성화",
     policy_label_default_wrap_ttl: "{value} (기본값)",
@@ -16,6 +16,13 @@ impl Messages {
         self.strings().rotate_summary_restart_stepca
     }
 
+    pub(crate) fn error_service_not_recovered(&self, service: &str) -> String {
+        format_template(
+            self.strings().error_service_not_recovered,
+            &[("service", service)],
+        )
+    }
+
     pub(crate) fn rotate_summary_db_dsn(&self, value: &str) -> String {
         format_template(self.strings().rotate_summary_db_dsn, &[("value", value)])
     }
@@ -131,6 +138,55 @@ impl Messages {
         )
     }
 
+    pub(crate) fn warning_openbao_agent_not_found(&self, service: &str, container: &str) -> String {
+        format_template(
+            self.strings().warning_openbao_agent_not_found,
+            &[("service", service), ("container", container)],
+        )
+    }
+
+    pub(crate) fn warning_openbao_agent_not_running(&self, container: &str, state: &str) -> String {
+        format_template(
+            self.strings().warning_openbao_agent_not_running,
+            &[("container", container), ("state", state)],
+        )
+    }
+
+    pub(crate) fn error_openbao_agent_not_reloaded(&self, service: &str) -> String {
+        format_template(
+            self.strings().error_openbao_agent_not_reloaded,
+            &[("service", service)],
+        )
+    }
+
+    pub(crate) fn rotate_plan_title(&self, target: &str) -> String {
+        format_template(self.strings().rotate_plan_title, &[("target", target)])
+    }
+
+    pub(crate) fn rotate_plan_kv_write(&self, value: &str) -> String {
+        format_template(self.strings().rotate_plan_kv_write, &[("value", value)])
+    }
+
+    pub(crate) fn rotate_plan_file(&self, value: &str) -> String {
+        format_template(self.strings().rotate_plan_file, &[("value", value)])
+    }
+
+    pub(crate) fn rotate_plan_approle(&self, value: &str) -> String {
+        format_template(self.strings().rotate_plan_approle, &[("value", value)])
+    }
+
+    pub(crate) fn rotate_plan_recovery(&self, value: &str) -> String {
+        format_template(self.strings().rotate_plan_recovery, &[("value", value)])
+    }
+
+    pub(crate) fn rotate_plan_restart(&self, value: &str) -> String {
+        format_template(self.strings().rotate_plan_restart, &[("value", value)])
+    }
+
+    pub(crate) fn rotate_plan_empty(&self) -> &'static str {
+        self.strings().rotate_plan_empty
+    }
+
     pub(crate) fn error_self_mint_failed(&self, role_name: &str) -> String {
         format_template(
             self.strings().error_self_mint_failed,
@@ -317,6 +373,20 @@ impl Messages {
         )
     }
 
+    pub(crate) fn rotate_summary_trust_sync_generation(&self, value: &str) -> String {
+        format_template(
+            self.strings().rotate_summary_trust_sync_generation,
+            &[("value", value)],
+        )
+    }
+
+    pub(crate) fn rotate_summary_trust_sync_current(&self, value: &str) -> String {
+        format_template(
+            self.strings().rotate_summary_trust_sync_current,
+            &[("value", value)],
+        )
+    }
+
     pub(crate) fn rotate_summary_force_reissue_deleted(
         &self,
         service_name: &str,
@@ -442,6 +512,13 @@ impl Messages {
         self.strings().warning_stale_backup
     }
 
+    pub(crate) fn warning_secrets_dir_mismatch(&self, provided: &str, recorded: &str) -> String {
+        format_template(
+            self.strings().warning_secrets_dir_mismatch,
+            &[("provided", provided), ("recorded", recorded)],
+        )
+    }
+
     pub(crate) fn rotate_ca_key_resuming(&self, phase: &str) -> String {
         format_template(self.strings().rotate_ca_key_resuming, &[("phase", phase)])
     }
@@ -578,4 +655,11 @@ impl Messages {
     pub(crate) fn rotate_infra_tls_no_entries(&self) -> &'static str {
         self.strings().rotate_infra_tls_no_entries
     }
+
+    pub(crate) fn prompt_confirm_secrets_dir_mismatch(&self, provided: &str) -> String {
+        format_template(
+            self.strings().prompt_confirm_secrets_dir_mismatch,
+            &[("provided", provided)],
+        )
+    }
 }
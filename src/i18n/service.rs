@@ -128,6 +128,92 @@ impl Messages {
         self.strings().error_status_failed
     }
 
+    pub(crate) fn error_audit_secrets_failed(&self) -> &'static str {
+        self.strings().error_audit_secrets_failed
+    }
+
+    pub(crate) fn error_support_bundle_failed(&self) -> &'static str {
+        self.strings().error_support_bundle_failed
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn error_self_test_failed(&self) -> &'static str {
+        self.strings().error_self_test_failed
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn error_self_test_deployment_exists(&self, paths: &str) -> String {
+        format_template(
+            self.strings().error_self_test_deployment_exists,
+            &[("paths", paths)],
+        )
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn error_self_test_container_exists(&self, container: &str) -> String {
+        format_template(
+            self.strings().error_self_test_container_exists,
+            &[("container", container)],
+        )
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn error_self_test_exe_missing(&self) -> &'static str {
+        self.strings().error_self_test_exe_missing
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn error_self_test_workspace(&self) -> &'static str {
+        self.strings().error_self_test_workspace
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn error_self_test_step_failed(&self, step: &str) -> String {
+        format_template(
+            self.strings().error_self_test_step_failed,
+            &[("step", step)],
+        )
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn error_self_test_teardown_failed(&self, reason: &str) -> String {
+        format_template(
+            self.strings().error_self_test_teardown_failed,
+            &[("reason", reason)],
+        )
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn self_test_step_started(&self, step: &str) -> String {
+        format_template(self.strings().self_test_step_started, &[("step", step)])
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn self_test_step_passed(&self, step: &str) -> String {
+        format_template(self.strings().self_test_step_passed, &[("step", step)])
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn self_test_teardown(&self) -> &'static str {
+        self.strings().self_test_teardown
+    }
+
+    #[cfg(feature = "self-test")]
+    pub(crate) fn self_test_passed(&self) -> &'static str {
+        self.strings().self_test_passed
+    }
+
+    pub(crate) fn error_cancelled(&self) -> &'static str {
+        self.strings().error_cancelled
+    }
+
+    pub(crate) fn error_operation_deadline_exceeded(&self, timeout: &str) -> String {
+        format_template(
+            self.strings().error_operation_deadline_exceeded,
+            &[("timeout", timeout)],
+        )
+    }
+
     pub(crate) fn error_service_add_failed(&self) -> &'static str {
         self.strings().error_service_add_failed
     }
@@ -136,6 +222,10 @@ impl Messages {
         self.strings().error_service_info_failed
     }
 
+    pub(crate) fn error_service_list_failed(&self) -> &'static str {
+        self.strings().error_service_list_failed
+    }
+
     pub(crate) fn error_service_remove_failed(&self) -> &'static str {
         self.strings().error_service_remove_failed
     }
@@ -191,6 +281,13 @@ impl Messages {
         format_template(self.strings().error_write_file_failed, &[("value", value)])
     }
 
+    pub(crate) fn error_config_file_invalid(&self, path: &str, reason: &str) -> String {
+        format_template(
+            self.strings().error_config_file_invalid,
+            &[("path", path), ("reason", reason)],
+        )
+    }
+
     pub(crate) fn error_read_dir_failed(&self, value: &str) -> String {
         format_template(self.strings().error_read_dir_failed, &[("value", value)])
     }
@@ -308,6 +405,33 @@ impl Messages {
         self.strings().error_openbao_kv_write_failed
     }
 
+    pub(crate) fn error_openbao_capability_missing(
+        &self,
+        capability: &str,
+        path: &str,
+        policy: &str,
+    ) -> String {
+        format_template(
+            self.strings().error_openbao_capability_missing,
+            &[
+                ("capability", capability),
+                ("path", path),
+                ("policy", policy),
+            ],
+        )
+    }
+
+    pub(crate) fn error_openbao_capability_missing_root(
+        &self,
+        capability: &str,
+        path: &str,
+    ) -> String {
+        format_template(
+            self.strings().error_openbao_capability_missing_root,
+            &[("capability", capability), ("path", path)],
+        )
+    }
+
     pub(crate) fn error_openbao_kv_read_failed(&self) -> &'static str {
         self.strings().error_openbao_kv_read_failed
     }
@@ -370,10 +494,6 @@ impl Messages {
         )
     }
 
-    pub(crate) fn error_secrets_dir_resolve_failed(&self) -> &'static str {
-        self.strings().error_secrets_dir_resolve_failed
-    }
-
     pub(crate) fn error_rendered_file_timeout(&self, value: &str) -> String {
         format_template(
             self.strings().error_rendered_file_timeout,
@@ -393,6 +513,10 @@ impl Messages {
         self.strings().error_ca_json_db_missing
     }
 
+    pub(crate) fn error_stepca_db_kv_missing(&self) -> &'static str {
+        self.strings().error_stepca_db_kv_missing
+    }
+
     pub(crate) fn error_ca_cert_missing(&self, path: &str) -> String {
         format_template(self.strings().error_ca_cert_missing, &[("value", path)])
     }
@@ -404,18 +528,6 @@ impl Messages {
         )
     }
 
-    pub(crate) fn error_ca_trust_missing(&self, key: &str) -> String {
-        format_template(self.strings().error_ca_trust_missing, &[("value", key)])
-    }
-
-    pub(crate) fn error_ca_trust_invalid(&self) -> &'static str {
-        self.strings().error_ca_trust_invalid
-    }
-
-    pub(crate) fn error_ca_trust_empty(&self) -> &'static str {
-        self.strings().error_ca_trust_empty
-    }
-
     pub(crate) fn error_parse_state_failed(&self) -> &'static str {
         self.strings().error_parse_state_failed
     }
@@ -499,6 +611,30 @@ impl Messages {
         self.strings().init_plan_overwrite_state
     }
 
+    pub(crate) fn init_dry_run_openbao_writes(&self) -> &'static str {
+        self.strings().init_dry_run_openbao_writes
+    }
+
+    pub(crate) fn init_dry_run_openbao_bootstrap(&self) -> &'static str {
+        self.strings().init_dry_run_openbao_bootstrap
+    }
+
+    pub(crate) fn init_dry_run_file_writes(&self) -> &'static str {
+        self.strings().init_dry_run_file_writes
+    }
+
+    pub(crate) fn init_dry_run_container_commands(&self) -> &'static str {
+        self.strings().init_dry_run_container_commands
+    }
+
+    pub(crate) fn init_dry_run_item(&self, item: &str) -> String {
+        format_template(self.strings().init_dry_run_item, &[("item", item)])
+    }
+
+    pub(crate) fn init_dry_run_done(&self) -> &'static str {
+        self.strings().init_dry_run_done
+    }
+
     pub(crate) fn service_add_summary(&self) -> &'static str {
         self.strings().service_add_summary
     }
@@ -511,6 +647,24 @@ impl Messages {
         self.strings().service_info_summary
     }
 
+    pub(crate) fn service_list_entry(
+        &self,
+        service: &str,
+        delivery_mode: &str,
+        hostname: &str,
+        domain: &str,
+    ) -> String {
+        format_template(
+            self.strings().service_list_entry,
+            &[
+                ("service", service),
+                ("delivery_mode", delivery_mode),
+                ("hostname", hostname),
+                ("domain", domain),
+            ],
+        )
+    }
+
     pub(crate) fn service_summary_kind(&self, value: &str) -> String {
         format_template(self.strings().service_summary_kind, &[("value", value)])
     }
@@ -665,6 +819,33 @@ impl Messages {
         format_template(self.strings().service_summary_key_path, &[("value", value)])
     }
 
+    pub(crate) fn service_info_cert_file(&self, value: &str) -> String {
+        format_template(self.strings().service_info_cert_file, &[("value", value)])
+    }
+
+    pub(crate) fn service_info_key_file(&self, value: &str) -> String {
+        format_template(self.strings().service_info_key_file, &[("value", value)])
+    }
+
+    pub(crate) fn service_info_cert_files_remote(&self) -> &'static str {
+        self.strings().service_info_cert_files_remote
+    }
+
+    pub(crate) fn cert_file_present(&self) -> &'static str {
+        self.strings().cert_file_present
+    }
+
+    pub(crate) fn cert_file_missing(&self) -> &'static str {
+        self.strings().cert_file_missing
+    }
+
+    pub(crate) fn key_file_wrong_mode(&self, mode: &str, expected: &str) -> String {
+        format_template(
+            self.strings().key_file_wrong_mode,
+            &[("mode", mode), ("expected", expected)],
+        )
+    }
+
     pub(crate) fn service_summary_next_steps(&self) -> &'static str {
         self.strings().service_summary_next_steps
     }
@@ -693,6 +874,18 @@ impl Messages {
         self.strings().service_summary_remote_idempotent_hint
     }
 
+    pub(crate) fn error_serialize_service_add_result_failed(&self) -> &'static str {
+        self.strings().error_serialize_service_add_result_failed
+    }
+
+    pub(crate) fn error_serialize_service_info_failed(&self) -> &'static str {
+        self.strings().error_serialize_service_info_failed
+    }
+
+    pub(crate) fn error_serialize_service_list_failed(&self) -> &'static str {
+        self.strings().error_serialize_service_list_failed
+    }
+
     pub(crate) fn service_next_steps_daemon_profile(
         &self,
         data: &ServiceNextStepsDaemon<'_>,
@@ -786,6 +979,17 @@ impl Messages {
         )
     }
 
+    pub(crate) fn service_info_sync_generation(&self, value: &str) -> String {
+        format_template(
+            self.strings().service_info_sync_generation,
+            &[("value", value)],
+        )
+    }
+
+    pub(crate) fn service_info_sync_generation_none(&self) -> &'static str {
+        self.strings().service_info_sync_generation_none
+    }
+
     pub(crate) fn policy_label_inherit(&self) -> &'static str {
         self.strings().policy_label_inherit
     }
@@ -103,10 +103,254 @@ impl Messages {
         )
     }
 
+    pub(crate) fn audit_secrets_not_git_repo(&self) -> &'static str {
+        self.strings().audit_secrets_not_git_repo
+    }
+
+    pub(crate) fn audit_secrets_clean(&self) -> &'static str {
+        self.strings().audit_secrets_clean
+    }
+
+    pub(crate) fn audit_secrets_tracked(&self, value: &str) -> String {
+        format_template(self.strings().audit_secrets_tracked, &[("value", value)])
+    }
+
+    pub(crate) fn audit_secrets_hint(&self) -> &'static str {
+        self.strings().audit_secrets_hint
+    }
+
+    pub(crate) fn error_audit_secrets_tracked(&self, count: &str) -> String {
+        format_template(
+            self.strings().error_audit_secrets_tracked,
+            &[("count", count)],
+        )
+    }
+
+    pub(crate) fn support_bundle_written(&self, value: &str) -> String {
+        format_template(self.strings().support_bundle_written, &[("value", value)])
+    }
+
+    pub(crate) fn support_bundle_state_missing(&self, value: &str) -> String {
+        format_template(
+            self.strings().support_bundle_state_missing,
+            &[("value", value)],
+        )
+    }
+
+    pub(crate) fn support_bundle_command_failed(&self, value: &str) -> String {
+        format_template(
+            self.strings().support_bundle_command_failed,
+            &[("value", value)],
+        )
+    }
+
+    pub(crate) fn version_git_sha(&self, value: &str) -> String {
+        format_template(self.strings().version_git_sha, &[("value", value)])
+    }
+
+    pub(crate) fn version_build_date(&self, value: &str) -> String {
+        format_template(self.strings().version_build_date, &[("value", value)])
+    }
+
+    pub(crate) fn version_rustc(&self, value: &str) -> String {
+        format_template(self.strings().version_rustc, &[("value", value)])
+    }
+
+    pub(crate) fn version_docker(&self, value: &str) -> String {
+        format_template(self.strings().version_docker, &[("value", value)])
+    }
+
+    pub(crate) fn version_podman(&self, value: &str) -> String {
+        format_template(self.strings().version_podman, &[("value", value)])
+    }
+
+    pub(crate) fn version_compose_images(&self, value: &str) -> String {
+        format_template(self.strings().version_compose_images, &[("value", value)])
+    }
+
+    pub(crate) fn version_unavailable(&self) -> &'static str {
+        self.strings().version_unavailable
+    }
+
+    pub(crate) fn migrate_copied(&self, from: &str, to: &str) -> String {
+        format_template(self.strings().migrate_copied, &[("from", from), ("to", to)])
+    }
+
+    pub(crate) fn migrate_summary(&self, count: &str, from: &str, to: &str) -> String {
+        format_template(
+            self.strings().migrate_summary,
+            &[("count", count), ("from", from), ("to", to)],
+        )
+    }
+
+    pub(crate) fn migrate_state_updated(&self, mount: &str) -> String {
+        format_template(self.strings().migrate_state_updated, &[("mount", mount)])
+    }
+
+    pub(crate) fn migrate_state_prefix_updated(&self, prefix: &str) -> String {
+        format_template(
+            self.strings().migrate_state_prefix_updated,
+            &[("prefix", prefix)],
+        )
+    }
+
+    pub(crate) fn trust_export_source_kv(&self, path: &str) -> String {
+        format_template(self.strings().trust_export_source_kv, &[("path", path)])
+    }
+
+    pub(crate) fn trust_export_source_local(&self, dir: &str) -> String {
+        format_template(self.strings().trust_export_source_local, &[("dir", dir)])
+    }
+
+    pub(crate) fn trust_export_kv_unavailable(&self, reason: &str) -> String {
+        format_template(
+            self.strings().trust_export_kv_unavailable,
+            &[("reason", reason)],
+        )
+    }
+
+    pub(crate) fn trust_export_written(&self, format: &str, count: &str, path: &str) -> String {
+        format_template(
+            self.strings().trust_export_written,
+            &[("format", format), ("count", count), ("path", path)],
+        )
+    }
+
+    pub(crate) fn trust_export_fingerprints(&self) -> &'static str {
+        self.strings().trust_export_fingerprints
+    }
+
+    pub(crate) fn error_trust_export_no_certs(&self) -> &'static str {
+        self.strings().error_trust_export_no_certs
+    }
+
+    pub(crate) fn error_trust_export_der_multiple(&self, count: &str) -> String {
+        format_template(
+            self.strings().error_trust_export_der_multiple,
+            &[("count", count)],
+        )
+    }
+
+    pub(crate) fn trust_export_no_root_token(&self) -> &'static str {
+        self.strings().trust_export_no_root_token
+    }
+
+    pub(crate) fn trust_show_local_header(&self, dir: &str) -> String {
+        format_template(self.strings().trust_show_local_header, &[("dir", dir)])
+    }
+
+    pub(crate) fn trust_show_kv_header(&self, path: &str) -> String {
+        format_template(self.strings().trust_show_kv_header, &[("path", path)])
+    }
+
+    pub(crate) fn trust_show_kv_unavailable(&self, reason: &str) -> String {
+        format_template(
+            self.strings().trust_show_kv_unavailable,
+            &[("reason", reason)],
+        )
+    }
+
+    pub(crate) fn trust_show_cert(&self, role: &str, subject: &str) -> String {
+        format_template(
+            self.strings().trust_show_cert,
+            &[("role", role), ("subject", subject)],
+        )
+    }
+
+    pub(crate) fn trust_show_cert_sha256(&self, sha256: &str) -> String {
+        format_template(self.strings().trust_show_cert_sha256, &[("sha256", sha256)])
+    }
+
+    pub(crate) fn trust_show_cert_not_after(&self, not_after: &str) -> String {
+        format_template(
+            self.strings().trust_show_cert_not_after,
+            &[("not_after", not_after)],
+        )
+    }
+
+    pub(crate) fn trust_show_role_root(&self) -> &'static str {
+        self.strings().trust_show_role_root
+    }
+
+    pub(crate) fn trust_show_role_intermediate(&self) -> &'static str {
+        self.strings().trust_show_role_intermediate
+    }
+
+    pub(crate) fn trust_show_drift_none(&self) -> &'static str {
+        self.strings().trust_show_drift_none
+    }
+
+    pub(crate) fn trust_show_drift_detected(&self) -> &'static str {
+        self.strings().trust_show_drift_detected
+    }
+
+    pub(crate) fn migrate_confirm_delete(&self, count: &str, location: &str) -> String {
+        format_template(
+            self.strings().migrate_confirm_delete,
+            &[("count", count), ("location", location)],
+        )
+    }
+
+    pub(crate) fn migrate_source_deleted(&self, count: &str, location: &str) -> String {
+        format_template(
+            self.strings().migrate_source_deleted,
+            &[("count", count), ("location", location)],
+        )
+    }
+
+    pub(crate) fn migrate_source_kept(&self, location: &str) -> String {
+        format_template(
+            self.strings().migrate_source_kept,
+            &[("location", location)],
+        )
+    }
+
+    pub(crate) fn migrate_dual_write_started(&self, current: &str, legacy: &str) -> String {
+        format_template(
+            self.strings().migrate_dual_write_started,
+            &[("current", current), ("legacy", legacy)],
+        )
+    }
+
+    pub(crate) fn migrate_dual_write_finalized(&self, count: &str, location: &str) -> String {
+        format_template(
+            self.strings().migrate_dual_write_finalized,
+            &[("count", count), ("location", location)],
+        )
+    }
+
+    pub(crate) fn migrate_references_note(&self) -> &'static str {
+        self.strings().migrate_references_note
+    }
+
+    pub(crate) fn cancellation_requested(&self) -> &'static str {
+        self.strings().cancellation_requested
+    }
+
+    pub(crate) fn status_openbao_target_inferred(
+        &self,
+        url: &str,
+        source: &str,
+        kv_mount: &str,
+    ) -> String {
+        format_template(
+            self.strings().status_openbao_target_inferred,
+            &[("url", url), ("source", source), ("kv_mount", kv_mount)],
+        )
+    }
+
+    pub(crate) fn status_openbao_target_default_source(&self) -> &'static str {
+        self.strings().status_openbao_target_default_source
+    }
+
     pub(crate) fn status_summary_title(&self) -> &'static str {
         self.strings().status_summary_title
     }
 
+    pub(crate) fn error_serialize_status_report_failed(&self) -> &'static str {
+        self.strings().error_serialize_status_report_failed
+    }
+
     pub(crate) fn status_section_infra(&self) -> &'static str {
         self.strings().status_section_infra
     }
@@ -167,6 +411,13 @@ impl Messages {
         )
     }
 
+    pub(crate) fn status_service_cert_files(&self, service: &str, cert: &str, key: &str) -> String {
+        format_template(
+            self.strings().status_service_cert_files,
+            &[("service", service), ("cert", cert), ("key", key)],
+        )
+    }
+
     pub(crate) fn status_value_ok(&self) -> &'static str {
         self.strings().status_value_ok
     }
@@ -223,4 +474,52 @@ impl Messages {
     pub(crate) fn status_error_openbao_unreachable(&self) -> &'static str {
         self.strings().status_error_openbao_unreachable
     }
+
+    pub(crate) fn error_migrate_failed(&self) -> &'static str {
+        self.strings().error_migrate_failed
+    }
+
+    pub(crate) fn error_trust_export_failed(&self) -> &'static str {
+        self.strings().error_trust_export_failed
+    }
+
+    pub(crate) fn error_trust_show_failed(&self) -> &'static str {
+        self.strings().error_trust_show_failed
+    }
+
+    pub(crate) fn error_migrate_same_location(&self, location: &str) -> String {
+        format_template(
+            self.strings().error_migrate_same_location,
+            &[("location", location)],
+        )
+    }
+
+    pub(crate) fn error_migrate_mount_and_prefix(&self) -> &'static str {
+        self.strings().error_migrate_mount_and_prefix
+    }
+
+    pub(crate) fn error_migrate_source_empty(&self, location: &str) -> String {
+        format_template(
+            self.strings().error_migrate_source_empty,
+            &[("location", location)],
+        )
+    }
+
+    pub(crate) fn error_migrate_verify_failed(&self, path: &str) -> String {
+        format_template(
+            self.strings().error_migrate_verify_failed,
+            &[("path", path)],
+        )
+    }
+
+    pub(crate) fn error_migrate_dual_write_active(&self, location: &str) -> String {
+        format_template(
+            self.strings().error_migrate_dual_write_active,
+            &[("location", location)],
+        )
+    }
+
+    pub(crate) fn error_migrate_no_dual_write(&self) -> &'static str {
+        self.strings().error_migrate_no_dual_write
+    }
 }
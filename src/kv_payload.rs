@@ -1,18 +1,22 @@
-//! Shared parsers and validators for per-service `OpenBao` KV payloads.
+//! Shared parsers and validators for `OpenBao` KV payloads.
 //!
 //! The `bootroot-remote bootstrap` / `apply-secret-id` paths and the remote
 //! `bootroot-agent` fast-poll loop read the same KV paths
 //! (`{kv_mount}/data/bootroot/services/<service>/{trust,secret_id}`). This
 //! module owns one implementation of the payload validation so both callers
-//! agree on shape, required fields, and fingerprint formatting. Callers that
-//! need localized error text wrap these errors with their own context.
+//! agree on shape, required fields, and fingerprint formatting. The infra
+//! payloads the control node reads (step-ca password and DSN, responder
+//! HMAC, global CA trust) are parsed here too, behind the typed readers on
+//! [`crate::openbao::OpenBaoClient`]. Callers that need localized error text
+//! wrap these errors with their own context.
 
 use std::collections::HashSet;
 
 use anyhow::{Context, Result, bail};
 
 use crate::trust_bootstrap::{
-    CA_BUNDLE_PEM_KEY, EAB_HMAC_KEY, EAB_KID_KEY, HMAC_KEY, SECRET_ID_KEY, TRUSTED_CA_KEY,
+    CA_BUNDLE_PEM_KEY, EAB_HMAC_KEY, EAB_KID_KEY, HMAC_KEY, SECRET_ID_KEY, TRUST_GENERATION_KEY,
+    TRUSTED_CA_KEY,
 };
 
 /// Length in hex characters of a SHA-256 fingerprint.
@@ -159,6 +163,59 @@ pub fn parse_responder_hmac(data: &serde_json::Value) -> Result<String> {
     parse_required_string(data, &[HMAC_KEY, "value"])
 }
 
+/// Parses a `{ "value": <secret> }` infra payload (step-ca password,
+/// step-ca DSN, responder HMAC), returning the secret.
+///
+/// # Errors
+///
+/// Returns an error when `value` does not hold a non-empty string.
+pub fn parse_value_secret(data: &serde_json::Value) -> Result<String> {
+    parse_required_string(data, &["value"])
+}
+
+/// Parsed global CA trust payload (`bootroot/ca`): the fingerprints and
+/// bundle every service trust path is seeded from, plus the generation
+/// they were published under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaTrustPayload {
+    pub trusted_ca_sha256: Vec<String>,
+    pub ca_bundle_pem: String,
+    /// `0` for payloads written before the counter existed.
+    pub trust_generation: u64,
+}
+
+/// Parses the global CA trust payload.
+///
+/// Unlike [`parse_trust_payload`], the bundle is kept byte-for-byte (it is
+/// compared against and copied into service trust paths) and is not
+/// cross-checked against the fingerprints.
+///
+/// # Errors
+///
+/// Returns an error when `trusted_ca_sha256` is missing, empty, or contains a
+/// non-string / non-64-hex value, when `ca_bundle_pem` is missing or blank,
+/// or when `trust_generation` is present but not a non-negative integer.
+pub fn parse_ca_trust_payload(data: &serde_json::Value) -> Result<CaTrustPayload> {
+    let trusted_ca_sha256 = parse_fingerprints(data)?;
+    let ca_bundle_pem = data
+        .get(CA_BUNDLE_PEM_KEY)
+        .and_then(serde_json::Value::as_str)
+        .filter(|pem| !pem.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("Missing required string key: {CA_BUNDLE_PEM_KEY}"))?
+        .to_string();
+    let trust_generation = match data.get(TRUST_GENERATION_KEY) {
+        None => 0,
+        Some(value) => value.as_u64().ok_or_else(|| {
+            anyhow::anyhow!("{TRUST_GENERATION_KEY} must be a non-negative integer")
+        })?,
+    };
+    Ok(CaTrustPayload {
+        trusted_ca_sha256,
+        ca_bundle_pem,
+        trust_generation,
+    })
+}
+
 /// Reads the first non-empty string value among `keys`, trimmed.
 ///
 /// # Errors
@@ -416,4 +473,55 @@ mod tests {
         let data = serde_json::json!({ "kid": "the-kid", "hmac": 42 });
         assert!(parse_eab_payload(&data).is_err());
     }
+
+    #[test]
+    fn parse_ca_trust_payload_keeps_bundle_and_generation() {
+        let data = serde_json::json!({
+            "trusted_ca_sha256": ["a".repeat(64)],
+            "ca_bundle_pem": "pem\n",
+            "trust_generation": 3,
+        });
+        let parsed = parse_ca_trust_payload(&data).expect("parse ca trust");
+        assert_eq!(parsed.trusted_ca_sha256, vec!["a".repeat(64)]);
+        assert_eq!(parsed.ca_bundle_pem, "pem\n");
+        assert_eq!(parsed.trust_generation, 3);
+    }
+
+    #[test]
+    fn parse_ca_trust_payload_defaults_missing_generation_to_zero() {
+        let data = serde_json::json!({
+            "trusted_ca_sha256": ["a".repeat(64)],
+            "ca_bundle_pem": "pem",
+        });
+        let parsed = parse_ca_trust_payload(&data).expect("parse legacy ca trust");
+        assert_eq!(parsed.trust_generation, 0);
+    }
+
+    #[test]
+    fn parse_ca_trust_payload_rejects_missing_fingerprints() {
+        let data = serde_json::json!({ "ca_bundle_pem": "pem", "trust_generation": 1 });
+        let err = parse_ca_trust_payload(&data).expect_err("missing fingerprints");
+        assert_eq!(
+            err.to_string(),
+            "Missing required array key: trusted_ca_sha256"
+        );
+    }
+
+    #[test]
+    fn parse_ca_trust_payload_rejects_invalid_generation() {
+        let data = serde_json::json!({
+            "trusted_ca_sha256": ["a".repeat(64)],
+            "ca_bundle_pem": "pem",
+            "trust_generation": "2",
+        });
+        let err = parse_ca_trust_payload(&data).expect_err("string generation");
+        assert!(err.to_string().contains("trust_generation"));
+    }
+
+    #[test]
+    fn parse_value_secret_rejects_blank_value() {
+        let data = serde_json::json!({ "value": "  " });
+        let err = parse_value_secret(&data).expect_err("blank value");
+        assert_eq!(err.to_string(), "Missing required string key: value");
+    }
 }
@@ -8,11 +8,13 @@ pub mod cert_group;
 pub mod config;
 pub mod db;
 pub mod eab;
+pub mod env_compat;
 pub mod fs_util;
 pub mod hooks;
 pub mod input_validation;
 pub mod kv_payload;
 pub mod locale;
+pub mod metrics;
 pub mod openbao;
 pub mod profile;
 pub mod tls;
@@ -25,10 +27,11 @@ mod fast_poll;
 
 pub use agent_args::Args;
 
-/// Runs the agent daemon loop for all profiles.
+/// Runs the agent daemon loop for all profiles, recording renewal state
+/// into `metrics`.
 ///
 /// # Errors
-/// Returns an error if issuance or shutdown handling fails.
+/// Returns an error if a profile or fast-poll loop fails.
 pub async fn run_daemon(
     settings: Arc<config::Settings>,
     default_eab: Option<eab::EabCredentials>,
@@ -36,6 +39,7 @@ pub async fn run_daemon(
     config_path: Option<PathBuf>,
     insecure_mode: bool,
     cli_overrides: config::CliOverrides,
+    metrics: Arc<metrics::AgentMetrics>,
 ) -> anyhow::Result<()> {
     daemon::run_daemon(
         settings,
@@ -44,6 +48,7 @@ pub async fn run_daemon(
         config_path,
         insecure_mode,
         cli_overrides,
+        metrics,
     )
     .await
 }
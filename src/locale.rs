@@ -7,10 +7,12 @@ use anyhow::{Context, Result};
 pub enum Locale {
     En,
     Ko,
+    Fr,
 }
 
 impl Locale {
-    /// Parses a locale string, accepting forms like `"en"`, `"en-US"`, `"ko"`, `"ko-KR"`.
+    /// Parses a locale string, accepting forms like `"en"`, `"en-US"`,
+    /// `"ko"`, `"ko-KR"`, `"fr"`, `"fr-FR"`.
     ///
     /// The comparison is case-insensitive and the region subtag is ignored.
     ///
@@ -26,9 +28,33 @@ impl Locale {
         match base {
             "en" => Ok(Locale::En),
             "ko" => Ok(Locale::Ko),
+            "fr" => Ok(Locale::Fr),
             _ => anyhow::bail!("Unsupported language: {input}"),
         }
     }
+
+    /// Resolves a `--lang` value: `"auto"` detects the operating system
+    /// locale, anything else goes through [`Locale::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an explicit language code is not supported.
+    pub fn resolve(input: &str) -> Result<Self> {
+        if input.trim().eq_ignore_ascii_case("auto") {
+            return Ok(Self::from_detected(sys_locale::get_locale().as_deref()));
+        }
+        Self::parse(input)
+    }
+
+    /// Maps an OS-reported locale such as `"ko-KR"`, `"fr_FR.UTF-8"`, or
+    /// `"zh-Hans-CN"` to a supported locale, falling back to English when
+    /// nothing was detected or the language is unsupported.
+    fn from_detected(detected: Option<&str>) -> Self {
+        detected
+            .and_then(|tag| tag.split(['-', '_', '.', '@']).next())
+            .and_then(|language| Self::parse(language).ok())
+            .unwrap_or(Self::En)
+    }
 }
 
 impl FromStr for Locale {
@@ -56,12 +82,45 @@ mod tests {
         assert_eq!(Locale::parse("ko-KR").unwrap(), Locale::Ko);
     }
 
+    #[test]
+    fn parse_fr_variants() {
+        assert_eq!(Locale::parse("fr").unwrap(), Locale::Fr);
+        assert_eq!(Locale::parse("fr-FR").unwrap(), Locale::Fr);
+    }
+
     #[test]
     fn parse_invalid_returns_error() {
-        let err = Locale::parse("fr").unwrap_err();
+        let err = Locale::parse("de").unwrap_err();
         assert!(err.to_string().contains("Unsupported language"));
     }
 
+    #[test]
+    fn resolve_passes_explicit_languages_to_parse() {
+        assert_eq!(Locale::resolve("ko-KR").unwrap(), Locale::Ko);
+        assert!(Locale::resolve("de").is_err());
+    }
+
+    #[test]
+    fn resolve_auto_never_fails() {
+        assert!(Locale::resolve("auto").is_ok());
+        assert!(Locale::resolve("AUTO").is_ok());
+    }
+
+    #[test]
+    fn from_detected_maps_supported_locales() {
+        assert_eq!(Locale::from_detected(Some("ko-KR")), Locale::Ko);
+        assert_eq!(Locale::from_detected(Some("fr_FR.UTF-8")), Locale::Fr);
+        assert_eq!(Locale::from_detected(Some("en-GB")), Locale::En);
+    }
+
+    #[test]
+    fn from_detected_falls_back_to_en_for_unsupported_locales() {
+        assert_eq!(Locale::from_detected(Some("de-DE")), Locale::En);
+        assert_eq!(Locale::from_detected(Some("zh-Hans-CN")), Locale::En);
+        assert_eq!(Locale::from_detected(Some("")), Locale::En);
+        assert_eq!(Locale::from_detected(None), Locale::En);
+    }
+
     #[test]
     fn from_str_delegates_to_parse() {
         assert_eq!("en".parse::<Locale>().unwrap(), Locale::En);
@@ -1,4 +1,5 @@
 use std::process::ExitCode;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 
@@ -11,7 +12,9 @@ use clap::Parser;
 
 use crate::cli::args::{
     CaCommand, Cli, CliCommand, InfraCommand, MonitoringCommand, OpenbaoCommand, ServiceCommand,
+    TrustCommand,
 };
+use crate::commands::cancellation;
 use crate::commands::rotate::RotateOutcome;
 use crate::i18n::Messages;
 
@@ -22,6 +25,8 @@ use crate::i18n::Messages;
 const EXIT_CODE_WAIT_TIMEOUT: u8 = 124;
 
 fn main() -> ExitCode {
+    // SAFETY: runs first in `main`, before any runtime or thread starts.
+    unsafe { bootroot::env_compat::apply_vault_env_aliases() };
     let cli = Cli::parse();
     let messages = match Messages::new(&cli.lang) {
         Ok(messages) => messages,
@@ -30,6 +35,16 @@ fn main() -> ExitCode {
             return ExitCode::from(1);
         }
     };
+    let cli = match cli::config_file::reparse_with_config_file(cli, std::env::args_os(), &messages)
+    {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(1);
+        }
+    };
+    init_verbose_logging(cli.verbose);
+    commands::container_runtime::select(cli.container_runtime);
     match run(cli, &messages) {
         Ok(code) => code,
         Err(err) => {
@@ -41,11 +56,36 @@ fn main() -> ExitCode {
             for cause in err.chain().skip(1) {
                 eprintln!("{}", messages.error_details(&cause.to_string()));
             }
+            // Checked first: an expired deadline also requests cancellation.
+            if cancellation::is_deadline_error(&err) {
+                return ExitCode::from(cancellation::EXIT_CODE_DEADLINE_EXCEEDED);
+            }
+            if cancellation::is_cancelled_error(&err) {
+                return ExitCode::from(cancellation::EXIT_CODE_CANCELLED);
+            }
             ExitCode::from(1)
         }
     }
 }
 
+/// Installs a stderr log subscriber for `--verbose`.
+///
+/// Without the flag no subscriber is installed, so the `OpenBao` request
+/// logging stays silent.
+fn init_verbose_logging(verbose: u8) {
+    let level = match verbose {
+        0 => return,
+        1 => "debug",
+        _ => "trace",
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::new(format!(
+            "bootroot={level}"
+        )))
+        .with_writer(std::io::stderr)
+        .init();
+}
+
 /// Creates a Tokio runtime and passes it to `f`, returning its result.
 ///
 /// # Errors
@@ -62,16 +102,39 @@ where
 
 #[allow(clippy::too_many_lines)] // Top-level CLI dispatcher.
 fn run(cli: Cli, messages: &Messages) -> Result<ExitCode> {
+    let verbose = cli.verbose > 0;
+    let deadline = cli.operation_timeout.map(Duration::from_secs);
     match cli.command {
         CliCommand::Infra(InfraCommand::Up(args)) => {
             with_runtime("infra up", messages, |rt| {
-                rt.block_on(commands::infra::run_infra_up(&args, messages))
+                cancellation::install_sigint_handler(rt, messages);
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::infra::run_infra_up(&args, messages),
+                ))
             })?
             .with_context(|| messages.error_infra_failed())?;
         }
         CliCommand::Infra(InfraCommand::Install(args)) => {
-            commands::infra::run_infra_install(&args, messages)
-                .with_context(|| messages.error_infra_install_failed())?;
+            with_runtime("infra install", messages, |rt| {
+                rt.block_on(commands::infra::run_infra_install(&args, messages))
+            })?
+            .with_context(|| messages.error_infra_install_failed())?;
+        }
+        CliCommand::Infra(InfraCommand::Down(args)) => {
+            commands::infra::run_infra_down(&args, messages)
+                .with_context(|| messages.error_infra_down_failed())?;
+        }
+        CliCommand::Infra(InfraCommand::Restart(args)) => {
+            with_runtime("infra restart", messages, |rt| {
+                rt.block_on(commands::infra::run_infra_restart(&args, messages))
+            })?
+            .with_context(|| messages.error_infra_restart_failed())?;
+        }
+        CliCommand::Infra(InfraCommand::Logs(args)) => {
+            commands::infra::run_infra_logs(&args, messages)
+                .with_context(|| messages.error_infra_logs_failed())?;
         }
         CliCommand::Monitoring(MonitoringCommand::Up(args)) => {
             commands::monitoring::run_monitoring_up(&args, messages)
@@ -87,25 +150,41 @@ fn run(cli: Cli, messages: &Messages) -> Result<ExitCode> {
         }
         CliCommand::Init(args) => {
             with_runtime("init", messages, |rt| {
-                rt.block_on(commands::init::run_init(&args, messages))
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::init::run_init(&args, messages),
+                ))
             })?
             .with_context(|| messages.error_init_failed())?;
         }
         CliCommand::Reinit(args) => {
             with_runtime("reinit", messages, |rt| {
-                rt.block_on(commands::reinit::run_reinit(&args, messages))
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::reinit::run_reinit(&args, messages),
+                ))
             })?
             .with_context(|| messages.error_reinit_failed())?;
         }
         CliCommand::Status(args) => {
             with_runtime("status", messages, |rt| {
-                rt.block_on(commands::status::run_status(&args, messages))
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::status::run_status(&args, messages),
+                ))
             })?
             .with_context(|| messages.error_status_failed())?;
         }
         CliCommand::Service(ServiceCommand::Add(args)) => {
             with_runtime("service add", messages, |rt| {
-                rt.block_on(commands::service::run_service_add(&args, messages))
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::service::run_service_add(&args, messages),
+                ))
             })?
             .with_context(|| messages.error_service_add_failed())?;
         }
@@ -113,13 +192,21 @@ fn run(cli: Cli, messages: &Messages) -> Result<ExitCode> {
             commands::service::run_service_info(&args, messages)
                 .with_context(|| messages.error_service_info_failed())?;
         }
+        CliCommand::Service(ServiceCommand::List(args)) => {
+            commands::service::run_service_list(&args, messages)
+                .with_context(|| messages.error_service_list_failed())?;
+        }
         CliCommand::Service(ServiceCommand::Update(args)) => {
             commands::service::run_service_update(&args, messages)
                 .with_context(|| messages.error_service_update_failed())?;
         }
         CliCommand::Service(ServiceCommand::Remove(args)) => {
             with_runtime("service remove", messages, |rt| {
-                rt.block_on(commands::service::run_service_remove(&args, messages))
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::service::run_service_remove(&args, messages),
+                ))
             })?
             .with_context(|| messages.error_service_remove_failed())?;
         }
@@ -127,7 +214,12 @@ fn run(cli: Cli, messages: &Messages) -> Result<ExitCode> {
             .with_context(|| messages.error_verify_failed())?,
         CliCommand::Rotate(args) => {
             let outcome = with_runtime("rotate", messages, |rt| {
-                rt.block_on(commands::rotate::run_rotate(&args, messages))
+                cancellation::install_sigint_handler(rt, messages);
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::rotate::run_rotate(&args, messages),
+                ))
             })?
             .with_context(|| messages.error_rotate_failed())?;
             if matches!(outcome, RotateOutcome::WaitTimedOut) {
@@ -154,6 +246,58 @@ fn run(cli: Cli, messages: &Messages) -> Result<ExitCode> {
             commands::ca::run_ca_restart(&args, messages)
                 .with_context(|| "ca restart failed".to_string())?;
         }
+        CliCommand::Trust(TrustCommand::Export(args)) => {
+            with_runtime("trust export", messages, |rt| {
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::trust::run_trust_export(&args, messages),
+                ))
+            })?
+            .with_context(|| messages.error_trust_export_failed())?;
+        }
+        CliCommand::Trust(TrustCommand::Show(args)) => {
+            with_runtime("trust show", messages, |rt| {
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::trust::run_trust_show(&args, messages),
+                ))
+            })?
+            .with_context(|| messages.error_trust_show_failed())?;
+        }
+        CliCommand::Migrate(args) => {
+            with_runtime("migrate", messages, |rt| {
+                rt.block_on(cancellation::with_deadline(
+                    deadline,
+                    messages,
+                    commands::migrate::run_migrate(&args, messages),
+                ))
+            })?
+            .with_context(|| messages.error_migrate_failed())?;
+        }
+        CliCommand::AuditSecrets(args) => {
+            commands::audit_secrets::run_audit_secrets(&args, messages)
+                .with_context(|| messages.error_audit_secrets_failed())?;
+        }
+        CliCommand::Version(args) => {
+            commands::version::run_version(&args, verbose, messages);
+        }
+        CliCommand::SupportBundle(args) => {
+            commands::support_bundle::run_support_bundle(&args, messages)
+                .with_context(|| messages.error_support_bundle_failed())?;
+        }
+        #[cfg(feature = "self-test")]
+        CliCommand::SelfTest(args) => {
+            // The runtime only hosts the SIGINT handler: a first Ctrl-C
+            // stops the current step instead of killing bootroot before
+            // it can tear the stack down.
+            with_runtime("self-test", messages, |rt| {
+                cancellation::install_sigint_handler(rt, messages);
+                commands::self_test::run_self_test(&args, messages)
+            })?
+            .with_context(|| messages.error_self_test_failed())?;
+        }
     }
     Ok(ExitCode::SUCCESS)
 }
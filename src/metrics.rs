@@ -0,0 +1,293 @@
+//! Liveness and renewal metrics for the agent daemon (`--metrics-addr`).
+//!
+//! The daemon records per-profile renewal outcomes and certificate expiry
+//! into [`AgentMetrics`]; when `--metrics-addr` is set, `/healthz` answers
+//! `ok` while the agent runs and `/metrics` renders the values in the
+//! Prometheus text format. The server outlives HUP reloads, so the values
+//! carry over when the daemon restarts with a new config; the series of a
+//! profile the new config no longer lists are dropped.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use anyhow::Context;
+use poem::listener::{Listener, TcpListener};
+use poem::web::Data;
+use poem::{Endpoint, EndpointExt, Response, Route, Server, handler};
+use time::OffsetDateTime;
+use tokio::task::JoinHandle;
+
+use crate::daemon::parse_cert_not_after;
+
+const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Metric name suffix, `# HELP` text, and per-profile value of one gauge.
+type Gauge = (
+    &'static str,
+    &'static str,
+    fn(&ProfileMetrics) -> Option<i64>,
+);
+
+#[derive(Debug, Default, Clone, Copy)]
+struct ProfileMetrics {
+    last_renewal: Option<i64>,
+    consecutive_failures: u64,
+    cert_expiry: Option<i64>,
+    next_renewal: Option<i64>,
+}
+
+/// Per-profile renewal state shared by the daemon loops and the metrics
+/// endpoint, keyed by profile domain.
+#[derive(Debug, Default)]
+pub struct AgentMetrics {
+    profiles: Mutex<BTreeMap<String, ProfileMetrics>>,
+}
+
+impl AgentMetrics {
+    fn update(&self, profile: &str, apply: impl FnOnce(&mut ProfileMetrics)) {
+        let mut profiles = self.profiles.lock().unwrap_or_else(PoisonError::into_inner);
+        apply(profiles.entry(profile.to_string()).or_default());
+    }
+
+    /// Records the outcome of an issuance for `profile` that finished at
+    /// `now`.
+    pub(crate) fn record_issuance(&self, profile: &str, succeeded: bool, now: OffsetDateTime) {
+        self.update(profile, |metrics| {
+            if succeeded {
+                metrics.last_renewal = Some(now.unix_timestamp());
+                metrics.consecutive_failures = 0;
+            } else {
+                metrics.consecutive_failures += 1;
+            }
+        });
+    }
+
+    /// Records the expiry of the certificate at `cert_path` and when it
+    /// enters the `renew_before` window. A missing or unreadable
+    /// certificate clears both.
+    pub(crate) async fn observe_cert(
+        &self,
+        profile: &str,
+        cert_path: &Path,
+        renew_before: Duration,
+    ) {
+        let not_after = tokio::fs::read(cert_path)
+            .await
+            .ok()
+            .and_then(|bytes| parse_cert_not_after(&bytes).ok());
+        let renew_before = time::Duration::try_from(renew_before).unwrap_or(time::Duration::MAX);
+        self.update(profile, |metrics| {
+            metrics.cert_expiry = not_after.map(OffsetDateTime::unix_timestamp);
+            metrics.next_renewal = not_after
+                .and_then(|not_after| not_after.checked_sub(renew_before))
+                .map(OffsetDateTime::unix_timestamp);
+        });
+    }
+
+    /// Drops the recorded values of every profile not in `profiles`, so a
+    /// profile removed by a config reload stops being exported.
+    pub fn retain_profiles(&self, profiles: &BTreeSet<String>) {
+        self.profiles
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|profile, _| profiles.contains(profile));
+    }
+
+    /// Renders every recorded value in the Prometheus text format.
+    pub fn render(&self) -> String {
+        let profiles = self
+            .profiles
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let gauges: [Gauge; 4] = [
+            (
+                "last_renewal_unixtime",
+                "Unix time of the last successful renewal.",
+                |metrics| metrics.last_renewal,
+            ),
+            (
+                "next_renewal_unixtime",
+                "Unix time the certificate enters its renew_before window.",
+                |metrics| metrics.next_renewal,
+            ),
+            (
+                "consecutive_failures",
+                "Issuance attempts that failed since the last success.",
+                |metrics| i64::try_from(metrics.consecutive_failures).ok(),
+            ),
+            (
+                "cert_expiry_unixtime",
+                "Unix time the current certificate expires.",
+                |metrics| metrics.cert_expiry,
+            ),
+        ];
+        let mut out = String::new();
+        for (name, help, value) in gauges {
+            let _ = writeln!(out, "# HELP bootroot_agent_{name} {help}");
+            let _ = writeln!(out, "# TYPE bootroot_agent_{name} gauge");
+            for (profile, metrics) in &profiles {
+                if let Some(value) = value(metrics) {
+                    let _ = writeln!(
+                        out,
+                        "bootroot_agent_{name}{{profile=\"{}\"}} {value}",
+                        escape_label(profile)
+                    );
+                }
+            }
+        }
+        out
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[handler]
+fn healthz_handler() -> &'static str {
+    "ok\n"
+}
+
+#[handler]
+fn metrics_handler(Data(metrics): Data<&Arc<AgentMetrics>>) -> Response {
+    Response::builder()
+        .content_type(METRICS_CONTENT_TYPE)
+        .body(metrics.render())
+}
+
+fn app(metrics: Arc<AgentMetrics>) -> impl Endpoint {
+    Route::new()
+        .at("/healthz", poem::get(healthz_handler))
+        .at("/metrics", poem::get(metrics_handler))
+        .data(metrics)
+}
+
+/// Binds `addr` and serves `/healthz` and `/metrics` from `metrics` on a
+/// background task for the rest of the process.
+///
+/// # Errors
+/// Returns an error if `addr` cannot be bound.
+pub async fn spawn_server(
+    addr: SocketAddr,
+    metrics: Arc<AgentMetrics>,
+) -> anyhow::Result<JoinHandle<()>> {
+    let acceptor = TcpListener::bind(addr)
+        .into_acceptor()
+        .await
+        .with_context(|| format!("Failed to bind metrics address {addr}"))?;
+    tracing::info!("Serving agent metrics on {addr}");
+    Ok(tokio::spawn(async move {
+        if let Err(err) = Server::new_with_acceptor(acceptor).run(app(metrics)).await {
+            tracing::error!("Agent metrics server on {addr} stopped: {err}");
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use poem::Request;
+    use poem::http::StatusCode;
+
+    use super::*;
+
+    const PROFILE: &str = "001.edge-proxy.edge-node-01.trusted.domain";
+
+    async fn get(metrics: &Arc<AgentMetrics>, path: &str) -> (StatusCode, String) {
+        let response = app(Arc::clone(metrics))
+            .get_response(Request::builder().uri_str(path).finish())
+            .await;
+        let status = response.status();
+        let body = response.into_body().into_string().await.unwrap();
+        (status, body)
+    }
+
+    #[tokio::test]
+    async fn metrics_reflect_successful_renewal_after_failures() {
+        let metrics = Arc::new(AgentMetrics::default());
+        metrics.record_issuance(
+            PROFILE,
+            false,
+            OffsetDateTime::from_unix_timestamp(100).unwrap(),
+        );
+        metrics.record_issuance(
+            PROFILE,
+            false,
+            OffsetDateTime::from_unix_timestamp(200).unwrap(),
+        );
+
+        let (_, body) = get(&metrics, "/metrics").await;
+        assert!(body.contains(&format!(
+            "bootroot_agent_consecutive_failures{{profile=\"{PROFILE}\"}} 2"
+        )));
+        assert!(!body.contains("bootroot_agent_last_renewal_unixtime{"));
+
+        let renewed_at = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+        metrics.record_issuance(PROFILE, true, renewed_at);
+
+        let (status, body) = get(&metrics, "/metrics").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains(&format!(
+            "bootroot_agent_last_renewal_unixtime{{profile=\"{PROFILE}\"}} 1700000000"
+        )));
+        assert!(body.contains(&format!(
+            "bootroot_agent_consecutive_failures{{profile=\"{PROFILE}\"}} 0"
+        )));
+    }
+
+    #[tokio::test]
+    async fn observe_cert_records_expiry_and_renewal_window() {
+        let dir = tempfile::tempdir().unwrap();
+        let cert_path = dir.path().join("cert.pem");
+        let not_after = OffsetDateTime::from_unix_timestamp(1_900_000_000).unwrap();
+        let mut params = rcgen::CertificateParams::new(vec!["edge.internal".to_string()]).unwrap();
+        params.not_after = not_after;
+        let key = rcgen::KeyPair::generate().unwrap();
+        std::fs::write(&cert_path, params.self_signed(&key).unwrap().pem()).unwrap();
+        let metrics = Arc::new(AgentMetrics::default());
+
+        metrics
+            .observe_cert(PROFILE, &cert_path, Duration::from_hours(1))
+            .await;
+
+        let body = metrics.render();
+        assert!(body.contains(&format!(
+            "bootroot_agent_cert_expiry_unixtime{{profile=\"{PROFILE}\"}} 1900000000"
+        )));
+        assert!(body.contains(&format!(
+            "bootroot_agent_next_renewal_unixtime{{profile=\"{PROFILE}\"}} 1899996400"
+        )));
+    }
+
+    #[test]
+    fn retain_profiles_drops_removed_profiles() {
+        const REMOVED: &str = "002.edge-proxy.edge-node-01.trusted.domain";
+        let metrics = AgentMetrics::default();
+        let now = OffsetDateTime::from_unix_timestamp(100).unwrap();
+        metrics.record_issuance(PROFILE, false, now);
+        metrics.record_issuance(REMOVED, false, now);
+
+        metrics.retain_profiles(&BTreeSet::from([PROFILE.to_string()]));
+
+        let body = metrics.render();
+        assert!(body.contains(&format!(
+            "bootroot_agent_consecutive_failures{{profile=\"{PROFILE}\"}} 1"
+        )));
+        assert!(!body.contains(REMOVED), "{body}");
+    }
+
+    #[tokio::test]
+    async fn healthz_answers_ok() {
+        let metrics = Arc::new(AgentMetrics::default());
+        let (status, body) = get(&metrics, "/healthz").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "ok\n");
+    }
+}
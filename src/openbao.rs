@@ -1,19 +1,125 @@
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::kv_payload::{CaTrustPayload, parse_ca_trust_payload, parse_value_secret};
+
+mod trace;
+
+pub use trace::{redact_secret_fields, redact_secret_text};
+
 const VAULT_TOKEN_HEADER: &str = "X-Vault-Token";
 const VAULT_WRAP_TTL_HEADER: &str = "X-Vault-Wrap-TTL";
 const ROOT_POLICY: &str = "root";
+/// How long a `sys/mounts/<mount>` lookup is reused before re-fetching.
+const MOUNT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// KV path of the step-ca key password (`{ "value": <password> }`).
+pub const KV_PATH_STEPCA_PASSWORD: &str = "bootroot/stepca/password";
+/// KV path of the runtime step-ca database DSN (`{ "value": <dsn> }`).
+pub const KV_PATH_STEPCA_DB: &str = "bootroot/stepca/db";
+/// KV path of the HTTP-01 responder HMAC (`{ "value": <hmac> }`).
+pub const KV_PATH_RESPONDER_HMAC: &str = "bootroot/responder/hmac";
+/// KV path of the global CA trust payload, parsed by
+/// [`crate::kv_payload::parse_ca_trust_payload`].
+pub const KV_PATH_CA_TRUST: &str = "bootroot/ca";
 
 #[derive(Debug, Clone)]
 pub struct OpenBaoClient {
     base_url: String,
     client: Client,
     token: Option<String>,
+    kv_dual_write: Option<KvDualWrite>,
+    mounts: MountCache,
+}
+
+/// Short-lived cache of `sys/mounts/<mount>` lookups, shared by clones of
+/// one client so repeated checks within a command hit `OpenBao` once.
+#[derive(Debug, Clone, Default)]
+struct MountCache {
+    entries: Arc<Mutex<HashMap<String, CachedMount>>>,
+}
+
+/// One cached lookup; `data` is `None` when the mount did not exist.
+#[derive(Debug, Clone)]
+struct CachedMount {
+    fetched_at: Instant,
+    data: Option<MountData>,
+}
+
+impl MountCache {
+    fn get(&self, mount: &str) -> Option<CachedMount> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(mount)
+            .filter(|entry| entry.fetched_at.elapsed() < MOUNT_CACHE_TTL)
+            .cloned()
+    }
+
+    fn insert(&self, mount: &str, data: Option<MountData>) {
+        if let Ok(mut entries) = self.entries.lock() {
+            let fetched_at = Instant::now();
+            entries.insert(mount.to_string(), CachedMount { fetched_at, data });
+        }
+    }
+
+    fn invalidate(&self, mount: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(mount);
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+/// A KV v2 mount plus the path prefix bootroot's secrets live under.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KvLayout {
+    pub mount: String,
+    pub prefix: String,
+}
+
+impl KvLayout {
+    /// Returns `path` relative to this layout's prefix, or `None` when
+    /// `mount`/`path` lives elsewhere.
+    fn strip<'a>(&self, mount: &str, path: &'a str) -> Option<&'a str> {
+        if mount != self.mount {
+            return None;
+        }
+        path.strip_prefix(self.prefix.trim_end_matches('/'))
+            .and_then(|rest| rest.strip_prefix('/'))
+    }
+}
+
+/// Transitional KV layout change during which every secret written under
+/// `current` is also written to the same relative path under `legacy`,
+/// so agents still rendering from the old paths keep resolving.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KvDualWrite {
+    pub current: KvLayout,
+    pub legacy: KvLayout,
+}
+
+impl KvDualWrite {
+    /// Returns the legacy `(mount, path)` a write to `mount`/`path` must
+    /// be mirrored to, if any.
+    #[must_use]
+    pub fn legacy_target(&self, mount: &str, path: &str) -> Option<(String, String)> {
+        let relative = self.current.strip(mount, path)?;
+        let legacy_path = format!("{}/{relative}", self.legacy.prefix.trim_end_matches('/'));
+        (self.legacy.mount != mount || legacy_path != path)
+            .then(|| (self.legacy.mount.clone(), legacy_path))
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -44,6 +150,12 @@ pub struct InitResponse {
     pub keys: Vec<String>,
     #[serde(default)]
     pub keys_base64: Vec<String>,
+    /// Recovery keys returned instead of unseal keys when the server
+    /// uses an auto-unseal seal (transit/KMS).
+    #[serde(default)]
+    pub recovery_keys: Vec<String>,
+    #[serde(default)]
+    pub recovery_keys_base64: Vec<String>,
     pub root_token: String,
 }
 
@@ -52,7 +164,7 @@ struct MountResponse {
     data: MountData,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct MountData {
     #[serde(rename = "type")]
     mount_type: String,
@@ -60,7 +172,7 @@ struct MountData {
     options: Option<MountOptions>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct MountOptions {
     #[serde(default)]
     version: Option<String>,
@@ -191,21 +303,47 @@ fn is_not_found(status: StatusCode, text: &str) -> bool {
         || (status == StatusCode::BAD_REQUEST && text.contains("No secret engine mount"))
 }
 
+/// Strips trailing slashes from a user-supplied `OpenBao` base URL so
+/// request paths never become `//v1/...`.
+///
+/// # Errors
+/// Returns an error when the URL path already contains the `/v1` API
+/// prefix, which would otherwise produce `/v1/v1/...` request paths.
+fn normalize_base_url(base_url: &str) -> Result<String> {
+    let trimmed = base_url.trim().trim_end_matches('/');
+    if let Ok(url) = reqwest::Url::parse(trimmed)
+        && url
+            .path_segments()
+            .is_some_and(|mut segments| segments.any(|segment| segment == "v1"))
+    {
+        anyhow::bail!("OpenBao URL must be the server root without the /v1 API prefix: {base_url}");
+    }
+    Ok(trimmed.to_string())
+}
+
 impl OpenBaoClient {
     /// Creates a new `OpenBao` client targeting the provided base URL.
     ///
+    /// Trailing slashes are stripped from `base_url`.
+    ///
     /// # Errors
-    /// Returns an error if the HTTP client cannot be initialized.
+    /// Returns an error if `base_url` includes the `/v1` API prefix or the
+    /// HTTP client cannot be initialized.
     pub fn new(base_url: &str) -> Result<Self> {
+        let base_url = normalize_base_url(base_url)?;
         let client = Client::builder()
             .connect_timeout(crate::tls::OPENBAO_CONNECT_TIMEOUT)
             .timeout(crate::tls::OPENBAO_REQUEST_TIMEOUT)
+            .redirect(crate::tls::openbao_redirect_policy())
+            .user_agent(crate::tls::openbao_user_agent())
             .build()
             .context("Failed to build OpenBao HTTP client")?;
         Ok(Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
+            base_url,
             client,
             token: None,
+            kv_dual_write: None,
+            mounts: MountCache::default(),
         })
     }
 
@@ -218,14 +356,17 @@ impl OpenBaoClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if the PEM content cannot be parsed or the HTTP
-    /// client fails to build.
+    /// Returns an error if `base_url` includes the `/v1` API prefix, the
+    /// PEM content cannot be parsed, or the HTTP client fails to build.
     pub fn with_pem_trust(base_url: &str, ca_pem: &str, pins: &[String]) -> Result<Self> {
+        let base_url = normalize_base_url(base_url)?;
         let client = crate::tls::build_http_client_from_pem(ca_pem, pins)?;
         Ok(Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
+            base_url,
             client,
             token: None,
+            kv_dual_write: None,
+            mounts: MountCache::default(),
         })
     }
 
@@ -266,9 +407,11 @@ impl OpenBaoClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if a bundle file exists but cannot be read or
-    /// parsed, or if the HTTP client fails to build.
+    /// Returns an error if `base_url` includes the `/v1` API prefix, if a
+    /// bundle file exists but cannot be read or parsed, or if the HTTP
+    /// client fails to build.
     pub fn with_local_trust(base_url: &str, secrets_dir: &std::path::Path) -> Result<Self> {
+        let base_url = normalize_base_url(base_url)?;
         if base_url.starts_with("https://") {
             let certs_dir = secrets_dir.join("certs");
             let root_path = certs_dir.join("root_ca.crt");
@@ -295,28 +438,72 @@ impl OpenBaoClient {
                 }
                 let client = crate::tls::build_http_client_with_local_and_webpki_roots(&ca_pem)?;
                 return Ok(Self {
-                    base_url: base_url.trim_end_matches('/').to_string(),
+                    base_url,
                     client,
                     token: None,
+                    kv_dual_write: None,
+                    mounts: MountCache::default(),
                 });
             }
         }
-        Self::new(base_url)
+        Self::new(&base_url)
     }
 
     /// Creates a new `OpenBao` client with a pre-configured
     /// [`reqwest::Client`].
-    #[must_use]
-    pub fn with_client(base_url: &str, client: Client) -> Self {
-        Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
+    ///
+    /// The caller's client is used as-is, including its `User-Agent`.
+    ///
+    /// # Errors
+    /// Returns an error if `base_url` includes the `/v1` API prefix.
+    pub fn with_client(base_url: &str, client: Client) -> Result<Self> {
+        Ok(Self {
+            base_url: normalize_base_url(base_url)?,
             client,
             token: None,
-        }
+            kv_dual_write: None,
+            mounts: MountCache::default(),
+        })
     }
 
     pub fn set_token(&mut self, token: String) {
         self.token = Some(token);
+        // Mount visibility depends on the token's policies.
+        self.mounts.clear();
+    }
+
+    /// Mirrors every later KV write under `dual.current` to `dual.legacy`
+    /// until cleared with `None`.
+    pub fn set_kv_dual_write(&mut self, dual: Option<KvDualWrite>) {
+        self.kv_dual_write = dual;
+    }
+
+    /// Writes `data` to the legacy location of `mount`/`path` when a
+    /// dual-write transition covers it.
+    async fn mirror_kv_write(
+        &self,
+        mount: &str,
+        path: &str,
+        data: serde_json::Value,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct KvRequest {
+            data: serde_json::Value,
+        }
+        let Some((legacy_mount, legacy_path)) = self
+            .kv_dual_write
+            .as_ref()
+            .and_then(|dual| dual.legacy_target(mount, path))
+        else {
+            return Ok(());
+        };
+        validate_kv_mount(&legacy_mount)?;
+        self.post_action(
+            &format!("{legacy_mount}/data/{legacy_path}"),
+            &KvRequest { data },
+        )
+        .await
+        .with_context(|| format!("Failed to mirror KV write to {legacy_mount}/{legacy_path}"))
     }
 
     /// Checks the `OpenBao` health endpoint.
@@ -327,9 +514,7 @@ impl OpenBaoClient {
     pub async fn health_check(&self) -> Result<()> {
         let url = self.endpoint("sys/health");
         let response = self
-            .client
-            .get(url)
-            .send()
+            .execute(self.client.get(url))
             .await
             .context("OpenBao health check failed")?;
         let status = response.status();
@@ -370,14 +555,38 @@ impl OpenBaoClient {
             secret_threshold: u8,
         }
         let url = self.endpoint("sys/init");
+        let request = self.client.post(url).json(&InitRequest {
+            secret_shares: shares,
+            secret_threshold: threshold,
+        });
         let response = self
-            .client
-            .post(url)
-            .json(&InitRequest {
-                secret_shares: shares,
-                secret_threshold: threshold,
-            })
-            .send()
+            .execute(request)
+            .await
+            .context("OpenBao init request failed")?;
+        Self::parse_response(response).await
+    }
+
+    /// Initializes an auto-unseal (transit/KMS) `OpenBao` with the
+    /// provided recovery shares and threshold.
+    ///
+    /// The seal unseals the server itself, so the response carries
+    /// recovery keys rather than unseal keys.
+    ///
+    /// # Errors
+    /// Returns an error if initialization fails or the response is invalid.
+    pub async fn init_with_recovery_keys(&self, shares: u8, threshold: u8) -> Result<InitResponse> {
+        #[derive(Serialize)]
+        struct RecoveryInitRequest {
+            recovery_shares: u8,
+            recovery_threshold: u8,
+        }
+        let url = self.endpoint("sys/init");
+        let request = self.client.post(url).json(&RecoveryInitRequest {
+            recovery_shares: shares,
+            recovery_threshold: threshold,
+        });
+        let response = self
+            .execute(request)
             .await
             .context("OpenBao init request failed")?;
         Self::parse_response(response).await
@@ -402,10 +611,7 @@ impl OpenBaoClient {
         }
         let url = self.endpoint("sys/unseal");
         let response = self
-            .client
-            .post(url)
-            .json(&UnsealRequest { key })
-            .send()
+            .execute(self.client.post(url).json(&UnsealRequest { key }))
             .await
             .context("OpenBao unseal request failed")?;
         Self::parse_response(response).await
@@ -495,16 +701,26 @@ impl OpenBaoClient {
     /// Ensures a KV v2 secrets engine is mounted at the given path.
     ///
     /// # Errors
-    /// Returns an error if the mount exists with the wrong type/version or if
-    /// enabling the mount fails.
+    /// Returns an error if the mount name is invalid, the mount exists with
+    /// the wrong type/version, or enabling the mount fails.
     pub async fn ensure_kv_v2(&self, mount: &str) -> Result<()> {
+        validate_kv_mount(mount)?;
         if let Some(data) = self.get_mount(mount).await? {
             if data.mount_type != "kv" {
-                anyhow::bail!("Mount {mount} exists but is not KV");
+                anyhow::bail!(
+                    "Mount {mount} is type {}, expected kv; choose another --kv-mount",
+                    data.mount_type
+                );
             }
             let version = data.options.and_then(|opt| opt.version);
             if version.as_deref() != Some("2") {
-                anyhow::bail!("Mount {mount} exists but is not KV v2");
+                // KV mounts created without a version option are v1.
+                let version = version.unwrap_or_else(|| "1".to_string());
+                anyhow::bail!(
+                    "Mount {mount} is kv version {version}, expected kv version 2; \
+                     upgrade it with `bao kv enable-versioning {mount}` or choose \
+                     another --kv-mount"
+                );
             }
         } else {
             #[derive(Serialize)]
@@ -525,6 +741,7 @@ impl OpenBaoClient {
                 },
             )
             .await?;
+            self.mounts.invalidate(mount);
         }
         Ok(())
     }
@@ -603,13 +820,18 @@ impl OpenBaoClient {
 
     /// Writes an ACL policy.
     ///
+    /// The policy is checked with [`validate_policy_hcl`] first so a
+    /// malformed template fails locally instead of as an opaque 400.
+    ///
     /// # Errors
-    /// Returns an error if the policy cannot be written.
+    /// Returns an error if the policy is malformed or cannot be written.
     pub async fn write_policy(&self, name: &str, policy: &str) -> Result<()> {
         #[derive(Serialize)]
         struct PolicyRequest<'a> {
             policy: &'a str,
         }
+        validate_policy_hcl(policy)
+            .with_context(|| format!("Generated policy {name} is malformed"))?;
         self.post_action(
             &format!("sys/policies/acl/{name}"),
             &PolicyRequest { policy },
@@ -637,6 +859,11 @@ impl OpenBaoClient {
 
     /// Creates or updates an `AppRole` with the given settings.
     ///
+    /// `bound_cidrs` are sent as both `secret_id_bound_cidrs` and
+    /// `token_bound_cidrs`, so logins and issued tokens are accepted only
+    /// from those source networks. An empty slice is sent explicitly and
+    /// clears any binding an earlier write left on the role.
+    ///
     /// # Errors
     /// Returns an error if the `AppRole` cannot be created or updated.
     pub async fn create_approle(
@@ -646,6 +873,7 @@ impl OpenBaoClient {
         token_ttl: &str,
         secret_id_ttl: &str,
         token_renewable: bool,
+        bound_cidrs: &[String],
     ) -> Result<()> {
         #[derive(Serialize)]
         struct AppRoleRequest<'a> {
@@ -654,6 +882,8 @@ impl OpenBaoClient {
             token_max_ttl: &'a str,
             token_renewable: bool,
             secret_id_ttl: &'a str,
+            secret_id_bound_cidrs: &'a [String],
+            token_bound_cidrs: &'a [String],
         }
         self.post_action(
             &format!("auth/approle/role/{name}"),
@@ -663,6 +893,8 @@ impl OpenBaoClient {
                 token_max_ttl: token_ttl,
                 token_renewable,
                 secret_id_ttl,
+                secret_id_bound_cidrs: bound_cidrs,
+                token_bound_cidrs: bound_cidrs,
             },
         )
         .await
@@ -764,14 +996,12 @@ impl OpenBaoClient {
     /// Returns an error if the login request fails.
     pub async fn login_approle(&self, role_id: &str, secret_id: &str) -> Result<String> {
         let url = self.endpoint("auth/approle/login");
+        let request = self.client.post(url).json(&serde_json::json!({
+            "role_id": role_id,
+            "secret_id": secret_id
+        }));
         let response = self
-            .client
-            .post(url)
-            .json(&serde_json::json!({
-                "role_id": role_id,
-                "secret_id": secret_id
-            }))
-            .send()
+            .execute(request)
             .await
             .with_context(|| "OpenBao request failed: auth/approle/login")?;
         let parsed: AppRoleLoginResponse = Self::parse_response(response)
@@ -789,8 +1019,13 @@ impl OpenBaoClient {
         struct KvRequest {
             data: serde_json::Value,
         }
-        self.post_action(&format!("{mount}/data/{path}"), &KvRequest { data })
-            .await
+        validate_kv_mount(mount)?;
+        self.post_action(
+            &format!("{mount}/data/{path}"),
+            &KvRequest { data: data.clone() },
+        )
+        .await?;
+        self.mirror_kv_write(mount, path, data).await
     }
 
     /// Writes a KV v2 secret and returns the version assigned by this
@@ -834,7 +1069,12 @@ impl OpenBaoClient {
         }
         let full_path = format!("{mount}/data/{path}");
         let response = self
-            .send_authed_json(Method::POST, &full_path, &KvRequest { data }, None)
+            .send_authed_json(
+                Method::POST,
+                &full_path,
+                &KvRequest { data: data.clone() },
+                None,
+            )
             .await?;
         let status = response.status();
         let text = response
@@ -844,6 +1084,7 @@ impl OpenBaoClient {
         if !status.is_success() {
             anyhow::bail!("OpenBao API error ({status}): {text}");
         }
+        self.mirror_kv_write(mount, path, data).await?;
         if text.trim().is_empty() {
             return Ok(None);
         }
@@ -870,6 +1111,52 @@ impl OpenBaoClient {
             .map(|response| response.data.data)
     }
 
+    /// Reads the step-ca key password from [`KV_PATH_STEPCA_PASSWORD`].
+    ///
+    /// # Errors
+    /// Returns an error if the read fails or the payload has no non-empty
+    /// `value`.
+    pub async fn read_stepca_password(&self, mount: &str) -> Result<String> {
+        self.read_kv_value_secret(mount, KV_PATH_STEPCA_PASSWORD)
+            .await
+    }
+
+    /// Reads the runtime step-ca database DSN from [`KV_PATH_STEPCA_DB`].
+    ///
+    /// # Errors
+    /// Returns an error if the read fails or the payload has no non-empty
+    /// `value`.
+    pub async fn read_db_dsn(&self, mount: &str) -> Result<String> {
+        self.read_kv_value_secret(mount, KV_PATH_STEPCA_DB).await
+    }
+
+    /// Reads the HTTP-01 responder HMAC from [`KV_PATH_RESPONDER_HMAC`].
+    ///
+    /// # Errors
+    /// Returns an error if the read fails or the payload has no non-empty
+    /// `value`.
+    pub async fn read_responder_hmac(&self, mount: &str) -> Result<String> {
+        self.read_kv_value_secret(mount, KV_PATH_RESPONDER_HMAC)
+            .await
+    }
+
+    /// Reads and validates the global CA trust payload from
+    /// [`KV_PATH_CA_TRUST`].
+    ///
+    /// # Errors
+    /// Returns an error if the read fails or the payload is malformed, as
+    /// described on [`crate::kv_payload::parse_ca_trust_payload`].
+    pub async fn read_ca_trust(&self, mount: &str) -> Result<CaTrustPayload> {
+        let data = self.read_kv(mount, KV_PATH_CA_TRUST).await?;
+        parse_ca_trust_payload(&data)
+            .with_context(|| format!("Malformed KV payload at {mount}/{KV_PATH_CA_TRUST}"))
+    }
+
+    async fn read_kv_value_secret(&self, mount: &str, path: &str) -> Result<String> {
+        let data = self.read_kv(mount, path).await?;
+        parse_value_secret(&data).with_context(|| format!("Malformed KV payload at {mount}/{path}"))
+    }
+
     /// Reads a KV v2 secret together with its version metadata.
     ///
     /// The `version` field is the monotonically-increasing KV v2 revision
@@ -974,6 +1261,80 @@ impl OpenBaoClient {
             .await
     }
 
+    /// Lists the keys directly under a KV v2 path.  Sub-directories are
+    /// returned with a trailing `/`, as `OpenBao` reports them.  A path
+    /// with no entries yields an empty list.
+    ///
+    /// # Errors
+    /// Returns an error if the list request fails or the response cannot
+    /// be parsed.
+    pub async fn list_kv(&self, mount: &str, path: &str) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct ListResponse {
+            data: ListResponseData,
+        }
+        #[derive(Deserialize)]
+        struct ListResponseData {
+            keys: Vec<String>,
+        }
+        validate_kv_mount(mount)?;
+        let full_path = format!("{mount}/metadata/{path}?list=true");
+        let response = self.send_authed(Method::GET, &full_path, None).await?;
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .context("Failed to read OpenBao response body")?;
+        if is_not_found(status, &text) {
+            return Ok(Vec::new());
+        }
+        if !status.is_success() {
+            anyhow::bail!("OpenBao API error ({status}): {text}");
+        }
+        let parsed: ListResponse =
+            serde_json::from_str(&text).context("Failed to parse OpenBao list response")?;
+        Ok(parsed.data.keys)
+    }
+
+    /// Returns the calling token's capabilities on each of `paths`, via
+    /// `sys/capabilities-self`.  A path the server does not report maps
+    /// to an empty list.
+    ///
+    /// # Errors
+    /// Returns an error if the request fails or the response cannot be
+    /// parsed.
+    pub async fn capabilities_self(&self, paths: &[&str]) -> Result<BTreeMap<String, Vec<String>>> {
+        #[derive(Serialize)]
+        struct CapabilitiesRequest<'a> {
+            paths: &'a [&'a str],
+        }
+        let response: serde_json::Value = self
+            .post_json(
+                "sys/capabilities-self",
+                &CapabilitiesRequest { paths },
+                None,
+            )
+            .await?;
+        // The per-path map appears both at the top level and under
+        // `data`; a single-path query also fills `capabilities`.
+        let body = response.get("data").unwrap_or(&response);
+        let mut capabilities = BTreeMap::new();
+        for &path in paths {
+            let listed = body.get(path).or_else(|| {
+                (paths.len() == 1)
+                    .then(|| body.get("capabilities"))
+                    .flatten()
+            });
+            let listed: Vec<String> = match listed {
+                Some(value) => serde_json::from_value(value.clone())
+                    .context("Failed to parse OpenBao capabilities response")?,
+                None => Vec::new(),
+            };
+            capabilities.insert(path.to_string(), listed);
+        }
+        Ok(capabilities)
+    }
+
     /// Checks the status of a KV v2 mount.
     ///
     /// # Errors
@@ -1060,11 +1421,36 @@ impl OpenBaoClient {
         self.with_auth_header(self.request_builder(method, path))
     }
 
+    /// Sends `request`, logging it via the redacting trace layer.
+    async fn execute(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        trace::execute_logged(&self.client, request).await
+    }
+
     async fn send_request(&self, request: RequestBuilder, path: &str) -> Result<Response> {
-        request
-            .send()
+        let response = self
+            .execute(request)
             .await
-            .with_context(|| format!("OpenBao request failed: {path}"))
+            .with_context(|| format!("OpenBao request failed: {path}"))?;
+        self.reject_redirect(response, path)
+    }
+
+    /// Turns a standby node's redirect into an error naming the active
+    /// node, so writes never silently miss the node that can apply them.
+    fn reject_redirect(&self, response: Response, path: &str) -> Result<Response> {
+        let status = response.status();
+        if !status.is_redirection() {
+            return Ok(response);
+        }
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("<no Location header>");
+        anyhow::bail!(
+            "OpenBao at {} redirected {path} to {location} ({status}); it is likely a \
+             standby node, point bootroot at the active node",
+            self.base_url
+        );
     }
 
     async fn send_authed(
@@ -1096,15 +1482,25 @@ impl OpenBaoClient {
     }
 
     async fn get_mount(&self, mount: &str) -> Result<Option<MountData>> {
+        if let Some(cached) = self.mounts.get(mount) {
+            return Ok(cached.data);
+        }
+        let data = self.fetch_mount(mount).await?;
+        self.mounts.insert(mount, data.clone());
+        Ok(data)
+    }
+
+    async fn fetch_mount(&self, mount: &str) -> Result<Option<MountData>> {
         let url = self.endpoint(&format!("sys/mounts/{mount}"));
         let mut request = self.client.get(url);
         if let Some(token) = &self.token {
             request = request.header(VAULT_TOKEN_HEADER, token);
         }
-        let response = request
-            .send()
+        let response = self
+            .execute(request)
             .await
             .context("Failed to query OpenBao mounts")?;
+        let response = self.reject_redirect(response, &format!("sys/mounts/{mount}"))?;
         let status = response.status();
         let text = response
             .text()
@@ -1261,8 +1657,15 @@ pub struct AgentConfigParams<'a> {
     /// Optional path to a CA certificate bundle for TLS verification of
     /// the `OpenBao` server.
     pub ca_cert: Option<&'a str>,
+    /// Optional consumer command, split on whitespace, that the agent
+    /// runs in an `exec` block and restarts whenever a rendered secret
+    /// changes.
+    pub exec_command: Option<&'a str>,
 }
 
+/// Stop signal the agent sends the `exec` child before restarting it.
+pub const EXEC_RESTART_STOP_SIGNAL: &str = "SIGTERM";
+
 /// Builds an `OpenBao` agent HCL configuration string.
 #[must_use]
 pub fn build_agent_config(params: &AgentConfigParams<'_>) -> String {
@@ -1320,9 +1723,191 @@ template {{
         )
         .expect("write template");
     }
+    if let Some(command) = params.exec_command {
+        let argv = command
+            .split_whitespace()
+            .map(|arg| format!("\"{}\"", arg.replace('\\', "\\\\").replace('"', "\\\"")))
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            &mut config,
+            r#"
+exec {{
+  command = [{argv}]
+  restart_on_secret_changes = "always"
+  restart_stop_signal = "{EXEC_RESTART_STOP_SIGNAL}"
+}}
+"#
+        )
+        .expect("write exec");
+    }
     config
 }
 
+/// Validates a KV mount name before it is interpolated into API paths
+/// and policy HCL.
+///
+/// Accepts ASCII letters, digits, `-`, `_`, `.`, and interior `/`
+/// separators; rejects empty segments and `.`/`..` segments.
+///
+/// # Errors
+/// Returns an error describing the first offending character or segment.
+pub fn validate_kv_mount(mount: &str) -> Result<()> {
+    if mount.is_empty() {
+        anyhow::bail!("KV mount name must not be empty");
+    }
+    if let Some(ch) = mount
+        .chars()
+        .find(|ch| !(ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.' | '/')))
+    {
+        anyhow::bail!("KV mount name {mount:?} contains invalid character {ch:?}");
+    }
+    if mount
+        .split('/')
+        .any(|segment| segment.is_empty() || segment == "." || segment == "..")
+    {
+        anyhow::bail!("KV mount name {mount:?} contains an empty or relative path segment");
+    }
+    Ok(())
+}
+
+/// Checks that `policy` is a sequence of well-formed
+/// `path "<pattern>" { capabilities = ["<cap>", ...] }` blocks.
+///
+/// This covers the subset of HCL bootroot generates; `#` comments are
+/// allowed between blocks.
+///
+/// # Errors
+/// Returns an error naming the byte offset and the token that was
+/// expected there.
+pub fn validate_policy_hcl(policy: &str) -> Result<()> {
+    let mut parser = PolicyParser {
+        input: policy,
+        pos: 0,
+    };
+    let mut blocks = 0;
+    loop {
+        parser.skip_trivia();
+        if parser.at_end() {
+            break;
+        }
+        parser.expect_word("path")?;
+        let pattern = parser.expect_string()?;
+        if pattern.is_empty() {
+            return Err(parser.error("non-empty path pattern"));
+        }
+        parser.expect_char('{')?;
+        parser.expect_word("capabilities")?;
+        parser.expect_char('=')?;
+        parser.expect_char('[')?;
+        let mut capabilities = 0;
+        loop {
+            parser.skip_trivia();
+            if parser.peek() == Some(']') {
+                break;
+            }
+            if parser.expect_string()?.is_empty() {
+                return Err(parser.error("non-empty capability"));
+            }
+            capabilities += 1;
+            parser.skip_trivia();
+            if parser.peek() == Some(',') {
+                parser.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if capabilities == 0 {
+            return Err(parser.error("at least one capability"));
+        }
+        parser.expect_char(']')?;
+        parser.expect_char('}')?;
+        blocks += 1;
+    }
+    if blocks == 0 {
+        anyhow::bail!("policy contains no path blocks");
+    }
+    Ok(())
+}
+
+struct PolicyParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> PolicyParser<'a> {
+    fn rest(&self) -> &'a str {
+        self.input.get(self.pos..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn at_end(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            if !trimmed.starts_with('#') {
+                return;
+            }
+            self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+        }
+    }
+
+    fn error(&self, expected: &str) -> anyhow::Error {
+        let found = self
+            .peek()
+            .map_or_else(|| "end of input".to_string(), |ch| format!("{ch:?}"));
+        anyhow::anyhow!("expected {expected} at offset {}, found {found}", self.pos)
+    }
+
+    fn expect_char(&mut self, expected: char) -> Result<()> {
+        self.skip_trivia();
+        if self.peek() != Some(expected) {
+            return Err(self.error(&format!("{expected:?}")));
+        }
+        self.pos += expected.len_utf8();
+        Ok(())
+    }
+
+    fn expect_word(&mut self, word: &str) -> Result<()> {
+        self.skip_trivia();
+        let rest = self.rest();
+        let end = rest
+            .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+            .unwrap_or(rest.len());
+        if rest.get(..end) != Some(word) {
+            return Err(self.error(&format!("`{word}`")));
+        }
+        self.pos += end;
+        Ok(())
+    }
+
+    fn expect_string(&mut self) -> Result<&'a str> {
+        self.skip_trivia();
+        if self.peek() != Some('"') {
+            return Err(self.error("a quoted string"));
+        }
+        let start = self.pos + 1;
+        let body = self.input.get(start..).unwrap_or_default();
+        let Some(len) = body.find(['"', '\n', '\\']) else {
+            return Err(self.error("a closing quote"));
+        };
+        if body.get(len..).and_then(|tail| tail.chars().next()) != Some('"') {
+            self.pos = start + len;
+            return Err(self.error("a closing quote"));
+        }
+        self.pos = start + len + 1;
+        Ok(self.input.get(start..start + len).unwrap_or_default())
+    }
+}
+
 #[cfg(test)]
 mod wrap_tests {
     use serde_json::json;
@@ -1366,6 +1951,32 @@ mod wrap_tests {
         assert_eq!(info.wrap_info.ttl, 90);
     }
 
+    #[tokio::test]
+    async fn requests_identify_bootroot_in_user_agent() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/health"))
+            .and(header(
+                "User-Agent",
+                crate::tls::openbao_user_agent().as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OpenBaoClient::new(&server.uri()).expect("client init");
+        client
+            .health_check()
+            .await
+            .expect("health check should send the bootroot user agent");
+        assert!(
+            crate::tls::openbao_user_agent()
+                .starts_with(&format!("bootroot/{}", env!("CARGO_PKG_VERSION")))
+        );
+    }
+
     #[tokio::test]
     async fn create_secret_id_wrapped_sends_header_and_unwraps() {
         let server = MockServer::start().await;
@@ -1412,6 +2023,67 @@ mod wrap_tests {
     }
 }
 
+#[cfg(test)]
+mod dual_write_tests {
+    use serde_json::json;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn dual_write() -> KvDualWrite {
+        KvDualWrite {
+            current: KvLayout {
+                mount: "bootroot".to_string(),
+                prefix: "bootroot".to_string(),
+            },
+            legacy: KvLayout {
+                mount: "secret".to_string(),
+                prefix: "bootroot".to_string(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn dual_write_writes_stepca_password_to_both_layouts() {
+        let server = MockServer::start().await;
+        let body = json!({ "data": { "value": "new-password" } });
+        for mount in ["bootroot", "secret"] {
+            Mock::given(method("POST"))
+                .and(path(format!("/v1/{mount}/data/bootroot/stepca/password")))
+                .and(body_json(&body))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client init");
+        client.set_token("root-token".to_string());
+        client.set_kv_dual_write(Some(dual_write()));
+        client
+            .write_kv(
+                "bootroot",
+                "bootroot/stepca/password",
+                json!({ "value": "new-password" }),
+            )
+            .await
+            .expect("dual write");
+        server.verify().await;
+    }
+
+    #[test]
+    fn legacy_target_ignores_paths_outside_current_layout() {
+        let dual = dual_write();
+        assert_eq!(
+            dual.legacy_target("bootroot", "bootroot/ca"),
+            Some(("secret".to_string(), "bootroot/ca".to_string()))
+        );
+        assert_eq!(dual.legacy_target("secret", "bootroot/ca"), None);
+        assert_eq!(dual.legacy_target("bootroot", "other/ca"), None);
+    }
+}
+
 #[cfg(test)]
 mod secret_id_options_tests {
     use super::*;
@@ -1499,6 +2171,7 @@ mod agent_config_tests {
                 perms: TEMPLATE_PERMS_SECRET,
             }],
             ca_cert: None,
+            exec_command: None,
         });
         assert!(hcl.contains(r#"address = "http://openbao:8200""#));
         assert!(hcl.contains("role_id_file_path = \"/role_id\""));
@@ -1524,6 +2197,7 @@ mod agent_config_tests {
                 perms: TEMPLATE_PERMS_SECRET,
             }],
             ca_cert: None,
+            exec_command: None,
         });
         assert!(hcl.contains(r#"mount_path = "auth/approle""#));
     }
@@ -1550,6 +2224,7 @@ mod agent_config_tests {
                 },
             ],
             ca_cert: None,
+            exec_command: None,
         });
         assert!(hcl.contains(r#"source = "/a.ctmpl""#));
         assert!(hcl.contains(r#"source = "/b.ctmpl""#));
@@ -1577,6 +2252,7 @@ mod agent_config_tests {
                 },
             ],
             ca_cert: None,
+            exec_command: None,
         });
         // The secret-bearing agent config stays 0600.
         assert!(hcl.contains("  destination = \"/agent.toml\"\n  perms = \"0600\""));
@@ -1600,11 +2276,31 @@ mod agent_config_tests {
                 perms: TEMPLATE_PERMS_SECRET,
             }],
             ca_cert: Some("/certs/ca-bundle.pem"),
+            exec_command: None,
         });
         assert!(hcl.contains(r#"address = "https://openbao:8200""#));
         assert!(hcl.contains(r#"ca_cert = "/certs/ca-bundle.pem""#));
     }
 
+    #[test]
+    fn with_exec_command() {
+        let hcl = build_agent_config(&AgentConfigParams {
+            openbao_addr: "http://openbao:8200",
+            role_id_path: "/role_id",
+            secret_id_path: "/secret_id",
+            token_path: "/token",
+            mount_path: None,
+            render_interval: "30s",
+            templates: &[],
+            ca_cert: None,
+            exec_command: Some("step-ca  /home/step/config/ca.json"),
+        });
+        assert!(hcl.ends_with(
+            "\nexec {\n  command = [\"step-ca\",\"/home/step/config/ca.json\"]\n  \
+             restart_on_secret_changes = \"always\"\n  restart_stop_signal = \"SIGTERM\"\n}\n"
+        ));
+    }
+
     #[test]
     fn without_ca_cert() {
         let hcl = build_agent_config(&AgentConfigParams {
@@ -1620,6 +2316,7 @@ mod agent_config_tests {
                 perms: TEMPLATE_PERMS_SECRET,
             }],
             ca_cert: None,
+            exec_command: None,
         });
         assert!(!hcl.contains("ca_cert"));
     }
@@ -1887,6 +2584,63 @@ mod tls_integration_tests {
     }
 }
 
+#[cfg(test)]
+mod base_url_tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[test]
+    fn new_strips_trailing_slashes() {
+        let client = OpenBaoClient::new("http://host:8200/").expect("trailing slash");
+        assert_eq!(client.base_url, "http://host:8200");
+        assert_eq!(
+            client.endpoint("sys/health"),
+            "http://host:8200/v1/sys/health"
+        );
+
+        let client = OpenBaoClient::new("http://host:8200").expect("bare url");
+        assert_eq!(
+            client.endpoint("sys/health"),
+            "http://host:8200/v1/sys/health"
+        );
+    }
+
+    #[test]
+    fn new_keeps_reverse_proxy_path_prefix() {
+        let client = OpenBaoClient::new("https://host/openbao/").expect("proxy prefix");
+        assert_eq!(
+            client.endpoint("sys/health"),
+            "https://host/openbao/v1/sys/health"
+        );
+    }
+
+    #[test]
+    fn new_rejects_embedded_api_prefix() {
+        for url in ["http://host:8200/v1", "http://host:8200/v1/"] {
+            let Err(err) = OpenBaoClient::new(url) else {
+                panic!("{url} must be rejected");
+            };
+            assert!(err.to_string().contains("/v1 API prefix"), "{err}");
+        }
+    }
+
+    #[tokio::test]
+    async fn trailing_slash_url_reaches_v1_endpoints() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/health"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OpenBaoClient::new(&format!("{}/", server.uri())).expect("client");
+        client.health_check().await.expect("health check");
+    }
+}
+
 #[cfg(test)]
 mod timeout_tests {
     use std::time::Duration;
@@ -1936,3 +2690,138 @@ mod timeout_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod policy_validation_tests {
+    use wiremock::MockServer;
+
+    use super::*;
+
+    #[test]
+    fn accepts_generated_policy_shape() {
+        let policy = r#"# agent access
+path "secret/data/bootroot/agent/eab" {
+  capabilities = ["read"]
+}
+path "auth/approle/role/bootroot-service-*/secret-id" {
+  capabilities = ["create", "update",]
+}
+"#;
+        validate_policy_hcl(policy).expect("valid policy");
+    }
+
+    #[test]
+    fn rejects_unbalanced_braces() {
+        let err = validate_policy_hcl("path \"secret/data/x\" {\n  capabilities = [\"read\"]\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("expected '}'"), "{err}");
+    }
+
+    #[test]
+    fn rejects_path_with_embedded_quote() {
+        let policy = "path \"sec\"ret/data/x\" {\n  capabilities = [\"read\"]\n}\n";
+        assert!(validate_policy_hcl(policy).is_err());
+    }
+
+    #[test]
+    fn rejects_empty_capabilities() {
+        let err = validate_policy_hcl("path \"secret/data/x\" { capabilities = [] }").unwrap_err();
+        assert!(err.to_string().contains("at least one capability"), "{err}");
+    }
+
+    #[test]
+    fn validate_kv_mount_rejects_special_characters() {
+        assert!(validate_kv_mount("secret").is_ok());
+        assert!(validate_kv_mount("team-a/kv_v2").is_ok());
+        assert!(validate_kv_mount("").is_err());
+        assert!(validate_kv_mount("sec\"ret").is_err());
+        assert!(validate_kv_mount("secret/../sys").is_err());
+        assert!(validate_kv_mount("/secret").is_err());
+    }
+
+    #[tokio::test]
+    async fn quoted_kv_mount_is_rejected_before_any_request() {
+        let server = MockServer::start().await;
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client init");
+        client.set_token("root-token".to_string());
+
+        let err = client.ensure_kv_v2("sec\"ret").await.unwrap_err();
+        assert!(err.to_string().contains("invalid character"), "{err}");
+        let err = client
+            .write_kv("sec\"ret", "bootroot/agent/eab", serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid character"), "{err}");
+
+        let requests = server.received_requests().await.expect("recording");
+        assert!(requests.is_empty(), "unexpected requests: {requests:?}");
+    }
+}
+
+#[cfg(test)]
+mod mount_cache_tests {
+    use serde_json::json;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn client_with_token(server: &MockServer) -> OpenBaoClient {
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client init");
+        client.set_token("root-token".to_string());
+        client
+    }
+
+    #[tokio::test]
+    async fn repeated_mount_lookups_hit_openbao_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/mounts/secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "type": "kv", "options": { "version": "2" } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client_with_token(&server);
+        client.ensure_kv_v2("secret").await.expect("ensure kv");
+        let status = client.clone().kv_mount_status("secret").await;
+        assert!(matches!(status, Ok(KvMountStatus::Ok)), "{status:?}");
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn creating_a_mount_invalidates_the_cached_lookup() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/mounts/secret"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({ "errors": [] })))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/sys/mounts/secret"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/mounts/secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "type": "kv", "options": { "version": "2" } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client_with_token(&server);
+        client.ensure_kv_v2("secret").await.expect("ensure kv");
+        let status = client.kv_mount_status("secret").await;
+        assert!(matches!(status, Ok(KvMountStatus::Ok)), "{status:?}");
+        let status = client.kv_mount_status("secret").await;
+        assert!(matches!(status, Ok(KvMountStatus::Ok)), "{status:?}");
+        server.verify().await;
+    }
+}
@@ -0,0 +1,374 @@
+//! Redacting request/response logging for [`super::OpenBaoClient`].
+//!
+//! Every request is logged at `debug` with its method, path, status, and
+//! latency.  At `trace` the headers and JSON bodies are logged as well,
+//! with tokens, `AppRole` secret IDs, unseal keys, and KV secret values
+//! masked so a verbose trace can be attached to a support bundle.
+
+use std::time::Instant;
+
+use reqwest::header::HeaderMap;
+use reqwest::{Client, RequestBuilder, Response};
+use tracing::{Level, debug, trace};
+
+use super::VAULT_TOKEN_HEADER;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// JSON field names whose values are masked wherever they appear.
+const SENSITIVE_FIELDS: &[&str] = &[
+    "client_token",
+    "dsn",
+    "hmac",
+    "key",
+    "keys",
+    "keys_base64",
+    "password",
+    "root_token",
+    "secret_id",
+    "token",
+    "value",
+];
+
+/// Sends `request` through `client`, logging it with secrets redacted.
+pub(super) async fn execute_logged(
+    client: &Client,
+    request: RequestBuilder,
+) -> reqwest::Result<Response> {
+    let request = request.build()?;
+    let method = request.method().clone();
+    let path = request.url().path().to_string();
+    if tracing::enabled!(Level::TRACE) {
+        let body = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .map(redact_body)
+            .unwrap_or_default();
+        trace!(
+            %method,
+            %path,
+            headers = %redact_headers(request.headers()),
+            %body,
+            "OpenBao request"
+        );
+    }
+    let started = Instant::now();
+    let response = client.execute(request).await;
+    let elapsed_ms = started.elapsed().as_millis();
+    let response = match response {
+        Ok(response) => response,
+        Err(err) => {
+            debug!(%method, %path, elapsed_ms, error = %err, "OpenBao request failed");
+            return Err(err);
+        }
+    };
+    let status = response.status();
+    debug!(%method, %path, %status, elapsed_ms, "OpenBao response");
+    if !tracing::enabled!(Level::TRACE) {
+        return Ok(response);
+    }
+    // Reading the body consumes the response, so rebuild it from the
+    // buffered bytes for the caller.
+    let version = response.version();
+    let headers = response.headers().clone();
+    let bytes = response.bytes().await?;
+    trace!(%method, %path, %status, body = %redact_body(&bytes), "OpenBao response body");
+    let mut rebuilt = http::Response::new(bytes);
+    *rebuilt.status_mut() = status;
+    *rebuilt.version_mut() = version;
+    *rebuilt.headers_mut() = headers;
+    Ok(Response::from(rebuilt))
+}
+
+/// Renders `headers` for logging with the `OpenBao` token masked.
+pub(super) fn redact_headers(headers: &HeaderMap) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if name.as_str().eq_ignore_ascii_case(VAULT_TOKEN_HEADER) {
+                REDACTED
+            } else {
+                value.to_str().unwrap_or("<binary>")
+            };
+            format!("{name}: {value}")
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Renders a request or response body for logging with every
+/// [`SENSITIVE_FIELDS`] value masked.
+///
+/// Bodies that are not JSON are summarized by size only, since their
+/// contents cannot be inspected for secrets.
+pub(super) fn redact_body(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return String::new();
+    }
+    match serde_json::from_slice::<serde_json::Value>(bytes) {
+        Ok(mut value) => {
+            redact_secret_fields(&mut value, &[]);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes, non-JSON>", bytes.len()),
+    }
+}
+
+/// Masks every [`SENSITIVE_FIELDS`] value in `value`, plus any field
+/// named in `extra_fields`, at any nesting depth.
+pub fn redact_secret_fields(value: &mut serde_json::Value, extra_fields: &[&str]) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (name, field) in map.iter_mut() {
+                if SENSITIVE_FIELDS.contains(&name.as_str())
+                    || extra_fields.contains(&name.as_str())
+                {
+                    *field = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_secret_fields(field, extra_fields);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| redact_secret_fields(item, extra_fields)),
+        _ => {}
+    }
+}
+
+/// Masks secret values in free-form log text, line by line.
+///
+/// A line carrying a JSON object, after any `service |` prefix, has that
+/// object masked with [`redact_secret_fields`].  Any other line has the
+/// value of every `name=value`, `name: value`, or `"name": "value"` pair
+/// masked when `name` is a [`SENSITIVE_FIELDS`] or `extra_fields` entry,
+/// or ends in one after a `_`, `-`, or `.` (`POSTGRES_PASSWORD`,
+/// `X-Vault-Token`).
+#[must_use]
+pub fn redact_secret_text(text: &str, extra_fields: &[&str]) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let (body, newline) = line
+            .strip_suffix('\n')
+            .map_or((line, ""), |body| (body, "\n"));
+        redacted.push_str(&redact_secret_line(body, extra_fields));
+        redacted.push_str(newline);
+    }
+    redacted
+}
+
+fn redact_secret_line(line: &str, extra_fields: &[&str]) -> String {
+    if let Some((prefix, json)) = line.find('{').map(|start| line.split_at(start))
+        && let Ok(mut value @ serde_json::Value::Object(_)) = serde_json::from_str(json)
+    {
+        redact_secret_fields(&mut value, extra_fields);
+        return format!("{prefix}{value}");
+    }
+    let mut redacted = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find(is_field_name_char) {
+        let (before, from_name) = rest.split_at(start);
+        let name_len = from_name
+            .find(|c| !is_field_name_char(c))
+            .unwrap_or(from_name.len());
+        let (name, after_name) = from_name.split_at(name_len);
+        redacted.push_str(before);
+        redacted.push_str(name);
+        rest = after_name;
+        if !is_secret_field_name(name, extra_fields) {
+            continue;
+        }
+        let Some((separator, value_and_rest)) = split_field_separator(after_name) else {
+            continue;
+        };
+        let (value, remainder) = split_field_value(value_and_rest);
+        if value.is_empty() {
+            continue;
+        }
+        redacted.push_str(separator);
+        if value.starts_with('"') {
+            redacted.push('"');
+            redacted.push_str(REDACTED);
+            redacted.push('"');
+        } else {
+            redacted.push_str(REDACTED);
+        }
+        rest = remainder;
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
+fn is_field_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+fn is_secret_field_name(name: &str, extra_fields: &[&str]) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_FIELDS.iter().chain(extra_fields).any(|field| {
+        name == *field
+            || name
+                .strip_suffix(field)
+                .is_some_and(|prefix| prefix.ends_with(['_', '-', '.']))
+    })
+}
+
+/// Splits the `=`/`:` separator, with an optional closing quote and
+/// surrounding spaces, off the text following a field name.
+fn split_field_separator(text: &str) -> Option<(&str, &str)> {
+    let unquoted = text.strip_prefix('"').unwrap_or(text);
+    let value = unquoted
+        .trim_start_matches(' ')
+        .strip_prefix(['=', ':'])?
+        .trim_start_matches(' ');
+    Some(text.split_at(text.len() - value.len()))
+}
+
+/// Splits a quoted string or a bare token off the start of `text`.
+fn split_field_value(text: &str) -> (&str, &str) {
+    let end = if let Some(quoted) = text.strip_prefix('"') {
+        let mut escaped = false;
+        quoted
+            .char_indices()
+            .find(|&(_, c)| {
+                let closes = c == '"' && !escaped;
+                escaped = c == '\\' && !escaped;
+                closes
+            })
+            .map_or(text.len(), |(index, _)| index + 2)
+    } else {
+        text.find(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '&' | '}' | ')' | ']'))
+            .unwrap_or(text.len())
+    };
+    text.split_at(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use reqwest::header::HeaderValue;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::openbao::OpenBaoClient;
+
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().expect("log buffer").extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            String::from_utf8_lossy(&self.0.lock().expect("log buffer")).into_owned()
+        }
+    }
+
+    #[test]
+    fn redact_body_masks_nested_sensitive_fields() {
+        let body = br#"{"data":{"value":"s3cret","kid":"k1","hmac":"h"},"auth":{"client_token":"t"},"keys":["a"],"secret_shares":3}"#;
+        let redacted = redact_body(body);
+        assert!(!redacted.contains("s3cret"), "{redacted}");
+        assert!(!redacted.contains("\"t\""), "{redacted}");
+        assert!(!redacted.contains("[\"a\"]"), "{redacted}");
+        assert!(redacted.contains("\"kid\":\"k1\""), "{redacted}");
+        assert!(redacted.contains("\"secret_shares\":3"), "{redacted}");
+    }
+
+    #[test]
+    fn redact_body_summarizes_non_json() {
+        assert_eq!(redact_body(b"not json"), "<8 bytes, non-JSON>");
+        assert_eq!(redact_body(b""), "");
+    }
+
+    #[test]
+    fn redact_secret_text_masks_pairs_and_json_lines() {
+        let logs = concat!(
+            "bootroot-postgres  | POSTGRES_PASSWORD=pg-s3cret user=step\n",
+            "bootroot-openbao   | X-Vault-Token: hvs.root-tok path=/v1/sys/health\n",
+            "bootroot-agent     | {\"level\":\"info\",\"secret_id\":\"sid-1\",\"role_id\":\"rid-1\"}\n",
+            "bootroot-agent     | renew failed: \"password\": \"quoted pw\", retry=3\n",
+            "bootroot-http01    | GET /.well-known/acme-challenge/abc?token=tok-2&x=1\n",
+        );
+        let redacted = redact_secret_text(logs, &["role_id"]);
+        for secret in [
+            "pg-s3cret",
+            "hvs.root-tok",
+            "sid-1",
+            "rid-1",
+            "quoted pw",
+            "tok-2",
+        ] {
+            assert!(!redacted.contains(secret), "{secret} leaked: {redacted}");
+        }
+        assert!(redacted.contains("user=step"), "{redacted}");
+        assert!(redacted.contains("path=/v1/sys/health"), "{redacted}");
+        assert!(redacted.contains("\"level\":\"info\""), "{redacted}");
+        assert!(redacted.contains("retry=3"), "{redacted}");
+        assert!(redacted.contains("&x=1"), "{redacted}");
+        assert_eq!(redacted.lines().count(), 5, "{redacted}");
+    }
+
+    #[test]
+    fn redact_headers_masks_vault_token() {
+        let mut headers = HeaderMap::new();
+        headers.insert(VAULT_TOKEN_HEADER, HeaderValue::from_static("root-token"));
+        headers.insert("content-type", HeaderValue::from_static("application/json"));
+        let rendered = redact_headers(&headers);
+        assert!(!rendered.contains("root-token"), "{rendered}");
+        assert!(rendered.contains("content-type: application/json"));
+    }
+
+    #[tokio::test]
+    async fn write_kv_trace_redacts_value_but_keeps_path() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/secret/data/bootroot/responder/hmac"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "version": 1 }
+            })))
+            .mount(&server)
+            .await;
+
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(Level::TRACE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let mut client = OpenBaoClient::new(&server.uri()).expect("client");
+        client.set_token("root-token".to_string());
+        client
+            .write_kv(
+                "secret",
+                "bootroot/responder/hmac",
+                serde_json::json!({ "value": "super-secret-hmac" }),
+            )
+            .await
+            .expect("write_kv");
+
+        let output = logs.contents();
+        assert!(
+            output.contains("/v1/secret/data/bootroot/responder/hmac"),
+            "path must be logged: {output}"
+        );
+        assert!(output.contains(REDACTED), "{output}");
+        assert!(!output.contains("super-secret-hmac"), "{output}");
+        assert!(!output.contains("root-token"), "{output}");
+        assert!(output.contains("status=200"), "{output}");
+    }
+}
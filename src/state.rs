@@ -1,13 +1,18 @@
 use std::collections::BTreeMap;
 use std::fmt;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use bootroot::openbao::KvDualWrite;
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_SECRETS_DIR: &str = "secrets";
+const DEFAULT_KV_PREFIX: &str = "bootroot";
+pub(crate) const SERVICE_KV_SEGMENT: &str = "services";
 const DEFAULT_STATE_FILE: &str = "state.json";
+const STATE_BACKUP_SUFFIX: &str = ".bak";
 pub(crate) const DEFAULT_HOOK_TIMEOUT_SECS: u64 = 30;
 
 /// Describes how to reload a service after its infrastructure certificate
@@ -64,8 +69,17 @@ pub(crate) struct InfraCertEntry {
 pub(crate) struct StateFile {
     pub(crate) openbao_url: String,
     pub(crate) kv_mount: String,
+    /// Path prefix bootroot's secrets live under inside `kv_mount`,
+    /// recorded by `bootroot migrate --to-prefix`; unset means
+    /// `bootroot`. Per-service secrets live under `<prefix>/services`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) kv_prefix: Option<String>,
     #[serde(default)]
     pub(crate) secrets_dir: Option<PathBuf>,
+    /// step-ca `ca.json` recorded from `bootroot init --ca-json`; unset
+    /// means `<secrets_dir>/config/ca.json`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) ca_json_path: Option<PathBuf>,
     #[serde(default)]
     pub(crate) policies: BTreeMap<String, String>,
     #[serde(default)]
@@ -100,6 +114,11 @@ pub(crate) struct StateFile {
     /// warning threshold (half this TTL) from it.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) rotate_secret_id_ttl: Option<String>,
+    /// Source CIDRs the init service `AppRole` (`bootroot-agent-role`) is
+    /// bound to (`bootroot init --approle-bind-cidr`), sent as the
+    /// role-level `secret_id_bound_cidrs` / `token_bound_cidrs`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) approle_bound_cidrs: Vec<String>,
     /// RFC 3339 timestamp of the last successful `bootroot rotate
     /// approle-secret-id` invocation (batch, single-service, and infra
     /// alike). Dead-man record point for the scheduled rotation job: a
@@ -107,6 +126,38 @@ pub(crate) struct StateFile {
     /// `bootroot status` warns when this timestamp goes stale.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) last_secret_id_rotation: Option<String>,
+    /// Docker Compose profiles selected via `--compose-profile` on
+    /// `infra install` / `infra up`. Replayed as `--profile <name>` by
+    /// later compose invocations (`infra up`, `status`, `rotate`) so
+    /// profile-gated services stay in the managed set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) compose_profiles: Vec<String>,
+    /// KV layout change started by `bootroot migrate --dual-write`.
+    /// While set, `init` and `rotate` mirror every secret they write
+    /// under the current layout to the legacy one, until `bootroot
+    /// migrate --finalize` deletes the legacy copies and clears it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) kv_dual_write: Option<KvDualWrite>,
+    /// step-ca ACME directory URL derived at `bootroot init` from the
+    /// step-ca bind address and `--stepca-provisioner`. `service add`
+    /// renders it into local-file agent configs unless `--ca-url` is
+    /// given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) acme_directory_url: Option<String>,
+    /// Set when `bootroot init --auto-unseal` initialized `OpenBao` with
+    /// recovery keys: the transit/KMS seal unseals the server itself, so
+    /// `infra up` skips its unseal step.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) openbao_auto_unseal: bool,
+    /// Image chosen with `bootroot init --stepca-image`. `reinit` passes
+    /// it back to init and `rotate` runs its `step` helper containers in
+    /// it, so a private-registry host never falls back to Docker Hub.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) stepca_image: Option<String>,
+    /// Image chosen with `bootroot init --openbao-agent-image`; `reinit`
+    /// rewrites the agent compose override with it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) openbao_agent_image: Option<String>,
 }
 
 impl StateFile {
@@ -114,19 +165,72 @@ impl StateFile {
         PathBuf::from(DEFAULT_STATE_FILE)
     }
 
+    /// Returns the path `save` copies the previous `state.json` to.
+    pub(crate) fn backup_path(path: &Path) -> PathBuf {
+        let mut name = path.as_os_str().to_os_string();
+        name.push(STATE_BACKUP_SUFFIX);
+        PathBuf::from(name)
+    }
+
+    /// Loads `path`, falling back to its [`StateFile::backup_path`] copy
+    /// with a warning when the primary file exists but does not parse.
     pub(crate) fn load(path: &Path) -> Result<Self> {
         let contents = std::fs::read_to_string(path)
             .with_context(|| format!("Failed to read {}", path.display()))?;
-        let state: StateFile =
-            serde_json::from_str(&contents).context("Failed to parse state.json")?;
+        let err = match serde_json::from_str(&contents) {
+            Ok(state) => return Ok(state),
+            Err(err) => err,
+        };
+        let backup = Self::backup_path(path);
+        let recovered = std::fs::read_to_string(&backup)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok());
+        let Some(state) = recovered else {
+            return Err(err).context("Failed to parse state.json");
+        };
+        eprintln!(
+            "warning: {} is corrupt ({err}); loaded the previous copy from {}",
+            path.display(),
+            backup.display()
+        );
         Ok(state)
     }
 
+    /// Writes the state atomically: the new contents go to a temporary
+    /// file in the same directory and are fsynced, the previous file is
+    /// copied to [`StateFile::backup_path`], and the temporary file is
+    /// renamed over `path`.  A crash at any point leaves either the old
+    /// or the new `state.json` in place, never a truncated one.
     pub(crate) fn save(&self, path: &Path) -> Result<()> {
         let contents =
             serde_json::to_string_pretty(self).context("Failed to serialize state.json")?;
-        std::fs::write(path, contents)
-            .with_context(|| format!("Failed to write {}", path.display()))
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let write_err = || format!("Failed to write {}", path.display());
+        let mut temp = tempfile::NamedTempFile::new_in(dir).with_context(write_err)?;
+        temp.write_all(contents.as_bytes())
+            .with_context(write_err)?;
+        if let Ok(metadata) = std::fs::metadata(path) {
+            // Keep the operator's permissions on the replaced file.
+            temp.as_file()
+                .set_permissions(metadata.permissions())
+                .with_context(write_err)?;
+        }
+        temp.as_file().sync_all().with_context(write_err)?;
+        if path.is_file() {
+            let backup = Self::backup_path(path);
+            std::fs::copy(path, &backup)
+                .with_context(|| format!("Failed to write {}", backup.display()))?;
+        }
+        temp.persist(path).with_context(write_err)?;
+        // Persist the rename itself; failure here is not fatal because
+        // the new file is already in place.
+        if let Ok(dir) = std::fs::File::open(dir) {
+            let _ = dir.sync_all();
+        }
+        Ok(())
     }
 
     pub(crate) fn secrets_dir(&self) -> &Path {
@@ -134,6 +238,41 @@ impl StateFile {
             .as_deref()
             .unwrap_or(Path::new(DEFAULT_SECRETS_DIR))
     }
+
+    pub(crate) fn kv_prefix(&self) -> &str {
+        self.kv_prefix
+            .as_deref()
+            .map_or(DEFAULT_KV_PREFIX, |prefix| prefix.trim_end_matches('/'))
+    }
+
+    /// Returns the recorded step-ca image, or the init default for states
+    /// written before it was recorded.
+    pub(crate) fn stepca_image(&self) -> &str {
+        self.stepca_image
+            .as_deref()
+            .unwrap_or(crate::commands::init::DEFAULT_STEPCA_IMAGE)
+    }
+
+    /// Returns the KV base every per-service secret lives under:
+    /// `<kv_prefix>/services`. `service add`, `service remove`, and
+    /// `rotate` all derive service paths from it so they never split one
+    /// service's secrets across two trees.
+    pub(crate) fn service_kv_base(&self) -> String {
+        format!("{}/{SERVICE_KV_SEGMENT}", self.kv_prefix())
+    }
+
+    /// Returns `<kv_prefix>/services/<service_name>/<suffix>`.
+    pub(crate) fn service_kv_path(&self, service_name: &str, suffix: &str) -> String {
+        format!("{}/{service_name}/{suffix}", self.service_kv_base())
+    }
+
+    /// Returns step-ca's `ca.json`: the recorded `--ca-json` override, or
+    /// `<secrets_dir>/config/ca.json`.
+    pub(crate) fn ca_json_path(&self) -> PathBuf {
+        self.ca_json_path
+            .clone()
+            .unwrap_or_else(|| self.secrets_dir().join("config").join("ca.json"))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -181,6 +320,17 @@ pub(crate) struct ServiceEntry {
     /// workarounds — see issue #593.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub(crate) cert_group_gid: Option<u32>,
+    /// Last sync generation `rotate` published to the service's
+    /// `generation` KV path. Only remote-bootstrap services get one;
+    /// `None` means no remote sync has bumped it yet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) sync_generation: Option<u64>,
+    /// CA trust generation (`trust_generation` in the `bootroot/ca`
+    /// payload) last written to the service's `trust` KV path.
+    /// `rotate trust-sync` skips the service while this matches the
+    /// current generation; `None` means it has not been recorded yet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) trust_generation: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
@@ -209,6 +359,9 @@ pub(crate) struct ServiceRoleEntry {
     pub(crate) secret_id_wrap_ttl: Option<String>,
     #[serde(default)]
     pub(crate) token_bound_cidrs: Option<Vec<String>>,
+    /// Role-level source CIDRs set via `service add --approle-bind-cidr`.
+    #[serde(default)]
+    pub(crate) approle_bound_cidrs: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
@@ -254,6 +407,56 @@ fn write_serde_string_value<T: Serialize>(
 mod tests {
     use super::*;
 
+    fn minimal_state(openbao_url: &str) -> StateFile {
+        serde_json::from_value(serde_json::json!({
+            "openbao_url": openbao_url,
+            "kv_mount": "secret",
+            "policies": {},
+            "approles": {},
+            "services": {}
+        }))
+        .expect("minimal state")
+    }
+
+    #[test]
+    fn save_keeps_previous_state_as_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        minimal_state("http://first:8200").save(&path).unwrap();
+        assert!(!StateFile::backup_path(&path).exists());
+
+        minimal_state("http://second:8200").save(&path).unwrap();
+        assert_eq!(
+            StateFile::load(&path).unwrap().openbao_url,
+            "http://second:8200"
+        );
+        let backup = StateFile::load(&StateFile::backup_path(&path)).unwrap();
+        assert_eq!(backup.openbao_url, "http://first:8200");
+    }
+
+    #[test]
+    fn load_falls_back_to_backup_when_primary_is_corrupt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        minimal_state("http://good:8200")
+            .save(&StateFile::backup_path(&path))
+            .unwrap();
+        std::fs::write(&path, "{\"openbao_url\": ").unwrap();
+
+        let state = StateFile::load(&path).unwrap();
+        assert_eq!(state.openbao_url, "http://good:8200");
+    }
+
+    #[test]
+    fn load_reports_parse_error_without_usable_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        let err = StateFile::load(&path).unwrap_err();
+        assert_eq!(err.to_string(), "Failed to parse state.json");
+    }
+
     #[test]
     fn delivery_mode_defaults_to_local_file() {
         let mode = DeliveryMode::default();
@@ -335,11 +538,14 @@ mod tests {
                 secret_id_ttl: None,
                 secret_id_wrap_ttl: None,
                 token_bound_cidrs: None,
+                approle_bound_cidrs: None,
             },
             agent_email: None,
             agent_server: None,
             agent_responder_url: None,
             cert_group_gid: None,
+            sync_generation: None,
+            trust_generation: None,
         };
         let json = serde_json::to_string_pretty(&entry).expect("serialize");
         let parsed: ServiceEntry = serde_json::from_str(&json).expect("deserialize");
@@ -390,6 +596,7 @@ mod tests {
             secret_id_ttl: Some("1h".to_string()),
             secret_id_wrap_ttl: Some("0".to_string()),
             token_bound_cidrs: Some(vec!["10.0.0.0/24".to_string()]),
+            approle_bound_cidrs: None,
         };
         let json = serde_json::to_string(&entry).expect("serialize");
         let parsed: ServiceRoleEntry = serde_json::from_str(&json).expect("deserialize");
@@ -482,6 +689,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn service_kv_base_defaults_to_library_constant() {
+        let state = StateFile::default();
+        assert_eq!(
+            state.service_kv_base(),
+            bootroot::trust_bootstrap::SERVICE_KV_BASE
+        );
+        assert_eq!(
+            state.service_kv_path("edge-proxy", "eab"),
+            "bootroot/services/edge-proxy/eab"
+        );
+    }
+
+    #[test]
+    fn service_kv_base_follows_recorded_prefix() {
+        let state = StateFile {
+            kv_prefix: Some("tenants/acme/".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(state.service_kv_base(), "tenants/acme/services");
+        assert_eq!(
+            state.service_kv_path("edge-proxy", "trust"),
+            "tenants/acme/services/edge-proxy/trust"
+        );
+        let json = serde_json::to_string(&state).expect("serialize");
+        let parsed: StateFile = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(parsed.kv_prefix(), "tenants/acme");
+    }
+
     #[test]
     fn state_file_without_rotation_fields_deserializes_as_defaults() {
         let json = r#"{
@@ -31,6 +31,29 @@ pub(crate) const OPENBAO_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 /// indefinitely, which shares one client across every poll.
 pub(crate) const OPENBAO_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Returns the `User-Agent` sent by `OpenBao` HTTP clients.
+///
+/// Identifies bootroot traffic in `OpenBao` audit logs as
+/// `bootroot/<version>`, followed by `(git <sha>)` when the build
+/// recorded a commit.
+pub(crate) fn openbao_user_agent() -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    match env!("BOOTROOT_GIT_SHA") {
+        "unknown" => format!("bootroot/{version}"),
+        sha => format!("bootroot/{version} (git {sha})"),
+    }
+}
+
+/// Redirect policy for `OpenBao` HTTP clients.
+///
+/// A standby node answers writes with a 307 pointing at the active node.
+/// Following it would replay the `X-Vault-Token` header to whatever host
+/// the standby names, so redirects are surfaced as errors instead and the
+/// operator points bootroot at the active node.
+pub(crate) fn openbao_redirect_policy() -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::none()
+}
+
 /// Builds a [`reqwest::Client`] configured according to the given
 /// [`TrustSettings`] and runtime TLS override.
 ///
@@ -109,6 +132,8 @@ pub fn build_http_client_from_pem(pem_content: &str, pins: &[String]) -> Result<
         .use_preconfigured_tls(config)
         .connect_timeout(OPENBAO_CONNECT_TIMEOUT)
         .timeout(OPENBAO_REQUEST_TIMEOUT)
+        .redirect(openbao_redirect_policy())
+        .user_agent(openbao_user_agent())
         .build()
         .context("Failed to build HTTP client from PEM bundle")
 }
@@ -166,6 +191,8 @@ pub fn build_http_client_with_local_and_webpki_roots(pem_content: &str) -> Resul
         .use_preconfigured_tls(config)
         .connect_timeout(OPENBAO_CONNECT_TIMEOUT)
         .timeout(OPENBAO_REQUEST_TIMEOUT)
+        .redirect(openbao_redirect_policy())
+        .user_agent(openbao_user_agent())
         .build()
         .context("Failed to build HTTP client with local+webpki roots")
 }
@@ -12,6 +12,9 @@ pub const EAB_HMAC_KEY: &str = "hmac";
 pub const TRUSTED_CA_KEY: &str = "trusted_ca_sha256";
 pub const CA_BUNDLE_PEM_KEY: &str = "ca_bundle_pem";
 pub const CA_BUNDLE_PATH_KEY: &str = "ca_bundle_path";
+/// Counter in the global CA trust payload, bumped whenever the published
+/// fingerprints or CA bundle change.
+pub const TRUST_GENERATION_KEY: &str = "trust_generation";
 
 /// KV v2 path suffix carrying the force-reissue request for a service.
 ///
@@ -43,6 +46,19 @@ pub const SERVICE_RESPONDER_HMAC_KV_SUFFIX: &str = "http_responder_hmac";
 ///
 /// Full path: `{kv_mount}/data/bootroot/services/<service>/eab`.
 pub const SERVICE_EAB_KV_SUFFIX: &str = "eab";
+/// KV v2 path suffix carrying the service sync generation (payload
+/// `{ "generation": <n>, "updated_at": <rfc3339> }`).
+///
+/// The control plane bumps `generation` after every per-service payload
+/// sync for a remote-bootstrap service, so an edge agent can learn that
+/// any of its payloads changed from this one path.
+///
+/// Full path: `{kv_mount}/data/bootroot/services/<service>/generation`.
+pub const SERVICE_GENERATION_KV_SUFFIX: &str = "generation";
+/// Payload field holding the monotonically increasing sync generation.
+pub const GENERATION_KEY: &str = "generation";
+/// Payload field holding the RFC3339 UTC timestamp of the last bump.
+pub const GENERATION_UPDATED_AT_KEY: &str = "updated_at";
 /// Payload field holding the RFC3339 UTC timestamp of the request.
 pub const REISSUE_REQUESTED_AT_KEY: &str = "requested_at";
 /// Payload field describing who issued the request (operator label).
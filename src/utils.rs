@@ -123,6 +123,34 @@ pub fn jittered_delay_with_seed(base: Duration, jitter: Duration, now_ns: i128)
     Duration::from_nanos(adjusted)
 }
 
+/// Extends `renew_before` by a `seed`-derived amount of up to `percent`
+/// percent, so renewal only ever moves earlier.
+///
+/// The extension is further capped at half of the gap between
+/// `renew_before` and the certificate `lifetime`, which keeps the result
+/// strictly below `lifetime`: a freshly issued certificate is never due
+/// right away. A `renew_before` that already reaches `lifetime` is
+/// returned unchanged. The same `seed` always yields the same value.
+#[must_use]
+pub fn jittered_renew_before(
+    renew_before: Duration,
+    percent: u8,
+    seed: u64,
+    lifetime: Duration,
+) -> Duration {
+    let base_ns = renew_before.as_nanos();
+    let jitter_ns = base_ns.saturating_mul(u128::from(percent.min(100))) / 100;
+    let headroom_ns = lifetime.as_nanos().saturating_sub(base_ns) / 2;
+    let max_offset_ns = jitter_ns.min(headroom_ns);
+    if max_offset_ns == 0 {
+        return renew_before;
+    }
+    let offset_ns = u128::from(seed) % max_offset_ns.saturating_add(1);
+    renew_before.saturating_add(Duration::from_nanos(
+        u64::try_from(offset_ns).unwrap_or(u64::MAX),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{Arc, Mutex};
@@ -250,4 +278,63 @@ mod tests {
         assert_eq!(*attempts.lock().expect("attempts mutex"), 1);
         assert!(sleeps.lock().expect("sleeps mutex").is_empty());
     }
+
+    #[test]
+    fn jittered_renew_before_only_moves_renewal_earlier() {
+        let renew_before = Duration::from_hours(16);
+        let lifetime = Duration::from_hours(240);
+        let latest = renew_before.mul_f64(1.1);
+        let mut spread = std::collections::BTreeSet::new();
+
+        for seed in jitter_seeds() {
+            let jittered = jittered_renew_before(renew_before, 10, seed, lifetime);
+            assert!(jittered >= renew_before, "seed {seed}: {jittered:?}");
+            assert!(jittered <= latest, "seed {seed}: {jittered:?}");
+            assert_eq!(
+                jittered,
+                jittered_renew_before(renew_before, 10, seed, lifetime)
+            );
+            spread.insert(jittered);
+        }
+        assert!(spread.len() > 1_000);
+
+        for seed in [0, 1, u64::MAX] {
+            assert_eq!(
+                jittered_renew_before(renew_before, 0, seed, lifetime),
+                renew_before
+            );
+        }
+    }
+
+    #[test]
+    fn jittered_renew_before_stays_below_default_cert_lifetime() {
+        // Defaults: 24h step-ca certificates renewed 16h before expiry.
+        let renew_before = Duration::from_hours(16);
+        let lifetime = Duration::from_hours(24);
+        let latest = Duration::from_hours(20);
+
+        for seed in jitter_seeds() {
+            let jittered = jittered_renew_before(
+                renew_before,
+                crate::config::MAX_RENEW_JITTER_PERCENT,
+                seed,
+                lifetime,
+            );
+            assert!(jittered >= renew_before, "seed {seed}: {jittered:?}");
+            assert!(jittered <= latest, "seed {seed}: {jittered:?}");
+            assert!(jittered < lifetime, "seed {seed}: {jittered:?}");
+        }
+
+        let too_long = Duration::from_hours(30);
+        assert_eq!(
+            jittered_renew_before(too_long, 50, u64::MAX, lifetime),
+            too_long
+        );
+    }
+
+    fn jitter_seeds() -> impl Iterator<Item = u64> {
+        (0..10_000u64)
+            .map(|n| n.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+            .chain([0, u64::MAX])
+    }
 }
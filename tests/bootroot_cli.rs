@@ -169,6 +169,220 @@ async fn test_status_command_summary() {
     assert!(stdout.contains("- edge-proxy delivery mode: local-file"));
 }
 
+#[cfg(unix)]
+#[tokio::test]
+async fn test_infra_up_retries_failed_pull() {
+    use std::env;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    use support::stub_openbao_uninitialized;
+    use tempfile::tempdir;
+    use wiremock::MockServer;
+
+    let temp_dir = tempdir().expect("create temp dir");
+    let compose_file = temp_dir.path().join("docker-compose.yml");
+    fs::write(
+        &compose_file,
+        "services:\n  openbao:\n    image: openbao/openbao\n    ports:\n      - \"127.0.0.1:8200:8200\"\n",
+    )
+    .expect("write compose file");
+
+    // `pull` fails on its first two calls, as a flaky registry would.
+    let bin_dir = temp_dir.path().join("bin");
+    fs::create_dir_all(&bin_dir).expect("create bin dir");
+    let docker = bin_dir.join("docker");
+    fs::write(
+        &docker,
+        r#"#!/bin/sh
+set -eu
+counter="$(dirname "$0")/pull-count"
+if [ "${1:-}" = "compose" ] && [ "${4:-}" = "pull" ]; then
+  count=$(( $(cat "$counter" 2>/dev/null || echo 0) + 1 ))
+  echo "$count" > "$counter"
+  if [ "$count" -le 2 ]; then
+    echo "toomanyrequests: rate limit exceeded" >&2
+    exit 1
+  fi
+  exit 0
+fi
+if [ "${1:-}" = "compose" ] && [ "${4:-}" = "ps" ]; then
+  printf "cid-%s" "${6:-}"
+  exit 0
+fi
+if [ "${1:-}" = "inspect" ]; then
+  printf "running|"
+  exit 0
+fi
+exit 0
+"#,
+    )
+    .expect("write fake docker");
+    fs::set_permissions(&docker, fs::Permissions::from_mode(0o700)).expect("chmod fake docker");
+
+    let server = MockServer::start().await;
+    stub_openbao_uninitialized(&server).await;
+
+    let path = env::var("PATH").unwrap_or_default();
+    let output = Command::new(env!("CARGO_BIN_EXE_bootroot"))
+        .current_dir(temp_dir.path())
+        .args([
+            "infra",
+            "up",
+            "--compose-file",
+            compose_file.to_string_lossy().as_ref(),
+            "--services",
+            "openbao",
+            "--openbao-url",
+            &server.uri(),
+        ])
+        .env("PATH", format!("{}:{path}", bin_dir.display()))
+        .output()
+        .expect("run infra up");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "infra up failed: {stderr}");
+    assert!(stderr.contains("retry 1/3 in 2s"), "stderr: {stderr}");
+    assert!(stderr.contains("retry 2/3 in 4s"), "stderr: {stderr}");
+    let pulls = fs::read_to_string(bin_dir.join("pull-count")).expect("read pull count");
+    assert_eq!(pulls.trim(), "3");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_status_lists_infra_readiness_in_service_order() {
+    use std::env;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    use support::{ROOT_TOKEN, stub_openbao};
+    use tempfile::tempdir;
+    use wiremock::MockServer;
+
+    let temp_dir = tempdir().expect("create temp dir");
+    let compose_file = temp_dir.path().join("docker-compose.yml");
+    fs::write(&compose_file, "services: {}").expect("write compose file");
+
+    // Inspecting earlier services takes longer, so the concurrent probes
+    // finish in reverse order.
+    let bin_dir = temp_dir.path().join("bin");
+    fs::create_dir_all(&bin_dir).expect("create bin dir");
+    let docker = bin_dir.join("docker");
+    fs::write(
+        &docker,
+        r#"#!/bin/sh
+set -eu
+if [ "${1:-}" = "compose" ] && [ "${4:-}" = "ps" ]; then
+  printf "cid-%s" "${6:-}"
+  exit 0
+fi
+if [ "${1:-}" = "inspect" ]; then
+  case "${4:-}" in
+    cid-openbao) sleep 0.6; printf "running|healthy" ;;
+    cid-postgres) sleep 0.4; printf "running|" ;;
+    cid-step-ca) sleep 0.2; printf "restarting|" ;;
+    *) printf "exited|" ;;
+  esac
+  exit 0
+fi
+exit 0
+"#,
+    )
+    .expect("write fake docker");
+    fs::set_permissions(&docker, fs::Permissions::from_mode(0o700)).expect("chmod fake docker");
+
+    let server = MockServer::start().await;
+    stub_openbao(&server).await;
+
+    let path = env::var("PATH").unwrap_or_default();
+    let output = Command::new(env!("CARGO_BIN_EXE_bootroot"))
+        .current_dir(temp_dir.path())
+        .args([
+            "status",
+            "--compose-file",
+            compose_file.to_string_lossy().as_ref(),
+            "--openbao-url",
+            &server.uri(),
+            "--root-token",
+            ROOT_TOKEN,
+        ])
+        .env("PATH", format!("{}:{path}", bin_dir.display()))
+        .output()
+        .expect("run status");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let positions: Vec<usize> = [
+        "  - openbao: running (health: healthy)",
+        "  - postgres: running",
+        "  - step-ca: restarting",
+        "  - bootroot-http01: exited",
+    ]
+    .iter()
+    .map(|line| {
+        stdout
+            .find(line)
+            .unwrap_or_else(|| panic!("missing {line:?} in stdout: {stdout}"))
+    })
+    .collect();
+    assert!(
+        positions.windows(2).all(|pair| pair[0] < pair[1]),
+        "readiness entries out of order: {stdout}"
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_status_command_uses_vault_token_when_root_token_unset() {
+    use std::env;
+    use std::fs;
+
+    use support::{ROOT_TOKEN, stub_openbao, write_fake_docker};
+    use tempfile::tempdir;
+    use wiremock::MockServer;
+
+    let temp_dir = tempdir().expect("create temp dir");
+    let compose_file = temp_dir.path().join("docker-compose.yml");
+    fs::write(&compose_file, "services: {}").expect("write compose file");
+
+    let bin_dir = temp_dir.path().join("bin");
+    fs::create_dir_all(&bin_dir).expect("create bin dir");
+    write_fake_docker(&bin_dir).expect("write fake docker");
+
+    let server = MockServer::start().await;
+    stub_openbao(&server).await;
+    write_state_with_service(temp_dir.path()).expect("write state");
+
+    let path = env::var("PATH").unwrap_or_default();
+    let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bootroot"))
+        .current_dir(temp_dir.path())
+        .args([
+            "status",
+            "--compose-file",
+            compose_file.to_string_lossy().as_ref(),
+            "--openbao-url",
+            &server.uri(),
+        ])
+        .env("PATH", combined_path)
+        .env_remove("OPENBAO_ROOT_TOKEN")
+        .env_remove("BAO_TOKEN")
+        .env("VAULT_TOKEN", ROOT_TOKEN)
+        .output()
+        .expect("run status");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    // KV/AppRole checks only run with a token, so their results prove
+    // VAULT_TOKEN reached the client.
+    assert!(stdout.contains("- KV paths:"), "stdout: {stdout}");
+    assert!(!stdout.contains("unknown"), "stdout: {stdout}");
+}
+
 #[cfg(unix)]
 #[tokio::test]
 async fn test_status_command_reports_infra_failure() {
@@ -308,6 +522,66 @@ async fn test_status_command_reports_unknown_without_token() {
     assert!(stdout.contains("unknown"));
 }
 
+#[cfg(unix)]
+#[tokio::test]
+async fn test_status_command_infers_openbao_url_from_vault_addr() {
+    use std::env;
+    use std::fs;
+
+    use anyhow::Context;
+    use support::write_fake_docker;
+    use tempfile::tempdir;
+    use wiremock::MockServer;
+
+    let temp_dir = tempdir().expect("create temp dir");
+    let compose_file = temp_dir.path().join("docker-compose.yml");
+    fs::write(&compose_file, "services: {}")
+        .context("write compose file")
+        .unwrap();
+
+    let bin_dir = temp_dir.path().join("bin");
+    fs::create_dir_all(&bin_dir)
+        .context("create bin dir")
+        .unwrap();
+    write_fake_docker(&bin_dir).expect("write fake docker");
+
+    let server = MockServer::start().await;
+    status_helpers::stub_openbao_health(&server).await;
+    status_helpers::stub_openbao_seal_status(&server).await;
+
+    let path = env::var("PATH").unwrap_or_default();
+    let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+    // No state.json and no --openbao-url: the URL must come from VAULT_ADDR,
+    // which fills the canonical OPENBAO_URL at startup.
+    let output = Command::new(env!("CARGO_BIN_EXE_bootroot"))
+        .current_dir(temp_dir.path())
+        .args([
+            "status",
+            "--compose-file",
+            compose_file.to_string_lossy().as_ref(),
+        ])
+        .env("PATH", combined_path)
+        .env_remove("OPENBAO_URL")
+        .env_remove("OPENBAO_ADDR")
+        .env_remove("BAO_ADDR")
+        .env("VAULT_ADDR", server.uri())
+        .output()
+        .expect("run status");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains(&format!(
+            "using OpenBao URL {} (from OPENBAO_URL)",
+            server.uri()
+        )),
+        "stderr: {stderr}"
+    );
+    assert!(stdout.contains("- OpenBao:"), "stdout: {stdout}");
+}
+
 #[cfg(unix)]
 #[tokio::test]
 async fn test_status_command_reports_invalid_kv_mount() {
@@ -368,6 +642,204 @@ fn test_status_command_message_korean() {
     assert!(stdout.contains("status"));
 }
 
+#[test]
+fn test_lang_flag_selects_korean_output_over_env() {
+    let (stdout, _stderr, code) = run_with_env(
+        &["--lang", "ko", "version", "--verbose"],
+        "BOOTROOT_LANG",
+        "en",
+    );
+    assert_eq!(code, 0);
+    assert!(stdout.contains("git 커밋: "), "stdout was: {stdout}");
+}
+
+#[test]
+fn test_lang_flag_rejects_unsupported_code_before_running_command() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let out_file = dir.path().join("bundle.zip");
+    let (_stdout, stderr, code) = run(&[
+        "--lang",
+        "xx",
+        "support-bundle",
+        "--out",
+        out_file.to_string_lossy().as_ref(),
+    ]);
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("Unsupported language: xx"),
+        "stderr was: {stderr}"
+    );
+    assert!(!out_file.exists(), "no bundle may be written");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_operation_timeout_aborts_command_that_exceeds_deadline() {
+    use std::fs;
+
+    use support::write_fake_docker;
+
+    let temp_dir = tempfile::tempdir().expect("create temp dir");
+    let compose_file = temp_dir.path().join("docker-compose.yml");
+    fs::write(&compose_file, "services: {}").expect("write compose file");
+    let bin_dir = temp_dir.path().join("bin");
+    fs::create_dir_all(&bin_dir).expect("create bin dir");
+    write_fake_docker(&bin_dir).expect("write fake docker");
+
+    // Accepts connections into the backlog but never answers, so the
+    // OpenBao probe hangs well past the deadline.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind listener");
+    let openbao_url = format!("http://{}", listener.local_addr().expect("local addr"));
+
+    let path = std::env::var("PATH").unwrap_or_default();
+    let started = std::time::Instant::now();
+    let output = Command::new(env!("CARGO_BIN_EXE_bootroot"))
+        .current_dir(temp_dir.path())
+        .args([
+            "--operation-timeout",
+            "1",
+            "status",
+            "--compose-file",
+            compose_file.to_string_lossy().as_ref(),
+            "--openbao-url",
+            &openbao_url,
+        ])
+        .env("PATH", format!("{}:{path}", bin_dir.display()))
+        .output()
+        .expect("run status");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(124), "stderr was: {stderr}");
+    assert!(
+        stderr.contains("Operation exceeded its deadline of 1s"),
+        "stderr was: {stderr}"
+    );
+    assert!(started.elapsed() < std::time::Duration::from_secs(25));
+    drop(listener);
+}
+
+#[test]
+fn test_operation_timeout_rejects_zero() {
+    let (_stdout, stderr, code) = run(&["--operation-timeout", "0", "version"]);
+    assert_eq!(code, 2);
+    assert!(
+        stderr.contains("--operation-timeout"),
+        "stderr was: {stderr}"
+    );
+}
+
+#[test]
+fn test_version_verbose_includes_crate_version_and_build_info() {
+    let (stdout, _stderr, code) = run(&["version", "--verbose"]);
+    assert_eq!(code, 0);
+    assert!(
+        stdout.contains(&format!("bootroot {}", env!("CARGO_PKG_VERSION"))),
+        "stdout was: {stdout}"
+    );
+    assert!(stdout.contains("git commit: "), "stdout was: {stdout}");
+    assert!(stdout.contains("rustc: "), "stdout was: {stdout}");
+}
+
+#[test]
+fn test_audit_secrets_warns_on_tracked_password() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir.path())
+            .status()
+            .expect("git runs in tests");
+        assert!(status.success(), "git {args:?} failed");
+    };
+    git(&["init", "--quiet"]);
+    std::fs::write(dir.path().join("password.txt"), "secret").expect("write password");
+    git(&["add", "password.txt"]);
+
+    let repo_dir = dir.path().to_string_lossy().to_string();
+    let (_stdout, stderr, code) = run(&["audit-secrets", "--repo-dir", &repo_dir]);
+    assert_eq!(code, 1);
+    assert!(
+        stderr.contains("WARNING: secret file is tracked by git: password.txt"),
+        "stderr was: {stderr}"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_support_bundle_zips_ps_and_redacted_state_and_logs() {
+    use std::env;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().expect("create temp dir");
+    let compose_file = temp_dir.path().join("docker-compose.yml");
+    fs::write(&compose_file, "services: {}").expect("write compose file");
+
+    let bin_dir = temp_dir.path().join("bin");
+    fs::create_dir_all(&bin_dir).expect("create bin dir");
+    let docker = bin_dir.join("docker");
+    fs::write(
+        &docker,
+        r#"#!/bin/sh
+set -eu
+if [ "${4:-}" = "ps" ]; then
+  printf "NAME STATUS\nbootroot-openbao running\n"
+fi
+if [ "${4:-}" = "logs" ]; then
+  printf "bootroot-postgres | POSTGRES_PASSWORD=pg-log-secret ready\n"
+fi
+exit 0
+"#,
+    )
+    .expect("write fake docker");
+    fs::set_permissions(&docker, fs::Permissions::from_mode(0o700)).expect("chmod fake docker");
+
+    write_state_with_service(temp_dir.path()).expect("write state");
+
+    let path = env::var("PATH").unwrap_or_default();
+    let combined_path = format!("{}:{}", bin_dir.display(), path);
+    let out_file = temp_dir.path().join("bundle.zip");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_bootroot"))
+        .current_dir(temp_dir.path())
+        .args([
+            "support-bundle",
+            "--out",
+            out_file.to_string_lossy().as_ref(),
+            "--compose-file",
+            compose_file.to_string_lossy().as_ref(),
+        ])
+        .env("PATH", combined_path)
+        .output()
+        .expect("run support-bundle");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Entries are stored uncompressed, so their text appears verbatim in
+    // the archive bytes.
+    let archive = fs::read(&out_file).expect("read bundle");
+    assert!(archive.starts_with(b"PK\x03\x04"), "bundle must be a zip");
+    let contents = String::from_utf8_lossy(&archive);
+    for name in [
+        "version.txt",
+        "compose-ps.txt",
+        "compose-logs.txt",
+        "state.json",
+    ] {
+        assert!(contents.contains(name), "missing {name}");
+    }
+    assert!(contents.contains("bootroot-openbao running"));
+    assert!(contents.contains("POSTGRES_PASSWORD=[REDACTED] ready"));
+    assert!(!contents.contains("pg-log-secret"));
+    assert!(!contents.contains("role-edge-proxy"));
+    assert!(contents.contains("edge-node-01"));
+}
+
 #[cfg(unix)]
 fn write_state_with_service(root: &std::path::Path) -> anyhow::Result<()> {
     let state = serde_json::json!({
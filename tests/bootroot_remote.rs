@@ -85,6 +85,95 @@ async fn test_bootroot_remote_applies_service_secrets() {
     assert_mode(ca_bundle_path.parent().expect("ca bundle parent"), 0o700);
 }
 
+#[tokio::test]
+async fn test_bootroot_remote_bootstraps_from_wrapped_artifact() {
+    let temp_dir = tempdir().expect("create temp dir");
+    let secrets_dir = temp_dir.path().join("secrets");
+    let role_id_path = secrets_dir.join("role_id");
+    let secret_id_path = secrets_dir.join("secret_id");
+    let eab_file_path = secrets_dir.join("eab.json");
+    let ca_bundle_path = temp_dir.path().join("certs").join("ca-bundle.pem");
+    let agent_config_path = temp_dir.path().join("agent.toml");
+    let artifact_path = temp_dir.path().join("bootstrap.json");
+
+    fs::create_dir_all(&secrets_dir).expect("create secrets dir");
+    fs::write(&role_id_path, "role-edge-proxy\n").expect("write role_id");
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/sys/wrapping/unwrap"))
+        .and(header("X-Vault-Token", "wrap-token-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "secret_id": "unwrapped-secret-id" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    stub_openbao_remote_sync(&server).await;
+
+    let (ca_bundle_pem, fingerprint) = valid_remote_trust();
+    let artifact = json!({
+        "schema_version": 4,
+        "openbao_url": server.uri(),
+        "kv_mount": "secret",
+        "service_name": "edge-proxy",
+        "role_id_path": role_id_path,
+        "secret_id_path": secret_id_path,
+        "eab_file_path": eab_file_path,
+        "agent_config_path": agent_config_path,
+        "profile_instance_id": "001",
+        "ca_bundle_path": ca_bundle_path,
+        "ca_bundle_pem": ca_bundle_pem,
+        "trusted_ca_sha256": [fingerprint],
+        "wrap_token": "wrap-token-1",
+        "wrap_expires_at": "2099-01-01T00:00:00Z"
+    });
+    fs::write(
+        &artifact_path,
+        serde_json::to_string_pretty(&artifact).expect("serialize artifact"),
+    )
+    .expect("write artifact");
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_bootroot-remote"))
+        .args([
+            "bootstrap",
+            "--artifact",
+            artifact_path.to_string_lossy().as_ref(),
+        ])
+        .output()
+        .expect("run bootroot-remote");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stdout.contains("- secret_id: applied"));
+    assert!(stdout.contains("- trust_sync: "));
+    assert!(stdout.contains("next steps:"));
+    assert!(stdout.contains(&format!(
+        "bootroot-agent --config {} --eab-file {}",
+        agent_config_path.display(),
+        eab_file_path.display()
+    )));
+
+    assert_eq!(
+        fs::read_to_string(&secret_id_path).expect("read secret_id"),
+        "new-secret-id\n"
+    );
+    assert_eq!(
+        fs::read_to_string(&ca_bundle_path).expect("read ca bundle"),
+        ca_bundle_pem
+    );
+    let agent_contents = fs::read_to_string(&agent_config_path).expect("read agent config");
+    assert!(agent_contents.contains("http_responder_hmac = \"responder-hmac-1\""));
+    assert!(agent_contents.contains("trusted_ca_sha256 = ["));
+
+    assert_mode(&secret_id_path, 0o600);
+    assert_mode(&eab_file_path, 0o600);
+    assert_mode(&agent_config_path, 0o600);
+    assert_mode(&ca_bundle_path, 0o600);
+    assert_mode(ca_bundle_path.parent().expect("ca bundle parent"), 0o700);
+}
+
 #[tokio::test]
 async fn test_bootroot_remote_is_idempotent_on_second_run() {
     let temp_dir = tempdir().expect("create temp dir");
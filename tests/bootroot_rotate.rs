@@ -1370,6 +1370,18 @@ fn write_state_file(root: &Path, openbao_url: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Stubs the sync generation bump that follows every per-service payload
+/// write for a remote-bootstrap service.
+async fn stub_service_generation(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/v1/secret/data/bootroot/services/{SERVICE_NAME}/generation"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(server)
+        .await;
+}
+
 async fn stub_openbao_for_rotation(server: &MockServer, new_secret_id: &str) {
     Mock::given(method("GET"))
         .and(path("/v1/sys/health"))
@@ -1415,6 +1427,8 @@ async fn stub_openbao_for_rotation(server: &MockServer, new_secret_id: &str) {
         .respond_with(ResponseTemplate::new(200))
         .mount(server)
         .await;
+
+    stub_service_generation(server).await;
 }
 
 /// Stubs `OpenBao` for rotation without a login mock. If login is
@@ -1513,6 +1527,8 @@ async fn stub_openbao_for_wrapped_rotation(server: &MockServer, new_secret_id: &
         .respond_with(ResponseTemplate::new(200))
         .mount(server)
         .await;
+
+    stub_service_generation(server).await;
 }
 
 async fn stub_openbao_for_stepca_password_rotation(server: &MockServer, expected_password: &str) {
@@ -1570,6 +1586,8 @@ async fn stub_openbao_for_responder_hmac_rotation_with_token(
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
         .mount(server)
         .await;
+
+    stub_service_generation(server).await;
 }
 
 async fn stub_openbao_for_responder_hmac_rotation_forbidden(server: &MockServer, token: &str) {
@@ -1801,6 +1819,18 @@ fn write_fake_docker(bin_dir: &Path, output_path: &Path) -> anyhow::Result<()> {
     let script = r#"#!/bin/sh
 set -eu
 
+# Report one running container per compose service label filter so
+# the OpenBao Agent discovery finds the bootroot-named agents.
+if [ "${1:-}" = "ps" ]; then
+  for arg in "$@"; do
+    case "$arg" in
+      label=com.docker.compose.service=*)
+        printf 'bootroot-%s\trunning\n' "${arg#label=com.docker.compose.service=}" ;;
+    esac
+  done
+  exit 0
+fi
+
 if [ -n "${DOCKER_OUTPUT:-}" ]; then
   printf "%s\n" "$*" >> "$DOCKER_OUTPUT"
 fi
@@ -1858,6 +1888,7 @@ async fn test_rotate_trust_sync_writes_global_and_per_service() {
         .expect(1)
         .mount_as_scoped(&openbao)
         .await;
+    stub_service_generation(&openbao).await;
 
     let output = Command::new(env!("CARGO_BIN_EXE_bootroot"))
         .current_dir(temp_dir.path())
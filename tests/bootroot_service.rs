@@ -164,6 +164,84 @@ async fn test_app_add_writes_state_and_secret() {
     assert_local_fast_poll_artifacts(temp_dir.path(), &agent_config, "edge-proxy");
 }
 
+#[cfg(unix)]
+#[tokio::test]
+async fn test_app_add_output_json_reports_approle() {
+    use support::ROOT_TOKEN;
+
+    let temp_dir = tempdir().expect("create temp dir");
+    let server = MockServer::start().await;
+    let agent_config = temp_dir.path().join("agent.toml");
+    let cert_path = temp_dir.path().join("certs").join("edge-proxy.crt");
+    let key_path = temp_dir.path().join("certs").join("edge-proxy.key");
+    fs::create_dir_all(cert_path.parent().unwrap()).expect("create cert dir");
+
+    write_state_file(temp_dir.path(), &server.uri()).expect("write state.json");
+    stub_app_add_openbao(&server, "edge-proxy").await;
+    stub_app_add_trust_missing(&server).await;
+    stub_app_add_service_sync_material(&server, "edge-proxy").await;
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_bootroot"))
+        .current_dir(temp_dir.path())
+        .args([
+            "service",
+            "add",
+            "--service-name",
+            "edge-proxy",
+            "--hostname",
+            "edge-node-01",
+            "--domain",
+            "trusted.domain",
+            "--agent-config",
+            agent_config.to_string_lossy().as_ref(),
+            "--cert-path",
+            cert_path.to_string_lossy().as_ref(),
+            "--key-path",
+            key_path.to_string_lossy().as_ref(),
+            "--instance-id",
+            "001",
+            "--root-token",
+            ROOT_TOKEN,
+            "--output",
+            "json",
+        ])
+        .output()
+        .expect("run service add");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "stdout:\n{stdout}\nstderr:\n{stderr}"
+    );
+    let result: serde_json::Value =
+        serde_json::from_str(&stdout).expect("stdout must be a single JSON document");
+    assert_eq!(result["service_name"], "edge-proxy");
+    assert_eq!(
+        result["approle"]["role_name"],
+        "bootroot-service-edge-proxy"
+    );
+    let secret_id_path = result["approle"]["secret_id_path"]
+        .as_str()
+        .expect("secret_id_path string");
+    assert!(
+        secret_id_path.ends_with("secrets/services/edge-proxy/secret_id"),
+        "unexpected secret_id_path: {secret_id_path}"
+    );
+    assert_ne!(
+        result["approle"]["role_id"], "role-edge-proxy",
+        "role_id must be masked without --show-secrets"
+    );
+    assert!(
+        result["kv_paths"]
+            .as_array()
+            .expect("kv_paths array")
+            .iter()
+            .any(|path| path == "secret/bootroot/services/edge-proxy/eab"),
+        "kv_paths must list the EAB path: {result}"
+    );
+}
+
 /// Issue #722 — a local-file `service add --secret-id-path <abs>`
 /// relocates `secret_id`, its sibling `role_id`, and `eab.json` into the
 /// operator-provisioned directory (outside `<secrets_dir>`), and the
@@ -2528,6 +2606,60 @@ fn test_app_info_missing_state_file() {
     assert!(stderr.contains("bootroot service info failed"));
 }
 
+fn run_service_list(root: &std::path::Path, extra_args: &[&str]) -> String {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_bootroot"))
+        .current_dir(root)
+        .args(["service", "list"])
+        .args(extra_args)
+        .output()
+        .expect("run service list");
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[cfg(unix)]
+#[test]
+fn test_service_list_without_services() {
+    let temp_dir = tempdir().expect("create temp dir");
+    write_state_file(temp_dir.path(), "http://localhost:8200").expect("write state.json");
+
+    let stdout = run_service_list(temp_dir.path(), &[]);
+    assert!(stdout.contains("- services:"), "{stdout}");
+    assert!(stdout.contains("  - none registered"), "{stdout}");
+
+    let stdout = run_service_list(temp_dir.path(), &["--output", "json"]);
+    let services: serde_json::Value = serde_json::from_str(&stdout).expect("parse json");
+    assert_eq!(services, json!([]));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_service_list_prints_registered_services() {
+    let temp_dir = tempdir().expect("create temp dir");
+    write_state_file(temp_dir.path(), "http://localhost:8200").expect("write state.json");
+    write_state_with_app(temp_dir.path());
+
+    let stdout = run_service_list(temp_dir.path(), &[]);
+    assert!(
+        stdout
+            .contains("  - edge-proxy (local-file): hostname edge-node-01, domain trusted.domain"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("none registered"), "{stdout}");
+
+    let stdout = run_service_list(temp_dir.path(), &["--output", "json"]);
+    let services: serde_json::Value = serde_json::from_str(&stdout).expect("parse json");
+    assert_eq!(services[0]["service_name"], "edge-proxy");
+    assert_eq!(services[0]["delivery_mode"], "local-file");
+    assert_eq!(services[0]["hostname"], "edge-node-01");
+    assert_eq!(services[0]["domain"], "trusted.domain");
+    assert!(services[0].get("approle").is_none(), "{stdout}");
+}
+
 fn write_state_file(root: &std::path::Path, openbao_url: &str) -> anyhow::Result<()> {
     let state = json!({
         "openbao_url": openbao_url,
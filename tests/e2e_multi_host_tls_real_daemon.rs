@@ -365,7 +365,14 @@ async fn provision_openbao(
     client.write_policy(&policy_name, &policy).await?;
 
     client
-        .create_approle(SERVICE_NAME, &[policy_name.as_str()], "1h", "1h", false)
+        .create_approle(
+            SERVICE_NAME,
+            &[policy_name.as_str()],
+            "1h",
+            "1h",
+            false,
+            &[],
+        )
         .await?;
     let role_id = client.read_role_id(SERVICE_NAME).await?;
 
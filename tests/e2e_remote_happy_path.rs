@@ -20,6 +20,7 @@ const SERVICE_NAME: &str = "edge-proxy";
 const HOSTNAME: &str = "edge-node-02";
 const DOMAIN: &str = "trusted.domain";
 const INSTANCE_ID: &str = "101";
+const DEFAULT_SERVICE_KV_BASE: &str = "bootroot/services";
 
 #[tokio::test]
 async fn test_two_node_remote_bootstrap_happy_path() {
@@ -31,7 +32,7 @@ async fn test_two_node_remote_bootstrap_happy_path() {
 
     let server = MockServer::start().await;
     stub_control_plane_openbao_no_wrap(&server).await;
-    stub_remote_service_secrets(&server).await;
+    stub_remote_service_secrets(&server, DEFAULT_SERVICE_KV_BASE).await;
 
     write_control_state(&control_dir, &server.uri()).expect("write control state");
     prepare_service_node_files(&service_dir).expect("prepare service node files");
@@ -242,7 +243,16 @@ fn run_remote_bootstrap(service_dir: &Path, openbao_url: &str) -> anyhow::Result
 }
 
 fn write_control_state(root: &Path, openbao_url: &str) -> anyhow::Result<()> {
+    write_control_state_with_prefix(root, openbao_url, None)
+}
+
+fn write_control_state_with_prefix(
+    root: &Path,
+    openbao_url: &str,
+    kv_prefix: Option<&str>,
+) -> anyhow::Result<()> {
     let state = json!({
+        "kv_prefix": kv_prefix,
         "openbao_url": openbao_url,
         "kv_mount": "secret",
         "secrets_dir": "secrets",
@@ -376,11 +386,11 @@ fn assert_mode(path: &Path, expected: u32) {
     assert_eq!(mode, expected, "path {}", path.display());
 }
 
-async fn stub_control_plane_openbao(server: &MockServer) {
+async fn stub_control_plane_openbao(server: &MockServer, service_kv_base: &str) {
     let role_name = format!("bootroot-service-{SERVICE_NAME}");
     stub_control_plane_approle(server, &role_name).await;
     stub_control_plane_global_materials(server).await;
-    stub_control_plane_service_material_writes(server).await;
+    stub_control_plane_service_material_writes(server, service_kv_base).await;
 }
 
 async fn stub_control_plane_approle(server: &MockServer, role_name: &str) {
@@ -464,7 +474,7 @@ async fn stub_control_plane_openbao_no_wrap(server: &MockServer) {
     let role_name = format!("bootroot-service-{SERVICE_NAME}");
     stub_control_plane_approle_no_wrap(server, &role_name).await;
     stub_control_plane_global_materials(server).await;
-    stub_control_plane_service_material_writes(server).await;
+    stub_control_plane_service_material_writes(server, DEFAULT_SERVICE_KV_BASE).await;
 }
 
 async fn stub_control_plane_approle_no_wrap(server: &MockServer, role_name: &str) {
@@ -571,35 +581,17 @@ async fn stub_control_plane_global_materials(server: &MockServer) {
         .await;
 }
 
-async fn stub_control_plane_service_material_writes(server: &MockServer) {
-    Mock::given(method("POST"))
-        .and(path(
-            "/v1/secret/data/bootroot/services/edge-proxy/secret_id",
-        ))
-        .and(header("X-Vault-Token", RUNTIME_CLIENT_TOKEN))
-        .respond_with(ResponseTemplate::new(200))
-        .mount(server)
-        .await;
-    Mock::given(method("POST"))
-        .and(path("/v1/secret/data/bootroot/services/edge-proxy/eab"))
-        .and(header("X-Vault-Token", RUNTIME_CLIENT_TOKEN))
-        .respond_with(ResponseTemplate::new(200))
-        .mount(server)
-        .await;
-    Mock::given(method("POST"))
-        .and(path(
-            "/v1/secret/data/bootroot/services/edge-proxy/http_responder_hmac",
-        ))
-        .and(header("X-Vault-Token", RUNTIME_CLIENT_TOKEN))
-        .respond_with(ResponseTemplate::new(200))
-        .mount(server)
-        .await;
-    Mock::given(method("POST"))
-        .and(path("/v1/secret/data/bootroot/services/edge-proxy/trust"))
-        .and(header("X-Vault-Token", RUNTIME_CLIENT_TOKEN))
-        .respond_with(ResponseTemplate::new(200))
-        .mount(server)
-        .await;
+async fn stub_control_plane_service_material_writes(server: &MockServer, service_kv_base: &str) {
+    for suffix in ["secret_id", "eab", "http_responder_hmac", "trust"] {
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/v1/secret/data/{service_kv_base}/{SERVICE_NAME}/{suffix}"
+            )))
+            .and(header("X-Vault-Token", RUNTIME_CLIENT_TOKEN))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(server)
+            .await;
+    }
 }
 
 fn copy_bootstrap_artifact_and_role_id(
@@ -664,8 +656,8 @@ async fn test_remote_bootstrap_artifact_invocation() {
     fs::create_dir_all(&service_dir).expect("create service dir");
 
     let server = MockServer::start().await;
-    stub_control_plane_openbao(&server).await;
-    stub_remote_service_secrets(&server).await;
+    stub_control_plane_openbao(&server, DEFAULT_SERVICE_KV_BASE).await;
+    stub_remote_service_secrets(&server, DEFAULT_SERVICE_KV_BASE).await;
 
     write_control_state(&control_dir, &server.uri()).expect("write control state");
     prepare_service_node_files(&service_dir).expect("prepare service node files");
@@ -706,6 +698,49 @@ async fn test_remote_bootstrap_artifact_invocation() {
     assert!(eab_path.exists(), "eab should be written after bootstrap");
 }
 
+/// With a custom `kv_prefix` in `state.json`, `service add` writes the
+/// service payloads under `<prefix>/services/<name>/` and the artifact
+/// carries that base, so `bootroot-remote bootstrap` reads the same tree
+/// and points the agent's fast-poll loop at it.  Only the prefixed paths
+/// are stubbed, so any read or write of the default tree fails the run.
+#[tokio::test]
+async fn test_remote_bootstrap_follows_custom_kv_prefix() {
+    const SERVICE_KV_BASE: &str = "tenants/acme/services";
+
+    let temp = tempdir().expect("create tempdir");
+    let control_dir = temp.path().join("prefix-control");
+    let service_dir = temp.path().join("prefix-service");
+    fs::create_dir_all(&control_dir).expect("create control dir");
+    fs::create_dir_all(&service_dir).expect("create service dir");
+
+    let server = MockServer::start().await;
+    stub_control_plane_openbao(&server, SERVICE_KV_BASE).await;
+    stub_remote_service_secrets(&server, SERVICE_KV_BASE).await;
+
+    write_control_state_with_prefix(&control_dir, &server.uri(), Some("tenants/acme/"))
+        .expect("write control state");
+    prepare_service_node_files(&service_dir).expect("prepare service node files");
+
+    run_service_add_remote(&control_dir, &service_dir).expect("service add remote");
+    copy_bootstrap_artifact_and_role_id(&control_dir, &service_dir)
+        .expect("copy artifact + role_id");
+    let artifact: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(service_dir.join("bootstrap.json")).expect("read artifact"),
+    )
+    .expect("parse artifact");
+    assert_eq!(artifact["service_kv_base"], SERVICE_KV_BASE);
+
+    run_remote_bootstrap_with_artifact(&service_dir, &server.uri())
+        .expect("remote bootstrap via artifact");
+
+    let agent_contents =
+        fs::read_to_string(service_dir.join("agent.toml")).expect("read agent config");
+    assert!(
+        agent_contents.contains(&format!("service_kv_base = \"{SERVICE_KV_BASE}\"")),
+        "{agent_contents}"
+    );
+}
+
 #[tokio::test]
 async fn test_remote_bootstrap_expired_wrap_token() {
     let temp = tempdir().expect("create tempdir");
@@ -713,7 +748,7 @@ async fn test_remote_bootstrap_expired_wrap_token() {
     fs::create_dir_all(&service_dir).expect("create service dir");
 
     let server = MockServer::start().await;
-    stub_remote_service_secrets(&server).await;
+    stub_remote_service_secrets(&server, DEFAULT_SERVICE_KV_BASE).await;
 
     // Stub an unwrap failure
     Mock::given(method("POST"))
@@ -1178,7 +1213,9 @@ async fn test_remote_bootstrap_https_rejects_wrong_ca() {
     );
 }
 
-async fn stub_remote_service_secrets(server: &MockServer) {
+async fn stub_remote_service_secrets(server: &MockServer, service_kv_base: &str) {
+    let service_path =
+        |suffix: &str| format!("/v1/secret/data/{service_kv_base}/{SERVICE_NAME}/{suffix}");
     Mock::given(method("POST"))
         .and(path("/v1/auth/approle/login"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
@@ -1187,9 +1224,7 @@ async fn stub_remote_service_secrets(server: &MockServer) {
         .mount(server)
         .await;
     Mock::given(method("GET"))
-        .and(path(
-            "/v1/secret/data/bootroot/services/edge-proxy/secret_id",
-        ))
+        .and(path(service_path("secret_id")))
         .and(header("X-Vault-Token", "remote-token"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
             "data": { "data": { "secret_id": "remote-secret-id" } }
@@ -1197,7 +1232,7 @@ async fn stub_remote_service_secrets(server: &MockServer) {
         .mount(server)
         .await;
     Mock::given(method("GET"))
-        .and(path("/v1/secret/data/bootroot/services/edge-proxy/eab"))
+        .and(path(service_path("eab")))
         .and(header("X-Vault-Token", "remote-token"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
             "data": { "data": { "kid": "remote-kid", "hmac": "remote-hmac" } }
@@ -1205,9 +1240,7 @@ async fn stub_remote_service_secrets(server: &MockServer) {
         .mount(server)
         .await;
     Mock::given(method("GET"))
-        .and(path(
-            "/v1/secret/data/bootroot/services/edge-proxy/http_responder_hmac",
-        ))
+        .and(path(service_path("http_responder_hmac")))
         .and(header("X-Vault-Token", "remote-token"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
             "data": { "data": { "hmac": "remote-responder-hmac" } }
@@ -1216,7 +1249,7 @@ async fn stub_remote_service_secrets(server: &MockServer) {
         .await;
     let (ca_pem, ca_fp) = support::test_trust_material();
     Mock::given(method("GET"))
-        .and(path("/v1/secret/data/bootroot/services/edge-proxy/trust"))
+        .and(path(service_path("trust")))
         .and(header("X-Vault-Token", "remote-token"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
             "data": { "data": {
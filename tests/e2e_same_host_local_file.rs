@@ -596,6 +596,18 @@ fn write_fake_docker(root: &Path) -> anyhow::Result<()> {
     let script = r#"#!/bin/sh
 set -eu
 
+# Report one running container per compose service label filter so
+# the OpenBao Agent discovery finds the bootroot-named agents.
+if [ "${1:-}" = "ps" ]; then
+  for arg in "$@"; do
+    case "$arg" in
+      label=com.docker.compose.service=*)
+        printf 'bootroot-%s\trunning\n' "${arg#label=com.docker.compose.service=}" ;;
+    esac
+  done
+  exit 0
+fi
+
 if [ -n "${DOCKER_OUTPUT:-}" ]; then
   printf "%s\n" "$*" >> "$DOCKER_OUTPUT"
 fi
@@ -0,0 +1,34 @@
+vault {
+  address = "http://openbao:8200"
+}
+
+auto_auth {
+  method "approle" {
+    config = {
+      role_id_file_path = "/openbao/secrets/openbao/stepca/role_id"
+      secret_id_file_path = "/openbao/secrets/openbao/stepca/secret_id"
+      remove_secret_id_file_after_reading = false
+    }
+  }
+  sink "file" {
+    config = {
+      path = "/openbao/secrets/openbao/token"
+    }
+  }
+}
+
+template_config {
+  static_secret_render_interval = "30s"
+}
+
+template {
+  source = "/openbao/secrets/templates/password.txt.ctmpl"
+  destination = "/openbao/secrets/password.txt"
+  perms = "0600"
+}
+
+template {
+  source = "/openbao/secrets/templates/ca.json.ctmpl"
+  destination = "/openbao/secrets/config/ca.json"
+  perms = "0600"
+}
@@ -106,6 +106,92 @@ async fn read_kv_returns_data() {
     );
 }
 
+#[tokio::test]
+async fn read_ca_trust_returns_typed_payload() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/data/bootroot/ca"))
+        .and(header("X-Vault-Token", "root-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "data": {
+                    "trusted_ca_sha256": ["a".repeat(64)],
+                    "ca_bundle_pem": "pem\n",
+                    "trust_generation": 2
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client_with_token(&server);
+
+    let trust = client
+        .read_ca_trust("secret")
+        .await
+        .expect("read_ca_trust should succeed");
+    assert_eq!(trust.trusted_ca_sha256, vec!["a".repeat(64)]);
+    assert_eq!(trust.ca_bundle_pem, "pem\n");
+    assert_eq!(trust.trust_generation, 2);
+}
+
+#[tokio::test]
+async fn read_ca_trust_rejects_payload_without_fingerprints() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/data/bootroot/ca"))
+        .and(header("X-Vault-Token", "root-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "data": {
+                    "ca_bundle_pem": "pem\n",
+                    "trust_generation": 2
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client_with_token(&server);
+
+    let err = client
+        .read_ca_trust("secret")
+        .await
+        .expect_err("missing fingerprints must not default to an empty list");
+    assert_eq!(
+        err.to_string(),
+        "Malformed KV payload at secret/bootroot/ca"
+    );
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Missing required array key: trusted_ca_sha256"
+    );
+}
+
+#[tokio::test]
+async fn read_responder_hmac_returns_value() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/secret/data/bootroot/responder/hmac"))
+        .and(header("X-Vault-Token", "root-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "data": { "value": "responder-hmac" } }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client_with_token(&server);
+
+    let hmac = client
+        .read_responder_hmac("secret")
+        .await
+        .expect("read_responder_hmac should succeed");
+    assert_eq!(hmac, "responder-hmac");
+}
+
 #[tokio::test]
 async fn read_kv_with_version_returns_data_and_version() {
     let server = MockServer::start().await;
@@ -400,6 +486,67 @@ async fn kv_mount_status_returns_not_v2() {
     assert_eq!(status, KvMountStatus::NotV2);
 }
 
+#[tokio::test]
+async fn ensure_kv_v2_reports_mount_type_mismatch() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/sys/mounts/secret"))
+        .and(header("X-Vault-Token", "root-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "type": "transit",
+                "options": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client_with_token(&server);
+    let err = client
+        .ensure_kv_v2("secret")
+        .await
+        .expect_err("transit mount must be rejected");
+    assert!(
+        err.to_string()
+            .contains("Mount secret is type transit, expected kv"),
+        "unexpected error: {err}"
+    );
+    let requests = server.received_requests().await.expect("recording");
+    assert!(
+        requests.iter().all(|req| req.method.as_str() == "GET"),
+        "no write may follow a type mismatch: {requests:?}"
+    );
+}
+
+#[tokio::test]
+async fn ensure_kv_v2_reports_kv_v1_mount() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/sys/mounts/secret"))
+        .and(header("X-Vault-Token", "root-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "type": "kv",
+                "options": {}
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client_with_token(&server);
+    let err = client
+        .ensure_kv_v2("secret")
+        .await
+        .expect_err("kv v1 mount must be rejected");
+    assert!(
+        err.to_string()
+            .contains("Mount secret is kv version 1, expected kv version 2"),
+        "unexpected error: {err}"
+    );
+}
+
 #[tokio::test]
 async fn delete_policy_uses_acl_path() {
     let server = MockServer::start().await;
@@ -494,6 +641,40 @@ async fn is_initialized_errors_on_malformed_body() {
     assert!(err.to_string().contains("OpenBao response parse failed"));
 }
 
+#[tokio::test]
+async fn init_with_recovery_keys_parses_recovery_key_response() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/sys/init"))
+        .and(body_json(json!({
+            "recovery_shares": 5,
+            "recovery_threshold": 3
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "keys": [],
+            "keys_base64": [],
+            "recovery_keys": ["recovery-hex-1", "recovery-hex-2"],
+            "recovery_keys_base64": ["cmVjb3Zlcnkx", "cmVjb3Zlcnky"],
+            "root_token": "root-token"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = OpenBaoClient::new(&server.uri()).expect("client init should succeed");
+    let response = client
+        .init_with_recovery_keys(5, 3)
+        .await
+        .expect("recovery init should succeed");
+    assert!(response.keys.is_empty());
+    assert_eq!(
+        response.recovery_keys,
+        vec!["recovery-hex-1".to_string(), "recovery-hex-2".to_string()]
+    );
+    assert_eq!(response.recovery_keys_base64.len(), 2);
+    assert_eq!(response.root_token, "root-token");
+}
+
 #[tokio::test]
 async fn start_root_rotation_uses_sys_rotate_root_init_path() {
     let server = MockServer::start().await;
@@ -801,3 +982,107 @@ async fn create_secret_id_with_token_bound_cidrs() {
         .expect("create_secret_id with CIDRs should succeed");
     assert_eq!(secret_id, "cidr-bound-secret");
 }
+
+#[tokio::test]
+async fn create_approle_sends_bound_cidrs() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/svc-role"))
+        .and(header("X-Vault-Token", "root-token"))
+        .and(body_json(json!({
+            "token_policies": ["svc-policy"],
+            "token_ttl": "1h",
+            "token_max_ttl": "1h",
+            "token_renewable": true,
+            "secret_id_ttl": "24h",
+            "secret_id_bound_cidrs": ["10.0.0.0/24"],
+            "token_bound_cidrs": ["10.0.0.0/24"]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_with_token(&server);
+    client
+        .create_approle(
+            "svc-role",
+            &["svc-policy"],
+            "1h",
+            "24h",
+            true,
+            &["10.0.0.0/24".to_string()],
+        )
+        .await
+        .expect("create_approle with bound CIDRs should succeed");
+}
+
+#[tokio::test]
+async fn create_approle_clears_bound_cidrs_when_empty() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/auth/approle/role/svc-role"))
+        .and(header("X-Vault-Token", "root-token"))
+        .and(body_json(json!({
+            "token_policies": ["svc-policy"],
+            "token_ttl": "1h",
+            "token_max_ttl": "1h",
+            "token_renewable": false,
+            "secret_id_ttl": "24h",
+            "secret_id_bound_cidrs": [],
+            "token_bound_cidrs": []
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_with_token(&server);
+    client
+        .create_approle("svc-role", &["svc-policy"], "1h", "24h", false, &[])
+        .await
+        .expect("create_approle without bound CIDRs should succeed");
+}
+
+#[tokio::test]
+async fn write_redirected_by_standby_fails_without_following() {
+    let standby = MockServer::start().await;
+    let active = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/secret/data/bootroot/stepca/password"))
+        .respond_with(ResponseTemplate::new(307).insert_header(
+            "Location",
+            format!("{}/v1/secret/data/bootroot/stepca/password", active.uri()),
+        ))
+        .expect(1)
+        .mount(&standby)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&active)
+        .await;
+
+    let client = client_with_token(&standby);
+    let err = client
+        .write_kv(
+            "secret",
+            "bootroot/stepca/password",
+            json!({ "value": "secret" }),
+        )
+        .await
+        .expect_err("a redirected write must fail");
+    let message = format!("{err:#}");
+    assert!(message.contains("307"), "unexpected error: {message}");
+    assert!(
+        message.contains(&active.uri()),
+        "unexpected error: {message}"
+    );
+    assert!(
+        message.contains("active node"),
+        "unexpected error: {message}"
+    );
+}
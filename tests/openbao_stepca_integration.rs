@@ -13,15 +13,24 @@ mod unix_integration {
     use wiremock::matchers::{method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    /// Compose file defining the services `init` drives; the responder
+    /// is left out so init treats the deployment as responder-less.
+    const INIT_COMPOSE: &str = "services:\n  openbao:\n    image: openbao\n  postgres:\n    image: postgres\n  \
+         step-ca:\n    image: step-ca\n";
+
     use super::support::{
         ROOT_TOKEN, create_secrets_dir, expect_rollback_deletes, stub_openbao,
         stub_openbao_audit_failure, stub_openbao_expect_audit, stub_openbao_sealed,
-        stub_openbao_unseal_failure, stub_openbao_with_write_failure, write_dotenv_file,
-        write_fake_docker, write_fake_docker_with_status, write_password_file,
+        stub_openbao_uninitialized, stub_openbao_unseal_failure, stub_openbao_with_write_failure,
+        write_dotenv_file, write_fake_docker, write_fake_docker_with_log,
+        write_fake_docker_with_status, write_password_file,
     };
 
     fn run_command_with_input(command: &mut Command, input: &str) -> Result<Output> {
         let mut child = command
+            // The fake docker never runs the OpenBao Agents, so there is
+            // no render to wait for.
+            .env("BOOTROOT_AGENT_RENDER_TIMEOUT_SECS", "0")
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -44,7 +53,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -89,12 +98,754 @@ mod unix_integration {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn init_audit_log_records_each_step_without_secrets() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+        write_dotenv_file(temp_dir.path())?;
+
+        let bin_dir = temp_dir.path().join("bin");
+        fs::create_dir_all(&bin_dir).context("Failed to create bin dir")?;
+        write_fake_docker(&bin_dir)?;
+
+        let server = MockServer::start().await;
+        stub_openbao_uninitialized(&server).await;
+
+        let path = env::var("PATH").unwrap_or_default();
+        let combined_path = format!("{}:{}", bin_dir.display(), path);
+        let audit_log = temp_dir.path().join("audit").join("init.jsonl");
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command
+            .current_dir(temp_dir.path())
+            .args([
+                "init",
+                "--openbao-url",
+                &server.uri(),
+                "--enable",
+                "auto-generate",
+                "--save-unseal-keys",
+                "--yes",
+                "--secrets-dir",
+                secrets_dir.to_string_lossy().as_ref(),
+                "--compose-file",
+                compose_file.to_string_lossy().as_ref(),
+                "--skip",
+                "responder-check",
+                "--audit-log",
+                audit_log.to_string_lossy().as_ref(),
+            ])
+            .env("PATH", combined_path);
+        let output = run_command_with_input(&mut command, "").context("Failed to run init")?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("bootroot init --audit-log failed: {stderr}");
+        }
+
+        let contents = fs::read_to_string(&audit_log).context("Failed to read audit log")?;
+        let records = contents
+            .lines()
+            .map(serde_json::from_str::<serde_json::Value>)
+            .collect::<Result<Vec<_>, _>>()
+            .context("audit log lines must be JSON")?;
+        let steps: Vec<&str> = records
+            .iter()
+            .filter_map(|record| record["step"].as_str())
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                "openbao_initialized",
+                "openbao_unsealed",
+                "policies_written",
+                "approles_created",
+                "kv_paths_written",
+                "trust_fingerprints_stored",
+            ]
+        );
+        for record in &records {
+            assert_eq!(record["status"], "ok", "record: {record}");
+            assert!(record["timestamp"].is_string(), "record: {record}");
+        }
+        assert!(
+            !contents.contains(ROOT_TOKEN),
+            "audit log leaked: {contents}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_skip_step_ca_bypasses_stepca_bootstrap_and_kv() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        // Without ca.json a regular init would run `step ca init`.
+        fs::remove_file(secrets_dir.join("config").join("ca.json"))
+            .context("Failed to remove ca.json")?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+
+        let bin_dir = temp_dir.path().join("bin");
+        fs::create_dir_all(&bin_dir).context("Failed to create bin dir")?;
+        let docker_log = temp_dir.path().join("docker.log");
+        write_fake_docker_with_log(&bin_dir, &docker_log)?;
+
+        let server = MockServer::start().await;
+        stub_openbao(&server).await;
+
+        let path = env::var("PATH").unwrap_or_default();
+        let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command
+            .current_dir(temp_dir.path())
+            .args([
+                "init",
+                "--openbao-url",
+                &server.uri(),
+                "--root-token",
+                ROOT_TOKEN,
+                "--enable",
+                "auto-generate",
+                "--skip-step-ca",
+                "--no-eab",
+                "--secrets-dir",
+                secrets_dir.to_string_lossy().as_ref(),
+                "--compose-file",
+                compose_file.to_string_lossy().as_ref(),
+            ])
+            .env("PATH", combined_path);
+        let output = run_command_with_input(&mut command, "").context("Failed to run init")?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            anyhow::bail!("bootroot init --skip-step-ca failed: {stderr}");
+        }
+        assert!(stdout.contains("external CA"), "stdout was: {stdout}");
+
+        let docker_calls = fs::read_to_string(&docker_log).unwrap_or_default();
+        assert!(
+            !docker_calls.contains("ca init"),
+            "step ca init must not run: {docker_calls}"
+        );
+        assert!(
+            !docker_calls.contains("step-ca"),
+            "docker calls: {docker_calls}"
+        );
+        assert!(!secrets_dir.join("password.txt").exists());
+        assert!(!secrets_dir.join("config").join("ca.json").exists());
+
+        let requests = server
+            .received_requests()
+            .await
+            .context("request recording disabled")?;
+        let stepca_writes: Vec<String> = requests
+            .iter()
+            .filter(|req| req.method.as_str() != "GET")
+            .map(|req| req.url.path().to_string())
+            .filter(|p| {
+                p.contains("bootroot/stepca")
+                    || p.ends_with("/bootroot-stepca")
+                    || p.contains("bootroot-stepca-role")
+            })
+            .collect();
+        assert!(
+            stepca_writes.is_empty(),
+            "unexpected step-ca writes: {stepca_writes:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_openbao_only_configures_openbao_without_stepca() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        // Without ca.json a full init would run `step ca init`.
+        fs::remove_file(secrets_dir.join("config").join("ca.json"))
+            .context("Failed to remove ca.json")?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+        write_dotenv_file(temp_dir.path())?;
+
+        let bin_dir = temp_dir.path().join("bin");
+        fs::create_dir_all(&bin_dir).context("Failed to create bin dir")?;
+        let docker_log = temp_dir.path().join("docker.log");
+        write_fake_docker_with_log(&bin_dir, &docker_log)?;
+
+        let server = MockServer::start().await;
+        stub_openbao(&server).await;
+
+        let path = env::var("PATH").unwrap_or_default();
+        let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command
+            .current_dir(temp_dir.path())
+            .args([
+                "init",
+                "--openbao-url",
+                &server.uri(),
+                "--root-token",
+                ROOT_TOKEN,
+                "--enable",
+                "auto-generate",
+                "--openbao-only",
+                "--no-eab",
+                "--secrets-dir",
+                secrets_dir.to_string_lossy().as_ref(),
+                "--compose-file",
+                compose_file.to_string_lossy().as_ref(),
+            ])
+            .env("PATH", combined_path);
+        let output = run_command_with_input(&mut command, "").context("Failed to run init")?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            anyhow::bail!("bootroot init --openbao-only failed: {stderr}");
+        }
+        assert!(stdout.contains("--openbao-only"), "stdout was: {stdout}");
+        assert!(stdout.contains("init --resume"), "stdout was: {stdout}");
+        assert!(temp_dir.path().join("state.json").exists());
+
+        let docker_calls = fs::read_to_string(&docker_log).unwrap_or_default();
+        assert!(
+            !docker_calls.contains("ca init"),
+            "step ca init must not run: {docker_calls}"
+        );
+        assert!(!secrets_dir.join("password.txt").exists());
+        assert!(!secrets_dir.join("config").join("ca.json").exists());
+        assert!(!secrets_dir.join("responder").exists());
+        assert!(!secrets_dir.join("openbao").join("stepca").exists());
+
+        let requests = server
+            .received_requests()
+            .await
+            .context("request recording disabled")?;
+        let posts: Vec<String> = requests
+            .iter()
+            .filter(|req| req.method.as_str() == "POST")
+            .map(|req| req.url.path().to_string())
+            .collect();
+        for expected in [
+            "/v1/sys/policies/acl/bootroot-stepca",
+            "/v1/auth/approle/role/bootroot-stepca-role",
+            "/v1/secret/data/bootroot/stepca/password",
+            "/v1/secret/data/bootroot/responder/hmac",
+        ] {
+            assert!(
+                posts.iter().any(|p| p == expected),
+                "{expected} must be written: {posts:?}"
+            );
+        }
+        assert!(
+            !posts.iter().any(|p| p == "/v1/secret/data/bootroot/ca"),
+            "CA trust must be left for the resume phase: {posts:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_dump_plan_json_reports_plan_without_writes() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+        let state_json = r#"{"openbao_url":"http://localhost:8200","kv_mount":"secret"}"#;
+        fs::write(temp_dir.path().join("state.json"), state_json)
+            .context("Failed to write state.json")?;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/health"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/init"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "initialized": true })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/sys/seal-status"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "sealed": false })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/metadata/bootroot/stepca/password"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": {} })),
+            )
+            .mount(&server)
+            .await;
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command.current_dir(temp_dir.path()).args([
+            "init",
+            "--openbao-url",
+            &server.uri(),
+            "--root-token",
+            ROOT_TOKEN,
+            "--dump-plan-json",
+            "--secrets-dir",
+            secrets_dir.to_string_lossy().as_ref(),
+            "--compose-file",
+            compose_file.to_string_lossy().as_ref(),
+        ]);
+        let output = run_command_with_input(&mut command, "").context("Failed to run init")?;
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            anyhow::bail!("bootroot init --dump-plan-json failed: {stderr}");
+        }
+
+        let plan: serde_json::Value =
+            serde_json::from_slice(&output.stdout).context("plan must be JSON")?;
+        assert_eq!(plan["overwrite_state"], true);
+        assert_eq!(plan["overwrite_ca_json"], true);
+        assert_eq!(plan["db_provision"], false);
+        assert_eq!(plan["initialize_stepca"], false);
+        assert_eq!(plan["kv_mount"], "secret");
+        assert_eq!(
+            plan["new_kv_paths"],
+            serde_json::json!(["bootroot/stepca/db", "bootroot/responder/hmac"])
+        );
+
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("state.json"))?,
+            state_json,
+            "state.json must be left untouched"
+        );
+        assert!(!secrets_dir.join("password.txt").exists());
+        let requests = server
+            .received_requests()
+            .await
+            .context("request recording disabled")?;
+        assert!(
+            requests.iter().all(|req| req.method.as_str() == "GET"),
+            "--dump-plan-json must only read OpenBao"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_dry_run_lists_actions_without_contacting_openbao() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+        let state_json = r#"{"openbao_url":"http://localhost:8200","kv_mount":"secret"}"#;
+        fs::write(temp_dir.path().join("state.json"), state_json)
+            .context("Failed to write state.json")?;
+
+        let server = MockServer::start().await;
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command.current_dir(temp_dir.path()).args([
+            "init",
+            "--openbao-url",
+            &server.uri(),
+            "--root-token",
+            ROOT_TOKEN,
+            "--dry-run",
+            "--secrets-dir",
+            secrets_dir.to_string_lossy().as_ref(),
+            "--compose-file",
+            compose_file.to_string_lossy().as_ref(),
+        ]);
+        let output = run_command_with_input(&mut command, "").context("Failed to run init")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            anyhow::bail!("bootroot init --dry-run failed: {stderr}");
+        }
+
+        assert!(stdout.contains("bootroot init: plan"), "stdout: {stdout}");
+        assert!(
+            stdout.contains("sys/policies/acl/bootroot-stepca"),
+            "stdout: {stdout}"
+        );
+        assert!(
+            stdout.contains("secret/data/bootroot/responder/hmac"),
+            "stdout: {stdout}"
+        );
+        assert!(
+            stdout.contains(&secrets_dir.join("password.txt").display().to_string()),
+            "stdout: {stdout}"
+        );
+        assert!(stdout.contains("docker compose"), "stdout: {stdout}");
+
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("state.json"))?,
+            state_json,
+            "state.json must be left untouched"
+        );
+        assert!(!secrets_dir.join("password.txt").exists());
+        let requests = server
+            .received_requests()
+            .await
+            .context("request recording disabled")?;
+        assert!(requests.is_empty(), "--dry-run must not contact OpenBao");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_resume_after_openbao_only_reuses_stored_stepca_password() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        // A leftover password.txt must not override the password kept in
+        // OpenBao, which the step-ca agent renders.
+        write_password_file(&secrets_dir, "leftover-password")?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+        write_dotenv_file(temp_dir.path())?;
+
+        let bin_dir = temp_dir.path().join("bin");
+        fs::create_dir_all(&bin_dir).context("Failed to create bin dir")?;
+        write_fake_docker(&bin_dir)?;
+
+        let server = MockServer::start().await;
+        for stored in [
+            "/v1/secret/metadata/bootroot/stepca/password",
+            "/v1/secret/metadata/bootroot/stepca/db",
+        ] {
+            Mock::given(method("GET"))
+                .and(path(stored))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "data": {}
+                })))
+                .with_priority(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/bootroot/stepca/password"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": { "value": "phase-one-password" } }
+            })))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        stub_openbao(&server).await;
+
+        let path = env::var("PATH").unwrap_or_default();
+        let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command
+            .current_dir(temp_dir.path())
+            .args([
+                "init",
+                "--openbao-url",
+                &server.uri(),
+                "--root-token",
+                ROOT_TOKEN,
+                "--enable",
+                "auto-generate",
+                "--resume",
+                "--no-eab",
+                "--secrets-dir",
+                secrets_dir.to_string_lossy().as_ref(),
+                "--compose-file",
+                compose_file.to_string_lossy().as_ref(),
+            ])
+            .env("PATH", combined_path);
+        let output = run_command_with_input(&mut command, "").context("Failed to run init")?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            anyhow::bail!("bootroot init --resume failed: {stderr}");
+        }
+        assert_eq!(
+            fs::read_to_string(secrets_dir.join("password.txt"))?.trim_end(),
+            "phase-one-password"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_resume_skips_existing_resources() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        // Leftovers of an interrupted init: password.txt on disk plus a
+        // policy, an AppRole, and the responder HMAC in OpenBao.
+        write_password_file(&secrets_dir, "previous-password")?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+        write_dotenv_file(temp_dir.path())?;
+
+        let bin_dir = temp_dir.path().join("bin");
+        fs::create_dir_all(&bin_dir).context("Failed to create bin dir")?;
+        write_fake_docker(&bin_dir)?;
+
+        let server = MockServer::start().await;
+        for existing in [
+            "/v1/sys/policies/acl/bootroot-agent",
+            "/v1/auth/approle/role/bootroot-agent-role",
+            "/v1/secret/metadata/bootroot/responder/hmac",
+        ] {
+            Mock::given(method("GET"))
+                .and(path(existing))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "data": {}
+                })))
+                .with_priority(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/bootroot/responder/hmac"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": { "value": "previous-hmac" } }
+            })))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        stub_openbao(&server).await;
+
+        let path = env::var("PATH").unwrap_or_default();
+        let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command
+            .current_dir(temp_dir.path())
+            .args([
+                "init",
+                "--openbao-url",
+                &server.uri(),
+                "--root-token",
+                ROOT_TOKEN,
+                "--enable",
+                "auto-generate",
+                "--resume",
+                "--no-eab",
+                "--secrets-dir",
+                secrets_dir.to_string_lossy().as_ref(),
+                "--compose-file",
+                compose_file.to_string_lossy().as_ref(),
+            ])
+            .env("PATH", combined_path);
+        // No stdin: resume must not ask to overwrite password.txt/ca.json.
+        let output = run_command_with_input(&mut command, "").context("Failed to run init")?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            anyhow::bail!("bootroot init --resume failed: {stderr}");
+        }
+        assert_eq!(
+            fs::read_to_string(secrets_dir.join("password.txt"))?.trim_end(),
+            "previous-password"
+        );
+        let responder_config =
+            fs::read_to_string(secrets_dir.join("responder").join("responder.toml"))?;
+        assert!(responder_config.contains("previous-hmac"));
+
+        let requests = server
+            .received_requests()
+            .await
+            .context("request recording disabled")?;
+        let posts: Vec<String> = requests
+            .iter()
+            .filter(|req| req.method.as_str() == "POST")
+            .map(|req| req.url.path().to_string())
+            .collect();
+        for existing in [
+            "/v1/sys/policies/acl/bootroot-agent",
+            "/v1/auth/approle/role/bootroot-agent-role",
+            "/v1/secret/data/bootroot/responder/hmac",
+        ] {
+            assert!(
+                !posts.iter().any(|p| p == existing),
+                "{existing} must not be recreated: {posts:?}"
+            );
+        }
+        assert!(
+            posts
+                .iter()
+                .any(|p| p == "/v1/sys/policies/acl/bootroot-responder"),
+            "missing policies must still be created: {posts:?}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_approle_bind_cidr_binds_only_the_service_role() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+        write_dotenv_file(temp_dir.path())?;
+
+        let bin_dir = temp_dir.path().join("bin");
+        fs::create_dir_all(&bin_dir).context("Failed to create bin dir")?;
+        write_fake_docker(&bin_dir)?;
+
+        let server = MockServer::start().await;
+        stub_openbao(&server).await;
+
+        let path = env::var("PATH").unwrap_or_default();
+        let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command
+            .current_dir(temp_dir.path())
+            .args([
+                "init",
+                "--openbao-url",
+                &server.uri(),
+                "--root-token",
+                ROOT_TOKEN,
+                "--enable",
+                "auto-generate",
+                "--no-eab",
+                "--approle-bind-cidr",
+                "10.0.0.0/24",
+                "--secrets-dir",
+                secrets_dir.to_string_lossy().as_ref(),
+                "--compose-file",
+                compose_file.to_string_lossy().as_ref(),
+            ])
+            .env("PATH", combined_path);
+        let output =
+            run_command_with_input(&mut command, "y\n").context("Failed to run bootroot init")?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            anyhow::bail!("bootroot init --approle-bind-cidr failed: {stderr}");
+        }
+
+        let requests = server
+            .received_requests()
+            .await
+            .context("request recording disabled")?;
+        let role_bindings: Vec<(String, serde_json::Value)> = requests
+            .iter()
+            .filter(|req| req.method.as_str() == "POST")
+            .filter_map(|req| {
+                let role = req.url.path().strip_prefix("/v1/auth/approle/role/")?;
+                if role.contains('/') {
+                    return None;
+                }
+                let body: serde_json::Value = serde_json::from_slice(&req.body).ok()?;
+                Some((role.to_string(), body))
+            })
+            .collect();
+        assert!(
+            role_bindings
+                .iter()
+                .any(|(role, _)| role == "bootroot-agent-role"),
+            "service role was not written: {role_bindings:?}"
+        );
+        for (role, body) in &role_bindings {
+            let expected = if role == "bootroot-agent-role" {
+                serde_json::json!(["10.0.0.0/24"])
+            } else {
+                serde_json::json!([])
+            };
+            assert_eq!(body["secret_id_bound_cidrs"], expected, "{role}");
+            assert_eq!(body["token_bound_cidrs"], expected, "{role}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_skip_responder_skips_hmac_kv_and_responder_check() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
+        write_dotenv_file(temp_dir.path())?;
+
+        let bin_dir = temp_dir.path().join("bin");
+        fs::create_dir_all(&bin_dir).context("Failed to create bin dir")?;
+        write_fake_docker(&bin_dir)?;
+
+        let server = MockServer::start().await;
+        stub_openbao(&server).await;
+        // An explicit responder URL would normally be probed; it must stay
+        // untouched when the responder is skipped.
+        let responder = MockServer::start().await;
+
+        let path = env::var("PATH").unwrap_or_default();
+        let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command
+            .current_dir(temp_dir.path())
+            .args([
+                "init",
+                "--openbao-url",
+                &server.uri(),
+                "--root-token",
+                ROOT_TOKEN,
+                "--enable",
+                "auto-generate",
+                "--skip-responder",
+                "--no-eab",
+                "--responder-url",
+                &responder.uri(),
+                "--secrets-dir",
+                secrets_dir.to_string_lossy().as_ref(),
+                "--compose-file",
+                compose_file.to_string_lossy().as_ref(),
+            ])
+            .env("PATH", combined_path);
+        let output =
+            run_command_with_input(&mut command, "y\n").context("Failed to run bootroot init")?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            anyhow::bail!("bootroot init --skip-responder failed: {stderr}");
+        }
+        assert!(
+            stdout.contains("responder check: skipped"),
+            "stdout was: {stdout}"
+        );
+        assert!(!stdout.contains("responder hmac"), "stdout was: {stdout}");
+        assert!(!secrets_dir.join("responder").exists());
+
+        let responder_requests = responder
+            .received_requests()
+            .await
+            .context("request recording disabled")?;
+        assert!(
+            responder_requests.is_empty(),
+            "responder must not be probed: {responder_requests:?}"
+        );
+
+        let requests = server
+            .received_requests()
+            .await
+            .context("request recording disabled")?;
+        let responder_writes: Vec<String> = requests
+            .iter()
+            .filter(|req| req.method.as_str() != "GET")
+            .map(|req| req.url.path().to_string())
+            .filter(|p| {
+                p.contains("bootroot/responder")
+                    || p.ends_with("/bootroot-responder")
+                    || p.contains("bootroot-responder-role")
+            })
+            .collect();
+        assert!(
+            responder_writes.is_empty(),
+            "unexpected responder writes: {responder_writes:?}"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn init_enables_audit_backend() -> Result<()> {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -140,7 +891,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -190,7 +941,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -244,7 +995,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -297,7 +1048,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -353,7 +1104,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -392,7 +1143,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -436,7 +1187,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
         let unseal_file = temp_dir.path().join("unseal.txt");
         fs::write(&unseal_file, "key1\n").context("Failed to write unseal file")?;
@@ -489,7 +1240,7 @@ mod unix_integration {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
 
         let bin_dir = temp_dir.path().join("bin");
@@ -540,12 +1291,88 @@ mod unix_integration {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn init_failure_stops_services_started_by_overrides() -> Result<()> {
+        let temp_dir = tempdir().context("Failed to create temp dir")?;
+        let secrets_dir = create_secrets_dir(temp_dir.path())?;
+        let compose_file = temp_dir.path().join("docker-compose.yml");
+        fs::write(
+            &compose_file,
+            format!("{INIT_COMPOSE}  bootroot-http01:\n    image: bootroot-http01:latest\n"),
+        )
+        .context("Failed to write compose file")?;
+        write_dotenv_file(temp_dir.path())?;
+
+        let bin_dir = temp_dir.path().join("bin");
+        fs::create_dir_all(&bin_dir).context("Failed to create bin dir")?;
+        let docker_log = temp_dir.path().join("docker.log");
+        write_fake_docker_with_log(&bin_dir, &docker_log)?;
+
+        let server = MockServer::start().await;
+        stub_openbao(&server).await;
+
+        // The responder check runs after both overrides were applied.
+        let responder = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/admin/http01"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&responder)
+            .await;
+
+        let path = env::var("PATH").unwrap_or_default();
+        let combined_path = format!("{}:{}", bin_dir.display(), path);
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_bootroot"));
+        command
+            .current_dir(temp_dir.path())
+            .args([
+                "init",
+                "--openbao-url",
+                &server.uri(),
+                "--root-token",
+                ROOT_TOKEN,
+                "--enable",
+                "auto-generate",
+                "--secrets-dir",
+                secrets_dir.to_string_lossy().as_ref(),
+                "--compose-file",
+                compose_file.to_string_lossy().as_ref(),
+                "--responder-url",
+                &responder.uri(),
+            ])
+            .env("PATH", combined_path);
+        let output =
+            run_command_with_input(&mut command, "y\n").context("Failed to run bootroot init")?;
+
+        assert!(!output.status.success());
+        let log = fs::read_to_string(&docker_log).context("Failed to read docker log")?;
+        let responder_up = log
+            .lines()
+            .position(|line| line.contains("up -d --no-deps bootroot-http01"))
+            .context("responder override was not applied")?;
+        let agents_up = log
+            .lines()
+            .position(|line| line.contains("up -d --no-deps openbao-agent-stepca"))
+            .context("agent override was not applied")?;
+        let responder_stop = log
+            .lines()
+            .position(|line| line.ends_with("stop bootroot-http01"))
+            .with_context(|| format!("rollback did not stop the responder: {log}"))?;
+        let agents_rm = log
+            .lines()
+            .position(|line| line.contains("rm -s -f openbao-agent-stepca openbao-agent-responder"))
+            .with_context(|| format!("rollback did not remove the agents: {log}"))?;
+        assert!(responder_stop > responder_up, "docker log: {log}");
+        assert!(agents_rm > agents_up, "docker log: {log}");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn init_cancels_on_overwrite_prompt() -> Result<()> {
         let temp_dir = tempdir().context("Failed to create temp dir")?;
         let secrets_dir = create_secrets_dir(temp_dir.path())?;
         let compose_file = temp_dir.path().join("docker-compose.yml");
-        fs::write(&compose_file, "services: {}").context("Failed to write compose file")?;
+        fs::write(&compose_file, INIT_COMPOSE).context("Failed to write compose file")?;
         write_dotenv_file(temp_dir.path())?;
         fs::write(temp_dir.path().join("state.json"), "{}")
             .context("Failed to write state.json")?;
@@ -67,6 +67,20 @@ exit 0
     Ok(path)
 }
 
+/// Writes a fake `docker` that behaves like [`write_fake_docker`] and
+/// appends each invocation's argv as one line to `log_path`.
+pub(crate) fn write_fake_docker_with_log(dir: &Path, log_path: &Path) -> Result<PathBuf> {
+    let path = write_fake_docker(dir)?;
+    let script = fs::read_to_string(&path).context("Failed to read fake docker script")?;
+    let logged = script.replacen(
+        "set -eu\n",
+        &format!("set -eu\n\necho \"$*\" >> \"{}\"\n", log_path.display()),
+        1,
+    );
+    fs::write(&path, logged).context("Failed to write fake docker script")?;
+    Ok(path)
+}
+
 pub(crate) fn create_secrets_dir(root: &Path) -> Result<PathBuf> {
     let secrets_dir = root.join("secrets");
     fs::create_dir_all(secrets_dir.join("config"))
@@ -289,6 +303,29 @@ pub(crate) async fn stub_openbao_sealed(server: &MockServer) {
     stub_kv_secrets(server).await;
 }
 
+pub(crate) async fn stub_openbao_uninitialized(server: &MockServer) {
+    stub_health(server).await;
+    stub_init_status_uninitialized(server).await;
+    stub_seal_status_sealed(server).await;
+    stub_unseal_success(server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/sys/init"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "keys": ["key1"],
+            "root_token": ROOT_TOKEN
+        })))
+        .mount(server)
+        .await;
+
+    stub_kv_mount(server).await;
+    stub_auth_backends(server).await;
+    stub_audit_backend(server).await;
+    stub_policies(server).await;
+    stub_approles(server).await;
+    stub_kv_secrets(server).await;
+}
+
 pub(crate) async fn expect_rollback_deletes(server: &MockServer, include_ca_trust: bool) {
     for policy in POLICY_NAMES {
         Mock::given(method("DELETE"))